@@ -6,6 +6,7 @@ use core::ops::{Deref, DerefMut};
 use crate::str::BasicStr;
 
 use crate::traits::CharTraits;
+#[cfg(feature = "utf")]
 use crate::utf::UtfCharTraits;
 
 pub struct BasicArrayString<CharT, Traits, const N: usize>([CharT; N], PhantomData<Traits>);
@@ -30,7 +31,7 @@ impl<CharT: PartialEq, Traits, const N: usize> PartialEq<BasicStr<CharT, Traits>
     for BasicArrayString<CharT, Traits, N>
 {
     fn eq(&self, rhs: &BasicStr<CharT, Traits>) -> bool {
-        &self.0 == rhs.as_chars()
+        self.0 == rhs.as_chars()
     }
 }
 
@@ -60,6 +61,10 @@ impl<Traits: CharTraits, const N: usize> Ord for BasicArrayString<Traits::Char,
 }
 
 impl<CharT, CharTraits, const N: usize> BasicArrayString<CharT, CharTraits, N> {
+    /// Converts an array of `CharT` into a [`BasicArrayString`] without validating the array
+    ///
+    /// # Safety
+    /// The `chars` shall be valid according to [`CharTraits::validate_range`](crate::traits::CharTraits::validate_range)
     #[inline]
     pub const unsafe fn from_chars_unchecked(chars: [CharT; N]) -> Self {
         Self(chars, PhantomData)
@@ -75,6 +80,10 @@ impl<CharT, CharTraits, const N: usize> BasicArrayString<CharT, CharTraits, N> {
         self.0
     }
 
+    /// Returns the underlying array as a mutably borrowed slice of `CharT`
+    ///
+    /// # Safety
+    /// The result slice shall not be modified to be invalid according to [`CharTraits::validate_range`](crate::traits::CharTraits::validate_range)
     #[inline]
     pub unsafe fn as_chars_mut(&mut self) -> &mut [CharT] {
         &mut self.0
@@ -201,6 +210,7 @@ impl<const N: usize> From<[char; N]> for U32ArrayString<N> {
     }
 }
 
+#[cfg(feature = "utf")]
 impl<const N: usize> AsMut<[char]> for U32ArrayString<N> {
     fn as_mut(&mut self) -> &mut [char] {
         &mut self.0
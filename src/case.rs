@@ -0,0 +1,588 @@
+//! Unicode case mapping for [`BasicStr`]
+//!
+//! The full case mappings and case foldings of individual characters and the Cased and Case_Ignorable properties
+//!  are generated by `tools/gen_case_tables.py` from Unicode 15.0.0, so they do not change with the version of Unicode
+//!  supported by [`char`].
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "pattern")]
+use crate::pattern::{Pattern, RevPattern, StepSearcher};
+use crate::str::UnicodeIter;
+#[cfg(feature = "alloc")]
+use crate::string::BasicString;
+#[cfg(feature = "pattern")]
+use crate::traits::DecodeRev;
+use crate::{str::BasicStr, traits::IntoChars};
+
+#[rustfmt::skip]
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+mod tables;
+
+/// A language whose case mapping rules differ from the default rules
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CaseLocale {
+    /// The default, language-independent case mapping rules
+    #[default]
+    Root,
+    /// The case mapping rules of Turkish and Azeri, which pair dotted `i` with `İ` and dotless `ı` with `I`
+    Turkic,
+}
+
+fn in_ranges(ranges: &[(u32, u32)], c: char) -> bool {
+    let c = c as u32;
+    ranges
+        .binary_search_by(|&(lo, hi)| {
+            if hi < c {
+                Ordering::Less
+            } else if lo > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns `true` if `c` has the Unicode Cased property
+pub fn is_cased(c: char) -> bool {
+    in_ranges(&tables::CASED, c)
+}
+
+/// Returns `true` if `c` has the Unicode Case_Ignorable property
+pub fn is_case_ignorable(c: char) -> bool {
+    in_ranges(&tables::CASE_IGNORABLE, c)
+}
+
+/// Writes the case folding of `c` into `buf`, and returns the number of characters written
+fn fold_into(c: char, full: bool, buf: &mut [char; 3]) -> usize {
+    if full {
+        if let Ok(i) = tables::FULL_CASE_FOLDING.binary_search_by_key(&c, |&(k, _)| k) {
+            *buf = tables::FULL_CASE_FOLDING[i].1;
+            return buf.iter().take_while(|&&c| c != '\0').count();
+        }
+    }
+    buf[0] = match tables::SIMPLE_CASE_FOLDING.binary_search_by_key(&c, |&(k, _)| k) {
+        Ok(i) => tables::SIMPLE_CASE_FOLDING[i].1,
+        Err(_) => c,
+    };
+    1
+}
+
+/// Returns the simple case folding of `c`
+#[cfg(feature = "regex")]
+pub(crate) fn simple_fold(c: char) -> char {
+    let mut buf = ['\0'; 3];
+    fold_into(c, false, &mut buf);
+    buf[0]
+}
+
+/// Returns the lowercase and uppercase mappings of `c`, each of which is `None` if it is not a single character
+#[cfg(feature = "regex")]
+pub(crate) fn single_char_mappings(c: char) -> [Option<char>; 2] {
+    let single = |table: &[(char, [char; 3])]| match table.binary_search_by_key(&c, |&(k, _)| k) {
+        Ok(i) => Some(table[i].1[0]).filter(|_| table[i].1[1] == '\0'),
+        Err(_) => Some(c),
+    };
+    [single(&tables::LOWERCASE), single(&tables::UPPERCASE)]
+}
+
+/// Returns the characters whose simple case folding is another character, with their foldings, sorted by the former
+#[cfg(feature = "regex")]
+pub(crate) fn simple_foldings() -> &'static [(char, char)] {
+    &tables::SIMPLE_CASE_FOLDING
+}
+
+/// An iterator over the case folding of the characters of `I`
+#[derive(Clone)]
+pub struct CaseFold<I> {
+    iter: I,
+    full: bool,
+    buf: [char; 3],
+    pos: usize,
+    len: usize,
+}
+
+impl<I: Iterator<Item = char>> CaseFold<I> {
+    /// Returns an iterator over the full case folding of the characters of `iter`, which may map a character to several characters
+    pub fn full(iter: I) -> Self {
+        Self::new(iter, true)
+    }
+
+    /// Returns an iterator over the simple case folding of the characters of `iter`, which maps each character to exactly one character
+    pub fn simple(iter: I) -> Self {
+        Self::new(iter, false)
+    }
+
+    fn new(iter: I, full: bool) -> Self {
+        Self {
+            iter,
+            full,
+            buf: ['\0'; 3],
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for CaseFold<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.pos == self.len {
+            let c = self.iter.next()?;
+            self.len = fold_into(c, self.full, &mut self.buf);
+            self.pos = 0;
+        }
+        let c = self.buf[self.pos];
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.len - self.pos;
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper
+                .and_then(|upper| upper.checked_mul(3))
+                .and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the full case folding of the characters of `self`
+    pub fn folded_chars(&self) -> CaseFold<UnicodeIter<'_, Traits::Char, Traits>> {
+        CaseFold::full(self.unicode_iter())
+    }
+
+    /// Returns an iterator over the simple case folding of the characters of `self`
+    pub fn folded_chars_simple(&self) -> CaseFold<UnicodeIter<'_, Traits::Char, Traits>> {
+        CaseFold::simple(self.unicode_iter())
+    }
+
+    /// Returns `true` if `self` and `other` are equal after full case folding.
+    ///
+    /// `other` may be in a different encoding, and neither string is normalized before comparing.
+    pub fn eq_ignore_case<Other: IntoChars>(&self, other: &BasicStr<Other::Char, Other>) -> bool {
+        self.folded_chars().eq(other.folded_chars())
+    }
+
+    /// Compares `self` and `other` by the code points of their full case folding.
+    ///
+    /// `other` may be in a different encoding, and neither string is normalized before comparing.
+    pub fn cmp_ignore_case<Other: IntoChars>(
+        &self,
+        other: &BasicStr<Other::Char, Other>,
+    ) -> Ordering {
+        self.folded_chars().cmp(other.folded_chars())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns the full case folding of `self`, which is suitable for caseless comparison
+    pub fn fold_case(&self) -> BasicString<Traits::Char, Traits> {
+        let mut out = BasicString::with_capacity(self.len());
+        self.folded_chars().for_each(|c| out.push(c));
+        out
+    }
+
+    /// Returns the simple case folding of `self`, which has the same number of characters as `self`
+    pub fn fold_case_simple(&self) -> BasicString<Traits::Char, Traits> {
+        let mut out = BasicString::with_capacity(self.len());
+        self.folded_chars_simple().for_each(|c| out.push(c));
+        out
+    }
+}
+
+/// A [`Pattern`] that matches the needle `.0` caselessly, according to full case folding.
+///
+/// The needle may be in a different encoding from the string being searched.
+/// A match always begins and ends on character boundaries of the string being searched,
+///  so `"ss"` matches `"ß"`, but `"s"` does not.
+///
+/// Overlapping matches are found differently from the start and from the end,
+///  so splits and match iterators using it are not double-ended.
+#[cfg(feature = "pattern")]
+pub struct IgnoreCase<'a, CharT, Traits>(pub &'a BasicStr<CharT, Traits>);
+
+#[cfg(feature = "pattern")]
+impl<NTraits: IntoChars> IgnoreCase<'_, NTraits::Char, NTraits> {
+    /// Returns the length of the match of the needle at the start of `slice`, if any
+    ///
+    /// # Safety
+    /// `slice` shall be valid according to [`CharTraits::validate_range`](crate::traits::CharTraits::validate_range)
+    unsafe fn match_len<Traits: IntoChars>(&self, slice: &[Traits::Char]) -> Option<usize> {
+        let mut needle = self.0.folded_chars().peekable();
+        let mut rest = slice;
+        let mut buf = ['\0'; 3];
+        loop {
+            if needle.peek().is_none() {
+                return Some(slice.len() - rest.len());
+            }
+            if rest.is_empty() {
+                return None;
+            }
+            let (c, next) = Traits::decode_buf_unchecked(rest);
+            let len = fold_into(c, true, &mut buf);
+            for &c in &buf[..len] {
+                if needle.next() != Some(c) {
+                    return None;
+                }
+            }
+            rest = next;
+        }
+    }
+}
+
+#[cfg(feature = "pattern")]
+unsafe impl<Traits: IntoChars, NTraits: IntoChars> Pattern<Traits::Char, Traits>
+    for IgnoreCase<'_, NTraits::Char, NTraits>
+{
+    type Searcher<'a>
+        = StepSearcher<'a, Self, Traits::Char, Traits>
+    where
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<Traits::Char, Traits>) -> Self::Searcher<'a> {
+        StepSearcher::new(haystack, self)
+    }
+
+    fn first_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        Traits::validate_range(slice).ok()?;
+        // SAFETY:
+        // `slice` was just validated
+        unsafe { <Self as Pattern<Traits::Char, Traits>>::first_match_unchecked(self, slice) }
+    }
+
+    unsafe fn first_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        let mut rest = slice;
+        loop {
+            if let Some(len) = self.match_len::<Traits>(rest) {
+                return Some(&rest[..len]);
+            }
+            if rest.is_empty() {
+                return None;
+            }
+            rest = Traits::decode_buf_unchecked(rest).1;
+        }
+    }
+
+    unsafe fn prefix_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        let len = self.match_len::<Traits>(slice)?;
+        Some(&slice[..len])
+    }
+}
+
+#[cfg(feature = "pattern")]
+unsafe impl<Traits: DecodeRev, NTraits: IntoChars> RevPattern<Traits::Char, Traits>
+    for IgnoreCase<'_, NTraits::Char, NTraits>
+{
+    fn last_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        Traits::validate_range(slice).ok()?;
+        // SAFETY:
+        // `slice` was just validated
+        unsafe { <Self as RevPattern<Traits::Char, Traits>>::last_match_unchecked(self, slice) }
+    }
+
+    unsafe fn last_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        let mut begin = slice.len();
+        loop {
+            if let Some(len) = self.match_len::<Traits>(&slice[begin..]) {
+                return Some(&slice[begin..][..len]);
+            }
+            if begin == 0 {
+                return None;
+            }
+            begin = Traits::decode_back_unchecked(&slice[..begin]).1.len();
+        }
+    }
+}
+
+/// Calls `f` with each character of the mapping of `c` in `table`, or with `c` itself if it has none
+#[cfg(feature = "alloc")]
+fn for_each_mapped(table: &[(char, [char; 3])], c: char, mut f: impl FnMut(char)) {
+    match table.binary_search_by_key(&c, |&(k, _)| k) {
+        Ok(i) => table[i]
+            .1
+            .iter()
+            .copied()
+            .take_while(|&c| c != '\0')
+            .for_each(f),
+        Err(_) => f(c),
+    }
+}
+
+/// Calls `f` with each character of the full titlecase mapping of `c`
+#[cfg(feature = "alloc")]
+fn for_each_titlecase(c: char, f: impl FnMut(char)) {
+    if tables::TITLECASE
+        .binary_search_by_key(&c, |&(k, _)| k)
+        .is_ok()
+    {
+        for_each_mapped(&tables::TITLECASE, c, f)
+    } else {
+        for_each_mapped(&tables::UPPERCASE, c, f)
+    }
+}
+
+/// Returns `true` if the characters of `iter` begin with a cased character, after skipping case-ignorable characters
+#[cfg(feature = "alloc")]
+fn case_ignorable_then_cased(mut iter: impl Iterator<Item = char>) -> bool {
+    iter.find(|&c| !is_case_ignorable(c)).is_some_and(is_cased)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+enum Mapping {
+    Upper,
+    Lower,
+    Title,
+}
+
+#[cfg(feature = "alloc")]
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns the uppercase equivalent of `self`, according to the default case mapping rules
+    pub fn to_uppercase(&self) -> BasicString<Traits::Char, Traits> {
+        self.to_uppercase_in(CaseLocale::Root)
+    }
+
+    /// Returns the lowercase equivalent of `self`, according to the default case mapping rules
+    pub fn to_lowercase(&self) -> BasicString<Traits::Char, Traits> {
+        self.to_lowercase_in(CaseLocale::Root)
+    }
+
+    /// Returns the titlecase equivalent of `self`, according to the default case mapping rules.
+    ///
+    /// Each cased character that is not preceded by another cased character (ignoring case-ignorable characters, such as apostrophes)
+    ///  is mapped to titlecase, and all other characters are mapped to lowercase.
+    pub fn to_titlecase(&self) -> BasicString<Traits::Char, Traits> {
+        self.to_titlecase_in(CaseLocale::Root)
+    }
+
+    /// Returns the uppercase equivalent of `self`, according to the case mapping rules of `locale`
+    pub fn to_uppercase_in(&self, locale: CaseLocale) -> BasicString<Traits::Char, Traits> {
+        self.map_case(Mapping::Upper, locale)
+    }
+
+    /// Returns the lowercase equivalent of `self`, according to the case mapping rules of `locale`.
+    ///
+    /// A capital sigma is mapped to a final sigma at the end of a word.
+    pub fn to_lowercase_in(&self, locale: CaseLocale) -> BasicString<Traits::Char, Traits> {
+        self.map_case(Mapping::Lower, locale)
+    }
+
+    /// Returns the titlecase equivalent of `self`, according to the case mapping rules of `locale`.
+    ///
+    /// See [`BasicStr::to_titlecase`] for how words are found.
+    pub fn to_titlecase_in(&self, locale: CaseLocale) -> BasicString<Traits::Char, Traits> {
+        self.map_case(Mapping::Title, locale)
+    }
+
+    fn map_case(&self, mapping: Mapping, locale: CaseLocale) -> BasicString<Traits::Char, Traits> {
+        let turkic = locale == CaseLocale::Turkic;
+        let mut out = BasicString::with_capacity(self.len());
+
+        // Whether the last character that is not case-ignorable was cased
+        let mut after_cased = false;
+        let mut prev = None;
+        let mut iter = self.unicode_iter();
+        while let Some(c) = iter.next() {
+            let mapping = match mapping {
+                Mapping::Title if after_cased => Mapping::Lower,
+                mapping => mapping,
+            };
+
+            match (mapping, c) {
+                (Mapping::Upper | Mapping::Title, 'i') if turkic => out.push('\u{130}'),
+                (Mapping::Upper, c) => for_each_mapped(&tables::UPPERCASE, c, |c| out.push(c)),
+                (Mapping::Title, c) => for_each_titlecase(c, |c| out.push(c)),
+                (Mapping::Lower, '\u{3A3}') => {
+                    if after_cased && !case_ignorable_then_cased(iter.clone()) {
+                        out.push('\u{3C2}')
+                    } else {
+                        out.push('\u{3C3}')
+                    }
+                }
+                // The Turkic rules only consider an `I` immediately followed by U+0307 COMBINING DOT ABOVE,
+                //  rather than allowing intervening combining marks
+                (Mapping::Lower, '\u{130}') if turkic => out.push('i'),
+                (Mapping::Lower, '\u{307}') if turkic && prev == Some('I') => {}
+                (Mapping::Lower, 'I') if turkic => {
+                    if iter.clone().next() == Some('\u{307}') {
+                        out.push('i')
+                    } else {
+                        out.push('\u{131}')
+                    }
+                }
+                (Mapping::Lower, c) => for_each_mapped(&tables::LOWERCASE, c, |c| out.push(c)),
+            }
+
+            if !is_case_ignorable(c) {
+                after_cased = is_cased(c);
+            }
+            prev = Some(c);
+        }
+
+        out
+    }
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Converts each ASCII lowercase letter of `self` to uppercase in place
+    pub fn make_ascii_uppercase(&mut self) {
+        self.map_ascii_in_place(|c| c.to_ascii_uppercase())
+    }
+
+    /// Converts each ASCII uppercase letter of `self` to lowercase in place
+    pub fn make_ascii_lowercase(&mut self) {
+        self.map_ascii_in_place(|c| c.to_ascii_lowercase())
+    }
+
+    /// Replaces each character of `self` with `f(c)`, where that is encoded with the same length
+    fn map_ascii_in_place(&mut self, f: impl Fn(char) -> char) {
+        // SAFETY:
+        // Only whole characters are replaced, by encoded characters of the same length
+        let chars = unsafe { self.as_chars_mut() };
+
+        let mut pos = 0;
+        while pos < chars.len() {
+            // SAFETY:
+            // `pos` is on a character boundary of a valid string
+            let (c, rest) = unsafe { Traits::decode_buf_unchecked(&chars[pos..]) };
+            let len = chars.len() - pos - rest.len();
+
+            let mapped = f(c);
+            if mapped != c && Traits::encoding_len(mapped) == len {
+                Traits::encode(mapped, &mut chars[pos..][..len]);
+            }
+            pos += len;
+        }
+    }
+}
+
+#[cfg(all(test, feature = "utf", feature = "alloc"))]
+mod test {
+    use super::CaseLocale;
+    use crate::str::{Str, U16Str};
+
+    fn u16s(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_case_mapping() {
+        let units = u16s("straße ΟΔΟΣ ΟΔΟΣ. they're ǆemal");
+        let s = U16Str::from_chars(&units).unwrap();
+
+        assert_eq!(
+            s.to_uppercase().as_chars(),
+            &u16s("STRASSE ΟΔΟΣ ΟΔΟΣ. THEY'RE ǄEMAL")[..]
+        );
+        assert_eq!(
+            s.to_lowercase().as_chars(),
+            &u16s("straße οδος οδος. they're ǆemal")[..]
+        );
+        assert_eq!(
+            s.to_titlecase().as_chars(),
+            &u16s("Straße Οδος Οδος. They're ǅemal")[..]
+        );
+        assert_eq!(Str::from_str("ßa").to_titlecase().as_str(), "Ssa");
+    }
+
+    #[test]
+    fn test_pinned_version() {
+        // U+1C89 CYRILLIC CAPITAL LETTER TJE was added in Unicode 16.0.0
+        let s = Str::from_str("\u{1C89}\u{1C8A}");
+        assert_eq!(s.to_lowercase().as_str(), "\u{1C89}\u{1C8A}");
+        assert_eq!(s.to_uppercase().as_str(), "\u{1C89}\u{1C8A}");
+        assert_eq!(s.to_titlecase().as_str(), "\u{1C89}\u{1C8A}");
+    }
+
+    #[test]
+    fn test_turkic() {
+        let s = Str::from_str("İstanbul Irmak i\u{307}I\u{307}");
+        assert_eq!(
+            s.to_lowercase_in(CaseLocale::Turkic).as_str(),
+            "istanbul ırmak i\u{307}i"
+        );
+        assert_eq!(
+            s.to_lowercase().as_str(),
+            "i\u{307}stanbul irmak i\u{307}i\u{307}"
+        );
+        assert_eq!(
+            Str::from_str("istanbul")
+                .to_uppercase_in(CaseLocale::Turkic)
+                .as_str(),
+            "İSTANBUL"
+        );
+    }
+
+    #[test]
+    fn test_make_ascii() {
+        let mut units = u16s("Grüße, World");
+        let s = U16Str::from_chars_mut(&mut units).unwrap();
+        s.make_ascii_uppercase();
+        assert_eq!(s.as_chars(), &u16s("GRüßE, WORLD")[..]);
+        s.make_ascii_lowercase();
+        assert_eq!(s.as_chars(), &u16s("grüße, world")[..]);
+    }
+
+    #[test]
+    fn test_fold_case() {
+        let units = u16s("STRASSE \u{1E9E}");
+        let s = U16Str::from_chars(&units).unwrap();
+
+        assert_eq!(s.fold_case().as_chars(), &u16s("strasse ss")[..]);
+        assert_eq!(s.fold_case_simple().as_chars(), &u16s("strasse \u{df}")[..]);
+
+        assert!(Str::from_str("Stra\u{df}e ss").eq_ignore_case(s));
+        assert!(!Str::from_str("Strase ss").eq_ignore_case(s));
+        assert_eq!(
+            Str::from_str("strasse").cmp_ignore_case(s),
+            core::cmp::Ordering::Less
+        );
+        assert_eq!(
+            Str::from_str("STRASSE T").cmp_ignore_case(s),
+            core::cmp::Ordering::Greater
+        );
+    }
+
+    #[cfg(feature = "pattern")]
+    #[test]
+    fn test_ignore_case_pattern() {
+        use super::IgnoreCase;
+
+        let needle = Str::from_str("SS");
+        let units = u16s("Ma\u{df}e, MASS, mass");
+        let s = U16Str::from_chars(&units).unwrap();
+
+        assert_eq!(s.find(IgnoreCase(needle)), Some(2));
+        assert_eq!(s.rfind(IgnoreCase(needle)), Some(14));
+        assert_eq!(s.split(IgnoreCase(needle)).count(), 4);
+        assert_eq!(s.find(IgnoreCase(Str::from_str("s"))), Some(8));
+        assert!(s.starts_with(IgnoreCase(Str::from_str("MASSE"))));
+        assert!(!s.starts_with(IgnoreCase(Str::from_str("MAS"))));
+
+        let s = Str::from_str("aAa");
+        let needle = Str::from_str("aa");
+        let starts =
+            |it: &mut dyn Iterator<Item = (usize, &Str)>| it.map(|(i, _)| i).collect::<Vec<_>>();
+        assert_eq!(starts(&mut s.match_indices(IgnoreCase(needle))), [0]);
+        assert_eq!(starts(&mut s.rmatch_indices(IgnoreCase(needle))), [1]);
+        let pieces: Vec<_> = s.rsplit(IgnoreCase(needle)).map(Str::as_str).collect();
+        assert_eq!(pieces, ["", "a"]);
+    }
+}
@@ -0,0 +1,181 @@
+//! Random access to the characters of a [`BasicStr`] by code point index
+//!
+//! A [`CharIndex`] records the offset of every `stride`th character of a string,
+//!  so that finding the `n`th character only needs to step over fewer than `stride` characters from the nearest record.
+
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+use crate::str::BasicStr;
+use crate::traits::IntoChars;
+
+/// The stride used by [`CharIndex::new`]
+pub const DEFAULT_STRIDE: usize = 64;
+
+/// An index of the code points of a [`BasicStr`], for accessing them by position in `O(stride)`
+pub struct CharIndex<'a, CharT, Traits> {
+    s: &'a BasicStr<CharT, Traits>,
+    stride: usize,
+    /// The offset of the `i * stride`th character
+    breadcrumbs: Vec<usize>,
+    char_len: usize,
+}
+
+impl<CharT, Traits> Clone for CharIndex<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self {
+            s: self.s,
+            stride: self.stride,
+            breadcrumbs: self.breadcrumbs.clone(),
+            char_len: self.char_len,
+        }
+    }
+}
+
+impl<'a, Traits: IntoChars> CharIndex<'a, Traits::Char, Traits> {
+    /// Builds the index of `s`, with a breadcrumb every [`DEFAULT_STRIDE`] characters
+    pub fn new(s: &'a BasicStr<Traits::Char, Traits>) -> Self {
+        Self::with_stride(s, DEFAULT_STRIDE)
+    }
+
+    /// Builds the index of `s`, with a breadcrumb every `stride` characters.
+    ///
+    /// A smaller stride makes accesses faster, at the cost of one `usize` per `stride` characters.
+    ///
+    /// # Panics
+    /// Panics if `stride` is zero
+    pub fn with_stride(s: &'a BasicStr<Traits::Char, Traits>, stride: usize) -> Self {
+        assert!(stride != 0, "stride must be nonzero");
+        let chars = s.as_chars();
+        let mut breadcrumbs = Vec::with_capacity(chars.len() / stride + 1);
+        let mut offset = 0;
+        let mut char_len = 0;
+        while offset < chars.len() {
+            if char_len % stride == 0 {
+                breadcrumbs.push(offset);
+            }
+            offset += Traits::first_char_len(&chars[offset..]);
+            char_len += 1;
+        }
+        Self {
+            s,
+            stride,
+            breadcrumbs,
+            char_len,
+        }
+    }
+
+    /// Returns the indexed string
+    pub fn as_basic_str(&self) -> &'a BasicStr<Traits::Char, Traits> {
+        self.s
+    }
+
+    /// Returns the number of characters in the string
+    pub fn char_len(&self) -> usize {
+        self.char_len
+    }
+
+    /// Returns the offset of the `n`th character, or the length of the string if `n` is the number of characters.
+    ///
+    /// Returns `None` if `n` is greater than the number of characters.
+    pub fn char_offset(&self, n: usize) -> Option<usize> {
+        if n >= self.char_len {
+            return (n == self.char_len).then_some(self.s.len());
+        }
+        let chars = self.s.as_chars();
+        let mut offset = self.breadcrumbs[n / self.stride];
+        for _ in 0..n % self.stride {
+            offset += Traits::first_char_len(&chars[offset..]);
+        }
+        Some(offset)
+    }
+
+    /// Returns the `n`th character, or `None` if there are not more than `n` characters
+    pub fn nth_char(&self, n: usize) -> Option<char> {
+        if n >= self.char_len {
+            return None;
+        }
+        let offset = self.char_offset(n)?;
+        // SAFETY:
+        // `offset` is the start of a character of `s`
+        let (c, _) = unsafe { Traits::decode_buf_unchecked(&self.s.as_chars()[offset..]) };
+        Some(c)
+    }
+
+    /// Slices the string by a range of character positions.
+    ///
+    /// Returns `None` if the range is decreasing, or if either end is greater than the number of characters.
+    pub fn char_slice<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> Option<&'a BasicStr<Traits::Char, Traits>> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.char_len,
+        };
+        if start > end {
+            return None;
+        }
+        let start = self.char_offset(start)?;
+        let end = self.char_offset(end)?;
+
+        // SAFETY:
+        // Both ends are the offsets of characters of `s`, or its end
+        Some(unsafe { BasicStr::from_chars_unchecked(&self.s.as_chars()[start..end]) })
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::CharIndex;
+    use crate::str::{Str, U16Str};
+
+    const TEXT: &str = "a\u{e9}\u{65e5}\u{1f600}b\u{10ffff}\u{7ff}cd\u{800}";
+
+    #[test]
+    fn test_char_index() {
+        let chars: Vec<char> = TEXT.chars().collect();
+        for stride in 1..=chars.len() + 1 {
+            let index = CharIndex::with_stride(Str::from_str(TEXT), stride);
+            assert_eq!(index.char_len(), chars.len());
+            for (n, (offset, c)) in TEXT.char_indices().enumerate() {
+                assert_eq!(index.nth_char(n), Some(c));
+                assert_eq!(index.char_offset(n), Some(offset));
+            }
+            assert_eq!(index.nth_char(chars.len()), None);
+            assert_eq!(index.char_offset(chars.len()), Some(TEXT.len()));
+            assert_eq!(index.char_offset(chars.len() + 1), None);
+
+            for a in 0..=chars.len() {
+                for b in a..=chars.len() {
+                    let expected: String = chars[a..b].iter().collect();
+                    assert_eq!(index.char_slice(a..b).unwrap().as_str(), expected);
+                }
+            }
+        }
+
+        let index = CharIndex::new(Str::from_str(TEXT));
+        assert_eq!(index.char_slice(3..=4).unwrap().as_str(), "\u{1f600}b");
+        assert_eq!(index.char_slice(8..).unwrap().as_str(), "d\u{800}");
+        assert!(index.char_slice(..11).is_none());
+
+        let units: Vec<u16> = TEXT.encode_utf16().collect();
+        let index = CharIndex::with_stride(U16Str::from_chars(&units).unwrap(), 4);
+        assert_eq!(index.char_len(), chars.len());
+        for (n, &c) in chars.iter().enumerate() {
+            assert_eq!(index.nth_char(n), Some(c));
+        }
+        assert_eq!(index.char_slice(3..6).unwrap().as_chars(), &units[3..8]);
+
+        let empty = CharIndex::new(Str::from_str(""));
+        assert_eq!(empty.char_len(), 0);
+        assert_eq!(empty.nth_char(0), None);
+        assert_eq!(empty.char_slice(..).unwrap().as_str(), "");
+    }
+}
@@ -0,0 +1,257 @@
+//! Comparison and hashing of strings by code point, across encodings
+//!
+//! Two strings in different encodings are equal if they decode to the same sequence of code points,
+//!  and are ordered by comparing those sequences lexicographically.
+//! None of the functions in this module allocate.
+//!
+//! [`BasicStr`] and [`BasicString`](crate::string::BasicString) hash their code units,
+//!  so a map keyed by them can only be looked up with a key in the same encoding.
+//! To look up a map with keys in any encoding, wrap its keys in [`ByCodePoints`], and look them up by `&dyn CodePoints`.
+//! As [`BasicStr`] is unsized, a `&dyn CodePoints` is made from a reference to a `&BasicStr`.
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::str::BasicStr;
+use crate::traits::IntoChars;
+
+#[cfg(feature = "utf")]
+use crate::str::{Str, U16Str, U32Str};
+
+/// Returns `true` if `a` and `b` decode to the same code points
+pub fn eq_code_points<A: IntoChars, B: IntoChars>(
+    a: &BasicStr<A::Char, A>,
+    b: &BasicStr<B::Char, B>,
+) -> bool {
+    a.unicode_iter().eq(b.unicode_iter())
+}
+
+/// Compares the code points of `a` and `b` lexicographically
+pub fn cmp_code_points<A: IntoChars, B: IntoChars>(
+    a: &BasicStr<A::Char, A>,
+    b: &BasicStr<B::Char, B>,
+) -> Ordering {
+    a.unicode_iter().cmp(b.unicode_iter())
+}
+
+fn hash_chars<H: Hasher>(chars: impl Iterator<Item = char>, state: &mut H) {
+    for c in chars {
+        state.write_u32(c as u32);
+    }
+    // Like `str`, terminate the string so that concatenations of different strings hash differently
+    state.write_u8(0xff);
+}
+
+/// Feeds the code points of `s` into `state`.
+///
+/// Strings that decode to the same code points hash the same, regardless of their encoding.
+pub fn hash_code_points<Traits: IntoChars, H: Hasher>(
+    s: &BasicStr<Traits::Char, Traits>,
+    state: &mut H,
+) {
+    hash_chars(s.unicode_iter(), state)
+}
+
+/// Object-safe access to the code points of a string in any encoding.
+///
+/// `dyn CodePoints` implements [`Eq`], [`Ord`] and [`Hash`] by code point.
+pub trait CodePoints {
+    /// Decodes the character that begins at `offset`, and returns it with the offset of the following character.
+    ///
+    /// Returns `None` at the end of the string.
+    /// Offsets are meaningful only to the implementation, and start at `0`.
+    fn decode_at(&self, offset: usize) -> Option<(char, usize)>;
+}
+
+impl<Traits: IntoChars> CodePoints for BasicStr<Traits::Char, Traits> {
+    fn decode_at(&self, offset: usize) -> Option<(char, usize)> {
+        let buf = self.as_chars().get(offset..)?;
+        let (c, rest) = Traits::decode_buf(buf)?;
+        Some((c, offset + buf.len() - rest.len()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Traits: IntoChars> CodePoints for crate::string::BasicString<Traits::Char, Traits> {
+    fn decode_at(&self, offset: usize) -> Option<(char, usize)> {
+        (**self).decode_at(offset)
+    }
+}
+
+impl<T: CodePoints + ?Sized> CodePoints for &T {
+    fn decode_at(&self, offset: usize) -> Option<(char, usize)> {
+        (**self).decode_at(offset)
+    }
+}
+
+fn chars<T: CodePoints + ?Sized>(s: &T) -> impl Iterator<Item = char> + '_ {
+    let mut offset = 0;
+    core::iter::from_fn(move || {
+        let (c, next) = s.decode_at(offset)?;
+        offset = next;
+        Some(c)
+    })
+}
+
+impl PartialEq for dyn CodePoints + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        chars(self).eq(chars(other))
+    }
+}
+
+impl Eq for dyn CodePoints + '_ {}
+
+impl PartialOrd for dyn CodePoints + '_ {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for dyn CodePoints + '_ {
+    fn cmp(&self, other: &Self) -> Ordering {
+        chars(self).cmp(chars(other))
+    }
+}
+
+impl Hash for dyn CodePoints + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_chars(chars(self), state)
+    }
+}
+
+/// A wrapper that compares and hashes a string by its code points.
+///
+/// A map keyed by `ByCodePoints<S>` can be looked up with a `&dyn CodePoints` in any encoding,
+///  such as `map.get(&Str::from_str("key") as &dyn CodePoints)` for a map keyed by `ByCodePoints<U16String>`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ByCodePoints<S>(pub S);
+
+impl<S: CodePoints> PartialEq for ByCodePoints<S> {
+    fn eq(&self, other: &Self) -> bool {
+        chars(&self.0).eq(chars(&other.0))
+    }
+}
+
+impl<S: CodePoints> Eq for ByCodePoints<S> {}
+
+impl<S: CodePoints> PartialOrd for ByCodePoints<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: CodePoints> Ord for ByCodePoints<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        chars(&self.0).cmp(chars(&other.0))
+    }
+}
+
+impl<S: CodePoints> Hash for ByCodePoints<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_chars(chars(&self.0), state)
+    }
+}
+
+impl<'a, S: CodePoints + 'a> Borrow<dyn CodePoints + 'a> for ByCodePoints<S> {
+    fn borrow(&self) -> &(dyn CodePoints + 'a) {
+        &self.0
+    }
+}
+
+#[cfg(feature = "utf")]
+macro_rules! cross_encoding_cmp {
+    ($($a:ty => $b:ty),* $(,)?) => {
+        $(
+            impl PartialEq<$b> for $a {
+                fn eq(&self, other: &$b) -> bool {
+                    eq_code_points(self, other)
+                }
+            }
+
+            impl PartialOrd<$b> for $a {
+                fn partial_cmp(&self, other: &$b) -> Option<Ordering> {
+                    Some(cmp_code_points(self, other))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "utf")]
+cross_encoding_cmp!(
+    Str => U16Str,
+    Str => U32Str,
+    U16Str => Str,
+    U16Str => U32Str,
+    U32Str => Str,
+    U32Str => U16Str,
+);
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::{cmp_code_points, eq_code_points, hash_code_points, ByCodePoints, CodePoints};
+    use crate::str::{Str, U16Str, U32Str};
+    use core::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut state = DefaultHasher::new();
+        value.hash(&mut state);
+        state.finish()
+    }
+
+    #[test]
+    fn test_cross_encoding_cmp() {
+        let text = "a\u{ff61}\u{10000}";
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let chars: Vec<char> = text.chars().collect();
+        let s = Str::from_str(text);
+        let u16s = U16Str::from_chars(&units).unwrap();
+        let u32s: &U32Str = chars.as_slice().as_ref();
+
+        assert!(eq_code_points(s, u16s));
+        assert!(*s == *u16s && *u16s == *u32s && *u32s == *s);
+        assert!(*s != *U16Str::from_chars(&units[..2]).unwrap());
+
+        // UTF-16 code units sort surrogates before U+E000..=U+FFFF, but code points sort them after
+        assert!(units[1] > units[2]);
+        let prefix = U16Str::from_chars(&units[..2]).unwrap();
+        assert_eq!(cmp_code_points(prefix, s), Ordering::Less);
+        assert!(*u16s > *Str::from_str("a\u{ff61}"));
+        assert!(*u32s < *Str::from_str("b"));
+        assert_eq!(s.partial_cmp(u16s), Some(Ordering::Equal));
+
+        let mut state = DefaultHasher::new();
+        hash_code_points(s, &mut state);
+        let mut other = DefaultHasher::new();
+        hash_code_points(u16s, &mut other);
+        assert_eq!(state.finish(), other.finish());
+        assert_eq!(hash(&ByCodePoints(u32s)), hash(&u16s as &dyn CodePoints));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_by_code_points() {
+        use std::collections::{BTreeMap, HashMap};
+
+        const KEYS: [&str; 3] = ["alpha", "\u{3b2}\u{1f600}", ""];
+        let mut map = HashMap::new();
+        let mut tree = BTreeMap::new();
+        for (i, key) in KEYS.into_iter().enumerate() {
+            let units: Vec<u16> = key.encode_utf16().collect();
+            let key = U16Str::from_chars(&units).unwrap();
+            map.insert(ByCodePoints(key.to_owned()), i);
+            tree.insert(ByCodePoints(key.to_owned()), i);
+        }
+
+        for (i, key) in KEYS.into_iter().enumerate() {
+            let key = &Str::from_str(key) as &dyn CodePoints;
+            assert_eq!(map.get(key), Some(&i));
+            assert_eq!(tree.get(key), Some(&i));
+        }
+        assert_eq!(map.get(&Str::from_str("alph") as &dyn CodePoints), None);
+        assert_eq!(tree.get(&Str::from_str("\u{3b2}") as &dyn CodePoints), None);
+    }
+}
@@ -68,6 +68,11 @@ impl<CharT, Traits> BasicCStr<CharT, Traits> {
         self.1.len()
     }
 
+    /// Returns `true` if `self` contains no characters before the null terminator
+    pub const fn is_empty(&self) -> bool {
+        self.1.len() <= 1
+    }
+
     ///
     /// Converts the `CStr` into a `Str` that includes the zero terminator.
     /// This may
@@ -300,6 +305,10 @@ impl CharTraits for RawCharTraits {
     fn eof() -> Self::Int {
         -1
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        idx <= buf.len()
+    }
 }
 
 #[cfg(feature = "const-raw-char-traits")]
@@ -44,4 +44,8 @@ impl const CharTraits for RawCharTraits {
     fn eof() -> Self::Int {
         -1
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        idx <= buf.len()
+    }
 }
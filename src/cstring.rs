@@ -1,4 +1,5 @@
 use core::marker::PhantomData;
+use core::ops::Deref;
 
 #[cfg(feature = "allocator-api")]
 use alloc::alloc::{Allocator, Global};
@@ -9,7 +10,7 @@ use crate::placeholders::*;
 use crate::cstr::BasicCStr;
 use crate::traits::CharTraits;
 
-use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
 
 #[cfg(feature = "allocator-api")]
@@ -28,11 +29,8 @@ pub struct BasicCString<CharT, Traits, A: Allocator = Global> {
 
 impl<Traits: CharTraits> BasicCString<Traits::Char, Traits, Global> {
     pub fn new() -> Self {
-        let mut arr = Vec::with_capacity(1);
-        arr.push(Traits::zero_term());
-
         Self {
-            inner: arr,
+            inner: vec![Traits::zero_term()],
             _traits: PhantomData,
             _allocator: PhantomData,
         }
@@ -55,6 +53,12 @@ impl<Traits: CharTraits> BasicCString<Traits::Char, Traits, Global> {
     }
 }
 
+impl<Traits: CharTraits> Default for BasicCString<Traits::Char, Traits, Global> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<Traits: CharTraits, A: Allocator> BasicCString<Traits::Char, Traits, A> {
     #[cfg(feature = "allocator-api")]
     pub fn new_in(alloc: A) -> Self {
@@ -85,3 +89,13 @@ impl<Traits: CharTraits, A: Allocator> BasicCString<Traits::Char, Traits, A> {
         }
     }
 }
+
+impl<CharT, Traits, A: Allocator> Deref for BasicCString<CharT, Traits, A> {
+    type Target = BasicCStr<CharT, Traits>;
+
+    fn deref(&self) -> &BasicCStr<CharT, Traits> {
+        // SAFETY:
+        // `inner` always ends with exactly one zero terminator, and is valid according to `Traits`
+        unsafe { BasicCStr::from_chars_with_null_unchecked(&self.inner) }
+    }
+}
@@ -0,0 +1,197 @@
+//! A cursor for moving through a [`BasicStr`] a character or grapheme cluster at a time
+
+use crate::str::BasicStr;
+use crate::traits::{CharTraits, DecodeRev, IntoChars};
+
+/// A position at a character boundary of a [`BasicStr`], which can move forwards and backwards.
+///
+/// The cursor never moves past either end of the string, and each method that moves it returns what it moved over.
+pub struct Cursor<'a, Traits: CharTraits> {
+    s: &'a BasicStr<Traits::Char, Traits>,
+    offset: usize,
+}
+
+impl<Traits: CharTraits> Clone for Cursor<'_, Traits> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Traits: CharTraits> Copy for Cursor<'_, Traits> {}
+
+impl<'a, Traits: IntoChars> Cursor<'a, Traits> {
+    /// Creates a cursor at the start of `s`
+    pub fn new(s: &'a BasicStr<Traits::Char, Traits>) -> Self {
+        Self { s, offset: 0 }
+    }
+
+    /// Creates a cursor at `offset` in `s`, or returns `None` if `offset` is not a character boundary
+    pub fn at(s: &'a BasicStr<Traits::Char, Traits>, offset: usize) -> Option<Self> {
+        s.is_char_boundary(offset).then_some(Self { s, offset })
+    }
+
+    /// Returns the whole string the cursor moves through
+    pub fn as_basic_str(&self) -> &'a BasicStr<Traits::Char, Traits> {
+        self.s
+    }
+
+    /// Returns the offset of the cursor in the string
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Moves the cursor to `offset`, and returns whether it is a character boundary.
+    ///
+    /// The cursor is left unchanged if it is not.
+    pub fn seek(&mut self, offset: usize) -> bool {
+        let valid = self.s.is_char_boundary(offset);
+        if valid {
+            self.offset = offset;
+        }
+        valid
+    }
+
+    /// Returns the part of the string after the cursor
+    pub fn rest(&self) -> &'a BasicStr<Traits::Char, Traits> {
+        // SAFETY:
+        // The cursor is always at a character boundary
+        unsafe { BasicStr::from_chars_unchecked(&self.s.as_chars()[self.offset..]) }
+    }
+
+    /// Returns the part of the string before the cursor
+    pub fn before(&self) -> &'a BasicStr<Traits::Char, Traits> {
+        // SAFETY:
+        // The cursor is always at a character boundary
+        unsafe { BasicStr::from_chars_unchecked(&self.s.as_chars()[..self.offset]) }
+    }
+
+    /// Returns the part of the string between `start` and the cursor, such as the text of a token that began at `start`.
+    ///
+    /// Returns `None` if `start` is after the cursor or is not a character boundary.
+    pub fn since(&self, start: usize) -> Option<&'a BasicStr<Traits::Char, Traits>> {
+        self.s.slice_checked(start..self.offset)
+    }
+
+    /// Returns `true` if the cursor is at the end of the string
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.s.len()
+    }
+
+    /// Returns the character after the cursor, without moving it
+    pub fn peek(&self) -> Option<char> {
+        self.rest().unicode_iter().next()
+    }
+
+    /// Moves the cursor over the next character, and returns it
+    pub fn next_char(&mut self) -> Option<char> {
+        let rest = self.rest().as_chars();
+        if rest.is_empty() {
+            return None;
+        }
+        // SAFETY:
+        // `rest` is nonempty and begins at a character boundary
+        let (c, after) = unsafe { Traits::decode_buf_unchecked(rest) };
+        self.offset += rest.len() - after.len();
+        Some(c)
+    }
+
+    /// Moves the cursor over the next character if `pred` returns `true` for it, and returns it
+    pub fn next_char_if(&mut self, pred: impl FnOnce(char) -> bool) -> Option<char> {
+        let c = self.peek().filter(|&c| pred(c))?;
+        self.offset += Traits::encoding_len(c);
+        Some(c)
+    }
+
+    /// Moves the cursor over the next extended grapheme cluster, and returns it
+    pub fn next_grapheme(&mut self) -> Option<&'a BasicStr<Traits::Char, Traits>> {
+        let cluster = self.rest().graphemes().next()?;
+        self.offset += cluster.len();
+        Some(cluster)
+    }
+}
+
+impl<'a, Traits: DecodeRev> Cursor<'a, Traits> {
+    /// Returns the character before the cursor, without moving it
+    pub fn peek_back(&self) -> Option<char> {
+        self.before().unicode_iter().next_back()
+    }
+
+    /// Moves the cursor back over the previous character, and returns it
+    pub fn prev_char(&mut self) -> Option<char> {
+        let before = self.before().as_chars();
+        if before.is_empty() {
+            return None;
+        }
+        // SAFETY:
+        // `before` is nonempty and ends at a character boundary
+        let (c, rest) = unsafe { Traits::decode_back_unchecked(before) };
+        self.offset = rest.len();
+        Some(c)
+    }
+
+    /// Moves the cursor back over the previous extended grapheme cluster, and returns it
+    pub fn prev_grapheme(&mut self) -> Option<&'a BasicStr<Traits::Char, Traits>> {
+        let cluster = self.before().graphemes().next_back()?;
+        self.offset -= cluster.len();
+        Some(cluster)
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::Cursor;
+    use crate::str::{Str, U16Str};
+
+    #[test]
+    fn test_cursor() {
+        let s = Str::from_str("x1 = \"e\u{301}\u{1f600}\"");
+        let mut cursor = Cursor::new(s);
+        assert_eq!(cursor.peek(), Some('x'));
+        assert_eq!(cursor.peek_back(), None);
+        assert_eq!(cursor.prev_char(), None);
+
+        let start = cursor.offset();
+        while cursor.next_char_if(char::is_alphanumeric).is_some() {}
+        assert_eq!(cursor.since(start).unwrap().as_str(), "x1");
+        assert_eq!(cursor.next_char(), Some(' '));
+        assert_eq!(cursor.next_char(), Some('='));
+        assert_eq!(cursor.next_char_if(|c| c == '='), None);
+        assert_eq!(cursor.rest().as_str(), " \"e\u{301}\u{1f600}\"");
+        cursor.next_char();
+        cursor.next_char();
+
+        assert_eq!(cursor.next_grapheme().unwrap().as_str(), "e\u{301}");
+        assert_eq!(cursor.next_char(), Some('\u{1f600}'));
+        assert_eq!(cursor.prev_char(), Some('\u{1f600}'));
+        assert_eq!(cursor.prev_grapheme().unwrap().as_str(), "e\u{301}");
+        assert_eq!(cursor.before().as_str(), "x1 = \"");
+        assert_eq!(cursor.since(cursor.offset() + 1), None);
+
+        assert!(!cursor.seek(8));
+        assert_eq!(cursor.offset(), 6);
+        assert!(cursor.seek(s.len()));
+        assert!(cursor.is_at_end());
+        assert_eq!(cursor.next_char(), None);
+        assert_eq!(cursor.next_grapheme(), None);
+        assert_eq!(cursor.peek_back(), Some('"'));
+
+        assert!(Cursor::at(s, 8).is_none());
+        assert_eq!(Cursor::at(s, 7).unwrap().peek(), Some('\u{301}'));
+    }
+
+    #[test]
+    fn test_cursor_utf16() {
+        let units: Vec<u16> = "a\u{1f600}b".encode_utf16().collect();
+        let s = U16Str::from_chars(&units).unwrap();
+        let mut cursor = Cursor::new(s);
+        assert_eq!(cursor.next_char(), Some('a'));
+        assert_eq!(cursor.next_char(), Some('\u{1f600}'));
+        assert_eq!(cursor.offset(), 3);
+        assert!(!cursor.seek(2));
+        assert_eq!(cursor.prev_char(), Some('\u{1f600}'));
+        assert_eq!(cursor.offset(), 1);
+        assert_eq!(cursor.prev_char(), Some('a'));
+        assert_eq!(cursor.next_grapheme().unwrap().as_chars(), &units[..1]);
+        assert!(Cursor::at(s, 2).is_none());
+    }
+}
@@ -0,0 +1,1094 @@
+//! Legacy and byte-oriented encodings, selectable at runtime by their [WHATWG Encoding Standard](https://encoding.spec.whatwg.org/) labels.
+//!
+//! Each encoding is represented by a [`CharTraits`] type over `u8`, which implements [`Decode`] and [`Encode`].
+//! [`Encoding`] maps labels to those types, and dispatches to them dynamically.
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+
+use crate::str::{BasicStr, Str};
+use crate::string::{BasicString, String};
+use crate::traits::{CharTraits, IntoChars, ValidationError};
+use crate::transcode::{Decode, DecodeResult, Encode, EncodeResult};
+use crate::utf::UtfCharTraits;
+
+#[rustfmt::skip]
+mod tables;
+
+/// The error returned when validating a range of bytes in one of the encodings of this module
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecodeError {
+    pos: usize,
+    len: Option<usize>,
+}
+
+impl ValidationError for DecodeError {
+    fn first_error_pos(&self) -> usize {
+        self.pos
+    }
+
+    fn first_error_len(&self) -> Option<usize> {
+        self.len
+    }
+}
+
+/// Validates `buf` by decoding it with `Traits::decode_next`
+fn validate_by_decoding<Traits: Decode>(buf: &[Traits::Char]) -> Result<(), DecodeError> {
+    let mut pos = 0;
+    while pos < buf.len() {
+        match Traits::decode_next(&buf[pos..]) {
+            DecodeResult::Char(_, len) => pos += len,
+            DecodeResult::Incomplete => return Err(DecodeError { pos, len: None }),
+            DecodeResult::Malformed(len) => {
+                return Err(DecodeError {
+                    pos,
+                    len: Some(len),
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Implements [`CharTraits`] for a byte encoding that implements [`Decode`].
+///
+/// Every index is a character boundary unless an `is_char_boundary` implementation is given.
+macro_rules! byte_char_traits {
+    ($name:ident) => {
+        byte_char_traits!($name, |buf, idx| idx <= buf.len());
+    };
+    ($name:ident, |$buf:ident, $idx:ident| $is_char_boundary:expr) => {
+        impl CharTraits for $name {
+            type Char = u8;
+            type Int = i32;
+            type Error = DecodeError;
+
+            fn validate_range(buf: &[u8]) -> Result<(), DecodeError> {
+                validate_by_decoding::<Self>(buf)
+            }
+
+            unsafe fn validate_subrange(buf: &[u8]) -> Result<(), DecodeError> {
+                Self::validate_range(buf)
+            }
+
+            fn compare(r1: &[u8], r2: &[u8]) -> Result<Ordering, DecodeError> {
+                Ok(r1.cmp(r2))
+            }
+
+            fn zero_term() -> u8 {
+                0
+            }
+
+            fn is_zero_term(c: u8) -> bool {
+                c == 0
+            }
+
+            fn eof() -> i32 {
+                -1
+            }
+
+            fn is_char_boundary($buf: &[u8], $idx: usize) -> bool {
+                $is_char_boundary
+            }
+        }
+    };
+}
+
+macro_rules! single_byte_char_traits {
+    ($($(#[$meta:meta])* $name:ident => $table:ident),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            pub struct $name;
+
+            byte_char_traits!($name);
+
+            impl Decode for $name {
+                fn decode_next(buf: &[u8]) -> DecodeResult {
+                    let b = buf[0];
+                    if b < 0x80 {
+                        DecodeResult::Char(b as char, 1)
+                    } else {
+                        match char::from_u32(tables::$table[(b - 0x80) as usize] as u32) {
+                            Some('\0') | None => DecodeResult::Malformed(1),
+                            Some(c) => DecodeResult::Char(c, 1),
+                        }
+                    }
+                }
+            }
+
+            impl Encode for $name {
+                fn encode_next(c: char, buf: &mut [u8]) -> EncodeResult {
+                    let b = if (c as u32) < 0x80 {
+                        c as u8
+                    } else {
+                        match tables::$table.iter().position(|&cp| cp as u32 == c as u32) {
+                            Some(pos) => pos as u8 + 0x80,
+                            None => return EncodeResult::Unmappable,
+                        }
+                    };
+
+                    match buf.first_mut() {
+                        Some(out) => {
+                            *out = b;
+                            EncodeResult::Encoded(1)
+                        }
+                        None => EncodeResult::OutputFull,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+single_byte_char_traits! {
+    /// [`CharTraits`] for the IBM866 encoding
+    Ibm866CharTraits => IBM866,
+    /// [`CharTraits`] for the ISO-8859-2 encoding
+    Iso8859_2CharTraits => ISO_8859_2,
+    /// [`CharTraits`] for the ISO-8859-3 encoding
+    Iso8859_3CharTraits => ISO_8859_3,
+    /// [`CharTraits`] for the ISO-8859-4 encoding
+    Iso8859_4CharTraits => ISO_8859_4,
+    /// [`CharTraits`] for the ISO-8859-5 encoding
+    Iso8859_5CharTraits => ISO_8859_5,
+    /// [`CharTraits`] for the ISO-8859-6 encoding
+    Iso8859_6CharTraits => ISO_8859_6,
+    /// [`CharTraits`] for the ISO-8859-7 encoding
+    Iso8859_7CharTraits => ISO_8859_7,
+    /// [`CharTraits`] for the ISO-8859-8 and ISO-8859-8-I encodings
+    Iso8859_8CharTraits => ISO_8859_8,
+    /// [`CharTraits`] for the ISO-8859-10 encoding
+    Iso8859_10CharTraits => ISO_8859_10,
+    /// [`CharTraits`] for the ISO-8859-13 encoding
+    Iso8859_13CharTraits => ISO_8859_13,
+    /// [`CharTraits`] for the ISO-8859-14 encoding
+    Iso8859_14CharTraits => ISO_8859_14,
+    /// [`CharTraits`] for the ISO-8859-15 encoding
+    Iso8859_15CharTraits => ISO_8859_15,
+    /// [`CharTraits`] for the ISO-8859-16 encoding
+    Iso8859_16CharTraits => ISO_8859_16,
+    /// [`CharTraits`] for the KOI8-R encoding
+    Koi8RCharTraits => KOI8_R,
+    /// [`CharTraits`] for the KOI8-U encoding
+    Koi8UCharTraits => KOI8_U,
+    /// [`CharTraits`] for the macintosh encoding
+    MacintoshCharTraits => MACINTOSH,
+    /// [`CharTraits`] for the windows-874 encoding
+    Windows874CharTraits => WINDOWS_874,
+    /// [`CharTraits`] for the windows-1250 encoding
+    Windows1250CharTraits => WINDOWS_1250,
+    /// [`CharTraits`] for the windows-1251 encoding
+    Windows1251CharTraits => WINDOWS_1251,
+    /// [`CharTraits`] for the windows-1252 encoding, which the WHATWG Encoding Standard also uses for the `latin1`, `iso-8859-1` and `ascii` labels
+    Windows1252CharTraits => WINDOWS_1252,
+    /// [`CharTraits`] for the windows-1253 encoding
+    Windows1253CharTraits => WINDOWS_1253,
+    /// [`CharTraits`] for the windows-1254 encoding
+    Windows1254CharTraits => WINDOWS_1254,
+    /// [`CharTraits`] for the windows-1255 encoding
+    Windows1255CharTraits => WINDOWS_1255,
+    /// [`CharTraits`] for the windows-1256 encoding
+    Windows1256CharTraits => WINDOWS_1256,
+    /// [`CharTraits`] for the windows-1257 encoding
+    Windows1257CharTraits => WINDOWS_1257,
+    /// [`CharTraits`] for the windows-1258 encoding
+    Windows1258CharTraits => WINDOWS_1258,
+    /// [`CharTraits`] for the x-mac-cyrillic encoding
+    XMacCyrillicCharTraits => X_MAC_CYRILLIC,
+}
+
+/// [`CharTraits`] for the x-user-defined encoding, which maps the bytes `0x80..=0xFF` to the private use code points `U+F780..=U+F7FF`
+pub struct XUserDefinedCharTraits;
+
+byte_char_traits!(XUserDefinedCharTraits);
+
+impl Decode for XUserDefinedCharTraits {
+    fn decode_next(buf: &[u8]) -> DecodeResult {
+        let b = buf[0];
+        if b < 0x80 {
+            DecodeResult::Char(b as char, 1)
+        } else {
+            // SAFETY:
+            // `U+F780..=U+F7FF` are all valid code points
+            DecodeResult::Char(unsafe { char::from_u32_unchecked(0xF700 + b as u32) }, 1)
+        }
+    }
+}
+
+impl Encode for XUserDefinedCharTraits {
+    fn encode_next(c: char, buf: &mut [u8]) -> EncodeResult {
+        let b = match c as u32 {
+            c @ 0x00..=0x7F => c as u8,
+            c @ 0xF780..=0xF7FF => (c - 0xF700) as u8,
+            _ => return EncodeResult::Unmappable,
+        };
+
+        match buf.first_mut() {
+            Some(out) => {
+                *out = b;
+                EncodeResult::Encoded(1)
+            }
+            None => EncodeResult::OutputFull,
+        }
+    }
+}
+
+/// [`CharTraits`] for the Shift_JIS encoding, as defined by the WHATWG Encoding Standard (equivalent to Windows code page 932)
+pub struct ShiftJisCharTraits;
+
+byte_char_traits!(ShiftJisCharTraits, |buf, idx| {
+    if idx >= buf.len() {
+        return idx == buf.len();
+    }
+    // A byte below 0x40 is never a trail byte, so a character begins after the last one before `idx`
+    let mut pos = buf[..idx]
+        .iter()
+        .rposition(|&b| b < 0x40)
+        .map_or(0, |pos| pos + 1);
+    while pos < idx {
+        match ShiftJisCharTraits::decode_next(&buf[pos..]) {
+            DecodeResult::Char(_, len) => pos += len,
+            _ => return false,
+        }
+    }
+    pos == idx
+});
+
+impl Decode for ShiftJisCharTraits {
+    fn decode_next(buf: &[u8]) -> DecodeResult {
+        let lead = buf[0];
+        match lead {
+            0x00..=0x80 => return DecodeResult::Char(lead as char, 1),
+            0xA1..=0xDF => {
+                // SAFETY:
+                // `U+FF61..=U+FF9F` are all valid code points
+                let c = unsafe { char::from_u32_unchecked(0xFF61 - 0xA1 + lead as u32) };
+                return DecodeResult::Char(c, 1);
+            }
+            0x81..=0x9F | 0xE0..=0xFC => {}
+            _ => return DecodeResult::Malformed(1),
+        }
+
+        let trail = match buf.get(1) {
+            Some(&trail) => trail,
+            None => return DecodeResult::Incomplete,
+        };
+
+        let lead_offset = if lead < 0xA0 { 0x81 } else { 0xC1 };
+        let trail_offset = if trail < 0x7F { 0x40 } else { 0x41 };
+
+        if let 0x40..=0x7E | 0x80..=0xFC = trail {
+            let pointer = (lead - lead_offset) as usize * 188 + (trail - trail_offset) as usize;
+
+            let cp = if (8836..=10715).contains(&pointer) {
+                // End-user defined characters map to the private use area
+                0xE000 - 8836 + pointer as u32
+            } else {
+                tables::JIS0208[pointer] as u32
+            };
+
+            if cp != 0 {
+                if let Some(c) = char::from_u32(cp) {
+                    return DecodeResult::Char(c, 2);
+                }
+            }
+        }
+
+        // An ASCII trail byte is not consumed by an invalid sequence
+        if trail < 0x80 {
+            DecodeResult::Malformed(1)
+        } else {
+            DecodeResult::Malformed(2)
+        }
+    }
+}
+
+impl Encode for ShiftJisCharTraits {
+    fn encode_next(c: char, buf: &mut [u8]) -> EncodeResult {
+        let single = match c as u32 {
+            c @ 0x00..=0x80 => Some(c as u8),
+            0xA5 => Some(0x5C),
+            0x203E => Some(0x7E),
+            c @ 0xFF61..=0xFF9F => Some((c - 0xFF61 + 0xA1) as u8),
+            _ => None,
+        };
+
+        if let Some(b) = single {
+            return match buf.first_mut() {
+                Some(out) => {
+                    *out = b;
+                    EncodeResult::Encoded(1)
+                }
+                None => EncodeResult::OutputFull,
+            };
+        }
+
+        let cp = if c == '\u{2212}' { 0xFF0D } else { c as u32 };
+
+        let pointer = match cp {
+            0x10000.. => return EncodeResult::Unmappable,
+            _ => match tables::JIS0208_ENCODE.binary_search_by_key(&(cp as u16), |&(cp, _)| cp) {
+                Ok(idx) => tables::JIS0208_ENCODE[idx].1 as u32,
+                Err(_) => return EncodeResult::Unmappable,
+            },
+        };
+
+        if buf.len() < 2 {
+            return EncodeResult::OutputFull;
+        }
+
+        let lead = pointer / 188;
+        let lead_offset = if lead < 0x1F { 0x81 } else { 0xC1 };
+        let trail = pointer % 188;
+        let trail_offset = if trail < 0x3F { 0x40 } else { 0x41 };
+
+        buf[0] = (lead + lead_offset) as u8;
+        buf[1] = (trail + trail_offset) as u8;
+        EncodeResult::Encoded(2)
+    }
+}
+
+macro_rules! utf16_byte_char_traits {
+    ($(#[$meta:meta])* $name:ident, $from_bytes:ident, $to_bytes:ident) => {
+        $(#[$meta])*
+        pub struct $name;
+
+        impl CharTraits for $name {
+            type Char = u8;
+            type Int = i32;
+            type Error = DecodeError;
+
+            fn validate_range(buf: &[u8]) -> Result<(), DecodeError> {
+                let mut pos = 0;
+                while pos < buf.len() {
+                    let unit = match buf.get(pos..pos + 2) {
+                        Some(unit) => u16::$from_bytes([unit[0], unit[1]]),
+                        None => return Err(DecodeError { pos, len: None }),
+                    };
+                    match unit {
+                        0xD800..=0xDBFF => match buf.get(pos + 2..pos + 4) {
+                            Some(next) if (0xDC00..=0xDFFF).contains(&u16::$from_bytes([next[0], next[1]])) => pos += 4,
+                            Some(_) => return Err(DecodeError { pos, len: Some(2) }),
+                            None => return Err(DecodeError { pos, len: None }),
+                        },
+                        0xDC00..=0xDFFF => return Err(DecodeError { pos, len: Some(2) }),
+                        _ => pos += 2,
+                    }
+                }
+                Ok(())
+            }
+
+            unsafe fn validate_subrange(buf: &[u8]) -> Result<(), DecodeError> {
+                if buf.len() % 2 != 0 {
+                    return Err(DecodeError {
+                        pos: buf.len() - 1,
+                        len: None,
+                    });
+                }
+                if let Some(&[a, b]) = buf.get(..2) {
+                    if (0xDC00..=0xDFFF).contains(&u16::$from_bytes([a, b])) {
+                        return Err(DecodeError { pos: 0, len: Some(2) });
+                    }
+                }
+                if let Some(&[a, b]) = buf.len().checked_sub(2).and_then(|pos| buf.get(pos..)) {
+                    if (0xD800..=0xDBFF).contains(&u16::$from_bytes([a, b])) {
+                        return Err(DecodeError {
+                            pos: buf.len() - 2,
+                            len: None,
+                        });
+                    }
+                }
+                Ok(())
+            }
+
+            fn compare(r1: &[u8], r2: &[u8]) -> Result<Ordering, DecodeError> {
+                let u1 = r1.chunks(2).map(|unit| u16::$from_bytes([unit[0], unit[1]]));
+                let u2 = r2.chunks(2).map(|unit| u16::$from_bytes([unit[0], unit[1]]));
+                Ok(u1.cmp(u2))
+            }
+
+            fn zero_term() -> u8 {
+                0
+            }
+
+            fn is_zero_term(c: u8) -> bool {
+                c == 0
+            }
+
+            fn eof() -> i32 {
+                -1
+            }
+
+            fn is_char_boundary(buf: &[u8], idx: usize) -> bool {
+                match buf.get(idx..idx + 2) {
+                    Some(unit) => {
+                        idx % 2 == 0
+                            && !(0xDC00..=0xDFFF).contains(&u16::$from_bytes([unit[0], unit[1]]))
+                    }
+                    None => idx == buf.len(),
+                }
+            }
+        }
+
+        unsafe impl IntoChars for $name {
+            unsafe fn decode_buf_unchecked(buf: &[u8]) -> (char, &[u8]) {
+                let v0 = u16::$from_bytes([*buf.get_unchecked(0), *buf.get_unchecked(1)]);
+                if (0xD800..=0xDBFF).contains(&v0) {
+                    let v1 = u16::$from_bytes([*buf.get_unchecked(2), *buf.get_unchecked(3)]);
+                    let val = (((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32)) + 0x10000;
+                    (char::from_u32_unchecked(val), buf.get_unchecked(4..))
+                } else {
+                    (char::from_u32_unchecked(v0 as u32), buf.get_unchecked(2..))
+                }
+            }
+
+            fn decode_buf(buf: &[u8]) -> Option<(char, &[u8])> {
+                let v0 = u16::$from_bytes([*buf.first()?, *buf.get(1)?]);
+                if (0xD800..=0xDBFF).contains(&v0) {
+                    let v1 = u16::$from_bytes([*buf.get(2)?, *buf.get(3)?]);
+                    if !(0xDC00..=0xDFFF).contains(&v1) {
+                        return None;
+                    }
+                    let val = (((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32)) + 0x10000;
+                    Some((char::from_u32(val)?, &buf[4..]))
+                } else {
+                    Some((char::from_u32(v0 as u32)?, &buf[2..]))
+                }
+            }
+
+            fn max_encoding_len() -> usize {
+                4
+            }
+
+            fn encode(c: char, buf: &mut [u8]) -> &mut [u8] {
+                let mut units = [0u16; 2];
+                let units = c.encode_utf16(&mut units);
+                let len = units.len() * 2;
+                for (out, unit) in buf[..len].chunks_mut(2).zip(units.iter()) {
+                    out.copy_from_slice(&unit.$to_bytes());
+                }
+                &mut buf[..len]
+            }
+
+            fn encoding_len(c: char) -> usize {
+                c.len_utf16() * 2
+            }
+
+            fn min_encoding_len() -> usize {
+                2
+            }
+
+            fn first_char_len(buf: &[u8]) -> usize {
+                match buf.get(..2) {
+                    Some(unit) if (0xD800..=0xDBFF).contains(&u16::$from_bytes([unit[0], unit[1]])) => {
+                        buf.len().min(4)
+                    }
+                    _ => buf.len().min(2),
+                }
+            }
+
+        }
+    };
+}
+
+utf16_byte_char_traits!(
+    /// [`CharTraits`] for UTF-16 in little-endian byte order, over bytes
+    Utf16LeCharTraits,
+    from_le_bytes,
+    to_le_bytes
+);
+
+utf16_byte_char_traits!(
+    /// [`CharTraits`] for UTF-16 in big-endian byte order, over bytes
+    Utf16BeCharTraits,
+    from_be_bytes,
+    to_be_bytes
+);
+
+macro_rules! utf32_byte_char_traits {
+    ($(#[$meta:meta])* $name:ident, $from_bytes:ident, $to_bytes:ident) => {
+        $(#[$meta])*
+        pub struct $name;
+
+        impl CharTraits for $name {
+            type Char = u8;
+            type Int = i32;
+            type Error = DecodeError;
+
+            fn validate_range(buf: &[u8]) -> Result<(), DecodeError> {
+                for (i, unit) in buf.chunks(4).enumerate() {
+                    match <[u8; 4]>::try_from(unit) {
+                        Ok(unit) if char::from_u32(u32::$from_bytes(unit)).is_some() => {}
+                        Ok(_) => {
+                            return Err(DecodeError {
+                                pos: i * 4,
+                                len: Some(4),
+                            })
+                        }
+                        Err(_) => return Err(DecodeError { pos: i * 4, len: None }),
+                    }
+                }
+                Ok(())
+            }
+
+            unsafe fn validate_subrange(buf: &[u8]) -> Result<(), DecodeError> {
+                if buf.len() % 4 != 0 {
+                    Err(DecodeError {
+                        pos: buf.len() - buf.len() % 4,
+                        len: None,
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+
+            fn compare(r1: &[u8], r2: &[u8]) -> Result<Ordering, DecodeError> {
+                let u1 = r1.chunks(4).map(|unit| u32::$from_bytes([unit[0], unit[1], unit[2], unit[3]]));
+                let u2 = r2.chunks(4).map(|unit| u32::$from_bytes([unit[0], unit[1], unit[2], unit[3]]));
+                Ok(u1.cmp(u2))
+            }
+
+            fn zero_term() -> u8 {
+                0
+            }
+
+            fn is_zero_term(c: u8) -> bool {
+                c == 0
+            }
+
+            fn eof() -> i32 {
+                -1
+            }
+
+            fn is_char_boundary(buf: &[u8], idx: usize) -> bool {
+                idx <= buf.len() && idx % 4 == 0
+            }
+        }
+
+        unsafe impl IntoChars for $name {
+            unsafe fn decode_buf_unchecked(buf: &[u8]) -> (char, &[u8]) {
+                let unit = [
+                    *buf.get_unchecked(0),
+                    *buf.get_unchecked(1),
+                    *buf.get_unchecked(2),
+                    *buf.get_unchecked(3),
+                ];
+                (char::from_u32_unchecked(u32::$from_bytes(unit)), buf.get_unchecked(4..))
+            }
+
+            fn decode_buf(buf: &[u8]) -> Option<(char, &[u8])> {
+                let unit = <[u8; 4]>::try_from(buf.get(..4)?).ok()?;
+                Some((char::from_u32(u32::$from_bytes(unit))?, &buf[4..]))
+            }
+
+            fn max_encoding_len() -> usize {
+                4
+            }
+
+            fn encode(c: char, buf: &mut [u8]) -> &mut [u8] {
+                buf[..4].copy_from_slice(&(c as u32).$to_bytes());
+                &mut buf[..4]
+            }
+
+            fn encoding_len(_: char) -> usize {
+                4
+            }
+
+            fn min_encoding_len() -> usize {
+                4
+            }
+
+            fn first_char_len(buf: &[u8]) -> usize {
+                buf.len().min(4)
+            }
+
+        }
+    };
+}
+
+utf32_byte_char_traits!(
+    /// [`CharTraits`] for UTF-32 in little-endian byte order, over bytes
+    Utf32LeCharTraits,
+    from_le_bytes,
+    to_le_bytes
+);
+
+utf32_byte_char_traits!(
+    /// [`CharTraits`] for UTF-32 in big-endian byte order, over bytes
+    Utf32BeCharTraits,
+    from_be_bytes,
+    to_be_bytes
+);
+
+/// Object-safe interface to the [`Decode`] and [`Encode`] implementations of an encoding
+trait Codec: Sync {
+    /// Decodes `bytes`, passing each character to `out`, and U+FFFD REPLACEMENT CHARACTER for each malformed sequence
+    fn decode_with(&self, bytes: &[u8], out: &mut dyn FnMut(char));
+
+    fn encode_into(&self, s: &Str, out: &mut Vec<u8>);
+}
+
+struct CodecImpl<Traits>(PhantomData<fn() -> Traits>);
+
+impl<Traits: Decode<Char = u8> + Encode> Codec for CodecImpl<Traits> {
+    fn decode_with(&self, mut bytes: &[u8], out: &mut dyn FnMut(char)) {
+        while !bytes.is_empty() {
+            match Traits::decode_next(bytes) {
+                DecodeResult::Char(c, len) => {
+                    out(c);
+                    bytes = &bytes[len..];
+                }
+                DecodeResult::Incomplete => {
+                    out(char::REPLACEMENT_CHARACTER);
+                    break;
+                }
+                DecodeResult::Malformed(len) => {
+                    out(char::REPLACEMENT_CHARACTER);
+                    bytes = &bytes[len.clamp(1, bytes.len())..];
+                }
+            }
+        }
+    }
+
+    fn encode_into(&self, s: &Str, out: &mut Vec<u8>) {
+        let mut buf = [0u8; 4];
+        for c in s.unicode_iter() {
+            match Traits::encode_next(c, &mut buf) {
+                EncodeResult::Encoded(len) => out.extend_from_slice(&buf[..len]),
+                EncodeResult::Unmappable => {
+                    out.extend_from_slice(alloc::format!("&#{};", c as u32).as_bytes())
+                }
+                EncodeResult::OutputFull => {
+                    unreachable!("Encodings of this module use at most 4 bytes per character")
+                }
+            }
+        }
+    }
+}
+
+macro_rules! encodings {
+    ($($(#[$meta:meta])* $variant:ident($traits:ty) = $name:literal [$($label:literal),* $(,)?]),* $(,)?) => {
+        /// An encoding supported by this module, identified by its name in the WHATWG Encoding Standard
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum Encoding {
+            $($(#[$meta])* $variant,)*
+        }
+
+        impl Encoding {
+            /// All of the encodings supported by this module
+            pub const ALL: &'static [Encoding] = &[$(Encoding::$variant,)*];
+
+            /// The canonical name of the encoding in the WHATWG Encoding Standard
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Encoding::$variant => $name,)*
+                }
+            }
+
+            /// The labels that identify the encoding in the WHATWG Encoding Standard, in lowercase
+            pub const fn labels(self) -> &'static [&'static str] {
+                match self {
+                    $(Encoding::$variant => &[$($label),*],)*
+                }
+            }
+
+            fn codec(self) -> &'static dyn Codec {
+                match self {
+                    $(Encoding::$variant => &CodecImpl::<$traits>(PhantomData),)*
+                }
+            }
+        }
+    };
+}
+
+encodings! {
+    Utf8(UtfCharTraits<u8>) = "UTF-8" [
+        "unicode-1-1-utf-8", "unicode11utf8", "unicode20utf8", "utf-8", "utf8", "x-unicode20utf8",
+    ],
+    Ibm866(Ibm866CharTraits) = "IBM866" ["866", "cp866", "csibm866", "ibm866"],
+    Iso8859_2(Iso8859_2CharTraits) = "ISO-8859-2" [
+        "csisolatin2", "iso-8859-2", "iso-ir-101", "iso8859-2", "iso88592", "iso_8859-2",
+        "iso_8859-2:1987", "l2", "latin2",
+    ],
+    Iso8859_3(Iso8859_3CharTraits) = "ISO-8859-3" [
+        "csisolatin3", "iso-8859-3", "iso-ir-109", "iso8859-3", "iso88593", "iso_8859-3",
+        "iso_8859-3:1988", "l3", "latin3",
+    ],
+    Iso8859_4(Iso8859_4CharTraits) = "ISO-8859-4" [
+        "csisolatin4", "iso-8859-4", "iso-ir-110", "iso8859-4", "iso88594", "iso_8859-4",
+        "iso_8859-4:1988", "l4", "latin4",
+    ],
+    Iso8859_5(Iso8859_5CharTraits) = "ISO-8859-5" [
+        "csisolatincyrillic", "cyrillic", "iso-8859-5", "iso-ir-144", "iso8859-5", "iso88595",
+        "iso_8859-5", "iso_8859-5:1988",
+    ],
+    Iso8859_6(Iso8859_6CharTraits) = "ISO-8859-6" [
+        "arabic", "asmo-708", "csiso88596e", "csiso88596i", "csisolatinarabic", "ecma-114",
+        "iso-8859-6", "iso-8859-6-e", "iso-8859-6-i", "iso-ir-127", "iso8859-6", "iso88596",
+        "iso_8859-6", "iso_8859-6:1987",
+    ],
+    Iso8859_7(Iso8859_7CharTraits) = "ISO-8859-7" [
+        "csisolatingreek", "ecma-118", "elot_928", "greek", "greek8", "iso-8859-7", "iso-ir-126",
+        "iso8859-7", "iso88597", "iso_8859-7", "iso_8859-7:1987", "sun_eu_greek",
+    ],
+    Iso8859_8(Iso8859_8CharTraits) = "ISO-8859-8" [
+        "csiso88598e", "csisolatinhebrew", "hebrew", "iso-8859-8", "iso-8859-8-e", "iso-ir-138",
+        "iso8859-8", "iso88598", "iso_8859-8", "iso_8859-8:1988", "visual",
+    ],
+    Iso8859_8I(Iso8859_8CharTraits) = "ISO-8859-8-I" ["csiso88598i", "iso-8859-8-i", "logical"],
+    Iso8859_10(Iso8859_10CharTraits) = "ISO-8859-10" [
+        "csisolatin6", "iso-8859-10", "iso-ir-157", "iso8859-10", "iso885910", "l6", "latin6",
+    ],
+    Iso8859_13(Iso8859_13CharTraits) = "ISO-8859-13" ["iso-8859-13", "iso8859-13", "iso885913"],
+    Iso8859_14(Iso8859_14CharTraits) = "ISO-8859-14" ["iso-8859-14", "iso8859-14", "iso885914"],
+    Iso8859_15(Iso8859_15CharTraits) = "ISO-8859-15" [
+        "csisolatin9", "iso-8859-15", "iso8859-15", "iso885915", "iso_8859-15", "l9",
+    ],
+    Iso8859_16(Iso8859_16CharTraits) = "ISO-8859-16" ["iso-8859-16"],
+    Koi8R(Koi8RCharTraits) = "KOI8-R" ["cskoi8r", "koi", "koi8", "koi8-r", "koi8_r"],
+    Koi8U(Koi8UCharTraits) = "KOI8-U" ["koi8-ru", "koi8-u"],
+    Macintosh(MacintoshCharTraits) = "macintosh" ["csmacintosh", "mac", "macintosh", "x-mac-roman"],
+    Windows874(Windows874CharTraits) = "windows-874" [
+        "dos-874", "iso-8859-11", "iso8859-11", "iso885911", "tis-620", "windows-874",
+    ],
+    Windows1250(Windows1250CharTraits) = "windows-1250" ["cp1250", "windows-1250", "x-cp1250"],
+    Windows1251(Windows1251CharTraits) = "windows-1251" ["cp1251", "windows-1251", "x-cp1251"],
+    Windows1252(Windows1252CharTraits) = "windows-1252" [
+        "ansi_x3.4-1968", "ascii", "cp1252", "cp819", "csisolatin1", "ibm819", "iso-8859-1",
+        "iso-ir-100", "iso8859-1", "iso88591", "iso_8859-1", "iso_8859-1:1987", "l1", "latin1",
+        "us-ascii", "windows-1252", "x-cp1252",
+    ],
+    Windows1253(Windows1253CharTraits) = "windows-1253" ["cp1253", "windows-1253", "x-cp1253"],
+    Windows1254(Windows1254CharTraits) = "windows-1254" [
+        "cp1254", "csisolatin5", "iso-8859-9", "iso-ir-148", "iso8859-9", "iso88599", "iso_8859-9",
+        "iso_8859-9:1989", "l5", "latin5", "windows-1254", "x-cp1254",
+    ],
+    Windows1255(Windows1255CharTraits) = "windows-1255" ["cp1255", "windows-1255", "x-cp1255"],
+    Windows1256(Windows1256CharTraits) = "windows-1256" ["cp1256", "windows-1256", "x-cp1256"],
+    Windows1257(Windows1257CharTraits) = "windows-1257" ["cp1257", "windows-1257", "x-cp1257"],
+    Windows1258(Windows1258CharTraits) = "windows-1258" ["cp1258", "windows-1258", "x-cp1258"],
+    XMacCyrillic(XMacCyrillicCharTraits) = "x-mac-cyrillic" ["x-mac-cyrillic", "x-mac-ukrainian"],
+    ShiftJis(ShiftJisCharTraits) = "Shift_JIS" [
+        "csshiftjis", "ms932", "ms_kanji", "shift-jis", "shift_jis", "sjis", "windows-31j", "x-sjis",
+    ],
+    Utf16Be(Utf16BeCharTraits) = "UTF-16BE" ["unicodefffe", "utf-16be"],
+    Utf16Le(Utf16LeCharTraits) = "UTF-16LE" [
+        "csunicode", "iso-10646-ucs-2", "ucs-2", "unicode", "unicodefeff", "utf-16", "utf-16le",
+    ],
+    XUserDefined(XUserDefinedCharTraits) = "x-user-defined" ["x-user-defined"],
+    /// UTF-32 is not part of the WHATWG Encoding Standard, and is only identified by its name
+    Utf32Be(Utf32BeCharTraits) = "UTF-32BE" ["utf-32be"],
+    /// UTF-32 is not part of the WHATWG Encoding Standard, and is only identified by its name
+    Utf32Le(Utf32LeCharTraits) = "UTF-32LE" ["utf-32le"],
+}
+
+impl Encoding {
+    /// Looks up an encoding by one of its WHATWG labels.
+    ///
+    /// Leading and trailing ASCII whitespace is ignored, and the label is matched ASCII case-insensitively.
+    /// Returns `None` if the label is unknown, or names an encoding that is not supported by this module (such as `gbk` or `big5`).
+    pub fn for_label(label: &str) -> Option<Encoding> {
+        let label = label.trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '));
+        Encoding::ALL.iter().copied().find(|encoding| {
+            encoding
+                .labels()
+                .iter()
+                .any(|candidate| candidate.eq_ignore_ascii_case(label))
+        })
+    }
+
+    /// Decodes `bytes`, replacing each malformed sequence with U+FFFD REPLACEMENT CHARACTER
+    pub fn decode(self, bytes: &[u8]) -> String {
+        self.decode_as(bytes)
+    }
+
+    /// Decodes `bytes` into a [`BasicString`] encoded according to `Traits`, replacing each malformed sequence with U+FFFD REPLACEMENT CHARACTER
+    pub fn decode_as<Traits: IntoChars>(self, bytes: &[u8]) -> BasicString<Traits::Char, Traits> {
+        let mut out = BasicString::with_capacity(bytes.len());
+        self.codec().decode_with(bytes, &mut |c| out.push(c));
+        out
+    }
+
+    /// Encodes `s`, replacing each character that cannot be represented with an HTML decimal character reference (such as `&#8364;`)
+    pub fn encode(self, s: &Str) -> Vec<u8> {
+        let mut out = Vec::with_capacity(s.len());
+        self.codec().encode_into(s, &mut out);
+        out
+    }
+}
+
+/// The result of [`detect_and_decode`]
+pub struct Detected<Traits: CharTraits> {
+    encoding: Encoding,
+    bom: bool,
+    text: BasicString<Traits::Char, Traits>,
+}
+
+impl<Traits: CharTraits> Detected<Traits> {
+    /// The encoding the input was decoded as
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Whether the input began with a byte order mark, which was stripped from the text
+    pub fn had_bom(&self) -> bool {
+        self.bom
+    }
+
+    /// The decoded text
+    pub fn text(&self) -> &BasicStr<Traits::Char, Traits> {
+        &self.text
+    }
+
+    /// Converts the result into the decoded text
+    pub fn into_text(self) -> BasicString<Traits::Char, Traits> {
+        self.text
+    }
+}
+
+/// Determines the encoding of `bytes` from its byte order mark, if any.
+///
+/// Returns the encoding and the length of the byte order mark.
+pub fn sniff_bom(bytes: &[u8]) -> Option<(Encoding, usize)> {
+    match bytes {
+        [0xFF, 0xFE, 0x00, 0x00, ..] => Some((Encoding::Utf32Le, 4)),
+        [0x00, 0x00, 0xFE, 0xFF, ..] => Some((Encoding::Utf32Be, 4)),
+        [0xEF, 0xBB, 0xBF, ..] => Some((Encoding::Utf8, 3)),
+        [0xFF, 0xFE, ..] => Some((Encoding::Utf16Le, 2)),
+        [0xFE, 0xFF, ..] => Some((Encoding::Utf16Be, 2)),
+        _ => None,
+    }
+}
+
+/// Guesses the encoding of `bytes`, which does not begin with a byte order mark.
+///
+/// UTF-16 is chosen if the input is valid UTF-16 and the zero bytes of its ASCII characters fall predominantly at odd (little-endian) or even (big-endian) positions.
+/// UTF-32 is never guessed, as short UTF-16 and binary inputs are often valid UTF-32 as well, and is only detected by its byte order mark.
+/// Otherwise, UTF-8 is chosen if the input is valid UTF-8, and windows-1252 (the WHATWG interpretation of Latin-1) if it is not.
+pub fn guess_encoding(bytes: &[u8]) -> Encoding {
+    if !bytes.is_empty() && bytes.len().is_multiple_of(2) {
+        let units = bytes.len() / 2;
+        let even_zeros = bytes.iter().step_by(2).filter(|&&b| b == 0).count();
+        let odd_zeros = bytes.iter().skip(1).step_by(2).filter(|&&b| b == 0).count();
+
+        // Require at least a quarter of the units to be ASCII, with zero bytes almost exclusively on one side
+        if odd_zeros * 4 >= units
+            && even_zeros * 8 <= odd_zeros
+            && Utf16LeCharTraits::validate_range(bytes).is_ok()
+        {
+            return Encoding::Utf16Le;
+        } else if even_zeros * 4 >= units
+            && odd_zeros * 8 <= even_zeros
+            && Utf16BeCharTraits::validate_range(bytes).is_ok()
+        {
+            return Encoding::Utf16Be;
+        }
+    }
+
+    if core::str::from_utf8(bytes).is_ok() {
+        Encoding::Utf8
+    } else {
+        Encoding::Windows1252
+    }
+}
+
+/// Detects the encoding of `bytes` and decodes it into a [`BasicString`] encoded according to `Traits`.
+///
+/// The encoding is determined by the byte order mark, which is stripped, or by [`guess_encoding`] if there is none.
+pub fn detect_and_decode<Traits: IntoChars>(bytes: &[u8]) -> Detected<Traits> {
+    let (encoding, bom_len) = sniff_bom(bytes).unwrap_or_else(|| (guess_encoding(bytes), 0));
+
+    Detected {
+        encoding,
+        bom: bom_len != 0,
+        text: encoding.decode_as(&bytes[bom_len..]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        detect_and_decode, guess_encoding, Encoding, ShiftJisCharTraits, Utf16LeCharTraits,
+        Windows1252CharTraits,
+    };
+    use crate::str::{BasicStr, Str};
+    use crate::utf::UtfCharTraits;
+
+    #[test]
+    fn test_for_label() {
+        assert_eq!(
+            Encoding::for_label(" Latin1\n"),
+            Some(Encoding::Windows1252)
+        );
+        assert_eq!(Encoding::for_label("utf-16le"), Some(Encoding::Utf16Le));
+        assert_eq!(Encoding::for_label("SHIFT_JIS"), Some(Encoding::ShiftJis));
+        assert_eq!(Encoding::for_label("not-an-encoding"), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cases = [
+            (
+                Encoding::Windows1252,
+                "caf\u{e9} \u{20ac}",
+                &b"caf\xe9 \x80"[..],
+            ),
+            (Encoding::Utf16Le, "a\u{1F600}", &b"a\0\x3d\xd8\x00\xde"[..]),
+            (Encoding::Utf16Be, "a", &b"\0a"[..]),
+            (
+                Encoding::ShiftJis,
+                "\u{65e5}\u{672c}\u{ff71}",
+                &b"\x93\xfa\x96\x7b\xb1"[..],
+            ),
+            (Encoding::Koi8R, "\u{41f}", &b"\xf0"[..]),
+        ];
+        for (encoding, text, bytes) in cases {
+            assert_eq!(encoding.decode(bytes).as_chars(), text.as_bytes());
+            assert_eq!(encoding.encode(Str::from_str(text)), bytes);
+        }
+    }
+
+    #[test]
+    fn test_single_byte_conformance() {
+        for &encoding in Encoding::ALL {
+            let Some(reference) = encoding_rs::Encoding::for_label(encoding.name().as_bytes())
+                .filter(|reference| reference.is_single_byte())
+            else {
+                continue;
+            };
+            for b in 0..=0xFF {
+                let byte = [b];
+                let decoded = encoding.decode(&byte);
+                let (expected, _) = reference.decode_without_bom_handling(&byte);
+                assert_eq!(
+                    decoded.as_chars(),
+                    expected.as_bytes(),
+                    "{} {:#04x}",
+                    encoding.name(),
+                    b
+                );
+                let (expected, _, _) = reference.encode(&expected);
+                assert_eq!(
+                    encoding.encode(&decoded),
+                    &expected[..],
+                    "{} {:#04x}",
+                    encoding.name(),
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_char_boundaries() {
+        let bytes = b"a\0\x3d\xd8\x00\xdeb\0";
+        let s = BasicStr::<u8, Utf16LeCharTraits>::from_chars(bytes).unwrap();
+        let boundaries: Vec<usize> = (0..=bytes.len())
+            .filter(|&i| s.is_char_boundary(i))
+            .collect();
+        assert_eq!(boundaries, [0, 2, 6, 8]);
+        let indices: Vec<_> = s.char_indices().collect();
+        assert_eq!(indices, [(0, 'a'), (2, '\u{1F600}'), (6, 'b')]);
+        assert!(s.slice_checked(4..).is_none());
+
+        // The trail bytes of Shift_JIS overlap with ASCII
+        let bytes = b"x\x83\x41\x83\x83A\x83\x41";
+        let s = BasicStr::<u8, ShiftJisCharTraits>::from_chars(bytes).unwrap();
+        let boundaries: Vec<usize> = (0..=bytes.len())
+            .filter(|&i| s.is_char_boundary(i))
+            .collect();
+        assert_eq!(boundaries, [0, 1, 3, 5, 6, 8]);
+
+        let s = BasicStr::<u8, Windows1252CharTraits>::from_chars(b"a\xe9").unwrap();
+        assert!((0..=2).all(|i| s.is_char_boundary(i)) && !s.is_char_boundary(3));
+    }
+
+    #[cfg(feature = "pattern")]
+    #[test]
+    fn test_substring_on_boundaries() {
+        type Utf16LeStr = BasicStr<u8, Utf16LeCharTraits>;
+        fn pieces<'a>(it: impl Iterator<Item = &'a Utf16LeStr>) -> Vec<&'a [u8]> {
+            it.map(Utf16LeStr::as_chars).collect()
+        }
+
+        // The needle U+4342 also occurs across the units U+4241 and U+0043
+        let s = Utf16LeStr::from_chars(b"\x41\x42\x43\x00").unwrap();
+        let needle = Utf16LeStr::from_chars(b"\x42\x43").unwrap();
+        assert_eq!(s.find(needle), None);
+        assert_eq!(s.rfind(needle), None);
+        assert_eq!(pieces(s.split(needle)), [s.as_chars()]);
+        assert_eq!(pieces(s.rsplit(needle)), [s.as_chars()]);
+
+        let empty = Utf16LeStr::from_chars(b"").unwrap();
+        let chars: [&[u8]; 4] = [b"", b"\x41\x42", b"\x43\x00", b""];
+        assert_eq!(pieces(s.split(empty)), chars);
+        let mut rev = pieces(s.rsplit(empty));
+        rev.reverse();
+        assert_eq!(rev, chars);
+
+        // The needle U+0041 also ends the unit U+4141
+        let s = Utf16LeStr::from_chars(b"\x41\x41\x41\x00").unwrap();
+        let needle = Utf16LeStr::from_chars(b"\x41\x00").unwrap();
+        assert_eq!(s.find(needle), Some(2));
+        assert_eq!(s.rfind(needle), Some(2));
+
+        // The trail byte of a Shift_JIS character can be an ASCII byte
+        let s = BasicStr::<u8, ShiftJisCharTraits>::from_chars(b"x\x83\x41y").unwrap();
+        let needle = BasicStr::<u8, ShiftJisCharTraits>::from_chars(b"A").unwrap();
+        assert_eq!(s.find(needle), None);
+        assert_eq!(s.rfind(needle), None);
+        assert!(!s.contains(needle));
+        let s = BasicStr::<u8, ShiftJisCharTraits>::from_chars(b"x\x83\x41").unwrap();
+        assert!(!s.ends_with(needle));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Encoding::ShiftJis.decode(b"\x93 ").as_chars(),
+            "\u{fffd} ".as_bytes()
+        );
+        assert_eq!(
+            Encoding::Windows1252.encode(Str::from_str("\u{65e5}")),
+            b"&#26085;"
+        );
+        // End-user defined characters decode to the private use area, but are not encoded back
+        assert_eq!(
+            Encoding::ShiftJis.decode(b"\xf0\x40").as_chars(),
+            "\u{e000}".as_bytes()
+        );
+        assert_eq!(
+            Encoding::ShiftJis.encode(Str::from_str("\u{e000}")),
+            b"&#57344;"
+        );
+    }
+
+    #[test]
+    fn test_detect_and_decode() {
+        let cases = [
+            (&b"\xef\xbb\xbfhi"[..], Encoding::Utf8, true),
+            (&b"\xff\xfeh\0i\0"[..], Encoding::Utf16Le, true),
+            (&b"\0\0\xfe\xff\0\0\0h\0\0\0i"[..], Encoding::Utf32Be, true),
+            (&b"h\0i\0"[..], Encoding::Utf16Le, false),
+            (&b"\0h\0i"[..], Encoding::Utf16Be, false),
+            (&b"hi"[..], Encoding::Utf8, false),
+        ];
+        for (bytes, encoding, bom) in cases {
+            let detected = detect_and_decode::<UtfCharTraits<u16>>(bytes);
+            assert_eq!(detected.encoding(), encoding);
+            assert_eq!(detected.had_bom(), bom);
+            assert_eq!(detected.text().as_chars(), &[b'h' as u16, b'i' as u16]);
+        }
+
+        let detected = detect_and_decode::<UtfCharTraits<u8>>(b"caf\xe9");
+        assert_eq!(detected.encoding(), Encoding::Windows1252);
+        assert_eq!(detected.text().as_chars(), "caf\u{e9}".as_bytes());
+
+        // Valid UTF-32 is not guessed without a byte order mark
+        assert_eq!(guess_encoding(b"h\0\0\0"), Encoding::Utf8);
+        assert_eq!(guess_encoding(b"\x00\xf6\x01\x00"), Encoding::Windows1252);
+    }
+}
@@ -5,7 +5,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(not(feature = "allocator-api"))]
+#[cfg(all(feature = "alloc", not(feature = "allocator-api")))]
 pub(crate) mod placeholders;
 
 pub mod cstr;
@@ -14,13 +14,34 @@ pub mod cstring;
 pub mod str;
 
 pub mod array_str;
+pub mod case;
+#[cfg(feature = "alloc")]
+pub mod char_index;
+pub mod code_points;
+pub mod cursor;
+#[cfg(feature = "alloc")]
+pub mod line_index;
+pub mod linebreak;
+pub mod normalize;
+pub mod segment;
+pub mod width;
 
 #[cfg(feature = "alloc")]
 pub mod string;
 pub mod traits;
+pub mod transcode;
 #[cfg(feature = "utf")]
 pub mod utf;
 pub mod view;
 
 #[cfg(feature = "pattern")]
 pub mod pattern;
+
+#[cfg(all(feature = "pattern", feature = "alloc"))]
+pub mod multi_pattern;
+
+#[cfg(feature = "regex")]
+pub mod regex;
+
+#[cfg(feature = "encoding")]
+pub mod encoding;
@@ -0,0 +1,366 @@
+//! Conversion of offsets between encodings, and between offsets and (line, column) pairs
+//!
+//! A [`LineIndex`] is built once from a [`BasicStr`], and answers queries in `O(log n)` without access to the text.
+//! This is useful for protocols that count positions in a different encoding than the one the text is stored in,
+//!  such as the Language Server Protocol, which counts UTF-16 code units by default.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use crate::str::BasicStr;
+use crate::traits::IntoChars;
+
+/// The unit in which an offset is counted
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// Code units of the encoding the text is stored in
+    Storage,
+    /// UTF-8 code units (bytes)
+    Utf8,
+    /// UTF-16 code units
+    Utf16,
+    /// Unicode code points
+    CodePoint,
+}
+
+/// A position in the text, counted in every [`Unit`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+struct Offsets {
+    storage: usize,
+    utf8: usize,
+    utf16: usize,
+    code_point: usize,
+}
+
+impl Offsets {
+    fn of<Traits: IntoChars>(c: char) -> Self {
+        Self {
+            storage: Traits::encoding_len(c),
+            utf8: c.len_utf8(),
+            utf16: c.len_utf16(),
+            code_point: 1,
+        }
+    }
+
+    fn get(&self, unit: Unit) -> usize {
+        match unit {
+            Unit::Storage => self.storage,
+            Unit::Utf8 => self.utf8,
+            Unit::Utf16 => self.utf16,
+            Unit::CodePoint => self.code_point,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            storage: self.storage + other.storage,
+            utf8: self.utf8 + other.utf8,
+            utf16: self.utf16 + other.utf16,
+            code_point: self.code_point + other.code_point,
+        }
+    }
+
+    /// Moves an offset at or after `from` so that `from` becomes `to`
+    fn rebase(self, from: Self, to: Self) -> Self {
+        Self {
+            storage: self.storage - from.storage + to.storage,
+            utf8: self.utf8 - from.utf8 + to.utf8,
+            utf16: self.utf16 - from.utf16 + to.utf16,
+            code_point: self.code_point - from.code_point + to.code_point,
+        }
+    }
+}
+
+/// A character whose length differs from that of an ASCII character in some [`Unit`]
+#[derive(Copy, Clone, Debug)]
+struct WideChar {
+    start: Offsets,
+    c: char,
+}
+
+/// The line starts and wide characters of a piece of text, relative to its start
+struct Measure {
+    lines: Vec<Offsets>,
+    wide: Vec<WideChar>,
+    len: Offsets,
+}
+
+fn measure<Traits: IntoChars>(s: &BasicStr<Traits::Char, Traits>) -> Measure {
+    let narrow = Offsets::of::<Traits>('a');
+    let mut lines = Vec::new();
+    let mut wide = Vec::new();
+    let mut len = Offsets::default();
+    for c in s.unicode_iter() {
+        let char_len = Offsets::of::<Traits>(c);
+        if char_len != narrow {
+            wide.push(WideChar { start: len, c });
+        }
+        len = len.add(char_len);
+        if c == '\n' {
+            lines.push(len);
+        }
+    }
+    Measure { lines, wide, len }
+}
+
+/// An index of the lines and non-ASCII characters of a [`BasicStr`], for converting offsets between [`Unit`]s.
+///
+/// Lines are terminated by `'\n'`. A `'\r'` that precedes it is part of the line, as is the `'\n'` itself.
+/// Columns are counted in the same unit as offsets, from the start of the line.
+///
+/// Conversions return `None` if the offset is out of bounds or in the middle of a character.
+pub struct LineIndex<Traits> {
+    /// The start of each line, beginning with the first at offset 0
+    lines: Vec<Offsets>,
+    /// The characters whose length differs from that of an ASCII character, in order
+    wide: Vec<WideChar>,
+    len: Offsets,
+    _traits: PhantomData<Traits>,
+}
+
+impl<Traits> Clone for LineIndex<Traits> {
+    fn clone(&self) -> Self {
+        Self {
+            lines: self.lines.clone(),
+            wide: self.wide.clone(),
+            len: self.len,
+            _traits: PhantomData,
+        }
+    }
+}
+
+impl<Traits: IntoChars> LineIndex<Traits> {
+    /// Builds the index of `s`
+    pub fn new(s: &BasicStr<Traits::Char, Traits>) -> Self {
+        let Measure { lines, wide, len } = measure(s);
+        let mut index = Self {
+            lines: Vec::with_capacity(lines.len() + 1),
+            wide,
+            len,
+            _traits: PhantomData,
+        };
+        index.lines.push(Offsets::default());
+        index.lines.extend(lines);
+        index
+    }
+
+    /// Returns the length of the indexed text in `unit`
+    pub fn len(&self, unit: Unit) -> usize {
+        self.len.get(unit)
+    }
+
+    /// Returns `true` if the indexed text is empty
+    pub fn is_empty(&self) -> bool {
+        self.len.storage == 0
+    }
+
+    /// Returns the number of lines, which is one more than the number of `'\n'`s
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the position at `offset` in `unit`, if it is a character boundary
+    fn position(&self, offset: usize, unit: Unit) -> Option<Offsets> {
+        if offset > self.len.get(unit) {
+            return None;
+        }
+
+        let narrow = Offsets::of::<Traits>('a');
+        // Every character between the last wide character before `offset` and `offset` is narrow
+        let i = self.wide.partition_point(|w| w.start.get(unit) <= offset);
+        let base = match self.wide[..i].last() {
+            Some(w) if w.start.get(unit) == offset => return Some(w.start),
+            Some(w) => w.start.add(Offsets::of::<Traits>(w.c)),
+            None => Offsets::default(),
+        };
+        let dist = offset.checked_sub(base.get(unit))?;
+        if dist % narrow.get(unit) != 0 {
+            return None;
+        }
+        let count = dist / narrow.get(unit);
+        Some(Offsets {
+            storage: base.storage + count * narrow.storage,
+            utf8: base.utf8 + count * narrow.utf8,
+            utf16: base.utf16 + count * narrow.utf16,
+            code_point: base.code_point + count * narrow.code_point,
+        })
+    }
+
+    /// Converts `offset` counted in `from` to the same position counted in `to`
+    pub fn convert(&self, offset: usize, from: Unit, to: Unit) -> Option<usize> {
+        self.position(offset, from).map(|pos| pos.get(to))
+    }
+
+    /// Converts `offset` counted in `unit` into a 0-based line number and a column counted in `unit`
+    pub fn line_col(&self, offset: usize, unit: Unit) -> Option<(usize, usize)> {
+        let pos = self.position(offset, unit)?;
+        let line = self.lines.partition_point(|l| l.storage <= pos.storage) - 1;
+        Some((line, offset - self.lines[line].get(unit)))
+    }
+
+    /// Converts a 0-based line number and a column counted in `unit` into an offset counted in `unit`.
+    ///
+    /// The column may be at most the length of the line, excluding its `'\n'`.
+    pub fn offset(&self, line: usize, col: usize, unit: Unit) -> Option<usize> {
+        let start = self.lines.get(line)?.get(unit);
+        let end = match self.lines.get(line + 1) {
+            // Exclude the terminating `'\n'`
+            Some(next) => next.get(unit) - Offsets::of::<Traits>('\n').get(unit),
+            None => self.len.get(unit),
+        };
+        let offset = start.checked_add(col).filter(|&offset| offset <= end)?;
+        self.position(offset, unit).map(|_| offset)
+    }
+
+    /// Updates the index after the text in `range`, counted in [`Unit::Storage`], is replaced by `text`.
+    ///
+    /// This takes time proportional to the length of `text` and the number of lines and wide characters after `range`,
+    ///  without rescanning the rest of the text.
+    ///
+    /// # Panics
+    /// Panics if either end of `range` is out of bounds or not a character boundary, or if the range is decreasing.
+    pub fn replace_range(&mut self, range: Range<usize>, text: &BasicStr<Traits::Char, Traits>) {
+        assert!(range.start <= range.end, "decreasing range");
+        let start = self
+            .position(range.start, Unit::Storage)
+            .expect("range start is not a character boundary");
+        let end = self
+            .position(range.end, Unit::Storage)
+            .expect("range end is not a character boundary");
+
+        let inserted = measure(text);
+        let new_end = start.add(inserted.len);
+
+        // Line starts in `(start, end]` follow a `'\n'` that was removed
+        let first = self.lines.partition_point(|l| l.storage <= start.storage);
+        let last = self.lines.partition_point(|l| l.storage <= end.storage);
+        let after: Vec<_> = self.lines[last..]
+            .iter()
+            .map(|l| l.rebase(end, new_end))
+            .collect();
+        self.lines.truncate(first);
+        self.lines
+            .extend(inserted.lines.iter().map(|l| l.add(start)));
+        self.lines.extend(after);
+
+        let first = self
+            .wide
+            .partition_point(|w| w.start.storage < start.storage);
+        let last = self.wide.partition_point(|w| w.start.storage < end.storage);
+        let after: Vec<_> = self.wide[last..]
+            .iter()
+            .map(|w| WideChar {
+                start: w.start.rebase(end, new_end),
+                c: w.c,
+            })
+            .collect();
+        self.wide.truncate(first);
+        self.wide.extend(inserted.wide.iter().map(|w| WideChar {
+            start: w.start.add(start),
+            c: w.c,
+        }));
+        self.wide.extend(after);
+
+        self.len = self.len.rebase(end, new_end);
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::{LineIndex, Unit};
+    use crate::str::{Str, U16Str};
+    use crate::utf::UtfCharTraits;
+
+    const TEXT: &str = "fn main() {\r\n    let s = \"h\u{e9}llo \u{1f600}\";\n\u{65e5}\u{672c}\n";
+
+    /// Checks every conversion of `index` against the positions computed from `text` directly
+    fn check<Traits: crate::traits::IntoChars>(index: &LineIndex<Traits>, text: &str) {
+        let mut utf16 = 0;
+        let mut line = 0;
+        let mut line_start = (0, 0);
+        for (cp, (utf8, c)) in text.char_indices().chain([(text.len(), '\0')]).enumerate() {
+            let storage = index.convert(utf8, Unit::Utf8, Unit::Storage).unwrap();
+            assert_eq!(
+                index.convert(storage, Unit::Storage, Unit::Utf8),
+                Some(utf8)
+            );
+            assert_eq!(index.convert(utf8, Unit::Utf8, Unit::Utf16), Some(utf16));
+            assert_eq!(index.convert(utf16, Unit::Utf16, Unit::CodePoint), Some(cp));
+            assert_eq!(index.convert(cp, Unit::CodePoint, Unit::Utf8), Some(utf8));
+            assert_eq!(
+                index.line_col(utf8, Unit::Utf8),
+                Some((line, utf8 - line_start.0))
+            );
+            assert_eq!(
+                index.line_col(utf16, Unit::Utf16),
+                Some((line, utf16 - line_start.1))
+            );
+            assert_eq!(
+                index.offset(line, utf16 - line_start.1, Unit::Utf16),
+                Some(utf16)
+            );
+            if c.len_utf8() > 1 {
+                assert_eq!(index.convert(utf8 + 1, Unit::Utf8, Unit::Utf16), None);
+            }
+
+            utf16 += c.len_utf16();
+            if c == '\n' {
+                line += 1;
+                line_start = (utf8 + 1, utf16);
+            }
+        }
+        assert_eq!(index.line_count(), line + 1);
+        assert_eq!(index.len(Unit::Utf8), text.len());
+        assert_eq!(index.convert(text.len() + 1, Unit::Utf8, Unit::Utf16), None);
+    }
+
+    #[test]
+    fn test_line_index() {
+        let index = LineIndex::new(Str::from_str(TEXT));
+        check(&index, TEXT);
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line_col(29, Unit::Utf16), Some((1, 16)));
+        assert_eq!(index.offset(1, 0, Unit::Utf8), Some(13));
+        // The column may point at the end of the line, but not past it
+        assert_eq!(index.offset(0, 12, Unit::Utf8), Some(12));
+        assert_eq!(index.offset(0, 13, Unit::Utf8), None);
+        assert_eq!(index.offset(4, 0, Unit::Utf8), None);
+
+        let units: Vec<u16> = TEXT.encode_utf16().collect();
+        let index = LineIndex::new(U16Str::from_chars(&units).unwrap());
+        check(&index, TEXT);
+        assert_eq!(index.len(Unit::Storage), units.len());
+
+        let chars: Vec<char> = TEXT.chars().collect();
+        let index = LineIndex::<UtfCharTraits<char>>::new(chars.as_slice().as_ref());
+        check(&index, TEXT);
+    }
+
+    #[test]
+    fn test_replace_range() {
+        let edits = [
+            (0..0, "// \u{1f980}\n"),
+            (16..21, "x\ny\n\u{e9}"),
+            (TEXT.len()..TEXT.len(), "\u{10ffff}"),
+            (40..47, ""),
+            (0..TEXT.len(), "\u{e9}\n\n"),
+        ];
+        for (range, replacement) in edits {
+            let mut text = String::from(TEXT);
+            let mut index = LineIndex::new(Str::from_str(&text));
+            index.replace_range(range.clone(), Str::from_str(replacement));
+            text.replace_range(range.clone(), replacement);
+            check(&index, &text);
+
+            let mut units: Vec<u16> = TEXT.encode_utf16().collect();
+            let mut index = LineIndex::new(U16Str::from_chars(&units).unwrap());
+            let start = TEXT[..range.start].encode_utf16().count();
+            let end = TEXT[..range.end].encode_utf16().count();
+            let replacement: Vec<u16> = replacement.encode_utf16().collect();
+            index.replace_range(start..end, U16Str::from_chars(&replacement).unwrap());
+            units.splice(start..end, replacement);
+            check(&index, &String::from_utf16(&units).unwrap());
+        }
+    }
+}
@@ -0,0 +1,469 @@
+//! Line breaking (UAX #14) and text wrapping for [`BasicStr`]
+//!
+//! The tables are generated by `tools/gen_linebreak_tables.py`.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+use crate::str::BasicStr;
+#[cfg(feature = "alloc")]
+use crate::string::BasicString;
+use crate::traits::IntoChars;
+use crate::width::{east_asian_width, EastAsianWidth};
+
+#[rustfmt::skip]
+mod tables;
+
+/// The Line_Break class of a character, resolved as described by rule LB1
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LineBreak {
+    Bk,
+    Cr,
+    Lf,
+    Nl,
+    Sp,
+    Zw,
+    Zwj,
+    Cm,
+    Wj,
+    Gl,
+    Ba,
+    Hy,
+    Cb,
+    Bb,
+    B2,
+    Cl,
+    Cp,
+    Ex,
+    In,
+    Ns,
+    Op,
+    Qu,
+    Is,
+    Nu,
+    Po,
+    Pr,
+    Sy,
+    Al,
+    Eb,
+    Em,
+    H2,
+    H3,
+    Hl,
+    Id,
+    Jl,
+    Jv,
+    Jt,
+    Ri,
+}
+
+fn range_cmp(lo: u32, hi: u32, c: u32) -> Ordering {
+    if hi < c {
+        Ordering::Less
+    } else if lo > c {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+fn line_break(c: char) -> LineBreak {
+    tables::LINE_BREAK
+        .binary_search_by(|&(lo, hi, _)| range_cmp(lo, hi, c as u32))
+        .map_or(LineBreak::Al, |i| tables::LINE_BREAK[i].2)
+}
+
+/// A line break opportunity
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BreakOpportunity {
+    /// The line shall be broken here, such as after a newline
+    Mandatory,
+    /// The line may be broken here
+    Allowed,
+}
+
+/// The context needed to find line break opportunities when scanning forwards
+#[derive(Copy, Clone, Debug)]
+struct LineBreakState {
+    /// The class of the previous character
+    raw: LineBreak,
+    /// The class of the previous character, after combining marks are attached to their base by LB9 and LB10
+    prev: LineBreak,
+    /// The class of the character before `prev`
+    prev2: LineBreak,
+    /// The class of `prev`, or of the character before the spaces that end with `prev`
+    before_sp: LineBreak,
+    /// Whether `prev` is East Asian Fullwidth, Wide or Halfwidth, for LB30
+    prev_wide: bool,
+    /// Whether `prev` ends an odd number of regional indicators, for LB30a
+    ri_odd: bool,
+}
+
+impl LineBreakState {
+    fn new(cls: LineBreak, wide: bool) -> Self {
+        let prev = match cls {
+            LineBreak::Cm | LineBreak::Zwj => LineBreak::Al,
+            cls => cls,
+        };
+        Self {
+            raw: cls,
+            prev,
+            prev2: LineBreak::Al,
+            before_sp: if prev == LineBreak::Sp {
+                LineBreak::Al
+            } else {
+                prev
+            },
+            prev_wide: wide,
+            ri_odd: prev == LineBreak::Ri,
+        }
+    }
+
+    /// Returns whether the pair rules LB11 to LB31 allow a break between `self.prev` and `cls`
+    fn is_pair_break(&self, cls: LineBreak, wide: bool) -> bool {
+        use LineBreak::*;
+        let prev = self.prev;
+
+        if cls == Wj || prev == Wj || prev == Gl {
+            return false;
+        }
+        if cls == Gl && !matches!(prev, Sp | Ba | Hy) {
+            return false;
+        }
+        if matches!(cls, Cl | Cp | Ex | Is | Sy) {
+            return false;
+        }
+        match (self.before_sp, cls) {
+            (Op, _) | (Qu, Op) | (Cl | Cp, Ns) | (B2, B2) => return false,
+            _ => {}
+        }
+        if prev == Sp {
+            return true;
+        }
+        if cls == Qu || prev == Qu {
+            return false;
+        }
+        if cls == Cb || prev == Cb {
+            return true;
+        }
+        match (prev, cls) {
+            (_, Ba | Hy | Ns | In) | (Bb, _) => false,
+            (Hy | Ba, _) if self.prev2 == Hl => false,
+            (Sy, Hl) => false,
+            (Al | Hl, Nu) | (Nu, Al | Hl) => false,
+            (Pr, Id | Eb | Em) | (Id | Eb | Em, Po) => false,
+            (Pr | Po, Al | Hl) | (Al | Hl, Pr | Po) => false,
+            (Cl | Cp | Nu, Po | Pr) | (Po | Pr, Op | Nu) | (Hy | Is | Nu | Sy, Nu) => false,
+            (Jl, Jl | Jv | H2 | H3) | (Jv | H2, Jv | Jt) | (Jt | H3, Jt) => false,
+            (Jl | Jv | Jt | H2 | H3, Po) | (Pr, Jl | Jv | Jt | H2 | H3) => false,
+            (Al | Hl, Al | Hl) | (Is, Al | Hl) => false,
+            (Al | Hl | Nu, Op) => wide,
+            (Cp, Al | Hl | Nu) => self.prev_wide,
+            (Ri, Ri) => !self.ri_odd,
+            (Eb, Em) => false,
+            _ => true,
+        }
+    }
+
+    /// Returns the break opportunity before a character of class `cls`, if any, and moves past it.
+    ///
+    /// `wide` is whether the character is East Asian Fullwidth, Wide or Halfwidth.
+    fn next(&mut self, cls: LineBreak, wide: bool) -> Option<BreakOpportunity> {
+        use LineBreak::*;
+
+        let raw = core::mem::replace(&mut self.raw, cls);
+        let opportunity = if raw == Cr && cls == Lf {
+            None
+        } else if matches!(raw, Bk | Cr | Lf | Nl) {
+            Some(BreakOpportunity::Mandatory)
+        } else if matches!(cls, Bk | Cr | Lf | Nl | Sp | Zw) {
+            None
+        } else if self.before_sp == Zw {
+            Some(BreakOpportunity::Allowed)
+        } else if matches!(cls, Cm | Zwj) && self.prev != Sp {
+            // LB9: the combining mark takes the class of its base, so the rest of the state is unchanged
+            return None;
+        } else if raw == Zwj {
+            None
+        } else {
+            let cls = if matches!(cls, Cm | Zwj) { Al } else { cls };
+            self.is_pair_break(cls, wide)
+                .then_some(BreakOpportunity::Allowed)
+        };
+
+        let cls = match cls {
+            Cm | Zwj if opportunity.is_some() || self.prev == Sp => Al,
+            cls => cls,
+        };
+        self.prev2 = self.prev;
+        self.prev = cls;
+        if cls != Sp {
+            self.before_sp = cls;
+        }
+        self.prev_wide = wide;
+        self.ri_odd = cls == Ri && !self.ri_odd;
+        opportunity
+    }
+}
+
+/// Returns whether `c` is East Asian Fullwidth, Wide or Halfwidth, if that is relevant to line breaking
+fn is_wide_for_break(c: char, cls: LineBreak) -> bool {
+    matches!(cls, LineBreak::Op | LineBreak::Cp)
+        && matches!(
+            east_asian_width(c),
+            EastAsianWidth::Fullwidth | EastAsianWidth::Wide | EastAsianWidth::Halfwidth
+        )
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the line break opportunities in `self`, as defined by UAX #14.
+    ///
+    /// Each opportunity is given as the offset of the character that follows it, and the kind of break.
+    /// The end of a non-empty string is always a mandatory break.
+    pub fn line_breaks(&self) -> LineBreaks<'_, Traits::Char, Traits> {
+        LineBreaks {
+            buf: self.as_chars(),
+            len: self.len(),
+            state: None,
+            finished: false,
+            _traits: PhantomData,
+        }
+    }
+
+    /// Wraps `self` into lines that are at most `width` columns wide, breaking at line break opportunities.
+    ///
+    /// Each line ends at a mandatory break, or before the next word would exceed `width`.
+    /// A word that is wider than `width` by itself is split between grapheme clusters.
+    /// Whitespace and newlines at the end of each line are removed.
+    ///
+    /// Widths are measured as by [`BasicStr::display_width`].
+    #[cfg(feature = "alloc")]
+    pub fn wrap(&self, width: usize) -> Vec<BasicString<Traits::Char, Traits>> {
+        let chars = self.as_chars();
+        let line = |start: usize, end: usize| {
+            // SAFETY:
+            // Lines are split from `self` on character boundaries
+            unsafe { Self::from_chars_unchecked(&chars[start..end]) }.to_owned()
+        };
+        let mut lines = Vec::new();
+
+        // The start of the current line, the end of its last word, the width up to that end,
+        //  and the width of the whitespace after it
+        let mut start = 0;
+        let mut end = 0;
+        let mut line_width = 0;
+        let mut space_width = 0;
+
+        let mut word_start = 0;
+        for (pos, opportunity) in self.line_breaks() {
+            // SAFETY:
+            // Line break opportunities are on character boundaries
+            let word = unsafe { Self::from_chars_unchecked(&chars[word_start..pos]) };
+            let mut word_len = 0;
+            let mut word_width = 0;
+            let mut trailing_width = 0;
+            for (offset, g) in word.grapheme_indices() {
+                let w = g.display_width();
+                if g.unicode_iter().next().is_some_and(char::is_whitespace) {
+                    trailing_width += w;
+                } else {
+                    word_len = offset + g.len();
+                    word_width += trailing_width + w;
+                    trailing_width = 0;
+                }
+            }
+
+            if word_len != 0 && end != start && line_width + space_width + word_width > width {
+                lines.push(line(start, end));
+                start = word_start;
+                end = word_start;
+                line_width = 0;
+                space_width = 0;
+            }
+
+            if end == start && word_width > width {
+                // The word does not fit on a line by itself
+                for (offset, g) in word.grapheme_indices() {
+                    if offset >= word_len {
+                        break;
+                    }
+                    let w = g.display_width();
+                    if end != start && line_width + space_width + w > width {
+                        lines.push(line(start, end));
+                        start = end;
+                        line_width = 0;
+                        space_width = 0;
+                    }
+                    end = word_start + offset + g.len();
+                    line_width += space_width + w;
+                    space_width = 0;
+                }
+                space_width = trailing_width;
+            } else if word_len != 0 {
+                end = word_start + word_len;
+                line_width += space_width + word_width;
+                space_width = trailing_width;
+            } else {
+                space_width += trailing_width;
+            }
+
+            if opportunity == BreakOpportunity::Mandatory {
+                lines.push(line(start, end));
+                start = pos;
+                end = pos;
+                line_width = 0;
+                space_width = 0;
+            }
+            word_start = pos;
+        }
+
+        lines
+    }
+}
+
+/// An iterator over the line break opportunities in a [`BasicStr`]
+pub struct LineBreaks<'a, CharT, Traits> {
+    buf: &'a [CharT],
+    len: usize,
+    state: Option<LineBreakState>,
+    finished: bool,
+    _traits: PhantomData<Traits>,
+}
+
+impl<CharT, Traits> Clone for LineBreaks<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf,
+            len: self.len,
+            state: self.state,
+            finished: self.finished,
+            _traits: PhantomData,
+        }
+    }
+}
+
+impl<Traits: IntoChars> Iterator for LineBreaks<'_, Traits::Char, Traits> {
+    type Item = (usize, BreakOpportunity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY (for each call below):
+        // `self.buf` is a suffix of a valid `BasicStr` on a character boundary
+        let state = match &mut self.state {
+            Some(state) => state,
+            None if self.buf.is_empty() => return None,
+            None => {
+                let (c, rest) = unsafe { Traits::decode_buf_unchecked(self.buf) };
+                let cls = line_break(c);
+                self.buf = rest;
+                self.state
+                    .insert(LineBreakState::new(cls, is_wide_for_break(c, cls)))
+            }
+        };
+
+        while !self.buf.is_empty() {
+            let pos = self.len - self.buf.len();
+            let (c, rest) = unsafe { Traits::decode_buf_unchecked(self.buf) };
+            let cls = line_break(c);
+            self.buf = rest;
+            if let Some(opportunity) = state.next(cls, is_wide_for_break(c, cls)) {
+                return Some((pos, opportunity));
+            }
+        }
+
+        // The end of the string, which is reported once
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some((self.len, BreakOpportunity::Mandatory))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::BreakOpportunity::{Allowed, Mandatory};
+    use crate::str::{Str, U16Str};
+
+    #[test]
+    fn test_line_breaks() {
+        let breaks: Vec<_> = Str::from_str("Hello world!").line_breaks().collect();
+        assert_eq!(breaks, [(6, Allowed), (12, Mandatory)]);
+
+        let breaks: Vec<_> = Str::from_str("a b\r\nc (d) e-f 3.5%")
+            .line_breaks()
+            .collect();
+        assert_eq!(
+            breaks,
+            [
+                (2, Allowed),
+                (5, Mandatory),
+                (7, Allowed),
+                (11, Allowed),
+                (13, Allowed),
+                (15, Allowed),
+                (19, Mandatory)
+            ]
+        );
+
+        // Ideographs may be broken between, but not before closing punctuation
+        let utf16: Vec<u16> = "\u{65e5}\u{672c}\u{3002}\u{8a9e}".encode_utf16().collect();
+        let breaks: Vec<_> = U16Str::from_chars(&utf16).unwrap().line_breaks().collect();
+        assert_eq!(breaks, [(1, Allowed), (3, Allowed), (4, Mandatory)]);
+
+        // Combining marks and emoji modifiers stay with their base
+        let breaks: Vec<_> = Str::from_str("\u{1f44d}\u{1f3fd}\u{1f44d}e\u{301}")
+            .line_breaks()
+            .collect();
+        assert_eq!(breaks, [(8, Allowed), (12, Allowed), (15, Mandatory)]);
+
+        assert_eq!(Str::from_str("").line_breaks().next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_wrap() {
+        let wrap = |s: &str, width| -> Vec<std::string::String> {
+            Str::from_str(s)
+                .wrap(width)
+                .into_iter()
+                .map(|l| l.as_str().into())
+                .collect()
+        };
+
+        assert_eq!(
+            wrap("The quick brown fox jumps over the lazy dog", 10),
+            ["The quick", "brown fox", "jumps over", "the lazy", "dog"]
+        );
+        assert_eq!(
+            wrap("first\n\n  indented line", 20),
+            ["first", "", "  indented line"]
+        );
+        assert_eq!(wrap("abcdefghij klm", 4), ["abcd", "efgh", "ij", "klm"]);
+        assert_eq!(wrap("", 10), [] as [&str; 0]);
+
+        // Ideographs are two columns wide, and may be broken between
+        assert_eq!(
+            wrap("\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{6587}\u{7ae0}abc", 5),
+            [
+                "\u{65e5}\u{672c}",
+                "\u{8a9e}\u{306e}",
+                "\u{6587}\u{7ae0}",
+                "abc"
+            ]
+        );
+
+        let utf16: Vec<u16> = "caf\u{e9}\u{301} au lait".encode_utf16().collect();
+        let lines: Vec<std::string::String> = U16Str::from_chars(&utf16)
+            .unwrap()
+            .wrap(7)
+            .iter()
+            .map(|l| std::string::String::from_utf16(l.as_chars()).unwrap())
+            .collect();
+        assert_eq!(lines, ["caf\u{e9}\u{301} au", "lait"]);
+    }
+}
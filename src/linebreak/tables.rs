@@ -0,0 +1,1954 @@
+// Generated by tools/gen_linebreak_tables.py. Do not edit.
+
+// Unicode Character Database version 15.0.0
+
+use super::LineBreak;
+
+/// Ranges of characters with a resolved Line_Break other than AL, and their class
+pub(super) static LINE_BREAK: [(u32, u32, LineBreak); 1945] = [
+    (0x0000, 0x0008, LineBreak::Cm),
+    (0x0009, 0x0009, LineBreak::Ba),
+    (0x000A, 0x000A, LineBreak::Lf),
+    (0x000B, 0x000C, LineBreak::Bk),
+    (0x000D, 0x000D, LineBreak::Cr),
+    (0x000E, 0x001F, LineBreak::Cm),
+    (0x0020, 0x0020, LineBreak::Sp),
+    (0x0021, 0x0021, LineBreak::Ex),
+    (0x0022, 0x0022, LineBreak::Qu),
+    (0x0024, 0x0024, LineBreak::Pr),
+    (0x0025, 0x0025, LineBreak::Po),
+    (0x0027, 0x0027, LineBreak::Qu),
+    (0x0028, 0x0028, LineBreak::Op),
+    (0x0029, 0x0029, LineBreak::Cp),
+    (0x002B, 0x002B, LineBreak::Pr),
+    (0x002C, 0x002C, LineBreak::Is),
+    (0x002D, 0x002D, LineBreak::Hy),
+    (0x002E, 0x002E, LineBreak::Is),
+    (0x002F, 0x002F, LineBreak::Sy),
+    (0x0030, 0x0039, LineBreak::Nu),
+    (0x003A, 0x003B, LineBreak::Is),
+    (0x003F, 0x003F, LineBreak::Ex),
+    (0x005B, 0x005B, LineBreak::Op),
+    (0x005C, 0x005C, LineBreak::Pr),
+    (0x005D, 0x005D, LineBreak::Cp),
+    (0x007B, 0x007B, LineBreak::Op),
+    (0x007C, 0x007C, LineBreak::Ba),
+    (0x007D, 0x007D, LineBreak::Cl),
+    (0x007F, 0x0084, LineBreak::Cm),
+    (0x0085, 0x0085, LineBreak::Nl),
+    (0x0086, 0x009F, LineBreak::Cm),
+    (0x00A0, 0x00A0, LineBreak::Gl),
+    (0x00A1, 0x00A1, LineBreak::Op),
+    (0x00A2, 0x00A2, LineBreak::Po),
+    (0x00A3, 0x00A5, LineBreak::Pr),
+    (0x00AB, 0x00AB, LineBreak::Qu),
+    (0x00AD, 0x00AD, LineBreak::Ba),
+    (0x00B0, 0x00B0, LineBreak::Po),
+    (0x00B1, 0x00B1, LineBreak::Pr),
+    (0x00B4, 0x00B4, LineBreak::Bb),
+    (0x00BB, 0x00BB, LineBreak::Qu),
+    (0x00BF, 0x00BF, LineBreak::Op),
+    (0x02C8, 0x02C8, LineBreak::Bb),
+    (0x02CC, 0x02CC, LineBreak::Bb),
+    (0x02DF, 0x02DF, LineBreak::Bb),
+    (0x0300, 0x034E, LineBreak::Cm),
+    (0x034F, 0x034F, LineBreak::Gl),
+    (0x0350, 0x035B, LineBreak::Cm),
+    (0x035C, 0x0362, LineBreak::Gl),
+    (0x0363, 0x036F, LineBreak::Cm),
+    (0x037E, 0x037E, LineBreak::Is),
+    (0x0483, 0x0489, LineBreak::Cm),
+    (0x0589, 0x0589, LineBreak::Is),
+    (0x058A, 0x058A, LineBreak::Ba),
+    (0x058F, 0x058F, LineBreak::Pr),
+    (0x0591, 0x05BD, LineBreak::Cm),
+    (0x05BE, 0x05BE, LineBreak::Ba),
+    (0x05BF, 0x05BF, LineBreak::Cm),
+    (0x05C1, 0x05C2, LineBreak::Cm),
+    (0x05C4, 0x05C5, LineBreak::Cm),
+    (0x05C6, 0x05C6, LineBreak::Ex),
+    (0x05C7, 0x05C7, LineBreak::Cm),
+    (0x05D0, 0x05EA, LineBreak::Hl),
+    (0x05EF, 0x05F2, LineBreak::Hl),
+    (0x0609, 0x060B, LineBreak::Po),
+    (0x060C, 0x060D, LineBreak::Is),
+    (0x0610, 0x061A, LineBreak::Cm),
+    (0x061B, 0x061B, LineBreak::Ex),
+    (0x061C, 0x061C, LineBreak::Cm),
+    (0x061D, 0x061F, LineBreak::Ex),
+    (0x064B, 0x065F, LineBreak::Cm),
+    (0x0660, 0x0669, LineBreak::Nu),
+    (0x066A, 0x066A, LineBreak::Po),
+    (0x066B, 0x066C, LineBreak::Nu),
+    (0x0670, 0x0670, LineBreak::Cm),
+    (0x06D4, 0x06D4, LineBreak::Ex),
+    (0x06D6, 0x06DC, LineBreak::Cm),
+    (0x06DF, 0x06E4, LineBreak::Cm),
+    (0x06E7, 0x06E8, LineBreak::Cm),
+    (0x06EA, 0x06ED, LineBreak::Cm),
+    (0x06F0, 0x06F9, LineBreak::Nu),
+    (0x0711, 0x0711, LineBreak::Cm),
+    (0x0730, 0x074A, LineBreak::Cm),
+    (0x07A6, 0x07B0, LineBreak::Cm),
+    (0x07C0, 0x07C9, LineBreak::Nu),
+    (0x07EB, 0x07F3, LineBreak::Cm),
+    (0x07F8, 0x07F8, LineBreak::Is),
+    (0x07F9, 0x07F9, LineBreak::Ex),
+    (0x07FD, 0x07FD, LineBreak::Cm),
+    (0x07FE, 0x07FF, LineBreak::Pr),
+    (0x0816, 0x0819, LineBreak::Cm),
+    (0x081B, 0x0823, LineBreak::Cm),
+    (0x0825, 0x0827, LineBreak::Cm),
+    (0x0829, 0x082D, LineBreak::Cm),
+    (0x0859, 0x085B, LineBreak::Cm),
+    (0x0898, 0x089F, LineBreak::Cm),
+    (0x08CA, 0x08E1, LineBreak::Cm),
+    (0x08E3, 0x0903, LineBreak::Cm),
+    (0x093A, 0x093C, LineBreak::Cm),
+    (0x093E, 0x094F, LineBreak::Cm),
+    (0x0951, 0x0957, LineBreak::Cm),
+    (0x0962, 0x0963, LineBreak::Cm),
+    (0x0964, 0x0965, LineBreak::Ba),
+    (0x0966, 0x096F, LineBreak::Nu),
+    (0x0981, 0x0983, LineBreak::Cm),
+    (0x09BC, 0x09BC, LineBreak::Cm),
+    (0x09BE, 0x09C4, LineBreak::Cm),
+    (0x09C7, 0x09C8, LineBreak::Cm),
+    (0x09CB, 0x09CD, LineBreak::Cm),
+    (0x09D7, 0x09D7, LineBreak::Cm),
+    (0x09E2, 0x09E3, LineBreak::Cm),
+    (0x09E6, 0x09EF, LineBreak::Nu),
+    (0x09F2, 0x09F3, LineBreak::Po),
+    (0x09F9, 0x09F9, LineBreak::Po),
+    (0x09FB, 0x09FB, LineBreak::Pr),
+    (0x09FE, 0x09FE, LineBreak::Cm),
+    (0x0A01, 0x0A03, LineBreak::Cm),
+    (0x0A3C, 0x0A3C, LineBreak::Cm),
+    (0x0A3E, 0x0A42, LineBreak::Cm),
+    (0x0A47, 0x0A48, LineBreak::Cm),
+    (0x0A4B, 0x0A4D, LineBreak::Cm),
+    (0x0A51, 0x0A51, LineBreak::Cm),
+    (0x0A66, 0x0A6F, LineBreak::Nu),
+    (0x0A70, 0x0A71, LineBreak::Cm),
+    (0x0A75, 0x0A75, LineBreak::Cm),
+    (0x0A81, 0x0A83, LineBreak::Cm),
+    (0x0ABC, 0x0ABC, LineBreak::Cm),
+    (0x0ABE, 0x0AC5, LineBreak::Cm),
+    (0x0AC7, 0x0AC9, LineBreak::Cm),
+    (0x0ACB, 0x0ACD, LineBreak::Cm),
+    (0x0AE2, 0x0AE3, LineBreak::Cm),
+    (0x0AE6, 0x0AEF, LineBreak::Nu),
+    (0x0AF1, 0x0AF1, LineBreak::Pr),
+    (0x0AFA, 0x0AFF, LineBreak::Cm),
+    (0x0B01, 0x0B03, LineBreak::Cm),
+    (0x0B3C, 0x0B3C, LineBreak::Cm),
+    (0x0B3E, 0x0B44, LineBreak::Cm),
+    (0x0B47, 0x0B48, LineBreak::Cm),
+    (0x0B4B, 0x0B4D, LineBreak::Cm),
+    (0x0B55, 0x0B57, LineBreak::Cm),
+    (0x0B62, 0x0B63, LineBreak::Cm),
+    (0x0B66, 0x0B6F, LineBreak::Nu),
+    (0x0B82, 0x0B82, LineBreak::Cm),
+    (0x0BBE, 0x0BC2, LineBreak::Cm),
+    (0x0BC6, 0x0BC8, LineBreak::Cm),
+    (0x0BCA, 0x0BCD, LineBreak::Cm),
+    (0x0BD7, 0x0BD7, LineBreak::Cm),
+    (0x0BE6, 0x0BEF, LineBreak::Nu),
+    (0x0BF9, 0x0BF9, LineBreak::Pr),
+    (0x0C00, 0x0C04, LineBreak::Cm),
+    (0x0C3C, 0x0C3C, LineBreak::Cm),
+    (0x0C3E, 0x0C44, LineBreak::Cm),
+    (0x0C46, 0x0C48, LineBreak::Cm),
+    (0x0C4A, 0x0C4D, LineBreak::Cm),
+    (0x0C55, 0x0C56, LineBreak::Cm),
+    (0x0C62, 0x0C63, LineBreak::Cm),
+    (0x0C66, 0x0C6F, LineBreak::Nu),
+    (0x0C77, 0x0C77, LineBreak::Bb),
+    (0x0C81, 0x0C83, LineBreak::Cm),
+    (0x0C84, 0x0C84, LineBreak::Bb),
+    (0x0CBC, 0x0CBC, LineBreak::Cm),
+    (0x0CBE, 0x0CC4, LineBreak::Cm),
+    (0x0CC6, 0x0CC8, LineBreak::Cm),
+    (0x0CCA, 0x0CCD, LineBreak::Cm),
+    (0x0CD5, 0x0CD6, LineBreak::Cm),
+    (0x0CE2, 0x0CE3, LineBreak::Cm),
+    (0x0CE6, 0x0CEF, LineBreak::Nu),
+    (0x0CF3, 0x0CF3, LineBreak::Cm),
+    (0x0D00, 0x0D03, LineBreak::Cm),
+    (0x0D3B, 0x0D3C, LineBreak::Cm),
+    (0x0D3E, 0x0D44, LineBreak::Cm),
+    (0x0D46, 0x0D48, LineBreak::Cm),
+    (0x0D4A, 0x0D4D, LineBreak::Cm),
+    (0x0D57, 0x0D57, LineBreak::Cm),
+    (0x0D62, 0x0D63, LineBreak::Cm),
+    (0x0D66, 0x0D6F, LineBreak::Nu),
+    (0x0D79, 0x0D79, LineBreak::Po),
+    (0x0D81, 0x0D83, LineBreak::Cm),
+    (0x0DCA, 0x0DCA, LineBreak::Cm),
+    (0x0DCF, 0x0DD4, LineBreak::Cm),
+    (0x0DD6, 0x0DD6, LineBreak::Cm),
+    (0x0DD8, 0x0DDF, LineBreak::Cm),
+    (0x0DE6, 0x0DEF, LineBreak::Nu),
+    (0x0DF2, 0x0DF3, LineBreak::Cm),
+    (0x0E31, 0x0E31, LineBreak::Cm),
+    (0x0E34, 0x0E3A, LineBreak::Cm),
+    (0x0E3F, 0x0E3F, LineBreak::Pr),
+    (0x0E47, 0x0E4E, LineBreak::Cm),
+    (0x0E50, 0x0E59, LineBreak::Nu),
+    (0x0E5A, 0x0E5B, LineBreak::Ba),
+    (0x0EB1, 0x0EB1, LineBreak::Cm),
+    (0x0EB4, 0x0EBC, LineBreak::Cm),
+    (0x0EC8, 0x0ECE, LineBreak::Cm),
+    (0x0ED0, 0x0ED9, LineBreak::Nu),
+    (0x0F01, 0x0F04, LineBreak::Bb),
+    (0x0F06, 0x0F07, LineBreak::Bb),
+    (0x0F08, 0x0F08, LineBreak::Gl),
+    (0x0F09, 0x0F0A, LineBreak::Bb),
+    (0x0F0B, 0x0F0B, LineBreak::Ba),
+    (0x0F0C, 0x0F0C, LineBreak::Gl),
+    (0x0F0D, 0x0F11, LineBreak::Ex),
+    (0x0F12, 0x0F12, LineBreak::Gl),
+    (0x0F14, 0x0F14, LineBreak::Ex),
+    (0x0F18, 0x0F19, LineBreak::Cm),
+    (0x0F20, 0x0F29, LineBreak::Nu),
+    (0x0F34, 0x0F34, LineBreak::Ba),
+    (0x0F35, 0x0F35, LineBreak::Cm),
+    (0x0F37, 0x0F37, LineBreak::Cm),
+    (0x0F39, 0x0F39, LineBreak::Cm),
+    (0x0F3A, 0x0F3A, LineBreak::Op),
+    (0x0F3B, 0x0F3B, LineBreak::Cl),
+    (0x0F3C, 0x0F3C, LineBreak::Op),
+    (0x0F3D, 0x0F3D, LineBreak::Cl),
+    (0x0F3E, 0x0F3F, LineBreak::Cm),
+    (0x0F71, 0x0F7E, LineBreak::Cm),
+    (0x0F7F, 0x0F7F, LineBreak::Ba),
+    (0x0F80, 0x0F84, LineBreak::Cm),
+    (0x0F85, 0x0F85, LineBreak::Ba),
+    (0x0F86, 0x0F87, LineBreak::Cm),
+    (0x0F8D, 0x0F97, LineBreak::Cm),
+    (0x0F99, 0x0FBC, LineBreak::Cm),
+    (0x0FBE, 0x0FBF, LineBreak::Ba),
+    (0x0FC6, 0x0FC6, LineBreak::Cm),
+    (0x0FD0, 0x0FD1, LineBreak::Bb),
+    (0x0FD2, 0x0FD2, LineBreak::Ba),
+    (0x0FD3, 0x0FD3, LineBreak::Bb),
+    (0x0FD9, 0x0FDA, LineBreak::Gl),
+    (0x102B, 0x103E, LineBreak::Cm),
+    (0x1040, 0x1049, LineBreak::Nu),
+    (0x104A, 0x104B, LineBreak::Ba),
+    (0x1056, 0x1059, LineBreak::Cm),
+    (0x105E, 0x1060, LineBreak::Cm),
+    (0x1062, 0x1064, LineBreak::Cm),
+    (0x1067, 0x106D, LineBreak::Cm),
+    (0x1071, 0x1074, LineBreak::Cm),
+    (0x1082, 0x108D, LineBreak::Cm),
+    (0x108F, 0x108F, LineBreak::Cm),
+    (0x1090, 0x1099, LineBreak::Nu),
+    (0x109A, 0x109D, LineBreak::Cm),
+    (0x1100, 0x115F, LineBreak::Jl),
+    (0x1160, 0x11A7, LineBreak::Jv),
+    (0x11A8, 0x11FF, LineBreak::Jt),
+    (0x135D, 0x135F, LineBreak::Cm),
+    (0x1361, 0x1361, LineBreak::Ba),
+    (0x1400, 0x1400, LineBreak::Ba),
+    (0x1680, 0x1680, LineBreak::Ba),
+    (0x169B, 0x169B, LineBreak::Op),
+    (0x169C, 0x169C, LineBreak::Cl),
+    (0x16EB, 0x16ED, LineBreak::Ba),
+    (0x1712, 0x1715, LineBreak::Cm),
+    (0x1732, 0x1734, LineBreak::Cm),
+    (0x1735, 0x1736, LineBreak::Ba),
+    (0x1752, 0x1753, LineBreak::Cm),
+    (0x1772, 0x1773, LineBreak::Cm),
+    (0x17B4, 0x17D3, LineBreak::Cm),
+    (0x17D4, 0x17D5, LineBreak::Ba),
+    (0x17D6, 0x17D6, LineBreak::Ns),
+    (0x17D8, 0x17D8, LineBreak::Ba),
+    (0x17DA, 0x17DA, LineBreak::Ba),
+    (0x17DB, 0x17DB, LineBreak::Pr),
+    (0x17DD, 0x17DD, LineBreak::Cm),
+    (0x17E0, 0x17E9, LineBreak::Nu),
+    (0x1802, 0x1803, LineBreak::Ex),
+    (0x1804, 0x1805, LineBreak::Ba),
+    (0x1806, 0x1806, LineBreak::Bb),
+    (0x1808, 0x1809, LineBreak::Ex),
+    (0x180B, 0x180D, LineBreak::Cm),
+    (0x180E, 0x180E, LineBreak::Gl),
+    (0x180F, 0x180F, LineBreak::Cm),
+    (0x1810, 0x1819, LineBreak::Nu),
+    (0x1885, 0x1886, LineBreak::Cm),
+    (0x18A9, 0x18A9, LineBreak::Cm),
+    (0x1920, 0x192B, LineBreak::Cm),
+    (0x1930, 0x193B, LineBreak::Cm),
+    (0x1944, 0x1945, LineBreak::Ex),
+    (0x1946, 0x194F, LineBreak::Nu),
+    (0x19D0, 0x19D9, LineBreak::Nu),
+    (0x1A17, 0x1A1B, LineBreak::Cm),
+    (0x1A55, 0x1A5E, LineBreak::Cm),
+    (0x1A60, 0x1A7C, LineBreak::Cm),
+    (0x1A7F, 0x1A7F, LineBreak::Cm),
+    (0x1A80, 0x1A89, LineBreak::Nu),
+    (0x1A90, 0x1A99, LineBreak::Nu),
+    (0x1AB0, 0x1ACE, LineBreak::Cm),
+    (0x1B00, 0x1B04, LineBreak::Cm),
+    (0x1B34, 0x1B44, LineBreak::Cm),
+    (0x1B50, 0x1B59, LineBreak::Nu),
+    (0x1B5A, 0x1B5B, LineBreak::Ba),
+    (0x1B5D, 0x1B60, LineBreak::Ba),
+    (0x1B6B, 0x1B73, LineBreak::Cm),
+    (0x1B7D, 0x1B7E, LineBreak::Ba),
+    (0x1B80, 0x1B82, LineBreak::Cm),
+    (0x1BA1, 0x1BAD, LineBreak::Cm),
+    (0x1BB0, 0x1BB9, LineBreak::Nu),
+    (0x1BE6, 0x1BF3, LineBreak::Cm),
+    (0x1C24, 0x1C37, LineBreak::Cm),
+    (0x1C3B, 0x1C3F, LineBreak::Ba),
+    (0x1C40, 0x1C49, LineBreak::Nu),
+    (0x1C50, 0x1C59, LineBreak::Nu),
+    (0x1C7E, 0x1C7F, LineBreak::Ba),
+    (0x1CD0, 0x1CD2, LineBreak::Cm),
+    (0x1CD4, 0x1CE8, LineBreak::Cm),
+    (0x1CED, 0x1CED, LineBreak::Cm),
+    (0x1CF4, 0x1CF4, LineBreak::Cm),
+    (0x1CF7, 0x1CF9, LineBreak::Cm),
+    (0x1DC0, 0x1DCC, LineBreak::Cm),
+    (0x1DCD, 0x1DCD, LineBreak::Gl),
+    (0x1DCE, 0x1DFB, LineBreak::Cm),
+    (0x1DFC, 0x1DFC, LineBreak::Gl),
+    (0x1DFD, 0x1DFF, LineBreak::Cm),
+    (0x1FFD, 0x1FFD, LineBreak::Bb),
+    (0x2000, 0x2006, LineBreak::Ba),
+    (0x2007, 0x2007, LineBreak::Gl),
+    (0x2008, 0x200A, LineBreak::Ba),
+    (0x200B, 0x200B, LineBreak::Zw),
+    (0x200C, 0x200C, LineBreak::Cm),
+    (0x200D, 0x200D, LineBreak::Zwj),
+    (0x200E, 0x200F, LineBreak::Cm),
+    (0x2010, 0x2010, LineBreak::Ba),
+    (0x2011, 0x2011, LineBreak::Gl),
+    (0x2012, 0x2013, LineBreak::Ba),
+    (0x2014, 0x2014, LineBreak::B2),
+    (0x2018, 0x2019, LineBreak::Qu),
+    (0x201A, 0x201A, LineBreak::Op),
+    (0x201B, 0x201D, LineBreak::Qu),
+    (0x201E, 0x201E, LineBreak::Op),
+    (0x201F, 0x201F, LineBreak::Qu),
+    (0x2024, 0x2026, LineBreak::In),
+    (0x2027, 0x2027, LineBreak::Ba),
+    (0x2028, 0x2029, LineBreak::Bk),
+    (0x202A, 0x202E, LineBreak::Cm),
+    (0x202F, 0x202F, LineBreak::Gl),
+    (0x2030, 0x2037, LineBreak::Po),
+    (0x2039, 0x203A, LineBreak::Qu),
+    (0x203C, 0x203D, LineBreak::Ns),
+    (0x2044, 0x2044, LineBreak::Is),
+    (0x2045, 0x2045, LineBreak::Op),
+    (0x2046, 0x2046, LineBreak::Cl),
+    (0x2047, 0x2049, LineBreak::Ns),
+    (0x2056, 0x2056, LineBreak::Ba),
+    (0x2057, 0x2057, LineBreak::Po),
+    (0x2058, 0x205B, LineBreak::Ba),
+    (0x205D, 0x205F, LineBreak::Ba),
+    (0x2060, 0x2060, LineBreak::Wj),
+    (0x2066, 0x206F, LineBreak::Cm),
+    (0x207D, 0x207D, LineBreak::Op),
+    (0x207E, 0x207E, LineBreak::Cl),
+    (0x208D, 0x208D, LineBreak::Op),
+    (0x208E, 0x208E, LineBreak::Cl),
+    (0x20A0, 0x20A6, LineBreak::Pr),
+    (0x20A7, 0x20A7, LineBreak::Po),
+    (0x20A8, 0x20B5, LineBreak::Pr),
+    (0x20B6, 0x20B6, LineBreak::Po),
+    (0x20B7, 0x20BA, LineBreak::Pr),
+    (0x20BB, 0x20BB, LineBreak::Po),
+    (0x20BC, 0x20BD, LineBreak::Pr),
+    (0x20BE, 0x20BE, LineBreak::Po),
+    (0x20BF, 0x20BF, LineBreak::Pr),
+    (0x20C0, 0x20C0, LineBreak::Po),
+    (0x20C1, 0x20CF, LineBreak::Pr),
+    (0x20D0, 0x20F0, LineBreak::Cm),
+    (0x2103, 0x2103, LineBreak::Po),
+    (0x2109, 0x2109, LineBreak::Po),
+    (0x2116, 0x2116, LineBreak::Pr),
+    (0x2212, 0x2213, LineBreak::Pr),
+    (0x22EF, 0x22EF, LineBreak::In),
+    (0x2308, 0x2308, LineBreak::Op),
+    (0x2309, 0x2309, LineBreak::Cl),
+    (0x230A, 0x230A, LineBreak::Op),
+    (0x230B, 0x230B, LineBreak::Cl),
+    (0x231A, 0x231B, LineBreak::Id),
+    (0x2329, 0x2329, LineBreak::Op),
+    (0x232A, 0x232A, LineBreak::Cl),
+    (0x23F0, 0x23F3, LineBreak::Id),
+    (0x2600, 0x2603, LineBreak::Id),
+    (0x2614, 0x2615, LineBreak::Id),
+    (0x2618, 0x2618, LineBreak::Id),
+    (0x261A, 0x261C, LineBreak::Id),
+    (0x261D, 0x261D, LineBreak::Eb),
+    (0x261E, 0x261F, LineBreak::Id),
+    (0x2639, 0x263B, LineBreak::Id),
+    (0x2668, 0x2668, LineBreak::Id),
+    (0x267F, 0x267F, LineBreak::Id),
+    (0x26BD, 0x26C8, LineBreak::Id),
+    (0x26CD, 0x26CD, LineBreak::Id),
+    (0x26CF, 0x26D1, LineBreak::Id),
+    (0x26D3, 0x26D4, LineBreak::Id),
+    (0x26D8, 0x26D9, LineBreak::Id),
+    (0x26DC, 0x26DC, LineBreak::Id),
+    (0x26DF, 0x26E1, LineBreak::Id),
+    (0x26EA, 0x26EA, LineBreak::Id),
+    (0x26F1, 0x26F5, LineBreak::Id),
+    (0x26F7, 0x26F8, LineBreak::Id),
+    (0x26F9, 0x26F9, LineBreak::Eb),
+    (0x26FA, 0x26FA, LineBreak::Id),
+    (0x26FD, 0x2704, LineBreak::Id),
+    (0x2708, 0x2709, LineBreak::Id),
+    (0x270A, 0x270D, LineBreak::Eb),
+    (0x275B, 0x2760, LineBreak::Qu),
+    (0x2762, 0x2763, LineBreak::Ex),
+    (0x2764, 0x2764, LineBreak::Id),
+    (0x2768, 0x2768, LineBreak::Op),
+    (0x2769, 0x2769, LineBreak::Cl),
+    (0x276A, 0x276A, LineBreak::Op),
+    (0x276B, 0x276B, LineBreak::Cl),
+    (0x276C, 0x276C, LineBreak::Op),
+    (0x276D, 0x276D, LineBreak::Cl),
+    (0x276E, 0x276E, LineBreak::Op),
+    (0x276F, 0x276F, LineBreak::Cl),
+    (0x2770, 0x2770, LineBreak::Op),
+    (0x2771, 0x2771, LineBreak::Cl),
+    (0x2772, 0x2772, LineBreak::Op),
+    (0x2773, 0x2773, LineBreak::Cl),
+    (0x2774, 0x2774, LineBreak::Op),
+    (0x2775, 0x2775, LineBreak::Cl),
+    (0x27C5, 0x27C5, LineBreak::Op),
+    (0x27C6, 0x27C6, LineBreak::Cl),
+    (0x27E6, 0x27E6, LineBreak::Op),
+    (0x27E7, 0x27E7, LineBreak::Cl),
+    (0x27E8, 0x27E8, LineBreak::Op),
+    (0x27E9, 0x27E9, LineBreak::Cl),
+    (0x27EA, 0x27EA, LineBreak::Op),
+    (0x27EB, 0x27EB, LineBreak::Cl),
+    (0x27EC, 0x27EC, LineBreak::Op),
+    (0x27ED, 0x27ED, LineBreak::Cl),
+    (0x27EE, 0x27EE, LineBreak::Op),
+    (0x27EF, 0x27EF, LineBreak::Cl),
+    (0x2983, 0x2983, LineBreak::Op),
+    (0x2984, 0x2984, LineBreak::Cl),
+    (0x2985, 0x2985, LineBreak::Op),
+    (0x2986, 0x2986, LineBreak::Cl),
+    (0x2987, 0x2987, LineBreak::Op),
+    (0x2988, 0x2988, LineBreak::Cl),
+    (0x2989, 0x2989, LineBreak::Op),
+    (0x298A, 0x298A, LineBreak::Cl),
+    (0x298B, 0x298B, LineBreak::Op),
+    (0x298C, 0x298C, LineBreak::Cl),
+    (0x298D, 0x298D, LineBreak::Op),
+    (0x298E, 0x298E, LineBreak::Cl),
+    (0x298F, 0x298F, LineBreak::Op),
+    (0x2990, 0x2990, LineBreak::Cl),
+    (0x2991, 0x2991, LineBreak::Op),
+    (0x2992, 0x2992, LineBreak::Cl),
+    (0x2993, 0x2993, LineBreak::Op),
+    (0x2994, 0x2994, LineBreak::Cl),
+    (0x2995, 0x2995, LineBreak::Op),
+    (0x2996, 0x2996, LineBreak::Cl),
+    (0x2997, 0x2997, LineBreak::Op),
+    (0x2998, 0x2998, LineBreak::Cl),
+    (0x29D8, 0x29D8, LineBreak::Op),
+    (0x29D9, 0x29D9, LineBreak::Cl),
+    (0x29DA, 0x29DA, LineBreak::Op),
+    (0x29DB, 0x29DB, LineBreak::Cl),
+    (0x29FC, 0x29FC, LineBreak::Op),
+    (0x29FD, 0x29FD, LineBreak::Cl),
+    (0x2CEF, 0x2CF1, LineBreak::Cm),
+    (0x2CF9, 0x2CF9, LineBreak::Ex),
+    (0x2CFA, 0x2CFC, LineBreak::Ba),
+    (0x2CFE, 0x2CFE, LineBreak::Ex),
+    (0x2CFF, 0x2CFF, LineBreak::Ba),
+    (0x2D70, 0x2D70, LineBreak::Ba),
+    (0x2D7F, 0x2D7F, LineBreak::Cm),
+    (0x2DE0, 0x2DFF, LineBreak::Cm),
+    (0x2E00, 0x2E0D, LineBreak::Qu),
+    (0x2E0E, 0x2E15, LineBreak::Ba),
+    (0x2E17, 0x2E17, LineBreak::Ba),
+    (0x2E18, 0x2E18, LineBreak::Op),
+    (0x2E19, 0x2E19, LineBreak::Ba),
+    (0x2E1C, 0x2E1D, LineBreak::Qu),
+    (0x2E20, 0x2E21, LineBreak::Qu),
+    (0x2E22, 0x2E22, LineBreak::Op),
+    (0x2E23, 0x2E23, LineBreak::Cl),
+    (0x2E24, 0x2E24, LineBreak::Op),
+    (0x2E25, 0x2E25, LineBreak::Cl),
+    (0x2E26, 0x2E26, LineBreak::Op),
+    (0x2E27, 0x2E27, LineBreak::Cl),
+    (0x2E28, 0x2E28, LineBreak::Op),
+    (0x2E29, 0x2E29, LineBreak::Cl),
+    (0x2E2A, 0x2E2D, LineBreak::Ba),
+    (0x2E2E, 0x2E2E, LineBreak::Ex),
+    (0x2E30, 0x2E31, LineBreak::Ba),
+    (0x2E33, 0x2E34, LineBreak::Ba),
+    (0x2E3A, 0x2E3B, LineBreak::B2),
+    (0x2E3C, 0x2E3E, LineBreak::Ba),
+    (0x2E40, 0x2E41, LineBreak::Ba),
+    (0x2E42, 0x2E42, LineBreak::Op),
+    (0x2E43, 0x2E4A, LineBreak::Ba),
+    (0x2E4C, 0x2E4C, LineBreak::Ba),
+    (0x2E4E, 0x2E4F, LineBreak::Ba),
+    (0x2E53, 0x2E54, LineBreak::Ex),
+    (0x2E55, 0x2E55, LineBreak::Op),
+    (0x2E56, 0x2E56, LineBreak::Cl),
+    (0x2E57, 0x2E57, LineBreak::Op),
+    (0x2E58, 0x2E58, LineBreak::Cl),
+    (0x2E59, 0x2E59, LineBreak::Op),
+    (0x2E5A, 0x2E5A, LineBreak::Cl),
+    (0x2E5B, 0x2E5B, LineBreak::Op),
+    (0x2E5C, 0x2E5C, LineBreak::Cl),
+    (0x2E5D, 0x2E5D, LineBreak::Ba),
+    (0x2E80, 0x2E99, LineBreak::Id),
+    (0x2E9B, 0x2EF3, LineBreak::Id),
+    (0x2F00, 0x2FD5, LineBreak::Id),
+    (0x2FF0, 0x2FFB, LineBreak::Id),
+    (0x3000, 0x3000, LineBreak::Ba),
+    (0x3001, 0x3002, LineBreak::Cl),
+    (0x3003, 0x3004, LineBreak::Id),
+    (0x3005, 0x3005, LineBreak::Ns),
+    (0x3006, 0x3007, LineBreak::Id),
+    (0x3008, 0x3008, LineBreak::Op),
+    (0x3009, 0x3009, LineBreak::Cl),
+    (0x300A, 0x300A, LineBreak::Op),
+    (0x300B, 0x300B, LineBreak::Cl),
+    (0x300C, 0x300C, LineBreak::Op),
+    (0x300D, 0x300D, LineBreak::Cl),
+    (0x300E, 0x300E, LineBreak::Op),
+    (0x300F, 0x300F, LineBreak::Cl),
+    (0x3010, 0x3010, LineBreak::Op),
+    (0x3011, 0x3011, LineBreak::Cl),
+    (0x3012, 0x3013, LineBreak::Id),
+    (0x3014, 0x3014, LineBreak::Op),
+    (0x3015, 0x3015, LineBreak::Cl),
+    (0x3016, 0x3016, LineBreak::Op),
+    (0x3017, 0x3017, LineBreak::Cl),
+    (0x3018, 0x3018, LineBreak::Op),
+    (0x3019, 0x3019, LineBreak::Cl),
+    (0x301A, 0x301A, LineBreak::Op),
+    (0x301B, 0x301B, LineBreak::Cl),
+    (0x301C, 0x301C, LineBreak::Ns),
+    (0x301D, 0x301D, LineBreak::Op),
+    (0x301E, 0x301F, LineBreak::Cl),
+    (0x3020, 0x3029, LineBreak::Id),
+    (0x302A, 0x302F, LineBreak::Cm),
+    (0x3030, 0x3034, LineBreak::Id),
+    (0x3035, 0x3035, LineBreak::Cm),
+    (0x3036, 0x303A, LineBreak::Id),
+    (0x303B, 0x303C, LineBreak::Ns),
+    (0x303D, 0x303F, LineBreak::Id),
+    (0x3041, 0x3041, LineBreak::Ns),
+    (0x3042, 0x3042, LineBreak::Id),
+    (0x3043, 0x3043, LineBreak::Ns),
+    (0x3044, 0x3044, LineBreak::Id),
+    (0x3045, 0x3045, LineBreak::Ns),
+    (0x3046, 0x3046, LineBreak::Id),
+    (0x3047, 0x3047, LineBreak::Ns),
+    (0x3048, 0x3048, LineBreak::Id),
+    (0x3049, 0x3049, LineBreak::Ns),
+    (0x304A, 0x3062, LineBreak::Id),
+    (0x3063, 0x3063, LineBreak::Ns),
+    (0x3064, 0x3082, LineBreak::Id),
+    (0x3083, 0x3083, LineBreak::Ns),
+    (0x3084, 0x3084, LineBreak::Id),
+    (0x3085, 0x3085, LineBreak::Ns),
+    (0x3086, 0x3086, LineBreak::Id),
+    (0x3087, 0x3087, LineBreak::Ns),
+    (0x3088, 0x308D, LineBreak::Id),
+    (0x308E, 0x308E, LineBreak::Ns),
+    (0x308F, 0x3094, LineBreak::Id),
+    (0x3095, 0x3096, LineBreak::Ns),
+    (0x3099, 0x309A, LineBreak::Cm),
+    (0x309B, 0x309E, LineBreak::Ns),
+    (0x309F, 0x309F, LineBreak::Id),
+    (0x30A0, 0x30A1, LineBreak::Ns),
+    (0x30A2, 0x30A2, LineBreak::Id),
+    (0x30A3, 0x30A3, LineBreak::Ns),
+    (0x30A4, 0x30A4, LineBreak::Id),
+    (0x30A5, 0x30A5, LineBreak::Ns),
+    (0x30A6, 0x30A6, LineBreak::Id),
+    (0x30A7, 0x30A7, LineBreak::Ns),
+    (0x30A8, 0x30A8, LineBreak::Id),
+    (0x30A9, 0x30A9, LineBreak::Ns),
+    (0x30AA, 0x30C2, LineBreak::Id),
+    (0x30C3, 0x30C3, LineBreak::Ns),
+    (0x30C4, 0x30E2, LineBreak::Id),
+    (0x30E3, 0x30E3, LineBreak::Ns),
+    (0x30E4, 0x30E4, LineBreak::Id),
+    (0x30E5, 0x30E5, LineBreak::Ns),
+    (0x30E6, 0x30E6, LineBreak::Id),
+    (0x30E7, 0x30E7, LineBreak::Ns),
+    (0x30E8, 0x30ED, LineBreak::Id),
+    (0x30EE, 0x30EE, LineBreak::Ns),
+    (0x30EF, 0x30F4, LineBreak::Id),
+    (0x30F5, 0x30F6, LineBreak::Ns),
+    (0x30F7, 0x30FA, LineBreak::Id),
+    (0x30FB, 0x30FE, LineBreak::Ns),
+    (0x30FF, 0x30FF, LineBreak::Id),
+    (0x3105, 0x312F, LineBreak::Id),
+    (0x3131, 0x318E, LineBreak::Id),
+    (0x3190, 0x31E3, LineBreak::Id),
+    (0x31F0, 0x31FF, LineBreak::Ns),
+    (0x3200, 0x321E, LineBreak::Id),
+    (0x3220, 0x3247, LineBreak::Id),
+    (0x3250, 0x4DBF, LineBreak::Id),
+    (0x4E00, 0xA014, LineBreak::Id),
+    (0xA015, 0xA015, LineBreak::Ns),
+    (0xA016, 0xA48C, LineBreak::Id),
+    (0xA490, 0xA4C6, LineBreak::Id),
+    (0xA4FE, 0xA4FF, LineBreak::Ba),
+    (0xA60D, 0xA60D, LineBreak::Ba),
+    (0xA60E, 0xA60E, LineBreak::Ex),
+    (0xA60F, 0xA60F, LineBreak::Ba),
+    (0xA620, 0xA629, LineBreak::Nu),
+    (0xA66F, 0xA672, LineBreak::Cm),
+    (0xA674, 0xA67D, LineBreak::Cm),
+    (0xA69E, 0xA69F, LineBreak::Cm),
+    (0xA6F0, 0xA6F1, LineBreak::Cm),
+    (0xA6F3, 0xA6F7, LineBreak::Ba),
+    (0xA802, 0xA802, LineBreak::Cm),
+    (0xA806, 0xA806, LineBreak::Cm),
+    (0xA80B, 0xA80B, LineBreak::Cm),
+    (0xA823, 0xA827, LineBreak::Cm),
+    (0xA82C, 0xA82C, LineBreak::Cm),
+    (0xA838, 0xA838, LineBreak::Po),
+    (0xA874, 0xA875, LineBreak::Bb),
+    (0xA876, 0xA877, LineBreak::Ex),
+    (0xA880, 0xA881, LineBreak::Cm),
+    (0xA8B4, 0xA8C5, LineBreak::Cm),
+    (0xA8CE, 0xA8CF, LineBreak::Ba),
+    (0xA8D0, 0xA8D9, LineBreak::Nu),
+    (0xA8E0, 0xA8F1, LineBreak::Cm),
+    (0xA8FC, 0xA8FC, LineBreak::Bb),
+    (0xA8FF, 0xA8FF, LineBreak::Cm),
+    (0xA900, 0xA909, LineBreak::Nu),
+    (0xA926, 0xA92D, LineBreak::Cm),
+    (0xA92E, 0xA92F, LineBreak::Ba),
+    (0xA947, 0xA953, LineBreak::Cm),
+    (0xA960, 0xA97C, LineBreak::Jl),
+    (0xA980, 0xA983, LineBreak::Cm),
+    (0xA9B3, 0xA9C0, LineBreak::Cm),
+    (0xA9C7, 0xA9C9, LineBreak::Ba),
+    (0xA9D0, 0xA9D9, LineBreak::Nu),
+    (0xA9E5, 0xA9E5, LineBreak::Cm),
+    (0xA9F0, 0xA9F9, LineBreak::Nu),
+    (0xAA29, 0xAA36, LineBreak::Cm),
+    (0xAA43, 0xAA43, LineBreak::Cm),
+    (0xAA4C, 0xAA4D, LineBreak::Cm),
+    (0xAA50, 0xAA59, LineBreak::Nu),
+    (0xAA5D, 0xAA5F, LineBreak::Ba),
+    (0xAA7B, 0xAA7D, LineBreak::Cm),
+    (0xAAB0, 0xAAB0, LineBreak::Cm),
+    (0xAAB2, 0xAAB4, LineBreak::Cm),
+    (0xAAB7, 0xAAB8, LineBreak::Cm),
+    (0xAABE, 0xAABF, LineBreak::Cm),
+    (0xAAC1, 0xAAC1, LineBreak::Cm),
+    (0xAAEB, 0xAAEF, LineBreak::Cm),
+    (0xAAF0, 0xAAF1, LineBreak::Ba),
+    (0xAAF5, 0xAAF6, LineBreak::Cm),
+    (0xABE3, 0xABEA, LineBreak::Cm),
+    (0xABEB, 0xABEB, LineBreak::Ba),
+    (0xABEC, 0xABED, LineBreak::Cm),
+    (0xABF0, 0xABF9, LineBreak::Nu),
+    (0xAC00, 0xAC00, LineBreak::H2),
+    (0xAC01, 0xAC1B, LineBreak::H3),
+    (0xAC1C, 0xAC1C, LineBreak::H2),
+    (0xAC1D, 0xAC37, LineBreak::H3),
+    (0xAC38, 0xAC38, LineBreak::H2),
+    (0xAC39, 0xAC53, LineBreak::H3),
+    (0xAC54, 0xAC54, LineBreak::H2),
+    (0xAC55, 0xAC6F, LineBreak::H3),
+    (0xAC70, 0xAC70, LineBreak::H2),
+    (0xAC71, 0xAC8B, LineBreak::H3),
+    (0xAC8C, 0xAC8C, LineBreak::H2),
+    (0xAC8D, 0xACA7, LineBreak::H3),
+    (0xACA8, 0xACA8, LineBreak::H2),
+    (0xACA9, 0xACC3, LineBreak::H3),
+    (0xACC4, 0xACC4, LineBreak::H2),
+    (0xACC5, 0xACDF, LineBreak::H3),
+    (0xACE0, 0xACE0, LineBreak::H2),
+    (0xACE1, 0xACFB, LineBreak::H3),
+    (0xACFC, 0xACFC, LineBreak::H2),
+    (0xACFD, 0xAD17, LineBreak::H3),
+    (0xAD18, 0xAD18, LineBreak::H2),
+    (0xAD19, 0xAD33, LineBreak::H3),
+    (0xAD34, 0xAD34, LineBreak::H2),
+    (0xAD35, 0xAD4F, LineBreak::H3),
+    (0xAD50, 0xAD50, LineBreak::H2),
+    (0xAD51, 0xAD6B, LineBreak::H3),
+    (0xAD6C, 0xAD6C, LineBreak::H2),
+    (0xAD6D, 0xAD87, LineBreak::H3),
+    (0xAD88, 0xAD88, LineBreak::H2),
+    (0xAD89, 0xADA3, LineBreak::H3),
+    (0xADA4, 0xADA4, LineBreak::H2),
+    (0xADA5, 0xADBF, LineBreak::H3),
+    (0xADC0, 0xADC0, LineBreak::H2),
+    (0xADC1, 0xADDB, LineBreak::H3),
+    (0xADDC, 0xADDC, LineBreak::H2),
+    (0xADDD, 0xADF7, LineBreak::H3),
+    (0xADF8, 0xADF8, LineBreak::H2),
+    (0xADF9, 0xAE13, LineBreak::H3),
+    (0xAE14, 0xAE14, LineBreak::H2),
+    (0xAE15, 0xAE2F, LineBreak::H3),
+    (0xAE30, 0xAE30, LineBreak::H2),
+    (0xAE31, 0xAE4B, LineBreak::H3),
+    (0xAE4C, 0xAE4C, LineBreak::H2),
+    (0xAE4D, 0xAE67, LineBreak::H3),
+    (0xAE68, 0xAE68, LineBreak::H2),
+    (0xAE69, 0xAE83, LineBreak::H3),
+    (0xAE84, 0xAE84, LineBreak::H2),
+    (0xAE85, 0xAE9F, LineBreak::H3),
+    (0xAEA0, 0xAEA0, LineBreak::H2),
+    (0xAEA1, 0xAEBB, LineBreak::H3),
+    (0xAEBC, 0xAEBC, LineBreak::H2),
+    (0xAEBD, 0xAED7, LineBreak::H3),
+    (0xAED8, 0xAED8, LineBreak::H2),
+    (0xAED9, 0xAEF3, LineBreak::H3),
+    (0xAEF4, 0xAEF4, LineBreak::H2),
+    (0xAEF5, 0xAF0F, LineBreak::H3),
+    (0xAF10, 0xAF10, LineBreak::H2),
+    (0xAF11, 0xAF2B, LineBreak::H3),
+    (0xAF2C, 0xAF2C, LineBreak::H2),
+    (0xAF2D, 0xAF47, LineBreak::H3),
+    (0xAF48, 0xAF48, LineBreak::H2),
+    (0xAF49, 0xAF63, LineBreak::H3),
+    (0xAF64, 0xAF64, LineBreak::H2),
+    (0xAF65, 0xAF7F, LineBreak::H3),
+    (0xAF80, 0xAF80, LineBreak::H2),
+    (0xAF81, 0xAF9B, LineBreak::H3),
+    (0xAF9C, 0xAF9C, LineBreak::H2),
+    (0xAF9D, 0xAFB7, LineBreak::H3),
+    (0xAFB8, 0xAFB8, LineBreak::H2),
+    (0xAFB9, 0xAFD3, LineBreak::H3),
+    (0xAFD4, 0xAFD4, LineBreak::H2),
+    (0xAFD5, 0xAFEF, LineBreak::H3),
+    (0xAFF0, 0xAFF0, LineBreak::H2),
+    (0xAFF1, 0xB00B, LineBreak::H3),
+    (0xB00C, 0xB00C, LineBreak::H2),
+    (0xB00D, 0xB027, LineBreak::H3),
+    (0xB028, 0xB028, LineBreak::H2),
+    (0xB029, 0xB043, LineBreak::H3),
+    (0xB044, 0xB044, LineBreak::H2),
+    (0xB045, 0xB05F, LineBreak::H3),
+    (0xB060, 0xB060, LineBreak::H2),
+    (0xB061, 0xB07B, LineBreak::H3),
+    (0xB07C, 0xB07C, LineBreak::H2),
+    (0xB07D, 0xB097, LineBreak::H3),
+    (0xB098, 0xB098, LineBreak::H2),
+    (0xB099, 0xB0B3, LineBreak::H3),
+    (0xB0B4, 0xB0B4, LineBreak::H2),
+    (0xB0B5, 0xB0CF, LineBreak::H3),
+    (0xB0D0, 0xB0D0, LineBreak::H2),
+    (0xB0D1, 0xB0EB, LineBreak::H3),
+    (0xB0EC, 0xB0EC, LineBreak::H2),
+    (0xB0ED, 0xB107, LineBreak::H3),
+    (0xB108, 0xB108, LineBreak::H2),
+    (0xB109, 0xB123, LineBreak::H3),
+    (0xB124, 0xB124, LineBreak::H2),
+    (0xB125, 0xB13F, LineBreak::H3),
+    (0xB140, 0xB140, LineBreak::H2),
+    (0xB141, 0xB15B, LineBreak::H3),
+    (0xB15C, 0xB15C, LineBreak::H2),
+    (0xB15D, 0xB177, LineBreak::H3),
+    (0xB178, 0xB178, LineBreak::H2),
+    (0xB179, 0xB193, LineBreak::H3),
+    (0xB194, 0xB194, LineBreak::H2),
+    (0xB195, 0xB1AF, LineBreak::H3),
+    (0xB1B0, 0xB1B0, LineBreak::H2),
+    (0xB1B1, 0xB1CB, LineBreak::H3),
+    (0xB1CC, 0xB1CC, LineBreak::H2),
+    (0xB1CD, 0xB1E7, LineBreak::H3),
+    (0xB1E8, 0xB1E8, LineBreak::H2),
+    (0xB1E9, 0xB203, LineBreak::H3),
+    (0xB204, 0xB204, LineBreak::H2),
+    (0xB205, 0xB21F, LineBreak::H3),
+    (0xB220, 0xB220, LineBreak::H2),
+    (0xB221, 0xB23B, LineBreak::H3),
+    (0xB23C, 0xB23C, LineBreak::H2),
+    (0xB23D, 0xB257, LineBreak::H3),
+    (0xB258, 0xB258, LineBreak::H2),
+    (0xB259, 0xB273, LineBreak::H3),
+    (0xB274, 0xB274, LineBreak::H2),
+    (0xB275, 0xB28F, LineBreak::H3),
+    (0xB290, 0xB290, LineBreak::H2),
+    (0xB291, 0xB2AB, LineBreak::H3),
+    (0xB2AC, 0xB2AC, LineBreak::H2),
+    (0xB2AD, 0xB2C7, LineBreak::H3),
+    (0xB2C8, 0xB2C8, LineBreak::H2),
+    (0xB2C9, 0xB2E3, LineBreak::H3),
+    (0xB2E4, 0xB2E4, LineBreak::H2),
+    (0xB2E5, 0xB2FF, LineBreak::H3),
+    (0xB300, 0xB300, LineBreak::H2),
+    (0xB301, 0xB31B, LineBreak::H3),
+    (0xB31C, 0xB31C, LineBreak::H2),
+    (0xB31D, 0xB337, LineBreak::H3),
+    (0xB338, 0xB338, LineBreak::H2),
+    (0xB339, 0xB353, LineBreak::H3),
+    (0xB354, 0xB354, LineBreak::H2),
+    (0xB355, 0xB36F, LineBreak::H3),
+    (0xB370, 0xB370, LineBreak::H2),
+    (0xB371, 0xB38B, LineBreak::H3),
+    (0xB38C, 0xB38C, LineBreak::H2),
+    (0xB38D, 0xB3A7, LineBreak::H3),
+    (0xB3A8, 0xB3A8, LineBreak::H2),
+    (0xB3A9, 0xB3C3, LineBreak::H3),
+    (0xB3C4, 0xB3C4, LineBreak::H2),
+    (0xB3C5, 0xB3DF, LineBreak::H3),
+    (0xB3E0, 0xB3E0, LineBreak::H2),
+    (0xB3E1, 0xB3FB, LineBreak::H3),
+    (0xB3FC, 0xB3FC, LineBreak::H2),
+    (0xB3FD, 0xB417, LineBreak::H3),
+    (0xB418, 0xB418, LineBreak::H2),
+    (0xB419, 0xB433, LineBreak::H3),
+    (0xB434, 0xB434, LineBreak::H2),
+    (0xB435, 0xB44F, LineBreak::H3),
+    (0xB450, 0xB450, LineBreak::H2),
+    (0xB451, 0xB46B, LineBreak::H3),
+    (0xB46C, 0xB46C, LineBreak::H2),
+    (0xB46D, 0xB487, LineBreak::H3),
+    (0xB488, 0xB488, LineBreak::H2),
+    (0xB489, 0xB4A3, LineBreak::H3),
+    (0xB4A4, 0xB4A4, LineBreak::H2),
+    (0xB4A5, 0xB4BF, LineBreak::H3),
+    (0xB4C0, 0xB4C0, LineBreak::H2),
+    (0xB4C1, 0xB4DB, LineBreak::H3),
+    (0xB4DC, 0xB4DC, LineBreak::H2),
+    (0xB4DD, 0xB4F7, LineBreak::H3),
+    (0xB4F8, 0xB4F8, LineBreak::H2),
+    (0xB4F9, 0xB513, LineBreak::H3),
+    (0xB514, 0xB514, LineBreak::H2),
+    (0xB515, 0xB52F, LineBreak::H3),
+    (0xB530, 0xB530, LineBreak::H2),
+    (0xB531, 0xB54B, LineBreak::H3),
+    (0xB54C, 0xB54C, LineBreak::H2),
+    (0xB54D, 0xB567, LineBreak::H3),
+    (0xB568, 0xB568, LineBreak::H2),
+    (0xB569, 0xB583, LineBreak::H3),
+    (0xB584, 0xB584, LineBreak::H2),
+    (0xB585, 0xB59F, LineBreak::H3),
+    (0xB5A0, 0xB5A0, LineBreak::H2),
+    (0xB5A1, 0xB5BB, LineBreak::H3),
+    (0xB5BC, 0xB5BC, LineBreak::H2),
+    (0xB5BD, 0xB5D7, LineBreak::H3),
+    (0xB5D8, 0xB5D8, LineBreak::H2),
+    (0xB5D9, 0xB5F3, LineBreak::H3),
+    (0xB5F4, 0xB5F4, LineBreak::H2),
+    (0xB5F5, 0xB60F, LineBreak::H3),
+    (0xB610, 0xB610, LineBreak::H2),
+    (0xB611, 0xB62B, LineBreak::H3),
+    (0xB62C, 0xB62C, LineBreak::H2),
+    (0xB62D, 0xB647, LineBreak::H3),
+    (0xB648, 0xB648, LineBreak::H2),
+    (0xB649, 0xB663, LineBreak::H3),
+    (0xB664, 0xB664, LineBreak::H2),
+    (0xB665, 0xB67F, LineBreak::H3),
+    (0xB680, 0xB680, LineBreak::H2),
+    (0xB681, 0xB69B, LineBreak::H3),
+    (0xB69C, 0xB69C, LineBreak::H2),
+    (0xB69D, 0xB6B7, LineBreak::H3),
+    (0xB6B8, 0xB6B8, LineBreak::H2),
+    (0xB6B9, 0xB6D3, LineBreak::H3),
+    (0xB6D4, 0xB6D4, LineBreak::H2),
+    (0xB6D5, 0xB6EF, LineBreak::H3),
+    (0xB6F0, 0xB6F0, LineBreak::H2),
+    (0xB6F1, 0xB70B, LineBreak::H3),
+    (0xB70C, 0xB70C, LineBreak::H2),
+    (0xB70D, 0xB727, LineBreak::H3),
+    (0xB728, 0xB728, LineBreak::H2),
+    (0xB729, 0xB743, LineBreak::H3),
+    (0xB744, 0xB744, LineBreak::H2),
+    (0xB745, 0xB75F, LineBreak::H3),
+    (0xB760, 0xB760, LineBreak::H2),
+    (0xB761, 0xB77B, LineBreak::H3),
+    (0xB77C, 0xB77C, LineBreak::H2),
+    (0xB77D, 0xB797, LineBreak::H3),
+    (0xB798, 0xB798, LineBreak::H2),
+    (0xB799, 0xB7B3, LineBreak::H3),
+    (0xB7B4, 0xB7B4, LineBreak::H2),
+    (0xB7B5, 0xB7CF, LineBreak::H3),
+    (0xB7D0, 0xB7D0, LineBreak::H2),
+    (0xB7D1, 0xB7EB, LineBreak::H3),
+    (0xB7EC, 0xB7EC, LineBreak::H2),
+    (0xB7ED, 0xB807, LineBreak::H3),
+    (0xB808, 0xB808, LineBreak::H2),
+    (0xB809, 0xB823, LineBreak::H3),
+    (0xB824, 0xB824, LineBreak::H2),
+    (0xB825, 0xB83F, LineBreak::H3),
+    (0xB840, 0xB840, LineBreak::H2),
+    (0xB841, 0xB85B, LineBreak::H3),
+    (0xB85C, 0xB85C, LineBreak::H2),
+    (0xB85D, 0xB877, LineBreak::H3),
+    (0xB878, 0xB878, LineBreak::H2),
+    (0xB879, 0xB893, LineBreak::H3),
+    (0xB894, 0xB894, LineBreak::H2),
+    (0xB895, 0xB8AF, LineBreak::H3),
+    (0xB8B0, 0xB8B0, LineBreak::H2),
+    (0xB8B1, 0xB8CB, LineBreak::H3),
+    (0xB8CC, 0xB8CC, LineBreak::H2),
+    (0xB8CD, 0xB8E7, LineBreak::H3),
+    (0xB8E8, 0xB8E8, LineBreak::H2),
+    (0xB8E9, 0xB903, LineBreak::H3),
+    (0xB904, 0xB904, LineBreak::H2),
+    (0xB905, 0xB91F, LineBreak::H3),
+    (0xB920, 0xB920, LineBreak::H2),
+    (0xB921, 0xB93B, LineBreak::H3),
+    (0xB93C, 0xB93C, LineBreak::H2),
+    (0xB93D, 0xB957, LineBreak::H3),
+    (0xB958, 0xB958, LineBreak::H2),
+    (0xB959, 0xB973, LineBreak::H3),
+    (0xB974, 0xB974, LineBreak::H2),
+    (0xB975, 0xB98F, LineBreak::H3),
+    (0xB990, 0xB990, LineBreak::H2),
+    (0xB991, 0xB9AB, LineBreak::H3),
+    (0xB9AC, 0xB9AC, LineBreak::H2),
+    (0xB9AD, 0xB9C7, LineBreak::H3),
+    (0xB9C8, 0xB9C8, LineBreak::H2),
+    (0xB9C9, 0xB9E3, LineBreak::H3),
+    (0xB9E4, 0xB9E4, LineBreak::H2),
+    (0xB9E5, 0xB9FF, LineBreak::H3),
+    (0xBA00, 0xBA00, LineBreak::H2),
+    (0xBA01, 0xBA1B, LineBreak::H3),
+    (0xBA1C, 0xBA1C, LineBreak::H2),
+    (0xBA1D, 0xBA37, LineBreak::H3),
+    (0xBA38, 0xBA38, LineBreak::H2),
+    (0xBA39, 0xBA53, LineBreak::H3),
+    (0xBA54, 0xBA54, LineBreak::H2),
+    (0xBA55, 0xBA6F, LineBreak::H3),
+    (0xBA70, 0xBA70, LineBreak::H2),
+    (0xBA71, 0xBA8B, LineBreak::H3),
+    (0xBA8C, 0xBA8C, LineBreak::H2),
+    (0xBA8D, 0xBAA7, LineBreak::H3),
+    (0xBAA8, 0xBAA8, LineBreak::H2),
+    (0xBAA9, 0xBAC3, LineBreak::H3),
+    (0xBAC4, 0xBAC4, LineBreak::H2),
+    (0xBAC5, 0xBADF, LineBreak::H3),
+    (0xBAE0, 0xBAE0, LineBreak::H2),
+    (0xBAE1, 0xBAFB, LineBreak::H3),
+    (0xBAFC, 0xBAFC, LineBreak::H2),
+    (0xBAFD, 0xBB17, LineBreak::H3),
+    (0xBB18, 0xBB18, LineBreak::H2),
+    (0xBB19, 0xBB33, LineBreak::H3),
+    (0xBB34, 0xBB34, LineBreak::H2),
+    (0xBB35, 0xBB4F, LineBreak::H3),
+    (0xBB50, 0xBB50, LineBreak::H2),
+    (0xBB51, 0xBB6B, LineBreak::H3),
+    (0xBB6C, 0xBB6C, LineBreak::H2),
+    (0xBB6D, 0xBB87, LineBreak::H3),
+    (0xBB88, 0xBB88, LineBreak::H2),
+    (0xBB89, 0xBBA3, LineBreak::H3),
+    (0xBBA4, 0xBBA4, LineBreak::H2),
+    (0xBBA5, 0xBBBF, LineBreak::H3),
+    (0xBBC0, 0xBBC0, LineBreak::H2),
+    (0xBBC1, 0xBBDB, LineBreak::H3),
+    (0xBBDC, 0xBBDC, LineBreak::H2),
+    (0xBBDD, 0xBBF7, LineBreak::H3),
+    (0xBBF8, 0xBBF8, LineBreak::H2),
+    (0xBBF9, 0xBC13, LineBreak::H3),
+    (0xBC14, 0xBC14, LineBreak::H2),
+    (0xBC15, 0xBC2F, LineBreak::H3),
+    (0xBC30, 0xBC30, LineBreak::H2),
+    (0xBC31, 0xBC4B, LineBreak::H3),
+    (0xBC4C, 0xBC4C, LineBreak::H2),
+    (0xBC4D, 0xBC67, LineBreak::H3),
+    (0xBC68, 0xBC68, LineBreak::H2),
+    (0xBC69, 0xBC83, LineBreak::H3),
+    (0xBC84, 0xBC84, LineBreak::H2),
+    (0xBC85, 0xBC9F, LineBreak::H3),
+    (0xBCA0, 0xBCA0, LineBreak::H2),
+    (0xBCA1, 0xBCBB, LineBreak::H3),
+    (0xBCBC, 0xBCBC, LineBreak::H2),
+    (0xBCBD, 0xBCD7, LineBreak::H3),
+    (0xBCD8, 0xBCD8, LineBreak::H2),
+    (0xBCD9, 0xBCF3, LineBreak::H3),
+    (0xBCF4, 0xBCF4, LineBreak::H2),
+    (0xBCF5, 0xBD0F, LineBreak::H3),
+    (0xBD10, 0xBD10, LineBreak::H2),
+    (0xBD11, 0xBD2B, LineBreak::H3),
+    (0xBD2C, 0xBD2C, LineBreak::H2),
+    (0xBD2D, 0xBD47, LineBreak::H3),
+    (0xBD48, 0xBD48, LineBreak::H2),
+    (0xBD49, 0xBD63, LineBreak::H3),
+    (0xBD64, 0xBD64, LineBreak::H2),
+    (0xBD65, 0xBD7F, LineBreak::H3),
+    (0xBD80, 0xBD80, LineBreak::H2),
+    (0xBD81, 0xBD9B, LineBreak::H3),
+    (0xBD9C, 0xBD9C, LineBreak::H2),
+    (0xBD9D, 0xBDB7, LineBreak::H3),
+    (0xBDB8, 0xBDB8, LineBreak::H2),
+    (0xBDB9, 0xBDD3, LineBreak::H3),
+    (0xBDD4, 0xBDD4, LineBreak::H2),
+    (0xBDD5, 0xBDEF, LineBreak::H3),
+    (0xBDF0, 0xBDF0, LineBreak::H2),
+    (0xBDF1, 0xBE0B, LineBreak::H3),
+    (0xBE0C, 0xBE0C, LineBreak::H2),
+    (0xBE0D, 0xBE27, LineBreak::H3),
+    (0xBE28, 0xBE28, LineBreak::H2),
+    (0xBE29, 0xBE43, LineBreak::H3),
+    (0xBE44, 0xBE44, LineBreak::H2),
+    (0xBE45, 0xBE5F, LineBreak::H3),
+    (0xBE60, 0xBE60, LineBreak::H2),
+    (0xBE61, 0xBE7B, LineBreak::H3),
+    (0xBE7C, 0xBE7C, LineBreak::H2),
+    (0xBE7D, 0xBE97, LineBreak::H3),
+    (0xBE98, 0xBE98, LineBreak::H2),
+    (0xBE99, 0xBEB3, LineBreak::H3),
+    (0xBEB4, 0xBEB4, LineBreak::H2),
+    (0xBEB5, 0xBECF, LineBreak::H3),
+    (0xBED0, 0xBED0, LineBreak::H2),
+    (0xBED1, 0xBEEB, LineBreak::H3),
+    (0xBEEC, 0xBEEC, LineBreak::H2),
+    (0xBEED, 0xBF07, LineBreak::H3),
+    (0xBF08, 0xBF08, LineBreak::H2),
+    (0xBF09, 0xBF23, LineBreak::H3),
+    (0xBF24, 0xBF24, LineBreak::H2),
+    (0xBF25, 0xBF3F, LineBreak::H3),
+    (0xBF40, 0xBF40, LineBreak::H2),
+    (0xBF41, 0xBF5B, LineBreak::H3),
+    (0xBF5C, 0xBF5C, LineBreak::H2),
+    (0xBF5D, 0xBF77, LineBreak::H3),
+    (0xBF78, 0xBF78, LineBreak::H2),
+    (0xBF79, 0xBF93, LineBreak::H3),
+    (0xBF94, 0xBF94, LineBreak::H2),
+    (0xBF95, 0xBFAF, LineBreak::H3),
+    (0xBFB0, 0xBFB0, LineBreak::H2),
+    (0xBFB1, 0xBFCB, LineBreak::H3),
+    (0xBFCC, 0xBFCC, LineBreak::H2),
+    (0xBFCD, 0xBFE7, LineBreak::H3),
+    (0xBFE8, 0xBFE8, LineBreak::H2),
+    (0xBFE9, 0xC003, LineBreak::H3),
+    (0xC004, 0xC004, LineBreak::H2),
+    (0xC005, 0xC01F, LineBreak::H3),
+    (0xC020, 0xC020, LineBreak::H2),
+    (0xC021, 0xC03B, LineBreak::H3),
+    (0xC03C, 0xC03C, LineBreak::H2),
+    (0xC03D, 0xC057, LineBreak::H3),
+    (0xC058, 0xC058, LineBreak::H2),
+    (0xC059, 0xC073, LineBreak::H3),
+    (0xC074, 0xC074, LineBreak::H2),
+    (0xC075, 0xC08F, LineBreak::H3),
+    (0xC090, 0xC090, LineBreak::H2),
+    (0xC091, 0xC0AB, LineBreak::H3),
+    (0xC0AC, 0xC0AC, LineBreak::H2),
+    (0xC0AD, 0xC0C7, LineBreak::H3),
+    (0xC0C8, 0xC0C8, LineBreak::H2),
+    (0xC0C9, 0xC0E3, LineBreak::H3),
+    (0xC0E4, 0xC0E4, LineBreak::H2),
+    (0xC0E5, 0xC0FF, LineBreak::H3),
+    (0xC100, 0xC100, LineBreak::H2),
+    (0xC101, 0xC11B, LineBreak::H3),
+    (0xC11C, 0xC11C, LineBreak::H2),
+    (0xC11D, 0xC137, LineBreak::H3),
+    (0xC138, 0xC138, LineBreak::H2),
+    (0xC139, 0xC153, LineBreak::H3),
+    (0xC154, 0xC154, LineBreak::H2),
+    (0xC155, 0xC16F, LineBreak::H3),
+    (0xC170, 0xC170, LineBreak::H2),
+    (0xC171, 0xC18B, LineBreak::H3),
+    (0xC18C, 0xC18C, LineBreak::H2),
+    (0xC18D, 0xC1A7, LineBreak::H3),
+    (0xC1A8, 0xC1A8, LineBreak::H2),
+    (0xC1A9, 0xC1C3, LineBreak::H3),
+    (0xC1C4, 0xC1C4, LineBreak::H2),
+    (0xC1C5, 0xC1DF, LineBreak::H3),
+    (0xC1E0, 0xC1E0, LineBreak::H2),
+    (0xC1E1, 0xC1FB, LineBreak::H3),
+    (0xC1FC, 0xC1FC, LineBreak::H2),
+    (0xC1FD, 0xC217, LineBreak::H3),
+    (0xC218, 0xC218, LineBreak::H2),
+    (0xC219, 0xC233, LineBreak::H3),
+    (0xC234, 0xC234, LineBreak::H2),
+    (0xC235, 0xC24F, LineBreak::H3),
+    (0xC250, 0xC250, LineBreak::H2),
+    (0xC251, 0xC26B, LineBreak::H3),
+    (0xC26C, 0xC26C, LineBreak::H2),
+    (0xC26D, 0xC287, LineBreak::H3),
+    (0xC288, 0xC288, LineBreak::H2),
+    (0xC289, 0xC2A3, LineBreak::H3),
+    (0xC2A4, 0xC2A4, LineBreak::H2),
+    (0xC2A5, 0xC2BF, LineBreak::H3),
+    (0xC2C0, 0xC2C0, LineBreak::H2),
+    (0xC2C1, 0xC2DB, LineBreak::H3),
+    (0xC2DC, 0xC2DC, LineBreak::H2),
+    (0xC2DD, 0xC2F7, LineBreak::H3),
+    (0xC2F8, 0xC2F8, LineBreak::H2),
+    (0xC2F9, 0xC313, LineBreak::H3),
+    (0xC314, 0xC314, LineBreak::H2),
+    (0xC315, 0xC32F, LineBreak::H3),
+    (0xC330, 0xC330, LineBreak::H2),
+    (0xC331, 0xC34B, LineBreak::H3),
+    (0xC34C, 0xC34C, LineBreak::H2),
+    (0xC34D, 0xC367, LineBreak::H3),
+    (0xC368, 0xC368, LineBreak::H2),
+    (0xC369, 0xC383, LineBreak::H3),
+    (0xC384, 0xC384, LineBreak::H2),
+    (0xC385, 0xC39F, LineBreak::H3),
+    (0xC3A0, 0xC3A0, LineBreak::H2),
+    (0xC3A1, 0xC3BB, LineBreak::H3),
+    (0xC3BC, 0xC3BC, LineBreak::H2),
+    (0xC3BD, 0xC3D7, LineBreak::H3),
+    (0xC3D8, 0xC3D8, LineBreak::H2),
+    (0xC3D9, 0xC3F3, LineBreak::H3),
+    (0xC3F4, 0xC3F4, LineBreak::H2),
+    (0xC3F5, 0xC40F, LineBreak::H3),
+    (0xC410, 0xC410, LineBreak::H2),
+    (0xC411, 0xC42B, LineBreak::H3),
+    (0xC42C, 0xC42C, LineBreak::H2),
+    (0xC42D, 0xC447, LineBreak::H3),
+    (0xC448, 0xC448, LineBreak::H2),
+    (0xC449, 0xC463, LineBreak::H3),
+    (0xC464, 0xC464, LineBreak::H2),
+    (0xC465, 0xC47F, LineBreak::H3),
+    (0xC480, 0xC480, LineBreak::H2),
+    (0xC481, 0xC49B, LineBreak::H3),
+    (0xC49C, 0xC49C, LineBreak::H2),
+    (0xC49D, 0xC4B7, LineBreak::H3),
+    (0xC4B8, 0xC4B8, LineBreak::H2),
+    (0xC4B9, 0xC4D3, LineBreak::H3),
+    (0xC4D4, 0xC4D4, LineBreak::H2),
+    (0xC4D5, 0xC4EF, LineBreak::H3),
+    (0xC4F0, 0xC4F0, LineBreak::H2),
+    (0xC4F1, 0xC50B, LineBreak::H3),
+    (0xC50C, 0xC50C, LineBreak::H2),
+    (0xC50D, 0xC527, LineBreak::H3),
+    (0xC528, 0xC528, LineBreak::H2),
+    (0xC529, 0xC543, LineBreak::H3),
+    (0xC544, 0xC544, LineBreak::H2),
+    (0xC545, 0xC55F, LineBreak::H3),
+    (0xC560, 0xC560, LineBreak::H2),
+    (0xC561, 0xC57B, LineBreak::H3),
+    (0xC57C, 0xC57C, LineBreak::H2),
+    (0xC57D, 0xC597, LineBreak::H3),
+    (0xC598, 0xC598, LineBreak::H2),
+    (0xC599, 0xC5B3, LineBreak::H3),
+    (0xC5B4, 0xC5B4, LineBreak::H2),
+    (0xC5B5, 0xC5CF, LineBreak::H3),
+    (0xC5D0, 0xC5D0, LineBreak::H2),
+    (0xC5D1, 0xC5EB, LineBreak::H3),
+    (0xC5EC, 0xC5EC, LineBreak::H2),
+    (0xC5ED, 0xC607, LineBreak::H3),
+    (0xC608, 0xC608, LineBreak::H2),
+    (0xC609, 0xC623, LineBreak::H3),
+    (0xC624, 0xC624, LineBreak::H2),
+    (0xC625, 0xC63F, LineBreak::H3),
+    (0xC640, 0xC640, LineBreak::H2),
+    (0xC641, 0xC65B, LineBreak::H3),
+    (0xC65C, 0xC65C, LineBreak::H2),
+    (0xC65D, 0xC677, LineBreak::H3),
+    (0xC678, 0xC678, LineBreak::H2),
+    (0xC679, 0xC693, LineBreak::H3),
+    (0xC694, 0xC694, LineBreak::H2),
+    (0xC695, 0xC6AF, LineBreak::H3),
+    (0xC6B0, 0xC6B0, LineBreak::H2),
+    (0xC6B1, 0xC6CB, LineBreak::H3),
+    (0xC6CC, 0xC6CC, LineBreak::H2),
+    (0xC6CD, 0xC6E7, LineBreak::H3),
+    (0xC6E8, 0xC6E8, LineBreak::H2),
+    (0xC6E9, 0xC703, LineBreak::H3),
+    (0xC704, 0xC704, LineBreak::H2),
+    (0xC705, 0xC71F, LineBreak::H3),
+    (0xC720, 0xC720, LineBreak::H2),
+    (0xC721, 0xC73B, LineBreak::H3),
+    (0xC73C, 0xC73C, LineBreak::H2),
+    (0xC73D, 0xC757, LineBreak::H3),
+    (0xC758, 0xC758, LineBreak::H2),
+    (0xC759, 0xC773, LineBreak::H3),
+    (0xC774, 0xC774, LineBreak::H2),
+    (0xC775, 0xC78F, LineBreak::H3),
+    (0xC790, 0xC790, LineBreak::H2),
+    (0xC791, 0xC7AB, LineBreak::H3),
+    (0xC7AC, 0xC7AC, LineBreak::H2),
+    (0xC7AD, 0xC7C7, LineBreak::H3),
+    (0xC7C8, 0xC7C8, LineBreak::H2),
+    (0xC7C9, 0xC7E3, LineBreak::H3),
+    (0xC7E4, 0xC7E4, LineBreak::H2),
+    (0xC7E5, 0xC7FF, LineBreak::H3),
+    (0xC800, 0xC800, LineBreak::H2),
+    (0xC801, 0xC81B, LineBreak::H3),
+    (0xC81C, 0xC81C, LineBreak::H2),
+    (0xC81D, 0xC837, LineBreak::H3),
+    (0xC838, 0xC838, LineBreak::H2),
+    (0xC839, 0xC853, LineBreak::H3),
+    (0xC854, 0xC854, LineBreak::H2),
+    (0xC855, 0xC86F, LineBreak::H3),
+    (0xC870, 0xC870, LineBreak::H2),
+    (0xC871, 0xC88B, LineBreak::H3),
+    (0xC88C, 0xC88C, LineBreak::H2),
+    (0xC88D, 0xC8A7, LineBreak::H3),
+    (0xC8A8, 0xC8A8, LineBreak::H2),
+    (0xC8A9, 0xC8C3, LineBreak::H3),
+    (0xC8C4, 0xC8C4, LineBreak::H2),
+    (0xC8C5, 0xC8DF, LineBreak::H3),
+    (0xC8E0, 0xC8E0, LineBreak::H2),
+    (0xC8E1, 0xC8FB, LineBreak::H3),
+    (0xC8FC, 0xC8FC, LineBreak::H2),
+    (0xC8FD, 0xC917, LineBreak::H3),
+    (0xC918, 0xC918, LineBreak::H2),
+    (0xC919, 0xC933, LineBreak::H3),
+    (0xC934, 0xC934, LineBreak::H2),
+    (0xC935, 0xC94F, LineBreak::H3),
+    (0xC950, 0xC950, LineBreak::H2),
+    (0xC951, 0xC96B, LineBreak::H3),
+    (0xC96C, 0xC96C, LineBreak::H2),
+    (0xC96D, 0xC987, LineBreak::H3),
+    (0xC988, 0xC988, LineBreak::H2),
+    (0xC989, 0xC9A3, LineBreak::H3),
+    (0xC9A4, 0xC9A4, LineBreak::H2),
+    (0xC9A5, 0xC9BF, LineBreak::H3),
+    (0xC9C0, 0xC9C0, LineBreak::H2),
+    (0xC9C1, 0xC9DB, LineBreak::H3),
+    (0xC9DC, 0xC9DC, LineBreak::H2),
+    (0xC9DD, 0xC9F7, LineBreak::H3),
+    (0xC9F8, 0xC9F8, LineBreak::H2),
+    (0xC9F9, 0xCA13, LineBreak::H3),
+    (0xCA14, 0xCA14, LineBreak::H2),
+    (0xCA15, 0xCA2F, LineBreak::H3),
+    (0xCA30, 0xCA30, LineBreak::H2),
+    (0xCA31, 0xCA4B, LineBreak::H3),
+    (0xCA4C, 0xCA4C, LineBreak::H2),
+    (0xCA4D, 0xCA67, LineBreak::H3),
+    (0xCA68, 0xCA68, LineBreak::H2),
+    (0xCA69, 0xCA83, LineBreak::H3),
+    (0xCA84, 0xCA84, LineBreak::H2),
+    (0xCA85, 0xCA9F, LineBreak::H3),
+    (0xCAA0, 0xCAA0, LineBreak::H2),
+    (0xCAA1, 0xCABB, LineBreak::H3),
+    (0xCABC, 0xCABC, LineBreak::H2),
+    (0xCABD, 0xCAD7, LineBreak::H3),
+    (0xCAD8, 0xCAD8, LineBreak::H2),
+    (0xCAD9, 0xCAF3, LineBreak::H3),
+    (0xCAF4, 0xCAF4, LineBreak::H2),
+    (0xCAF5, 0xCB0F, LineBreak::H3),
+    (0xCB10, 0xCB10, LineBreak::H2),
+    (0xCB11, 0xCB2B, LineBreak::H3),
+    (0xCB2C, 0xCB2C, LineBreak::H2),
+    (0xCB2D, 0xCB47, LineBreak::H3),
+    (0xCB48, 0xCB48, LineBreak::H2),
+    (0xCB49, 0xCB63, LineBreak::H3),
+    (0xCB64, 0xCB64, LineBreak::H2),
+    (0xCB65, 0xCB7F, LineBreak::H3),
+    (0xCB80, 0xCB80, LineBreak::H2),
+    (0xCB81, 0xCB9B, LineBreak::H3),
+    (0xCB9C, 0xCB9C, LineBreak::H2),
+    (0xCB9D, 0xCBB7, LineBreak::H3),
+    (0xCBB8, 0xCBB8, LineBreak::H2),
+    (0xCBB9, 0xCBD3, LineBreak::H3),
+    (0xCBD4, 0xCBD4, LineBreak::H2),
+    (0xCBD5, 0xCBEF, LineBreak::H3),
+    (0xCBF0, 0xCBF0, LineBreak::H2),
+    (0xCBF1, 0xCC0B, LineBreak::H3),
+    (0xCC0C, 0xCC0C, LineBreak::H2),
+    (0xCC0D, 0xCC27, LineBreak::H3),
+    (0xCC28, 0xCC28, LineBreak::H2),
+    (0xCC29, 0xCC43, LineBreak::H3),
+    (0xCC44, 0xCC44, LineBreak::H2),
+    (0xCC45, 0xCC5F, LineBreak::H3),
+    (0xCC60, 0xCC60, LineBreak::H2),
+    (0xCC61, 0xCC7B, LineBreak::H3),
+    (0xCC7C, 0xCC7C, LineBreak::H2),
+    (0xCC7D, 0xCC97, LineBreak::H3),
+    (0xCC98, 0xCC98, LineBreak::H2),
+    (0xCC99, 0xCCB3, LineBreak::H3),
+    (0xCCB4, 0xCCB4, LineBreak::H2),
+    (0xCCB5, 0xCCCF, LineBreak::H3),
+    (0xCCD0, 0xCCD0, LineBreak::H2),
+    (0xCCD1, 0xCCEB, LineBreak::H3),
+    (0xCCEC, 0xCCEC, LineBreak::H2),
+    (0xCCED, 0xCD07, LineBreak::H3),
+    (0xCD08, 0xCD08, LineBreak::H2),
+    (0xCD09, 0xCD23, LineBreak::H3),
+    (0xCD24, 0xCD24, LineBreak::H2),
+    (0xCD25, 0xCD3F, LineBreak::H3),
+    (0xCD40, 0xCD40, LineBreak::H2),
+    (0xCD41, 0xCD5B, LineBreak::H3),
+    (0xCD5C, 0xCD5C, LineBreak::H2),
+    (0xCD5D, 0xCD77, LineBreak::H3),
+    (0xCD78, 0xCD78, LineBreak::H2),
+    (0xCD79, 0xCD93, LineBreak::H3),
+    (0xCD94, 0xCD94, LineBreak::H2),
+    (0xCD95, 0xCDAF, LineBreak::H3),
+    (0xCDB0, 0xCDB0, LineBreak::H2),
+    (0xCDB1, 0xCDCB, LineBreak::H3),
+    (0xCDCC, 0xCDCC, LineBreak::H2),
+    (0xCDCD, 0xCDE7, LineBreak::H3),
+    (0xCDE8, 0xCDE8, LineBreak::H2),
+    (0xCDE9, 0xCE03, LineBreak::H3),
+    (0xCE04, 0xCE04, LineBreak::H2),
+    (0xCE05, 0xCE1F, LineBreak::H3),
+    (0xCE20, 0xCE20, LineBreak::H2),
+    (0xCE21, 0xCE3B, LineBreak::H3),
+    (0xCE3C, 0xCE3C, LineBreak::H2),
+    (0xCE3D, 0xCE57, LineBreak::H3),
+    (0xCE58, 0xCE58, LineBreak::H2),
+    (0xCE59, 0xCE73, LineBreak::H3),
+    (0xCE74, 0xCE74, LineBreak::H2),
+    (0xCE75, 0xCE8F, LineBreak::H3),
+    (0xCE90, 0xCE90, LineBreak::H2),
+    (0xCE91, 0xCEAB, LineBreak::H3),
+    (0xCEAC, 0xCEAC, LineBreak::H2),
+    (0xCEAD, 0xCEC7, LineBreak::H3),
+    (0xCEC8, 0xCEC8, LineBreak::H2),
+    (0xCEC9, 0xCEE3, LineBreak::H3),
+    (0xCEE4, 0xCEE4, LineBreak::H2),
+    (0xCEE5, 0xCEFF, LineBreak::H3),
+    (0xCF00, 0xCF00, LineBreak::H2),
+    (0xCF01, 0xCF1B, LineBreak::H3),
+    (0xCF1C, 0xCF1C, LineBreak::H2),
+    (0xCF1D, 0xCF37, LineBreak::H3),
+    (0xCF38, 0xCF38, LineBreak::H2),
+    (0xCF39, 0xCF53, LineBreak::H3),
+    (0xCF54, 0xCF54, LineBreak::H2),
+    (0xCF55, 0xCF6F, LineBreak::H3),
+    (0xCF70, 0xCF70, LineBreak::H2),
+    (0xCF71, 0xCF8B, LineBreak::H3),
+    (0xCF8C, 0xCF8C, LineBreak::H2),
+    (0xCF8D, 0xCFA7, LineBreak::H3),
+    (0xCFA8, 0xCFA8, LineBreak::H2),
+    (0xCFA9, 0xCFC3, LineBreak::H3),
+    (0xCFC4, 0xCFC4, LineBreak::H2),
+    (0xCFC5, 0xCFDF, LineBreak::H3),
+    (0xCFE0, 0xCFE0, LineBreak::H2),
+    (0xCFE1, 0xCFFB, LineBreak::H3),
+    (0xCFFC, 0xCFFC, LineBreak::H2),
+    (0xCFFD, 0xD017, LineBreak::H3),
+    (0xD018, 0xD018, LineBreak::H2),
+    (0xD019, 0xD033, LineBreak::H3),
+    (0xD034, 0xD034, LineBreak::H2),
+    (0xD035, 0xD04F, LineBreak::H3),
+    (0xD050, 0xD050, LineBreak::H2),
+    (0xD051, 0xD06B, LineBreak::H3),
+    (0xD06C, 0xD06C, LineBreak::H2),
+    (0xD06D, 0xD087, LineBreak::H3),
+    (0xD088, 0xD088, LineBreak::H2),
+    (0xD089, 0xD0A3, LineBreak::H3),
+    (0xD0A4, 0xD0A4, LineBreak::H2),
+    (0xD0A5, 0xD0BF, LineBreak::H3),
+    (0xD0C0, 0xD0C0, LineBreak::H2),
+    (0xD0C1, 0xD0DB, LineBreak::H3),
+    (0xD0DC, 0xD0DC, LineBreak::H2),
+    (0xD0DD, 0xD0F7, LineBreak::H3),
+    (0xD0F8, 0xD0F8, LineBreak::H2),
+    (0xD0F9, 0xD113, LineBreak::H3),
+    (0xD114, 0xD114, LineBreak::H2),
+    (0xD115, 0xD12F, LineBreak::H3),
+    (0xD130, 0xD130, LineBreak::H2),
+    (0xD131, 0xD14B, LineBreak::H3),
+    (0xD14C, 0xD14C, LineBreak::H2),
+    (0xD14D, 0xD167, LineBreak::H3),
+    (0xD168, 0xD168, LineBreak::H2),
+    (0xD169, 0xD183, LineBreak::H3),
+    (0xD184, 0xD184, LineBreak::H2),
+    (0xD185, 0xD19F, LineBreak::H3),
+    (0xD1A0, 0xD1A0, LineBreak::H2),
+    (0xD1A1, 0xD1BB, LineBreak::H3),
+    (0xD1BC, 0xD1BC, LineBreak::H2),
+    (0xD1BD, 0xD1D7, LineBreak::H3),
+    (0xD1D8, 0xD1D8, LineBreak::H2),
+    (0xD1D9, 0xD1F3, LineBreak::H3),
+    (0xD1F4, 0xD1F4, LineBreak::H2),
+    (0xD1F5, 0xD20F, LineBreak::H3),
+    (0xD210, 0xD210, LineBreak::H2),
+    (0xD211, 0xD22B, LineBreak::H3),
+    (0xD22C, 0xD22C, LineBreak::H2),
+    (0xD22D, 0xD247, LineBreak::H3),
+    (0xD248, 0xD248, LineBreak::H2),
+    (0xD249, 0xD263, LineBreak::H3),
+    (0xD264, 0xD264, LineBreak::H2),
+    (0xD265, 0xD27F, LineBreak::H3),
+    (0xD280, 0xD280, LineBreak::H2),
+    (0xD281, 0xD29B, LineBreak::H3),
+    (0xD29C, 0xD29C, LineBreak::H2),
+    (0xD29D, 0xD2B7, LineBreak::H3),
+    (0xD2B8, 0xD2B8, LineBreak::H2),
+    (0xD2B9, 0xD2D3, LineBreak::H3),
+    (0xD2D4, 0xD2D4, LineBreak::H2),
+    (0xD2D5, 0xD2EF, LineBreak::H3),
+    (0xD2F0, 0xD2F0, LineBreak::H2),
+    (0xD2F1, 0xD30B, LineBreak::H3),
+    (0xD30C, 0xD30C, LineBreak::H2),
+    (0xD30D, 0xD327, LineBreak::H3),
+    (0xD328, 0xD328, LineBreak::H2),
+    (0xD329, 0xD343, LineBreak::H3),
+    (0xD344, 0xD344, LineBreak::H2),
+    (0xD345, 0xD35F, LineBreak::H3),
+    (0xD360, 0xD360, LineBreak::H2),
+    (0xD361, 0xD37B, LineBreak::H3),
+    (0xD37C, 0xD37C, LineBreak::H2),
+    (0xD37D, 0xD397, LineBreak::H3),
+    (0xD398, 0xD398, LineBreak::H2),
+    (0xD399, 0xD3B3, LineBreak::H3),
+    (0xD3B4, 0xD3B4, LineBreak::H2),
+    (0xD3B5, 0xD3CF, LineBreak::H3),
+    (0xD3D0, 0xD3D0, LineBreak::H2),
+    (0xD3D1, 0xD3EB, LineBreak::H3),
+    (0xD3EC, 0xD3EC, LineBreak::H2),
+    (0xD3ED, 0xD407, LineBreak::H3),
+    (0xD408, 0xD408, LineBreak::H2),
+    (0xD409, 0xD423, LineBreak::H3),
+    (0xD424, 0xD424, LineBreak::H2),
+    (0xD425, 0xD43F, LineBreak::H3),
+    (0xD440, 0xD440, LineBreak::H2),
+    (0xD441, 0xD45B, LineBreak::H3),
+    (0xD45C, 0xD45C, LineBreak::H2),
+    (0xD45D, 0xD477, LineBreak::H3),
+    (0xD478, 0xD478, LineBreak::H2),
+    (0xD479, 0xD493, LineBreak::H3),
+    (0xD494, 0xD494, LineBreak::H2),
+    (0xD495, 0xD4AF, LineBreak::H3),
+    (0xD4B0, 0xD4B0, LineBreak::H2),
+    (0xD4B1, 0xD4CB, LineBreak::H3),
+    (0xD4CC, 0xD4CC, LineBreak::H2),
+    (0xD4CD, 0xD4E7, LineBreak::H3),
+    (0xD4E8, 0xD4E8, LineBreak::H2),
+    (0xD4E9, 0xD503, LineBreak::H3),
+    (0xD504, 0xD504, LineBreak::H2),
+    (0xD505, 0xD51F, LineBreak::H3),
+    (0xD520, 0xD520, LineBreak::H2),
+    (0xD521, 0xD53B, LineBreak::H3),
+    (0xD53C, 0xD53C, LineBreak::H2),
+    (0xD53D, 0xD557, LineBreak::H3),
+    (0xD558, 0xD558, LineBreak::H2),
+    (0xD559, 0xD573, LineBreak::H3),
+    (0xD574, 0xD574, LineBreak::H2),
+    (0xD575, 0xD58F, LineBreak::H3),
+    (0xD590, 0xD590, LineBreak::H2),
+    (0xD591, 0xD5AB, LineBreak::H3),
+    (0xD5AC, 0xD5AC, LineBreak::H2),
+    (0xD5AD, 0xD5C7, LineBreak::H3),
+    (0xD5C8, 0xD5C8, LineBreak::H2),
+    (0xD5C9, 0xD5E3, LineBreak::H3),
+    (0xD5E4, 0xD5E4, LineBreak::H2),
+    (0xD5E5, 0xD5FF, LineBreak::H3),
+    (0xD600, 0xD600, LineBreak::H2),
+    (0xD601, 0xD61B, LineBreak::H3),
+    (0xD61C, 0xD61C, LineBreak::H2),
+    (0xD61D, 0xD637, LineBreak::H3),
+    (0xD638, 0xD638, LineBreak::H2),
+    (0xD639, 0xD653, LineBreak::H3),
+    (0xD654, 0xD654, LineBreak::H2),
+    (0xD655, 0xD66F, LineBreak::H3),
+    (0xD670, 0xD670, LineBreak::H2),
+    (0xD671, 0xD68B, LineBreak::H3),
+    (0xD68C, 0xD68C, LineBreak::H2),
+    (0xD68D, 0xD6A7, LineBreak::H3),
+    (0xD6A8, 0xD6A8, LineBreak::H2),
+    (0xD6A9, 0xD6C3, LineBreak::H3),
+    (0xD6C4, 0xD6C4, LineBreak::H2),
+    (0xD6C5, 0xD6DF, LineBreak::H3),
+    (0xD6E0, 0xD6E0, LineBreak::H2),
+    (0xD6E1, 0xD6FB, LineBreak::H3),
+    (0xD6FC, 0xD6FC, LineBreak::H2),
+    (0xD6FD, 0xD717, LineBreak::H3),
+    (0xD718, 0xD718, LineBreak::H2),
+    (0xD719, 0xD733, LineBreak::H3),
+    (0xD734, 0xD734, LineBreak::H2),
+    (0xD735, 0xD74F, LineBreak::H3),
+    (0xD750, 0xD750, LineBreak::H2),
+    (0xD751, 0xD76B, LineBreak::H3),
+    (0xD76C, 0xD76C, LineBreak::H2),
+    (0xD76D, 0xD787, LineBreak::H3),
+    (0xD788, 0xD788, LineBreak::H2),
+    (0xD789, 0xD7A3, LineBreak::H3),
+    (0xD7B0, 0xD7C6, LineBreak::Jv),
+    (0xD7CB, 0xD7FB, LineBreak::Jt),
+    (0xF900, 0xFAFF, LineBreak::Id),
+    (0xFB1D, 0xFB1D, LineBreak::Hl),
+    (0xFB1E, 0xFB1E, LineBreak::Cm),
+    (0xFB1F, 0xFB28, LineBreak::Hl),
+    (0xFB2A, 0xFB36, LineBreak::Hl),
+    (0xFB38, 0xFB3C, LineBreak::Hl),
+    (0xFB3E, 0xFB3E, LineBreak::Hl),
+    (0xFB40, 0xFB41, LineBreak::Hl),
+    (0xFB43, 0xFB44, LineBreak::Hl),
+    (0xFB46, 0xFB4F, LineBreak::Hl),
+    (0xFD3E, 0xFD3E, LineBreak::Cl),
+    (0xFD3F, 0xFD3F, LineBreak::Op),
+    (0xFDFC, 0xFDFC, LineBreak::Po),
+    (0xFE00, 0xFE0F, LineBreak::Cm),
+    (0xFE10, 0xFE10, LineBreak::Is),
+    (0xFE11, 0xFE12, LineBreak::Cl),
+    (0xFE13, 0xFE14, LineBreak::Is),
+    (0xFE15, 0xFE16, LineBreak::Ex),
+    (0xFE17, 0xFE17, LineBreak::Op),
+    (0xFE18, 0xFE18, LineBreak::Cl),
+    (0xFE19, 0xFE19, LineBreak::In),
+    (0xFE20, 0xFE2F, LineBreak::Cm),
+    (0xFE30, 0xFE34, LineBreak::Id),
+    (0xFE35, 0xFE35, LineBreak::Op),
+    (0xFE36, 0xFE36, LineBreak::Cl),
+    (0xFE37, 0xFE37, LineBreak::Op),
+    (0xFE38, 0xFE38, LineBreak::Cl),
+    (0xFE39, 0xFE39, LineBreak::Op),
+    (0xFE3A, 0xFE3A, LineBreak::Cl),
+    (0xFE3B, 0xFE3B, LineBreak::Op),
+    (0xFE3C, 0xFE3C, LineBreak::Cl),
+    (0xFE3D, 0xFE3D, LineBreak::Op),
+    (0xFE3E, 0xFE3E, LineBreak::Cl),
+    (0xFE3F, 0xFE3F, LineBreak::Op),
+    (0xFE40, 0xFE40, LineBreak::Cl),
+    (0xFE41, 0xFE41, LineBreak::Op),
+    (0xFE42, 0xFE42, LineBreak::Cl),
+    (0xFE43, 0xFE43, LineBreak::Op),
+    (0xFE44, 0xFE44, LineBreak::Cl),
+    (0xFE45, 0xFE46, LineBreak::Id),
+    (0xFE47, 0xFE47, LineBreak::Op),
+    (0xFE48, 0xFE48, LineBreak::Cl),
+    (0xFE49, 0xFE4F, LineBreak::Id),
+    (0xFE50, 0xFE50, LineBreak::Cl),
+    (0xFE51, 0xFE51, LineBreak::Id),
+    (0xFE52, 0xFE52, LineBreak::Cl),
+    (0xFE54, 0xFE55, LineBreak::Ns),
+    (0xFE56, 0xFE57, LineBreak::Ex),
+    (0xFE58, 0xFE58, LineBreak::Id),
+    (0xFE59, 0xFE59, LineBreak::Op),
+    (0xFE5A, 0xFE5A, LineBreak::Cl),
+    (0xFE5B, 0xFE5B, LineBreak::Op),
+    (0xFE5C, 0xFE5C, LineBreak::Cl),
+    (0xFE5D, 0xFE5D, LineBreak::Op),
+    (0xFE5E, 0xFE5E, LineBreak::Cl),
+    (0xFE5F, 0xFE66, LineBreak::Id),
+    (0xFE68, 0xFE68, LineBreak::Id),
+    (0xFE69, 0xFE69, LineBreak::Pr),
+    (0xFE6A, 0xFE6A, LineBreak::Po),
+    (0xFE6B, 0xFE6B, LineBreak::Id),
+    (0xFEFF, 0xFEFF, LineBreak::Wj),
+    (0xFF01, 0xFF01, LineBreak::Ex),
+    (0xFF02, 0xFF03, LineBreak::Id),
+    (0xFF04, 0xFF04, LineBreak::Pr),
+    (0xFF05, 0xFF05, LineBreak::Po),
+    (0xFF06, 0xFF07, LineBreak::Id),
+    (0xFF08, 0xFF08, LineBreak::Op),
+    (0xFF09, 0xFF09, LineBreak::Cl),
+    (0xFF0A, 0xFF0B, LineBreak::Id),
+    (0xFF0C, 0xFF0C, LineBreak::Cl),
+    (0xFF0D, 0xFF0D, LineBreak::Id),
+    (0xFF0E, 0xFF0E, LineBreak::Cl),
+    (0xFF0F, 0xFF19, LineBreak::Id),
+    (0xFF1A, 0xFF1B, LineBreak::Ns),
+    (0xFF1C, 0xFF1E, LineBreak::Id),
+    (0xFF1F, 0xFF1F, LineBreak::Ex),
+    (0xFF20, 0xFF3A, LineBreak::Id),
+    (0xFF3B, 0xFF3B, LineBreak::Op),
+    (0xFF3C, 0xFF3C, LineBreak::Id),
+    (0xFF3D, 0xFF3D, LineBreak::Cl),
+    (0xFF3E, 0xFF5A, LineBreak::Id),
+    (0xFF5B, 0xFF5B, LineBreak::Op),
+    (0xFF5C, 0xFF5C, LineBreak::Id),
+    (0xFF5D, 0xFF5D, LineBreak::Cl),
+    (0xFF5E, 0xFF5E, LineBreak::Id),
+    (0xFF5F, 0xFF5F, LineBreak::Op),
+    (0xFF60, 0xFF61, LineBreak::Cl),
+    (0xFF62, 0xFF62, LineBreak::Op),
+    (0xFF63, 0xFF64, LineBreak::Cl),
+    (0xFF65, 0xFF65, LineBreak::Ns),
+    (0xFF66, 0xFF66, LineBreak::Id),
+    (0xFF67, 0xFF70, LineBreak::Ns),
+    (0xFF71, 0xFF9D, LineBreak::Id),
+    (0xFF9E, 0xFF9F, LineBreak::Ns),
+    (0xFFA0, 0xFFBE, LineBreak::Id),
+    (0xFFC2, 0xFFC7, LineBreak::Id),
+    (0xFFCA, 0xFFCF, LineBreak::Id),
+    (0xFFD2, 0xFFD7, LineBreak::Id),
+    (0xFFDA, 0xFFDC, LineBreak::Id),
+    (0xFFE0, 0xFFE0, LineBreak::Po),
+    (0xFFE1, 0xFFE1, LineBreak::Pr),
+    (0xFFE2, 0xFFE4, LineBreak::Id),
+    (0xFFE5, 0xFFE6, LineBreak::Pr),
+    (0xFFF9, 0xFFFB, LineBreak::Cm),
+    (0xFFFC, 0xFFFC, LineBreak::Cb),
+    (0x10100, 0x10102, LineBreak::Ba),
+    (0x101FD, 0x101FD, LineBreak::Cm),
+    (0x102E0, 0x102E0, LineBreak::Cm),
+    (0x10376, 0x1037A, LineBreak::Cm),
+    (0x1039F, 0x1039F, LineBreak::Ba),
+    (0x103D0, 0x103D0, LineBreak::Ba),
+    (0x104A0, 0x104A9, LineBreak::Nu),
+    (0x10857, 0x10857, LineBreak::Ba),
+    (0x1091F, 0x1091F, LineBreak::Ba),
+    (0x10A01, 0x10A03, LineBreak::Cm),
+    (0x10A05, 0x10A06, LineBreak::Cm),
+    (0x10A0C, 0x10A0F, LineBreak::Cm),
+    (0x10A38, 0x10A3A, LineBreak::Cm),
+    (0x10A3F, 0x10A3F, LineBreak::Cm),
+    (0x10A50, 0x10A57, LineBreak::Ba),
+    (0x10AE5, 0x10AE6, LineBreak::Cm),
+    (0x10AF0, 0x10AF5, LineBreak::Ba),
+    (0x10AF6, 0x10AF6, LineBreak::In),
+    (0x10B39, 0x10B3F, LineBreak::Ba),
+    (0x10D24, 0x10D27, LineBreak::Cm),
+    (0x10D30, 0x10D39, LineBreak::Nu),
+    (0x10EAB, 0x10EAC, LineBreak::Cm),
+    (0x10EAD, 0x10EAD, LineBreak::Ba),
+    (0x10EFD, 0x10EFF, LineBreak::Cm),
+    (0x10F46, 0x10F50, LineBreak::Cm),
+    (0x10F82, 0x10F85, LineBreak::Cm),
+    (0x11000, 0x11002, LineBreak::Cm),
+    (0x11038, 0x11046, LineBreak::Cm),
+    (0x11047, 0x11048, LineBreak::Ba),
+    (0x11066, 0x1106F, LineBreak::Nu),
+    (0x11070, 0x11070, LineBreak::Cm),
+    (0x11073, 0x11074, LineBreak::Cm),
+    (0x1107F, 0x11082, LineBreak::Cm),
+    (0x110B0, 0x110BA, LineBreak::Cm),
+    (0x110BE, 0x110C1, LineBreak::Ba),
+    (0x110C2, 0x110C2, LineBreak::Cm),
+    (0x110F0, 0x110F9, LineBreak::Nu),
+    (0x11100, 0x11102, LineBreak::Cm),
+    (0x11127, 0x11134, LineBreak::Cm),
+    (0x11136, 0x1113F, LineBreak::Nu),
+    (0x11140, 0x11143, LineBreak::Ba),
+    (0x11145, 0x11146, LineBreak::Cm),
+    (0x11173, 0x11173, LineBreak::Cm),
+    (0x11175, 0x11175, LineBreak::Bb),
+    (0x11180, 0x11182, LineBreak::Cm),
+    (0x111B3, 0x111C0, LineBreak::Cm),
+    (0x111C5, 0x111C6, LineBreak::Ba),
+    (0x111C8, 0x111C8, LineBreak::Ba),
+    (0x111C9, 0x111CC, LineBreak::Cm),
+    (0x111CE, 0x111CF, LineBreak::Cm),
+    (0x111D0, 0x111D9, LineBreak::Nu),
+    (0x111DB, 0x111DB, LineBreak::Bb),
+    (0x111DD, 0x111DF, LineBreak::Ba),
+    (0x1122C, 0x11237, LineBreak::Cm),
+    (0x11238, 0x11239, LineBreak::Ba),
+    (0x1123B, 0x1123C, LineBreak::Ba),
+    (0x1123E, 0x1123E, LineBreak::Cm),
+    (0x11241, 0x11241, LineBreak::Cm),
+    (0x112A9, 0x112A9, LineBreak::Ba),
+    (0x112DF, 0x112EA, LineBreak::Cm),
+    (0x112F0, 0x112F9, LineBreak::Nu),
+    (0x11300, 0x11303, LineBreak::Cm),
+    (0x1133B, 0x1133C, LineBreak::Cm),
+    (0x1133E, 0x11344, LineBreak::Cm),
+    (0x11347, 0x11348, LineBreak::Cm),
+    (0x1134B, 0x1134D, LineBreak::Cm),
+    (0x11357, 0x11357, LineBreak::Cm),
+    (0x11362, 0x11363, LineBreak::Cm),
+    (0x11366, 0x1136C, LineBreak::Cm),
+    (0x11370, 0x11374, LineBreak::Cm),
+    (0x11435, 0x11446, LineBreak::Cm),
+    (0x1144B, 0x1144E, LineBreak::Ba),
+    (0x11450, 0x11459, LineBreak::Nu),
+    (0x1145A, 0x1145B, LineBreak::Ba),
+    (0x1145E, 0x1145E, LineBreak::Cm),
+    (0x114B0, 0x114C3, LineBreak::Cm),
+    (0x114D0, 0x114D9, LineBreak::Nu),
+    (0x115AF, 0x115B5, LineBreak::Cm),
+    (0x115B8, 0x115C0, LineBreak::Cm),
+    (0x115C1, 0x115C1, LineBreak::Bb),
+    (0x115C2, 0x115C3, LineBreak::Ba),
+    (0x115C4, 0x115C5, LineBreak::Ex),
+    (0x115C9, 0x115D7, LineBreak::Ba),
+    (0x115DC, 0x115DD, LineBreak::Cm),
+    (0x11630, 0x11640, LineBreak::Cm),
+    (0x11641, 0x11642, LineBreak::Ba),
+    (0x11650, 0x11659, LineBreak::Nu),
+    (0x11660, 0x1166C, LineBreak::Bb),
+    (0x116AB, 0x116B7, LineBreak::Cm),
+    (0x116C0, 0x116C9, LineBreak::Nu),
+    (0x1171D, 0x1172B, LineBreak::Cm),
+    (0x11730, 0x11739, LineBreak::Nu),
+    (0x1173C, 0x1173E, LineBreak::Ba),
+    (0x1182C, 0x1183A, LineBreak::Cm),
+    (0x118E0, 0x118E9, LineBreak::Nu),
+    (0x11930, 0x11935, LineBreak::Cm),
+    (0x11937, 0x11938, LineBreak::Cm),
+    (0x1193B, 0x1193E, LineBreak::Cm),
+    (0x11940, 0x11940, LineBreak::Cm),
+    (0x11942, 0x11943, LineBreak::Cm),
+    (0x11944, 0x11946, LineBreak::Ba),
+    (0x11950, 0x11959, LineBreak::Nu),
+    (0x119D1, 0x119D7, LineBreak::Cm),
+    (0x119DA, 0x119E0, LineBreak::Cm),
+    (0x119E2, 0x119E2, LineBreak::Bb),
+    (0x119E4, 0x119E4, LineBreak::Cm),
+    (0x11A01, 0x11A0A, LineBreak::Cm),
+    (0x11A33, 0x11A39, LineBreak::Cm),
+    (0x11A3B, 0x11A3E, LineBreak::Cm),
+    (0x11A3F, 0x11A3F, LineBreak::Bb),
+    (0x11A41, 0x11A44, LineBreak::Ba),
+    (0x11A45, 0x11A45, LineBreak::Bb),
+    (0x11A47, 0x11A47, LineBreak::Cm),
+    (0x11A51, 0x11A5B, LineBreak::Cm),
+    (0x11A8A, 0x11A99, LineBreak::Cm),
+    (0x11A9A, 0x11A9C, LineBreak::Ba),
+    (0x11A9E, 0x11AA0, LineBreak::Bb),
+    (0x11AA1, 0x11AA2, LineBreak::Ba),
+    (0x11B00, 0x11B09, LineBreak::Bb),
+    (0x11C2F, 0x11C36, LineBreak::Cm),
+    (0x11C38, 0x11C3F, LineBreak::Cm),
+    (0x11C41, 0x11C45, LineBreak::Ba),
+    (0x11C50, 0x11C59, LineBreak::Nu),
+    (0x11C70, 0x11C70, LineBreak::Bb),
+    (0x11C71, 0x11C71, LineBreak::Ex),
+    (0x11C92, 0x11CA7, LineBreak::Cm),
+    (0x11CA9, 0x11CB6, LineBreak::Cm),
+    (0x11D31, 0x11D36, LineBreak::Cm),
+    (0x11D3A, 0x11D3A, LineBreak::Cm),
+    (0x11D3C, 0x11D3D, LineBreak::Cm),
+    (0x11D3F, 0x11D45, LineBreak::Cm),
+    (0x11D47, 0x11D47, LineBreak::Cm),
+    (0x11D50, 0x11D59, LineBreak::Nu),
+    (0x11D8A, 0x11D8E, LineBreak::Cm),
+    (0x11D90, 0x11D91, LineBreak::Cm),
+    (0x11D93, 0x11D97, LineBreak::Cm),
+    (0x11DA0, 0x11DA9, LineBreak::Nu),
+    (0x11EF3, 0x11EF6, LineBreak::Cm),
+    (0x11F00, 0x11F01, LineBreak::Cm),
+    (0x11F03, 0x11F03, LineBreak::Cm),
+    (0x11F34, 0x11F3A, LineBreak::Cm),
+    (0x11F3E, 0x11F42, LineBreak::Cm),
+    (0x11F43, 0x11F44, LineBreak::Ba),
+    (0x11F45, 0x11F4F, LineBreak::Id),
+    (0x11F50, 0x11F59, LineBreak::Nu),
+    (0x11FDD, 0x11FE0, LineBreak::Po),
+    (0x11FFF, 0x11FFF, LineBreak::Ba),
+    (0x12470, 0x12474, LineBreak::Ba),
+    (0x13258, 0x1325A, LineBreak::Op),
+    (0x1325B, 0x1325D, LineBreak::Cl),
+    (0x13282, 0x13282, LineBreak::Cl),
+    (0x13286, 0x13286, LineBreak::Op),
+    (0x13287, 0x13287, LineBreak::Cl),
+    (0x13288, 0x13288, LineBreak::Op),
+    (0x13289, 0x13289, LineBreak::Cl),
+    (0x13379, 0x13379, LineBreak::Op),
+    (0x1337A, 0x1337B, LineBreak::Cl),
+    (0x13430, 0x13436, LineBreak::Gl),
+    (0x13437, 0x13437, LineBreak::Op),
+    (0x13438, 0x13438, LineBreak::Cl),
+    (0x13439, 0x1343B, LineBreak::Gl),
+    (0x1343C, 0x1343C, LineBreak::Op),
+    (0x1343D, 0x1343D, LineBreak::Cl),
+    (0x1343E, 0x1343E, LineBreak::Op),
+    (0x1343F, 0x1343F, LineBreak::Cl),
+    (0x13440, 0x13440, LineBreak::Cm),
+    (0x13447, 0x13455, LineBreak::Cm),
+    (0x145CE, 0x145CE, LineBreak::Op),
+    (0x145CF, 0x145CF, LineBreak::Cl),
+    (0x16A60, 0x16A69, LineBreak::Nu),
+    (0x16A6E, 0x16A6F, LineBreak::Ba),
+    (0x16AC0, 0x16AC9, LineBreak::Nu),
+    (0x16AF0, 0x16AF4, LineBreak::Cm),
+    (0x16AF5, 0x16AF5, LineBreak::Ba),
+    (0x16B30, 0x16B36, LineBreak::Cm),
+    (0x16B37, 0x16B39, LineBreak::Ba),
+    (0x16B44, 0x16B44, LineBreak::Ba),
+    (0x16B50, 0x16B59, LineBreak::Nu),
+    (0x16E97, 0x16E98, LineBreak::Ba),
+    (0x16F4F, 0x16F4F, LineBreak::Cm),
+    (0x16F51, 0x16F87, LineBreak::Cm),
+    (0x16F8F, 0x16F92, LineBreak::Cm),
+    (0x16FE0, 0x16FE3, LineBreak::Ns),
+    (0x16FE4, 0x16FE4, LineBreak::Gl),
+    (0x16FF0, 0x16FF1, LineBreak::Cm),
+    (0x17000, 0x187F7, LineBreak::Id),
+    (0x18800, 0x18AFF, LineBreak::Id),
+    (0x18D00, 0x18D08, LineBreak::Id),
+    (0x1B000, 0x1B122, LineBreak::Id),
+    (0x1B132, 0x1B132, LineBreak::Ns),
+    (0x1B150, 0x1B152, LineBreak::Ns),
+    (0x1B155, 0x1B155, LineBreak::Ns),
+    (0x1B164, 0x1B167, LineBreak::Ns),
+    (0x1B170, 0x1B2FB, LineBreak::Id),
+    (0x1BC9D, 0x1BC9E, LineBreak::Cm),
+    (0x1BC9F, 0x1BC9F, LineBreak::Ba),
+    (0x1BCA0, 0x1BCA3, LineBreak::Cm),
+    (0x1CF00, 0x1CF2D, LineBreak::Cm),
+    (0x1CF30, 0x1CF46, LineBreak::Cm),
+    (0x1D165, 0x1D169, LineBreak::Cm),
+    (0x1D16D, 0x1D182, LineBreak::Cm),
+    (0x1D185, 0x1D18B, LineBreak::Cm),
+    (0x1D1AA, 0x1D1AD, LineBreak::Cm),
+    (0x1D242, 0x1D244, LineBreak::Cm),
+    (0x1D7CE, 0x1D7FF, LineBreak::Nu),
+    (0x1DA00, 0x1DA36, LineBreak::Cm),
+    (0x1DA3B, 0x1DA6C, LineBreak::Cm),
+    (0x1DA75, 0x1DA75, LineBreak::Cm),
+    (0x1DA84, 0x1DA84, LineBreak::Cm),
+    (0x1DA87, 0x1DA8A, LineBreak::Ba),
+    (0x1DA9B, 0x1DA9F, LineBreak::Cm),
+    (0x1DAA1, 0x1DAAF, LineBreak::Cm),
+    (0x1E000, 0x1E006, LineBreak::Cm),
+    (0x1E008, 0x1E018, LineBreak::Cm),
+    (0x1E01B, 0x1E021, LineBreak::Cm),
+    (0x1E023, 0x1E024, LineBreak::Cm),
+    (0x1E026, 0x1E02A, LineBreak::Cm),
+    (0x1E08F, 0x1E08F, LineBreak::Cm),
+    (0x1E130, 0x1E136, LineBreak::Cm),
+    (0x1E140, 0x1E149, LineBreak::Nu),
+    (0x1E2AE, 0x1E2AE, LineBreak::Cm),
+    (0x1E2EC, 0x1E2EF, LineBreak::Cm),
+    (0x1E2F0, 0x1E2F9, LineBreak::Nu),
+    (0x1E2FF, 0x1E2FF, LineBreak::Pr),
+    (0x1E4EC, 0x1E4EF, LineBreak::Cm),
+    (0x1E4F0, 0x1E4F9, LineBreak::Nu),
+    (0x1E8D0, 0x1E8D6, LineBreak::Cm),
+    (0x1E944, 0x1E94A, LineBreak::Cm),
+    (0x1E950, 0x1E959, LineBreak::Nu),
+    (0x1E95E, 0x1E95F, LineBreak::Op),
+    (0x1ECAC, 0x1ECAC, LineBreak::Po),
+    (0x1ECB0, 0x1ECB0, LineBreak::Po),
+    (0x1F000, 0x1F02B, LineBreak::Id),
+    (0x1F02C, 0x1F02F, LineBreak::Eb),
+    (0x1F030, 0x1F093, LineBreak::Id),
+    (0x1F094, 0x1F09F, LineBreak::Eb),
+    (0x1F0A0, 0x1F0AE, LineBreak::Id),
+    (0x1F0AF, 0x1F0B0, LineBreak::Eb),
+    (0x1F0B1, 0x1F0BF, LineBreak::Id),
+    (0x1F0C0, 0x1F0C0, LineBreak::Eb),
+    (0x1F0C1, 0x1F0CF, LineBreak::Id),
+    (0x1F0D0, 0x1F0D0, LineBreak::Eb),
+    (0x1F0D1, 0x1F0F5, LineBreak::Id),
+    (0x1F0F6, 0x1F0FF, LineBreak::Eb),
+    (0x1F10D, 0x1F10F, LineBreak::Id),
+    (0x1F16D, 0x1F16F, LineBreak::Id),
+    (0x1F1AD, 0x1F1AD, LineBreak::Id),
+    (0x1F1AE, 0x1F1E5, LineBreak::Eb),
+    (0x1F1E6, 0x1F1FF, LineBreak::Ri),
+    (0x1F200, 0x1F202, LineBreak::Id),
+    (0x1F203, 0x1F20F, LineBreak::Eb),
+    (0x1F210, 0x1F23B, LineBreak::Id),
+    (0x1F23C, 0x1F23F, LineBreak::Eb),
+    (0x1F240, 0x1F248, LineBreak::Id),
+    (0x1F249, 0x1F24F, LineBreak::Eb),
+    (0x1F250, 0x1F251, LineBreak::Id),
+    (0x1F252, 0x1F25F, LineBreak::Eb),
+    (0x1F260, 0x1F265, LineBreak::Id),
+    (0x1F266, 0x1F2FF, LineBreak::Eb),
+    (0x1F300, 0x1F384, LineBreak::Id),
+    (0x1F385, 0x1F385, LineBreak::Eb),
+    (0x1F386, 0x1F39B, LineBreak::Id),
+    (0x1F39E, 0x1F3B4, LineBreak::Id),
+    (0x1F3B7, 0x1F3BB, LineBreak::Id),
+    (0x1F3BD, 0x1F3C1, LineBreak::Id),
+    (0x1F3C2, 0x1F3C4, LineBreak::Eb),
+    (0x1F3C5, 0x1F3C6, LineBreak::Id),
+    (0x1F3C7, 0x1F3C7, LineBreak::Eb),
+    (0x1F3C8, 0x1F3C9, LineBreak::Id),
+    (0x1F3CA, 0x1F3CC, LineBreak::Eb),
+    (0x1F3CD, 0x1F3FA, LineBreak::Id),
+    (0x1F3FB, 0x1F3FF, LineBreak::Em),
+    (0x1F400, 0x1F441, LineBreak::Id),
+    (0x1F442, 0x1F443, LineBreak::Eb),
+    (0x1F444, 0x1F445, LineBreak::Id),
+    (0x1F446, 0x1F450, LineBreak::Eb),
+    (0x1F451, 0x1F465, LineBreak::Id),
+    (0x1F466, 0x1F478, LineBreak::Eb),
+    (0x1F479, 0x1F47B, LineBreak::Id),
+    (0x1F47C, 0x1F47C, LineBreak::Eb),
+    (0x1F47D, 0x1F480, LineBreak::Id),
+    (0x1F481, 0x1F483, LineBreak::Eb),
+    (0x1F484, 0x1F484, LineBreak::Id),
+    (0x1F485, 0x1F487, LineBreak::Eb),
+    (0x1F488, 0x1F48E, LineBreak::Id),
+    (0x1F48F, 0x1F48F, LineBreak::Eb),
+    (0x1F490, 0x1F490, LineBreak::Id),
+    (0x1F491, 0x1F491, LineBreak::Eb),
+    (0x1F492, 0x1F49F, LineBreak::Id),
+    (0x1F4A1, 0x1F4A1, LineBreak::Id),
+    (0x1F4A3, 0x1F4A3, LineBreak::Id),
+    (0x1F4A5, 0x1F4A9, LineBreak::Id),
+    (0x1F4AA, 0x1F4AA, LineBreak::Eb),
+    (0x1F4AB, 0x1F4AE, LineBreak::Id),
+    (0x1F4B0, 0x1F4B0, LineBreak::Id),
+    (0x1F4B3, 0x1F4FF, LineBreak::Id),
+    (0x1F507, 0x1F516, LineBreak::Id),
+    (0x1F525, 0x1F531, LineBreak::Id),
+    (0x1F54A, 0x1F573, LineBreak::Id),
+    (0x1F574, 0x1F575, LineBreak::Eb),
+    (0x1F576, 0x1F579, LineBreak::Id),
+    (0x1F57A, 0x1F57A, LineBreak::Eb),
+    (0x1F57B, 0x1F58F, LineBreak::Id),
+    (0x1F590, 0x1F590, LineBreak::Eb),
+    (0x1F591, 0x1F594, LineBreak::Id),
+    (0x1F595, 0x1F596, LineBreak::Eb),
+    (0x1F597, 0x1F5D3, LineBreak::Id),
+    (0x1F5DC, 0x1F5F3, LineBreak::Id),
+    (0x1F5FA, 0x1F644, LineBreak::Id),
+    (0x1F645, 0x1F647, LineBreak::Eb),
+    (0x1F648, 0x1F64A, LineBreak::Id),
+    (0x1F64B, 0x1F64F, LineBreak::Eb),
+    (0x1F676, 0x1F678, LineBreak::Qu),
+    (0x1F679, 0x1F67B, LineBreak::Ns),
+    (0x1F680, 0x1F6A2, LineBreak::Id),
+    (0x1F6A3, 0x1F6A3, LineBreak::Eb),
+    (0x1F6A4, 0x1F6B3, LineBreak::Id),
+    (0x1F6B4, 0x1F6B6, LineBreak::Eb),
+    (0x1F6B7, 0x1F6BF, LineBreak::Id),
+    (0x1F6C0, 0x1F6C0, LineBreak::Eb),
+    (0x1F6C1, 0x1F6CB, LineBreak::Id),
+    (0x1F6CC, 0x1F6CC, LineBreak::Eb),
+    (0x1F6CD, 0x1F6D7, LineBreak::Id),
+    (0x1F6D8, 0x1F6DB, LineBreak::Eb),
+    (0x1F6DC, 0x1F6EC, LineBreak::Id),
+    (0x1F6ED, 0x1F6EF, LineBreak::Eb),
+    (0x1F6F0, 0x1F6FC, LineBreak::Id),
+    (0x1F6FD, 0x1F6FF, LineBreak::Eb),
+    (0x1F774, 0x1F776, LineBreak::Id),
+    (0x1F777, 0x1F77A, LineBreak::Eb),
+    (0x1F77B, 0x1F77F, LineBreak::Id),
+    (0x1F7D5, 0x1F7D9, LineBreak::Id),
+    (0x1F7DA, 0x1F7DF, LineBreak::Eb),
+    (0x1F7E0, 0x1F7EB, LineBreak::Id),
+    (0x1F7EC, 0x1F7EF, LineBreak::Eb),
+    (0x1F7F0, 0x1F7F0, LineBreak::Id),
+    (0x1F7F1, 0x1F7FF, LineBreak::Eb),
+    (0x1F80C, 0x1F80F, LineBreak::Eb),
+    (0x1F848, 0x1F84F, LineBreak::Eb),
+    (0x1F85A, 0x1F85F, LineBreak::Eb),
+    (0x1F888, 0x1F88F, LineBreak::Eb),
+    (0x1F8AE, 0x1F8AF, LineBreak::Eb),
+    (0x1F8B0, 0x1F8B1, LineBreak::Id),
+    (0x1F8B2, 0x1F8FF, LineBreak::Eb),
+    (0x1F90C, 0x1F90C, LineBreak::Eb),
+    (0x1F90D, 0x1F90E, LineBreak::Id),
+    (0x1F90F, 0x1F90F, LineBreak::Eb),
+    (0x1F910, 0x1F917, LineBreak::Id),
+    (0x1F918, 0x1F91F, LineBreak::Eb),
+    (0x1F920, 0x1F925, LineBreak::Id),
+    (0x1F926, 0x1F926, LineBreak::Eb),
+    (0x1F927, 0x1F92F, LineBreak::Id),
+    (0x1F930, 0x1F939, LineBreak::Eb),
+    (0x1F93A, 0x1F93B, LineBreak::Id),
+    (0x1F93C, 0x1F93E, LineBreak::Eb),
+    (0x1F93F, 0x1F976, LineBreak::Id),
+    (0x1F977, 0x1F977, LineBreak::Eb),
+    (0x1F978, 0x1F9B4, LineBreak::Id),
+    (0x1F9B5, 0x1F9B6, LineBreak::Eb),
+    (0x1F9B7, 0x1F9B7, LineBreak::Id),
+    (0x1F9B8, 0x1F9B9, LineBreak::Eb),
+    (0x1F9BA, 0x1F9BA, LineBreak::Id),
+    (0x1F9BB, 0x1F9BB, LineBreak::Eb),
+    (0x1F9BC, 0x1F9CC, LineBreak::Id),
+    (0x1F9CD, 0x1F9CF, LineBreak::Eb),
+    (0x1F9D0, 0x1F9D0, LineBreak::Id),
+    (0x1F9D1, 0x1F9DD, LineBreak::Eb),
+    (0x1F9DE, 0x1F9FF, LineBreak::Id),
+    (0x1FA54, 0x1FA5F, LineBreak::Eb),
+    (0x1FA60, 0x1FA6D, LineBreak::Id),
+    (0x1FA6E, 0x1FA6F, LineBreak::Eb),
+    (0x1FA70, 0x1FA7C, LineBreak::Id),
+    (0x1FA7D, 0x1FA7F, LineBreak::Eb),
+    (0x1FA80, 0x1FA88, LineBreak::Id),
+    (0x1FA89, 0x1FA8F, LineBreak::Eb),
+    (0x1FA90, 0x1FABD, LineBreak::Id),
+    (0x1FABE, 0x1FABE, LineBreak::Eb),
+    (0x1FABF, 0x1FAC2, LineBreak::Id),
+    (0x1FAC3, 0x1FACD, LineBreak::Eb),
+    (0x1FACE, 0x1FADB, LineBreak::Id),
+    (0x1FADC, 0x1FADF, LineBreak::Eb),
+    (0x1FAE0, 0x1FAE8, LineBreak::Id),
+    (0x1FAE9, 0x1FAFF, LineBreak::Eb),
+    (0x1FBF0, 0x1FBF9, LineBreak::Nu),
+    (0x1FC00, 0x1FFFD, LineBreak::Eb),
+    (0x20000, 0x2FFFD, LineBreak::Id),
+    (0x30000, 0x3FFFD, LineBreak::Id),
+    (0xE0001, 0xE0001, LineBreak::Cm),
+    (0xE0020, 0xE007F, LineBreak::Cm),
+    (0xE0100, 0xE01EF, LineBreak::Cm),
+];
@@ -0,0 +1,524 @@
+//! Searching for any of a set of needles at once
+//!
+//! A [`MultiPattern`] finds the matches of all of its needles in a single pass over a string,
+//!  using an Aho–Corasick automaton over the code units of the needles.
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::pattern::{Pattern, RevPattern, StepSearcher};
+use crate::str::BasicStr;
+use crate::traits::{Char, CharTraits, IntoChars};
+
+/// How a [`MultiPattern`] chooses between the needles that match at the same position.
+///
+/// A match that begins earlier is always preferred, or one that ends later when searching from the end.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MatchKind {
+    /// Prefer the needle that was given first
+    #[default]
+    LeftmostFirst,
+    /// Prefer the longest needle, and then the needle that was given first
+    LeftmostLongest,
+}
+
+impl MatchKind {
+    /// Returns `true` if `a` is preferred to `b`, which begin at the same position
+    fn prefers(self, a: Match, b: Match) -> bool {
+        match self {
+            MatchKind::LeftmostFirst => a.needle < b.needle,
+            MatchKind::LeftmostLongest => {
+                a.len() > b.len() || (a.len() == b.len() && a.needle < b.needle)
+            }
+        }
+    }
+}
+
+/// A match of one of the needles of a [`MultiPattern`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// The index of the needle that matched, in the order that the needles were given
+    pub needle: usize,
+    /// The offset of the start of the match
+    pub start: usize,
+    /// The offset of the end of the match
+    pub end: usize,
+}
+
+impl Match {
+    /// Returns the length of the match
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the match is empty
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// A trie of needles with failure links, over units of type `C`
+struct Automaton<C> {
+    /// The transitions out of each state, sorted by unit
+    trans: Vec<Vec<(C, usize)>>,
+    /// The state of the longest proper suffix of each state that is also in the trie
+    fail: Vec<usize>,
+    /// The needles that end at each state, including those that end at the states reached by its failure links
+    out: Vec<Vec<usize>>,
+}
+
+impl<C: Char> Automaton<C> {
+    fn new<I: IntoIterator<Item = C>>(needles: impl Iterator<Item = I>) -> Self {
+        let mut this = Self {
+            trans: vec![Vec::new()],
+            fail: vec![0],
+            out: vec![Vec::new()],
+        };
+        for (needle, units) in needles.enumerate() {
+            let mut state = 0;
+            for c in units {
+                state = match this.find_trans(state, c) {
+                    Ok(i) => this.trans[state][i].1,
+                    Err(i) => {
+                        let next = this.trans.len();
+                        this.trans.push(Vec::new());
+                        this.fail.push(0);
+                        this.out.push(Vec::new());
+                        this.trans[state].insert(i, (c, next));
+                        next
+                    }
+                };
+            }
+            this.out[state].push(needle);
+        }
+
+        // Breadth-first, so that the failure links of shallower states are set first.
+        // The states one unit deep fail to the root
+        let mut queue: VecDeque<usize> = this.trans[0].iter().map(|&(_, next)| next).collect();
+        for &state in &queue {
+            let inherited = this.out[0].clone();
+            this.out[state].extend(inherited);
+        }
+        while let Some(state) = queue.pop_front() {
+            for i in 0..this.trans[state].len() {
+                let (c, next) = this.trans[state][i];
+                let fail = this.step(this.fail[state], c);
+                this.fail[next] = fail;
+                let inherited = this.out[fail].clone();
+                this.out[next].extend(inherited);
+                queue.push_back(next);
+            }
+        }
+        this
+    }
+
+    fn find_trans(&self, state: usize, c: C) -> Result<usize, usize> {
+        self.trans[state].binary_search_by(|&(u, _)| u.into_int().cmp(&c.into_int()))
+    }
+
+    /// Returns the state reached from `state` by the unit `c`
+    fn step(&self, mut state: usize, c: C) -> usize {
+        loop {
+            match self.find_trans(state, c) {
+                Ok(i) => return self.trans[state][i].1,
+                Err(_) if state == 0 => return 0,
+                Err(_) => state = self.fail[state],
+            }
+        }
+    }
+
+    /// Replaces `best` with any needle that ends at `end` in `state`, is accepted by `accept`, and is preferred to it
+    fn consider(
+        &self,
+        best: &mut Option<Match>,
+        state: usize,
+        end: usize,
+        lens: &[usize],
+        kind: MatchKind,
+        accept: &impl Fn(Match) -> bool,
+    ) {
+        for &needle in &self.out[state] {
+            let m = Match {
+                needle,
+                start: end - lens[needle],
+                end,
+            };
+            if !accept(m) {
+                continue;
+            }
+            match *best {
+                Some(b) if m.start > b.start || (m.start == b.start && !kind.prefers(m, b)) => {}
+                _ => *best = Some(m),
+            }
+        }
+    }
+
+    /// Returns the leftmost match in `haystack`, choosing between needles that begin at the same position by `kind`.
+    ///
+    /// `lens` are the lengths of the needles, the longest of which is `max_len`.
+    /// Matches that `accept` rejects are skipped, and the search continues past them.
+    fn leftmost(
+        &self,
+        haystack: impl Iterator<Item = C>,
+        lens: &[usize],
+        max_len: usize,
+        kind: MatchKind,
+        accept: impl Fn(Match) -> bool,
+    ) -> Option<Match> {
+        let mut best = None;
+        self.consider(&mut best, 0, 0, lens, kind, &accept);
+        let mut state = 0;
+        for (i, c) in haystack.enumerate() {
+            // No match that ends after this unit can begin at or before the best match so far
+            if best.is_some_and(|b: Match| i >= b.start + max_len) {
+                break;
+            }
+            state = self.step(state, c);
+            self.consider(&mut best, state, i + 1, lens, kind, &accept);
+        }
+        best
+    }
+}
+
+/// A [`Pattern`] that matches any of a set of needles.
+///
+/// Of the needles that match a string, the one that begins first is found, and [`MatchKind`] chooses between needles that begin at the same position.
+/// Searching from the end finds the one that ends last instead.
+pub struct MultiPattern<CharT, Traits> {
+    kind: MatchKind,
+    lens: Vec<usize>,
+    max_len: usize,
+    forward: Automaton<CharT>,
+    /// The automaton of the reversed needles
+    backward: Automaton<CharT>,
+    phantom: PhantomData<Traits>,
+}
+
+impl<Traits: CharTraits> MultiPattern<Traits::Char, Traits> {
+    /// Builds a pattern that matches any of `needles`, preferring the needle given first
+    pub fn new<'b>(needles: impl IntoIterator<Item = &'b BasicStr<Traits::Char, Traits>>) -> Self
+    where
+        Traits: 'b,
+    {
+        Self::with_match_kind(needles, MatchKind::LeftmostFirst)
+    }
+
+    /// Builds a pattern that matches any of `needles`, choosing between them by `kind`
+    pub fn with_match_kind<'b>(
+        needles: impl IntoIterator<Item = &'b BasicStr<Traits::Char, Traits>>,
+        kind: MatchKind,
+    ) -> Self
+    where
+        Traits: 'b,
+    {
+        let needles: Vec<_> = needles.into_iter().map(BasicStr::as_chars).collect();
+        let lens: Vec<_> = needles.iter().map(|needle| needle.len()).collect();
+        Self {
+            kind,
+            max_len: lens.iter().copied().max().unwrap_or(0),
+            lens,
+            forward: Automaton::new(needles.iter().map(|needle| needle.iter().copied())),
+            backward: Automaton::new(needles.iter().map(|needle| needle.iter().rev().copied())),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of needles
+    pub fn needle_count(&self) -> usize {
+        self.lens.len()
+    }
+
+    /// Returns how the pattern chooses between needles that match at the same position
+    pub fn match_kind(&self) -> MatchKind {
+        self.kind
+    }
+
+    /// Returns the first match in `slice`, which shall begin on a character boundary.
+    ///
+    /// In an encoding that is not self-synchronizing, a needle can also occur across characters of `slice`,
+    ///  so only matches that begin on a character boundary are found.
+    fn find_in(&self, slice: &[Traits::Char]) -> Option<Match> {
+        self.forward.leftmost(
+            slice.iter().copied(),
+            &self.lens,
+            self.max_len,
+            self.kind,
+            |m| Traits::is_char_boundary(slice, m.start),
+        )
+    }
+
+    /// Returns the last match in `slice`, which shall begin on a character boundary
+    fn rfind_in(&self, slice: &[Traits::Char]) -> Option<Match> {
+        // The automaton runs over the reversed slice, so `m.end` is the distance from the start of the match to the end of `slice`
+        let m = self.backward.leftmost(
+            slice.iter().rev().copied(),
+            &self.lens,
+            self.max_len,
+            self.kind,
+            |m| Traits::is_char_boundary(slice, slice.len() - m.end),
+        )?;
+        Some(Match {
+            needle: m.needle,
+            start: slice.len() - m.end,
+            end: slice.len() - m.start,
+        })
+    }
+
+    /// Returns the first match in `haystack`
+    pub fn find(&self, haystack: &BasicStr<Traits::Char, Traits>) -> Option<Match> {
+        self.find_in(haystack.as_chars())
+    }
+
+    /// Returns the last match in `haystack`
+    pub fn rfind(&self, haystack: &BasicStr<Traits::Char, Traits>) -> Option<Match> {
+        self.rfind_in(haystack.as_chars())
+    }
+}
+
+impl<Traits: IntoChars> MultiPattern<Traits::Char, Traits> {
+    /// Returns an iterator over the non-overlapping matches in `haystack`
+    pub fn find_iter<'a>(
+        &'a self,
+        haystack: &'a BasicStr<Traits::Char, Traits>,
+    ) -> FindIter<'a, Traits::Char, Traits> {
+        FindIter {
+            pat: self,
+            haystack,
+            pos: 0,
+            finished: false,
+        }
+    }
+}
+
+/// An iterator over the non-overlapping matches of a [`MultiPattern`], created by [`MultiPattern::find_iter`]
+pub struct FindIter<'a, CharT, Traits> {
+    pat: &'a MultiPattern<CharT, Traits>,
+    haystack: &'a BasicStr<CharT, Traits>,
+    pos: usize,
+    finished: bool,
+}
+
+impl<Traits: IntoChars> Iterator for FindIter<'_, Traits::Char, Traits> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        if self.finished {
+            return None;
+        }
+        let chars = self.haystack.as_chars();
+        let Some(mut m) = self.pat.find_in(&chars[self.pos..]) else {
+            self.finished = true;
+            return None;
+        };
+        m.start += self.pos;
+        m.end += self.pos;
+
+        self.pos = m.end;
+        if m.is_empty() {
+            // An empty match must not be found again, so step over the character that follows it
+            if self.pos == chars.len() {
+                self.finished = true;
+            } else {
+                self.pos += Traits::first_char_len(&chars[self.pos..]);
+            }
+        }
+        Some(m)
+    }
+}
+
+unsafe impl<Traits: CharTraits> Pattern<Traits::Char, Traits>
+    for MultiPattern<Traits::Char, Traits>
+{
+    type Searcher<'a>
+        = StepSearcher<'a, Self, Traits::Char, Traits>
+    where
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<Traits::Char, Traits>) -> Self::Searcher<'a> {
+        StepSearcher::new(haystack, self)
+    }
+
+    fn first_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        let m = self.find_in(slice)?;
+        Some(&slice[m.start..m.end])
+    }
+}
+
+unsafe impl<Traits: CharTraits> RevPattern<Traits::Char, Traits>
+    for MultiPattern<Traits::Char, Traits>
+{
+    fn last_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        let m = self.rfind_in(slice)?;
+        Some(&slice[m.start..m.end])
+    }
+}
+
+unsafe impl<Traits: CharTraits> Pattern<Traits::Char, Traits>
+    for &MultiPattern<Traits::Char, Traits>
+{
+    type Searcher<'a>
+        = StepSearcher<'a, Self, Traits::Char, Traits>
+    where
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<Traits::Char, Traits>) -> Self::Searcher<'a> {
+        StepSearcher::new(haystack, self)
+    }
+
+    fn first_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        (**self).first_match(slice)
+    }
+}
+
+unsafe impl<Traits: CharTraits> RevPattern<Traits::Char, Traits>
+    for &MultiPattern<Traits::Char, Traits>
+{
+    fn last_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        (**self).last_match(slice)
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::{Match, MatchKind, MultiPattern};
+    use crate::str::{Str, U16Str};
+
+    /// Finds the match that `MultiPattern` should, by trying every needle at every position
+    fn naive(needles: &[&str], haystack: &str, kind: MatchKind, rev: bool) -> Option<Match> {
+        let mut positions: Vec<usize> = (0..=haystack.len()).collect();
+        if rev {
+            positions.reverse();
+        }
+        positions.into_iter().find_map(|pos| {
+            let candidates = needles.iter().enumerate().filter_map(|(needle, s)| {
+                let start = if rev { pos.checked_sub(s.len())? } else { pos };
+                haystack[start..].starts_with(s).then_some(Match {
+                    needle,
+                    start,
+                    end: start + s.len(),
+                })
+            });
+            candidates.reduce(|best, m| if kind.prefers(m, best) { m } else { best })
+        })
+    }
+
+    #[test]
+    fn test_multi_pattern() {
+        const NEEDLE_SETS: &[&[&str]] = &[
+            &["a", "ab", "bab", "b"],
+            &["ab", "a"],
+            &["aa", "aaa", "ba"],
+            &["abba", "bb", "b"],
+            &["", "a"],
+            &["bab", "abab", "aa"],
+            &[],
+        ];
+
+        let mut haystacks = vec![String::new()];
+        for len in 1..=7 {
+            for bits in 0..1u32 << len {
+                haystacks.push(
+                    (0..len)
+                        .map(|i| if bits >> i & 1 == 0 { 'a' } else { 'b' })
+                        .collect(),
+                );
+            }
+        }
+
+        for &needles in NEEDLE_SETS {
+            for kind in [MatchKind::LeftmostFirst, MatchKind::LeftmostLongest] {
+                let pat =
+                    MultiPattern::with_match_kind(needles.iter().map(|&s| Str::from_str(s)), kind);
+                assert_eq!(pat.needle_count(), needles.len());
+                for haystack in &haystacks {
+                    let s = Str::from_str(haystack);
+                    let msg = format!("{needles:?} in {haystack:?} ({kind:?})");
+                    assert_eq!(pat.find(s), naive(needles, haystack, kind, false), "{msg}");
+                    assert_eq!(pat.rfind(s), naive(needles, haystack, kind, true), "{msg}");
+                    assert_eq!(s.find(&pat), pat.find(s).map(|m| m.start), "{msg}");
+                    assert_eq!(s.rfind(&pat), pat.rfind(s).map(|m| m.start), "{msg}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_pattern_utf16() {
+        let text = "Secret \u{1f600} secrets, secretary";
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let s = U16Str::from_chars(&units).unwrap();
+        let needles: Vec<Vec<u16>> = ["secret", "secretary", "\u{1f600}"]
+            .iter()
+            .map(|needle| needle.encode_utf16().collect())
+            .collect();
+        let needles: Vec<&U16Str> = needles
+            .iter()
+            .map(|needle| U16Str::from_chars(needle).unwrap())
+            .collect();
+
+        let pat = MultiPattern::new(needles.iter().copied());
+        let found: Vec<_> = pat.find_iter(s).map(|m| (m.needle, m.start)).collect();
+        assert_eq!(found, [(2, 7), (0, 10), (0, 19)]);
+
+        let pat =
+            MultiPattern::with_match_kind(needles.iter().copied(), MatchKind::LeftmostLongest);
+        let found: Vec<_> = pat.find_iter(s).map(|m| (m.needle, m.start)).collect();
+        assert_eq!(found, [(2, 7), (0, 10), (1, 19)]);
+        assert_eq!(
+            s.rmatch_indices(&pat).map(|(i, _)| i).collect::<Vec<_>>(),
+            [19, 10, 7]
+        );
+
+        let pieces: Vec<String> = s
+            .split(pat)
+            .map(|piece| String::from_utf16(piece.as_chars()).unwrap())
+            .collect();
+        assert_eq!(pieces, ["Secret ", " ", "s, ", ""]);
+
+        let empty = MultiPattern::new([Str::from_str("")]);
+        let found: Vec<_> = empty
+            .find_iter(Str::from_str("a\u{e9}"))
+            .map(|m| m.start)
+            .collect();
+        assert_eq!(found, [0, 1, 3]);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn test_multi_pattern_on_boundaries() {
+        use crate::encoding::{Utf16BeCharTraits, Utf16LeCharTraits};
+        use crate::str::BasicStr;
+
+        // The needle U+0041 also occurs across the units U+4141 and U+0000, or U+4100 and U+4100
+        let cases: [(&[u8], &[u8], &[u8]); 2] = [
+            (b"\x42\x00\x41\x41\x00\x00", b"\x41\x00", b"\x42\x00"),
+            (b"\x00\x42\x41\x00\x41\x00", b"\x00\x41", b"\x00\x42"),
+        ];
+        fn check<Traits: crate::traits::IntoChars<Char = u8>>(haystack: &[u8], a: &[u8], b: &[u8]) {
+            let s = BasicStr::<u8, Traits>::from_chars(haystack).unwrap();
+            let a = BasicStr::<u8, Traits>::from_chars(a).unwrap();
+            let b = BasicStr::<u8, Traits>::from_chars(b).unwrap();
+            let b_match = Match {
+                needle: 1,
+                start: 0,
+                end: 2,
+            };
+
+            let pat = MultiPattern::new([a]);
+            assert_eq!(pat.find(s), None);
+            assert_eq!(pat.rfind(s), None);
+            assert_eq!(pat.find_iter(s).count(), 0);
+            assert!(!s.contains(&pat));
+
+            let pat = MultiPattern::new([a, b]);
+            assert_eq!(pat.find(s), Some(b_match));
+            assert_eq!(pat.rfind(s), Some(b_match));
+            assert_eq!(pat.find_iter(s).collect::<Vec<_>>(), [b_match]);
+            assert_eq!(s.rfind(&pat), Some(0));
+        }
+        check::<Utf16LeCharTraits>(cases[0].0, cases[0].1, cases[0].2);
+        check::<Utf16BeCharTraits>(cases[1].0, cases[1].1, cases[1].2);
+    }
+}
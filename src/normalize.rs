@@ -0,0 +1,558 @@
+//! Unicode normalization (UAX #15) for [`BasicStr`]
+//!
+//! The tables are generated by `tools/gen_normalization_tables.py`.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use crate::str::UnicodeIter;
+#[cfg(feature = "alloc")]
+use crate::string::BasicString;
+use crate::{str::BasicStr, traits::IntoChars};
+
+#[rustfmt::skip]
+mod tables;
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// A Unicode normalization form
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// The result of a normalization quick check
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IsNormalized {
+    /// The string is normalized
+    Yes,
+    /// The string is not normalized
+    No,
+    /// The string may or may not be normalized, which can only be determined by normalizing it
+    Maybe,
+}
+
+fn range_cmp(lo: u32, hi: u32, c: u32) -> Ordering {
+    if hi < c {
+        Ordering::Less
+    } else if lo > c {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+fn in_ranges(ranges: &[(u32, u32)], c: char) -> bool {
+    ranges
+        .binary_search_by(|&(lo, hi)| range_cmp(lo, hi, c as u32))
+        .is_ok()
+}
+
+fn is_hangul_syllable(c: char) -> bool {
+    (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&(c as u32))
+}
+
+/// Returns the Canonical_Combining_Class of `c`
+pub fn canonical_combining_class(c: char) -> u8 {
+    tables::COMBINING_CLASS
+        .binary_search_by(|&(lo, hi, _)| range_cmp(lo, hi, c as u32))
+        .map_or(0, |i| tables::COMBINING_CLASS[i].2)
+}
+
+fn lookup_decomposition(
+    table: &[(char, u16, u8)],
+    chars: &'static [char],
+    c: char,
+) -> Option<&'static [char]> {
+    let i = table.binary_search_by_key(&c, |&(k, _, _)| k).ok()?;
+    let (_, offset, len) = table[i];
+    Some(&chars[offset as usize..][..len as usize])
+}
+
+/// Calls `f` with each character of the full decomposition of `c`, which is the compatibility decomposition if `compatible` is `true`,
+///  and the canonical decomposition otherwise
+fn decompose(c: char, compatible: bool, mut f: impl FnMut(char)) {
+    if is_hangul_syllable(c) {
+        let index = c as u32 - HANGUL_S_BASE;
+        let l = HANGUL_L_BASE + index / HANGUL_N_COUNT;
+        let v = HANGUL_V_BASE + (index % HANGUL_N_COUNT) / HANGUL_T_COUNT;
+        let t = HANGUL_T_BASE + index % HANGUL_T_COUNT;
+
+        // SAFETY:
+        // Hangul jamo are in the BMP, outside of the surrogate range
+        unsafe {
+            f(char::from_u32_unchecked(l));
+            f(char::from_u32_unchecked(v));
+            if t != HANGUL_T_BASE {
+                f(char::from_u32_unchecked(t));
+            }
+        }
+        return;
+    }
+
+    let decomposed = compatible
+        .then(|| lookup_decomposition(&tables::COMPATIBLE, &tables::COMPATIBLE_CHARS, c))
+        .flatten()
+        .or_else(|| lookup_decomposition(&tables::CANONICAL, &tables::CANONICAL_CHARS, c));
+    match decomposed {
+        Some(chars) => chars.iter().copied().for_each(f),
+        None => f(c),
+    }
+}
+
+/// Calls `f` with each character of the full canonical decomposition of `c`
+pub fn decompose_canonical(c: char, f: impl FnMut(char)) {
+    decompose(c, false, f)
+}
+
+/// Calls `f` with each character of the full compatibility decomposition of `c`
+pub fn decompose_compatible(c: char, f: impl FnMut(char)) {
+    decompose(c, true, f)
+}
+
+/// Returns the primary composite of `a` followed by `b`, if any
+pub fn compose(a: char, b: char) -> Option<char> {
+    let (a32, b32) = (a as u32, b as u32);
+    if (HANGUL_L_BASE..HANGUL_L_BASE + HANGUL_L_COUNT).contains(&a32)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&b32)
+    {
+        let index = (a32 - HANGUL_L_BASE) * HANGUL_N_COUNT + (b32 - HANGUL_V_BASE) * HANGUL_T_COUNT;
+        return char::from_u32(HANGUL_S_BASE + index);
+    }
+    if is_hangul_syllable(a)
+        && (a32 - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT)
+        && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&b32)
+    {
+        return char::from_u32(a32 + (b32 - HANGUL_T_BASE));
+    }
+
+    tables::COMPOSITIONS
+        .binary_search_by_key(&(a, b), |&(a, b, _)| (a, b))
+        .ok()
+        .map(|i| tables::COMPOSITIONS[i].2)
+}
+
+fn quick_check_char(c: char, form: NormalizationForm) -> IsNormalized {
+    let no = match form {
+        NormalizationForm::Nfd => is_hangul_syllable(c) || in_ranges(&tables::NFD_QC_NO, c),
+        NormalizationForm::Nfkd => is_hangul_syllable(c) || in_ranges(&tables::NFKD_QC_NO, c),
+        NormalizationForm::Nfc => in_ranges(&tables::NFC_QC_NO, c),
+        NormalizationForm::Nfkc => in_ranges(&tables::NFKC_QC_NO, c),
+    };
+    let maybe = matches!(form, NormalizationForm::Nfc | NormalizationForm::Nfkc)
+        && in_ranges(&tables::NFC_QC_MAYBE, c);
+
+    if no {
+        IsNormalized::No
+    } else if maybe {
+        IsNormalized::Maybe
+    } else {
+        IsNormalized::Yes
+    }
+}
+
+/// Performs the normalization quick check algorithm on `chars`, without allocating
+pub fn quick_check(chars: impl IntoIterator<Item = char>, form: NormalizationForm) -> IsNormalized {
+    let mut last_class = 0;
+    let mut result = IsNormalized::Yes;
+    for c in chars {
+        let class = canonical_combining_class(c);
+        if class != 0 && last_class > class {
+            return IsNormalized::No;
+        }
+        match quick_check_char(c, form) {
+            IsNormalized::Yes => {}
+            IsNormalized::No => return IsNormalized::No,
+            IsNormalized::Maybe => result = IsNormalized::Maybe,
+        }
+        last_class = class;
+    }
+    result
+}
+
+/// An iterator over the canonical or compatibility decomposition of the characters of `I`
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Decompositions<I> {
+    iter: core::iter::Fuse<I>,
+    compatible: bool,
+    // Decomposed characters with their combining classes. Those in `..ready` are in canonical order,
+    //  and the rest are a run of non-starters that may still need to be reordered
+    buffer: Vec<(u8, char)>,
+    next: usize,
+    ready: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Decompositions<I> {
+    fn new(iter: I, compatible: bool) -> Self {
+        Self {
+            iter: iter.fuse(),
+            compatible,
+            buffer: Vec::new(),
+            next: 0,
+            ready: 0,
+        }
+    }
+
+    fn sort_pending(&mut self) {
+        // Stable, so characters of the same combining class keep their order
+        self.buffer[self.ready..].sort_by_key(|&(class, _)| class);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Iterator for Decompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.next == self.ready {
+            self.buffer.drain(..self.ready);
+            self.next = 0;
+            self.ready = 0;
+
+            match self.iter.next() {
+                Some(c) => {
+                    let Self {
+                        buffer,
+                        ready,
+                        compatible,
+                        ..
+                    } = self;
+                    decompose(c, *compatible, |c| {
+                        let class = canonical_combining_class(c);
+                        if class == 0 {
+                            buffer[*ready..].sort_by_key(|&(class, _)| class);
+                            *ready = buffer.len() + 1;
+                        }
+                        buffer.push((class, c));
+                    });
+                }
+                None if self.buffer.is_empty() => return None,
+                None => {
+                    self.sort_pending();
+                    self.ready = self.buffer.len();
+                }
+            }
+        }
+
+        let (_, c) = self.buffer[self.next];
+        self.next += 1;
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.iter.size_hint();
+        (lower + self.buffer.len() - self.next, None)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+enum RecompositionState {
+    Composing,
+    Purging(usize),
+    Finished(usize),
+}
+
+/// An iterator over the canonical composition of the canonical or compatibility decomposition of the characters of `I`
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Recompositions<I> {
+    iter: Decompositions<I>,
+    state: RecompositionState,
+    // Characters following `composee` that were not composed with it
+    buffer: Vec<char>,
+    composee: Option<char>,
+    last_class: Option<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Recompositions<I> {
+    fn new(iter: I, compatible: bool) -> Self {
+        Self {
+            iter: Decompositions::new(iter, compatible),
+            state: RecompositionState::Composing,
+            buffer: Vec::new(),
+            composee: None,
+            last_class: None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Iterator for Recompositions<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            match self.state {
+                RecompositionState::Composing => {
+                    for c in self.iter.by_ref() {
+                        let class = canonical_combining_class(c);
+                        let Some(composee) = self.composee else {
+                            if class != 0 {
+                                return Some(c);
+                            }
+                            self.composee = Some(c);
+                            continue;
+                        };
+
+                        match self.last_class {
+                            // `c` is blocked from `composee` by a character of the same or a higher class
+                            Some(last_class) if last_class >= class => {
+                                if class == 0 {
+                                    self.composee = Some(c);
+                                    self.last_class = None;
+                                    self.state = RecompositionState::Purging(0);
+                                    return Some(composee);
+                                }
+                                self.buffer.push(c);
+                                self.last_class = Some(class);
+                            }
+                            last_class => match compose(composee, c) {
+                                Some(composed) => self.composee = Some(composed),
+                                None if class == 0 && last_class.is_none() => {
+                                    self.composee = Some(c);
+                                    return Some(composee);
+                                }
+                                None => {
+                                    self.buffer.push(c);
+                                    self.last_class = Some(class);
+                                }
+                            },
+                        }
+                    }
+                    self.state = RecompositionState::Finished(0);
+                    if self.composee.is_some() {
+                        return self.composee.take();
+                    }
+                }
+                RecompositionState::Purging(next) => match self.buffer.get(next) {
+                    Some(&c) => {
+                        self.state = RecompositionState::Purging(next + 1);
+                        return Some(c);
+                    }
+                    None => {
+                        self.buffer.clear();
+                        self.state = RecompositionState::Composing;
+                    }
+                },
+                RecompositionState::Finished(next) => match self.buffer.get(next) {
+                    Some(&c) => {
+                        self.state = RecompositionState::Finished(next + 1);
+                        return Some(c);
+                    }
+                    None => {
+                        self.buffer.clear();
+                        return self.composee.take();
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Performs the normalization quick check on `self`, without allocating.
+    ///
+    /// The result is never [`IsNormalized::Maybe`] for [`NormalizationForm::Nfd`] and [`NormalizationForm::Nfkd`].
+    pub fn is_normalized_quick(&self, form: NormalizationForm) -> IsNormalized {
+        quick_check(self.unicode_iter(), form)
+    }
+
+    /// Returns `true` if `self` is in the normalization form `form`.
+    ///
+    /// This only allocates if the quick check is inconclusive.
+    #[cfg(feature = "alloc")]
+    pub fn is_normalized(&self, form: NormalizationForm) -> bool {
+        match self.is_normalized_quick(form) {
+            IsNormalized::Yes => true,
+            IsNormalized::No => false,
+            // Only the composed forms have a `Maybe` quick check result
+            IsNormalized::Maybe => self.unicode_iter().eq(Recompositions::new(
+                self.unicode_iter(),
+                form == NormalizationForm::Nfkc,
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the characters of `self` in Normalization Form C
+    pub fn nfc(&self) -> Recompositions<UnicodeIter<'_, Traits::Char, Traits>> {
+        Recompositions::new(self.unicode_iter(), false)
+    }
+
+    /// Returns an iterator over the characters of `self` in Normalization Form D
+    pub fn nfd(&self) -> Decompositions<UnicodeIter<'_, Traits::Char, Traits>> {
+        Decompositions::new(self.unicode_iter(), false)
+    }
+
+    /// Returns an iterator over the characters of `self` in Normalization Form KC
+    pub fn nfkc(&self) -> Recompositions<UnicodeIter<'_, Traits::Char, Traits>> {
+        Recompositions::new(self.unicode_iter(), true)
+    }
+
+    /// Returns an iterator over the characters of `self` in Normalization Form KD
+    pub fn nfkd(&self) -> Decompositions<UnicodeIter<'_, Traits::Char, Traits>> {
+        Decompositions::new(self.unicode_iter(), true)
+    }
+
+    /// Returns `self` in Normalization Form C
+    pub fn to_nfc(&self) -> BasicString<Traits::Char, Traits> {
+        self.collect_normalized(self.nfc())
+    }
+
+    /// Returns `self` in Normalization Form D
+    pub fn to_nfd(&self) -> BasicString<Traits::Char, Traits> {
+        self.collect_normalized(self.nfd())
+    }
+
+    /// Returns `self` in Normalization Form KC
+    pub fn to_nfkc(&self) -> BasicString<Traits::Char, Traits> {
+        self.collect_normalized(self.nfkc())
+    }
+
+    /// Returns `self` in Normalization Form KD
+    pub fn to_nfkd(&self) -> BasicString<Traits::Char, Traits> {
+        self.collect_normalized(self.nfkd())
+    }
+
+    /// Returns `self` in the normalization form `form`
+    pub fn to_normalized(&self, form: NormalizationForm) -> BasicString<Traits::Char, Traits> {
+        match form {
+            NormalizationForm::Nfc => self.to_nfc(),
+            NormalizationForm::Nfd => self.to_nfd(),
+            NormalizationForm::Nfkc => self.to_nfkc(),
+            NormalizationForm::Nfkd => self.to_nfkd(),
+        }
+    }
+
+    fn collect_normalized(
+        &self,
+        iter: impl Iterator<Item = char>,
+    ) -> BasicString<Traits::Char, Traits> {
+        let mut out = BasicString::with_capacity(self.len());
+        iter.for_each(|c| out.push(c));
+        out
+    }
+}
+
+#[cfg(all(test, feature = "utf", feature = "alloc"))]
+mod test {
+    use super::{IsNormalized, NormalizationForm};
+    use crate::str::{Str, U16Str};
+
+    #[test]
+    fn test_normalize() {
+        // The input, followed by its NFC, NFD, NFKC and NFKD forms
+        let cases = [
+            // Precomposed, decomposed, and out-of-order combining marks
+            [
+                "caf\u{e9}",
+                "caf\u{e9}",
+                "cafe\u{301}",
+                "caf\u{e9}",
+                "cafe\u{301}",
+            ],
+            [
+                "cafe\u{301}",
+                "caf\u{e9}",
+                "cafe\u{301}",
+                "caf\u{e9}",
+                "cafe\u{301}",
+            ],
+            [
+                "a\u{302}\u{323}b",
+                "\u{1ead}b",
+                "a\u{323}\u{302}b",
+                "\u{1ead}b",
+                "a\u{323}\u{302}b",
+            ],
+            // Hangul syllables and jamo
+            [
+                "\u{d55c}\u{1100}\u{1161}\u{11a8}",
+                "\u{d55c}\u{ac01}",
+                "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1161}\u{11a8}",
+                "\u{d55c}\u{ac01}",
+                "\u{1112}\u{1161}\u{11ab}\u{1100}\u{1161}\u{11a8}",
+            ],
+            // Compatibility characters, a composition exclusion and a singleton
+            [
+                "\u{fb01}\u{2460}\u{958}\u{212b}",
+                "\u{fb01}\u{2460}\u{915}\u{93c}\u{c5}",
+                "\u{fb01}\u{2460}\u{915}\u{93c}A\u{30a}",
+                "fi1\u{915}\u{93c}\u{c5}",
+                "fi1\u{915}\u{93c}A\u{30a}",
+            ],
+        ];
+        let forms = [
+            NormalizationForm::Nfc,
+            NormalizationForm::Nfd,
+            NormalizationForm::Nfkc,
+            NormalizationForm::Nfkd,
+        ];
+        for [input, expected @ ..] in cases {
+            let units: Vec<u16> = input.encode_utf16().collect();
+            let s = U16Str::from_chars(&units).unwrap();
+            for (form, expected) in forms.into_iter().zip(expected) {
+                let normalized = s.to_normalized(form);
+                assert_eq!(
+                    String::from_utf16(normalized.as_chars()).unwrap(),
+                    expected,
+                    "{:?} of {:?}",
+                    form,
+                    input
+                );
+                assert!(normalized.is_normalized(form));
+                assert_eq!(s.is_normalized(form), input == expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quick_check() {
+        let s = Str::from_str("cafe\u{301}");
+        assert_eq!(
+            s.is_normalized_quick(NormalizationForm::Nfd),
+            IsNormalized::Yes
+        );
+        assert_eq!(
+            s.is_normalized_quick(NormalizationForm::Nfc),
+            IsNormalized::Maybe
+        );
+        let s = Str::from_str("caf\u{e9}");
+        assert_eq!(
+            s.is_normalized_quick(NormalizationForm::Nfd),
+            IsNormalized::No
+        );
+        assert_eq!(
+            s.is_normalized_quick(NormalizationForm::Nfc),
+            IsNormalized::Yes
+        );
+        let s = Str::from_str("a\u{302}\u{323}");
+        assert_eq!(
+            s.is_normalized_quick(NormalizationForm::Nfd),
+            IsNormalized::No
+        );
+    }
+}
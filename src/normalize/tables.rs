@@ -0,0 +1,9388 @@
+// Generated by tools/gen_normalization_tables.py. Do not edit.
+
+// Unicode Character Database version 15.0.0
+
+/// Ranges of characters with a non-zero Canonical_Combining_Class, and that class
+pub(super) static COMBINING_CLASS: [(u32, u32, u8); 388] = [
+    (0x0300, 0x0314, 230),
+    (0x0315, 0x0315, 232),
+    (0x0316, 0x0319, 220),
+    (0x031A, 0x031A, 232),
+    (0x031B, 0x031B, 216),
+    (0x031C, 0x0320, 220),
+    (0x0321, 0x0322, 202),
+    (0x0323, 0x0326, 220),
+    (0x0327, 0x0328, 202),
+    (0x0329, 0x0333, 220),
+    (0x0334, 0x0338, 1),
+    (0x0339, 0x033C, 220),
+    (0x033D, 0x0344, 230),
+    (0x0345, 0x0345, 240),
+    (0x0346, 0x0346, 230),
+    (0x0347, 0x0349, 220),
+    (0x034A, 0x034C, 230),
+    (0x034D, 0x034E, 220),
+    (0x0350, 0x0352, 230),
+    (0x0353, 0x0356, 220),
+    (0x0357, 0x0357, 230),
+    (0x0358, 0x0358, 232),
+    (0x0359, 0x035A, 220),
+    (0x035B, 0x035B, 230),
+    (0x035C, 0x035C, 233),
+    (0x035D, 0x035E, 234),
+    (0x035F, 0x035F, 233),
+    (0x0360, 0x0361, 234),
+    (0x0362, 0x0362, 233),
+    (0x0363, 0x036F, 230),
+    (0x0483, 0x0487, 230),
+    (0x0591, 0x0591, 220),
+    (0x0592, 0x0595, 230),
+    (0x0596, 0x0596, 220),
+    (0x0597, 0x0599, 230),
+    (0x059A, 0x059A, 222),
+    (0x059B, 0x059B, 220),
+    (0x059C, 0x05A1, 230),
+    (0x05A2, 0x05A7, 220),
+    (0x05A8, 0x05A9, 230),
+    (0x05AA, 0x05AA, 220),
+    (0x05AB, 0x05AC, 230),
+    (0x05AD, 0x05AD, 222),
+    (0x05AE, 0x05AE, 228),
+    (0x05AF, 0x05AF, 230),
+    (0x05B0, 0x05B0, 10),
+    (0x05B1, 0x05B1, 11),
+    (0x05B2, 0x05B2, 12),
+    (0x05B3, 0x05B3, 13),
+    (0x05B4, 0x05B4, 14),
+    (0x05B5, 0x05B5, 15),
+    (0x05B6, 0x05B6, 16),
+    (0x05B7, 0x05B7, 17),
+    (0x05B8, 0x05B8, 18),
+    (0x05B9, 0x05BA, 19),
+    (0x05BB, 0x05BB, 20),
+    (0x05BC, 0x05BC, 21),
+    (0x05BD, 0x05BD, 22),
+    (0x05BF, 0x05BF, 23),
+    (0x05C1, 0x05C1, 24),
+    (0x05C2, 0x05C2, 25),
+    (0x05C4, 0x05C4, 230),
+    (0x05C5, 0x05C5, 220),
+    (0x05C7, 0x05C7, 18),
+    (0x0610, 0x0617, 230),
+    (0x0618, 0x0618, 30),
+    (0x0619, 0x0619, 31),
+    (0x061A, 0x061A, 32),
+    (0x064B, 0x064B, 27),
+    (0x064C, 0x064C, 28),
+    (0x064D, 0x064D, 29),
+    (0x064E, 0x064E, 30),
+    (0x064F, 0x064F, 31),
+    (0x0650, 0x0650, 32),
+    (0x0651, 0x0651, 33),
+    (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230),
+    (0x0655, 0x0656, 220),
+    (0x0657, 0x065B, 230),
+    (0x065C, 0x065C, 220),
+    (0x065D, 0x065E, 230),
+    (0x065F, 0x065F, 220),
+    (0x0670, 0x0670, 35),
+    (0x06D6, 0x06DC, 230),
+    (0x06DF, 0x06E2, 230),
+    (0x06E3, 0x06E3, 220),
+    (0x06E4, 0x06E4, 230),
+    (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220),
+    (0x06EB, 0x06EC, 230),
+    (0x06ED, 0x06ED, 220),
+    (0x0711, 0x0711, 36),
+    (0x0730, 0x0730, 230),
+    (0x0731, 0x0731, 220),
+    (0x0732, 0x0733, 230),
+    (0x0734, 0x0734, 220),
+    (0x0735, 0x0736, 230),
+    (0x0737, 0x0739, 220),
+    (0x073A, 0x073A, 230),
+    (0x073B, 0x073C, 220),
+    (0x073D, 0x073D, 230),
+    (0x073E, 0x073E, 220),
+    (0x073F, 0x0741, 230),
+    (0x0742, 0x0742, 220),
+    (0x0743, 0x0743, 230),
+    (0x0744, 0x0744, 220),
+    (0x0745, 0x0745, 230),
+    (0x0746, 0x0746, 220),
+    (0x0747, 0x0747, 230),
+    (0x0748, 0x0748, 220),
+    (0x0749, 0x074A, 230),
+    (0x07EB, 0x07F1, 230),
+    (0x07F2, 0x07F2, 220),
+    (0x07F3, 0x07F3, 230),
+    (0x07FD, 0x07FD, 220),
+    (0x0816, 0x0819, 230),
+    (0x081B, 0x0823, 230),
+    (0x0825, 0x0827, 230),
+    (0x0829, 0x082D, 230),
+    (0x0859, 0x085B, 220),
+    (0x0898, 0x0898, 230),
+    (0x0899, 0x089B, 220),
+    (0x089C, 0x089F, 230),
+    (0x08CA, 0x08CE, 230),
+    (0x08CF, 0x08D3, 220),
+    (0x08D4, 0x08E1, 230),
+    (0x08E3, 0x08E3, 220),
+    (0x08E4, 0x08E5, 230),
+    (0x08E6, 0x08E6, 220),
+    (0x08E7, 0x08E8, 230),
+    (0x08E9, 0x08E9, 220),
+    (0x08EA, 0x08EC, 230),
+    (0x08ED, 0x08EF, 220),
+    (0x08F0, 0x08F0, 27),
+    (0x08F1, 0x08F1, 28),
+    (0x08F2, 0x08F2, 29),
+    (0x08F3, 0x08F5, 230),
+    (0x08F6, 0x08F6, 220),
+    (0x08F7, 0x08F8, 230),
+    (0x08F9, 0x08FA, 220),
+    (0x08FB, 0x08FF, 230),
+    (0x093C, 0x093C, 7),
+    (0x094D, 0x094D, 9),
+    (0x0951, 0x0951, 230),
+    (0x0952, 0x0952, 220),
+    (0x0953, 0x0954, 230),
+    (0x09BC, 0x09BC, 7),
+    (0x09CD, 0x09CD, 9),
+    (0x09FE, 0x09FE, 230),
+    (0x0A3C, 0x0A3C, 7),
+    (0x0A4D, 0x0A4D, 9),
+    (0x0ABC, 0x0ABC, 7),
+    (0x0ACD, 0x0ACD, 9),
+    (0x0B3C, 0x0B3C, 7),
+    (0x0B4D, 0x0B4D, 9),
+    (0x0BCD, 0x0BCD, 9),
+    (0x0C3C, 0x0C3C, 7),
+    (0x0C4D, 0x0C4D, 9),
+    (0x0C55, 0x0C55, 84),
+    (0x0C56, 0x0C56, 91),
+    (0x0CBC, 0x0CBC, 7),
+    (0x0CCD, 0x0CCD, 9),
+    (0x0D3B, 0x0D3C, 9),
+    (0x0D4D, 0x0D4D, 9),
+    (0x0DCA, 0x0DCA, 9),
+    (0x0E38, 0x0E39, 103),
+    (0x0E3A, 0x0E3A, 9),
+    (0x0E48, 0x0E4B, 107),
+    (0x0EB8, 0x0EB9, 118),
+    (0x0EBA, 0x0EBA, 9),
+    (0x0EC8, 0x0ECB, 122),
+    (0x0F18, 0x0F19, 220),
+    (0x0F35, 0x0F35, 220),
+    (0x0F37, 0x0F37, 220),
+    (0x0F39, 0x0F39, 216),
+    (0x0F71, 0x0F71, 129),
+    (0x0F72, 0x0F72, 130),
+    (0x0F74, 0x0F74, 132),
+    (0x0F7A, 0x0F7D, 130),
+    (0x0F80, 0x0F80, 130),
+    (0x0F82, 0x0F83, 230),
+    (0x0F84, 0x0F84, 9),
+    (0x0F86, 0x0F87, 230),
+    (0x0FC6, 0x0FC6, 220),
+    (0x1037, 0x1037, 7),
+    (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220),
+    (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9),
+    (0x1734, 0x1734, 9),
+    (0x17D2, 0x17D2, 9),
+    (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228),
+    (0x1939, 0x1939, 222),
+    (0x193A, 0x193A, 230),
+    (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230),
+    (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9),
+    (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220),
+    (0x1AB0, 0x1AB4, 230),
+    (0x1AB5, 0x1ABA, 220),
+    (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220),
+    (0x1ABF, 0x1AC0, 220),
+    (0x1AC1, 0x1AC2, 230),
+    (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230),
+    (0x1ACA, 0x1ACA, 220),
+    (0x1ACB, 0x1ACE, 230),
+    (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9),
+    (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9),
+    (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230),
+    (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220),
+    (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230),
+    (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230),
+    (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214),
+    (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232),
+    (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220),
+    (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230),
+    (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220),
+    (0x20D0, 0x20D1, 230),
+    (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1),
+    (0x20DB, 0x20DC, 230),
+    (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230),
+    (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220),
+    (0x20F0, 0x20F0, 230),
+    (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230),
+    (0x302A, 0x302A, 218),
+    (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222),
+    (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230),
+    (0xA69E, 0xA69F, 230),
+    (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9),
+    (0xA8C4, 0xA8C4, 9),
+    (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9),
+    (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230),
+    (0xAAB4, 0xAAB4, 220),
+    (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230),
+    (0xAAF6, 0xAAF6, 9),
+    (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230),
+    (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220),
+    (0x10376, 0x1037A, 230),
+    (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230),
+    (0x10A39, 0x10A39, 1),
+    (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230),
+    (0x10AE6, 0x10AE6, 220),
+    (0x10D24, 0x10D27, 230),
+    (0x10EAB, 0x10EAC, 230),
+    (0x10EFD, 0x10EFF, 220),
+    (0x10F46, 0x10F47, 220),
+    (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220),
+    (0x10F82, 0x10F82, 230),
+    (0x10F83, 0x10F83, 220),
+    (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220),
+    (0x11046, 0x11046, 9),
+    (0x11070, 0x11070, 9),
+    (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9),
+    (0x110BA, 0x110BA, 7),
+    (0x11100, 0x11102, 230),
+    (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7),
+    (0x111C0, 0x111C0, 9),
+    (0x111CA, 0x111CA, 7),
+    (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7),
+    (0x112E9, 0x112E9, 7),
+    (0x112EA, 0x112EA, 9),
+    (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9),
+    (0x11366, 0x1136C, 230),
+    (0x11370, 0x11374, 230),
+    (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7),
+    (0x1145E, 0x1145E, 230),
+    (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9),
+    (0x115C0, 0x115C0, 7),
+    (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9),
+    (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9),
+    (0x11943, 0x11943, 7),
+    (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9),
+    (0x11A99, 0x11A99, 9),
+    (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9),
+    (0x11F41, 0x11F42, 9),
+    (0x16AF0, 0x16AF4, 1),
+    (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1),
+    (0x1D165, 0x1D166, 216),
+    (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220),
+    (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230),
+    (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230),
+    (0x1E026, 0x1E02A, 230),
+    (0x1E08F, 0x1E08F, 230),
+    (0x1E130, 0x1E136, 230),
+    (0x1E2AE, 0x1E2AE, 230),
+    (0x1E2EC, 0x1E2EF, 230),
+    (0x1E4EC, 0x1E4ED, 232),
+    (0x1E4EE, 0x1E4EE, 220),
+    (0x1E4EF, 0x1E4EF, 230),
+    (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+/// Full canonical decompositions, as offsets and lengths into `CANONICAL_CHARS`
+pub(super) static CANONICAL: [(char, u16, u8); 2061] = [
+    ('\u{00C0}', 0, 2),
+    ('\u{00C1}', 2, 2),
+    ('\u{00C2}', 4, 2),
+    ('\u{00C3}', 6, 2),
+    ('\u{00C4}', 8, 2),
+    ('\u{00C5}', 10, 2),
+    ('\u{00C7}', 12, 2),
+    ('\u{00C8}', 14, 2),
+    ('\u{00C9}', 16, 2),
+    ('\u{00CA}', 18, 2),
+    ('\u{00CB}', 20, 2),
+    ('\u{00CC}', 22, 2),
+    ('\u{00CD}', 24, 2),
+    ('\u{00CE}', 26, 2),
+    ('\u{00CF}', 28, 2),
+    ('\u{00D1}', 30, 2),
+    ('\u{00D2}', 32, 2),
+    ('\u{00D3}', 34, 2),
+    ('\u{00D4}', 36, 2),
+    ('\u{00D5}', 38, 2),
+    ('\u{00D6}', 40, 2),
+    ('\u{00D9}', 42, 2),
+    ('\u{00DA}', 44, 2),
+    ('\u{00DB}', 46, 2),
+    ('\u{00DC}', 48, 2),
+    ('\u{00DD}', 50, 2),
+    ('\u{00E0}', 52, 2),
+    ('\u{00E1}', 54, 2),
+    ('\u{00E2}', 56, 2),
+    ('\u{00E3}', 58, 2),
+    ('\u{00E4}', 60, 2),
+    ('\u{00E5}', 62, 2),
+    ('\u{00E7}', 64, 2),
+    ('\u{00E8}', 66, 2),
+    ('\u{00E9}', 68, 2),
+    ('\u{00EA}', 70, 2),
+    ('\u{00EB}', 72, 2),
+    ('\u{00EC}', 74, 2),
+    ('\u{00ED}', 76, 2),
+    ('\u{00EE}', 78, 2),
+    ('\u{00EF}', 80, 2),
+    ('\u{00F1}', 82, 2),
+    ('\u{00F2}', 84, 2),
+    ('\u{00F3}', 86, 2),
+    ('\u{00F4}', 88, 2),
+    ('\u{00F5}', 90, 2),
+    ('\u{00F6}', 92, 2),
+    ('\u{00F9}', 94, 2),
+    ('\u{00FA}', 96, 2),
+    ('\u{00FB}', 98, 2),
+    ('\u{00FC}', 100, 2),
+    ('\u{00FD}', 102, 2),
+    ('\u{00FF}', 104, 2),
+    ('\u{0100}', 106, 2),
+    ('\u{0101}', 108, 2),
+    ('\u{0102}', 110, 2),
+    ('\u{0103}', 112, 2),
+    ('\u{0104}', 114, 2),
+    ('\u{0105}', 116, 2),
+    ('\u{0106}', 118, 2),
+    ('\u{0107}', 120, 2),
+    ('\u{0108}', 122, 2),
+    ('\u{0109}', 124, 2),
+    ('\u{010A}', 126, 2),
+    ('\u{010B}', 128, 2),
+    ('\u{010C}', 130, 2),
+    ('\u{010D}', 132, 2),
+    ('\u{010E}', 134, 2),
+    ('\u{010F}', 136, 2),
+    ('\u{0112}', 138, 2),
+    ('\u{0113}', 140, 2),
+    ('\u{0114}', 142, 2),
+    ('\u{0115}', 144, 2),
+    ('\u{0116}', 146, 2),
+    ('\u{0117}', 148, 2),
+    ('\u{0118}', 150, 2),
+    ('\u{0119}', 152, 2),
+    ('\u{011A}', 154, 2),
+    ('\u{011B}', 156, 2),
+    ('\u{011C}', 158, 2),
+    ('\u{011D}', 160, 2),
+    ('\u{011E}', 162, 2),
+    ('\u{011F}', 164, 2),
+    ('\u{0120}', 166, 2),
+    ('\u{0121}', 168, 2),
+    ('\u{0122}', 170, 2),
+    ('\u{0123}', 172, 2),
+    ('\u{0124}', 174, 2),
+    ('\u{0125}', 176, 2),
+    ('\u{0128}', 178, 2),
+    ('\u{0129}', 180, 2),
+    ('\u{012A}', 182, 2),
+    ('\u{012B}', 184, 2),
+    ('\u{012C}', 186, 2),
+    ('\u{012D}', 188, 2),
+    ('\u{012E}', 190, 2),
+    ('\u{012F}', 192, 2),
+    ('\u{0130}', 194, 2),
+    ('\u{0134}', 196, 2),
+    ('\u{0135}', 198, 2),
+    ('\u{0136}', 200, 2),
+    ('\u{0137}', 202, 2),
+    ('\u{0139}', 204, 2),
+    ('\u{013A}', 206, 2),
+    ('\u{013B}', 208, 2),
+    ('\u{013C}', 210, 2),
+    ('\u{013D}', 212, 2),
+    ('\u{013E}', 214, 2),
+    ('\u{0143}', 216, 2),
+    ('\u{0144}', 218, 2),
+    ('\u{0145}', 220, 2),
+    ('\u{0146}', 222, 2),
+    ('\u{0147}', 224, 2),
+    ('\u{0148}', 226, 2),
+    ('\u{014C}', 228, 2),
+    ('\u{014D}', 230, 2),
+    ('\u{014E}', 232, 2),
+    ('\u{014F}', 234, 2),
+    ('\u{0150}', 236, 2),
+    ('\u{0151}', 238, 2),
+    ('\u{0154}', 240, 2),
+    ('\u{0155}', 242, 2),
+    ('\u{0156}', 244, 2),
+    ('\u{0157}', 246, 2),
+    ('\u{0158}', 248, 2),
+    ('\u{0159}', 250, 2),
+    ('\u{015A}', 252, 2),
+    ('\u{015B}', 254, 2),
+    ('\u{015C}', 256, 2),
+    ('\u{015D}', 258, 2),
+    ('\u{015E}', 260, 2),
+    ('\u{015F}', 262, 2),
+    ('\u{0160}', 264, 2),
+    ('\u{0161}', 266, 2),
+    ('\u{0162}', 268, 2),
+    ('\u{0163}', 270, 2),
+    ('\u{0164}', 272, 2),
+    ('\u{0165}', 274, 2),
+    ('\u{0168}', 276, 2),
+    ('\u{0169}', 278, 2),
+    ('\u{016A}', 280, 2),
+    ('\u{016B}', 282, 2),
+    ('\u{016C}', 284, 2),
+    ('\u{016D}', 286, 2),
+    ('\u{016E}', 288, 2),
+    ('\u{016F}', 290, 2),
+    ('\u{0170}', 292, 2),
+    ('\u{0171}', 294, 2),
+    ('\u{0172}', 296, 2),
+    ('\u{0173}', 298, 2),
+    ('\u{0174}', 300, 2),
+    ('\u{0175}', 302, 2),
+    ('\u{0176}', 304, 2),
+    ('\u{0177}', 306, 2),
+    ('\u{0178}', 308, 2),
+    ('\u{0179}', 310, 2),
+    ('\u{017A}', 312, 2),
+    ('\u{017B}', 314, 2),
+    ('\u{017C}', 316, 2),
+    ('\u{017D}', 318, 2),
+    ('\u{017E}', 320, 2),
+    ('\u{01A0}', 322, 2),
+    ('\u{01A1}', 324, 2),
+    ('\u{01AF}', 326, 2),
+    ('\u{01B0}', 328, 2),
+    ('\u{01CD}', 330, 2),
+    ('\u{01CE}', 332, 2),
+    ('\u{01CF}', 334, 2),
+    ('\u{01D0}', 336, 2),
+    ('\u{01D1}', 338, 2),
+    ('\u{01D2}', 340, 2),
+    ('\u{01D3}', 342, 2),
+    ('\u{01D4}', 344, 2),
+    ('\u{01D5}', 346, 3),
+    ('\u{01D6}', 349, 3),
+    ('\u{01D7}', 352, 3),
+    ('\u{01D8}', 355, 3),
+    ('\u{01D9}', 358, 3),
+    ('\u{01DA}', 361, 3),
+    ('\u{01DB}', 364, 3),
+    ('\u{01DC}', 367, 3),
+    ('\u{01DE}', 370, 3),
+    ('\u{01DF}', 373, 3),
+    ('\u{01E0}', 376, 3),
+    ('\u{01E1}', 379, 3),
+    ('\u{01E2}', 382, 2),
+    ('\u{01E3}', 384, 2),
+    ('\u{01E6}', 386, 2),
+    ('\u{01E7}', 388, 2),
+    ('\u{01E8}', 390, 2),
+    ('\u{01E9}', 392, 2),
+    ('\u{01EA}', 394, 2),
+    ('\u{01EB}', 396, 2),
+    ('\u{01EC}', 398, 3),
+    ('\u{01ED}', 401, 3),
+    ('\u{01EE}', 404, 2),
+    ('\u{01EF}', 406, 2),
+    ('\u{01F0}', 408, 2),
+    ('\u{01F4}', 410, 2),
+    ('\u{01F5}', 412, 2),
+    ('\u{01F8}', 414, 2),
+    ('\u{01F9}', 416, 2),
+    ('\u{01FA}', 418, 3),
+    ('\u{01FB}', 421, 3),
+    ('\u{01FC}', 424, 2),
+    ('\u{01FD}', 426, 2),
+    ('\u{01FE}', 428, 2),
+    ('\u{01FF}', 430, 2),
+    ('\u{0200}', 432, 2),
+    ('\u{0201}', 434, 2),
+    ('\u{0202}', 436, 2),
+    ('\u{0203}', 438, 2),
+    ('\u{0204}', 440, 2),
+    ('\u{0205}', 442, 2),
+    ('\u{0206}', 444, 2),
+    ('\u{0207}', 446, 2),
+    ('\u{0208}', 448, 2),
+    ('\u{0209}', 450, 2),
+    ('\u{020A}', 452, 2),
+    ('\u{020B}', 454, 2),
+    ('\u{020C}', 456, 2),
+    ('\u{020D}', 458, 2),
+    ('\u{020E}', 460, 2),
+    ('\u{020F}', 462, 2),
+    ('\u{0210}', 464, 2),
+    ('\u{0211}', 466, 2),
+    ('\u{0212}', 468, 2),
+    ('\u{0213}', 470, 2),
+    ('\u{0214}', 472, 2),
+    ('\u{0215}', 474, 2),
+    ('\u{0216}', 476, 2),
+    ('\u{0217}', 478, 2),
+    ('\u{0218}', 480, 2),
+    ('\u{0219}', 482, 2),
+    ('\u{021A}', 484, 2),
+    ('\u{021B}', 486, 2),
+    ('\u{021E}', 488, 2),
+    ('\u{021F}', 490, 2),
+    ('\u{0226}', 492, 2),
+    ('\u{0227}', 494, 2),
+    ('\u{0228}', 496, 2),
+    ('\u{0229}', 498, 2),
+    ('\u{022A}', 500, 3),
+    ('\u{022B}', 503, 3),
+    ('\u{022C}', 506, 3),
+    ('\u{022D}', 509, 3),
+    ('\u{022E}', 512, 2),
+    ('\u{022F}', 514, 2),
+    ('\u{0230}', 516, 3),
+    ('\u{0231}', 519, 3),
+    ('\u{0232}', 522, 2),
+    ('\u{0233}', 524, 2),
+    ('\u{0340}', 526, 1),
+    ('\u{0341}', 527, 1),
+    ('\u{0343}', 528, 1),
+    ('\u{0344}', 529, 2),
+    ('\u{0374}', 531, 1),
+    ('\u{037E}', 532, 1),
+    ('\u{0385}', 533, 2),
+    ('\u{0386}', 535, 2),
+    ('\u{0387}', 537, 1),
+    ('\u{0388}', 538, 2),
+    ('\u{0389}', 540, 2),
+    ('\u{038A}', 542, 2),
+    ('\u{038C}', 544, 2),
+    ('\u{038E}', 546, 2),
+    ('\u{038F}', 548, 2),
+    ('\u{0390}', 550, 3),
+    ('\u{03AA}', 553, 2),
+    ('\u{03AB}', 555, 2),
+    ('\u{03AC}', 557, 2),
+    ('\u{03AD}', 559, 2),
+    ('\u{03AE}', 561, 2),
+    ('\u{03AF}', 563, 2),
+    ('\u{03B0}', 565, 3),
+    ('\u{03CA}', 568, 2),
+    ('\u{03CB}', 570, 2),
+    ('\u{03CC}', 572, 2),
+    ('\u{03CD}', 574, 2),
+    ('\u{03CE}', 576, 2),
+    ('\u{03D3}', 578, 2),
+    ('\u{03D4}', 580, 2),
+    ('\u{0400}', 582, 2),
+    ('\u{0401}', 584, 2),
+    ('\u{0403}', 586, 2),
+    ('\u{0407}', 588, 2),
+    ('\u{040C}', 590, 2),
+    ('\u{040D}', 592, 2),
+    ('\u{040E}', 594, 2),
+    ('\u{0419}', 596, 2),
+    ('\u{0439}', 598, 2),
+    ('\u{0450}', 600, 2),
+    ('\u{0451}', 602, 2),
+    ('\u{0453}', 604, 2),
+    ('\u{0457}', 606, 2),
+    ('\u{045C}', 608, 2),
+    ('\u{045D}', 610, 2),
+    ('\u{045E}', 612, 2),
+    ('\u{0476}', 614, 2),
+    ('\u{0477}', 616, 2),
+    ('\u{04C1}', 618, 2),
+    ('\u{04C2}', 620, 2),
+    ('\u{04D0}', 622, 2),
+    ('\u{04D1}', 624, 2),
+    ('\u{04D2}', 626, 2),
+    ('\u{04D3}', 628, 2),
+    ('\u{04D6}', 630, 2),
+    ('\u{04D7}', 632, 2),
+    ('\u{04DA}', 634, 2),
+    ('\u{04DB}', 636, 2),
+    ('\u{04DC}', 638, 2),
+    ('\u{04DD}', 640, 2),
+    ('\u{04DE}', 642, 2),
+    ('\u{04DF}', 644, 2),
+    ('\u{04E2}', 646, 2),
+    ('\u{04E3}', 648, 2),
+    ('\u{04E4}', 650, 2),
+    ('\u{04E5}', 652, 2),
+    ('\u{04E6}', 654, 2),
+    ('\u{04E7}', 656, 2),
+    ('\u{04EA}', 658, 2),
+    ('\u{04EB}', 660, 2),
+    ('\u{04EC}', 662, 2),
+    ('\u{04ED}', 664, 2),
+    ('\u{04EE}', 666, 2),
+    ('\u{04EF}', 668, 2),
+    ('\u{04F0}', 670, 2),
+    ('\u{04F1}', 672, 2),
+    ('\u{04F2}', 674, 2),
+    ('\u{04F3}', 676, 2),
+    ('\u{04F4}', 678, 2),
+    ('\u{04F5}', 680, 2),
+    ('\u{04F8}', 682, 2),
+    ('\u{04F9}', 684, 2),
+    ('\u{0622}', 686, 2),
+    ('\u{0623}', 688, 2),
+    ('\u{0624}', 690, 2),
+    ('\u{0625}', 692, 2),
+    ('\u{0626}', 694, 2),
+    ('\u{06C0}', 696, 2),
+    ('\u{06C2}', 698, 2),
+    ('\u{06D3}', 700, 2),
+    ('\u{0929}', 702, 2),
+    ('\u{0931}', 704, 2),
+    ('\u{0934}', 706, 2),
+    ('\u{0958}', 708, 2),
+    ('\u{0959}', 710, 2),
+    ('\u{095A}', 712, 2),
+    ('\u{095B}', 714, 2),
+    ('\u{095C}', 716, 2),
+    ('\u{095D}', 718, 2),
+    ('\u{095E}', 720, 2),
+    ('\u{095F}', 722, 2),
+    ('\u{09CB}', 724, 2),
+    ('\u{09CC}', 726, 2),
+    ('\u{09DC}', 728, 2),
+    ('\u{09DD}', 730, 2),
+    ('\u{09DF}', 732, 2),
+    ('\u{0A33}', 734, 2),
+    ('\u{0A36}', 736, 2),
+    ('\u{0A59}', 738, 2),
+    ('\u{0A5A}', 740, 2),
+    ('\u{0A5B}', 742, 2),
+    ('\u{0A5E}', 744, 2),
+    ('\u{0B48}', 746, 2),
+    ('\u{0B4B}', 748, 2),
+    ('\u{0B4C}', 750, 2),
+    ('\u{0B5C}', 752, 2),
+    ('\u{0B5D}', 754, 2),
+    ('\u{0B94}', 756, 2),
+    ('\u{0BCA}', 758, 2),
+    ('\u{0BCB}', 760, 2),
+    ('\u{0BCC}', 762, 2),
+    ('\u{0C48}', 764, 2),
+    ('\u{0CC0}', 766, 2),
+    ('\u{0CC7}', 768, 2),
+    ('\u{0CC8}', 770, 2),
+    ('\u{0CCA}', 772, 2),
+    ('\u{0CCB}', 774, 3),
+    ('\u{0D4A}', 777, 2),
+    ('\u{0D4B}', 779, 2),
+    ('\u{0D4C}', 781, 2),
+    ('\u{0DDA}', 783, 2),
+    ('\u{0DDC}', 785, 2),
+    ('\u{0DDD}', 787, 3),
+    ('\u{0DDE}', 790, 2),
+    ('\u{0F43}', 792, 2),
+    ('\u{0F4D}', 794, 2),
+    ('\u{0F52}', 796, 2),
+    ('\u{0F57}', 798, 2),
+    ('\u{0F5C}', 800, 2),
+    ('\u{0F69}', 802, 2),
+    ('\u{0F73}', 804, 2),
+    ('\u{0F75}', 806, 2),
+    ('\u{0F76}', 808, 2),
+    ('\u{0F78}', 810, 2),
+    ('\u{0F81}', 812, 2),
+    ('\u{0F93}', 814, 2),
+    ('\u{0F9D}', 816, 2),
+    ('\u{0FA2}', 818, 2),
+    ('\u{0FA7}', 820, 2),
+    ('\u{0FAC}', 822, 2),
+    ('\u{0FB9}', 824, 2),
+    ('\u{1026}', 826, 2),
+    ('\u{1B06}', 828, 2),
+    ('\u{1B08}', 830, 2),
+    ('\u{1B0A}', 832, 2),
+    ('\u{1B0C}', 834, 2),
+    ('\u{1B0E}', 836, 2),
+    ('\u{1B12}', 838, 2),
+    ('\u{1B3B}', 840, 2),
+    ('\u{1B3D}', 842, 2),
+    ('\u{1B40}', 844, 2),
+    ('\u{1B41}', 846, 2),
+    ('\u{1B43}', 848, 2),
+    ('\u{1E00}', 850, 2),
+    ('\u{1E01}', 852, 2),
+    ('\u{1E02}', 854, 2),
+    ('\u{1E03}', 856, 2),
+    ('\u{1E04}', 858, 2),
+    ('\u{1E05}', 860, 2),
+    ('\u{1E06}', 862, 2),
+    ('\u{1E07}', 864, 2),
+    ('\u{1E08}', 866, 3),
+    ('\u{1E09}', 869, 3),
+    ('\u{1E0A}', 872, 2),
+    ('\u{1E0B}', 874, 2),
+    ('\u{1E0C}', 876, 2),
+    ('\u{1E0D}', 878, 2),
+    ('\u{1E0E}', 880, 2),
+    ('\u{1E0F}', 882, 2),
+    ('\u{1E10}', 884, 2),
+    ('\u{1E11}', 886, 2),
+    ('\u{1E12}', 888, 2),
+    ('\u{1E13}', 890, 2),
+    ('\u{1E14}', 892, 3),
+    ('\u{1E15}', 895, 3),
+    ('\u{1E16}', 898, 3),
+    ('\u{1E17}', 901, 3),
+    ('\u{1E18}', 904, 2),
+    ('\u{1E19}', 906, 2),
+    ('\u{1E1A}', 908, 2),
+    ('\u{1E1B}', 910, 2),
+    ('\u{1E1C}', 912, 3),
+    ('\u{1E1D}', 915, 3),
+    ('\u{1E1E}', 918, 2),
+    ('\u{1E1F}', 920, 2),
+    ('\u{1E20}', 922, 2),
+    ('\u{1E21}', 924, 2),
+    ('\u{1E22}', 926, 2),
+    ('\u{1E23}', 928, 2),
+    ('\u{1E24}', 930, 2),
+    ('\u{1E25}', 932, 2),
+    ('\u{1E26}', 934, 2),
+    ('\u{1E27}', 936, 2),
+    ('\u{1E28}', 938, 2),
+    ('\u{1E29}', 940, 2),
+    ('\u{1E2A}', 942, 2),
+    ('\u{1E2B}', 944, 2),
+    ('\u{1E2C}', 946, 2),
+    ('\u{1E2D}', 948, 2),
+    ('\u{1E2E}', 950, 3),
+    ('\u{1E2F}', 953, 3),
+    ('\u{1E30}', 956, 2),
+    ('\u{1E31}', 958, 2),
+    ('\u{1E32}', 960, 2),
+    ('\u{1E33}', 962, 2),
+    ('\u{1E34}', 964, 2),
+    ('\u{1E35}', 966, 2),
+    ('\u{1E36}', 968, 2),
+    ('\u{1E37}', 970, 2),
+    ('\u{1E38}', 972, 3),
+    ('\u{1E39}', 975, 3),
+    ('\u{1E3A}', 978, 2),
+    ('\u{1E3B}', 980, 2),
+    ('\u{1E3C}', 982, 2),
+    ('\u{1E3D}', 984, 2),
+    ('\u{1E3E}', 986, 2),
+    ('\u{1E3F}', 988, 2),
+    ('\u{1E40}', 990, 2),
+    ('\u{1E41}', 992, 2),
+    ('\u{1E42}', 994, 2),
+    ('\u{1E43}', 996, 2),
+    ('\u{1E44}', 998, 2),
+    ('\u{1E45}', 1000, 2),
+    ('\u{1E46}', 1002, 2),
+    ('\u{1E47}', 1004, 2),
+    ('\u{1E48}', 1006, 2),
+    ('\u{1E49}', 1008, 2),
+    ('\u{1E4A}', 1010, 2),
+    ('\u{1E4B}', 1012, 2),
+    ('\u{1E4C}', 1014, 3),
+    ('\u{1E4D}', 1017, 3),
+    ('\u{1E4E}', 1020, 3),
+    ('\u{1E4F}', 1023, 3),
+    ('\u{1E50}', 1026, 3),
+    ('\u{1E51}', 1029, 3),
+    ('\u{1E52}', 1032, 3),
+    ('\u{1E53}', 1035, 3),
+    ('\u{1E54}', 1038, 2),
+    ('\u{1E55}', 1040, 2),
+    ('\u{1E56}', 1042, 2),
+    ('\u{1E57}', 1044, 2),
+    ('\u{1E58}', 1046, 2),
+    ('\u{1E59}', 1048, 2),
+    ('\u{1E5A}', 1050, 2),
+    ('\u{1E5B}', 1052, 2),
+    ('\u{1E5C}', 1054, 3),
+    ('\u{1E5D}', 1057, 3),
+    ('\u{1E5E}', 1060, 2),
+    ('\u{1E5F}', 1062, 2),
+    ('\u{1E60}', 1064, 2),
+    ('\u{1E61}', 1066, 2),
+    ('\u{1E62}', 1068, 2),
+    ('\u{1E63}', 1070, 2),
+    ('\u{1E64}', 1072, 3),
+    ('\u{1E65}', 1075, 3),
+    ('\u{1E66}', 1078, 3),
+    ('\u{1E67}', 1081, 3),
+    ('\u{1E68}', 1084, 3),
+    ('\u{1E69}', 1087, 3),
+    ('\u{1E6A}', 1090, 2),
+    ('\u{1E6B}', 1092, 2),
+    ('\u{1E6C}', 1094, 2),
+    ('\u{1E6D}', 1096, 2),
+    ('\u{1E6E}', 1098, 2),
+    ('\u{1E6F}', 1100, 2),
+    ('\u{1E70}', 1102, 2),
+    ('\u{1E71}', 1104, 2),
+    ('\u{1E72}', 1106, 2),
+    ('\u{1E73}', 1108, 2),
+    ('\u{1E74}', 1110, 2),
+    ('\u{1E75}', 1112, 2),
+    ('\u{1E76}', 1114, 2),
+    ('\u{1E77}', 1116, 2),
+    ('\u{1E78}', 1118, 3),
+    ('\u{1E79}', 1121, 3),
+    ('\u{1E7A}', 1124, 3),
+    ('\u{1E7B}', 1127, 3),
+    ('\u{1E7C}', 1130, 2),
+    ('\u{1E7D}', 1132, 2),
+    ('\u{1E7E}', 1134, 2),
+    ('\u{1E7F}', 1136, 2),
+    ('\u{1E80}', 1138, 2),
+    ('\u{1E81}', 1140, 2),
+    ('\u{1E82}', 1142, 2),
+    ('\u{1E83}', 1144, 2),
+    ('\u{1E84}', 1146, 2),
+    ('\u{1E85}', 1148, 2),
+    ('\u{1E86}', 1150, 2),
+    ('\u{1E87}', 1152, 2),
+    ('\u{1E88}', 1154, 2),
+    ('\u{1E89}', 1156, 2),
+    ('\u{1E8A}', 1158, 2),
+    ('\u{1E8B}', 1160, 2),
+    ('\u{1E8C}', 1162, 2),
+    ('\u{1E8D}', 1164, 2),
+    ('\u{1E8E}', 1166, 2),
+    ('\u{1E8F}', 1168, 2),
+    ('\u{1E90}', 1170, 2),
+    ('\u{1E91}', 1172, 2),
+    ('\u{1E92}', 1174, 2),
+    ('\u{1E93}', 1176, 2),
+    ('\u{1E94}', 1178, 2),
+    ('\u{1E95}', 1180, 2),
+    ('\u{1E96}', 1182, 2),
+    ('\u{1E97}', 1184, 2),
+    ('\u{1E98}', 1186, 2),
+    ('\u{1E99}', 1188, 2),
+    ('\u{1E9B}', 1190, 2),
+    ('\u{1EA0}', 1192, 2),
+    ('\u{1EA1}', 1194, 2),
+    ('\u{1EA2}', 1196, 2),
+    ('\u{1EA3}', 1198, 2),
+    ('\u{1EA4}', 1200, 3),
+    ('\u{1EA5}', 1203, 3),
+    ('\u{1EA6}', 1206, 3),
+    ('\u{1EA7}', 1209, 3),
+    ('\u{1EA8}', 1212, 3),
+    ('\u{1EA9}', 1215, 3),
+    ('\u{1EAA}', 1218, 3),
+    ('\u{1EAB}', 1221, 3),
+    ('\u{1EAC}', 1224, 3),
+    ('\u{1EAD}', 1227, 3),
+    ('\u{1EAE}', 1230, 3),
+    ('\u{1EAF}', 1233, 3),
+    ('\u{1EB0}', 1236, 3),
+    ('\u{1EB1}', 1239, 3),
+    ('\u{1EB2}', 1242, 3),
+    ('\u{1EB3}', 1245, 3),
+    ('\u{1EB4}', 1248, 3),
+    ('\u{1EB5}', 1251, 3),
+    ('\u{1EB6}', 1254, 3),
+    ('\u{1EB7}', 1257, 3),
+    ('\u{1EB8}', 1260, 2),
+    ('\u{1EB9}', 1262, 2),
+    ('\u{1EBA}', 1264, 2),
+    ('\u{1EBB}', 1266, 2),
+    ('\u{1EBC}', 1268, 2),
+    ('\u{1EBD}', 1270, 2),
+    ('\u{1EBE}', 1272, 3),
+    ('\u{1EBF}', 1275, 3),
+    ('\u{1EC0}', 1278, 3),
+    ('\u{1EC1}', 1281, 3),
+    ('\u{1EC2}', 1284, 3),
+    ('\u{1EC3}', 1287, 3),
+    ('\u{1EC4}', 1290, 3),
+    ('\u{1EC5}', 1293, 3),
+    ('\u{1EC6}', 1296, 3),
+    ('\u{1EC7}', 1299, 3),
+    ('\u{1EC8}', 1302, 2),
+    ('\u{1EC9}', 1304, 2),
+    ('\u{1ECA}', 1306, 2),
+    ('\u{1ECB}', 1308, 2),
+    ('\u{1ECC}', 1310, 2),
+    ('\u{1ECD}', 1312, 2),
+    ('\u{1ECE}', 1314, 2),
+    ('\u{1ECF}', 1316, 2),
+    ('\u{1ED0}', 1318, 3),
+    ('\u{1ED1}', 1321, 3),
+    ('\u{1ED2}', 1324, 3),
+    ('\u{1ED3}', 1327, 3),
+    ('\u{1ED4}', 1330, 3),
+    ('\u{1ED5}', 1333, 3),
+    ('\u{1ED6}', 1336, 3),
+    ('\u{1ED7}', 1339, 3),
+    ('\u{1ED8}', 1342, 3),
+    ('\u{1ED9}', 1345, 3),
+    ('\u{1EDA}', 1348, 3),
+    ('\u{1EDB}', 1351, 3),
+    ('\u{1EDC}', 1354, 3),
+    ('\u{1EDD}', 1357, 3),
+    ('\u{1EDE}', 1360, 3),
+    ('\u{1EDF}', 1363, 3),
+    ('\u{1EE0}', 1366, 3),
+    ('\u{1EE1}', 1369, 3),
+    ('\u{1EE2}', 1372, 3),
+    ('\u{1EE3}', 1375, 3),
+    ('\u{1EE4}', 1378, 2),
+    ('\u{1EE5}', 1380, 2),
+    ('\u{1EE6}', 1382, 2),
+    ('\u{1EE7}', 1384, 2),
+    ('\u{1EE8}', 1386, 3),
+    ('\u{1EE9}', 1389, 3),
+    ('\u{1EEA}', 1392, 3),
+    ('\u{1EEB}', 1395, 3),
+    ('\u{1EEC}', 1398, 3),
+    ('\u{1EED}', 1401, 3),
+    ('\u{1EEE}', 1404, 3),
+    ('\u{1EEF}', 1407, 3),
+    ('\u{1EF0}', 1410, 3),
+    ('\u{1EF1}', 1413, 3),
+    ('\u{1EF2}', 1416, 2),
+    ('\u{1EF3}', 1418, 2),
+    ('\u{1EF4}', 1420, 2),
+    ('\u{1EF5}', 1422, 2),
+    ('\u{1EF6}', 1424, 2),
+    ('\u{1EF7}', 1426, 2),
+    ('\u{1EF8}', 1428, 2),
+    ('\u{1EF9}', 1430, 2),
+    ('\u{1F00}', 1432, 2),
+    ('\u{1F01}', 1434, 2),
+    ('\u{1F02}', 1436, 3),
+    ('\u{1F03}', 1439, 3),
+    ('\u{1F04}', 1442, 3),
+    ('\u{1F05}', 1445, 3),
+    ('\u{1F06}', 1448, 3),
+    ('\u{1F07}', 1451, 3),
+    ('\u{1F08}', 1454, 2),
+    ('\u{1F09}', 1456, 2),
+    ('\u{1F0A}', 1458, 3),
+    ('\u{1F0B}', 1461, 3),
+    ('\u{1F0C}', 1464, 3),
+    ('\u{1F0D}', 1467, 3),
+    ('\u{1F0E}', 1470, 3),
+    ('\u{1F0F}', 1473, 3),
+    ('\u{1F10}', 1476, 2),
+    ('\u{1F11}', 1478, 2),
+    ('\u{1F12}', 1480, 3),
+    ('\u{1F13}', 1483, 3),
+    ('\u{1F14}', 1486, 3),
+    ('\u{1F15}', 1489, 3),
+    ('\u{1F18}', 1492, 2),
+    ('\u{1F19}', 1494, 2),
+    ('\u{1F1A}', 1496, 3),
+    ('\u{1F1B}', 1499, 3),
+    ('\u{1F1C}', 1502, 3),
+    ('\u{1F1D}', 1505, 3),
+    ('\u{1F20}', 1508, 2),
+    ('\u{1F21}', 1510, 2),
+    ('\u{1F22}', 1512, 3),
+    ('\u{1F23}', 1515, 3),
+    ('\u{1F24}', 1518, 3),
+    ('\u{1F25}', 1521, 3),
+    ('\u{1F26}', 1524, 3),
+    ('\u{1F27}', 1527, 3),
+    ('\u{1F28}', 1530, 2),
+    ('\u{1F29}', 1532, 2),
+    ('\u{1F2A}', 1534, 3),
+    ('\u{1F2B}', 1537, 3),
+    ('\u{1F2C}', 1540, 3),
+    ('\u{1F2D}', 1543, 3),
+    ('\u{1F2E}', 1546, 3),
+    ('\u{1F2F}', 1549, 3),
+    ('\u{1F30}', 1552, 2),
+    ('\u{1F31}', 1554, 2),
+    ('\u{1F32}', 1556, 3),
+    ('\u{1F33}', 1559, 3),
+    ('\u{1F34}', 1562, 3),
+    ('\u{1F35}', 1565, 3),
+    ('\u{1F36}', 1568, 3),
+    ('\u{1F37}', 1571, 3),
+    ('\u{1F38}', 1574, 2),
+    ('\u{1F39}', 1576, 2),
+    ('\u{1F3A}', 1578, 3),
+    ('\u{1F3B}', 1581, 3),
+    ('\u{1F3C}', 1584, 3),
+    ('\u{1F3D}', 1587, 3),
+    ('\u{1F3E}', 1590, 3),
+    ('\u{1F3F}', 1593, 3),
+    ('\u{1F40}', 1596, 2),
+    ('\u{1F41}', 1598, 2),
+    ('\u{1F42}', 1600, 3),
+    ('\u{1F43}', 1603, 3),
+    ('\u{1F44}', 1606, 3),
+    ('\u{1F45}', 1609, 3),
+    ('\u{1F48}', 1612, 2),
+    ('\u{1F49}', 1614, 2),
+    ('\u{1F4A}', 1616, 3),
+    ('\u{1F4B}', 1619, 3),
+    ('\u{1F4C}', 1622, 3),
+    ('\u{1F4D}', 1625, 3),
+    ('\u{1F50}', 1628, 2),
+    ('\u{1F51}', 1630, 2),
+    ('\u{1F52}', 1632, 3),
+    ('\u{1F53}', 1635, 3),
+    ('\u{1F54}', 1638, 3),
+    ('\u{1F55}', 1641, 3),
+    ('\u{1F56}', 1644, 3),
+    ('\u{1F57}', 1647, 3),
+    ('\u{1F59}', 1650, 2),
+    ('\u{1F5B}', 1652, 3),
+    ('\u{1F5D}', 1655, 3),
+    ('\u{1F5F}', 1658, 3),
+    ('\u{1F60}', 1661, 2),
+    ('\u{1F61}', 1663, 2),
+    ('\u{1F62}', 1665, 3),
+    ('\u{1F63}', 1668, 3),
+    ('\u{1F64}', 1671, 3),
+    ('\u{1F65}', 1674, 3),
+    ('\u{1F66}', 1677, 3),
+    ('\u{1F67}', 1680, 3),
+    ('\u{1F68}', 1683, 2),
+    ('\u{1F69}', 1685, 2),
+    ('\u{1F6A}', 1687, 3),
+    ('\u{1F6B}', 1690, 3),
+    ('\u{1F6C}', 1693, 3),
+    ('\u{1F6D}', 1696, 3),
+    ('\u{1F6E}', 1699, 3),
+    ('\u{1F6F}', 1702, 3),
+    ('\u{1F70}', 1705, 2),
+    ('\u{1F71}', 1707, 2),
+    ('\u{1F72}', 1709, 2),
+    ('\u{1F73}', 1711, 2),
+    ('\u{1F74}', 1713, 2),
+    ('\u{1F75}', 1715, 2),
+    ('\u{1F76}', 1717, 2),
+    ('\u{1F77}', 1719, 2),
+    ('\u{1F78}', 1721, 2),
+    ('\u{1F79}', 1723, 2),
+    ('\u{1F7A}', 1725, 2),
+    ('\u{1F7B}', 1727, 2),
+    ('\u{1F7C}', 1729, 2),
+    ('\u{1F7D}', 1731, 2),
+    ('\u{1F80}', 1733, 3),
+    ('\u{1F81}', 1736, 3),
+    ('\u{1F82}', 1739, 4),
+    ('\u{1F83}', 1743, 4),
+    ('\u{1F84}', 1747, 4),
+    ('\u{1F85}', 1751, 4),
+    ('\u{1F86}', 1755, 4),
+    ('\u{1F87}', 1759, 4),
+    ('\u{1F88}', 1763, 3),
+    ('\u{1F89}', 1766, 3),
+    ('\u{1F8A}', 1769, 4),
+    ('\u{1F8B}', 1773, 4),
+    ('\u{1F8C}', 1777, 4),
+    ('\u{1F8D}', 1781, 4),
+    ('\u{1F8E}', 1785, 4),
+    ('\u{1F8F}', 1789, 4),
+    ('\u{1F90}', 1793, 3),
+    ('\u{1F91}', 1796, 3),
+    ('\u{1F92}', 1799, 4),
+    ('\u{1F93}', 1803, 4),
+    ('\u{1F94}', 1807, 4),
+    ('\u{1F95}', 1811, 4),
+    ('\u{1F96}', 1815, 4),
+    ('\u{1F97}', 1819, 4),
+    ('\u{1F98}', 1823, 3),
+    ('\u{1F99}', 1826, 3),
+    ('\u{1F9A}', 1829, 4),
+    ('\u{1F9B}', 1833, 4),
+    ('\u{1F9C}', 1837, 4),
+    ('\u{1F9D}', 1841, 4),
+    ('\u{1F9E}', 1845, 4),
+    ('\u{1F9F}', 1849, 4),
+    ('\u{1FA0}', 1853, 3),
+    ('\u{1FA1}', 1856, 3),
+    ('\u{1FA2}', 1859, 4),
+    ('\u{1FA3}', 1863, 4),
+    ('\u{1FA4}', 1867, 4),
+    ('\u{1FA5}', 1871, 4),
+    ('\u{1FA6}', 1875, 4),
+    ('\u{1FA7}', 1879, 4),
+    ('\u{1FA8}', 1883, 3),
+    ('\u{1FA9}', 1886, 3),
+    ('\u{1FAA}', 1889, 4),
+    ('\u{1FAB}', 1893, 4),
+    ('\u{1FAC}', 1897, 4),
+    ('\u{1FAD}', 1901, 4),
+    ('\u{1FAE}', 1905, 4),
+    ('\u{1FAF}', 1909, 4),
+    ('\u{1FB0}', 1913, 2),
+    ('\u{1FB1}', 1915, 2),
+    ('\u{1FB2}', 1917, 3),
+    ('\u{1FB3}', 1920, 2),
+    ('\u{1FB4}', 1922, 3),
+    ('\u{1FB6}', 1925, 2),
+    ('\u{1FB7}', 1927, 3),
+    ('\u{1FB8}', 1930, 2),
+    ('\u{1FB9}', 1932, 2),
+    ('\u{1FBA}', 1934, 2),
+    ('\u{1FBB}', 1936, 2),
+    ('\u{1FBC}', 1938, 2),
+    ('\u{1FBE}', 1940, 1),
+    ('\u{1FC1}', 1941, 2),
+    ('\u{1FC2}', 1943, 3),
+    ('\u{1FC3}', 1946, 2),
+    ('\u{1FC4}', 1948, 3),
+    ('\u{1FC6}', 1951, 2),
+    ('\u{1FC7}', 1953, 3),
+    ('\u{1FC8}', 1956, 2),
+    ('\u{1FC9}', 1958, 2),
+    ('\u{1FCA}', 1960, 2),
+    ('\u{1FCB}', 1962, 2),
+    ('\u{1FCC}', 1964, 2),
+    ('\u{1FCD}', 1966, 2),
+    ('\u{1FCE}', 1968, 2),
+    ('\u{1FCF}', 1970, 2),
+    ('\u{1FD0}', 1972, 2),
+    ('\u{1FD1}', 1974, 2),
+    ('\u{1FD2}', 1976, 3),
+    ('\u{1FD3}', 1979, 3),
+    ('\u{1FD6}', 1982, 2),
+    ('\u{1FD7}', 1984, 3),
+    ('\u{1FD8}', 1987, 2),
+    ('\u{1FD9}', 1989, 2),
+    ('\u{1FDA}', 1991, 2),
+    ('\u{1FDB}', 1993, 2),
+    ('\u{1FDD}', 1995, 2),
+    ('\u{1FDE}', 1997, 2),
+    ('\u{1FDF}', 1999, 2),
+    ('\u{1FE0}', 2001, 2),
+    ('\u{1FE1}', 2003, 2),
+    ('\u{1FE2}', 2005, 3),
+    ('\u{1FE3}', 2008, 3),
+    ('\u{1FE4}', 2011, 2),
+    ('\u{1FE5}', 2013, 2),
+    ('\u{1FE6}', 2015, 2),
+    ('\u{1FE7}', 2017, 3),
+    ('\u{1FE8}', 2020, 2),
+    ('\u{1FE9}', 2022, 2),
+    ('\u{1FEA}', 2024, 2),
+    ('\u{1FEB}', 2026, 2),
+    ('\u{1FEC}', 2028, 2),
+    ('\u{1FED}', 2030, 2),
+    ('\u{1FEE}', 2032, 2),
+    ('\u{1FEF}', 2034, 1),
+    ('\u{1FF2}', 2035, 3),
+    ('\u{1FF3}', 2038, 2),
+    ('\u{1FF4}', 2040, 3),
+    ('\u{1FF6}', 2043, 2),
+    ('\u{1FF7}', 2045, 3),
+    ('\u{1FF8}', 2048, 2),
+    ('\u{1FF9}', 2050, 2),
+    ('\u{1FFA}', 2052, 2),
+    ('\u{1FFB}', 2054, 2),
+    ('\u{1FFC}', 2056, 2),
+    ('\u{1FFD}', 2058, 1),
+    ('\u{2000}', 2059, 1),
+    ('\u{2001}', 2060, 1),
+    ('\u{2126}', 2061, 1),
+    ('\u{212A}', 2062, 1),
+    ('\u{212B}', 2063, 2),
+    ('\u{219A}', 2065, 2),
+    ('\u{219B}', 2067, 2),
+    ('\u{21AE}', 2069, 2),
+    ('\u{21CD}', 2071, 2),
+    ('\u{21CE}', 2073, 2),
+    ('\u{21CF}', 2075, 2),
+    ('\u{2204}', 2077, 2),
+    ('\u{2209}', 2079, 2),
+    ('\u{220C}', 2081, 2),
+    ('\u{2224}', 2083, 2),
+    ('\u{2226}', 2085, 2),
+    ('\u{2241}', 2087, 2),
+    ('\u{2244}', 2089, 2),
+    ('\u{2247}', 2091, 2),
+    ('\u{2249}', 2093, 2),
+    ('\u{2260}', 2095, 2),
+    ('\u{2262}', 2097, 2),
+    ('\u{226D}', 2099, 2),
+    ('\u{226E}', 2101, 2),
+    ('\u{226F}', 2103, 2),
+    ('\u{2270}', 2105, 2),
+    ('\u{2271}', 2107, 2),
+    ('\u{2274}', 2109, 2),
+    ('\u{2275}', 2111, 2),
+    ('\u{2278}', 2113, 2),
+    ('\u{2279}', 2115, 2),
+    ('\u{2280}', 2117, 2),
+    ('\u{2281}', 2119, 2),
+    ('\u{2284}', 2121, 2),
+    ('\u{2285}', 2123, 2),
+    ('\u{2288}', 2125, 2),
+    ('\u{2289}', 2127, 2),
+    ('\u{22AC}', 2129, 2),
+    ('\u{22AD}', 2131, 2),
+    ('\u{22AE}', 2133, 2),
+    ('\u{22AF}', 2135, 2),
+    ('\u{22E0}', 2137, 2),
+    ('\u{22E1}', 2139, 2),
+    ('\u{22E2}', 2141, 2),
+    ('\u{22E3}', 2143, 2),
+    ('\u{22EA}', 2145, 2),
+    ('\u{22EB}', 2147, 2),
+    ('\u{22EC}', 2149, 2),
+    ('\u{22ED}', 2151, 2),
+    ('\u{2329}', 2153, 1),
+    ('\u{232A}', 2154, 1),
+    ('\u{2ADC}', 2155, 2),
+    ('\u{304C}', 2157, 2),
+    ('\u{304E}', 2159, 2),
+    ('\u{3050}', 2161, 2),
+    ('\u{3052}', 2163, 2),
+    ('\u{3054}', 2165, 2),
+    ('\u{3056}', 2167, 2),
+    ('\u{3058}', 2169, 2),
+    ('\u{305A}', 2171, 2),
+    ('\u{305C}', 2173, 2),
+    ('\u{305E}', 2175, 2),
+    ('\u{3060}', 2177, 2),
+    ('\u{3062}', 2179, 2),
+    ('\u{3065}', 2181, 2),
+    ('\u{3067}', 2183, 2),
+    ('\u{3069}', 2185, 2),
+    ('\u{3070}', 2187, 2),
+    ('\u{3071}', 2189, 2),
+    ('\u{3073}', 2191, 2),
+    ('\u{3074}', 2193, 2),
+    ('\u{3076}', 2195, 2),
+    ('\u{3077}', 2197, 2),
+    ('\u{3079}', 2199, 2),
+    ('\u{307A}', 2201, 2),
+    ('\u{307C}', 2203, 2),
+    ('\u{307D}', 2205, 2),
+    ('\u{3094}', 2207, 2),
+    ('\u{309E}', 2209, 2),
+    ('\u{30AC}', 2211, 2),
+    ('\u{30AE}', 2213, 2),
+    ('\u{30B0}', 2215, 2),
+    ('\u{30B2}', 2217, 2),
+    ('\u{30B4}', 2219, 2),
+    ('\u{30B6}', 2221, 2),
+    ('\u{30B8}', 2223, 2),
+    ('\u{30BA}', 2225, 2),
+    ('\u{30BC}', 2227, 2),
+    ('\u{30BE}', 2229, 2),
+    ('\u{30C0}', 2231, 2),
+    ('\u{30C2}', 2233, 2),
+    ('\u{30C5}', 2235, 2),
+    ('\u{30C7}', 2237, 2),
+    ('\u{30C9}', 2239, 2),
+    ('\u{30D0}', 2241, 2),
+    ('\u{30D1}', 2243, 2),
+    ('\u{30D3}', 2245, 2),
+    ('\u{30D4}', 2247, 2),
+    ('\u{30D6}', 2249, 2),
+    ('\u{30D7}', 2251, 2),
+    ('\u{30D9}', 2253, 2),
+    ('\u{30DA}', 2255, 2),
+    ('\u{30DC}', 2257, 2),
+    ('\u{30DD}', 2259, 2),
+    ('\u{30F4}', 2261, 2),
+    ('\u{30F7}', 2263, 2),
+    ('\u{30F8}', 2265, 2),
+    ('\u{30F9}', 2267, 2),
+    ('\u{30FA}', 2269, 2),
+    ('\u{30FE}', 2271, 2),
+    ('\u{F900}', 2273, 1),
+    ('\u{F901}', 2274, 1),
+    ('\u{F902}', 2275, 1),
+    ('\u{F903}', 2276, 1),
+    ('\u{F904}', 2277, 1),
+    ('\u{F905}', 2278, 1),
+    ('\u{F906}', 2279, 1),
+    ('\u{F907}', 2280, 1),
+    ('\u{F908}', 2281, 1),
+    ('\u{F909}', 2282, 1),
+    ('\u{F90A}', 2283, 1),
+    ('\u{F90B}', 2284, 1),
+    ('\u{F90C}', 2285, 1),
+    ('\u{F90D}', 2286, 1),
+    ('\u{F90E}', 2287, 1),
+    ('\u{F90F}', 2288, 1),
+    ('\u{F910}', 2289, 1),
+    ('\u{F911}', 2290, 1),
+    ('\u{F912}', 2291, 1),
+    ('\u{F913}', 2292, 1),
+    ('\u{F914}', 2293, 1),
+    ('\u{F915}', 2294, 1),
+    ('\u{F916}', 2295, 1),
+    ('\u{F917}', 2296, 1),
+    ('\u{F918}', 2297, 1),
+    ('\u{F919}', 2298, 1),
+    ('\u{F91A}', 2299, 1),
+    ('\u{F91B}', 2300, 1),
+    ('\u{F91C}', 2301, 1),
+    ('\u{F91D}', 2302, 1),
+    ('\u{F91E}', 2303, 1),
+    ('\u{F91F}', 2304, 1),
+    ('\u{F920}', 2305, 1),
+    ('\u{F921}', 2306, 1),
+    ('\u{F922}', 2307, 1),
+    ('\u{F923}', 2308, 1),
+    ('\u{F924}', 2309, 1),
+    ('\u{F925}', 2310, 1),
+    ('\u{F926}', 2311, 1),
+    ('\u{F927}', 2312, 1),
+    ('\u{F928}', 2313, 1),
+    ('\u{F929}', 2314, 1),
+    ('\u{F92A}', 2315, 1),
+    ('\u{F92B}', 2316, 1),
+    ('\u{F92C}', 2317, 1),
+    ('\u{F92D}', 2318, 1),
+    ('\u{F92E}', 2319, 1),
+    ('\u{F92F}', 2320, 1),
+    ('\u{F930}', 2321, 1),
+    ('\u{F931}', 2322, 1),
+    ('\u{F932}', 2323, 1),
+    ('\u{F933}', 2324, 1),
+    ('\u{F934}', 2325, 1),
+    ('\u{F935}', 2326, 1),
+    ('\u{F936}', 2327, 1),
+    ('\u{F937}', 2328, 1),
+    ('\u{F938}', 2329, 1),
+    ('\u{F939}', 2330, 1),
+    ('\u{F93A}', 2331, 1),
+    ('\u{F93B}', 2332, 1),
+    ('\u{F93C}', 2333, 1),
+    ('\u{F93D}', 2334, 1),
+    ('\u{F93E}', 2335, 1),
+    ('\u{F93F}', 2336, 1),
+    ('\u{F940}', 2337, 1),
+    ('\u{F941}', 2338, 1),
+    ('\u{F942}', 2339, 1),
+    ('\u{F943}', 2340, 1),
+    ('\u{F944}', 2341, 1),
+    ('\u{F945}', 2342, 1),
+    ('\u{F946}', 2343, 1),
+    ('\u{F947}', 2344, 1),
+    ('\u{F948}', 2345, 1),
+    ('\u{F949}', 2346, 1),
+    ('\u{F94A}', 2347, 1),
+    ('\u{F94B}', 2348, 1),
+    ('\u{F94C}', 2349, 1),
+    ('\u{F94D}', 2350, 1),
+    ('\u{F94E}', 2351, 1),
+    ('\u{F94F}', 2352, 1),
+    ('\u{F950}', 2353, 1),
+    ('\u{F951}', 2354, 1),
+    ('\u{F952}', 2355, 1),
+    ('\u{F953}', 2356, 1),
+    ('\u{F954}', 2357, 1),
+    ('\u{F955}', 2358, 1),
+    ('\u{F956}', 2359, 1),
+    ('\u{F957}', 2360, 1),
+    ('\u{F958}', 2361, 1),
+    ('\u{F959}', 2362, 1),
+    ('\u{F95A}', 2363, 1),
+    ('\u{F95B}', 2364, 1),
+    ('\u{F95C}', 2365, 1),
+    ('\u{F95D}', 2366, 1),
+    ('\u{F95E}', 2367, 1),
+    ('\u{F95F}', 2368, 1),
+    ('\u{F960}', 2369, 1),
+    ('\u{F961}', 2370, 1),
+    ('\u{F962}', 2371, 1),
+    ('\u{F963}', 2372, 1),
+    ('\u{F964}', 2373, 1),
+    ('\u{F965}', 2374, 1),
+    ('\u{F966}', 2375, 1),
+    ('\u{F967}', 2376, 1),
+    ('\u{F968}', 2377, 1),
+    ('\u{F969}', 2378, 1),
+    ('\u{F96A}', 2379, 1),
+    ('\u{F96B}', 2380, 1),
+    ('\u{F96C}', 2381, 1),
+    ('\u{F96D}', 2382, 1),
+    ('\u{F96E}', 2383, 1),
+    ('\u{F96F}', 2384, 1),
+    ('\u{F970}', 2385, 1),
+    ('\u{F971}', 2386, 1),
+    ('\u{F972}', 2387, 1),
+    ('\u{F973}', 2388, 1),
+    ('\u{F974}', 2389, 1),
+    ('\u{F975}', 2390, 1),
+    ('\u{F976}', 2391, 1),
+    ('\u{F977}', 2392, 1),
+    ('\u{F978}', 2393, 1),
+    ('\u{F979}', 2394, 1),
+    ('\u{F97A}', 2395, 1),
+    ('\u{F97B}', 2396, 1),
+    ('\u{F97C}', 2397, 1),
+    ('\u{F97D}', 2398, 1),
+    ('\u{F97E}', 2399, 1),
+    ('\u{F97F}', 2400, 1),
+    ('\u{F980}', 2401, 1),
+    ('\u{F981}', 2402, 1),
+    ('\u{F982}', 2403, 1),
+    ('\u{F983}', 2404, 1),
+    ('\u{F984}', 2405, 1),
+    ('\u{F985}', 2406, 1),
+    ('\u{F986}', 2407, 1),
+    ('\u{F987}', 2408, 1),
+    ('\u{F988}', 2409, 1),
+    ('\u{F989}', 2410, 1),
+    ('\u{F98A}', 2411, 1),
+    ('\u{F98B}', 2412, 1),
+    ('\u{F98C}', 2413, 1),
+    ('\u{F98D}', 2414, 1),
+    ('\u{F98E}', 2415, 1),
+    ('\u{F98F}', 2416, 1),
+    ('\u{F990}', 2417, 1),
+    ('\u{F991}', 2418, 1),
+    ('\u{F992}', 2419, 1),
+    ('\u{F993}', 2420, 1),
+    ('\u{F994}', 2421, 1),
+    ('\u{F995}', 2422, 1),
+    ('\u{F996}', 2423, 1),
+    ('\u{F997}', 2424, 1),
+    ('\u{F998}', 2425, 1),
+    ('\u{F999}', 2426, 1),
+    ('\u{F99A}', 2427, 1),
+    ('\u{F99B}', 2428, 1),
+    ('\u{F99C}', 2429, 1),
+    ('\u{F99D}', 2430, 1),
+    ('\u{F99E}', 2431, 1),
+    ('\u{F99F}', 2432, 1),
+    ('\u{F9A0}', 2433, 1),
+    ('\u{F9A1}', 2434, 1),
+    ('\u{F9A2}', 2435, 1),
+    ('\u{F9A3}', 2436, 1),
+    ('\u{F9A4}', 2437, 1),
+    ('\u{F9A5}', 2438, 1),
+    ('\u{F9A6}', 2439, 1),
+    ('\u{F9A7}', 2440, 1),
+    ('\u{F9A8}', 2441, 1),
+    ('\u{F9A9}', 2442, 1),
+    ('\u{F9AA}', 2443, 1),
+    ('\u{F9AB}', 2444, 1),
+    ('\u{F9AC}', 2445, 1),
+    ('\u{F9AD}', 2446, 1),
+    ('\u{F9AE}', 2447, 1),
+    ('\u{F9AF}', 2448, 1),
+    ('\u{F9B0}', 2449, 1),
+    ('\u{F9B1}', 2450, 1),
+    ('\u{F9B2}', 2451, 1),
+    ('\u{F9B3}', 2452, 1),
+    ('\u{F9B4}', 2453, 1),
+    ('\u{F9B5}', 2454, 1),
+    ('\u{F9B6}', 2455, 1),
+    ('\u{F9B7}', 2456, 1),
+    ('\u{F9B8}', 2457, 1),
+    ('\u{F9B9}', 2458, 1),
+    ('\u{F9BA}', 2459, 1),
+    ('\u{F9BB}', 2460, 1),
+    ('\u{F9BC}', 2461, 1),
+    ('\u{F9BD}', 2462, 1),
+    ('\u{F9BE}', 2463, 1),
+    ('\u{F9BF}', 2464, 1),
+    ('\u{F9C0}', 2465, 1),
+    ('\u{F9C1}', 2466, 1),
+    ('\u{F9C2}', 2467, 1),
+    ('\u{F9C3}', 2468, 1),
+    ('\u{F9C4}', 2469, 1),
+    ('\u{F9C5}', 2470, 1),
+    ('\u{F9C6}', 2471, 1),
+    ('\u{F9C7}', 2472, 1),
+    ('\u{F9C8}', 2473, 1),
+    ('\u{F9C9}', 2474, 1),
+    ('\u{F9CA}', 2475, 1),
+    ('\u{F9CB}', 2476, 1),
+    ('\u{F9CC}', 2477, 1),
+    ('\u{F9CD}', 2478, 1),
+    ('\u{F9CE}', 2479, 1),
+    ('\u{F9CF}', 2480, 1),
+    ('\u{F9D0}', 2481, 1),
+    ('\u{F9D1}', 2482, 1),
+    ('\u{F9D2}', 2483, 1),
+    ('\u{F9D3}', 2484, 1),
+    ('\u{F9D4}', 2485, 1),
+    ('\u{F9D5}', 2486, 1),
+    ('\u{F9D6}', 2487, 1),
+    ('\u{F9D7}', 2488, 1),
+    ('\u{F9D8}', 2489, 1),
+    ('\u{F9D9}', 2490, 1),
+    ('\u{F9DA}', 2491, 1),
+    ('\u{F9DB}', 2492, 1),
+    ('\u{F9DC}', 2493, 1),
+    ('\u{F9DD}', 2494, 1),
+    ('\u{F9DE}', 2495, 1),
+    ('\u{F9DF}', 2496, 1),
+    ('\u{F9E0}', 2497, 1),
+    ('\u{F9E1}', 2498, 1),
+    ('\u{F9E2}', 2499, 1),
+    ('\u{F9E3}', 2500, 1),
+    ('\u{F9E4}', 2501, 1),
+    ('\u{F9E5}', 2502, 1),
+    ('\u{F9E6}', 2503, 1),
+    ('\u{F9E7}', 2504, 1),
+    ('\u{F9E8}', 2505, 1),
+    ('\u{F9E9}', 2506, 1),
+    ('\u{F9EA}', 2507, 1),
+    ('\u{F9EB}', 2508, 1),
+    ('\u{F9EC}', 2509, 1),
+    ('\u{F9ED}', 2510, 1),
+    ('\u{F9EE}', 2511, 1),
+    ('\u{F9EF}', 2512, 1),
+    ('\u{F9F0}', 2513, 1),
+    ('\u{F9F1}', 2514, 1),
+    ('\u{F9F2}', 2515, 1),
+    ('\u{F9F3}', 2516, 1),
+    ('\u{F9F4}', 2517, 1),
+    ('\u{F9F5}', 2518, 1),
+    ('\u{F9F6}', 2519, 1),
+    ('\u{F9F7}', 2520, 1),
+    ('\u{F9F8}', 2521, 1),
+    ('\u{F9F9}', 2522, 1),
+    ('\u{F9FA}', 2523, 1),
+    ('\u{F9FB}', 2524, 1),
+    ('\u{F9FC}', 2525, 1),
+    ('\u{F9FD}', 2526, 1),
+    ('\u{F9FE}', 2527, 1),
+    ('\u{F9FF}', 2528, 1),
+    ('\u{FA00}', 2529, 1),
+    ('\u{FA01}', 2530, 1),
+    ('\u{FA02}', 2531, 1),
+    ('\u{FA03}', 2532, 1),
+    ('\u{FA04}', 2533, 1),
+    ('\u{FA05}', 2534, 1),
+    ('\u{FA06}', 2535, 1),
+    ('\u{FA07}', 2536, 1),
+    ('\u{FA08}', 2537, 1),
+    ('\u{FA09}', 2538, 1),
+    ('\u{FA0A}', 2539, 1),
+    ('\u{FA0B}', 2540, 1),
+    ('\u{FA0C}', 2541, 1),
+    ('\u{FA0D}', 2542, 1),
+    ('\u{FA10}', 2543, 1),
+    ('\u{FA12}', 2544, 1),
+    ('\u{FA15}', 2545, 1),
+    ('\u{FA16}', 2546, 1),
+    ('\u{FA17}', 2547, 1),
+    ('\u{FA18}', 2548, 1),
+    ('\u{FA19}', 2549, 1),
+    ('\u{FA1A}', 2550, 1),
+    ('\u{FA1B}', 2551, 1),
+    ('\u{FA1C}', 2552, 1),
+    ('\u{FA1D}', 2553, 1),
+    ('\u{FA1E}', 2554, 1),
+    ('\u{FA20}', 2555, 1),
+    ('\u{FA22}', 2556, 1),
+    ('\u{FA25}', 2557, 1),
+    ('\u{FA26}', 2558, 1),
+    ('\u{FA2A}', 2559, 1),
+    ('\u{FA2B}', 2560, 1),
+    ('\u{FA2C}', 2561, 1),
+    ('\u{FA2D}', 2562, 1),
+    ('\u{FA2E}', 2563, 1),
+    ('\u{FA2F}', 2564, 1),
+    ('\u{FA30}', 2565, 1),
+    ('\u{FA31}', 2566, 1),
+    ('\u{FA32}', 2567, 1),
+    ('\u{FA33}', 2568, 1),
+    ('\u{FA34}', 2569, 1),
+    ('\u{FA35}', 2570, 1),
+    ('\u{FA36}', 2571, 1),
+    ('\u{FA37}', 2572, 1),
+    ('\u{FA38}', 2573, 1),
+    ('\u{FA39}', 2574, 1),
+    ('\u{FA3A}', 2575, 1),
+    ('\u{FA3B}', 2576, 1),
+    ('\u{FA3C}', 2577, 1),
+    ('\u{FA3D}', 2578, 1),
+    ('\u{FA3E}', 2579, 1),
+    ('\u{FA3F}', 2580, 1),
+    ('\u{FA40}', 2581, 1),
+    ('\u{FA41}', 2582, 1),
+    ('\u{FA42}', 2583, 1),
+    ('\u{FA43}', 2584, 1),
+    ('\u{FA44}', 2585, 1),
+    ('\u{FA45}', 2586, 1),
+    ('\u{FA46}', 2587, 1),
+    ('\u{FA47}', 2588, 1),
+    ('\u{FA48}', 2589, 1),
+    ('\u{FA49}', 2590, 1),
+    ('\u{FA4A}', 2591, 1),
+    ('\u{FA4B}', 2592, 1),
+    ('\u{FA4C}', 2593, 1),
+    ('\u{FA4D}', 2594, 1),
+    ('\u{FA4E}', 2595, 1),
+    ('\u{FA4F}', 2596, 1),
+    ('\u{FA50}', 2597, 1),
+    ('\u{FA51}', 2598, 1),
+    ('\u{FA52}', 2599, 1),
+    ('\u{FA53}', 2600, 1),
+    ('\u{FA54}', 2601, 1),
+    ('\u{FA55}', 2602, 1),
+    ('\u{FA56}', 2603, 1),
+    ('\u{FA57}', 2604, 1),
+    ('\u{FA58}', 2605, 1),
+    ('\u{FA59}', 2606, 1),
+    ('\u{FA5A}', 2607, 1),
+    ('\u{FA5B}', 2608, 1),
+    ('\u{FA5C}', 2609, 1),
+    ('\u{FA5D}', 2610, 1),
+    ('\u{FA5E}', 2611, 1),
+    ('\u{FA5F}', 2612, 1),
+    ('\u{FA60}', 2613, 1),
+    ('\u{FA61}', 2614, 1),
+    ('\u{FA62}', 2615, 1),
+    ('\u{FA63}', 2616, 1),
+    ('\u{FA64}', 2617, 1),
+    ('\u{FA65}', 2618, 1),
+    ('\u{FA66}', 2619, 1),
+    ('\u{FA67}', 2620, 1),
+    ('\u{FA68}', 2621, 1),
+    ('\u{FA69}', 2622, 1),
+    ('\u{FA6A}', 2623, 1),
+    ('\u{FA6B}', 2624, 1),
+    ('\u{FA6C}', 2625, 1),
+    ('\u{FA6D}', 2626, 1),
+    ('\u{FA70}', 2627, 1),
+    ('\u{FA71}', 2628, 1),
+    ('\u{FA72}', 2629, 1),
+    ('\u{FA73}', 2630, 1),
+    ('\u{FA74}', 2631, 1),
+    ('\u{FA75}', 2632, 1),
+    ('\u{FA76}', 2633, 1),
+    ('\u{FA77}', 2634, 1),
+    ('\u{FA78}', 2635, 1),
+    ('\u{FA79}', 2636, 1),
+    ('\u{FA7A}', 2637, 1),
+    ('\u{FA7B}', 2638, 1),
+    ('\u{FA7C}', 2639, 1),
+    ('\u{FA7D}', 2640, 1),
+    ('\u{FA7E}', 2641, 1),
+    ('\u{FA7F}', 2642, 1),
+    ('\u{FA80}', 2643, 1),
+    ('\u{FA81}', 2644, 1),
+    ('\u{FA82}', 2645, 1),
+    ('\u{FA83}', 2646, 1),
+    ('\u{FA84}', 2647, 1),
+    ('\u{FA85}', 2648, 1),
+    ('\u{FA86}', 2649, 1),
+    ('\u{FA87}', 2650, 1),
+    ('\u{FA88}', 2651, 1),
+    ('\u{FA89}', 2652, 1),
+    ('\u{FA8A}', 2653, 1),
+    ('\u{FA8B}', 2654, 1),
+    ('\u{FA8C}', 2655, 1),
+    ('\u{FA8D}', 2656, 1),
+    ('\u{FA8E}', 2657, 1),
+    ('\u{FA8F}', 2658, 1),
+    ('\u{FA90}', 2659, 1),
+    ('\u{FA91}', 2660, 1),
+    ('\u{FA92}', 2661, 1),
+    ('\u{FA93}', 2662, 1),
+    ('\u{FA94}', 2663, 1),
+    ('\u{FA95}', 2664, 1),
+    ('\u{FA96}', 2665, 1),
+    ('\u{FA97}', 2666, 1),
+    ('\u{FA98}', 2667, 1),
+    ('\u{FA99}', 2668, 1),
+    ('\u{FA9A}', 2669, 1),
+    ('\u{FA9B}', 2670, 1),
+    ('\u{FA9C}', 2671, 1),
+    ('\u{FA9D}', 2672, 1),
+    ('\u{FA9E}', 2673, 1),
+    ('\u{FA9F}', 2674, 1),
+    ('\u{FAA0}', 2675, 1),
+    ('\u{FAA1}', 2676, 1),
+    ('\u{FAA2}', 2677, 1),
+    ('\u{FAA3}', 2678, 1),
+    ('\u{FAA4}', 2679, 1),
+    ('\u{FAA5}', 2680, 1),
+    ('\u{FAA6}', 2681, 1),
+    ('\u{FAA7}', 2682, 1),
+    ('\u{FAA8}', 2683, 1),
+    ('\u{FAA9}', 2684, 1),
+    ('\u{FAAA}', 2685, 1),
+    ('\u{FAAB}', 2686, 1),
+    ('\u{FAAC}', 2687, 1),
+    ('\u{FAAD}', 2688, 1),
+    ('\u{FAAE}', 2689, 1),
+    ('\u{FAAF}', 2690, 1),
+    ('\u{FAB0}', 2691, 1),
+    ('\u{FAB1}', 2692, 1),
+    ('\u{FAB2}', 2693, 1),
+    ('\u{FAB3}', 2694, 1),
+    ('\u{FAB4}', 2695, 1),
+    ('\u{FAB5}', 2696, 1),
+    ('\u{FAB6}', 2697, 1),
+    ('\u{FAB7}', 2698, 1),
+    ('\u{FAB8}', 2699, 1),
+    ('\u{FAB9}', 2700, 1),
+    ('\u{FABA}', 2701, 1),
+    ('\u{FABB}', 2702, 1),
+    ('\u{FABC}', 2703, 1),
+    ('\u{FABD}', 2704, 1),
+    ('\u{FABE}', 2705, 1),
+    ('\u{FABF}', 2706, 1),
+    ('\u{FAC0}', 2707, 1),
+    ('\u{FAC1}', 2708, 1),
+    ('\u{FAC2}', 2709, 1),
+    ('\u{FAC3}', 2710, 1),
+    ('\u{FAC4}', 2711, 1),
+    ('\u{FAC5}', 2712, 1),
+    ('\u{FAC6}', 2713, 1),
+    ('\u{FAC7}', 2714, 1),
+    ('\u{FAC8}', 2715, 1),
+    ('\u{FAC9}', 2716, 1),
+    ('\u{FACA}', 2717, 1),
+    ('\u{FACB}', 2718, 1),
+    ('\u{FACC}', 2719, 1),
+    ('\u{FACD}', 2720, 1),
+    ('\u{FACE}', 2721, 1),
+    ('\u{FACF}', 2722, 1),
+    ('\u{FAD0}', 2723, 1),
+    ('\u{FAD1}', 2724, 1),
+    ('\u{FAD2}', 2725, 1),
+    ('\u{FAD3}', 2726, 1),
+    ('\u{FAD4}', 2727, 1),
+    ('\u{FAD5}', 2728, 1),
+    ('\u{FAD6}', 2729, 1),
+    ('\u{FAD7}', 2730, 1),
+    ('\u{FAD8}', 2731, 1),
+    ('\u{FAD9}', 2732, 1),
+    ('\u{FB1D}', 2733, 2),
+    ('\u{FB1F}', 2735, 2),
+    ('\u{FB2A}', 2737, 2),
+    ('\u{FB2B}', 2739, 2),
+    ('\u{FB2C}', 2741, 3),
+    ('\u{FB2D}', 2744, 3),
+    ('\u{FB2E}', 2747, 2),
+    ('\u{FB2F}', 2749, 2),
+    ('\u{FB30}', 2751, 2),
+    ('\u{FB31}', 2753, 2),
+    ('\u{FB32}', 2755, 2),
+    ('\u{FB33}', 2757, 2),
+    ('\u{FB34}', 2759, 2),
+    ('\u{FB35}', 2761, 2),
+    ('\u{FB36}', 2763, 2),
+    ('\u{FB38}', 2765, 2),
+    ('\u{FB39}', 2767, 2),
+    ('\u{FB3A}', 2769, 2),
+    ('\u{FB3B}', 2771, 2),
+    ('\u{FB3C}', 2773, 2),
+    ('\u{FB3E}', 2775, 2),
+    ('\u{FB40}', 2777, 2),
+    ('\u{FB41}', 2779, 2),
+    ('\u{FB43}', 2781, 2),
+    ('\u{FB44}', 2783, 2),
+    ('\u{FB46}', 2785, 2),
+    ('\u{FB47}', 2787, 2),
+    ('\u{FB48}', 2789, 2),
+    ('\u{FB49}', 2791, 2),
+    ('\u{FB4A}', 2793, 2),
+    ('\u{FB4B}', 2795, 2),
+    ('\u{FB4C}', 2797, 2),
+    ('\u{FB4D}', 2799, 2),
+    ('\u{FB4E}', 2801, 2),
+    ('\u{1109A}', 2803, 2),
+    ('\u{1109C}', 2805, 2),
+    ('\u{110AB}', 2807, 2),
+    ('\u{1112E}', 2809, 2),
+    ('\u{1112F}', 2811, 2),
+    ('\u{1134B}', 2813, 2),
+    ('\u{1134C}', 2815, 2),
+    ('\u{114BB}', 2817, 2),
+    ('\u{114BC}', 2819, 2),
+    ('\u{114BE}', 2821, 2),
+    ('\u{115BA}', 2823, 2),
+    ('\u{115BB}', 2825, 2),
+    ('\u{11938}', 2827, 2),
+    ('\u{1D15E}', 2829, 2),
+    ('\u{1D15F}', 2831, 2),
+    ('\u{1D160}', 2833, 3),
+    ('\u{1D161}', 2836, 3),
+    ('\u{1D162}', 2839, 3),
+    ('\u{1D163}', 2842, 3),
+    ('\u{1D164}', 2845, 3),
+    ('\u{1D1BB}', 2848, 2),
+    ('\u{1D1BC}', 2850, 2),
+    ('\u{1D1BD}', 2852, 3),
+    ('\u{1D1BE}', 2855, 3),
+    ('\u{1D1BF}', 2858, 3),
+    ('\u{1D1C0}', 2861, 3),
+    ('\u{2F800}', 2864, 1),
+    ('\u{2F801}', 2865, 1),
+    ('\u{2F802}', 2866, 1),
+    ('\u{2F803}', 2867, 1),
+    ('\u{2F804}', 2868, 1),
+    ('\u{2F805}', 2869, 1),
+    ('\u{2F806}', 2870, 1),
+    ('\u{2F807}', 2871, 1),
+    ('\u{2F808}', 2872, 1),
+    ('\u{2F809}', 2873, 1),
+    ('\u{2F80A}', 2874, 1),
+    ('\u{2F80B}', 2875, 1),
+    ('\u{2F80C}', 2876, 1),
+    ('\u{2F80D}', 2877, 1),
+    ('\u{2F80E}', 2878, 1),
+    ('\u{2F80F}', 2879, 1),
+    ('\u{2F810}', 2880, 1),
+    ('\u{2F811}', 2881, 1),
+    ('\u{2F812}', 2882, 1),
+    ('\u{2F813}', 2883, 1),
+    ('\u{2F814}', 2884, 1),
+    ('\u{2F815}', 2885, 1),
+    ('\u{2F816}', 2886, 1),
+    ('\u{2F817}', 2887, 1),
+    ('\u{2F818}', 2888, 1),
+    ('\u{2F819}', 2889, 1),
+    ('\u{2F81A}', 2890, 1),
+    ('\u{2F81B}', 2891, 1),
+    ('\u{2F81C}', 2892, 1),
+    ('\u{2F81D}', 2893, 1),
+    ('\u{2F81E}', 2894, 1),
+    ('\u{2F81F}', 2895, 1),
+    ('\u{2F820}', 2896, 1),
+    ('\u{2F821}', 2897, 1),
+    ('\u{2F822}', 2898, 1),
+    ('\u{2F823}', 2899, 1),
+    ('\u{2F824}', 2900, 1),
+    ('\u{2F825}', 2901, 1),
+    ('\u{2F826}', 2902, 1),
+    ('\u{2F827}', 2903, 1),
+    ('\u{2F828}', 2904, 1),
+    ('\u{2F829}', 2905, 1),
+    ('\u{2F82A}', 2906, 1),
+    ('\u{2F82B}', 2907, 1),
+    ('\u{2F82C}', 2908, 1),
+    ('\u{2F82D}', 2909, 1),
+    ('\u{2F82E}', 2910, 1),
+    ('\u{2F82F}', 2911, 1),
+    ('\u{2F830}', 2912, 1),
+    ('\u{2F831}', 2913, 1),
+    ('\u{2F832}', 2914, 1),
+    ('\u{2F833}', 2915, 1),
+    ('\u{2F834}', 2916, 1),
+    ('\u{2F835}', 2917, 1),
+    ('\u{2F836}', 2918, 1),
+    ('\u{2F837}', 2919, 1),
+    ('\u{2F838}', 2920, 1),
+    ('\u{2F839}', 2921, 1),
+    ('\u{2F83A}', 2922, 1),
+    ('\u{2F83B}', 2923, 1),
+    ('\u{2F83C}', 2924, 1),
+    ('\u{2F83D}', 2925, 1),
+    ('\u{2F83E}', 2926, 1),
+    ('\u{2F83F}', 2927, 1),
+    ('\u{2F840}', 2928, 1),
+    ('\u{2F841}', 2929, 1),
+    ('\u{2F842}', 2930, 1),
+    ('\u{2F843}', 2931, 1),
+    ('\u{2F844}', 2932, 1),
+    ('\u{2F845}', 2933, 1),
+    ('\u{2F846}', 2934, 1),
+    ('\u{2F847}', 2935, 1),
+    ('\u{2F848}', 2936, 1),
+    ('\u{2F849}', 2937, 1),
+    ('\u{2F84A}', 2938, 1),
+    ('\u{2F84B}', 2939, 1),
+    ('\u{2F84C}', 2940, 1),
+    ('\u{2F84D}', 2941, 1),
+    ('\u{2F84E}', 2942, 1),
+    ('\u{2F84F}', 2943, 1),
+    ('\u{2F850}', 2944, 1),
+    ('\u{2F851}', 2945, 1),
+    ('\u{2F852}', 2946, 1),
+    ('\u{2F853}', 2947, 1),
+    ('\u{2F854}', 2948, 1),
+    ('\u{2F855}', 2949, 1),
+    ('\u{2F856}', 2950, 1),
+    ('\u{2F857}', 2951, 1),
+    ('\u{2F858}', 2952, 1),
+    ('\u{2F859}', 2953, 1),
+    ('\u{2F85A}', 2954, 1),
+    ('\u{2F85B}', 2955, 1),
+    ('\u{2F85C}', 2956, 1),
+    ('\u{2F85D}', 2957, 1),
+    ('\u{2F85E}', 2958, 1),
+    ('\u{2F85F}', 2959, 1),
+    ('\u{2F860}', 2960, 1),
+    ('\u{2F861}', 2961, 1),
+    ('\u{2F862}', 2962, 1),
+    ('\u{2F863}', 2963, 1),
+    ('\u{2F864}', 2964, 1),
+    ('\u{2F865}', 2965, 1),
+    ('\u{2F866}', 2966, 1),
+    ('\u{2F867}', 2967, 1),
+    ('\u{2F868}', 2968, 1),
+    ('\u{2F869}', 2969, 1),
+    ('\u{2F86A}', 2970, 1),
+    ('\u{2F86B}', 2971, 1),
+    ('\u{2F86C}', 2972, 1),
+    ('\u{2F86D}', 2973, 1),
+    ('\u{2F86E}', 2974, 1),
+    ('\u{2F86F}', 2975, 1),
+    ('\u{2F870}', 2976, 1),
+    ('\u{2F871}', 2977, 1),
+    ('\u{2F872}', 2978, 1),
+    ('\u{2F873}', 2979, 1),
+    ('\u{2F874}', 2980, 1),
+    ('\u{2F875}', 2981, 1),
+    ('\u{2F876}', 2982, 1),
+    ('\u{2F877}', 2983, 1),
+    ('\u{2F878}', 2984, 1),
+    ('\u{2F879}', 2985, 1),
+    ('\u{2F87A}', 2986, 1),
+    ('\u{2F87B}', 2987, 1),
+    ('\u{2F87C}', 2988, 1),
+    ('\u{2F87D}', 2989, 1),
+    ('\u{2F87E}', 2990, 1),
+    ('\u{2F87F}', 2991, 1),
+    ('\u{2F880}', 2992, 1),
+    ('\u{2F881}', 2993, 1),
+    ('\u{2F882}', 2994, 1),
+    ('\u{2F883}', 2995, 1),
+    ('\u{2F884}', 2996, 1),
+    ('\u{2F885}', 2997, 1),
+    ('\u{2F886}', 2998, 1),
+    ('\u{2F887}', 2999, 1),
+    ('\u{2F888}', 3000, 1),
+    ('\u{2F889}', 3001, 1),
+    ('\u{2F88A}', 3002, 1),
+    ('\u{2F88B}', 3003, 1),
+    ('\u{2F88C}', 3004, 1),
+    ('\u{2F88D}', 3005, 1),
+    ('\u{2F88E}', 3006, 1),
+    ('\u{2F88F}', 3007, 1),
+    ('\u{2F890}', 3008, 1),
+    ('\u{2F891}', 3009, 1),
+    ('\u{2F892}', 3010, 1),
+    ('\u{2F893}', 3011, 1),
+    ('\u{2F894}', 3012, 1),
+    ('\u{2F895}', 3013, 1),
+    ('\u{2F896}', 3014, 1),
+    ('\u{2F897}', 3015, 1),
+    ('\u{2F898}', 3016, 1),
+    ('\u{2F899}', 3017, 1),
+    ('\u{2F89A}', 3018, 1),
+    ('\u{2F89B}', 3019, 1),
+    ('\u{2F89C}', 3020, 1),
+    ('\u{2F89D}', 3021, 1),
+    ('\u{2F89E}', 3022, 1),
+    ('\u{2F89F}', 3023, 1),
+    ('\u{2F8A0}', 3024, 1),
+    ('\u{2F8A1}', 3025, 1),
+    ('\u{2F8A2}', 3026, 1),
+    ('\u{2F8A3}', 3027, 1),
+    ('\u{2F8A4}', 3028, 1),
+    ('\u{2F8A5}', 3029, 1),
+    ('\u{2F8A6}', 3030, 1),
+    ('\u{2F8A7}', 3031, 1),
+    ('\u{2F8A8}', 3032, 1),
+    ('\u{2F8A9}', 3033, 1),
+    ('\u{2F8AA}', 3034, 1),
+    ('\u{2F8AB}', 3035, 1),
+    ('\u{2F8AC}', 3036, 1),
+    ('\u{2F8AD}', 3037, 1),
+    ('\u{2F8AE}', 3038, 1),
+    ('\u{2F8AF}', 3039, 1),
+    ('\u{2F8B0}', 3040, 1),
+    ('\u{2F8B1}', 3041, 1),
+    ('\u{2F8B2}', 3042, 1),
+    ('\u{2F8B3}', 3043, 1),
+    ('\u{2F8B4}', 3044, 1),
+    ('\u{2F8B5}', 3045, 1),
+    ('\u{2F8B6}', 3046, 1),
+    ('\u{2F8B7}', 3047, 1),
+    ('\u{2F8B8}', 3048, 1),
+    ('\u{2F8B9}', 3049, 1),
+    ('\u{2F8BA}', 3050, 1),
+    ('\u{2F8BB}', 3051, 1),
+    ('\u{2F8BC}', 3052, 1),
+    ('\u{2F8BD}', 3053, 1),
+    ('\u{2F8BE}', 3054, 1),
+    ('\u{2F8BF}', 3055, 1),
+    ('\u{2F8C0}', 3056, 1),
+    ('\u{2F8C1}', 3057, 1),
+    ('\u{2F8C2}', 3058, 1),
+    ('\u{2F8C3}', 3059, 1),
+    ('\u{2F8C4}', 3060, 1),
+    ('\u{2F8C5}', 3061, 1),
+    ('\u{2F8C6}', 3062, 1),
+    ('\u{2F8C7}', 3063, 1),
+    ('\u{2F8C8}', 3064, 1),
+    ('\u{2F8C9}', 3065, 1),
+    ('\u{2F8CA}', 3066, 1),
+    ('\u{2F8CB}', 3067, 1),
+    ('\u{2F8CC}', 3068, 1),
+    ('\u{2F8CD}', 3069, 1),
+    ('\u{2F8CE}', 3070, 1),
+    ('\u{2F8CF}', 3071, 1),
+    ('\u{2F8D0}', 3072, 1),
+    ('\u{2F8D1}', 3073, 1),
+    ('\u{2F8D2}', 3074, 1),
+    ('\u{2F8D3}', 3075, 1),
+    ('\u{2F8D4}', 3076, 1),
+    ('\u{2F8D5}', 3077, 1),
+    ('\u{2F8D6}', 3078, 1),
+    ('\u{2F8D7}', 3079, 1),
+    ('\u{2F8D8}', 3080, 1),
+    ('\u{2F8D9}', 3081, 1),
+    ('\u{2F8DA}', 3082, 1),
+    ('\u{2F8DB}', 3083, 1),
+    ('\u{2F8DC}', 3084, 1),
+    ('\u{2F8DD}', 3085, 1),
+    ('\u{2F8DE}', 3086, 1),
+    ('\u{2F8DF}', 3087, 1),
+    ('\u{2F8E0}', 3088, 1),
+    ('\u{2F8E1}', 3089, 1),
+    ('\u{2F8E2}', 3090, 1),
+    ('\u{2F8E3}', 3091, 1),
+    ('\u{2F8E4}', 3092, 1),
+    ('\u{2F8E5}', 3093, 1),
+    ('\u{2F8E6}', 3094, 1),
+    ('\u{2F8E7}', 3095, 1),
+    ('\u{2F8E8}', 3096, 1),
+    ('\u{2F8E9}', 3097, 1),
+    ('\u{2F8EA}', 3098, 1),
+    ('\u{2F8EB}', 3099, 1),
+    ('\u{2F8EC}', 3100, 1),
+    ('\u{2F8ED}', 3101, 1),
+    ('\u{2F8EE}', 3102, 1),
+    ('\u{2F8EF}', 3103, 1),
+    ('\u{2F8F0}', 3104, 1),
+    ('\u{2F8F1}', 3105, 1),
+    ('\u{2F8F2}', 3106, 1),
+    ('\u{2F8F3}', 3107, 1),
+    ('\u{2F8F4}', 3108, 1),
+    ('\u{2F8F5}', 3109, 1),
+    ('\u{2F8F6}', 3110, 1),
+    ('\u{2F8F7}', 3111, 1),
+    ('\u{2F8F8}', 3112, 1),
+    ('\u{2F8F9}', 3113, 1),
+    ('\u{2F8FA}', 3114, 1),
+    ('\u{2F8FB}', 3115, 1),
+    ('\u{2F8FC}', 3116, 1),
+    ('\u{2F8FD}', 3117, 1),
+    ('\u{2F8FE}', 3118, 1),
+    ('\u{2F8FF}', 3119, 1),
+    ('\u{2F900}', 3120, 1),
+    ('\u{2F901}', 3121, 1),
+    ('\u{2F902}', 3122, 1),
+    ('\u{2F903}', 3123, 1),
+    ('\u{2F904}', 3124, 1),
+    ('\u{2F905}', 3125, 1),
+    ('\u{2F906}', 3126, 1),
+    ('\u{2F907}', 3127, 1),
+    ('\u{2F908}', 3128, 1),
+    ('\u{2F909}', 3129, 1),
+    ('\u{2F90A}', 3130, 1),
+    ('\u{2F90B}', 3131, 1),
+    ('\u{2F90C}', 3132, 1),
+    ('\u{2F90D}', 3133, 1),
+    ('\u{2F90E}', 3134, 1),
+    ('\u{2F90F}', 3135, 1),
+    ('\u{2F910}', 3136, 1),
+    ('\u{2F911}', 3137, 1),
+    ('\u{2F912}', 3138, 1),
+    ('\u{2F913}', 3139, 1),
+    ('\u{2F914}', 3140, 1),
+    ('\u{2F915}', 3141, 1),
+    ('\u{2F916}', 3142, 1),
+    ('\u{2F917}', 3143, 1),
+    ('\u{2F918}', 3144, 1),
+    ('\u{2F919}', 3145, 1),
+    ('\u{2F91A}', 3146, 1),
+    ('\u{2F91B}', 3147, 1),
+    ('\u{2F91C}', 3148, 1),
+    ('\u{2F91D}', 3149, 1),
+    ('\u{2F91E}', 3150, 1),
+    ('\u{2F91F}', 3151, 1),
+    ('\u{2F920}', 3152, 1),
+    ('\u{2F921}', 3153, 1),
+    ('\u{2F922}', 3154, 1),
+    ('\u{2F923}', 3155, 1),
+    ('\u{2F924}', 3156, 1),
+    ('\u{2F925}', 3157, 1),
+    ('\u{2F926}', 3158, 1),
+    ('\u{2F927}', 3159, 1),
+    ('\u{2F928}', 3160, 1),
+    ('\u{2F929}', 3161, 1),
+    ('\u{2F92A}', 3162, 1),
+    ('\u{2F92B}', 3163, 1),
+    ('\u{2F92C}', 3164, 1),
+    ('\u{2F92D}', 3165, 1),
+    ('\u{2F92E}', 3166, 1),
+    ('\u{2F92F}', 3167, 1),
+    ('\u{2F930}', 3168, 1),
+    ('\u{2F931}', 3169, 1),
+    ('\u{2F932}', 3170, 1),
+    ('\u{2F933}', 3171, 1),
+    ('\u{2F934}', 3172, 1),
+    ('\u{2F935}', 3173, 1),
+    ('\u{2F936}', 3174, 1),
+    ('\u{2F937}', 3175, 1),
+    ('\u{2F938}', 3176, 1),
+    ('\u{2F939}', 3177, 1),
+    ('\u{2F93A}', 3178, 1),
+    ('\u{2F93B}', 3179, 1),
+    ('\u{2F93C}', 3180, 1),
+    ('\u{2F93D}', 3181, 1),
+    ('\u{2F93E}', 3182, 1),
+    ('\u{2F93F}', 3183, 1),
+    ('\u{2F940}', 3184, 1),
+    ('\u{2F941}', 3185, 1),
+    ('\u{2F942}', 3186, 1),
+    ('\u{2F943}', 3187, 1),
+    ('\u{2F944}', 3188, 1),
+    ('\u{2F945}', 3189, 1),
+    ('\u{2F946}', 3190, 1),
+    ('\u{2F947}', 3191, 1),
+    ('\u{2F948}', 3192, 1),
+    ('\u{2F949}', 3193, 1),
+    ('\u{2F94A}', 3194, 1),
+    ('\u{2F94B}', 3195, 1),
+    ('\u{2F94C}', 3196, 1),
+    ('\u{2F94D}', 3197, 1),
+    ('\u{2F94E}', 3198, 1),
+    ('\u{2F94F}', 3199, 1),
+    ('\u{2F950}', 3200, 1),
+    ('\u{2F951}', 3201, 1),
+    ('\u{2F952}', 3202, 1),
+    ('\u{2F953}', 3203, 1),
+    ('\u{2F954}', 3204, 1),
+    ('\u{2F955}', 3205, 1),
+    ('\u{2F956}', 3206, 1),
+    ('\u{2F957}', 3207, 1),
+    ('\u{2F958}', 3208, 1),
+    ('\u{2F959}', 3209, 1),
+    ('\u{2F95A}', 3210, 1),
+    ('\u{2F95B}', 3211, 1),
+    ('\u{2F95C}', 3212, 1),
+    ('\u{2F95D}', 3213, 1),
+    ('\u{2F95E}', 3214, 1),
+    ('\u{2F95F}', 3215, 1),
+    ('\u{2F960}', 3216, 1),
+    ('\u{2F961}', 3217, 1),
+    ('\u{2F962}', 3218, 1),
+    ('\u{2F963}', 3219, 1),
+    ('\u{2F964}', 3220, 1),
+    ('\u{2F965}', 3221, 1),
+    ('\u{2F966}', 3222, 1),
+    ('\u{2F967}', 3223, 1),
+    ('\u{2F968}', 3224, 1),
+    ('\u{2F969}', 3225, 1),
+    ('\u{2F96A}', 3226, 1),
+    ('\u{2F96B}', 3227, 1),
+    ('\u{2F96C}', 3228, 1),
+    ('\u{2F96D}', 3229, 1),
+    ('\u{2F96E}', 3230, 1),
+    ('\u{2F96F}', 3231, 1),
+    ('\u{2F970}', 3232, 1),
+    ('\u{2F971}', 3233, 1),
+    ('\u{2F972}', 3234, 1),
+    ('\u{2F973}', 3235, 1),
+    ('\u{2F974}', 3236, 1),
+    ('\u{2F975}', 3237, 1),
+    ('\u{2F976}', 3238, 1),
+    ('\u{2F977}', 3239, 1),
+    ('\u{2F978}', 3240, 1),
+    ('\u{2F979}', 3241, 1),
+    ('\u{2F97A}', 3242, 1),
+    ('\u{2F97B}', 3243, 1),
+    ('\u{2F97C}', 3244, 1),
+    ('\u{2F97D}', 3245, 1),
+    ('\u{2F97E}', 3246, 1),
+    ('\u{2F97F}', 3247, 1),
+    ('\u{2F980}', 3248, 1),
+    ('\u{2F981}', 3249, 1),
+    ('\u{2F982}', 3250, 1),
+    ('\u{2F983}', 3251, 1),
+    ('\u{2F984}', 3252, 1),
+    ('\u{2F985}', 3253, 1),
+    ('\u{2F986}', 3254, 1),
+    ('\u{2F987}', 3255, 1),
+    ('\u{2F988}', 3256, 1),
+    ('\u{2F989}', 3257, 1),
+    ('\u{2F98A}', 3258, 1),
+    ('\u{2F98B}', 3259, 1),
+    ('\u{2F98C}', 3260, 1),
+    ('\u{2F98D}', 3261, 1),
+    ('\u{2F98E}', 3262, 1),
+    ('\u{2F98F}', 3263, 1),
+    ('\u{2F990}', 3264, 1),
+    ('\u{2F991}', 3265, 1),
+    ('\u{2F992}', 3266, 1),
+    ('\u{2F993}', 3267, 1),
+    ('\u{2F994}', 3268, 1),
+    ('\u{2F995}', 3269, 1),
+    ('\u{2F996}', 3270, 1),
+    ('\u{2F997}', 3271, 1),
+    ('\u{2F998}', 3272, 1),
+    ('\u{2F999}', 3273, 1),
+    ('\u{2F99A}', 3274, 1),
+    ('\u{2F99B}', 3275, 1),
+    ('\u{2F99C}', 3276, 1),
+    ('\u{2F99D}', 3277, 1),
+    ('\u{2F99E}', 3278, 1),
+    ('\u{2F99F}', 3279, 1),
+    ('\u{2F9A0}', 3280, 1),
+    ('\u{2F9A1}', 3281, 1),
+    ('\u{2F9A2}', 3282, 1),
+    ('\u{2F9A3}', 3283, 1),
+    ('\u{2F9A4}', 3284, 1),
+    ('\u{2F9A5}', 3285, 1),
+    ('\u{2F9A6}', 3286, 1),
+    ('\u{2F9A7}', 3287, 1),
+    ('\u{2F9A8}', 3288, 1),
+    ('\u{2F9A9}', 3289, 1),
+    ('\u{2F9AA}', 3290, 1),
+    ('\u{2F9AB}', 3291, 1),
+    ('\u{2F9AC}', 3292, 1),
+    ('\u{2F9AD}', 3293, 1),
+    ('\u{2F9AE}', 3294, 1),
+    ('\u{2F9AF}', 3295, 1),
+    ('\u{2F9B0}', 3296, 1),
+    ('\u{2F9B1}', 3297, 1),
+    ('\u{2F9B2}', 3298, 1),
+    ('\u{2F9B3}', 3299, 1),
+    ('\u{2F9B4}', 3300, 1),
+    ('\u{2F9B5}', 3301, 1),
+    ('\u{2F9B6}', 3302, 1),
+    ('\u{2F9B7}', 3303, 1),
+    ('\u{2F9B8}', 3304, 1),
+    ('\u{2F9B9}', 3305, 1),
+    ('\u{2F9BA}', 3306, 1),
+    ('\u{2F9BB}', 3307, 1),
+    ('\u{2F9BC}', 3308, 1),
+    ('\u{2F9BD}', 3309, 1),
+    ('\u{2F9BE}', 3310, 1),
+    ('\u{2F9BF}', 3311, 1),
+    ('\u{2F9C0}', 3312, 1),
+    ('\u{2F9C1}', 3313, 1),
+    ('\u{2F9C2}', 3314, 1),
+    ('\u{2F9C3}', 3315, 1),
+    ('\u{2F9C4}', 3316, 1),
+    ('\u{2F9C5}', 3317, 1),
+    ('\u{2F9C6}', 3318, 1),
+    ('\u{2F9C7}', 3319, 1),
+    ('\u{2F9C8}', 3320, 1),
+    ('\u{2F9C9}', 3321, 1),
+    ('\u{2F9CA}', 3322, 1),
+    ('\u{2F9CB}', 3323, 1),
+    ('\u{2F9CC}', 3324, 1),
+    ('\u{2F9CD}', 3325, 1),
+    ('\u{2F9CE}', 3326, 1),
+    ('\u{2F9CF}', 3327, 1),
+    ('\u{2F9D0}', 3328, 1),
+    ('\u{2F9D1}', 3329, 1),
+    ('\u{2F9D2}', 3330, 1),
+    ('\u{2F9D3}', 3331, 1),
+    ('\u{2F9D4}', 3332, 1),
+    ('\u{2F9D5}', 3333, 1),
+    ('\u{2F9D6}', 3334, 1),
+    ('\u{2F9D7}', 3335, 1),
+    ('\u{2F9D8}', 3336, 1),
+    ('\u{2F9D9}', 3337, 1),
+    ('\u{2F9DA}', 3338, 1),
+    ('\u{2F9DB}', 3339, 1),
+    ('\u{2F9DC}', 3340, 1),
+    ('\u{2F9DD}', 3341, 1),
+    ('\u{2F9DE}', 3342, 1),
+    ('\u{2F9DF}', 3343, 1),
+    ('\u{2F9E0}', 3344, 1),
+    ('\u{2F9E1}', 3345, 1),
+    ('\u{2F9E2}', 3346, 1),
+    ('\u{2F9E3}', 3347, 1),
+    ('\u{2F9E4}', 3348, 1),
+    ('\u{2F9E5}', 3349, 1),
+    ('\u{2F9E6}', 3350, 1),
+    ('\u{2F9E7}', 3351, 1),
+    ('\u{2F9E8}', 3352, 1),
+    ('\u{2F9E9}', 3353, 1),
+    ('\u{2F9EA}', 3354, 1),
+    ('\u{2F9EB}', 3355, 1),
+    ('\u{2F9EC}', 3356, 1),
+    ('\u{2F9ED}', 3357, 1),
+    ('\u{2F9EE}', 3358, 1),
+    ('\u{2F9EF}', 3359, 1),
+    ('\u{2F9F0}', 3360, 1),
+    ('\u{2F9F1}', 3361, 1),
+    ('\u{2F9F2}', 3362, 1),
+    ('\u{2F9F3}', 3363, 1),
+    ('\u{2F9F4}', 3364, 1),
+    ('\u{2F9F5}', 3365, 1),
+    ('\u{2F9F6}', 3366, 1),
+    ('\u{2F9F7}', 3367, 1),
+    ('\u{2F9F8}', 3368, 1),
+    ('\u{2F9F9}', 3369, 1),
+    ('\u{2F9FA}', 3370, 1),
+    ('\u{2F9FB}', 3371, 1),
+    ('\u{2F9FC}', 3372, 1),
+    ('\u{2F9FD}', 3373, 1),
+    ('\u{2F9FE}', 3374, 1),
+    ('\u{2F9FF}', 3375, 1),
+    ('\u{2FA00}', 3376, 1),
+    ('\u{2FA01}', 3377, 1),
+    ('\u{2FA02}', 3378, 1),
+    ('\u{2FA03}', 3379, 1),
+    ('\u{2FA04}', 3380, 1),
+    ('\u{2FA05}', 3381, 1),
+    ('\u{2FA06}', 3382, 1),
+    ('\u{2FA07}', 3383, 1),
+    ('\u{2FA08}', 3384, 1),
+    ('\u{2FA09}', 3385, 1),
+    ('\u{2FA0A}', 3386, 1),
+    ('\u{2FA0B}', 3387, 1),
+    ('\u{2FA0C}', 3388, 1),
+    ('\u{2FA0D}', 3389, 1),
+    ('\u{2FA0E}', 3390, 1),
+    ('\u{2FA0F}', 3391, 1),
+    ('\u{2FA10}', 3392, 1),
+    ('\u{2FA11}', 3393, 1),
+    ('\u{2FA12}', 3394, 1),
+    ('\u{2FA13}', 3395, 1),
+    ('\u{2FA14}', 3396, 1),
+    ('\u{2FA15}', 3397, 1),
+    ('\u{2FA16}', 3398, 1),
+    ('\u{2FA17}', 3399, 1),
+    ('\u{2FA18}', 3400, 1),
+    ('\u{2FA19}', 3401, 1),
+    ('\u{2FA1A}', 3402, 1),
+    ('\u{2FA1B}', 3403, 1),
+    ('\u{2FA1C}', 3404, 1),
+    ('\u{2FA1D}', 3405, 1),
+];
+
+pub(super) static CANONICAL_CHARS: [char; 3406] = [
+    '\u{0041}', '\u{0300}', '\u{0041}', '\u{0301}', '\u{0041}', '\u{0302}', '\u{0041}', '\u{0303}',
+    '\u{0041}', '\u{0308}', '\u{0041}', '\u{030A}', '\u{0043}', '\u{0327}', '\u{0045}', '\u{0300}',
+    '\u{0045}', '\u{0301}', '\u{0045}', '\u{0302}', '\u{0045}', '\u{0308}', '\u{0049}', '\u{0300}',
+    '\u{0049}', '\u{0301}', '\u{0049}', '\u{0302}', '\u{0049}', '\u{0308}', '\u{004E}', '\u{0303}',
+    '\u{004F}', '\u{0300}', '\u{004F}', '\u{0301}', '\u{004F}', '\u{0302}', '\u{004F}', '\u{0303}',
+    '\u{004F}', '\u{0308}', '\u{0055}', '\u{0300}', '\u{0055}', '\u{0301}', '\u{0055}', '\u{0302}',
+    '\u{0055}', '\u{0308}', '\u{0059}', '\u{0301}', '\u{0061}', '\u{0300}', '\u{0061}', '\u{0301}',
+    '\u{0061}', '\u{0302}', '\u{0061}', '\u{0303}', '\u{0061}', '\u{0308}', '\u{0061}', '\u{030A}',
+    '\u{0063}', '\u{0327}', '\u{0065}', '\u{0300}', '\u{0065}', '\u{0301}', '\u{0065}', '\u{0302}',
+    '\u{0065}', '\u{0308}', '\u{0069}', '\u{0300}', '\u{0069}', '\u{0301}', '\u{0069}', '\u{0302}',
+    '\u{0069}', '\u{0308}', '\u{006E}', '\u{0303}', '\u{006F}', '\u{0300}', '\u{006F}', '\u{0301}',
+    '\u{006F}', '\u{0302}', '\u{006F}', '\u{0303}', '\u{006F}', '\u{0308}', '\u{0075}', '\u{0300}',
+    '\u{0075}', '\u{0301}', '\u{0075}', '\u{0302}', '\u{0075}', '\u{0308}', '\u{0079}', '\u{0301}',
+    '\u{0079}', '\u{0308}', '\u{0041}', '\u{0304}', '\u{0061}', '\u{0304}', '\u{0041}', '\u{0306}',
+    '\u{0061}', '\u{0306}', '\u{0041}', '\u{0328}', '\u{0061}', '\u{0328}', '\u{0043}', '\u{0301}',
+    '\u{0063}', '\u{0301}', '\u{0043}', '\u{0302}', '\u{0063}', '\u{0302}', '\u{0043}', '\u{0307}',
+    '\u{0063}', '\u{0307}', '\u{0043}', '\u{030C}', '\u{0063}', '\u{030C}', '\u{0044}', '\u{030C}',
+    '\u{0064}', '\u{030C}', '\u{0045}', '\u{0304}', '\u{0065}', '\u{0304}', '\u{0045}', '\u{0306}',
+    '\u{0065}', '\u{0306}', '\u{0045}', '\u{0307}', '\u{0065}', '\u{0307}', '\u{0045}', '\u{0328}',
+    '\u{0065}', '\u{0328}', '\u{0045}', '\u{030C}', '\u{0065}', '\u{030C}', '\u{0047}', '\u{0302}',
+    '\u{0067}', '\u{0302}', '\u{0047}', '\u{0306}', '\u{0067}', '\u{0306}', '\u{0047}', '\u{0307}',
+    '\u{0067}', '\u{0307}', '\u{0047}', '\u{0327}', '\u{0067}', '\u{0327}', '\u{0048}', '\u{0302}',
+    '\u{0068}', '\u{0302}', '\u{0049}', '\u{0303}', '\u{0069}', '\u{0303}', '\u{0049}', '\u{0304}',
+    '\u{0069}', '\u{0304}', '\u{0049}', '\u{0306}', '\u{0069}', '\u{0306}', '\u{0049}', '\u{0328}',
+    '\u{0069}', '\u{0328}', '\u{0049}', '\u{0307}', '\u{004A}', '\u{0302}', '\u{006A}', '\u{0302}',
+    '\u{004B}', '\u{0327}', '\u{006B}', '\u{0327}', '\u{004C}', '\u{0301}', '\u{006C}', '\u{0301}',
+    '\u{004C}', '\u{0327}', '\u{006C}', '\u{0327}', '\u{004C}', '\u{030C}', '\u{006C}', '\u{030C}',
+    '\u{004E}', '\u{0301}', '\u{006E}', '\u{0301}', '\u{004E}', '\u{0327}', '\u{006E}', '\u{0327}',
+    '\u{004E}', '\u{030C}', '\u{006E}', '\u{030C}', '\u{004F}', '\u{0304}', '\u{006F}', '\u{0304}',
+    '\u{004F}', '\u{0306}', '\u{006F}', '\u{0306}', '\u{004F}', '\u{030B}', '\u{006F}', '\u{030B}',
+    '\u{0052}', '\u{0301}', '\u{0072}', '\u{0301}', '\u{0052}', '\u{0327}', '\u{0072}', '\u{0327}',
+    '\u{0052}', '\u{030C}', '\u{0072}', '\u{030C}', '\u{0053}', '\u{0301}', '\u{0073}', '\u{0301}',
+    '\u{0053}', '\u{0302}', '\u{0073}', '\u{0302}', '\u{0053}', '\u{0327}', '\u{0073}', '\u{0327}',
+    '\u{0053}', '\u{030C}', '\u{0073}', '\u{030C}', '\u{0054}', '\u{0327}', '\u{0074}', '\u{0327}',
+    '\u{0054}', '\u{030C}', '\u{0074}', '\u{030C}', '\u{0055}', '\u{0303}', '\u{0075}', '\u{0303}',
+    '\u{0055}', '\u{0304}', '\u{0075}', '\u{0304}', '\u{0055}', '\u{0306}', '\u{0075}', '\u{0306}',
+    '\u{0055}', '\u{030A}', '\u{0075}', '\u{030A}', '\u{0055}', '\u{030B}', '\u{0075}', '\u{030B}',
+    '\u{0055}', '\u{0328}', '\u{0075}', '\u{0328}', '\u{0057}', '\u{0302}', '\u{0077}', '\u{0302}',
+    '\u{0059}', '\u{0302}', '\u{0079}', '\u{0302}', '\u{0059}', '\u{0308}', '\u{005A}', '\u{0301}',
+    '\u{007A}', '\u{0301}', '\u{005A}', '\u{0307}', '\u{007A}', '\u{0307}', '\u{005A}', '\u{030C}',
+    '\u{007A}', '\u{030C}', '\u{004F}', '\u{031B}', '\u{006F}', '\u{031B}', '\u{0055}', '\u{031B}',
+    '\u{0075}', '\u{031B}', '\u{0041}', '\u{030C}', '\u{0061}', '\u{030C}', '\u{0049}', '\u{030C}',
+    '\u{0069}', '\u{030C}', '\u{004F}', '\u{030C}', '\u{006F}', '\u{030C}', '\u{0055}', '\u{030C}',
+    '\u{0075}', '\u{030C}', '\u{0055}', '\u{0308}', '\u{0304}', '\u{0075}', '\u{0308}', '\u{0304}',
+    '\u{0055}', '\u{0308}', '\u{0301}', '\u{0075}', '\u{0308}', '\u{0301}', '\u{0055}', '\u{0308}',
+    '\u{030C}', '\u{0075}', '\u{0308}', '\u{030C}', '\u{0055}', '\u{0308}', '\u{0300}', '\u{0075}',
+    '\u{0308}', '\u{0300}', '\u{0041}', '\u{0308}', '\u{0304}', '\u{0061}', '\u{0308}', '\u{0304}',
+    '\u{0041}', '\u{0307}', '\u{0304}', '\u{0061}', '\u{0307}', '\u{0304}', '\u{00C6}', '\u{0304}',
+    '\u{00E6}', '\u{0304}', '\u{0047}', '\u{030C}', '\u{0067}', '\u{030C}', '\u{004B}', '\u{030C}',
+    '\u{006B}', '\u{030C}', '\u{004F}', '\u{0328}', '\u{006F}', '\u{0328}', '\u{004F}', '\u{0328}',
+    '\u{0304}', '\u{006F}', '\u{0328}', '\u{0304}', '\u{01B7}', '\u{030C}', '\u{0292}', '\u{030C}',
+    '\u{006A}', '\u{030C}', '\u{0047}', '\u{0301}', '\u{0067}', '\u{0301}', '\u{004E}', '\u{0300}',
+    '\u{006E}', '\u{0300}', '\u{0041}', '\u{030A}', '\u{0301}', '\u{0061}', '\u{030A}', '\u{0301}',
+    '\u{00C6}', '\u{0301}', '\u{00E6}', '\u{0301}', '\u{00D8}', '\u{0301}', '\u{00F8}', '\u{0301}',
+    '\u{0041}', '\u{030F}', '\u{0061}', '\u{030F}', '\u{0041}', '\u{0311}', '\u{0061}', '\u{0311}',
+    '\u{0045}', '\u{030F}', '\u{0065}', '\u{030F}', '\u{0045}', '\u{0311}', '\u{0065}', '\u{0311}',
+    '\u{0049}', '\u{030F}', '\u{0069}', '\u{030F}', '\u{0049}', '\u{0311}', '\u{0069}', '\u{0311}',
+    '\u{004F}', '\u{030F}', '\u{006F}', '\u{030F}', '\u{004F}', '\u{0311}', '\u{006F}', '\u{0311}',
+    '\u{0052}', '\u{030F}', '\u{0072}', '\u{030F}', '\u{0052}', '\u{0311}', '\u{0072}', '\u{0311}',
+    '\u{0055}', '\u{030F}', '\u{0075}', '\u{030F}', '\u{0055}', '\u{0311}', '\u{0075}', '\u{0311}',
+    '\u{0053}', '\u{0326}', '\u{0073}', '\u{0326}', '\u{0054}', '\u{0326}', '\u{0074}', '\u{0326}',
+    '\u{0048}', '\u{030C}', '\u{0068}', '\u{030C}', '\u{0041}', '\u{0307}', '\u{0061}', '\u{0307}',
+    '\u{0045}', '\u{0327}', '\u{0065}', '\u{0327}', '\u{004F}', '\u{0308}', '\u{0304}', '\u{006F}',
+    '\u{0308}', '\u{0304}', '\u{004F}', '\u{0303}', '\u{0304}', '\u{006F}', '\u{0303}', '\u{0304}',
+    '\u{004F}', '\u{0307}', '\u{006F}', '\u{0307}', '\u{004F}', '\u{0307}', '\u{0304}', '\u{006F}',
+    '\u{0307}', '\u{0304}', '\u{0059}', '\u{0304}', '\u{0079}', '\u{0304}', '\u{0300}', '\u{0301}',
+    '\u{0313}', '\u{0308}', '\u{0301}', '\u{02B9}', '\u{003B}', '\u{00A8}', '\u{0301}', '\u{0391}',
+    '\u{0301}', '\u{00B7}', '\u{0395}', '\u{0301}', '\u{0397}', '\u{0301}', '\u{0399}', '\u{0301}',
+    '\u{039F}', '\u{0301}', '\u{03A5}', '\u{0301}', '\u{03A9}', '\u{0301}', '\u{03B9}', '\u{0308}',
+    '\u{0301}', '\u{0399}', '\u{0308}', '\u{03A5}', '\u{0308}', '\u{03B1}', '\u{0301}', '\u{03B5}',
+    '\u{0301}', '\u{03B7}', '\u{0301}', '\u{03B9}', '\u{0301}', '\u{03C5}', '\u{0308}', '\u{0301}',
+    '\u{03B9}', '\u{0308}', '\u{03C5}', '\u{0308}', '\u{03BF}', '\u{0301}', '\u{03C5}', '\u{0301}',
+    '\u{03C9}', '\u{0301}', '\u{03D2}', '\u{0301}', '\u{03D2}', '\u{0308}', '\u{0415}', '\u{0300}',
+    '\u{0415}', '\u{0308}', '\u{0413}', '\u{0301}', '\u{0406}', '\u{0308}', '\u{041A}', '\u{0301}',
+    '\u{0418}', '\u{0300}', '\u{0423}', '\u{0306}', '\u{0418}', '\u{0306}', '\u{0438}', '\u{0306}',
+    '\u{0435}', '\u{0300}', '\u{0435}', '\u{0308}', '\u{0433}', '\u{0301}', '\u{0456}', '\u{0308}',
+    '\u{043A}', '\u{0301}', '\u{0438}', '\u{0300}', '\u{0443}', '\u{0306}', '\u{0474}', '\u{030F}',
+    '\u{0475}', '\u{030F}', '\u{0416}', '\u{0306}', '\u{0436}', '\u{0306}', '\u{0410}', '\u{0306}',
+    '\u{0430}', '\u{0306}', '\u{0410}', '\u{0308}', '\u{0430}', '\u{0308}', '\u{0415}', '\u{0306}',
+    '\u{0435}', '\u{0306}', '\u{04D8}', '\u{0308}', '\u{04D9}', '\u{0308}', '\u{0416}', '\u{0308}',
+    '\u{0436}', '\u{0308}', '\u{0417}', '\u{0308}', '\u{0437}', '\u{0308}', '\u{0418}', '\u{0304}',
+    '\u{0438}', '\u{0304}', '\u{0418}', '\u{0308}', '\u{0438}', '\u{0308}', '\u{041E}', '\u{0308}',
+    '\u{043E}', '\u{0308}', '\u{04E8}', '\u{0308}', '\u{04E9}', '\u{0308}', '\u{042D}', '\u{0308}',
+    '\u{044D}', '\u{0308}', '\u{0423}', '\u{0304}', '\u{0443}', '\u{0304}', '\u{0423}', '\u{0308}',
+    '\u{0443}', '\u{0308}', '\u{0423}', '\u{030B}', '\u{0443}', '\u{030B}', '\u{0427}', '\u{0308}',
+    '\u{0447}', '\u{0308}', '\u{042B}', '\u{0308}', '\u{044B}', '\u{0308}', '\u{0627}', '\u{0653}',
+    '\u{0627}', '\u{0654}', '\u{0648}', '\u{0654}', '\u{0627}', '\u{0655}', '\u{064A}', '\u{0654}',
+    '\u{06D5}', '\u{0654}', '\u{06C1}', '\u{0654}', '\u{06D2}', '\u{0654}', '\u{0928}', '\u{093C}',
+    '\u{0930}', '\u{093C}', '\u{0933}', '\u{093C}', '\u{0915}', '\u{093C}', '\u{0916}', '\u{093C}',
+    '\u{0917}', '\u{093C}', '\u{091C}', '\u{093C}', '\u{0921}', '\u{093C}', '\u{0922}', '\u{093C}',
+    '\u{092B}', '\u{093C}', '\u{092F}', '\u{093C}', '\u{09C7}', '\u{09BE}', '\u{09C7}', '\u{09D7}',
+    '\u{09A1}', '\u{09BC}', '\u{09A2}', '\u{09BC}', '\u{09AF}', '\u{09BC}', '\u{0A32}', '\u{0A3C}',
+    '\u{0A38}', '\u{0A3C}', '\u{0A16}', '\u{0A3C}', '\u{0A17}', '\u{0A3C}', '\u{0A1C}', '\u{0A3C}',
+    '\u{0A2B}', '\u{0A3C}', '\u{0B47}', '\u{0B56}', '\u{0B47}', '\u{0B3E}', '\u{0B47}', '\u{0B57}',
+    '\u{0B21}', '\u{0B3C}', '\u{0B22}', '\u{0B3C}', '\u{0B92}', '\u{0BD7}', '\u{0BC6}', '\u{0BBE}',
+    '\u{0BC7}', '\u{0BBE}', '\u{0BC6}', '\u{0BD7}', '\u{0C46}', '\u{0C56}', '\u{0CBF}', '\u{0CD5}',
+    '\u{0CC6}', '\u{0CD5}', '\u{0CC6}', '\u{0CD6}', '\u{0CC6}', '\u{0CC2}', '\u{0CC6}', '\u{0CC2}',
+    '\u{0CD5}', '\u{0D46}', '\u{0D3E}', '\u{0D47}', '\u{0D3E}', '\u{0D46}', '\u{0D57}', '\u{0DD9}',
+    '\u{0DCA}', '\u{0DD9}', '\u{0DCF}', '\u{0DD9}', '\u{0DCF}', '\u{0DCA}', '\u{0DD9}', '\u{0DDF}',
+    '\u{0F42}', '\u{0FB7}', '\u{0F4C}', '\u{0FB7}', '\u{0F51}', '\u{0FB7}', '\u{0F56}', '\u{0FB7}',
+    '\u{0F5B}', '\u{0FB7}', '\u{0F40}', '\u{0FB5}', '\u{0F71}', '\u{0F72}', '\u{0F71}', '\u{0F74}',
+    '\u{0FB2}', '\u{0F80}', '\u{0FB3}', '\u{0F80}', '\u{0F71}', '\u{0F80}', '\u{0F92}', '\u{0FB7}',
+    '\u{0F9C}', '\u{0FB7}', '\u{0FA1}', '\u{0FB7}', '\u{0FA6}', '\u{0FB7}', '\u{0FAB}', '\u{0FB7}',
+    '\u{0F90}', '\u{0FB5}', '\u{1025}', '\u{102E}', '\u{1B05}', '\u{1B35}', '\u{1B07}', '\u{1B35}',
+    '\u{1B09}', '\u{1B35}', '\u{1B0B}', '\u{1B35}', '\u{1B0D}', '\u{1B35}', '\u{1B11}', '\u{1B35}',
+    '\u{1B3A}', '\u{1B35}', '\u{1B3C}', '\u{1B35}', '\u{1B3E}', '\u{1B35}', '\u{1B3F}', '\u{1B35}',
+    '\u{1B42}', '\u{1B35}', '\u{0041}', '\u{0325}', '\u{0061}', '\u{0325}', '\u{0042}', '\u{0307}',
+    '\u{0062}', '\u{0307}', '\u{0042}', '\u{0323}', '\u{0062}', '\u{0323}', '\u{0042}', '\u{0331}',
+    '\u{0062}', '\u{0331}', '\u{0043}', '\u{0327}', '\u{0301}', '\u{0063}', '\u{0327}', '\u{0301}',
+    '\u{0044}', '\u{0307}', '\u{0064}', '\u{0307}', '\u{0044}', '\u{0323}', '\u{0064}', '\u{0323}',
+    '\u{0044}', '\u{0331}', '\u{0064}', '\u{0331}', '\u{0044}', '\u{0327}', '\u{0064}', '\u{0327}',
+    '\u{0044}', '\u{032D}', '\u{0064}', '\u{032D}', '\u{0045}', '\u{0304}', '\u{0300}', '\u{0065}',
+    '\u{0304}', '\u{0300}', '\u{0045}', '\u{0304}', '\u{0301}', '\u{0065}', '\u{0304}', '\u{0301}',
+    '\u{0045}', '\u{032D}', '\u{0065}', '\u{032D}', '\u{0045}', '\u{0330}', '\u{0065}', '\u{0330}',
+    '\u{0045}', '\u{0327}', '\u{0306}', '\u{0065}', '\u{0327}', '\u{0306}', '\u{0046}', '\u{0307}',
+    '\u{0066}', '\u{0307}', '\u{0047}', '\u{0304}', '\u{0067}', '\u{0304}', '\u{0048}', '\u{0307}',
+    '\u{0068}', '\u{0307}', '\u{0048}', '\u{0323}', '\u{0068}', '\u{0323}', '\u{0048}', '\u{0308}',
+    '\u{0068}', '\u{0308}', '\u{0048}', '\u{0327}', '\u{0068}', '\u{0327}', '\u{0048}', '\u{032E}',
+    '\u{0068}', '\u{032E}', '\u{0049}', '\u{0330}', '\u{0069}', '\u{0330}', '\u{0049}', '\u{0308}',
+    '\u{0301}', '\u{0069}', '\u{0308}', '\u{0301}', '\u{004B}', '\u{0301}', '\u{006B}', '\u{0301}',
+    '\u{004B}', '\u{0323}', '\u{006B}', '\u{0323}', '\u{004B}', '\u{0331}', '\u{006B}', '\u{0331}',
+    '\u{004C}', '\u{0323}', '\u{006C}', '\u{0323}', '\u{004C}', '\u{0323}', '\u{0304}', '\u{006C}',
+    '\u{0323}', '\u{0304}', '\u{004C}', '\u{0331}', '\u{006C}', '\u{0331}', '\u{004C}', '\u{032D}',
+    '\u{006C}', '\u{032D}', '\u{004D}', '\u{0301}', '\u{006D}', '\u{0301}', '\u{004D}', '\u{0307}',
+    '\u{006D}', '\u{0307}', '\u{004D}', '\u{0323}', '\u{006D}', '\u{0323}', '\u{004E}', '\u{0307}',
+    '\u{006E}', '\u{0307}', '\u{004E}', '\u{0323}', '\u{006E}', '\u{0323}', '\u{004E}', '\u{0331}',
+    '\u{006E}', '\u{0331}', '\u{004E}', '\u{032D}', '\u{006E}', '\u{032D}', '\u{004F}', '\u{0303}',
+    '\u{0301}', '\u{006F}', '\u{0303}', '\u{0301}', '\u{004F}', '\u{0303}', '\u{0308}', '\u{006F}',
+    '\u{0303}', '\u{0308}', '\u{004F}', '\u{0304}', '\u{0300}', '\u{006F}', '\u{0304}', '\u{0300}',
+    '\u{004F}', '\u{0304}', '\u{0301}', '\u{006F}', '\u{0304}', '\u{0301}', '\u{0050}', '\u{0301}',
+    '\u{0070}', '\u{0301}', '\u{0050}', '\u{0307}', '\u{0070}', '\u{0307}', '\u{0052}', '\u{0307}',
+    '\u{0072}', '\u{0307}', '\u{0052}', '\u{0323}', '\u{0072}', '\u{0323}', '\u{0052}', '\u{0323}',
+    '\u{0304}', '\u{0072}', '\u{0323}', '\u{0304}', '\u{0052}', '\u{0331}', '\u{0072}', '\u{0331}',
+    '\u{0053}', '\u{0307}', '\u{0073}', '\u{0307}', '\u{0053}', '\u{0323}', '\u{0073}', '\u{0323}',
+    '\u{0053}', '\u{0301}', '\u{0307}', '\u{0073}', '\u{0301}', '\u{0307}', '\u{0053}', '\u{030C}',
+    '\u{0307}', '\u{0073}', '\u{030C}', '\u{0307}', '\u{0053}', '\u{0323}', '\u{0307}', '\u{0073}',
+    '\u{0323}', '\u{0307}', '\u{0054}', '\u{0307}', '\u{0074}', '\u{0307}', '\u{0054}', '\u{0323}',
+    '\u{0074}', '\u{0323}', '\u{0054}', '\u{0331}', '\u{0074}', '\u{0331}', '\u{0054}', '\u{032D}',
+    '\u{0074}', '\u{032D}', '\u{0055}', '\u{0324}', '\u{0075}', '\u{0324}', '\u{0055}', '\u{0330}',
+    '\u{0075}', '\u{0330}', '\u{0055}', '\u{032D}', '\u{0075}', '\u{032D}', '\u{0055}', '\u{0303}',
+    '\u{0301}', '\u{0075}', '\u{0303}', '\u{0301}', '\u{0055}', '\u{0304}', '\u{0308}', '\u{0075}',
+    '\u{0304}', '\u{0308}', '\u{0056}', '\u{0303}', '\u{0076}', '\u{0303}', '\u{0056}', '\u{0323}',
+    '\u{0076}', '\u{0323}', '\u{0057}', '\u{0300}', '\u{0077}', '\u{0300}', '\u{0057}', '\u{0301}',
+    '\u{0077}', '\u{0301}', '\u{0057}', '\u{0308}', '\u{0077}', '\u{0308}', '\u{0057}', '\u{0307}',
+    '\u{0077}', '\u{0307}', '\u{0057}', '\u{0323}', '\u{0077}', '\u{0323}', '\u{0058}', '\u{0307}',
+    '\u{0078}', '\u{0307}', '\u{0058}', '\u{0308}', '\u{0078}', '\u{0308}', '\u{0059}', '\u{0307}',
+    '\u{0079}', '\u{0307}', '\u{005A}', '\u{0302}', '\u{007A}', '\u{0302}', '\u{005A}', '\u{0323}',
+    '\u{007A}', '\u{0323}', '\u{005A}', '\u{0331}', '\u{007A}', '\u{0331}', '\u{0068}', '\u{0331}',
+    '\u{0074}', '\u{0308}', '\u{0077}', '\u{030A}', '\u{0079}', '\u{030A}', '\u{017F}', '\u{0307}',
+    '\u{0041}', '\u{0323}', '\u{0061}', '\u{0323}', '\u{0041}', '\u{0309}', '\u{0061}', '\u{0309}',
+    '\u{0041}', '\u{0302}', '\u{0301}', '\u{0061}', '\u{0302}', '\u{0301}', '\u{0041}', '\u{0302}',
+    '\u{0300}', '\u{0061}', '\u{0302}', '\u{0300}', '\u{0041}', '\u{0302}', '\u{0309}', '\u{0061}',
+    '\u{0302}', '\u{0309}', '\u{0041}', '\u{0302}', '\u{0303}', '\u{0061}', '\u{0302}', '\u{0303}',
+    '\u{0041}', '\u{0323}', '\u{0302}', '\u{0061}', '\u{0323}', '\u{0302}', '\u{0041}', '\u{0306}',
+    '\u{0301}', '\u{0061}', '\u{0306}', '\u{0301}', '\u{0041}', '\u{0306}', '\u{0300}', '\u{0061}',
+    '\u{0306}', '\u{0300}', '\u{0041}', '\u{0306}', '\u{0309}', '\u{0061}', '\u{0306}', '\u{0309}',
+    '\u{0041}', '\u{0306}', '\u{0303}', '\u{0061}', '\u{0306}', '\u{0303}', '\u{0041}', '\u{0323}',
+    '\u{0306}', '\u{0061}', '\u{0323}', '\u{0306}', '\u{0045}', '\u{0323}', '\u{0065}', '\u{0323}',
+    '\u{0045}', '\u{0309}', '\u{0065}', '\u{0309}', '\u{0045}', '\u{0303}', '\u{0065}', '\u{0303}',
+    '\u{0045}', '\u{0302}', '\u{0301}', '\u{0065}', '\u{0302}', '\u{0301}', '\u{0045}', '\u{0302}',
+    '\u{0300}', '\u{0065}', '\u{0302}', '\u{0300}', '\u{0045}', '\u{0302}', '\u{0309}', '\u{0065}',
+    '\u{0302}', '\u{0309}', '\u{0045}', '\u{0302}', '\u{0303}', '\u{0065}', '\u{0302}', '\u{0303}',
+    '\u{0045}', '\u{0323}', '\u{0302}', '\u{0065}', '\u{0323}', '\u{0302}', '\u{0049}', '\u{0309}',
+    '\u{0069}', '\u{0309}', '\u{0049}', '\u{0323}', '\u{0069}', '\u{0323}', '\u{004F}', '\u{0323}',
+    '\u{006F}', '\u{0323}', '\u{004F}', '\u{0309}', '\u{006F}', '\u{0309}', '\u{004F}', '\u{0302}',
+    '\u{0301}', '\u{006F}', '\u{0302}', '\u{0301}', '\u{004F}', '\u{0302}', '\u{0300}', '\u{006F}',
+    '\u{0302}', '\u{0300}', '\u{004F}', '\u{0302}', '\u{0309}', '\u{006F}', '\u{0302}', '\u{0309}',
+    '\u{004F}', '\u{0302}', '\u{0303}', '\u{006F}', '\u{0302}', '\u{0303}', '\u{004F}', '\u{0323}',
+    '\u{0302}', '\u{006F}', '\u{0323}', '\u{0302}', '\u{004F}', '\u{031B}', '\u{0301}', '\u{006F}',
+    '\u{031B}', '\u{0301}', '\u{004F}', '\u{031B}', '\u{0300}', '\u{006F}', '\u{031B}', '\u{0300}',
+    '\u{004F}', '\u{031B}', '\u{0309}', '\u{006F}', '\u{031B}', '\u{0309}', '\u{004F}', '\u{031B}',
+    '\u{0303}', '\u{006F}', '\u{031B}', '\u{0303}', '\u{004F}', '\u{031B}', '\u{0323}', '\u{006F}',
+    '\u{031B}', '\u{0323}', '\u{0055}', '\u{0323}', '\u{0075}', '\u{0323}', '\u{0055}', '\u{0309}',
+    '\u{0075}', '\u{0309}', '\u{0055}', '\u{031B}', '\u{0301}', '\u{0075}', '\u{031B}', '\u{0301}',
+    '\u{0055}', '\u{031B}', '\u{0300}', '\u{0075}', '\u{031B}', '\u{0300}', '\u{0055}', '\u{031B}',
+    '\u{0309}', '\u{0075}', '\u{031B}', '\u{0309}', '\u{0055}', '\u{031B}', '\u{0303}', '\u{0075}',
+    '\u{031B}', '\u{0303}', '\u{0055}', '\u{031B}', '\u{0323}', '\u{0075}', '\u{031B}', '\u{0323}',
+    '\u{0059}', '\u{0300}', '\u{0079}', '\u{0300}', '\u{0059}', '\u{0323}', '\u{0079}', '\u{0323}',
+    '\u{0059}', '\u{0309}', '\u{0079}', '\u{0309}', '\u{0059}', '\u{0303}', '\u{0079}', '\u{0303}',
+    '\u{03B1}', '\u{0313}', '\u{03B1}', '\u{0314}', '\u{03B1}', '\u{0313}', '\u{0300}', '\u{03B1}',
+    '\u{0314}', '\u{0300}', '\u{03B1}', '\u{0313}', '\u{0301}', '\u{03B1}', '\u{0314}', '\u{0301}',
+    '\u{03B1}', '\u{0313}', '\u{0342}', '\u{03B1}', '\u{0314}', '\u{0342}', '\u{0391}', '\u{0313}',
+    '\u{0391}', '\u{0314}', '\u{0391}', '\u{0313}', '\u{0300}', '\u{0391}', '\u{0314}', '\u{0300}',
+    '\u{0391}', '\u{0313}', '\u{0301}', '\u{0391}', '\u{0314}', '\u{0301}', '\u{0391}', '\u{0313}',
+    '\u{0342}', '\u{0391}', '\u{0314}', '\u{0342}', '\u{03B5}', '\u{0313}', '\u{03B5}', '\u{0314}',
+    '\u{03B5}', '\u{0313}', '\u{0300}', '\u{03B5}', '\u{0314}', '\u{0300}', '\u{03B5}', '\u{0313}',
+    '\u{0301}', '\u{03B5}', '\u{0314}', '\u{0301}', '\u{0395}', '\u{0313}', '\u{0395}', '\u{0314}',
+    '\u{0395}', '\u{0313}', '\u{0300}', '\u{0395}', '\u{0314}', '\u{0300}', '\u{0395}', '\u{0313}',
+    '\u{0301}', '\u{0395}', '\u{0314}', '\u{0301}', '\u{03B7}', '\u{0313}', '\u{03B7}', '\u{0314}',
+    '\u{03B7}', '\u{0313}', '\u{0300}', '\u{03B7}', '\u{0314}', '\u{0300}', '\u{03B7}', '\u{0313}',
+    '\u{0301}', '\u{03B7}', '\u{0314}', '\u{0301}', '\u{03B7}', '\u{0313}', '\u{0342}', '\u{03B7}',
+    '\u{0314}', '\u{0342}', '\u{0397}', '\u{0313}', '\u{0397}', '\u{0314}', '\u{0397}', '\u{0313}',
+    '\u{0300}', '\u{0397}', '\u{0314}', '\u{0300}', '\u{0397}', '\u{0313}', '\u{0301}', '\u{0397}',
+    '\u{0314}', '\u{0301}', '\u{0397}', '\u{0313}', '\u{0342}', '\u{0397}', '\u{0314}', '\u{0342}',
+    '\u{03B9}', '\u{0313}', '\u{03B9}', '\u{0314}', '\u{03B9}', '\u{0313}', '\u{0300}', '\u{03B9}',
+    '\u{0314}', '\u{0300}', '\u{03B9}', '\u{0313}', '\u{0301}', '\u{03B9}', '\u{0314}', '\u{0301}',
+    '\u{03B9}', '\u{0313}', '\u{0342}', '\u{03B9}', '\u{0314}', '\u{0342}', '\u{0399}', '\u{0313}',
+    '\u{0399}', '\u{0314}', '\u{0399}', '\u{0313}', '\u{0300}', '\u{0399}', '\u{0314}', '\u{0300}',
+    '\u{0399}', '\u{0313}', '\u{0301}', '\u{0399}', '\u{0314}', '\u{0301}', '\u{0399}', '\u{0313}',
+    '\u{0342}', '\u{0399}', '\u{0314}', '\u{0342}', '\u{03BF}', '\u{0313}', '\u{03BF}', '\u{0314}',
+    '\u{03BF}', '\u{0313}', '\u{0300}', '\u{03BF}', '\u{0314}', '\u{0300}', '\u{03BF}', '\u{0313}',
+    '\u{0301}', '\u{03BF}', '\u{0314}', '\u{0301}', '\u{039F}', '\u{0313}', '\u{039F}', '\u{0314}',
+    '\u{039F}', '\u{0313}', '\u{0300}', '\u{039F}', '\u{0314}', '\u{0300}', '\u{039F}', '\u{0313}',
+    '\u{0301}', '\u{039F}', '\u{0314}', '\u{0301}', '\u{03C5}', '\u{0313}', '\u{03C5}', '\u{0314}',
+    '\u{03C5}', '\u{0313}', '\u{0300}', '\u{03C5}', '\u{0314}', '\u{0300}', '\u{03C5}', '\u{0313}',
+    '\u{0301}', '\u{03C5}', '\u{0314}', '\u{0301}', '\u{03C5}', '\u{0313}', '\u{0342}', '\u{03C5}',
+    '\u{0314}', '\u{0342}', '\u{03A5}', '\u{0314}', '\u{03A5}', '\u{0314}', '\u{0300}', '\u{03A5}',
+    '\u{0314}', '\u{0301}', '\u{03A5}', '\u{0314}', '\u{0342}', '\u{03C9}', '\u{0313}', '\u{03C9}',
+    '\u{0314}', '\u{03C9}', '\u{0313}', '\u{0300}', '\u{03C9}', '\u{0314}', '\u{0300}', '\u{03C9}',
+    '\u{0313}', '\u{0301}', '\u{03C9}', '\u{0314}', '\u{0301}', '\u{03C9}', '\u{0313}', '\u{0342}',
+    '\u{03C9}', '\u{0314}', '\u{0342}', '\u{03A9}', '\u{0313}', '\u{03A9}', '\u{0314}', '\u{03A9}',
+    '\u{0313}', '\u{0300}', '\u{03A9}', '\u{0314}', '\u{0300}', '\u{03A9}', '\u{0313}', '\u{0301}',
+    '\u{03A9}', '\u{0314}', '\u{0301}', '\u{03A9}', '\u{0313}', '\u{0342}', '\u{03A9}', '\u{0314}',
+    '\u{0342}', '\u{03B1}', '\u{0300}', '\u{03B1}', '\u{0301}', '\u{03B5}', '\u{0300}', '\u{03B5}',
+    '\u{0301}', '\u{03B7}', '\u{0300}', '\u{03B7}', '\u{0301}', '\u{03B9}', '\u{0300}', '\u{03B9}',
+    '\u{0301}', '\u{03BF}', '\u{0300}', '\u{03BF}', '\u{0301}', '\u{03C5}', '\u{0300}', '\u{03C5}',
+    '\u{0301}', '\u{03C9}', '\u{0300}', '\u{03C9}', '\u{0301}', '\u{03B1}', '\u{0313}', '\u{0345}',
+    '\u{03B1}', '\u{0314}', '\u{0345}', '\u{03B1}', '\u{0313}', '\u{0300}', '\u{0345}', '\u{03B1}',
+    '\u{0314}', '\u{0300}', '\u{0345}', '\u{03B1}', '\u{0313}', '\u{0301}', '\u{0345}', '\u{03B1}',
+    '\u{0314}', '\u{0301}', '\u{0345}', '\u{03B1}', '\u{0313}', '\u{0342}', '\u{0345}', '\u{03B1}',
+    '\u{0314}', '\u{0342}', '\u{0345}', '\u{0391}', '\u{0313}', '\u{0345}', '\u{0391}', '\u{0314}',
+    '\u{0345}', '\u{0391}', '\u{0313}', '\u{0300}', '\u{0345}', '\u{0391}', '\u{0314}', '\u{0300}',
+    '\u{0345}', '\u{0391}', '\u{0313}', '\u{0301}', '\u{0345}', '\u{0391}', '\u{0314}', '\u{0301}',
+    '\u{0345}', '\u{0391}', '\u{0313}', '\u{0342}', '\u{0345}', '\u{0391}', '\u{0314}', '\u{0342}',
+    '\u{0345}', '\u{03B7}', '\u{0313}', '\u{0345}', '\u{03B7}', '\u{0314}', '\u{0345}', '\u{03B7}',
+    '\u{0313}', '\u{0300}', '\u{0345}', '\u{03B7}', '\u{0314}', '\u{0300}', '\u{0345}', '\u{03B7}',
+    '\u{0313}', '\u{0301}', '\u{0345}', '\u{03B7}', '\u{0314}', '\u{0301}', '\u{0345}', '\u{03B7}',
+    '\u{0313}', '\u{0342}', '\u{0345}', '\u{03B7}', '\u{0314}', '\u{0342}', '\u{0345}', '\u{0397}',
+    '\u{0313}', '\u{0345}', '\u{0397}', '\u{0314}', '\u{0345}', '\u{0397}', '\u{0313}', '\u{0300}',
+    '\u{0345}', '\u{0397}', '\u{0314}', '\u{0300}', '\u{0345}', '\u{0397}', '\u{0313}', '\u{0301}',
+    '\u{0345}', '\u{0397}', '\u{0314}', '\u{0301}', '\u{0345}', '\u{0397}', '\u{0313}', '\u{0342}',
+    '\u{0345}', '\u{0397}', '\u{0314}', '\u{0342}', '\u{0345}', '\u{03C9}', '\u{0313}', '\u{0345}',
+    '\u{03C9}', '\u{0314}', '\u{0345}', '\u{03C9}', '\u{0313}', '\u{0300}', '\u{0345}', '\u{03C9}',
+    '\u{0314}', '\u{0300}', '\u{0345}', '\u{03C9}', '\u{0313}', '\u{0301}', '\u{0345}', '\u{03C9}',
+    '\u{0314}', '\u{0301}', '\u{0345}', '\u{03C9}', '\u{0313}', '\u{0342}', '\u{0345}', '\u{03C9}',
+    '\u{0314}', '\u{0342}', '\u{0345}', '\u{03A9}', '\u{0313}', '\u{0345}', '\u{03A9}', '\u{0314}',
+    '\u{0345}', '\u{03A9}', '\u{0313}', '\u{0300}', '\u{0345}', '\u{03A9}', '\u{0314}', '\u{0300}',
+    '\u{0345}', '\u{03A9}', '\u{0313}', '\u{0301}', '\u{0345}', '\u{03A9}', '\u{0314}', '\u{0301}',
+    '\u{0345}', '\u{03A9}', '\u{0313}', '\u{0342}', '\u{0345}', '\u{03A9}', '\u{0314}', '\u{0342}',
+    '\u{0345}', '\u{03B1}', '\u{0306}', '\u{03B1}', '\u{0304}', '\u{03B1}', '\u{0300}', '\u{0345}',
+    '\u{03B1}', '\u{0345}', '\u{03B1}', '\u{0301}', '\u{0345}', '\u{03B1}', '\u{0342}', '\u{03B1}',
+    '\u{0342}', '\u{0345}', '\u{0391}', '\u{0306}', '\u{0391}', '\u{0304}', '\u{0391}', '\u{0300}',
+    '\u{0391}', '\u{0301}', '\u{0391}', '\u{0345}', '\u{03B9}', '\u{00A8}', '\u{0342}', '\u{03B7}',
+    '\u{0300}', '\u{0345}', '\u{03B7}', '\u{0345}', '\u{03B7}', '\u{0301}', '\u{0345}', '\u{03B7}',
+    '\u{0342}', '\u{03B7}', '\u{0342}', '\u{0345}', '\u{0395}', '\u{0300}', '\u{0395}', '\u{0301}',
+    '\u{0397}', '\u{0300}', '\u{0397}', '\u{0301}', '\u{0397}', '\u{0345}', '\u{1FBF}', '\u{0300}',
+    '\u{1FBF}', '\u{0301}', '\u{1FBF}', '\u{0342}', '\u{03B9}', '\u{0306}', '\u{03B9}', '\u{0304}',
+    '\u{03B9}', '\u{0308}', '\u{0300}', '\u{03B9}', '\u{0308}', '\u{0301}', '\u{03B9}', '\u{0342}',
+    '\u{03B9}', '\u{0308}', '\u{0342}', '\u{0399}', '\u{0306}', '\u{0399}', '\u{0304}', '\u{0399}',
+    '\u{0300}', '\u{0399}', '\u{0301}', '\u{1FFE}', '\u{0300}', '\u{1FFE}', '\u{0301}', '\u{1FFE}',
+    '\u{0342}', '\u{03C5}', '\u{0306}', '\u{03C5}', '\u{0304}', '\u{03C5}', '\u{0308}', '\u{0300}',
+    '\u{03C5}', '\u{0308}', '\u{0301}', '\u{03C1}', '\u{0313}', '\u{03C1}', '\u{0314}', '\u{03C5}',
+    '\u{0342}', '\u{03C5}', '\u{0308}', '\u{0342}', '\u{03A5}', '\u{0306}', '\u{03A5}', '\u{0304}',
+    '\u{03A5}', '\u{0300}', '\u{03A5}', '\u{0301}', '\u{03A1}', '\u{0314}', '\u{00A8}', '\u{0300}',
+    '\u{00A8}', '\u{0301}', '\u{0060}', '\u{03C9}', '\u{0300}', '\u{0345}', '\u{03C9}', '\u{0345}',
+    '\u{03C9}', '\u{0301}', '\u{0345}', '\u{03C9}', '\u{0342}', '\u{03C9}', '\u{0342}', '\u{0345}',
+    '\u{039F}', '\u{0300}', '\u{039F}', '\u{0301}', '\u{03A9}', '\u{0300}', '\u{03A9}', '\u{0301}',
+    '\u{03A9}', '\u{0345}', '\u{00B4}', '\u{2002}', '\u{2003}', '\u{03A9}', '\u{004B}', '\u{0041}',
+    '\u{030A}', '\u{2190}', '\u{0338}', '\u{2192}', '\u{0338}', '\u{2194}', '\u{0338}', '\u{21D0}',
+    '\u{0338}', '\u{21D4}', '\u{0338}', '\u{21D2}', '\u{0338}', '\u{2203}', '\u{0338}', '\u{2208}',
+    '\u{0338}', '\u{220B}', '\u{0338}', '\u{2223}', '\u{0338}', '\u{2225}', '\u{0338}', '\u{223C}',
+    '\u{0338}', '\u{2243}', '\u{0338}', '\u{2245}', '\u{0338}', '\u{2248}', '\u{0338}', '\u{003D}',
+    '\u{0338}', '\u{2261}', '\u{0338}', '\u{224D}', '\u{0338}', '\u{003C}', '\u{0338}', '\u{003E}',
+    '\u{0338}', '\u{2264}', '\u{0338}', '\u{2265}', '\u{0338}', '\u{2272}', '\u{0338}', '\u{2273}',
+    '\u{0338}', '\u{2276}', '\u{0338}', '\u{2277}', '\u{0338}', '\u{227A}', '\u{0338}', '\u{227B}',
+    '\u{0338}', '\u{2282}', '\u{0338}', '\u{2283}', '\u{0338}', '\u{2286}', '\u{0338}', '\u{2287}',
+    '\u{0338}', '\u{22A2}', '\u{0338}', '\u{22A8}', '\u{0338}', '\u{22A9}', '\u{0338}', '\u{22AB}',
+    '\u{0338}', '\u{227C}', '\u{0338}', '\u{227D}', '\u{0338}', '\u{2291}', '\u{0338}', '\u{2292}',
+    '\u{0338}', '\u{22B2}', '\u{0338}', '\u{22B3}', '\u{0338}', '\u{22B4}', '\u{0338}', '\u{22B5}',
+    '\u{0338}', '\u{3008}', '\u{3009}', '\u{2ADD}', '\u{0338}', '\u{304B}', '\u{3099}', '\u{304D}',
+    '\u{3099}', '\u{304F}', '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}',
+    '\u{3099}', '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}', '\u{305B}', '\u{3099}', '\u{305D}',
+    '\u{3099}', '\u{305F}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}', '\u{3099}', '\u{3066}',
+    '\u{3099}', '\u{3068}', '\u{3099}', '\u{306F}', '\u{3099}', '\u{306F}', '\u{309A}', '\u{3072}',
+    '\u{3099}', '\u{3072}', '\u{309A}', '\u{3075}', '\u{3099}', '\u{3075}', '\u{309A}', '\u{3078}',
+    '\u{3099}', '\u{3078}', '\u{309A}', '\u{307B}', '\u{3099}', '\u{307B}', '\u{309A}', '\u{3046}',
+    '\u{3099}', '\u{309D}', '\u{3099}', '\u{30AB}', '\u{3099}', '\u{30AD}', '\u{3099}', '\u{30AF}',
+    '\u{3099}', '\u{30B1}', '\u{3099}', '\u{30B3}', '\u{3099}', '\u{30B5}', '\u{3099}', '\u{30B7}',
+    '\u{3099}', '\u{30B9}', '\u{3099}', '\u{30BB}', '\u{3099}', '\u{30BD}', '\u{3099}', '\u{30BF}',
+    '\u{3099}', '\u{30C1}', '\u{3099}', '\u{30C4}', '\u{3099}', '\u{30C6}', '\u{3099}', '\u{30C8}',
+    '\u{3099}', '\u{30CF}', '\u{3099}', '\u{30CF}', '\u{309A}', '\u{30D2}', '\u{3099}', '\u{30D2}',
+    '\u{309A}', '\u{30D5}', '\u{3099}', '\u{30D5}', '\u{309A}', '\u{30D8}', '\u{3099}', '\u{30D8}',
+    '\u{309A}', '\u{30DB}', '\u{3099}', '\u{30DB}', '\u{309A}', '\u{30A6}', '\u{3099}', '\u{30EF}',
+    '\u{3099}', '\u{30F0}', '\u{3099}', '\u{30F1}', '\u{3099}', '\u{30F2}', '\u{3099}', '\u{30FD}',
+    '\u{3099}', '\u{8C48}', '\u{66F4}', '\u{8ECA}', '\u{8CC8}', '\u{6ED1}', '\u{4E32}', '\u{53E5}',
+    '\u{9F9C}', '\u{9F9C}', '\u{5951}', '\u{91D1}', '\u{5587}', '\u{5948}', '\u{61F6}', '\u{7669}',
+    '\u{7F85}', '\u{863F}', '\u{87BA}', '\u{88F8}', '\u{908F}', '\u{6A02}', '\u{6D1B}', '\u{70D9}',
+    '\u{73DE}', '\u{843D}', '\u{916A}', '\u{99F1}', '\u{4E82}', '\u{5375}', '\u{6B04}', '\u{721B}',
+    '\u{862D}', '\u{9E1E}', '\u{5D50}', '\u{6FEB}', '\u{85CD}', '\u{8964}', '\u{62C9}', '\u{81D8}',
+    '\u{881F}', '\u{5ECA}', '\u{6717}', '\u{6D6A}', '\u{72FC}', '\u{90CE}', '\u{4F86}', '\u{51B7}',
+    '\u{52DE}', '\u{64C4}', '\u{6AD3}', '\u{7210}', '\u{76E7}', '\u{8001}', '\u{8606}', '\u{865C}',
+    '\u{8DEF}', '\u{9732}', '\u{9B6F}', '\u{9DFA}', '\u{788C}', '\u{797F}', '\u{7DA0}', '\u{83C9}',
+    '\u{9304}', '\u{9E7F}', '\u{8AD6}', '\u{58DF}', '\u{5F04}', '\u{7C60}', '\u{807E}', '\u{7262}',
+    '\u{78CA}', '\u{8CC2}', '\u{96F7}', '\u{58D8}', '\u{5C62}', '\u{6A13}', '\u{6DDA}', '\u{6F0F}',
+    '\u{7D2F}', '\u{7E37}', '\u{964B}', '\u{52D2}', '\u{808B}', '\u{51DC}', '\u{51CC}', '\u{7A1C}',
+    '\u{7DBE}', '\u{83F1}', '\u{9675}', '\u{8B80}', '\u{62CF}', '\u{6A02}', '\u{8AFE}', '\u{4E39}',
+    '\u{5BE7}', '\u{6012}', '\u{7387}', '\u{7570}', '\u{5317}', '\u{78FB}', '\u{4FBF}', '\u{5FA9}',
+    '\u{4E0D}', '\u{6CCC}', '\u{6578}', '\u{7D22}', '\u{53C3}', '\u{585E}', '\u{7701}', '\u{8449}',
+    '\u{8AAA}', '\u{6BBA}', '\u{8FB0}', '\u{6C88}', '\u{62FE}', '\u{82E5}', '\u{63A0}', '\u{7565}',
+    '\u{4EAE}', '\u{5169}', '\u{51C9}', '\u{6881}', '\u{7CE7}', '\u{826F}', '\u{8AD2}', '\u{91CF}',
+    '\u{52F5}', '\u{5442}', '\u{5973}', '\u{5EEC}', '\u{65C5}', '\u{6FFE}', '\u{792A}', '\u{95AD}',
+    '\u{9A6A}', '\u{9E97}', '\u{9ECE}', '\u{529B}', '\u{66C6}', '\u{6B77}', '\u{8F62}', '\u{5E74}',
+    '\u{6190}', '\u{6200}', '\u{649A}', '\u{6F23}', '\u{7149}', '\u{7489}', '\u{79CA}', '\u{7DF4}',
+    '\u{806F}', '\u{8F26}', '\u{84EE}', '\u{9023}', '\u{934A}', '\u{5217}', '\u{52A3}', '\u{54BD}',
+    '\u{70C8}', '\u{88C2}', '\u{8AAA}', '\u{5EC9}', '\u{5FF5}', '\u{637B}', '\u{6BAE}', '\u{7C3E}',
+    '\u{7375}', '\u{4EE4}', '\u{56F9}', '\u{5BE7}', '\u{5DBA}', '\u{601C}', '\u{73B2}', '\u{7469}',
+    '\u{7F9A}', '\u{8046}', '\u{9234}', '\u{96F6}', '\u{9748}', '\u{9818}', '\u{4F8B}', '\u{79AE}',
+    '\u{91B4}', '\u{96B8}', '\u{60E1}', '\u{4E86}', '\u{50DA}', '\u{5BEE}', '\u{5C3F}', '\u{6599}',
+    '\u{6A02}', '\u{71CE}', '\u{7642}', '\u{84FC}', '\u{907C}', '\u{9F8D}', '\u{6688}', '\u{962E}',
+    '\u{5289}', '\u{677B}', '\u{67F3}', '\u{6D41}', '\u{6E9C}', '\u{7409}', '\u{7559}', '\u{786B}',
+    '\u{7D10}', '\u{985E}', '\u{516D}', '\u{622E}', '\u{9678}', '\u{502B}', '\u{5D19}', '\u{6DEA}',
+    '\u{8F2A}', '\u{5F8B}', '\u{6144}', '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540F}',
+    '\u{5C65}', '\u{6613}', '\u{674E}', '\u{68A8}', '\u{6CE5}', '\u{7406}', '\u{75E2}', '\u{7F79}',
+    '\u{88CF}', '\u{88E1}', '\u{91CC}', '\u{96E2}', '\u{533F}', '\u{6EBA}', '\u{541D}', '\u{71D0}',
+    '\u{7498}', '\u{85FA}', '\u{96A3}', '\u{9C57}', '\u{9E9F}', '\u{6797}', '\u{6DCB}', '\u{81E8}',
+    '\u{7ACB}', '\u{7B20}', '\u{7C92}', '\u{72C0}', '\u{7099}', '\u{8B58}', '\u{4EC0}', '\u{8336}',
+    '\u{523A}', '\u{5207}', '\u{5EA6}', '\u{62D3}', '\u{7CD6}', '\u{5B85}', '\u{6D1E}', '\u{66B4}',
+    '\u{8F3B}', '\u{884C}', '\u{964D}', '\u{898B}', '\u{5ED3}', '\u{5140}', '\u{55C0}', '\u{585A}',
+    '\u{6674}', '\u{51DE}', '\u{732A}', '\u{76CA}', '\u{793C}', '\u{795E}', '\u{7965}', '\u{798F}',
+    '\u{9756}', '\u{7CBE}', '\u{7FBD}', '\u{8612}', '\u{8AF8}', '\u{9038}', '\u{90FD}', '\u{98EF}',
+    '\u{98FC}', '\u{9928}', '\u{9DB4}', '\u{90DE}', '\u{96B7}', '\u{4FAE}', '\u{50E7}', '\u{514D}',
+    '\u{52C9}', '\u{52E4}', '\u{5351}', '\u{559D}', '\u{5606}', '\u{5668}', '\u{5840}', '\u{58A8}',
+    '\u{5C64}', '\u{5C6E}', '\u{6094}', '\u{6168}', '\u{618E}', '\u{61F2}', '\u{654F}', '\u{65E2}',
+    '\u{6691}', '\u{6885}', '\u{6D77}', '\u{6E1A}', '\u{6F22}', '\u{716E}', '\u{722B}', '\u{7422}',
+    '\u{7891}', '\u{793E}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}', '\u{795D}', '\u{798D}',
+    '\u{798E}', '\u{7A40}', '\u{7A81}', '\u{7BC0}', '\u{7DF4}', '\u{7E09}', '\u{7E41}', '\u{7F72}',
+    '\u{8005}', '\u{81ED}', '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8B01}',
+    '\u{8B39}', '\u{8CD3}', '\u{8D08}', '\u{8FB6}', '\u{9038}', '\u{96E3}', '\u{97FF}', '\u{983B}',
+    '\u{6075}', '\u{242EE}', '\u{8218}', '\u{4E26}', '\u{51B5}', '\u{5168}', '\u{4F80}', '\u{5145}',
+    '\u{5180}', '\u{52C7}', '\u{52FA}', '\u{559D}', '\u{5555}', '\u{5599}', '\u{55E2}', '\u{585A}',
+    '\u{58B3}', '\u{5944}', '\u{5954}', '\u{5A62}', '\u{5B28}', '\u{5ED2}', '\u{5ED9}', '\u{5F69}',
+    '\u{5FAD}', '\u{60D8}', '\u{614E}', '\u{6108}', '\u{618E}', '\u{6160}', '\u{61F2}', '\u{6234}',
+    '\u{63C4}', '\u{641C}', '\u{6452}', '\u{6556}', '\u{6674}', '\u{6717}', '\u{671B}', '\u{6756}',
+    '\u{6B79}', '\u{6BBA}', '\u{6D41}', '\u{6EDB}', '\u{6ECB}', '\u{6F22}', '\u{701E}', '\u{716E}',
+    '\u{77A7}', '\u{7235}', '\u{72AF}', '\u{732A}', '\u{7471}', '\u{7506}', '\u{753B}', '\u{761D}',
+    '\u{761F}', '\u{76CA}', '\u{76DB}', '\u{76F4}', '\u{774A}', '\u{7740}', '\u{78CC}', '\u{7AB1}',
+    '\u{7BC0}', '\u{7C7B}', '\u{7D5B}', '\u{7DF4}', '\u{7F3E}', '\u{8005}', '\u{8352}', '\u{83EF}',
+    '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}', '\u{8ABF}', '\u{8AF8}', '\u{8ACB}', '\u{8B01}',
+    '\u{8AFE}', '\u{8AED}', '\u{8B39}', '\u{8B8A}', '\u{8D08}', '\u{8F38}', '\u{9072}', '\u{9199}',
+    '\u{9276}', '\u{967C}', '\u{96E3}', '\u{9756}', '\u{97DB}', '\u{97FF}', '\u{980B}', '\u{983B}',
+    '\u{9B12}', '\u{9F9C}', '\u{2284A}', '\u{22844}', '\u{233D5}', '\u{3B9D}', '\u{4018}', '\u{4039}',
+    '\u{25249}', '\u{25CD0}', '\u{27ED3}', '\u{9F43}', '\u{9F8E}', '\u{05D9}', '\u{05B4}', '\u{05F2}',
+    '\u{05B7}', '\u{05E9}', '\u{05C1}', '\u{05E9}', '\u{05C2}', '\u{05E9}', '\u{05BC}', '\u{05C1}',
+    '\u{05E9}', '\u{05BC}', '\u{05C2}', '\u{05D0}', '\u{05B7}', '\u{05D0}', '\u{05B8}', '\u{05D0}',
+    '\u{05BC}', '\u{05D1}', '\u{05BC}', '\u{05D2}', '\u{05BC}', '\u{05D3}', '\u{05BC}', '\u{05D4}',
+    '\u{05BC}', '\u{05D5}', '\u{05BC}', '\u{05D6}', '\u{05BC}', '\u{05D8}', '\u{05BC}', '\u{05D9}',
+    '\u{05BC}', '\u{05DA}', '\u{05BC}', '\u{05DB}', '\u{05BC}', '\u{05DC}', '\u{05BC}', '\u{05DE}',
+    '\u{05BC}', '\u{05E0}', '\u{05BC}', '\u{05E1}', '\u{05BC}', '\u{05E3}', '\u{05BC}', '\u{05E4}',
+    '\u{05BC}', '\u{05E6}', '\u{05BC}', '\u{05E7}', '\u{05BC}', '\u{05E8}', '\u{05BC}', '\u{05E9}',
+    '\u{05BC}', '\u{05EA}', '\u{05BC}', '\u{05D5}', '\u{05B9}', '\u{05D1}', '\u{05BF}', '\u{05DB}',
+    '\u{05BF}', '\u{05E4}', '\u{05BF}', '\u{11099}', '\u{110BA}', '\u{1109B}', '\u{110BA}', '\u{110A5}',
+    '\u{110BA}', '\u{11131}', '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}', '\u{1133E}', '\u{11347}',
+    '\u{11357}', '\u{114B9}', '\u{114BA}', '\u{114B9}', '\u{114B0}', '\u{114B9}', '\u{114BD}', '\u{115B8}',
+    '\u{115AF}', '\u{115B9}', '\u{115AF}', '\u{11935}', '\u{11930}', '\u{1D157}', '\u{1D165}', '\u{1D158}',
+    '\u{1D165}', '\u{1D158}', '\u{1D165}', '\u{1D16E}', '\u{1D158}', '\u{1D165}', '\u{1D16F}', '\u{1D158}',
+    '\u{1D165}', '\u{1D170}', '\u{1D158}', '\u{1D165}', '\u{1D171}', '\u{1D158}', '\u{1D165}', '\u{1D172}',
+    '\u{1D1B9}', '\u{1D165}', '\u{1D1BA}', '\u{1D165}', '\u{1D1B9}', '\u{1D165}', '\u{1D16E}', '\u{1D1BA}',
+    '\u{1D165}', '\u{1D16E}', '\u{1D1B9}', '\u{1D165}', '\u{1D16F}', '\u{1D1BA}', '\u{1D165}', '\u{1D16F}',
+    '\u{4E3D}', '\u{4E38}', '\u{4E41}', '\u{20122}', '\u{4F60}', '\u{4FAE}', '\u{4FBB}', '\u{5002}',
+    '\u{507A}', '\u{5099}', '\u{50E7}', '\u{50CF}', '\u{349E}', '\u{2063A}', '\u{514D}', '\u{5154}',
+    '\u{5164}', '\u{5177}', '\u{2051C}', '\u{34B9}', '\u{5167}', '\u{518D}', '\u{2054B}', '\u{5197}',
+    '\u{51A4}', '\u{4ECC}', '\u{51AC}', '\u{51B5}', '\u{291DF}', '\u{51F5}', '\u{5203}', '\u{34DF}',
+    '\u{523B}', '\u{5246}', '\u{5272}', '\u{5277}', '\u{3515}', '\u{52C7}', '\u{52C9}', '\u{52E4}',
+    '\u{52FA}', '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}', '\u{535A}', '\u{5373}',
+    '\u{537D}', '\u{537F}', '\u{537F}', '\u{537F}', '\u{20A2C}', '\u{7070}', '\u{53CA}', '\u{53DF}',
+    '\u{20B63}', '\u{53EB}', '\u{53F1}', '\u{5406}', '\u{549E}', '\u{5438}', '\u{5448}', '\u{5468}',
+    '\u{54A2}', '\u{54F6}', '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{5584}', '\u{5599}',
+    '\u{55AB}', '\u{55B3}', '\u{55C2}', '\u{5716}', '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}',
+    '\u{5207}', '\u{58EE}', '\u{57CE}', '\u{57F4}', '\u{580D}', '\u{578B}', '\u{5832}', '\u{5831}',
+    '\u{58AC}', '\u{214E4}', '\u{58F2}', '\u{58F7}', '\u{5906}', '\u{591A}', '\u{5922}', '\u{5962}',
+    '\u{216A8}', '\u{216EA}', '\u{59EC}', '\u{5A1B}', '\u{5A27}', '\u{59D8}', '\u{5A66}', '\u{36EE}',
+    '\u{36FC}', '\u{5B08}', '\u{5B3E}', '\u{5B3E}', '\u{219C8}', '\u{5BC3}', '\u{5BD8}', '\u{5BE7}',
+    '\u{5BF3}', '\u{21B18}', '\u{5BFF}', '\u{5C06}', '\u{5F53}', '\u{5C22}', '\u{3781}', '\u{5C60}',
+    '\u{5C6E}', '\u{5CC0}', '\u{5C8D}', '\u{21DE4}', '\u{5D43}', '\u{21DE6}', '\u{5D6E}', '\u{5D6B}',
+    '\u{5D7C}', '\u{5DE1}', '\u{5DE2}', '\u{382F}', '\u{5DFD}', '\u{5E28}', '\u{5E3D}', '\u{5E69}',
+    '\u{3862}', '\u{22183}', '\u{387C}', '\u{5EB0}', '\u{5EB3}', '\u{5EB6}', '\u{5ECA}', '\u{2A392}',
+    '\u{5EFE}', '\u{22331}', '\u{22331}', '\u{8201}', '\u{5F22}', '\u{5F22}', '\u{38C7}', '\u{232B8}',
+    '\u{261DA}', '\u{5F62}', '\u{5F6B}', '\u{38E3}', '\u{5F9A}', '\u{5FCD}', '\u{5FD7}', '\u{5FF9}',
+    '\u{6081}', '\u{393A}', '\u{391C}', '\u{6094}', '\u{226D4}', '\u{60C7}', '\u{6148}', '\u{614C}',
+    '\u{614E}', '\u{614C}', '\u{617A}', '\u{618E}', '\u{61B2}', '\u{61A4}', '\u{61AF}', '\u{61DE}',
+    '\u{61F2}', '\u{61F6}', '\u{6210}', '\u{621B}', '\u{625D}', '\u{62B1}', '\u{62D4}', '\u{6350}',
+    '\u{22B0C}', '\u{633D}', '\u{62FC}', '\u{6368}', '\u{6383}', '\u{63E4}', '\u{22BF1}', '\u{6422}',
+    '\u{63C5}', '\u{63A9}', '\u{3A2E}', '\u{6469}', '\u{647E}', '\u{649D}', '\u{6477}', '\u{3A6C}',
+    '\u{654F}', '\u{656C}', '\u{2300A}', '\u{65E3}', '\u{66F8}', '\u{6649}', '\u{3B19}', '\u{6691}',
+    '\u{3B08}', '\u{3AE4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669C}', '\u{80AD}', '\u{43D9}',
+    '\u{6717}', '\u{671B}', '\u{6721}', '\u{675E}', '\u{6753}', '\u{233C3}', '\u{3B49}', '\u{67FA}',
+    '\u{6785}', '\u{6852}', '\u{6885}', '\u{2346D}', '\u{688E}', '\u{681F}', '\u{6914}', '\u{3B9D}',
+    '\u{6942}', '\u{69A3}', '\u{69EA}', '\u{6AA8}', '\u{236A3}', '\u{6ADB}', '\u{3C18}', '\u{6B21}',
+    '\u{238A7}', '\u{6B54}', '\u{3C4E}', '\u{6B72}', '\u{6B9F}', '\u{6BBA}', '\u{6BBB}', '\u{23A8D}',
+    '\u{21D0B}', '\u{23AFA}', '\u{6C4E}', '\u{23CBC}', '\u{6CBF}', '\u{6CCD}', '\u{6C67}', '\u{6D16}',
+    '\u{6D3E}', '\u{6D77}', '\u{6D41}', '\u{6D69}', '\u{6D78}', '\u{6D85}', '\u{23D1E}', '\u{6D34}',
+    '\u{6E2F}', '\u{6E6E}', '\u{3D33}', '\u{6ECB}', '\u{6EC7}', '\u{23ED1}', '\u{6DF9}', '\u{6F6E}',
+    '\u{23F5E}', '\u{23F8E}', '\u{6FC6}', '\u{7039}', '\u{701E}', '\u{701B}', '\u{3D96}', '\u{704A}',
+    '\u{707D}', '\u{7077}', '\u{70AD}', '\u{20525}', '\u{7145}', '\u{24263}', '\u{719C}', '\u{243AB}',
+    '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}', '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}',
+    '\u{737A}', '\u{738B}', '\u{3EAC}', '\u{73A5}', '\u{3EB8}', '\u{3EB8}', '\u{7447}', '\u{745C}',
+    '\u{7471}', '\u{7485}', '\u{74CA}', '\u{3F1B}', '\u{7524}', '\u{24C36}', '\u{753E}', '\u{24C92}',
+    '\u{7570}', '\u{2219F}', '\u{7610}', '\u{24FA1}', '\u{24FB8}', '\u{25044}', '\u{3FFC}', '\u{4008}',
+    '\u{76F4}', '\u{250F3}', '\u{250F2}', '\u{25119}', '\u{25133}', '\u{771E}', '\u{771F}', '\u{771F}',
+    '\u{774A}', '\u{4039}', '\u{778B}', '\u{4046}', '\u{4096}', '\u{2541D}', '\u{784E}', '\u{788C}',
+    '\u{78CC}', '\u{40E3}', '\u{25626}', '\u{7956}', '\u{2569A}', '\u{256C5}', '\u{798F}', '\u{79EB}',
+    '\u{412F}', '\u{7A40}', '\u{7A4A}', '\u{7A4F}', '\u{2597C}', '\u{25AA7}', '\u{25AA7}', '\u{7AEE}',
+    '\u{4202}', '\u{25BAB}', '\u{7BC6}', '\u{7BC9}', '\u{4227}', '\u{25C80}', '\u{7CD2}', '\u{42A0}',
+    '\u{7CE8}', '\u{7CE3}', '\u{7D00}', '\u{25F86}', '\u{7D63}', '\u{4301}', '\u{7DC7}', '\u{7E02}',
+    '\u{7E45}', '\u{4334}', '\u{26228}', '\u{26247}', '\u{4359}', '\u{262D9}', '\u{7F7A}', '\u{2633E}',
+    '\u{7F95}', '\u{7FFA}', '\u{8005}', '\u{264DA}', '\u{26523}', '\u{8060}', '\u{265A8}', '\u{8070}',
+    '\u{2335F}', '\u{43D5}', '\u{80B2}', '\u{8103}', '\u{440B}', '\u{813E}', '\u{5AB5}', '\u{267A7}',
+    '\u{267B5}', '\u{23393}', '\u{2339C}', '\u{8201}', '\u{8204}', '\u{8F9E}', '\u{446B}', '\u{8291}',
+    '\u{828B}', '\u{829D}', '\u{52B3}', '\u{82B1}', '\u{82B3}', '\u{82BD}', '\u{82E6}', '\u{26B3C}',
+    '\u{82E5}', '\u{831D}', '\u{8363}', '\u{83AD}', '\u{8323}', '\u{83BD}', '\u{83E7}', '\u{8457}',
+    '\u{8353}', '\u{83CA}', '\u{83CC}', '\u{83DC}', '\u{26C36}', '\u{26D6B}', '\u{26CD5}', '\u{452B}',
+    '\u{84F1}', '\u{84F3}', '\u{8516}', '\u{273CA}', '\u{8564}', '\u{26F2C}', '\u{455D}', '\u{4561}',
+    '\u{26FB1}', '\u{270D2}', '\u{456B}', '\u{8650}', '\u{865C}', '\u{8667}', '\u{8669}', '\u{86A9}',
+    '\u{8688}', '\u{870E}', '\u{86E2}', '\u{8779}', '\u{8728}', '\u{876B}', '\u{8786}', '\u{45D7}',
+    '\u{87E1}', '\u{8801}', '\u{45F9}', '\u{8860}', '\u{8863}', '\u{27667}', '\u{88D7}', '\u{88DE}',
+    '\u{4635}', '\u{88FA}', '\u{34BB}', '\u{278AE}', '\u{27966}', '\u{46BE}', '\u{46C7}', '\u{8AA0}',
+    '\u{8AED}', '\u{8B8A}', '\u{8C55}', '\u{27CA8}', '\u{8CAB}', '\u{8CC1}', '\u{8D1B}', '\u{8D77}',
+    '\u{27F2F}', '\u{20804}', '\u{8DCB}', '\u{8DBC}', '\u{8DF0}', '\u{208DE}', '\u{8ED4}', '\u{8F38}',
+    '\u{285D2}', '\u{285ED}', '\u{9094}', '\u{90F1}', '\u{9111}', '\u{2872E}', '\u{911B}', '\u{9238}',
+    '\u{92D7}', '\u{92D8}', '\u{927C}', '\u{93F9}', '\u{9415}', '\u{28BFA}', '\u{958B}', '\u{4995}',
+    '\u{95B7}', '\u{28D77}', '\u{49E6}', '\u{96C3}', '\u{5DB2}', '\u{9723}', '\u{29145}', '\u{2921A}',
+    '\u{4A6E}', '\u{4A76}', '\u{97E0}', '\u{2940A}', '\u{4AB2}', '\u{29496}', '\u{980B}', '\u{980B}',
+    '\u{9829}', '\u{295B6}', '\u{98E2}', '\u{4B33}', '\u{9929}', '\u{99A7}', '\u{99C2}', '\u{99FE}',
+    '\u{4BCE}', '\u{29B30}', '\u{9B12}', '\u{9C40}', '\u{9CFD}', '\u{4CCE}', '\u{4CED}', '\u{9D67}',
+    '\u{2A0CE}', '\u{4CF8}', '\u{2A105}', '\u{2A20E}', '\u{2A291}', '\u{9EBB}', '\u{4D56}', '\u{9EF9}',
+    '\u{9EFE}', '\u{9F05}', '\u{9F0F}', '\u{9F16}', '\u{9F3B}', '\u{2A600}',
+];
+
+/// Full compatibility decompositions that differ from the canonical decomposition, as offsets and lengths into `COMPATIBLE_CHARS`
+pub(super) static COMPATIBLE: [(char, u16, u8); 3812] = [
+    ('\u{00A0}', 0, 1),
+    ('\u{00A8}', 1, 2),
+    ('\u{00AA}', 3, 1),
+    ('\u{00AF}', 4, 2),
+    ('\u{00B2}', 6, 1),
+    ('\u{00B3}', 7, 1),
+    ('\u{00B4}', 8, 2),
+    ('\u{00B5}', 10, 1),
+    ('\u{00B8}', 11, 2),
+    ('\u{00B9}', 13, 1),
+    ('\u{00BA}', 14, 1),
+    ('\u{00BC}', 15, 3),
+    ('\u{00BD}', 18, 3),
+    ('\u{00BE}', 21, 3),
+    ('\u{0132}', 24, 2),
+    ('\u{0133}', 26, 2),
+    ('\u{013F}', 28, 2),
+    ('\u{0140}', 30, 2),
+    ('\u{0149}', 32, 2),
+    ('\u{017F}', 34, 1),
+    ('\u{01C4}', 35, 3),
+    ('\u{01C5}', 38, 3),
+    ('\u{01C6}', 41, 3),
+    ('\u{01C7}', 44, 2),
+    ('\u{01C8}', 46, 2),
+    ('\u{01C9}', 48, 2),
+    ('\u{01CA}', 50, 2),
+    ('\u{01CB}', 52, 2),
+    ('\u{01CC}', 54, 2),
+    ('\u{01F1}', 56, 2),
+    ('\u{01F2}', 58, 2),
+    ('\u{01F3}', 60, 2),
+    ('\u{02B0}', 62, 1),
+    ('\u{02B1}', 63, 1),
+    ('\u{02B2}', 64, 1),
+    ('\u{02B3}', 65, 1),
+    ('\u{02B4}', 66, 1),
+    ('\u{02B5}', 67, 1),
+    ('\u{02B6}', 68, 1),
+    ('\u{02B7}', 69, 1),
+    ('\u{02B8}', 70, 1),
+    ('\u{02D8}', 71, 2),
+    ('\u{02D9}', 73, 2),
+    ('\u{02DA}', 75, 2),
+    ('\u{02DB}', 77, 2),
+    ('\u{02DC}', 79, 2),
+    ('\u{02DD}', 81, 2),
+    ('\u{02E0}', 83, 1),
+    ('\u{02E1}', 84, 1),
+    ('\u{02E2}', 85, 1),
+    ('\u{02E3}', 86, 1),
+    ('\u{02E4}', 87, 1),
+    ('\u{037A}', 88, 2),
+    ('\u{0384}', 90, 2),
+    ('\u{0385}', 92, 3),
+    ('\u{03D0}', 95, 1),
+    ('\u{03D1}', 96, 1),
+    ('\u{03D2}', 97, 1),
+    ('\u{03D3}', 98, 2),
+    ('\u{03D4}', 100, 2),
+    ('\u{03D5}', 102, 1),
+    ('\u{03D6}', 103, 1),
+    ('\u{03F0}', 104, 1),
+    ('\u{03F1}', 105, 1),
+    ('\u{03F2}', 106, 1),
+    ('\u{03F4}', 107, 1),
+    ('\u{03F5}', 108, 1),
+    ('\u{03F9}', 109, 1),
+    ('\u{0587}', 110, 2),
+    ('\u{0675}', 112, 2),
+    ('\u{0676}', 114, 2),
+    ('\u{0677}', 116, 2),
+    ('\u{0678}', 118, 2),
+    ('\u{0E33}', 120, 2),
+    ('\u{0EB3}', 122, 2),
+    ('\u{0EDC}', 124, 2),
+    ('\u{0EDD}', 126, 2),
+    ('\u{0F0C}', 128, 1),
+    ('\u{0F77}', 129, 3),
+    ('\u{0F79}', 132, 3),
+    ('\u{10FC}', 135, 1),
+    ('\u{1D2C}', 136, 1),
+    ('\u{1D2D}', 137, 1),
+    ('\u{1D2E}', 138, 1),
+    ('\u{1D30}', 139, 1),
+    ('\u{1D31}', 140, 1),
+    ('\u{1D32}', 141, 1),
+    ('\u{1D33}', 142, 1),
+    ('\u{1D34}', 143, 1),
+    ('\u{1D35}', 144, 1),
+    ('\u{1D36}', 145, 1),
+    ('\u{1D37}', 146, 1),
+    ('\u{1D38}', 147, 1),
+    ('\u{1D39}', 148, 1),
+    ('\u{1D3A}', 149, 1),
+    ('\u{1D3C}', 150, 1),
+    ('\u{1D3D}', 151, 1),
+    ('\u{1D3E}', 152, 1),
+    ('\u{1D3F}', 153, 1),
+    ('\u{1D40}', 154, 1),
+    ('\u{1D41}', 155, 1),
+    ('\u{1D42}', 156, 1),
+    ('\u{1D43}', 157, 1),
+    ('\u{1D44}', 158, 1),
+    ('\u{1D45}', 159, 1),
+    ('\u{1D46}', 160, 1),
+    ('\u{1D47}', 161, 1),
+    ('\u{1D48}', 162, 1),
+    ('\u{1D49}', 163, 1),
+    ('\u{1D4A}', 164, 1),
+    ('\u{1D4B}', 165, 1),
+    ('\u{1D4C}', 166, 1),
+    ('\u{1D4D}', 167, 1),
+    ('\u{1D4F}', 168, 1),
+    ('\u{1D50}', 169, 1),
+    ('\u{1D51}', 170, 1),
+    ('\u{1D52}', 171, 1),
+    ('\u{1D53}', 172, 1),
+    ('\u{1D54}', 173, 1),
+    ('\u{1D55}', 174, 1),
+    ('\u{1D56}', 175, 1),
+    ('\u{1D57}', 176, 1),
+    ('\u{1D58}', 177, 1),
+    ('\u{1D59}', 178, 1),
+    ('\u{1D5A}', 179, 1),
+    ('\u{1D5B}', 180, 1),
+    ('\u{1D5C}', 181, 1),
+    ('\u{1D5D}', 182, 1),
+    ('\u{1D5E}', 183, 1),
+    ('\u{1D5F}', 184, 1),
+    ('\u{1D60}', 185, 1),
+    ('\u{1D61}', 186, 1),
+    ('\u{1D62}', 187, 1),
+    ('\u{1D63}', 188, 1),
+    ('\u{1D64}', 189, 1),
+    ('\u{1D65}', 190, 1),
+    ('\u{1D66}', 191, 1),
+    ('\u{1D67}', 192, 1),
+    ('\u{1D68}', 193, 1),
+    ('\u{1D69}', 194, 1),
+    ('\u{1D6A}', 195, 1),
+    ('\u{1D78}', 196, 1),
+    ('\u{1D9B}', 197, 1),
+    ('\u{1D9C}', 198, 1),
+    ('\u{1D9D}', 199, 1),
+    ('\u{1D9E}', 200, 1),
+    ('\u{1D9F}', 201, 1),
+    ('\u{1DA0}', 202, 1),
+    ('\u{1DA1}', 203, 1),
+    ('\u{1DA2}', 204, 1),
+    ('\u{1DA3}', 205, 1),
+    ('\u{1DA4}', 206, 1),
+    ('\u{1DA5}', 207, 1),
+    ('\u{1DA6}', 208, 1),
+    ('\u{1DA7}', 209, 1),
+    ('\u{1DA8}', 210, 1),
+    ('\u{1DA9}', 211, 1),
+    ('\u{1DAA}', 212, 1),
+    ('\u{1DAB}', 213, 1),
+    ('\u{1DAC}', 214, 1),
+    ('\u{1DAD}', 215, 1),
+    ('\u{1DAE}', 216, 1),
+    ('\u{1DAF}', 217, 1),
+    ('\u{1DB0}', 218, 1),
+    ('\u{1DB1}', 219, 1),
+    ('\u{1DB2}', 220, 1),
+    ('\u{1DB3}', 221, 1),
+    ('\u{1DB4}', 222, 1),
+    ('\u{1DB5}', 223, 1),
+    ('\u{1DB6}', 224, 1),
+    ('\u{1DB7}', 225, 1),
+    ('\u{1DB8}', 226, 1),
+    ('\u{1DB9}', 227, 1),
+    ('\u{1DBA}', 228, 1),
+    ('\u{1DBB}', 229, 1),
+    ('\u{1DBC}', 230, 1),
+    ('\u{1DBD}', 231, 1),
+    ('\u{1DBE}', 232, 1),
+    ('\u{1DBF}', 233, 1),
+    ('\u{1E9A}', 234, 2),
+    ('\u{1E9B}', 236, 2),
+    ('\u{1FBD}', 238, 2),
+    ('\u{1FBF}', 240, 2),
+    ('\u{1FC0}', 242, 2),
+    ('\u{1FC1}', 244, 3),
+    ('\u{1FCD}', 247, 3),
+    ('\u{1FCE}', 250, 3),
+    ('\u{1FCF}', 253, 3),
+    ('\u{1FDD}', 256, 3),
+    ('\u{1FDE}', 259, 3),
+    ('\u{1FDF}', 262, 3),
+    ('\u{1FED}', 265, 3),
+    ('\u{1FEE}', 268, 3),
+    ('\u{1FFD}', 271, 2),
+    ('\u{1FFE}', 273, 2),
+    ('\u{2000}', 275, 1),
+    ('\u{2001}', 276, 1),
+    ('\u{2002}', 277, 1),
+    ('\u{2003}', 278, 1),
+    ('\u{2004}', 279, 1),
+    ('\u{2005}', 280, 1),
+    ('\u{2006}', 281, 1),
+    ('\u{2007}', 282, 1),
+    ('\u{2008}', 283, 1),
+    ('\u{2009}', 284, 1),
+    ('\u{200A}', 285, 1),
+    ('\u{2011}', 286, 1),
+    ('\u{2017}', 287, 2),
+    ('\u{2024}', 289, 1),
+    ('\u{2025}', 290, 2),
+    ('\u{2026}', 292, 3),
+    ('\u{202F}', 295, 1),
+    ('\u{2033}', 296, 2),
+    ('\u{2034}', 298, 3),
+    ('\u{2036}', 301, 2),
+    ('\u{2037}', 303, 3),
+    ('\u{203C}', 306, 2),
+    ('\u{203E}', 308, 2),
+    ('\u{2047}', 310, 2),
+    ('\u{2048}', 312, 2),
+    ('\u{2049}', 314, 2),
+    ('\u{2057}', 316, 4),
+    ('\u{205F}', 320, 1),
+    ('\u{2070}', 321, 1),
+    ('\u{2071}', 322, 1),
+    ('\u{2074}', 323, 1),
+    ('\u{2075}', 324, 1),
+    ('\u{2076}', 325, 1),
+    ('\u{2077}', 326, 1),
+    ('\u{2078}', 327, 1),
+    ('\u{2079}', 328, 1),
+    ('\u{207A}', 329, 1),
+    ('\u{207B}', 330, 1),
+    ('\u{207C}', 331, 1),
+    ('\u{207D}', 332, 1),
+    ('\u{207E}', 333, 1),
+    ('\u{207F}', 334, 1),
+    ('\u{2080}', 335, 1),
+    ('\u{2081}', 336, 1),
+    ('\u{2082}', 337, 1),
+    ('\u{2083}', 338, 1),
+    ('\u{2084}', 339, 1),
+    ('\u{2085}', 340, 1),
+    ('\u{2086}', 341, 1),
+    ('\u{2087}', 342, 1),
+    ('\u{2088}', 343, 1),
+    ('\u{2089}', 344, 1),
+    ('\u{208A}', 345, 1),
+    ('\u{208B}', 346, 1),
+    ('\u{208C}', 347, 1),
+    ('\u{208D}', 348, 1),
+    ('\u{208E}', 349, 1),
+    ('\u{2090}', 350, 1),
+    ('\u{2091}', 351, 1),
+    ('\u{2092}', 352, 1),
+    ('\u{2093}', 353, 1),
+    ('\u{2094}', 354, 1),
+    ('\u{2095}', 355, 1),
+    ('\u{2096}', 356, 1),
+    ('\u{2097}', 357, 1),
+    ('\u{2098}', 358, 1),
+    ('\u{2099}', 359, 1),
+    ('\u{209A}', 360, 1),
+    ('\u{209B}', 361, 1),
+    ('\u{209C}', 362, 1),
+    ('\u{20A8}', 363, 2),
+    ('\u{2100}', 365, 3),
+    ('\u{2101}', 368, 3),
+    ('\u{2102}', 371, 1),
+    ('\u{2103}', 372, 2),
+    ('\u{2105}', 374, 3),
+    ('\u{2106}', 377, 3),
+    ('\u{2107}', 380, 1),
+    ('\u{2109}', 381, 2),
+    ('\u{210A}', 383, 1),
+    ('\u{210B}', 384, 1),
+    ('\u{210C}', 385, 1),
+    ('\u{210D}', 386, 1),
+    ('\u{210E}', 387, 1),
+    ('\u{210F}', 388, 1),
+    ('\u{2110}', 389, 1),
+    ('\u{2111}', 390, 1),
+    ('\u{2112}', 391, 1),
+    ('\u{2113}', 392, 1),
+    ('\u{2115}', 393, 1),
+    ('\u{2116}', 394, 2),
+    ('\u{2119}', 396, 1),
+    ('\u{211A}', 397, 1),
+    ('\u{211B}', 398, 1),
+    ('\u{211C}', 399, 1),
+    ('\u{211D}', 400, 1),
+    ('\u{2120}', 401, 2),
+    ('\u{2121}', 403, 3),
+    ('\u{2122}', 406, 2),
+    ('\u{2124}', 408, 1),
+    ('\u{2128}', 409, 1),
+    ('\u{212C}', 410, 1),
+    ('\u{212D}', 411, 1),
+    ('\u{212F}', 412, 1),
+    ('\u{2130}', 413, 1),
+    ('\u{2131}', 414, 1),
+    ('\u{2133}', 415, 1),
+    ('\u{2134}', 416, 1),
+    ('\u{2135}', 417, 1),
+    ('\u{2136}', 418, 1),
+    ('\u{2137}', 419, 1),
+    ('\u{2138}', 420, 1),
+    ('\u{2139}', 421, 1),
+    ('\u{213B}', 422, 3),
+    ('\u{213C}', 425, 1),
+    ('\u{213D}', 426, 1),
+    ('\u{213E}', 427, 1),
+    ('\u{213F}', 428, 1),
+    ('\u{2140}', 429, 1),
+    ('\u{2145}', 430, 1),
+    ('\u{2146}', 431, 1),
+    ('\u{2147}', 432, 1),
+    ('\u{2148}', 433, 1),
+    ('\u{2149}', 434, 1),
+    ('\u{2150}', 435, 3),
+    ('\u{2151}', 438, 3),
+    ('\u{2152}', 441, 4),
+    ('\u{2153}', 445, 3),
+    ('\u{2154}', 448, 3),
+    ('\u{2155}', 451, 3),
+    ('\u{2156}', 454, 3),
+    ('\u{2157}', 457, 3),
+    ('\u{2158}', 460, 3),
+    ('\u{2159}', 463, 3),
+    ('\u{215A}', 466, 3),
+    ('\u{215B}', 469, 3),
+    ('\u{215C}', 472, 3),
+    ('\u{215D}', 475, 3),
+    ('\u{215E}', 478, 3),
+    ('\u{215F}', 481, 2),
+    ('\u{2160}', 483, 1),
+    ('\u{2161}', 484, 2),
+    ('\u{2162}', 486, 3),
+    ('\u{2163}', 489, 2),
+    ('\u{2164}', 491, 1),
+    ('\u{2165}', 492, 2),
+    ('\u{2166}', 494, 3),
+    ('\u{2167}', 497, 4),
+    ('\u{2168}', 501, 2),
+    ('\u{2169}', 503, 1),
+    ('\u{216A}', 504, 2),
+    ('\u{216B}', 506, 3),
+    ('\u{216C}', 509, 1),
+    ('\u{216D}', 510, 1),
+    ('\u{216E}', 511, 1),
+    ('\u{216F}', 512, 1),
+    ('\u{2170}', 513, 1),
+    ('\u{2171}', 514, 2),
+    ('\u{2172}', 516, 3),
+    ('\u{2173}', 519, 2),
+    ('\u{2174}', 521, 1),
+    ('\u{2175}', 522, 2),
+    ('\u{2176}', 524, 3),
+    ('\u{2177}', 527, 4),
+    ('\u{2178}', 531, 2),
+    ('\u{2179}', 533, 1),
+    ('\u{217A}', 534, 2),
+    ('\u{217B}', 536, 3),
+    ('\u{217C}', 539, 1),
+    ('\u{217D}', 540, 1),
+    ('\u{217E}', 541, 1),
+    ('\u{217F}', 542, 1),
+    ('\u{2189}', 543, 3),
+    ('\u{222C}', 546, 2),
+    ('\u{222D}', 548, 3),
+    ('\u{222F}', 551, 2),
+    ('\u{2230}', 553, 3),
+    ('\u{2460}', 556, 1),
+    ('\u{2461}', 557, 1),
+    ('\u{2462}', 558, 1),
+    ('\u{2463}', 559, 1),
+    ('\u{2464}', 560, 1),
+    ('\u{2465}', 561, 1),
+    ('\u{2466}', 562, 1),
+    ('\u{2467}', 563, 1),
+    ('\u{2468}', 564, 1),
+    ('\u{2469}', 565, 2),
+    ('\u{246A}', 567, 2),
+    ('\u{246B}', 569, 2),
+    ('\u{246C}', 571, 2),
+    ('\u{246D}', 573, 2),
+    ('\u{246E}', 575, 2),
+    ('\u{246F}', 577, 2),
+    ('\u{2470}', 579, 2),
+    ('\u{2471}', 581, 2),
+    ('\u{2472}', 583, 2),
+    ('\u{2473}', 585, 2),
+    ('\u{2474}', 587, 3),
+    ('\u{2475}', 590, 3),
+    ('\u{2476}', 593, 3),
+    ('\u{2477}', 596, 3),
+    ('\u{2478}', 599, 3),
+    ('\u{2479}', 602, 3),
+    ('\u{247A}', 605, 3),
+    ('\u{247B}', 608, 3),
+    ('\u{247C}', 611, 3),
+    ('\u{247D}', 614, 4),
+    ('\u{247E}', 618, 4),
+    ('\u{247F}', 622, 4),
+    ('\u{2480}', 626, 4),
+    ('\u{2481}', 630, 4),
+    ('\u{2482}', 634, 4),
+    ('\u{2483}', 638, 4),
+    ('\u{2484}', 642, 4),
+    ('\u{2485}', 646, 4),
+    ('\u{2486}', 650, 4),
+    ('\u{2487}', 654, 4),
+    ('\u{2488}', 658, 2),
+    ('\u{2489}', 660, 2),
+    ('\u{248A}', 662, 2),
+    ('\u{248B}', 664, 2),
+    ('\u{248C}', 666, 2),
+    ('\u{248D}', 668, 2),
+    ('\u{248E}', 670, 2),
+    ('\u{248F}', 672, 2),
+    ('\u{2490}', 674, 2),
+    ('\u{2491}', 676, 3),
+    ('\u{2492}', 679, 3),
+    ('\u{2493}', 682, 3),
+    ('\u{2494}', 685, 3),
+    ('\u{2495}', 688, 3),
+    ('\u{2496}', 691, 3),
+    ('\u{2497}', 694, 3),
+    ('\u{2498}', 697, 3),
+    ('\u{2499}', 700, 3),
+    ('\u{249A}', 703, 3),
+    ('\u{249B}', 706, 3),
+    ('\u{249C}', 709, 3),
+    ('\u{249D}', 712, 3),
+    ('\u{249E}', 715, 3),
+    ('\u{249F}', 718, 3),
+    ('\u{24A0}', 721, 3),
+    ('\u{24A1}', 724, 3),
+    ('\u{24A2}', 727, 3),
+    ('\u{24A3}', 730, 3),
+    ('\u{24A4}', 733, 3),
+    ('\u{24A5}', 736, 3),
+    ('\u{24A6}', 739, 3),
+    ('\u{24A7}', 742, 3),
+    ('\u{24A8}', 745, 3),
+    ('\u{24A9}', 748, 3),
+    ('\u{24AA}', 751, 3),
+    ('\u{24AB}', 754, 3),
+    ('\u{24AC}', 757, 3),
+    ('\u{24AD}', 760, 3),
+    ('\u{24AE}', 763, 3),
+    ('\u{24AF}', 766, 3),
+    ('\u{24B0}', 769, 3),
+    ('\u{24B1}', 772, 3),
+    ('\u{24B2}', 775, 3),
+    ('\u{24B3}', 778, 3),
+    ('\u{24B4}', 781, 3),
+    ('\u{24B5}', 784, 3),
+    ('\u{24B6}', 787, 1),
+    ('\u{24B7}', 788, 1),
+    ('\u{24B8}', 789, 1),
+    ('\u{24B9}', 790, 1),
+    ('\u{24BA}', 791, 1),
+    ('\u{24BB}', 792, 1),
+    ('\u{24BC}', 793, 1),
+    ('\u{24BD}', 794, 1),
+    ('\u{24BE}', 795, 1),
+    ('\u{24BF}', 796, 1),
+    ('\u{24C0}', 797, 1),
+    ('\u{24C1}', 798, 1),
+    ('\u{24C2}', 799, 1),
+    ('\u{24C3}', 800, 1),
+    ('\u{24C4}', 801, 1),
+    ('\u{24C5}', 802, 1),
+    ('\u{24C6}', 803, 1),
+    ('\u{24C7}', 804, 1),
+    ('\u{24C8}', 805, 1),
+    ('\u{24C9}', 806, 1),
+    ('\u{24CA}', 807, 1),
+    ('\u{24CB}', 808, 1),
+    ('\u{24CC}', 809, 1),
+    ('\u{24CD}', 810, 1),
+    ('\u{24CE}', 811, 1),
+    ('\u{24CF}', 812, 1),
+    ('\u{24D0}', 813, 1),
+    ('\u{24D1}', 814, 1),
+    ('\u{24D2}', 815, 1),
+    ('\u{24D3}', 816, 1),
+    ('\u{24D4}', 817, 1),
+    ('\u{24D5}', 818, 1),
+    ('\u{24D6}', 819, 1),
+    ('\u{24D7}', 820, 1),
+    ('\u{24D8}', 821, 1),
+    ('\u{24D9}', 822, 1),
+    ('\u{24DA}', 823, 1),
+    ('\u{24DB}', 824, 1),
+    ('\u{24DC}', 825, 1),
+    ('\u{24DD}', 826, 1),
+    ('\u{24DE}', 827, 1),
+    ('\u{24DF}', 828, 1),
+    ('\u{24E0}', 829, 1),
+    ('\u{24E1}', 830, 1),
+    ('\u{24E2}', 831, 1),
+    ('\u{24E3}', 832, 1),
+    ('\u{24E4}', 833, 1),
+    ('\u{24E5}', 834, 1),
+    ('\u{24E6}', 835, 1),
+    ('\u{24E7}', 836, 1),
+    ('\u{24E8}', 837, 1),
+    ('\u{24E9}', 838, 1),
+    ('\u{24EA}', 839, 1),
+    ('\u{2A0C}', 840, 4),
+    ('\u{2A74}', 844, 3),
+    ('\u{2A75}', 847, 2),
+    ('\u{2A76}', 849, 3),
+    ('\u{2C7C}', 852, 1),
+    ('\u{2C7D}', 853, 1),
+    ('\u{2D6F}', 854, 1),
+    ('\u{2E9F}', 855, 1),
+    ('\u{2EF3}', 856, 1),
+    ('\u{2F00}', 857, 1),
+    ('\u{2F01}', 858, 1),
+    ('\u{2F02}', 859, 1),
+    ('\u{2F03}', 860, 1),
+    ('\u{2F04}', 861, 1),
+    ('\u{2F05}', 862, 1),
+    ('\u{2F06}', 863, 1),
+    ('\u{2F07}', 864, 1),
+    ('\u{2F08}', 865, 1),
+    ('\u{2F09}', 866, 1),
+    ('\u{2F0A}', 867, 1),
+    ('\u{2F0B}', 868, 1),
+    ('\u{2F0C}', 869, 1),
+    ('\u{2F0D}', 870, 1),
+    ('\u{2F0E}', 871, 1),
+    ('\u{2F0F}', 872, 1),
+    ('\u{2F10}', 873, 1),
+    ('\u{2F11}', 874, 1),
+    ('\u{2F12}', 875, 1),
+    ('\u{2F13}', 876, 1),
+    ('\u{2F14}', 877, 1),
+    ('\u{2F15}', 878, 1),
+    ('\u{2F16}', 879, 1),
+    ('\u{2F17}', 880, 1),
+    ('\u{2F18}', 881, 1),
+    ('\u{2F19}', 882, 1),
+    ('\u{2F1A}', 883, 1),
+    ('\u{2F1B}', 884, 1),
+    ('\u{2F1C}', 885, 1),
+    ('\u{2F1D}', 886, 1),
+    ('\u{2F1E}', 887, 1),
+    ('\u{2F1F}', 888, 1),
+    ('\u{2F20}', 889, 1),
+    ('\u{2F21}', 890, 1),
+    ('\u{2F22}', 891, 1),
+    ('\u{2F23}', 892, 1),
+    ('\u{2F24}', 893, 1),
+    ('\u{2F25}', 894, 1),
+    ('\u{2F26}', 895, 1),
+    ('\u{2F27}', 896, 1),
+    ('\u{2F28}', 897, 1),
+    ('\u{2F29}', 898, 1),
+    ('\u{2F2A}', 899, 1),
+    ('\u{2F2B}', 900, 1),
+    ('\u{2F2C}', 901, 1),
+    ('\u{2F2D}', 902, 1),
+    ('\u{2F2E}', 903, 1),
+    ('\u{2F2F}', 904, 1),
+    ('\u{2F30}', 905, 1),
+    ('\u{2F31}', 906, 1),
+    ('\u{2F32}', 907, 1),
+    ('\u{2F33}', 908, 1),
+    ('\u{2F34}', 909, 1),
+    ('\u{2F35}', 910, 1),
+    ('\u{2F36}', 911, 1),
+    ('\u{2F37}', 912, 1),
+    ('\u{2F38}', 913, 1),
+    ('\u{2F39}', 914, 1),
+    ('\u{2F3A}', 915, 1),
+    ('\u{2F3B}', 916, 1),
+    ('\u{2F3C}', 917, 1),
+    ('\u{2F3D}', 918, 1),
+    ('\u{2F3E}', 919, 1),
+    ('\u{2F3F}', 920, 1),
+    ('\u{2F40}', 921, 1),
+    ('\u{2F41}', 922, 1),
+    ('\u{2F42}', 923, 1),
+    ('\u{2F43}', 924, 1),
+    ('\u{2F44}', 925, 1),
+    ('\u{2F45}', 926, 1),
+    ('\u{2F46}', 927, 1),
+    ('\u{2F47}', 928, 1),
+    ('\u{2F48}', 929, 1),
+    ('\u{2F49}', 930, 1),
+    ('\u{2F4A}', 931, 1),
+    ('\u{2F4B}', 932, 1),
+    ('\u{2F4C}', 933, 1),
+    ('\u{2F4D}', 934, 1),
+    ('\u{2F4E}', 935, 1),
+    ('\u{2F4F}', 936, 1),
+    ('\u{2F50}', 937, 1),
+    ('\u{2F51}', 938, 1),
+    ('\u{2F52}', 939, 1),
+    ('\u{2F53}', 940, 1),
+    ('\u{2F54}', 941, 1),
+    ('\u{2F55}', 942, 1),
+    ('\u{2F56}', 943, 1),
+    ('\u{2F57}', 944, 1),
+    ('\u{2F58}', 945, 1),
+    ('\u{2F59}', 946, 1),
+    ('\u{2F5A}', 947, 1),
+    ('\u{2F5B}', 948, 1),
+    ('\u{2F5C}', 949, 1),
+    ('\u{2F5D}', 950, 1),
+    ('\u{2F5E}', 951, 1),
+    ('\u{2F5F}', 952, 1),
+    ('\u{2F60}', 953, 1),
+    ('\u{2F61}', 954, 1),
+    ('\u{2F62}', 955, 1),
+    ('\u{2F63}', 956, 1),
+    ('\u{2F64}', 957, 1),
+    ('\u{2F65}', 958, 1),
+    ('\u{2F66}', 959, 1),
+    ('\u{2F67}', 960, 1),
+    ('\u{2F68}', 961, 1),
+    ('\u{2F69}', 962, 1),
+    ('\u{2F6A}', 963, 1),
+    ('\u{2F6B}', 964, 1),
+    ('\u{2F6C}', 965, 1),
+    ('\u{2F6D}', 966, 1),
+    ('\u{2F6E}', 967, 1),
+    ('\u{2F6F}', 968, 1),
+    ('\u{2F70}', 969, 1),
+    ('\u{2F71}', 970, 1),
+    ('\u{2F72}', 971, 1),
+    ('\u{2F73}', 972, 1),
+    ('\u{2F74}', 973, 1),
+    ('\u{2F75}', 974, 1),
+    ('\u{2F76}', 975, 1),
+    ('\u{2F77}', 976, 1),
+    ('\u{2F78}', 977, 1),
+    ('\u{2F79}', 978, 1),
+    ('\u{2F7A}', 979, 1),
+    ('\u{2F7B}', 980, 1),
+    ('\u{2F7C}', 981, 1),
+    ('\u{2F7D}', 982, 1),
+    ('\u{2F7E}', 983, 1),
+    ('\u{2F7F}', 984, 1),
+    ('\u{2F80}', 985, 1),
+    ('\u{2F81}', 986, 1),
+    ('\u{2F82}', 987, 1),
+    ('\u{2F83}', 988, 1),
+    ('\u{2F84}', 989, 1),
+    ('\u{2F85}', 990, 1),
+    ('\u{2F86}', 991, 1),
+    ('\u{2F87}', 992, 1),
+    ('\u{2F88}', 993, 1),
+    ('\u{2F89}', 994, 1),
+    ('\u{2F8A}', 995, 1),
+    ('\u{2F8B}', 996, 1),
+    ('\u{2F8C}', 997, 1),
+    ('\u{2F8D}', 998, 1),
+    ('\u{2F8E}', 999, 1),
+    ('\u{2F8F}', 1000, 1),
+    ('\u{2F90}', 1001, 1),
+    ('\u{2F91}', 1002, 1),
+    ('\u{2F92}', 1003, 1),
+    ('\u{2F93}', 1004, 1),
+    ('\u{2F94}', 1005, 1),
+    ('\u{2F95}', 1006, 1),
+    ('\u{2F96}', 1007, 1),
+    ('\u{2F97}', 1008, 1),
+    ('\u{2F98}', 1009, 1),
+    ('\u{2F99}', 1010, 1),
+    ('\u{2F9A}', 1011, 1),
+    ('\u{2F9B}', 1012, 1),
+    ('\u{2F9C}', 1013, 1),
+    ('\u{2F9D}', 1014, 1),
+    ('\u{2F9E}', 1015, 1),
+    ('\u{2F9F}', 1016, 1),
+    ('\u{2FA0}', 1017, 1),
+    ('\u{2FA1}', 1018, 1),
+    ('\u{2FA2}', 1019, 1),
+    ('\u{2FA3}', 1020, 1),
+    ('\u{2FA4}', 1021, 1),
+    ('\u{2FA5}', 1022, 1),
+    ('\u{2FA6}', 1023, 1),
+    ('\u{2FA7}', 1024, 1),
+    ('\u{2FA8}', 1025, 1),
+    ('\u{2FA9}', 1026, 1),
+    ('\u{2FAA}', 1027, 1),
+    ('\u{2FAB}', 1028, 1),
+    ('\u{2FAC}', 1029, 1),
+    ('\u{2FAD}', 1030, 1),
+    ('\u{2FAE}', 1031, 1),
+    ('\u{2FAF}', 1032, 1),
+    ('\u{2FB0}', 1033, 1),
+    ('\u{2FB1}', 1034, 1),
+    ('\u{2FB2}', 1035, 1),
+    ('\u{2FB3}', 1036, 1),
+    ('\u{2FB4}', 1037, 1),
+    ('\u{2FB5}', 1038, 1),
+    ('\u{2FB6}', 1039, 1),
+    ('\u{2FB7}', 1040, 1),
+    ('\u{2FB8}', 1041, 1),
+    ('\u{2FB9}', 1042, 1),
+    ('\u{2FBA}', 1043, 1),
+    ('\u{2FBB}', 1044, 1),
+    ('\u{2FBC}', 1045, 1),
+    ('\u{2FBD}', 1046, 1),
+    ('\u{2FBE}', 1047, 1),
+    ('\u{2FBF}', 1048, 1),
+    ('\u{2FC0}', 1049, 1),
+    ('\u{2FC1}', 1050, 1),
+    ('\u{2FC2}', 1051, 1),
+    ('\u{2FC3}', 1052, 1),
+    ('\u{2FC4}', 1053, 1),
+    ('\u{2FC5}', 1054, 1),
+    ('\u{2FC6}', 1055, 1),
+    ('\u{2FC7}', 1056, 1),
+    ('\u{2FC8}', 1057, 1),
+    ('\u{2FC9}', 1058, 1),
+    ('\u{2FCA}', 1059, 1),
+    ('\u{2FCB}', 1060, 1),
+    ('\u{2FCC}', 1061, 1),
+    ('\u{2FCD}', 1062, 1),
+    ('\u{2FCE}', 1063, 1),
+    ('\u{2FCF}', 1064, 1),
+    ('\u{2FD0}', 1065, 1),
+    ('\u{2FD1}', 1066, 1),
+    ('\u{2FD2}', 1067, 1),
+    ('\u{2FD3}', 1068, 1),
+    ('\u{2FD4}', 1069, 1),
+    ('\u{2FD5}', 1070, 1),
+    ('\u{3000}', 1071, 1),
+    ('\u{3036}', 1072, 1),
+    ('\u{3038}', 1073, 1),
+    ('\u{3039}', 1074, 1),
+    ('\u{303A}', 1075, 1),
+    ('\u{309B}', 1076, 2),
+    ('\u{309C}', 1078, 2),
+    ('\u{309F}', 1080, 2),
+    ('\u{30FF}', 1082, 2),
+    ('\u{3131}', 1084, 1),
+    ('\u{3132}', 1085, 1),
+    ('\u{3133}', 1086, 1),
+    ('\u{3134}', 1087, 1),
+    ('\u{3135}', 1088, 1),
+    ('\u{3136}', 1089, 1),
+    ('\u{3137}', 1090, 1),
+    ('\u{3138}', 1091, 1),
+    ('\u{3139}', 1092, 1),
+    ('\u{313A}', 1093, 1),
+    ('\u{313B}', 1094, 1),
+    ('\u{313C}', 1095, 1),
+    ('\u{313D}', 1096, 1),
+    ('\u{313E}', 1097, 1),
+    ('\u{313F}', 1098, 1),
+    ('\u{3140}', 1099, 1),
+    ('\u{3141}', 1100, 1),
+    ('\u{3142}', 1101, 1),
+    ('\u{3143}', 1102, 1),
+    ('\u{3144}', 1103, 1),
+    ('\u{3145}', 1104, 1),
+    ('\u{3146}', 1105, 1),
+    ('\u{3147}', 1106, 1),
+    ('\u{3148}', 1107, 1),
+    ('\u{3149}', 1108, 1),
+    ('\u{314A}', 1109, 1),
+    ('\u{314B}', 1110, 1),
+    ('\u{314C}', 1111, 1),
+    ('\u{314D}', 1112, 1),
+    ('\u{314E}', 1113, 1),
+    ('\u{314F}', 1114, 1),
+    ('\u{3150}', 1115, 1),
+    ('\u{3151}', 1116, 1),
+    ('\u{3152}', 1117, 1),
+    ('\u{3153}', 1118, 1),
+    ('\u{3154}', 1119, 1),
+    ('\u{3155}', 1120, 1),
+    ('\u{3156}', 1121, 1),
+    ('\u{3157}', 1122, 1),
+    ('\u{3158}', 1123, 1),
+    ('\u{3159}', 1124, 1),
+    ('\u{315A}', 1125, 1),
+    ('\u{315B}', 1126, 1),
+    ('\u{315C}', 1127, 1),
+    ('\u{315D}', 1128, 1),
+    ('\u{315E}', 1129, 1),
+    ('\u{315F}', 1130, 1),
+    ('\u{3160}', 1131, 1),
+    ('\u{3161}', 1132, 1),
+    ('\u{3162}', 1133, 1),
+    ('\u{3163}', 1134, 1),
+    ('\u{3164}', 1135, 1),
+    ('\u{3165}', 1136, 1),
+    ('\u{3166}', 1137, 1),
+    ('\u{3167}', 1138, 1),
+    ('\u{3168}', 1139, 1),
+    ('\u{3169}', 1140, 1),
+    ('\u{316A}', 1141, 1),
+    ('\u{316B}', 1142, 1),
+    ('\u{316C}', 1143, 1),
+    ('\u{316D}', 1144, 1),
+    ('\u{316E}', 1145, 1),
+    ('\u{316F}', 1146, 1),
+    ('\u{3170}', 1147, 1),
+    ('\u{3171}', 1148, 1),
+    ('\u{3172}', 1149, 1),
+    ('\u{3173}', 1150, 1),
+    ('\u{3174}', 1151, 1),
+    ('\u{3175}', 1152, 1),
+    ('\u{3176}', 1153, 1),
+    ('\u{3177}', 1154, 1),
+    ('\u{3178}', 1155, 1),
+    ('\u{3179}', 1156, 1),
+    ('\u{317A}', 1157, 1),
+    ('\u{317B}', 1158, 1),
+    ('\u{317C}', 1159, 1),
+    ('\u{317D}', 1160, 1),
+    ('\u{317E}', 1161, 1),
+    ('\u{317F}', 1162, 1),
+    ('\u{3180}', 1163, 1),
+    ('\u{3181}', 1164, 1),
+    ('\u{3182}', 1165, 1),
+    ('\u{3183}', 1166, 1),
+    ('\u{3184}', 1167, 1),
+    ('\u{3185}', 1168, 1),
+    ('\u{3186}', 1169, 1),
+    ('\u{3187}', 1170, 1),
+    ('\u{3188}', 1171, 1),
+    ('\u{3189}', 1172, 1),
+    ('\u{318A}', 1173, 1),
+    ('\u{318B}', 1174, 1),
+    ('\u{318C}', 1175, 1),
+    ('\u{318D}', 1176, 1),
+    ('\u{318E}', 1177, 1),
+    ('\u{3192}', 1178, 1),
+    ('\u{3193}', 1179, 1),
+    ('\u{3194}', 1180, 1),
+    ('\u{3195}', 1181, 1),
+    ('\u{3196}', 1182, 1),
+    ('\u{3197}', 1183, 1),
+    ('\u{3198}', 1184, 1),
+    ('\u{3199}', 1185, 1),
+    ('\u{319A}', 1186, 1),
+    ('\u{319B}', 1187, 1),
+    ('\u{319C}', 1188, 1),
+    ('\u{319D}', 1189, 1),
+    ('\u{319E}', 1190, 1),
+    ('\u{319F}', 1191, 1),
+    ('\u{3200}', 1192, 3),
+    ('\u{3201}', 1195, 3),
+    ('\u{3202}', 1198, 3),
+    ('\u{3203}', 1201, 3),
+    ('\u{3204}', 1204, 3),
+    ('\u{3205}', 1207, 3),
+    ('\u{3206}', 1210, 3),
+    ('\u{3207}', 1213, 3),
+    ('\u{3208}', 1216, 3),
+    ('\u{3209}', 1219, 3),
+    ('\u{320A}', 1222, 3),
+    ('\u{320B}', 1225, 3),
+    ('\u{320C}', 1228, 3),
+    ('\u{320D}', 1231, 3),
+    ('\u{320E}', 1234, 4),
+    ('\u{320F}', 1238, 4),
+    ('\u{3210}', 1242, 4),
+    ('\u{3211}', 1246, 4),
+    ('\u{3212}', 1250, 4),
+    ('\u{3213}', 1254, 4),
+    ('\u{3214}', 1258, 4),
+    ('\u{3215}', 1262, 4),
+    ('\u{3216}', 1266, 4),
+    ('\u{3217}', 1270, 4),
+    ('\u{3218}', 1274, 4),
+    ('\u{3219}', 1278, 4),
+    ('\u{321A}', 1282, 4),
+    ('\u{321B}', 1286, 4),
+    ('\u{321C}', 1290, 4),
+    ('\u{321D}', 1294, 7),
+    ('\u{321E}', 1301, 6),
+    ('\u{3220}', 1307, 3),
+    ('\u{3221}', 1310, 3),
+    ('\u{3222}', 1313, 3),
+    ('\u{3223}', 1316, 3),
+    ('\u{3224}', 1319, 3),
+    ('\u{3225}', 1322, 3),
+    ('\u{3226}', 1325, 3),
+    ('\u{3227}', 1328, 3),
+    ('\u{3228}', 1331, 3),
+    ('\u{3229}', 1334, 3),
+    ('\u{322A}', 1337, 3),
+    ('\u{322B}', 1340, 3),
+    ('\u{322C}', 1343, 3),
+    ('\u{322D}', 1346, 3),
+    ('\u{322E}', 1349, 3),
+    ('\u{322F}', 1352, 3),
+    ('\u{3230}', 1355, 3),
+    ('\u{3231}', 1358, 3),
+    ('\u{3232}', 1361, 3),
+    ('\u{3233}', 1364, 3),
+    ('\u{3234}', 1367, 3),
+    ('\u{3235}', 1370, 3),
+    ('\u{3236}', 1373, 3),
+    ('\u{3237}', 1376, 3),
+    ('\u{3238}', 1379, 3),
+    ('\u{3239}', 1382, 3),
+    ('\u{323A}', 1385, 3),
+    ('\u{323B}', 1388, 3),
+    ('\u{323C}', 1391, 3),
+    ('\u{323D}', 1394, 3),
+    ('\u{323E}', 1397, 3),
+    ('\u{323F}', 1400, 3),
+    ('\u{3240}', 1403, 3),
+    ('\u{3241}', 1406, 3),
+    ('\u{3242}', 1409, 3),
+    ('\u{3243}', 1412, 3),
+    ('\u{3244}', 1415, 1),
+    ('\u{3245}', 1416, 1),
+    ('\u{3246}', 1417, 1),
+    ('\u{3247}', 1418, 1),
+    ('\u{3250}', 1419, 3),
+    ('\u{3251}', 1422, 2),
+    ('\u{3252}', 1424, 2),
+    ('\u{3253}', 1426, 2),
+    ('\u{3254}', 1428, 2),
+    ('\u{3255}', 1430, 2),
+    ('\u{3256}', 1432, 2),
+    ('\u{3257}', 1434, 2),
+    ('\u{3258}', 1436, 2),
+    ('\u{3259}', 1438, 2),
+    ('\u{325A}', 1440, 2),
+    ('\u{325B}', 1442, 2),
+    ('\u{325C}', 1444, 2),
+    ('\u{325D}', 1446, 2),
+    ('\u{325E}', 1448, 2),
+    ('\u{325F}', 1450, 2),
+    ('\u{3260}', 1452, 1),
+    ('\u{3261}', 1453, 1),
+    ('\u{3262}', 1454, 1),
+    ('\u{3263}', 1455, 1),
+    ('\u{3264}', 1456, 1),
+    ('\u{3265}', 1457, 1),
+    ('\u{3266}', 1458, 1),
+    ('\u{3267}', 1459, 1),
+    ('\u{3268}', 1460, 1),
+    ('\u{3269}', 1461, 1),
+    ('\u{326A}', 1462, 1),
+    ('\u{326B}', 1463, 1),
+    ('\u{326C}', 1464, 1),
+    ('\u{326D}', 1465, 1),
+    ('\u{326E}', 1466, 2),
+    ('\u{326F}', 1468, 2),
+    ('\u{3270}', 1470, 2),
+    ('\u{3271}', 1472, 2),
+    ('\u{3272}', 1474, 2),
+    ('\u{3273}', 1476, 2),
+    ('\u{3274}', 1478, 2),
+    ('\u{3275}', 1480, 2),
+    ('\u{3276}', 1482, 2),
+    ('\u{3277}', 1484, 2),
+    ('\u{3278}', 1486, 2),
+    ('\u{3279}', 1488, 2),
+    ('\u{327A}', 1490, 2),
+    ('\u{327B}', 1492, 2),
+    ('\u{327C}', 1494, 5),
+    ('\u{327D}', 1499, 4),
+    ('\u{327E}', 1503, 2),
+    ('\u{3280}', 1505, 1),
+    ('\u{3281}', 1506, 1),
+    ('\u{3282}', 1507, 1),
+    ('\u{3283}', 1508, 1),
+    ('\u{3284}', 1509, 1),
+    ('\u{3285}', 1510, 1),
+    ('\u{3286}', 1511, 1),
+    ('\u{3287}', 1512, 1),
+    ('\u{3288}', 1513, 1),
+    ('\u{3289}', 1514, 1),
+    ('\u{328A}', 1515, 1),
+    ('\u{328B}', 1516, 1),
+    ('\u{328C}', 1517, 1),
+    ('\u{328D}', 1518, 1),
+    ('\u{328E}', 1519, 1),
+    ('\u{328F}', 1520, 1),
+    ('\u{3290}', 1521, 1),
+    ('\u{3291}', 1522, 1),
+    ('\u{3292}', 1523, 1),
+    ('\u{3293}', 1524, 1),
+    ('\u{3294}', 1525, 1),
+    ('\u{3295}', 1526, 1),
+    ('\u{3296}', 1527, 1),
+    ('\u{3297}', 1528, 1),
+    ('\u{3298}', 1529, 1),
+    ('\u{3299}', 1530, 1),
+    ('\u{329A}', 1531, 1),
+    ('\u{329B}', 1532, 1),
+    ('\u{329C}', 1533, 1),
+    ('\u{329D}', 1534, 1),
+    ('\u{329E}', 1535, 1),
+    ('\u{329F}', 1536, 1),
+    ('\u{32A0}', 1537, 1),
+    ('\u{32A1}', 1538, 1),
+    ('\u{32A2}', 1539, 1),
+    ('\u{32A3}', 1540, 1),
+    ('\u{32A4}', 1541, 1),
+    ('\u{32A5}', 1542, 1),
+    ('\u{32A6}', 1543, 1),
+    ('\u{32A7}', 1544, 1),
+    ('\u{32A8}', 1545, 1),
+    ('\u{32A9}', 1546, 1),
+    ('\u{32AA}', 1547, 1),
+    ('\u{32AB}', 1548, 1),
+    ('\u{32AC}', 1549, 1),
+    ('\u{32AD}', 1550, 1),
+    ('\u{32AE}', 1551, 1),
+    ('\u{32AF}', 1552, 1),
+    ('\u{32B0}', 1553, 1),
+    ('\u{32B1}', 1554, 2),
+    ('\u{32B2}', 1556, 2),
+    ('\u{32B3}', 1558, 2),
+    ('\u{32B4}', 1560, 2),
+    ('\u{32B5}', 1562, 2),
+    ('\u{32B6}', 1564, 2),
+    ('\u{32B7}', 1566, 2),
+    ('\u{32B8}', 1568, 2),
+    ('\u{32B9}', 1570, 2),
+    ('\u{32BA}', 1572, 2),
+    ('\u{32BB}', 1574, 2),
+    ('\u{32BC}', 1576, 2),
+    ('\u{32BD}', 1578, 2),
+    ('\u{32BE}', 1580, 2),
+    ('\u{32BF}', 1582, 2),
+    ('\u{32C0}', 1584, 2),
+    ('\u{32C1}', 1586, 2),
+    ('\u{32C2}', 1588, 2),
+    ('\u{32C3}', 1590, 2),
+    ('\u{32C4}', 1592, 2),
+    ('\u{32C5}', 1594, 2),
+    ('\u{32C6}', 1596, 2),
+    ('\u{32C7}', 1598, 2),
+    ('\u{32C8}', 1600, 2),
+    ('\u{32C9}', 1602, 3),
+    ('\u{32CA}', 1605, 3),
+    ('\u{32CB}', 1608, 3),
+    ('\u{32CC}', 1611, 2),
+    ('\u{32CD}', 1613, 3),
+    ('\u{32CE}', 1616, 2),
+    ('\u{32CF}', 1618, 3),
+    ('\u{32D0}', 1621, 1),
+    ('\u{32D1}', 1622, 1),
+    ('\u{32D2}', 1623, 1),
+    ('\u{32D3}', 1624, 1),
+    ('\u{32D4}', 1625, 1),
+    ('\u{32D5}', 1626, 1),
+    ('\u{32D6}', 1627, 1),
+    ('\u{32D7}', 1628, 1),
+    ('\u{32D8}', 1629, 1),
+    ('\u{32D9}', 1630, 1),
+    ('\u{32DA}', 1631, 1),
+    ('\u{32DB}', 1632, 1),
+    ('\u{32DC}', 1633, 1),
+    ('\u{32DD}', 1634, 1),
+    ('\u{32DE}', 1635, 1),
+    ('\u{32DF}', 1636, 1),
+    ('\u{32E0}', 1637, 1),
+    ('\u{32E1}', 1638, 1),
+    ('\u{32E2}', 1639, 1),
+    ('\u{32E3}', 1640, 1),
+    ('\u{32E4}', 1641, 1),
+    ('\u{32E5}', 1642, 1),
+    ('\u{32E6}', 1643, 1),
+    ('\u{32E7}', 1644, 1),
+    ('\u{32E8}', 1645, 1),
+    ('\u{32E9}', 1646, 1),
+    ('\u{32EA}', 1647, 1),
+    ('\u{32EB}', 1648, 1),
+    ('\u{32EC}', 1649, 1),
+    ('\u{32ED}', 1650, 1),
+    ('\u{32EE}', 1651, 1),
+    ('\u{32EF}', 1652, 1),
+    ('\u{32F0}', 1653, 1),
+    ('\u{32F1}', 1654, 1),
+    ('\u{32F2}', 1655, 1),
+    ('\u{32F3}', 1656, 1),
+    ('\u{32F4}', 1657, 1),
+    ('\u{32F5}', 1658, 1),
+    ('\u{32F6}', 1659, 1),
+    ('\u{32F7}', 1660, 1),
+    ('\u{32F8}', 1661, 1),
+    ('\u{32F9}', 1662, 1),
+    ('\u{32FA}', 1663, 1),
+    ('\u{32FB}', 1664, 1),
+    ('\u{32FC}', 1665, 1),
+    ('\u{32FD}', 1666, 1),
+    ('\u{32FE}', 1667, 1),
+    ('\u{32FF}', 1668, 2),
+    ('\u{3300}', 1670, 5),
+    ('\u{3301}', 1675, 4),
+    ('\u{3302}', 1679, 5),
+    ('\u{3303}', 1684, 3),
+    ('\u{3304}', 1687, 5),
+    ('\u{3305}', 1692, 3),
+    ('\u{3306}', 1695, 3),
+    ('\u{3307}', 1698, 6),
+    ('\u{3308}', 1704, 4),
+    ('\u{3309}', 1708, 3),
+    ('\u{330A}', 1711, 3),
+    ('\u{330B}', 1714, 3),
+    ('\u{330C}', 1717, 4),
+    ('\u{330D}', 1721, 4),
+    ('\u{330E}', 1725, 4),
+    ('\u{330F}', 1729, 4),
+    ('\u{3310}', 1733, 4),
+    ('\u{3311}', 1737, 4),
+    ('\u{3312}', 1741, 4),
+    ('\u{3313}', 1745, 6),
+    ('\u{3314}', 1751, 2),
+    ('\u{3315}', 1753, 6),
+    ('\u{3316}', 1759, 6),
+    ('\u{3317}', 1765, 5),
+    ('\u{3318}', 1770, 4),
+    ('\u{3319}', 1774, 6),
+    ('\u{331A}', 1780, 6),
+    ('\u{331B}', 1786, 4),
+    ('\u{331C}', 1790, 3),
+    ('\u{331D}', 1793, 3),
+    ('\u{331E}', 1796, 4),
+    ('\u{331F}', 1800, 4),
+    ('\u{3320}', 1804, 5),
+    ('\u{3321}', 1809, 5),
+    ('\u{3322}', 1814, 3),
+    ('\u{3323}', 1817, 3),
+    ('\u{3324}', 1820, 4),
+    ('\u{3325}', 1824, 3),
+    ('\u{3326}', 1827, 3),
+    ('\u{3327}', 1830, 2),
+    ('\u{3328}', 1832, 2),
+    ('\u{3329}', 1834, 3),
+    ('\u{332A}', 1837, 3),
+    ('\u{332B}', 1840, 6),
+    ('\u{332C}', 1846, 4),
+    ('\u{332D}', 1850, 5),
+    ('\u{332E}', 1855, 6),
+    ('\u{332F}', 1861, 4),
+    ('\u{3330}', 1865, 3),
+    ('\u{3331}', 1868, 3),
+    ('\u{3332}', 1871, 6),
+    ('\u{3333}', 1877, 4),
+    ('\u{3334}', 1881, 6),
+    ('\u{3335}', 1887, 3),
+    ('\u{3336}', 1890, 5),
+    ('\u{3337}', 1895, 3),
+    ('\u{3338}', 1898, 4),
+    ('\u{3339}', 1902, 3),
+    ('\u{333A}', 1905, 4),
+    ('\u{333B}', 1909, 5),
+    ('\u{333C}', 1914, 4),
+    ('\u{333D}', 1918, 5),
+    ('\u{333E}', 1923, 4),
+    ('\u{333F}', 1927, 2),
+    ('\u{3340}', 1929, 5),
+    ('\u{3341}', 1934, 3),
+    ('\u{3342}', 1937, 3),
+    ('\u{3343}', 1940, 4),
+    ('\u{3344}', 1944, 3),
+    ('\u{3345}', 1947, 3),
+    ('\u{3346}', 1950, 3),
+    ('\u{3347}', 1953, 5),
+    ('\u{3348}', 1958, 4),
+    ('\u{3349}', 1962, 2),
+    ('\u{334A}', 1964, 6),
+    ('\u{334B}', 1970, 3),
+    ('\u{334C}', 1973, 5),
+    ('\u{334D}', 1978, 4),
+    ('\u{334E}', 1982, 4),
+    ('\u{334F}', 1986, 3),
+    ('\u{3350}', 1989, 3),
+    ('\u{3351}', 1992, 4),
+    ('\u{3352}', 1996, 2),
+    ('\u{3353}', 1998, 4),
+    ('\u{3354}', 2002, 5),
+    ('\u{3355}', 2007, 2),
+    ('\u{3356}', 2009, 6),
+    ('\u{3357}', 2015, 3),
+    ('\u{3358}', 2018, 2),
+    ('\u{3359}', 2020, 2),
+    ('\u{335A}', 2022, 2),
+    ('\u{335B}', 2024, 2),
+    ('\u{335C}', 2026, 2),
+    ('\u{335D}', 2028, 2),
+    ('\u{335E}', 2030, 2),
+    ('\u{335F}', 2032, 2),
+    ('\u{3360}', 2034, 2),
+    ('\u{3361}', 2036, 2),
+    ('\u{3362}', 2038, 3),
+    ('\u{3363}', 2041, 3),
+    ('\u{3364}', 2044, 3),
+    ('\u{3365}', 2047, 3),
+    ('\u{3366}', 2050, 3),
+    ('\u{3367}', 2053, 3),
+    ('\u{3368}', 2056, 3),
+    ('\u{3369}', 2059, 3),
+    ('\u{336A}', 2062, 3),
+    ('\u{336B}', 2065, 3),
+    ('\u{336C}', 2068, 3),
+    ('\u{336D}', 2071, 3),
+    ('\u{336E}', 2074, 3),
+    ('\u{336F}', 2077, 3),
+    ('\u{3370}', 2080, 3),
+    ('\u{3371}', 2083, 3),
+    ('\u{3372}', 2086, 2),
+    ('\u{3373}', 2088, 2),
+    ('\u{3374}', 2090, 3),
+    ('\u{3375}', 2093, 2),
+    ('\u{3376}', 2095, 2),
+    ('\u{3377}', 2097, 2),
+    ('\u{3378}', 2099, 3),
+    ('\u{3379}', 2102, 3),
+    ('\u{337A}', 2105, 2),
+    ('\u{337B}', 2107, 2),
+    ('\u{337C}', 2109, 2),
+    ('\u{337D}', 2111, 2),
+    ('\u{337E}', 2113, 2),
+    ('\u{337F}', 2115, 4),
+    ('\u{3380}', 2119, 2),
+    ('\u{3381}', 2121, 2),
+    ('\u{3382}', 2123, 2),
+    ('\u{3383}', 2125, 2),
+    ('\u{3384}', 2127, 2),
+    ('\u{3385}', 2129, 2),
+    ('\u{3386}', 2131, 2),
+    ('\u{3387}', 2133, 2),
+    ('\u{3388}', 2135, 3),
+    ('\u{3389}', 2138, 4),
+    ('\u{338A}', 2142, 2),
+    ('\u{338B}', 2144, 2),
+    ('\u{338C}', 2146, 2),
+    ('\u{338D}', 2148, 2),
+    ('\u{338E}', 2150, 2),
+    ('\u{338F}', 2152, 2),
+    ('\u{3390}', 2154, 2),
+    ('\u{3391}', 2156, 3),
+    ('\u{3392}', 2159, 3),
+    ('\u{3393}', 2162, 3),
+    ('\u{3394}', 2165, 3),
+    ('\u{3395}', 2168, 2),
+    ('\u{3396}', 2170, 2),
+    ('\u{3397}', 2172, 2),
+    ('\u{3398}', 2174, 2),
+    ('\u{3399}', 2176, 2),
+    ('\u{339A}', 2178, 2),
+    ('\u{339B}', 2180, 2),
+    ('\u{339C}', 2182, 2),
+    ('\u{339D}', 2184, 2),
+    ('\u{339E}', 2186, 2),
+    ('\u{339F}', 2188, 3),
+    ('\u{33A0}', 2191, 3),
+    ('\u{33A1}', 2194, 2),
+    ('\u{33A2}', 2196, 3),
+    ('\u{33A3}', 2199, 3),
+    ('\u{33A4}', 2202, 3),
+    ('\u{33A5}', 2205, 2),
+    ('\u{33A6}', 2207, 3),
+    ('\u{33A7}', 2210, 3),
+    ('\u{33A8}', 2213, 4),
+    ('\u{33A9}', 2217, 2),
+    ('\u{33AA}', 2219, 3),
+    ('\u{33AB}', 2222, 3),
+    ('\u{33AC}', 2225, 3),
+    ('\u{33AD}', 2228, 3),
+    ('\u{33AE}', 2231, 5),
+    ('\u{33AF}', 2236, 6),
+    ('\u{33B0}', 2242, 2),
+    ('\u{33B1}', 2244, 2),
+    ('\u{33B2}', 2246, 2),
+    ('\u{33B3}', 2248, 2),
+    ('\u{33B4}', 2250, 2),
+    ('\u{33B5}', 2252, 2),
+    ('\u{33B6}', 2254, 2),
+    ('\u{33B7}', 2256, 2),
+    ('\u{33B8}', 2258, 2),
+    ('\u{33B9}', 2260, 2),
+    ('\u{33BA}', 2262, 2),
+    ('\u{33BB}', 2264, 2),
+    ('\u{33BC}', 2266, 2),
+    ('\u{33BD}', 2268, 2),
+    ('\u{33BE}', 2270, 2),
+    ('\u{33BF}', 2272, 2),
+    ('\u{33C0}', 2274, 2),
+    ('\u{33C1}', 2276, 2),
+    ('\u{33C2}', 2278, 4),
+    ('\u{33C3}', 2282, 2),
+    ('\u{33C4}', 2284, 2),
+    ('\u{33C5}', 2286, 2),
+    ('\u{33C6}', 2288, 4),
+    ('\u{33C7}', 2292, 3),
+    ('\u{33C8}', 2295, 2),
+    ('\u{33C9}', 2297, 2),
+    ('\u{33CA}', 2299, 2),
+    ('\u{33CB}', 2301, 2),
+    ('\u{33CC}', 2303, 2),
+    ('\u{33CD}', 2305, 2),
+    ('\u{33CE}', 2307, 2),
+    ('\u{33CF}', 2309, 2),
+    ('\u{33D0}', 2311, 2),
+    ('\u{33D1}', 2313, 2),
+    ('\u{33D2}', 2315, 3),
+    ('\u{33D3}', 2318, 2),
+    ('\u{33D4}', 2320, 2),
+    ('\u{33D5}', 2322, 3),
+    ('\u{33D6}', 2325, 3),
+    ('\u{33D7}', 2328, 2),
+    ('\u{33D8}', 2330, 4),
+    ('\u{33D9}', 2334, 3),
+    ('\u{33DA}', 2337, 2),
+    ('\u{33DB}', 2339, 2),
+    ('\u{33DC}', 2341, 2),
+    ('\u{33DD}', 2343, 2),
+    ('\u{33DE}', 2345, 3),
+    ('\u{33DF}', 2348, 3),
+    ('\u{33E0}', 2351, 2),
+    ('\u{33E1}', 2353, 2),
+    ('\u{33E2}', 2355, 2),
+    ('\u{33E3}', 2357, 2),
+    ('\u{33E4}', 2359, 2),
+    ('\u{33E5}', 2361, 2),
+    ('\u{33E6}', 2363, 2),
+    ('\u{33E7}', 2365, 2),
+    ('\u{33E8}', 2367, 2),
+    ('\u{33E9}', 2369, 3),
+    ('\u{33EA}', 2372, 3),
+    ('\u{33EB}', 2375, 3),
+    ('\u{33EC}', 2378, 3),
+    ('\u{33ED}', 2381, 3),
+    ('\u{33EE}', 2384, 3),
+    ('\u{33EF}', 2387, 3),
+    ('\u{33F0}', 2390, 3),
+    ('\u{33F1}', 2393, 3),
+    ('\u{33F2}', 2396, 3),
+    ('\u{33F3}', 2399, 3),
+    ('\u{33F4}', 2402, 3),
+    ('\u{33F5}', 2405, 3),
+    ('\u{33F6}', 2408, 3),
+    ('\u{33F7}', 2411, 3),
+    ('\u{33F8}', 2414, 3),
+    ('\u{33F9}', 2417, 3),
+    ('\u{33FA}', 2420, 3),
+    ('\u{33FB}', 2423, 3),
+    ('\u{33FC}', 2426, 3),
+    ('\u{33FD}', 2429, 3),
+    ('\u{33FE}', 2432, 3),
+    ('\u{33FF}', 2435, 3),
+    ('\u{A69C}', 2438, 1),
+    ('\u{A69D}', 2439, 1),
+    ('\u{A770}', 2440, 1),
+    ('\u{A7F2}', 2441, 1),
+    ('\u{A7F3}', 2442, 1),
+    ('\u{A7F4}', 2443, 1),
+    ('\u{A7F8}', 2444, 1),
+    ('\u{A7F9}', 2445, 1),
+    ('\u{AB5C}', 2446, 1),
+    ('\u{AB5D}', 2447, 1),
+    ('\u{AB5E}', 2448, 1),
+    ('\u{AB5F}', 2449, 1),
+    ('\u{AB69}', 2450, 1),
+    ('\u{FB00}', 2451, 2),
+    ('\u{FB01}', 2453, 2),
+    ('\u{FB02}', 2455, 2),
+    ('\u{FB03}', 2457, 3),
+    ('\u{FB04}', 2460, 3),
+    ('\u{FB05}', 2463, 2),
+    ('\u{FB06}', 2465, 2),
+    ('\u{FB13}', 2467, 2),
+    ('\u{FB14}', 2469, 2),
+    ('\u{FB15}', 2471, 2),
+    ('\u{FB16}', 2473, 2),
+    ('\u{FB17}', 2475, 2),
+    ('\u{FB20}', 2477, 1),
+    ('\u{FB21}', 2478, 1),
+    ('\u{FB22}', 2479, 1),
+    ('\u{FB23}', 2480, 1),
+    ('\u{FB24}', 2481, 1),
+    ('\u{FB25}', 2482, 1),
+    ('\u{FB26}', 2483, 1),
+    ('\u{FB27}', 2484, 1),
+    ('\u{FB28}', 2485, 1),
+    ('\u{FB29}', 2486, 1),
+    ('\u{FB4F}', 2487, 2),
+    ('\u{FB50}', 2489, 1),
+    ('\u{FB51}', 2490, 1),
+    ('\u{FB52}', 2491, 1),
+    ('\u{FB53}', 2492, 1),
+    ('\u{FB54}', 2493, 1),
+    ('\u{FB55}', 2494, 1),
+    ('\u{FB56}', 2495, 1),
+    ('\u{FB57}', 2496, 1),
+    ('\u{FB58}', 2497, 1),
+    ('\u{FB59}', 2498, 1),
+    ('\u{FB5A}', 2499, 1),
+    ('\u{FB5B}', 2500, 1),
+    ('\u{FB5C}', 2501, 1),
+    ('\u{FB5D}', 2502, 1),
+    ('\u{FB5E}', 2503, 1),
+    ('\u{FB5F}', 2504, 1),
+    ('\u{FB60}', 2505, 1),
+    ('\u{FB61}', 2506, 1),
+    ('\u{FB62}', 2507, 1),
+    ('\u{FB63}', 2508, 1),
+    ('\u{FB64}', 2509, 1),
+    ('\u{FB65}', 2510, 1),
+    ('\u{FB66}', 2511, 1),
+    ('\u{FB67}', 2512, 1),
+    ('\u{FB68}', 2513, 1),
+    ('\u{FB69}', 2514, 1),
+    ('\u{FB6A}', 2515, 1),
+    ('\u{FB6B}', 2516, 1),
+    ('\u{FB6C}', 2517, 1),
+    ('\u{FB6D}', 2518, 1),
+    ('\u{FB6E}', 2519, 1),
+    ('\u{FB6F}', 2520, 1),
+    ('\u{FB70}', 2521, 1),
+    ('\u{FB71}', 2522, 1),
+    ('\u{FB72}', 2523, 1),
+    ('\u{FB73}', 2524, 1),
+    ('\u{FB74}', 2525, 1),
+    ('\u{FB75}', 2526, 1),
+    ('\u{FB76}', 2527, 1),
+    ('\u{FB77}', 2528, 1),
+    ('\u{FB78}', 2529, 1),
+    ('\u{FB79}', 2530, 1),
+    ('\u{FB7A}', 2531, 1),
+    ('\u{FB7B}', 2532, 1),
+    ('\u{FB7C}', 2533, 1),
+    ('\u{FB7D}', 2534, 1),
+    ('\u{FB7E}', 2535, 1),
+    ('\u{FB7F}', 2536, 1),
+    ('\u{FB80}', 2537, 1),
+    ('\u{FB81}', 2538, 1),
+    ('\u{FB82}', 2539, 1),
+    ('\u{FB83}', 2540, 1),
+    ('\u{FB84}', 2541, 1),
+    ('\u{FB85}', 2542, 1),
+    ('\u{FB86}', 2543, 1),
+    ('\u{FB87}', 2544, 1),
+    ('\u{FB88}', 2545, 1),
+    ('\u{FB89}', 2546, 1),
+    ('\u{FB8A}', 2547, 1),
+    ('\u{FB8B}', 2548, 1),
+    ('\u{FB8C}', 2549, 1),
+    ('\u{FB8D}', 2550, 1),
+    ('\u{FB8E}', 2551, 1),
+    ('\u{FB8F}', 2552, 1),
+    ('\u{FB90}', 2553, 1),
+    ('\u{FB91}', 2554, 1),
+    ('\u{FB92}', 2555, 1),
+    ('\u{FB93}', 2556, 1),
+    ('\u{FB94}', 2557, 1),
+    ('\u{FB95}', 2558, 1),
+    ('\u{FB96}', 2559, 1),
+    ('\u{FB97}', 2560, 1),
+    ('\u{FB98}', 2561, 1),
+    ('\u{FB99}', 2562, 1),
+    ('\u{FB9A}', 2563, 1),
+    ('\u{FB9B}', 2564, 1),
+    ('\u{FB9C}', 2565, 1),
+    ('\u{FB9D}', 2566, 1),
+    ('\u{FB9E}', 2567, 1),
+    ('\u{FB9F}', 2568, 1),
+    ('\u{FBA0}', 2569, 1),
+    ('\u{FBA1}', 2570, 1),
+    ('\u{FBA2}', 2571, 1),
+    ('\u{FBA3}', 2572, 1),
+    ('\u{FBA4}', 2573, 2),
+    ('\u{FBA5}', 2575, 2),
+    ('\u{FBA6}', 2577, 1),
+    ('\u{FBA7}', 2578, 1),
+    ('\u{FBA8}', 2579, 1),
+    ('\u{FBA9}', 2580, 1),
+    ('\u{FBAA}', 2581, 1),
+    ('\u{FBAB}', 2582, 1),
+    ('\u{FBAC}', 2583, 1),
+    ('\u{FBAD}', 2584, 1),
+    ('\u{FBAE}', 2585, 1),
+    ('\u{FBAF}', 2586, 1),
+    ('\u{FBB0}', 2587, 2),
+    ('\u{FBB1}', 2589, 2),
+    ('\u{FBD3}', 2591, 1),
+    ('\u{FBD4}', 2592, 1),
+    ('\u{FBD5}', 2593, 1),
+    ('\u{FBD6}', 2594, 1),
+    ('\u{FBD7}', 2595, 1),
+    ('\u{FBD8}', 2596, 1),
+    ('\u{FBD9}', 2597, 1),
+    ('\u{FBDA}', 2598, 1),
+    ('\u{FBDB}', 2599, 1),
+    ('\u{FBDC}', 2600, 1),
+    ('\u{FBDD}', 2601, 2),
+    ('\u{FBDE}', 2603, 1),
+    ('\u{FBDF}', 2604, 1),
+    ('\u{FBE0}', 2605, 1),
+    ('\u{FBE1}', 2606, 1),
+    ('\u{FBE2}', 2607, 1),
+    ('\u{FBE3}', 2608, 1),
+    ('\u{FBE4}', 2609, 1),
+    ('\u{FBE5}', 2610, 1),
+    ('\u{FBE6}', 2611, 1),
+    ('\u{FBE7}', 2612, 1),
+    ('\u{FBE8}', 2613, 1),
+    ('\u{FBE9}', 2614, 1),
+    ('\u{FBEA}', 2615, 3),
+    ('\u{FBEB}', 2618, 3),
+    ('\u{FBEC}', 2621, 3),
+    ('\u{FBED}', 2624, 3),
+    ('\u{FBEE}', 2627, 3),
+    ('\u{FBEF}', 2630, 3),
+    ('\u{FBF0}', 2633, 3),
+    ('\u{FBF1}', 2636, 3),
+    ('\u{FBF2}', 2639, 3),
+    ('\u{FBF3}', 2642, 3),
+    ('\u{FBF4}', 2645, 3),
+    ('\u{FBF5}', 2648, 3),
+    ('\u{FBF6}', 2651, 3),
+    ('\u{FBF7}', 2654, 3),
+    ('\u{FBF8}', 2657, 3),
+    ('\u{FBF9}', 2660, 3),
+    ('\u{FBFA}', 2663, 3),
+    ('\u{FBFB}', 2666, 3),
+    ('\u{FBFC}', 2669, 1),
+    ('\u{FBFD}', 2670, 1),
+    ('\u{FBFE}', 2671, 1),
+    ('\u{FBFF}', 2672, 1),
+    ('\u{FC00}', 2673, 3),
+    ('\u{FC01}', 2676, 3),
+    ('\u{FC02}', 2679, 3),
+    ('\u{FC03}', 2682, 3),
+    ('\u{FC04}', 2685, 3),
+    ('\u{FC05}', 2688, 2),
+    ('\u{FC06}', 2690, 2),
+    ('\u{FC07}', 2692, 2),
+    ('\u{FC08}', 2694, 2),
+    ('\u{FC09}', 2696, 2),
+    ('\u{FC0A}', 2698, 2),
+    ('\u{FC0B}', 2700, 2),
+    ('\u{FC0C}', 2702, 2),
+    ('\u{FC0D}', 2704, 2),
+    ('\u{FC0E}', 2706, 2),
+    ('\u{FC0F}', 2708, 2),
+    ('\u{FC10}', 2710, 2),
+    ('\u{FC11}', 2712, 2),
+    ('\u{FC12}', 2714, 2),
+    ('\u{FC13}', 2716, 2),
+    ('\u{FC14}', 2718, 2),
+    ('\u{FC15}', 2720, 2),
+    ('\u{FC16}', 2722, 2),
+    ('\u{FC17}', 2724, 2),
+    ('\u{FC18}', 2726, 2),
+    ('\u{FC19}', 2728, 2),
+    ('\u{FC1A}', 2730, 2),
+    ('\u{FC1B}', 2732, 2),
+    ('\u{FC1C}', 2734, 2),
+    ('\u{FC1D}', 2736, 2),
+    ('\u{FC1E}', 2738, 2),
+    ('\u{FC1F}', 2740, 2),
+    ('\u{FC20}', 2742, 2),
+    ('\u{FC21}', 2744, 2),
+    ('\u{FC22}', 2746, 2),
+    ('\u{FC23}', 2748, 2),
+    ('\u{FC24}', 2750, 2),
+    ('\u{FC25}', 2752, 2),
+    ('\u{FC26}', 2754, 2),
+    ('\u{FC27}', 2756, 2),
+    ('\u{FC28}', 2758, 2),
+    ('\u{FC29}', 2760, 2),
+    ('\u{FC2A}', 2762, 2),
+    ('\u{FC2B}', 2764, 2),
+    ('\u{FC2C}', 2766, 2),
+    ('\u{FC2D}', 2768, 2),
+    ('\u{FC2E}', 2770, 2),
+    ('\u{FC2F}', 2772, 2),
+    ('\u{FC30}', 2774, 2),
+    ('\u{FC31}', 2776, 2),
+    ('\u{FC32}', 2778, 2),
+    ('\u{FC33}', 2780, 2),
+    ('\u{FC34}', 2782, 2),
+    ('\u{FC35}', 2784, 2),
+    ('\u{FC36}', 2786, 2),
+    ('\u{FC37}', 2788, 2),
+    ('\u{FC38}', 2790, 2),
+    ('\u{FC39}', 2792, 2),
+    ('\u{FC3A}', 2794, 2),
+    ('\u{FC3B}', 2796, 2),
+    ('\u{FC3C}', 2798, 2),
+    ('\u{FC3D}', 2800, 2),
+    ('\u{FC3E}', 2802, 2),
+    ('\u{FC3F}', 2804, 2),
+    ('\u{FC40}', 2806, 2),
+    ('\u{FC41}', 2808, 2),
+    ('\u{FC42}', 2810, 2),
+    ('\u{FC43}', 2812, 2),
+    ('\u{FC44}', 2814, 2),
+    ('\u{FC45}', 2816, 2),
+    ('\u{FC46}', 2818, 2),
+    ('\u{FC47}', 2820, 2),
+    ('\u{FC48}', 2822, 2),
+    ('\u{FC49}', 2824, 2),
+    ('\u{FC4A}', 2826, 2),
+    ('\u{FC4B}', 2828, 2),
+    ('\u{FC4C}', 2830, 2),
+    ('\u{FC4D}', 2832, 2),
+    ('\u{FC4E}', 2834, 2),
+    ('\u{FC4F}', 2836, 2),
+    ('\u{FC50}', 2838, 2),
+    ('\u{FC51}', 2840, 2),
+    ('\u{FC52}', 2842, 2),
+    ('\u{FC53}', 2844, 2),
+    ('\u{FC54}', 2846, 2),
+    ('\u{FC55}', 2848, 2),
+    ('\u{FC56}', 2850, 2),
+    ('\u{FC57}', 2852, 2),
+    ('\u{FC58}', 2854, 2),
+    ('\u{FC59}', 2856, 2),
+    ('\u{FC5A}', 2858, 2),
+    ('\u{FC5B}', 2860, 2),
+    ('\u{FC5C}', 2862, 2),
+    ('\u{FC5D}', 2864, 2),
+    ('\u{FC5E}', 2866, 3),
+    ('\u{FC5F}', 2869, 3),
+    ('\u{FC60}', 2872, 3),
+    ('\u{FC61}', 2875, 3),
+    ('\u{FC62}', 2878, 3),
+    ('\u{FC63}', 2881, 3),
+    ('\u{FC64}', 2884, 3),
+    ('\u{FC65}', 2887, 3),
+    ('\u{FC66}', 2890, 3),
+    ('\u{FC67}', 2893, 3),
+    ('\u{FC68}', 2896, 3),
+    ('\u{FC69}', 2899, 3),
+    ('\u{FC6A}', 2902, 2),
+    ('\u{FC6B}', 2904, 2),
+    ('\u{FC6C}', 2906, 2),
+    ('\u{FC6D}', 2908, 2),
+    ('\u{FC6E}', 2910, 2),
+    ('\u{FC6F}', 2912, 2),
+    ('\u{FC70}', 2914, 2),
+    ('\u{FC71}', 2916, 2),
+    ('\u{FC72}', 2918, 2),
+    ('\u{FC73}', 2920, 2),
+    ('\u{FC74}', 2922, 2),
+    ('\u{FC75}', 2924, 2),
+    ('\u{FC76}', 2926, 2),
+    ('\u{FC77}', 2928, 2),
+    ('\u{FC78}', 2930, 2),
+    ('\u{FC79}', 2932, 2),
+    ('\u{FC7A}', 2934, 2),
+    ('\u{FC7B}', 2936, 2),
+    ('\u{FC7C}', 2938, 2),
+    ('\u{FC7D}', 2940, 2),
+    ('\u{FC7E}', 2942, 2),
+    ('\u{FC7F}', 2944, 2),
+    ('\u{FC80}', 2946, 2),
+    ('\u{FC81}', 2948, 2),
+    ('\u{FC82}', 2950, 2),
+    ('\u{FC83}', 2952, 2),
+    ('\u{FC84}', 2954, 2),
+    ('\u{FC85}', 2956, 2),
+    ('\u{FC86}', 2958, 2),
+    ('\u{FC87}', 2960, 2),
+    ('\u{FC88}', 2962, 2),
+    ('\u{FC89}', 2964, 2),
+    ('\u{FC8A}', 2966, 2),
+    ('\u{FC8B}', 2968, 2),
+    ('\u{FC8C}', 2970, 2),
+    ('\u{FC8D}', 2972, 2),
+    ('\u{FC8E}', 2974, 2),
+    ('\u{FC8F}', 2976, 2),
+    ('\u{FC90}', 2978, 2),
+    ('\u{FC91}', 2980, 2),
+    ('\u{FC92}', 2982, 2),
+    ('\u{FC93}', 2984, 2),
+    ('\u{FC94}', 2986, 2),
+    ('\u{FC95}', 2988, 2),
+    ('\u{FC96}', 2990, 2),
+    ('\u{FC97}', 2992, 3),
+    ('\u{FC98}', 2995, 3),
+    ('\u{FC99}', 2998, 3),
+    ('\u{FC9A}', 3001, 3),
+    ('\u{FC9B}', 3004, 3),
+    ('\u{FC9C}', 3007, 2),
+    ('\u{FC9D}', 3009, 2),
+    ('\u{FC9E}', 3011, 2),
+    ('\u{FC9F}', 3013, 2),
+    ('\u{FCA0}', 3015, 2),
+    ('\u{FCA1}', 3017, 2),
+    ('\u{FCA2}', 3019, 2),
+    ('\u{FCA3}', 3021, 2),
+    ('\u{FCA4}', 3023, 2),
+    ('\u{FCA5}', 3025, 2),
+    ('\u{FCA6}', 3027, 2),
+    ('\u{FCA7}', 3029, 2),
+    ('\u{FCA8}', 3031, 2),
+    ('\u{FCA9}', 3033, 2),
+    ('\u{FCAA}', 3035, 2),
+    ('\u{FCAB}', 3037, 2),
+    ('\u{FCAC}', 3039, 2),
+    ('\u{FCAD}', 3041, 2),
+    ('\u{FCAE}', 3043, 2),
+    ('\u{FCAF}', 3045, 2),
+    ('\u{FCB0}', 3047, 2),
+    ('\u{FCB1}', 3049, 2),
+    ('\u{FCB2}', 3051, 2),
+    ('\u{FCB3}', 3053, 2),
+    ('\u{FCB4}', 3055, 2),
+    ('\u{FCB5}', 3057, 2),
+    ('\u{FCB6}', 3059, 2),
+    ('\u{FCB7}', 3061, 2),
+    ('\u{FCB8}', 3063, 2),
+    ('\u{FCB9}', 3065, 2),
+    ('\u{FCBA}', 3067, 2),
+    ('\u{FCBB}', 3069, 2),
+    ('\u{FCBC}', 3071, 2),
+    ('\u{FCBD}', 3073, 2),
+    ('\u{FCBE}', 3075, 2),
+    ('\u{FCBF}', 3077, 2),
+    ('\u{FCC0}', 3079, 2),
+    ('\u{FCC1}', 3081, 2),
+    ('\u{FCC2}', 3083, 2),
+    ('\u{FCC3}', 3085, 2),
+    ('\u{FCC4}', 3087, 2),
+    ('\u{FCC5}', 3089, 2),
+    ('\u{FCC6}', 3091, 2),
+    ('\u{FCC7}', 3093, 2),
+    ('\u{FCC8}', 3095, 2),
+    ('\u{FCC9}', 3097, 2),
+    ('\u{FCCA}', 3099, 2),
+    ('\u{FCCB}', 3101, 2),
+    ('\u{FCCC}', 3103, 2),
+    ('\u{FCCD}', 3105, 2),
+    ('\u{FCCE}', 3107, 2),
+    ('\u{FCCF}', 3109, 2),
+    ('\u{FCD0}', 3111, 2),
+    ('\u{FCD1}', 3113, 2),
+    ('\u{FCD2}', 3115, 2),
+    ('\u{FCD3}', 3117, 2),
+    ('\u{FCD4}', 3119, 2),
+    ('\u{FCD5}', 3121, 2),
+    ('\u{FCD6}', 3123, 2),
+    ('\u{FCD7}', 3125, 2),
+    ('\u{FCD8}', 3127, 2),
+    ('\u{FCD9}', 3129, 2),
+    ('\u{FCDA}', 3131, 2),
+    ('\u{FCDB}', 3133, 2),
+    ('\u{FCDC}', 3135, 2),
+    ('\u{FCDD}', 3137, 2),
+    ('\u{FCDE}', 3139, 2),
+    ('\u{FCDF}', 3141, 3),
+    ('\u{FCE0}', 3144, 3),
+    ('\u{FCE1}', 3147, 2),
+    ('\u{FCE2}', 3149, 2),
+    ('\u{FCE3}', 3151, 2),
+    ('\u{FCE4}', 3153, 2),
+    ('\u{FCE5}', 3155, 2),
+    ('\u{FCE6}', 3157, 2),
+    ('\u{FCE7}', 3159, 2),
+    ('\u{FCE8}', 3161, 2),
+    ('\u{FCE9}', 3163, 2),
+    ('\u{FCEA}', 3165, 2),
+    ('\u{FCEB}', 3167, 2),
+    ('\u{FCEC}', 3169, 2),
+    ('\u{FCED}', 3171, 2),
+    ('\u{FCEE}', 3173, 2),
+    ('\u{FCEF}', 3175, 2),
+    ('\u{FCF0}', 3177, 2),
+    ('\u{FCF1}', 3179, 2),
+    ('\u{FCF2}', 3181, 3),
+    ('\u{FCF3}', 3184, 3),
+    ('\u{FCF4}', 3187, 3),
+    ('\u{FCF5}', 3190, 2),
+    ('\u{FCF6}', 3192, 2),
+    ('\u{FCF7}', 3194, 2),
+    ('\u{FCF8}', 3196, 2),
+    ('\u{FCF9}', 3198, 2),
+    ('\u{FCFA}', 3200, 2),
+    ('\u{FCFB}', 3202, 2),
+    ('\u{FCFC}', 3204, 2),
+    ('\u{FCFD}', 3206, 2),
+    ('\u{FCFE}', 3208, 2),
+    ('\u{FCFF}', 3210, 2),
+    ('\u{FD00}', 3212, 2),
+    ('\u{FD01}', 3214, 2),
+    ('\u{FD02}', 3216, 2),
+    ('\u{FD03}', 3218, 2),
+    ('\u{FD04}', 3220, 2),
+    ('\u{FD05}', 3222, 2),
+    ('\u{FD06}', 3224, 2),
+    ('\u{FD07}', 3226, 2),
+    ('\u{FD08}', 3228, 2),
+    ('\u{FD09}', 3230, 2),
+    ('\u{FD0A}', 3232, 2),
+    ('\u{FD0B}', 3234, 2),
+    ('\u{FD0C}', 3236, 2),
+    ('\u{FD0D}', 3238, 2),
+    ('\u{FD0E}', 3240, 2),
+    ('\u{FD0F}', 3242, 2),
+    ('\u{FD10}', 3244, 2),
+    ('\u{FD11}', 3246, 2),
+    ('\u{FD12}', 3248, 2),
+    ('\u{FD13}', 3250, 2),
+    ('\u{FD14}', 3252, 2),
+    ('\u{FD15}', 3254, 2),
+    ('\u{FD16}', 3256, 2),
+    ('\u{FD17}', 3258, 2),
+    ('\u{FD18}', 3260, 2),
+    ('\u{FD19}', 3262, 2),
+    ('\u{FD1A}', 3264, 2),
+    ('\u{FD1B}', 3266, 2),
+    ('\u{FD1C}', 3268, 2),
+    ('\u{FD1D}', 3270, 2),
+    ('\u{FD1E}', 3272, 2),
+    ('\u{FD1F}', 3274, 2),
+    ('\u{FD20}', 3276, 2),
+    ('\u{FD21}', 3278, 2),
+    ('\u{FD22}', 3280, 2),
+    ('\u{FD23}', 3282, 2),
+    ('\u{FD24}', 3284, 2),
+    ('\u{FD25}', 3286, 2),
+    ('\u{FD26}', 3288, 2),
+    ('\u{FD27}', 3290, 2),
+    ('\u{FD28}', 3292, 2),
+    ('\u{FD29}', 3294, 2),
+    ('\u{FD2A}', 3296, 2),
+    ('\u{FD2B}', 3298, 2),
+    ('\u{FD2C}', 3300, 2),
+    ('\u{FD2D}', 3302, 2),
+    ('\u{FD2E}', 3304, 2),
+    ('\u{FD2F}', 3306, 2),
+    ('\u{FD30}', 3308, 2),
+    ('\u{FD31}', 3310, 2),
+    ('\u{FD32}', 3312, 2),
+    ('\u{FD33}', 3314, 2),
+    ('\u{FD34}', 3316, 2),
+    ('\u{FD35}', 3318, 2),
+    ('\u{FD36}', 3320, 2),
+    ('\u{FD37}', 3322, 2),
+    ('\u{FD38}', 3324, 2),
+    ('\u{FD39}', 3326, 2),
+    ('\u{FD3A}', 3328, 2),
+    ('\u{FD3B}', 3330, 2),
+    ('\u{FD3C}', 3332, 2),
+    ('\u{FD3D}', 3334, 2),
+    ('\u{FD50}', 3336, 3),
+    ('\u{FD51}', 3339, 3),
+    ('\u{FD52}', 3342, 3),
+    ('\u{FD53}', 3345, 3),
+    ('\u{FD54}', 3348, 3),
+    ('\u{FD55}', 3351, 3),
+    ('\u{FD56}', 3354, 3),
+    ('\u{FD57}', 3357, 3),
+    ('\u{FD58}', 3360, 3),
+    ('\u{FD59}', 3363, 3),
+    ('\u{FD5A}', 3366, 3),
+    ('\u{FD5B}', 3369, 3),
+    ('\u{FD5C}', 3372, 3),
+    ('\u{FD5D}', 3375, 3),
+    ('\u{FD5E}', 3378, 3),
+    ('\u{FD5F}', 3381, 3),
+    ('\u{FD60}', 3384, 3),
+    ('\u{FD61}', 3387, 3),
+    ('\u{FD62}', 3390, 3),
+    ('\u{FD63}', 3393, 3),
+    ('\u{FD64}', 3396, 3),
+    ('\u{FD65}', 3399, 3),
+    ('\u{FD66}', 3402, 3),
+    ('\u{FD67}', 3405, 3),
+    ('\u{FD68}', 3408, 3),
+    ('\u{FD69}', 3411, 3),
+    ('\u{FD6A}', 3414, 3),
+    ('\u{FD6B}', 3417, 3),
+    ('\u{FD6C}', 3420, 3),
+    ('\u{FD6D}', 3423, 3),
+    ('\u{FD6E}', 3426, 3),
+    ('\u{FD6F}', 3429, 3),
+    ('\u{FD70}', 3432, 3),
+    ('\u{FD71}', 3435, 3),
+    ('\u{FD72}', 3438, 3),
+    ('\u{FD73}', 3441, 3),
+    ('\u{FD74}', 3444, 3),
+    ('\u{FD75}', 3447, 3),
+    ('\u{FD76}', 3450, 3),
+    ('\u{FD77}', 3453, 3),
+    ('\u{FD78}', 3456, 3),
+    ('\u{FD79}', 3459, 3),
+    ('\u{FD7A}', 3462, 3),
+    ('\u{FD7B}', 3465, 3),
+    ('\u{FD7C}', 3468, 3),
+    ('\u{FD7D}', 3471, 3),
+    ('\u{FD7E}', 3474, 3),
+    ('\u{FD7F}', 3477, 3),
+    ('\u{FD80}', 3480, 3),
+    ('\u{FD81}', 3483, 3),
+    ('\u{FD82}', 3486, 3),
+    ('\u{FD83}', 3489, 3),
+    ('\u{FD84}', 3492, 3),
+    ('\u{FD85}', 3495, 3),
+    ('\u{FD86}', 3498, 3),
+    ('\u{FD87}', 3501, 3),
+    ('\u{FD88}', 3504, 3),
+    ('\u{FD89}', 3507, 3),
+    ('\u{FD8A}', 3510, 3),
+    ('\u{FD8B}', 3513, 3),
+    ('\u{FD8C}', 3516, 3),
+    ('\u{FD8D}', 3519, 3),
+    ('\u{FD8E}', 3522, 3),
+    ('\u{FD8F}', 3525, 3),
+    ('\u{FD92}', 3528, 3),
+    ('\u{FD93}', 3531, 3),
+    ('\u{FD94}', 3534, 3),
+    ('\u{FD95}', 3537, 3),
+    ('\u{FD96}', 3540, 3),
+    ('\u{FD97}', 3543, 3),
+    ('\u{FD98}', 3546, 3),
+    ('\u{FD99}', 3549, 3),
+    ('\u{FD9A}', 3552, 3),
+    ('\u{FD9B}', 3555, 3),
+    ('\u{FD9C}', 3558, 3),
+    ('\u{FD9D}', 3561, 3),
+    ('\u{FD9E}', 3564, 3),
+    ('\u{FD9F}', 3567, 3),
+    ('\u{FDA0}', 3570, 3),
+    ('\u{FDA1}', 3573, 3),
+    ('\u{FDA2}', 3576, 3),
+    ('\u{FDA3}', 3579, 3),
+    ('\u{FDA4}', 3582, 3),
+    ('\u{FDA5}', 3585, 3),
+    ('\u{FDA6}', 3588, 3),
+    ('\u{FDA7}', 3591, 3),
+    ('\u{FDA8}', 3594, 3),
+    ('\u{FDA9}', 3597, 3),
+    ('\u{FDAA}', 3600, 3),
+    ('\u{FDAB}', 3603, 3),
+    ('\u{FDAC}', 3606, 3),
+    ('\u{FDAD}', 3609, 3),
+    ('\u{FDAE}', 3612, 3),
+    ('\u{FDAF}', 3615, 3),
+    ('\u{FDB0}', 3618, 3),
+    ('\u{FDB1}', 3621, 3),
+    ('\u{FDB2}', 3624, 3),
+    ('\u{FDB3}', 3627, 3),
+    ('\u{FDB4}', 3630, 3),
+    ('\u{FDB5}', 3633, 3),
+    ('\u{FDB6}', 3636, 3),
+    ('\u{FDB7}', 3639, 3),
+    ('\u{FDB8}', 3642, 3),
+    ('\u{FDB9}', 3645, 3),
+    ('\u{FDBA}', 3648, 3),
+    ('\u{FDBB}', 3651, 3),
+    ('\u{FDBC}', 3654, 3),
+    ('\u{FDBD}', 3657, 3),
+    ('\u{FDBE}', 3660, 3),
+    ('\u{FDBF}', 3663, 3),
+    ('\u{FDC0}', 3666, 3),
+    ('\u{FDC1}', 3669, 3),
+    ('\u{FDC2}', 3672, 3),
+    ('\u{FDC3}', 3675, 3),
+    ('\u{FDC4}', 3678, 3),
+    ('\u{FDC5}', 3681, 3),
+    ('\u{FDC6}', 3684, 3),
+    ('\u{FDC7}', 3687, 3),
+    ('\u{FDF0}', 3690, 3),
+    ('\u{FDF1}', 3693, 3),
+    ('\u{FDF2}', 3696, 4),
+    ('\u{FDF3}', 3700, 4),
+    ('\u{FDF4}', 3704, 4),
+    ('\u{FDF5}', 3708, 4),
+    ('\u{FDF6}', 3712, 4),
+    ('\u{FDF7}', 3716, 4),
+    ('\u{FDF8}', 3720, 4),
+    ('\u{FDF9}', 3724, 3),
+    ('\u{FDFA}', 3727, 18),
+    ('\u{FDFB}', 3745, 8),
+    ('\u{FDFC}', 3753, 4),
+    ('\u{FE10}', 3757, 1),
+    ('\u{FE11}', 3758, 1),
+    ('\u{FE12}', 3759, 1),
+    ('\u{FE13}', 3760, 1),
+    ('\u{FE14}', 3761, 1),
+    ('\u{FE15}', 3762, 1),
+    ('\u{FE16}', 3763, 1),
+    ('\u{FE17}', 3764, 1),
+    ('\u{FE18}', 3765, 1),
+    ('\u{FE19}', 3766, 3),
+    ('\u{FE30}', 3769, 2),
+    ('\u{FE31}', 3771, 1),
+    ('\u{FE32}', 3772, 1),
+    ('\u{FE33}', 3773, 1),
+    ('\u{FE34}', 3774, 1),
+    ('\u{FE35}', 3775, 1),
+    ('\u{FE36}', 3776, 1),
+    ('\u{FE37}', 3777, 1),
+    ('\u{FE38}', 3778, 1),
+    ('\u{FE39}', 3779, 1),
+    ('\u{FE3A}', 3780, 1),
+    ('\u{FE3B}', 3781, 1),
+    ('\u{FE3C}', 3782, 1),
+    ('\u{FE3D}', 3783, 1),
+    ('\u{FE3E}', 3784, 1),
+    ('\u{FE3F}', 3785, 1),
+    ('\u{FE40}', 3786, 1),
+    ('\u{FE41}', 3787, 1),
+    ('\u{FE42}', 3788, 1),
+    ('\u{FE43}', 3789, 1),
+    ('\u{FE44}', 3790, 1),
+    ('\u{FE47}', 3791, 1),
+    ('\u{FE48}', 3792, 1),
+    ('\u{FE49}', 3793, 2),
+    ('\u{FE4A}', 3795, 2),
+    ('\u{FE4B}', 3797, 2),
+    ('\u{FE4C}', 3799, 2),
+    ('\u{FE4D}', 3801, 1),
+    ('\u{FE4E}', 3802, 1),
+    ('\u{FE4F}', 3803, 1),
+    ('\u{FE50}', 3804, 1),
+    ('\u{FE51}', 3805, 1),
+    ('\u{FE52}', 3806, 1),
+    ('\u{FE54}', 3807, 1),
+    ('\u{FE55}', 3808, 1),
+    ('\u{FE56}', 3809, 1),
+    ('\u{FE57}', 3810, 1),
+    ('\u{FE58}', 3811, 1),
+    ('\u{FE59}', 3812, 1),
+    ('\u{FE5A}', 3813, 1),
+    ('\u{FE5B}', 3814, 1),
+    ('\u{FE5C}', 3815, 1),
+    ('\u{FE5D}', 3816, 1),
+    ('\u{FE5E}', 3817, 1),
+    ('\u{FE5F}', 3818, 1),
+    ('\u{FE60}', 3819, 1),
+    ('\u{FE61}', 3820, 1),
+    ('\u{FE62}', 3821, 1),
+    ('\u{FE63}', 3822, 1),
+    ('\u{FE64}', 3823, 1),
+    ('\u{FE65}', 3824, 1),
+    ('\u{FE66}', 3825, 1),
+    ('\u{FE68}', 3826, 1),
+    ('\u{FE69}', 3827, 1),
+    ('\u{FE6A}', 3828, 1),
+    ('\u{FE6B}', 3829, 1),
+    ('\u{FE70}', 3830, 2),
+    ('\u{FE71}', 3832, 2),
+    ('\u{FE72}', 3834, 2),
+    ('\u{FE74}', 3836, 2),
+    ('\u{FE76}', 3838, 2),
+    ('\u{FE77}', 3840, 2),
+    ('\u{FE78}', 3842, 2),
+    ('\u{FE79}', 3844, 2),
+    ('\u{FE7A}', 3846, 2),
+    ('\u{FE7B}', 3848, 2),
+    ('\u{FE7C}', 3850, 2),
+    ('\u{FE7D}', 3852, 2),
+    ('\u{FE7E}', 3854, 2),
+    ('\u{FE7F}', 3856, 2),
+    ('\u{FE80}', 3858, 1),
+    ('\u{FE81}', 3859, 2),
+    ('\u{FE82}', 3861, 2),
+    ('\u{FE83}', 3863, 2),
+    ('\u{FE84}', 3865, 2),
+    ('\u{FE85}', 3867, 2),
+    ('\u{FE86}', 3869, 2),
+    ('\u{FE87}', 3871, 2),
+    ('\u{FE88}', 3873, 2),
+    ('\u{FE89}', 3875, 2),
+    ('\u{FE8A}', 3877, 2),
+    ('\u{FE8B}', 3879, 2),
+    ('\u{FE8C}', 3881, 2),
+    ('\u{FE8D}', 3883, 1),
+    ('\u{FE8E}', 3884, 1),
+    ('\u{FE8F}', 3885, 1),
+    ('\u{FE90}', 3886, 1),
+    ('\u{FE91}', 3887, 1),
+    ('\u{FE92}', 3888, 1),
+    ('\u{FE93}', 3889, 1),
+    ('\u{FE94}', 3890, 1),
+    ('\u{FE95}', 3891, 1),
+    ('\u{FE96}', 3892, 1),
+    ('\u{FE97}', 3893, 1),
+    ('\u{FE98}', 3894, 1),
+    ('\u{FE99}', 3895, 1),
+    ('\u{FE9A}', 3896, 1),
+    ('\u{FE9B}', 3897, 1),
+    ('\u{FE9C}', 3898, 1),
+    ('\u{FE9D}', 3899, 1),
+    ('\u{FE9E}', 3900, 1),
+    ('\u{FE9F}', 3901, 1),
+    ('\u{FEA0}', 3902, 1),
+    ('\u{FEA1}', 3903, 1),
+    ('\u{FEA2}', 3904, 1),
+    ('\u{FEA3}', 3905, 1),
+    ('\u{FEA4}', 3906, 1),
+    ('\u{FEA5}', 3907, 1),
+    ('\u{FEA6}', 3908, 1),
+    ('\u{FEA7}', 3909, 1),
+    ('\u{FEA8}', 3910, 1),
+    ('\u{FEA9}', 3911, 1),
+    ('\u{FEAA}', 3912, 1),
+    ('\u{FEAB}', 3913, 1),
+    ('\u{FEAC}', 3914, 1),
+    ('\u{FEAD}', 3915, 1),
+    ('\u{FEAE}', 3916, 1),
+    ('\u{FEAF}', 3917, 1),
+    ('\u{FEB0}', 3918, 1),
+    ('\u{FEB1}', 3919, 1),
+    ('\u{FEB2}', 3920, 1),
+    ('\u{FEB3}', 3921, 1),
+    ('\u{FEB4}', 3922, 1),
+    ('\u{FEB5}', 3923, 1),
+    ('\u{FEB6}', 3924, 1),
+    ('\u{FEB7}', 3925, 1),
+    ('\u{FEB8}', 3926, 1),
+    ('\u{FEB9}', 3927, 1),
+    ('\u{FEBA}', 3928, 1),
+    ('\u{FEBB}', 3929, 1),
+    ('\u{FEBC}', 3930, 1),
+    ('\u{FEBD}', 3931, 1),
+    ('\u{FEBE}', 3932, 1),
+    ('\u{FEBF}', 3933, 1),
+    ('\u{FEC0}', 3934, 1),
+    ('\u{FEC1}', 3935, 1),
+    ('\u{FEC2}', 3936, 1),
+    ('\u{FEC3}', 3937, 1),
+    ('\u{FEC4}', 3938, 1),
+    ('\u{FEC5}', 3939, 1),
+    ('\u{FEC6}', 3940, 1),
+    ('\u{FEC7}', 3941, 1),
+    ('\u{FEC8}', 3942, 1),
+    ('\u{FEC9}', 3943, 1),
+    ('\u{FECA}', 3944, 1),
+    ('\u{FECB}', 3945, 1),
+    ('\u{FECC}', 3946, 1),
+    ('\u{FECD}', 3947, 1),
+    ('\u{FECE}', 3948, 1),
+    ('\u{FECF}', 3949, 1),
+    ('\u{FED0}', 3950, 1),
+    ('\u{FED1}', 3951, 1),
+    ('\u{FED2}', 3952, 1),
+    ('\u{FED3}', 3953, 1),
+    ('\u{FED4}', 3954, 1),
+    ('\u{FED5}', 3955, 1),
+    ('\u{FED6}', 3956, 1),
+    ('\u{FED7}', 3957, 1),
+    ('\u{FED8}', 3958, 1),
+    ('\u{FED9}', 3959, 1),
+    ('\u{FEDA}', 3960, 1),
+    ('\u{FEDB}', 3961, 1),
+    ('\u{FEDC}', 3962, 1),
+    ('\u{FEDD}', 3963, 1),
+    ('\u{FEDE}', 3964, 1),
+    ('\u{FEDF}', 3965, 1),
+    ('\u{FEE0}', 3966, 1),
+    ('\u{FEE1}', 3967, 1),
+    ('\u{FEE2}', 3968, 1),
+    ('\u{FEE3}', 3969, 1),
+    ('\u{FEE4}', 3970, 1),
+    ('\u{FEE5}', 3971, 1),
+    ('\u{FEE6}', 3972, 1),
+    ('\u{FEE7}', 3973, 1),
+    ('\u{FEE8}', 3974, 1),
+    ('\u{FEE9}', 3975, 1),
+    ('\u{FEEA}', 3976, 1),
+    ('\u{FEEB}', 3977, 1),
+    ('\u{FEEC}', 3978, 1),
+    ('\u{FEED}', 3979, 1),
+    ('\u{FEEE}', 3980, 1),
+    ('\u{FEEF}', 3981, 1),
+    ('\u{FEF0}', 3982, 1),
+    ('\u{FEF1}', 3983, 1),
+    ('\u{FEF2}', 3984, 1),
+    ('\u{FEF3}', 3985, 1),
+    ('\u{FEF4}', 3986, 1),
+    ('\u{FEF5}', 3987, 3),
+    ('\u{FEF6}', 3990, 3),
+    ('\u{FEF7}', 3993, 3),
+    ('\u{FEF8}', 3996, 3),
+    ('\u{FEF9}', 3999, 3),
+    ('\u{FEFA}', 4002, 3),
+    ('\u{FEFB}', 4005, 2),
+    ('\u{FEFC}', 4007, 2),
+    ('\u{FF01}', 4009, 1),
+    ('\u{FF02}', 4010, 1),
+    ('\u{FF03}', 4011, 1),
+    ('\u{FF04}', 4012, 1),
+    ('\u{FF05}', 4013, 1),
+    ('\u{FF06}', 4014, 1),
+    ('\u{FF07}', 4015, 1),
+    ('\u{FF08}', 4016, 1),
+    ('\u{FF09}', 4017, 1),
+    ('\u{FF0A}', 4018, 1),
+    ('\u{FF0B}', 4019, 1),
+    ('\u{FF0C}', 4020, 1),
+    ('\u{FF0D}', 4021, 1),
+    ('\u{FF0E}', 4022, 1),
+    ('\u{FF0F}', 4023, 1),
+    ('\u{FF10}', 4024, 1),
+    ('\u{FF11}', 4025, 1),
+    ('\u{FF12}', 4026, 1),
+    ('\u{FF13}', 4027, 1),
+    ('\u{FF14}', 4028, 1),
+    ('\u{FF15}', 4029, 1),
+    ('\u{FF16}', 4030, 1),
+    ('\u{FF17}', 4031, 1),
+    ('\u{FF18}', 4032, 1),
+    ('\u{FF19}', 4033, 1),
+    ('\u{FF1A}', 4034, 1),
+    ('\u{FF1B}', 4035, 1),
+    ('\u{FF1C}', 4036, 1),
+    ('\u{FF1D}', 4037, 1),
+    ('\u{FF1E}', 4038, 1),
+    ('\u{FF1F}', 4039, 1),
+    ('\u{FF20}', 4040, 1),
+    ('\u{FF21}', 4041, 1),
+    ('\u{FF22}', 4042, 1),
+    ('\u{FF23}', 4043, 1),
+    ('\u{FF24}', 4044, 1),
+    ('\u{FF25}', 4045, 1),
+    ('\u{FF26}', 4046, 1),
+    ('\u{FF27}', 4047, 1),
+    ('\u{FF28}', 4048, 1),
+    ('\u{FF29}', 4049, 1),
+    ('\u{FF2A}', 4050, 1),
+    ('\u{FF2B}', 4051, 1),
+    ('\u{FF2C}', 4052, 1),
+    ('\u{FF2D}', 4053, 1),
+    ('\u{FF2E}', 4054, 1),
+    ('\u{FF2F}', 4055, 1),
+    ('\u{FF30}', 4056, 1),
+    ('\u{FF31}', 4057, 1),
+    ('\u{FF32}', 4058, 1),
+    ('\u{FF33}', 4059, 1),
+    ('\u{FF34}', 4060, 1),
+    ('\u{FF35}', 4061, 1),
+    ('\u{FF36}', 4062, 1),
+    ('\u{FF37}', 4063, 1),
+    ('\u{FF38}', 4064, 1),
+    ('\u{FF39}', 4065, 1),
+    ('\u{FF3A}', 4066, 1),
+    ('\u{FF3B}', 4067, 1),
+    ('\u{FF3C}', 4068, 1),
+    ('\u{FF3D}', 4069, 1),
+    ('\u{FF3E}', 4070, 1),
+    ('\u{FF3F}', 4071, 1),
+    ('\u{FF40}', 4072, 1),
+    ('\u{FF41}', 4073, 1),
+    ('\u{FF42}', 4074, 1),
+    ('\u{FF43}', 4075, 1),
+    ('\u{FF44}', 4076, 1),
+    ('\u{FF45}', 4077, 1),
+    ('\u{FF46}', 4078, 1),
+    ('\u{FF47}', 4079, 1),
+    ('\u{FF48}', 4080, 1),
+    ('\u{FF49}', 4081, 1),
+    ('\u{FF4A}', 4082, 1),
+    ('\u{FF4B}', 4083, 1),
+    ('\u{FF4C}', 4084, 1),
+    ('\u{FF4D}', 4085, 1),
+    ('\u{FF4E}', 4086, 1),
+    ('\u{FF4F}', 4087, 1),
+    ('\u{FF50}', 4088, 1),
+    ('\u{FF51}', 4089, 1),
+    ('\u{FF52}', 4090, 1),
+    ('\u{FF53}', 4091, 1),
+    ('\u{FF54}', 4092, 1),
+    ('\u{FF55}', 4093, 1),
+    ('\u{FF56}', 4094, 1),
+    ('\u{FF57}', 4095, 1),
+    ('\u{FF58}', 4096, 1),
+    ('\u{FF59}', 4097, 1),
+    ('\u{FF5A}', 4098, 1),
+    ('\u{FF5B}', 4099, 1),
+    ('\u{FF5C}', 4100, 1),
+    ('\u{FF5D}', 4101, 1),
+    ('\u{FF5E}', 4102, 1),
+    ('\u{FF5F}', 4103, 1),
+    ('\u{FF60}', 4104, 1),
+    ('\u{FF61}', 4105, 1),
+    ('\u{FF62}', 4106, 1),
+    ('\u{FF63}', 4107, 1),
+    ('\u{FF64}', 4108, 1),
+    ('\u{FF65}', 4109, 1),
+    ('\u{FF66}', 4110, 1),
+    ('\u{FF67}', 4111, 1),
+    ('\u{FF68}', 4112, 1),
+    ('\u{FF69}', 4113, 1),
+    ('\u{FF6A}', 4114, 1),
+    ('\u{FF6B}', 4115, 1),
+    ('\u{FF6C}', 4116, 1),
+    ('\u{FF6D}', 4117, 1),
+    ('\u{FF6E}', 4118, 1),
+    ('\u{FF6F}', 4119, 1),
+    ('\u{FF70}', 4120, 1),
+    ('\u{FF71}', 4121, 1),
+    ('\u{FF72}', 4122, 1),
+    ('\u{FF73}', 4123, 1),
+    ('\u{FF74}', 4124, 1),
+    ('\u{FF75}', 4125, 1),
+    ('\u{FF76}', 4126, 1),
+    ('\u{FF77}', 4127, 1),
+    ('\u{FF78}', 4128, 1),
+    ('\u{FF79}', 4129, 1),
+    ('\u{FF7A}', 4130, 1),
+    ('\u{FF7B}', 4131, 1),
+    ('\u{FF7C}', 4132, 1),
+    ('\u{FF7D}', 4133, 1),
+    ('\u{FF7E}', 4134, 1),
+    ('\u{FF7F}', 4135, 1),
+    ('\u{FF80}', 4136, 1),
+    ('\u{FF81}', 4137, 1),
+    ('\u{FF82}', 4138, 1),
+    ('\u{FF83}', 4139, 1),
+    ('\u{FF84}', 4140, 1),
+    ('\u{FF85}', 4141, 1),
+    ('\u{FF86}', 4142, 1),
+    ('\u{FF87}', 4143, 1),
+    ('\u{FF88}', 4144, 1),
+    ('\u{FF89}', 4145, 1),
+    ('\u{FF8A}', 4146, 1),
+    ('\u{FF8B}', 4147, 1),
+    ('\u{FF8C}', 4148, 1),
+    ('\u{FF8D}', 4149, 1),
+    ('\u{FF8E}', 4150, 1),
+    ('\u{FF8F}', 4151, 1),
+    ('\u{FF90}', 4152, 1),
+    ('\u{FF91}', 4153, 1),
+    ('\u{FF92}', 4154, 1),
+    ('\u{FF93}', 4155, 1),
+    ('\u{FF94}', 4156, 1),
+    ('\u{FF95}', 4157, 1),
+    ('\u{FF96}', 4158, 1),
+    ('\u{FF97}', 4159, 1),
+    ('\u{FF98}', 4160, 1),
+    ('\u{FF99}', 4161, 1),
+    ('\u{FF9A}', 4162, 1),
+    ('\u{FF9B}', 4163, 1),
+    ('\u{FF9C}', 4164, 1),
+    ('\u{FF9D}', 4165, 1),
+    ('\u{FF9E}', 4166, 1),
+    ('\u{FF9F}', 4167, 1),
+    ('\u{FFA0}', 4168, 1),
+    ('\u{FFA1}', 4169, 1),
+    ('\u{FFA2}', 4170, 1),
+    ('\u{FFA3}', 4171, 1),
+    ('\u{FFA4}', 4172, 1),
+    ('\u{FFA5}', 4173, 1),
+    ('\u{FFA6}', 4174, 1),
+    ('\u{FFA7}', 4175, 1),
+    ('\u{FFA8}', 4176, 1),
+    ('\u{FFA9}', 4177, 1),
+    ('\u{FFAA}', 4178, 1),
+    ('\u{FFAB}', 4179, 1),
+    ('\u{FFAC}', 4180, 1),
+    ('\u{FFAD}', 4181, 1),
+    ('\u{FFAE}', 4182, 1),
+    ('\u{FFAF}', 4183, 1),
+    ('\u{FFB0}', 4184, 1),
+    ('\u{FFB1}', 4185, 1),
+    ('\u{FFB2}', 4186, 1),
+    ('\u{FFB3}', 4187, 1),
+    ('\u{FFB4}', 4188, 1),
+    ('\u{FFB5}', 4189, 1),
+    ('\u{FFB6}', 4190, 1),
+    ('\u{FFB7}', 4191, 1),
+    ('\u{FFB8}', 4192, 1),
+    ('\u{FFB9}', 4193, 1),
+    ('\u{FFBA}', 4194, 1),
+    ('\u{FFBB}', 4195, 1),
+    ('\u{FFBC}', 4196, 1),
+    ('\u{FFBD}', 4197, 1),
+    ('\u{FFBE}', 4198, 1),
+    ('\u{FFC2}', 4199, 1),
+    ('\u{FFC3}', 4200, 1),
+    ('\u{FFC4}', 4201, 1),
+    ('\u{FFC5}', 4202, 1),
+    ('\u{FFC6}', 4203, 1),
+    ('\u{FFC7}', 4204, 1),
+    ('\u{FFCA}', 4205, 1),
+    ('\u{FFCB}', 4206, 1),
+    ('\u{FFCC}', 4207, 1),
+    ('\u{FFCD}', 4208, 1),
+    ('\u{FFCE}', 4209, 1),
+    ('\u{FFCF}', 4210, 1),
+    ('\u{FFD2}', 4211, 1),
+    ('\u{FFD3}', 4212, 1),
+    ('\u{FFD4}', 4213, 1),
+    ('\u{FFD5}', 4214, 1),
+    ('\u{FFD6}', 4215, 1),
+    ('\u{FFD7}', 4216, 1),
+    ('\u{FFDA}', 4217, 1),
+    ('\u{FFDB}', 4218, 1),
+    ('\u{FFDC}', 4219, 1),
+    ('\u{FFE0}', 4220, 1),
+    ('\u{FFE1}', 4221, 1),
+    ('\u{FFE2}', 4222, 1),
+    ('\u{FFE3}', 4223, 2),
+    ('\u{FFE4}', 4225, 1),
+    ('\u{FFE5}', 4226, 1),
+    ('\u{FFE6}', 4227, 1),
+    ('\u{FFE8}', 4228, 1),
+    ('\u{FFE9}', 4229, 1),
+    ('\u{FFEA}', 4230, 1),
+    ('\u{FFEB}', 4231, 1),
+    ('\u{FFEC}', 4232, 1),
+    ('\u{FFED}', 4233, 1),
+    ('\u{FFEE}', 4234, 1),
+    ('\u{10781}', 4235, 1),
+    ('\u{10782}', 4236, 1),
+    ('\u{10783}', 4237, 1),
+    ('\u{10784}', 4238, 1),
+    ('\u{10785}', 4239, 1),
+    ('\u{10787}', 4240, 1),
+    ('\u{10788}', 4241, 1),
+    ('\u{10789}', 4242, 1),
+    ('\u{1078A}', 4243, 1),
+    ('\u{1078B}', 4244, 1),
+    ('\u{1078C}', 4245, 1),
+    ('\u{1078D}', 4246, 1),
+    ('\u{1078E}', 4247, 1),
+    ('\u{1078F}', 4248, 1),
+    ('\u{10790}', 4249, 1),
+    ('\u{10791}', 4250, 1),
+    ('\u{10792}', 4251, 1),
+    ('\u{10793}', 4252, 1),
+    ('\u{10794}', 4253, 1),
+    ('\u{10795}', 4254, 1),
+    ('\u{10796}', 4255, 1),
+    ('\u{10797}', 4256, 1),
+    ('\u{10798}', 4257, 1),
+    ('\u{10799}', 4258, 1),
+    ('\u{1079A}', 4259, 1),
+    ('\u{1079B}', 4260, 1),
+    ('\u{1079C}', 4261, 1),
+    ('\u{1079D}', 4262, 1),
+    ('\u{1079E}', 4263, 1),
+    ('\u{1079F}', 4264, 1),
+    ('\u{107A0}', 4265, 1),
+    ('\u{107A1}', 4266, 1),
+    ('\u{107A2}', 4267, 1),
+    ('\u{107A3}', 4268, 1),
+    ('\u{107A4}', 4269, 1),
+    ('\u{107A5}', 4270, 1),
+    ('\u{107A6}', 4271, 1),
+    ('\u{107A7}', 4272, 1),
+    ('\u{107A8}', 4273, 1),
+    ('\u{107A9}', 4274, 1),
+    ('\u{107AA}', 4275, 1),
+    ('\u{107AB}', 4276, 1),
+    ('\u{107AC}', 4277, 1),
+    ('\u{107AD}', 4278, 1),
+    ('\u{107AE}', 4279, 1),
+    ('\u{107AF}', 4280, 1),
+    ('\u{107B0}', 4281, 1),
+    ('\u{107B2}', 4282, 1),
+    ('\u{107B3}', 4283, 1),
+    ('\u{107B4}', 4284, 1),
+    ('\u{107B5}', 4285, 1),
+    ('\u{107B6}', 4286, 1),
+    ('\u{107B7}', 4287, 1),
+    ('\u{107B8}', 4288, 1),
+    ('\u{107B9}', 4289, 1),
+    ('\u{107BA}', 4290, 1),
+    ('\u{1D400}', 4291, 1),
+    ('\u{1D401}', 4292, 1),
+    ('\u{1D402}', 4293, 1),
+    ('\u{1D403}', 4294, 1),
+    ('\u{1D404}', 4295, 1),
+    ('\u{1D405}', 4296, 1),
+    ('\u{1D406}', 4297, 1),
+    ('\u{1D407}', 4298, 1),
+    ('\u{1D408}', 4299, 1),
+    ('\u{1D409}', 4300, 1),
+    ('\u{1D40A}', 4301, 1),
+    ('\u{1D40B}', 4302, 1),
+    ('\u{1D40C}', 4303, 1),
+    ('\u{1D40D}', 4304, 1),
+    ('\u{1D40E}', 4305, 1),
+    ('\u{1D40F}', 4306, 1),
+    ('\u{1D410}', 4307, 1),
+    ('\u{1D411}', 4308, 1),
+    ('\u{1D412}', 4309, 1),
+    ('\u{1D413}', 4310, 1),
+    ('\u{1D414}', 4311, 1),
+    ('\u{1D415}', 4312, 1),
+    ('\u{1D416}', 4313, 1),
+    ('\u{1D417}', 4314, 1),
+    ('\u{1D418}', 4315, 1),
+    ('\u{1D419}', 4316, 1),
+    ('\u{1D41A}', 4317, 1),
+    ('\u{1D41B}', 4318, 1),
+    ('\u{1D41C}', 4319, 1),
+    ('\u{1D41D}', 4320, 1),
+    ('\u{1D41E}', 4321, 1),
+    ('\u{1D41F}', 4322, 1),
+    ('\u{1D420}', 4323, 1),
+    ('\u{1D421}', 4324, 1),
+    ('\u{1D422}', 4325, 1),
+    ('\u{1D423}', 4326, 1),
+    ('\u{1D424}', 4327, 1),
+    ('\u{1D425}', 4328, 1),
+    ('\u{1D426}', 4329, 1),
+    ('\u{1D427}', 4330, 1),
+    ('\u{1D428}', 4331, 1),
+    ('\u{1D429}', 4332, 1),
+    ('\u{1D42A}', 4333, 1),
+    ('\u{1D42B}', 4334, 1),
+    ('\u{1D42C}', 4335, 1),
+    ('\u{1D42D}', 4336, 1),
+    ('\u{1D42E}', 4337, 1),
+    ('\u{1D42F}', 4338, 1),
+    ('\u{1D430}', 4339, 1),
+    ('\u{1D431}', 4340, 1),
+    ('\u{1D432}', 4341, 1),
+    ('\u{1D433}', 4342, 1),
+    ('\u{1D434}', 4343, 1),
+    ('\u{1D435}', 4344, 1),
+    ('\u{1D436}', 4345, 1),
+    ('\u{1D437}', 4346, 1),
+    ('\u{1D438}', 4347, 1),
+    ('\u{1D439}', 4348, 1),
+    ('\u{1D43A}', 4349, 1),
+    ('\u{1D43B}', 4350, 1),
+    ('\u{1D43C}', 4351, 1),
+    ('\u{1D43D}', 4352, 1),
+    ('\u{1D43E}', 4353, 1),
+    ('\u{1D43F}', 4354, 1),
+    ('\u{1D440}', 4355, 1),
+    ('\u{1D441}', 4356, 1),
+    ('\u{1D442}', 4357, 1),
+    ('\u{1D443}', 4358, 1),
+    ('\u{1D444}', 4359, 1),
+    ('\u{1D445}', 4360, 1),
+    ('\u{1D446}', 4361, 1),
+    ('\u{1D447}', 4362, 1),
+    ('\u{1D448}', 4363, 1),
+    ('\u{1D449}', 4364, 1),
+    ('\u{1D44A}', 4365, 1),
+    ('\u{1D44B}', 4366, 1),
+    ('\u{1D44C}', 4367, 1),
+    ('\u{1D44D}', 4368, 1),
+    ('\u{1D44E}', 4369, 1),
+    ('\u{1D44F}', 4370, 1),
+    ('\u{1D450}', 4371, 1),
+    ('\u{1D451}', 4372, 1),
+    ('\u{1D452}', 4373, 1),
+    ('\u{1D453}', 4374, 1),
+    ('\u{1D454}', 4375, 1),
+    ('\u{1D456}', 4376, 1),
+    ('\u{1D457}', 4377, 1),
+    ('\u{1D458}', 4378, 1),
+    ('\u{1D459}', 4379, 1),
+    ('\u{1D45A}', 4380, 1),
+    ('\u{1D45B}', 4381, 1),
+    ('\u{1D45C}', 4382, 1),
+    ('\u{1D45D}', 4383, 1),
+    ('\u{1D45E}', 4384, 1),
+    ('\u{1D45F}', 4385, 1),
+    ('\u{1D460}', 4386, 1),
+    ('\u{1D461}', 4387, 1),
+    ('\u{1D462}', 4388, 1),
+    ('\u{1D463}', 4389, 1),
+    ('\u{1D464}', 4390, 1),
+    ('\u{1D465}', 4391, 1),
+    ('\u{1D466}', 4392, 1),
+    ('\u{1D467}', 4393, 1),
+    ('\u{1D468}', 4394, 1),
+    ('\u{1D469}', 4395, 1),
+    ('\u{1D46A}', 4396, 1),
+    ('\u{1D46B}', 4397, 1),
+    ('\u{1D46C}', 4398, 1),
+    ('\u{1D46D}', 4399, 1),
+    ('\u{1D46E}', 4400, 1),
+    ('\u{1D46F}', 4401, 1),
+    ('\u{1D470}', 4402, 1),
+    ('\u{1D471}', 4403, 1),
+    ('\u{1D472}', 4404, 1),
+    ('\u{1D473}', 4405, 1),
+    ('\u{1D474}', 4406, 1),
+    ('\u{1D475}', 4407, 1),
+    ('\u{1D476}', 4408, 1),
+    ('\u{1D477}', 4409, 1),
+    ('\u{1D478}', 4410, 1),
+    ('\u{1D479}', 4411, 1),
+    ('\u{1D47A}', 4412, 1),
+    ('\u{1D47B}', 4413, 1),
+    ('\u{1D47C}', 4414, 1),
+    ('\u{1D47D}', 4415, 1),
+    ('\u{1D47E}', 4416, 1),
+    ('\u{1D47F}', 4417, 1),
+    ('\u{1D480}', 4418, 1),
+    ('\u{1D481}', 4419, 1),
+    ('\u{1D482}', 4420, 1),
+    ('\u{1D483}', 4421, 1),
+    ('\u{1D484}', 4422, 1),
+    ('\u{1D485}', 4423, 1),
+    ('\u{1D486}', 4424, 1),
+    ('\u{1D487}', 4425, 1),
+    ('\u{1D488}', 4426, 1),
+    ('\u{1D489}', 4427, 1),
+    ('\u{1D48A}', 4428, 1),
+    ('\u{1D48B}', 4429, 1),
+    ('\u{1D48C}', 4430, 1),
+    ('\u{1D48D}', 4431, 1),
+    ('\u{1D48E}', 4432, 1),
+    ('\u{1D48F}', 4433, 1),
+    ('\u{1D490}', 4434, 1),
+    ('\u{1D491}', 4435, 1),
+    ('\u{1D492}', 4436, 1),
+    ('\u{1D493}', 4437, 1),
+    ('\u{1D494}', 4438, 1),
+    ('\u{1D495}', 4439, 1),
+    ('\u{1D496}', 4440, 1),
+    ('\u{1D497}', 4441, 1),
+    ('\u{1D498}', 4442, 1),
+    ('\u{1D499}', 4443, 1),
+    ('\u{1D49A}', 4444, 1),
+    ('\u{1D49B}', 4445, 1),
+    ('\u{1D49C}', 4446, 1),
+    ('\u{1D49E}', 4447, 1),
+    ('\u{1D49F}', 4448, 1),
+    ('\u{1D4A2}', 4449, 1),
+    ('\u{1D4A5}', 4450, 1),
+    ('\u{1D4A6}', 4451, 1),
+    ('\u{1D4A9}', 4452, 1),
+    ('\u{1D4AA}', 4453, 1),
+    ('\u{1D4AB}', 4454, 1),
+    ('\u{1D4AC}', 4455, 1),
+    ('\u{1D4AE}', 4456, 1),
+    ('\u{1D4AF}', 4457, 1),
+    ('\u{1D4B0}', 4458, 1),
+    ('\u{1D4B1}', 4459, 1),
+    ('\u{1D4B2}', 4460, 1),
+    ('\u{1D4B3}', 4461, 1),
+    ('\u{1D4B4}', 4462, 1),
+    ('\u{1D4B5}', 4463, 1),
+    ('\u{1D4B6}', 4464, 1),
+    ('\u{1D4B7}', 4465, 1),
+    ('\u{1D4B8}', 4466, 1),
+    ('\u{1D4B9}', 4467, 1),
+    ('\u{1D4BB}', 4468, 1),
+    ('\u{1D4BD}', 4469, 1),
+    ('\u{1D4BE}', 4470, 1),
+    ('\u{1D4BF}', 4471, 1),
+    ('\u{1D4C0}', 4472, 1),
+    ('\u{1D4C1}', 4473, 1),
+    ('\u{1D4C2}', 4474, 1),
+    ('\u{1D4C3}', 4475, 1),
+    ('\u{1D4C5}', 4476, 1),
+    ('\u{1D4C6}', 4477, 1),
+    ('\u{1D4C7}', 4478, 1),
+    ('\u{1D4C8}', 4479, 1),
+    ('\u{1D4C9}', 4480, 1),
+    ('\u{1D4CA}', 4481, 1),
+    ('\u{1D4CB}', 4482, 1),
+    ('\u{1D4CC}', 4483, 1),
+    ('\u{1D4CD}', 4484, 1),
+    ('\u{1D4CE}', 4485, 1),
+    ('\u{1D4CF}', 4486, 1),
+    ('\u{1D4D0}', 4487, 1),
+    ('\u{1D4D1}', 4488, 1),
+    ('\u{1D4D2}', 4489, 1),
+    ('\u{1D4D3}', 4490, 1),
+    ('\u{1D4D4}', 4491, 1),
+    ('\u{1D4D5}', 4492, 1),
+    ('\u{1D4D6}', 4493, 1),
+    ('\u{1D4D7}', 4494, 1),
+    ('\u{1D4D8}', 4495, 1),
+    ('\u{1D4D9}', 4496, 1),
+    ('\u{1D4DA}', 4497, 1),
+    ('\u{1D4DB}', 4498, 1),
+    ('\u{1D4DC}', 4499, 1),
+    ('\u{1D4DD}', 4500, 1),
+    ('\u{1D4DE}', 4501, 1),
+    ('\u{1D4DF}', 4502, 1),
+    ('\u{1D4E0}', 4503, 1),
+    ('\u{1D4E1}', 4504, 1),
+    ('\u{1D4E2}', 4505, 1),
+    ('\u{1D4E3}', 4506, 1),
+    ('\u{1D4E4}', 4507, 1),
+    ('\u{1D4E5}', 4508, 1),
+    ('\u{1D4E6}', 4509, 1),
+    ('\u{1D4E7}', 4510, 1),
+    ('\u{1D4E8}', 4511, 1),
+    ('\u{1D4E9}', 4512, 1),
+    ('\u{1D4EA}', 4513, 1),
+    ('\u{1D4EB}', 4514, 1),
+    ('\u{1D4EC}', 4515, 1),
+    ('\u{1D4ED}', 4516, 1),
+    ('\u{1D4EE}', 4517, 1),
+    ('\u{1D4EF}', 4518, 1),
+    ('\u{1D4F0}', 4519, 1),
+    ('\u{1D4F1}', 4520, 1),
+    ('\u{1D4F2}', 4521, 1),
+    ('\u{1D4F3}', 4522, 1),
+    ('\u{1D4F4}', 4523, 1),
+    ('\u{1D4F5}', 4524, 1),
+    ('\u{1D4F6}', 4525, 1),
+    ('\u{1D4F7}', 4526, 1),
+    ('\u{1D4F8}', 4527, 1),
+    ('\u{1D4F9}', 4528, 1),
+    ('\u{1D4FA}', 4529, 1),
+    ('\u{1D4FB}', 4530, 1),
+    ('\u{1D4FC}', 4531, 1),
+    ('\u{1D4FD}', 4532, 1),
+    ('\u{1D4FE}', 4533, 1),
+    ('\u{1D4FF}', 4534, 1),
+    ('\u{1D500}', 4535, 1),
+    ('\u{1D501}', 4536, 1),
+    ('\u{1D502}', 4537, 1),
+    ('\u{1D503}', 4538, 1),
+    ('\u{1D504}', 4539, 1),
+    ('\u{1D505}', 4540, 1),
+    ('\u{1D507}', 4541, 1),
+    ('\u{1D508}', 4542, 1),
+    ('\u{1D509}', 4543, 1),
+    ('\u{1D50A}', 4544, 1),
+    ('\u{1D50D}', 4545, 1),
+    ('\u{1D50E}', 4546, 1),
+    ('\u{1D50F}', 4547, 1),
+    ('\u{1D510}', 4548, 1),
+    ('\u{1D511}', 4549, 1),
+    ('\u{1D512}', 4550, 1),
+    ('\u{1D513}', 4551, 1),
+    ('\u{1D514}', 4552, 1),
+    ('\u{1D516}', 4553, 1),
+    ('\u{1D517}', 4554, 1),
+    ('\u{1D518}', 4555, 1),
+    ('\u{1D519}', 4556, 1),
+    ('\u{1D51A}', 4557, 1),
+    ('\u{1D51B}', 4558, 1),
+    ('\u{1D51C}', 4559, 1),
+    ('\u{1D51E}', 4560, 1),
+    ('\u{1D51F}', 4561, 1),
+    ('\u{1D520}', 4562, 1),
+    ('\u{1D521}', 4563, 1),
+    ('\u{1D522}', 4564, 1),
+    ('\u{1D523}', 4565, 1),
+    ('\u{1D524}', 4566, 1),
+    ('\u{1D525}', 4567, 1),
+    ('\u{1D526}', 4568, 1),
+    ('\u{1D527}', 4569, 1),
+    ('\u{1D528}', 4570, 1),
+    ('\u{1D529}', 4571, 1),
+    ('\u{1D52A}', 4572, 1),
+    ('\u{1D52B}', 4573, 1),
+    ('\u{1D52C}', 4574, 1),
+    ('\u{1D52D}', 4575, 1),
+    ('\u{1D52E}', 4576, 1),
+    ('\u{1D52F}', 4577, 1),
+    ('\u{1D530}', 4578, 1),
+    ('\u{1D531}', 4579, 1),
+    ('\u{1D532}', 4580, 1),
+    ('\u{1D533}', 4581, 1),
+    ('\u{1D534}', 4582, 1),
+    ('\u{1D535}', 4583, 1),
+    ('\u{1D536}', 4584, 1),
+    ('\u{1D537}', 4585, 1),
+    ('\u{1D538}', 4586, 1),
+    ('\u{1D539}', 4587, 1),
+    ('\u{1D53B}', 4588, 1),
+    ('\u{1D53C}', 4589, 1),
+    ('\u{1D53D}', 4590, 1),
+    ('\u{1D53E}', 4591, 1),
+    ('\u{1D540}', 4592, 1),
+    ('\u{1D541}', 4593, 1),
+    ('\u{1D542}', 4594, 1),
+    ('\u{1D543}', 4595, 1),
+    ('\u{1D544}', 4596, 1),
+    ('\u{1D546}', 4597, 1),
+    ('\u{1D54A}', 4598, 1),
+    ('\u{1D54B}', 4599, 1),
+    ('\u{1D54C}', 4600, 1),
+    ('\u{1D54D}', 4601, 1),
+    ('\u{1D54E}', 4602, 1),
+    ('\u{1D54F}', 4603, 1),
+    ('\u{1D550}', 4604, 1),
+    ('\u{1D552}', 4605, 1),
+    ('\u{1D553}', 4606, 1),
+    ('\u{1D554}', 4607, 1),
+    ('\u{1D555}', 4608, 1),
+    ('\u{1D556}', 4609, 1),
+    ('\u{1D557}', 4610, 1),
+    ('\u{1D558}', 4611, 1),
+    ('\u{1D559}', 4612, 1),
+    ('\u{1D55A}', 4613, 1),
+    ('\u{1D55B}', 4614, 1),
+    ('\u{1D55C}', 4615, 1),
+    ('\u{1D55D}', 4616, 1),
+    ('\u{1D55E}', 4617, 1),
+    ('\u{1D55F}', 4618, 1),
+    ('\u{1D560}', 4619, 1),
+    ('\u{1D561}', 4620, 1),
+    ('\u{1D562}', 4621, 1),
+    ('\u{1D563}', 4622, 1),
+    ('\u{1D564}', 4623, 1),
+    ('\u{1D565}', 4624, 1),
+    ('\u{1D566}', 4625, 1),
+    ('\u{1D567}', 4626, 1),
+    ('\u{1D568}', 4627, 1),
+    ('\u{1D569}', 4628, 1),
+    ('\u{1D56A}', 4629, 1),
+    ('\u{1D56B}', 4630, 1),
+    ('\u{1D56C}', 4631, 1),
+    ('\u{1D56D}', 4632, 1),
+    ('\u{1D56E}', 4633, 1),
+    ('\u{1D56F}', 4634, 1),
+    ('\u{1D570}', 4635, 1),
+    ('\u{1D571}', 4636, 1),
+    ('\u{1D572}', 4637, 1),
+    ('\u{1D573}', 4638, 1),
+    ('\u{1D574}', 4639, 1),
+    ('\u{1D575}', 4640, 1),
+    ('\u{1D576}', 4641, 1),
+    ('\u{1D577}', 4642, 1),
+    ('\u{1D578}', 4643, 1),
+    ('\u{1D579}', 4644, 1),
+    ('\u{1D57A}', 4645, 1),
+    ('\u{1D57B}', 4646, 1),
+    ('\u{1D57C}', 4647, 1),
+    ('\u{1D57D}', 4648, 1),
+    ('\u{1D57E}', 4649, 1),
+    ('\u{1D57F}', 4650, 1),
+    ('\u{1D580}', 4651, 1),
+    ('\u{1D581}', 4652, 1),
+    ('\u{1D582}', 4653, 1),
+    ('\u{1D583}', 4654, 1),
+    ('\u{1D584}', 4655, 1),
+    ('\u{1D585}', 4656, 1),
+    ('\u{1D586}', 4657, 1),
+    ('\u{1D587}', 4658, 1),
+    ('\u{1D588}', 4659, 1),
+    ('\u{1D589}', 4660, 1),
+    ('\u{1D58A}', 4661, 1),
+    ('\u{1D58B}', 4662, 1),
+    ('\u{1D58C}', 4663, 1),
+    ('\u{1D58D}', 4664, 1),
+    ('\u{1D58E}', 4665, 1),
+    ('\u{1D58F}', 4666, 1),
+    ('\u{1D590}', 4667, 1),
+    ('\u{1D591}', 4668, 1),
+    ('\u{1D592}', 4669, 1),
+    ('\u{1D593}', 4670, 1),
+    ('\u{1D594}', 4671, 1),
+    ('\u{1D595}', 4672, 1),
+    ('\u{1D596}', 4673, 1),
+    ('\u{1D597}', 4674, 1),
+    ('\u{1D598}', 4675, 1),
+    ('\u{1D599}', 4676, 1),
+    ('\u{1D59A}', 4677, 1),
+    ('\u{1D59B}', 4678, 1),
+    ('\u{1D59C}', 4679, 1),
+    ('\u{1D59D}', 4680, 1),
+    ('\u{1D59E}', 4681, 1),
+    ('\u{1D59F}', 4682, 1),
+    ('\u{1D5A0}', 4683, 1),
+    ('\u{1D5A1}', 4684, 1),
+    ('\u{1D5A2}', 4685, 1),
+    ('\u{1D5A3}', 4686, 1),
+    ('\u{1D5A4}', 4687, 1),
+    ('\u{1D5A5}', 4688, 1),
+    ('\u{1D5A6}', 4689, 1),
+    ('\u{1D5A7}', 4690, 1),
+    ('\u{1D5A8}', 4691, 1),
+    ('\u{1D5A9}', 4692, 1),
+    ('\u{1D5AA}', 4693, 1),
+    ('\u{1D5AB}', 4694, 1),
+    ('\u{1D5AC}', 4695, 1),
+    ('\u{1D5AD}', 4696, 1),
+    ('\u{1D5AE}', 4697, 1),
+    ('\u{1D5AF}', 4698, 1),
+    ('\u{1D5B0}', 4699, 1),
+    ('\u{1D5B1}', 4700, 1),
+    ('\u{1D5B2}', 4701, 1),
+    ('\u{1D5B3}', 4702, 1),
+    ('\u{1D5B4}', 4703, 1),
+    ('\u{1D5B5}', 4704, 1),
+    ('\u{1D5B6}', 4705, 1),
+    ('\u{1D5B7}', 4706, 1),
+    ('\u{1D5B8}', 4707, 1),
+    ('\u{1D5B9}', 4708, 1),
+    ('\u{1D5BA}', 4709, 1),
+    ('\u{1D5BB}', 4710, 1),
+    ('\u{1D5BC}', 4711, 1),
+    ('\u{1D5BD}', 4712, 1),
+    ('\u{1D5BE}', 4713, 1),
+    ('\u{1D5BF}', 4714, 1),
+    ('\u{1D5C0}', 4715, 1),
+    ('\u{1D5C1}', 4716, 1),
+    ('\u{1D5C2}', 4717, 1),
+    ('\u{1D5C3}', 4718, 1),
+    ('\u{1D5C4}', 4719, 1),
+    ('\u{1D5C5}', 4720, 1),
+    ('\u{1D5C6}', 4721, 1),
+    ('\u{1D5C7}', 4722, 1),
+    ('\u{1D5C8}', 4723, 1),
+    ('\u{1D5C9}', 4724, 1),
+    ('\u{1D5CA}', 4725, 1),
+    ('\u{1D5CB}', 4726, 1),
+    ('\u{1D5CC}', 4727, 1),
+    ('\u{1D5CD}', 4728, 1),
+    ('\u{1D5CE}', 4729, 1),
+    ('\u{1D5CF}', 4730, 1),
+    ('\u{1D5D0}', 4731, 1),
+    ('\u{1D5D1}', 4732, 1),
+    ('\u{1D5D2}', 4733, 1),
+    ('\u{1D5D3}', 4734, 1),
+    ('\u{1D5D4}', 4735, 1),
+    ('\u{1D5D5}', 4736, 1),
+    ('\u{1D5D6}', 4737, 1),
+    ('\u{1D5D7}', 4738, 1),
+    ('\u{1D5D8}', 4739, 1),
+    ('\u{1D5D9}', 4740, 1),
+    ('\u{1D5DA}', 4741, 1),
+    ('\u{1D5DB}', 4742, 1),
+    ('\u{1D5DC}', 4743, 1),
+    ('\u{1D5DD}', 4744, 1),
+    ('\u{1D5DE}', 4745, 1),
+    ('\u{1D5DF}', 4746, 1),
+    ('\u{1D5E0}', 4747, 1),
+    ('\u{1D5E1}', 4748, 1),
+    ('\u{1D5E2}', 4749, 1),
+    ('\u{1D5E3}', 4750, 1),
+    ('\u{1D5E4}', 4751, 1),
+    ('\u{1D5E5}', 4752, 1),
+    ('\u{1D5E6}', 4753, 1),
+    ('\u{1D5E7}', 4754, 1),
+    ('\u{1D5E8}', 4755, 1),
+    ('\u{1D5E9}', 4756, 1),
+    ('\u{1D5EA}', 4757, 1),
+    ('\u{1D5EB}', 4758, 1),
+    ('\u{1D5EC}', 4759, 1),
+    ('\u{1D5ED}', 4760, 1),
+    ('\u{1D5EE}', 4761, 1),
+    ('\u{1D5EF}', 4762, 1),
+    ('\u{1D5F0}', 4763, 1),
+    ('\u{1D5F1}', 4764, 1),
+    ('\u{1D5F2}', 4765, 1),
+    ('\u{1D5F3}', 4766, 1),
+    ('\u{1D5F4}', 4767, 1),
+    ('\u{1D5F5}', 4768, 1),
+    ('\u{1D5F6}', 4769, 1),
+    ('\u{1D5F7}', 4770, 1),
+    ('\u{1D5F8}', 4771, 1),
+    ('\u{1D5F9}', 4772, 1),
+    ('\u{1D5FA}', 4773, 1),
+    ('\u{1D5FB}', 4774, 1),
+    ('\u{1D5FC}', 4775, 1),
+    ('\u{1D5FD}', 4776, 1),
+    ('\u{1D5FE}', 4777, 1),
+    ('\u{1D5FF}', 4778, 1),
+    ('\u{1D600}', 4779, 1),
+    ('\u{1D601}', 4780, 1),
+    ('\u{1D602}', 4781, 1),
+    ('\u{1D603}', 4782, 1),
+    ('\u{1D604}', 4783, 1),
+    ('\u{1D605}', 4784, 1),
+    ('\u{1D606}', 4785, 1),
+    ('\u{1D607}', 4786, 1),
+    ('\u{1D608}', 4787, 1),
+    ('\u{1D609}', 4788, 1),
+    ('\u{1D60A}', 4789, 1),
+    ('\u{1D60B}', 4790, 1),
+    ('\u{1D60C}', 4791, 1),
+    ('\u{1D60D}', 4792, 1),
+    ('\u{1D60E}', 4793, 1),
+    ('\u{1D60F}', 4794, 1),
+    ('\u{1D610}', 4795, 1),
+    ('\u{1D611}', 4796, 1),
+    ('\u{1D612}', 4797, 1),
+    ('\u{1D613}', 4798, 1),
+    ('\u{1D614}', 4799, 1),
+    ('\u{1D615}', 4800, 1),
+    ('\u{1D616}', 4801, 1),
+    ('\u{1D617}', 4802, 1),
+    ('\u{1D618}', 4803, 1),
+    ('\u{1D619}', 4804, 1),
+    ('\u{1D61A}', 4805, 1),
+    ('\u{1D61B}', 4806, 1),
+    ('\u{1D61C}', 4807, 1),
+    ('\u{1D61D}', 4808, 1),
+    ('\u{1D61E}', 4809, 1),
+    ('\u{1D61F}', 4810, 1),
+    ('\u{1D620}', 4811, 1),
+    ('\u{1D621}', 4812, 1),
+    ('\u{1D622}', 4813, 1),
+    ('\u{1D623}', 4814, 1),
+    ('\u{1D624}', 4815, 1),
+    ('\u{1D625}', 4816, 1),
+    ('\u{1D626}', 4817, 1),
+    ('\u{1D627}', 4818, 1),
+    ('\u{1D628}', 4819, 1),
+    ('\u{1D629}', 4820, 1),
+    ('\u{1D62A}', 4821, 1),
+    ('\u{1D62B}', 4822, 1),
+    ('\u{1D62C}', 4823, 1),
+    ('\u{1D62D}', 4824, 1),
+    ('\u{1D62E}', 4825, 1),
+    ('\u{1D62F}', 4826, 1),
+    ('\u{1D630}', 4827, 1),
+    ('\u{1D631}', 4828, 1),
+    ('\u{1D632}', 4829, 1),
+    ('\u{1D633}', 4830, 1),
+    ('\u{1D634}', 4831, 1),
+    ('\u{1D635}', 4832, 1),
+    ('\u{1D636}', 4833, 1),
+    ('\u{1D637}', 4834, 1),
+    ('\u{1D638}', 4835, 1),
+    ('\u{1D639}', 4836, 1),
+    ('\u{1D63A}', 4837, 1),
+    ('\u{1D63B}', 4838, 1),
+    ('\u{1D63C}', 4839, 1),
+    ('\u{1D63D}', 4840, 1),
+    ('\u{1D63E}', 4841, 1),
+    ('\u{1D63F}', 4842, 1),
+    ('\u{1D640}', 4843, 1),
+    ('\u{1D641}', 4844, 1),
+    ('\u{1D642}', 4845, 1),
+    ('\u{1D643}', 4846, 1),
+    ('\u{1D644}', 4847, 1),
+    ('\u{1D645}', 4848, 1),
+    ('\u{1D646}', 4849, 1),
+    ('\u{1D647}', 4850, 1),
+    ('\u{1D648}', 4851, 1),
+    ('\u{1D649}', 4852, 1),
+    ('\u{1D64A}', 4853, 1),
+    ('\u{1D64B}', 4854, 1),
+    ('\u{1D64C}', 4855, 1),
+    ('\u{1D64D}', 4856, 1),
+    ('\u{1D64E}', 4857, 1),
+    ('\u{1D64F}', 4858, 1),
+    ('\u{1D650}', 4859, 1),
+    ('\u{1D651}', 4860, 1),
+    ('\u{1D652}', 4861, 1),
+    ('\u{1D653}', 4862, 1),
+    ('\u{1D654}', 4863, 1),
+    ('\u{1D655}', 4864, 1),
+    ('\u{1D656}', 4865, 1),
+    ('\u{1D657}', 4866, 1),
+    ('\u{1D658}', 4867, 1),
+    ('\u{1D659}', 4868, 1),
+    ('\u{1D65A}', 4869, 1),
+    ('\u{1D65B}', 4870, 1),
+    ('\u{1D65C}', 4871, 1),
+    ('\u{1D65D}', 4872, 1),
+    ('\u{1D65E}', 4873, 1),
+    ('\u{1D65F}', 4874, 1),
+    ('\u{1D660}', 4875, 1),
+    ('\u{1D661}', 4876, 1),
+    ('\u{1D662}', 4877, 1),
+    ('\u{1D663}', 4878, 1),
+    ('\u{1D664}', 4879, 1),
+    ('\u{1D665}', 4880, 1),
+    ('\u{1D666}', 4881, 1),
+    ('\u{1D667}', 4882, 1),
+    ('\u{1D668}', 4883, 1),
+    ('\u{1D669}', 4884, 1),
+    ('\u{1D66A}', 4885, 1),
+    ('\u{1D66B}', 4886, 1),
+    ('\u{1D66C}', 4887, 1),
+    ('\u{1D66D}', 4888, 1),
+    ('\u{1D66E}', 4889, 1),
+    ('\u{1D66F}', 4890, 1),
+    ('\u{1D670}', 4891, 1),
+    ('\u{1D671}', 4892, 1),
+    ('\u{1D672}', 4893, 1),
+    ('\u{1D673}', 4894, 1),
+    ('\u{1D674}', 4895, 1),
+    ('\u{1D675}', 4896, 1),
+    ('\u{1D676}', 4897, 1),
+    ('\u{1D677}', 4898, 1),
+    ('\u{1D678}', 4899, 1),
+    ('\u{1D679}', 4900, 1),
+    ('\u{1D67A}', 4901, 1),
+    ('\u{1D67B}', 4902, 1),
+    ('\u{1D67C}', 4903, 1),
+    ('\u{1D67D}', 4904, 1),
+    ('\u{1D67E}', 4905, 1),
+    ('\u{1D67F}', 4906, 1),
+    ('\u{1D680}', 4907, 1),
+    ('\u{1D681}', 4908, 1),
+    ('\u{1D682}', 4909, 1),
+    ('\u{1D683}', 4910, 1),
+    ('\u{1D684}', 4911, 1),
+    ('\u{1D685}', 4912, 1),
+    ('\u{1D686}', 4913, 1),
+    ('\u{1D687}', 4914, 1),
+    ('\u{1D688}', 4915, 1),
+    ('\u{1D689}', 4916, 1),
+    ('\u{1D68A}', 4917, 1),
+    ('\u{1D68B}', 4918, 1),
+    ('\u{1D68C}', 4919, 1),
+    ('\u{1D68D}', 4920, 1),
+    ('\u{1D68E}', 4921, 1),
+    ('\u{1D68F}', 4922, 1),
+    ('\u{1D690}', 4923, 1),
+    ('\u{1D691}', 4924, 1),
+    ('\u{1D692}', 4925, 1),
+    ('\u{1D693}', 4926, 1),
+    ('\u{1D694}', 4927, 1),
+    ('\u{1D695}', 4928, 1),
+    ('\u{1D696}', 4929, 1),
+    ('\u{1D697}', 4930, 1),
+    ('\u{1D698}', 4931, 1),
+    ('\u{1D699}', 4932, 1),
+    ('\u{1D69A}', 4933, 1),
+    ('\u{1D69B}', 4934, 1),
+    ('\u{1D69C}', 4935, 1),
+    ('\u{1D69D}', 4936, 1),
+    ('\u{1D69E}', 4937, 1),
+    ('\u{1D69F}', 4938, 1),
+    ('\u{1D6A0}', 4939, 1),
+    ('\u{1D6A1}', 4940, 1),
+    ('\u{1D6A2}', 4941, 1),
+    ('\u{1D6A3}', 4942, 1),
+    ('\u{1D6A4}', 4943, 1),
+    ('\u{1D6A5}', 4944, 1),
+    ('\u{1D6A8}', 4945, 1),
+    ('\u{1D6A9}', 4946, 1),
+    ('\u{1D6AA}', 4947, 1),
+    ('\u{1D6AB}', 4948, 1),
+    ('\u{1D6AC}', 4949, 1),
+    ('\u{1D6AD}', 4950, 1),
+    ('\u{1D6AE}', 4951, 1),
+    ('\u{1D6AF}', 4952, 1),
+    ('\u{1D6B0}', 4953, 1),
+    ('\u{1D6B1}', 4954, 1),
+    ('\u{1D6B2}', 4955, 1),
+    ('\u{1D6B3}', 4956, 1),
+    ('\u{1D6B4}', 4957, 1),
+    ('\u{1D6B5}', 4958, 1),
+    ('\u{1D6B6}', 4959, 1),
+    ('\u{1D6B7}', 4960, 1),
+    ('\u{1D6B8}', 4961, 1),
+    ('\u{1D6B9}', 4962, 1),
+    ('\u{1D6BA}', 4963, 1),
+    ('\u{1D6BB}', 4964, 1),
+    ('\u{1D6BC}', 4965, 1),
+    ('\u{1D6BD}', 4966, 1),
+    ('\u{1D6BE}', 4967, 1),
+    ('\u{1D6BF}', 4968, 1),
+    ('\u{1D6C0}', 4969, 1),
+    ('\u{1D6C1}', 4970, 1),
+    ('\u{1D6C2}', 4971, 1),
+    ('\u{1D6C3}', 4972, 1),
+    ('\u{1D6C4}', 4973, 1),
+    ('\u{1D6C5}', 4974, 1),
+    ('\u{1D6C6}', 4975, 1),
+    ('\u{1D6C7}', 4976, 1),
+    ('\u{1D6C8}', 4977, 1),
+    ('\u{1D6C9}', 4978, 1),
+    ('\u{1D6CA}', 4979, 1),
+    ('\u{1D6CB}', 4980, 1),
+    ('\u{1D6CC}', 4981, 1),
+    ('\u{1D6CD}', 4982, 1),
+    ('\u{1D6CE}', 4983, 1),
+    ('\u{1D6CF}', 4984, 1),
+    ('\u{1D6D0}', 4985, 1),
+    ('\u{1D6D1}', 4986, 1),
+    ('\u{1D6D2}', 4987, 1),
+    ('\u{1D6D3}', 4988, 1),
+    ('\u{1D6D4}', 4989, 1),
+    ('\u{1D6D5}', 4990, 1),
+    ('\u{1D6D6}', 4991, 1),
+    ('\u{1D6D7}', 4992, 1),
+    ('\u{1D6D8}', 4993, 1),
+    ('\u{1D6D9}', 4994, 1),
+    ('\u{1D6DA}', 4995, 1),
+    ('\u{1D6DB}', 4996, 1),
+    ('\u{1D6DC}', 4997, 1),
+    ('\u{1D6DD}', 4998, 1),
+    ('\u{1D6DE}', 4999, 1),
+    ('\u{1D6DF}', 5000, 1),
+    ('\u{1D6E0}', 5001, 1),
+    ('\u{1D6E1}', 5002, 1),
+    ('\u{1D6E2}', 5003, 1),
+    ('\u{1D6E3}', 5004, 1),
+    ('\u{1D6E4}', 5005, 1),
+    ('\u{1D6E5}', 5006, 1),
+    ('\u{1D6E6}', 5007, 1),
+    ('\u{1D6E7}', 5008, 1),
+    ('\u{1D6E8}', 5009, 1),
+    ('\u{1D6E9}', 5010, 1),
+    ('\u{1D6EA}', 5011, 1),
+    ('\u{1D6EB}', 5012, 1),
+    ('\u{1D6EC}', 5013, 1),
+    ('\u{1D6ED}', 5014, 1),
+    ('\u{1D6EE}', 5015, 1),
+    ('\u{1D6EF}', 5016, 1),
+    ('\u{1D6F0}', 5017, 1),
+    ('\u{1D6F1}', 5018, 1),
+    ('\u{1D6F2}', 5019, 1),
+    ('\u{1D6F3}', 5020, 1),
+    ('\u{1D6F4}', 5021, 1),
+    ('\u{1D6F5}', 5022, 1),
+    ('\u{1D6F6}', 5023, 1),
+    ('\u{1D6F7}', 5024, 1),
+    ('\u{1D6F8}', 5025, 1),
+    ('\u{1D6F9}', 5026, 1),
+    ('\u{1D6FA}', 5027, 1),
+    ('\u{1D6FB}', 5028, 1),
+    ('\u{1D6FC}', 5029, 1),
+    ('\u{1D6FD}', 5030, 1),
+    ('\u{1D6FE}', 5031, 1),
+    ('\u{1D6FF}', 5032, 1),
+    ('\u{1D700}', 5033, 1),
+    ('\u{1D701}', 5034, 1),
+    ('\u{1D702}', 5035, 1),
+    ('\u{1D703}', 5036, 1),
+    ('\u{1D704}', 5037, 1),
+    ('\u{1D705}', 5038, 1),
+    ('\u{1D706}', 5039, 1),
+    ('\u{1D707}', 5040, 1),
+    ('\u{1D708}', 5041, 1),
+    ('\u{1D709}', 5042, 1),
+    ('\u{1D70A}', 5043, 1),
+    ('\u{1D70B}', 5044, 1),
+    ('\u{1D70C}', 5045, 1),
+    ('\u{1D70D}', 5046, 1),
+    ('\u{1D70E}', 5047, 1),
+    ('\u{1D70F}', 5048, 1),
+    ('\u{1D710}', 5049, 1),
+    ('\u{1D711}', 5050, 1),
+    ('\u{1D712}', 5051, 1),
+    ('\u{1D713}', 5052, 1),
+    ('\u{1D714}', 5053, 1),
+    ('\u{1D715}', 5054, 1),
+    ('\u{1D716}', 5055, 1),
+    ('\u{1D717}', 5056, 1),
+    ('\u{1D718}', 5057, 1),
+    ('\u{1D719}', 5058, 1),
+    ('\u{1D71A}', 5059, 1),
+    ('\u{1D71B}', 5060, 1),
+    ('\u{1D71C}', 5061, 1),
+    ('\u{1D71D}', 5062, 1),
+    ('\u{1D71E}', 5063, 1),
+    ('\u{1D71F}', 5064, 1),
+    ('\u{1D720}', 5065, 1),
+    ('\u{1D721}', 5066, 1),
+    ('\u{1D722}', 5067, 1),
+    ('\u{1D723}', 5068, 1),
+    ('\u{1D724}', 5069, 1),
+    ('\u{1D725}', 5070, 1),
+    ('\u{1D726}', 5071, 1),
+    ('\u{1D727}', 5072, 1),
+    ('\u{1D728}', 5073, 1),
+    ('\u{1D729}', 5074, 1),
+    ('\u{1D72A}', 5075, 1),
+    ('\u{1D72B}', 5076, 1),
+    ('\u{1D72C}', 5077, 1),
+    ('\u{1D72D}', 5078, 1),
+    ('\u{1D72E}', 5079, 1),
+    ('\u{1D72F}', 5080, 1),
+    ('\u{1D730}', 5081, 1),
+    ('\u{1D731}', 5082, 1),
+    ('\u{1D732}', 5083, 1),
+    ('\u{1D733}', 5084, 1),
+    ('\u{1D734}', 5085, 1),
+    ('\u{1D735}', 5086, 1),
+    ('\u{1D736}', 5087, 1),
+    ('\u{1D737}', 5088, 1),
+    ('\u{1D738}', 5089, 1),
+    ('\u{1D739}', 5090, 1),
+    ('\u{1D73A}', 5091, 1),
+    ('\u{1D73B}', 5092, 1),
+    ('\u{1D73C}', 5093, 1),
+    ('\u{1D73D}', 5094, 1),
+    ('\u{1D73E}', 5095, 1),
+    ('\u{1D73F}', 5096, 1),
+    ('\u{1D740}', 5097, 1),
+    ('\u{1D741}', 5098, 1),
+    ('\u{1D742}', 5099, 1),
+    ('\u{1D743}', 5100, 1),
+    ('\u{1D744}', 5101, 1),
+    ('\u{1D745}', 5102, 1),
+    ('\u{1D746}', 5103, 1),
+    ('\u{1D747}', 5104, 1),
+    ('\u{1D748}', 5105, 1),
+    ('\u{1D749}', 5106, 1),
+    ('\u{1D74A}', 5107, 1),
+    ('\u{1D74B}', 5108, 1),
+    ('\u{1D74C}', 5109, 1),
+    ('\u{1D74D}', 5110, 1),
+    ('\u{1D74E}', 5111, 1),
+    ('\u{1D74F}', 5112, 1),
+    ('\u{1D750}', 5113, 1),
+    ('\u{1D751}', 5114, 1),
+    ('\u{1D752}', 5115, 1),
+    ('\u{1D753}', 5116, 1),
+    ('\u{1D754}', 5117, 1),
+    ('\u{1D755}', 5118, 1),
+    ('\u{1D756}', 5119, 1),
+    ('\u{1D757}', 5120, 1),
+    ('\u{1D758}', 5121, 1),
+    ('\u{1D759}', 5122, 1),
+    ('\u{1D75A}', 5123, 1),
+    ('\u{1D75B}', 5124, 1),
+    ('\u{1D75C}', 5125, 1),
+    ('\u{1D75D}', 5126, 1),
+    ('\u{1D75E}', 5127, 1),
+    ('\u{1D75F}', 5128, 1),
+    ('\u{1D760}', 5129, 1),
+    ('\u{1D761}', 5130, 1),
+    ('\u{1D762}', 5131, 1),
+    ('\u{1D763}', 5132, 1),
+    ('\u{1D764}', 5133, 1),
+    ('\u{1D765}', 5134, 1),
+    ('\u{1D766}', 5135, 1),
+    ('\u{1D767}', 5136, 1),
+    ('\u{1D768}', 5137, 1),
+    ('\u{1D769}', 5138, 1),
+    ('\u{1D76A}', 5139, 1),
+    ('\u{1D76B}', 5140, 1),
+    ('\u{1D76C}', 5141, 1),
+    ('\u{1D76D}', 5142, 1),
+    ('\u{1D76E}', 5143, 1),
+    ('\u{1D76F}', 5144, 1),
+    ('\u{1D770}', 5145, 1),
+    ('\u{1D771}', 5146, 1),
+    ('\u{1D772}', 5147, 1),
+    ('\u{1D773}', 5148, 1),
+    ('\u{1D774}', 5149, 1),
+    ('\u{1D775}', 5150, 1),
+    ('\u{1D776}', 5151, 1),
+    ('\u{1D777}', 5152, 1),
+    ('\u{1D778}', 5153, 1),
+    ('\u{1D779}', 5154, 1),
+    ('\u{1D77A}', 5155, 1),
+    ('\u{1D77B}', 5156, 1),
+    ('\u{1D77C}', 5157, 1),
+    ('\u{1D77D}', 5158, 1),
+    ('\u{1D77E}', 5159, 1),
+    ('\u{1D77F}', 5160, 1),
+    ('\u{1D780}', 5161, 1),
+    ('\u{1D781}', 5162, 1),
+    ('\u{1D782}', 5163, 1),
+    ('\u{1D783}', 5164, 1),
+    ('\u{1D784}', 5165, 1),
+    ('\u{1D785}', 5166, 1),
+    ('\u{1D786}', 5167, 1),
+    ('\u{1D787}', 5168, 1),
+    ('\u{1D788}', 5169, 1),
+    ('\u{1D789}', 5170, 1),
+    ('\u{1D78A}', 5171, 1),
+    ('\u{1D78B}', 5172, 1),
+    ('\u{1D78C}', 5173, 1),
+    ('\u{1D78D}', 5174, 1),
+    ('\u{1D78E}', 5175, 1),
+    ('\u{1D78F}', 5176, 1),
+    ('\u{1D790}', 5177, 1),
+    ('\u{1D791}', 5178, 1),
+    ('\u{1D792}', 5179, 1),
+    ('\u{1D793}', 5180, 1),
+    ('\u{1D794}', 5181, 1),
+    ('\u{1D795}', 5182, 1),
+    ('\u{1D796}', 5183, 1),
+    ('\u{1D797}', 5184, 1),
+    ('\u{1D798}', 5185, 1),
+    ('\u{1D799}', 5186, 1),
+    ('\u{1D79A}', 5187, 1),
+    ('\u{1D79B}', 5188, 1),
+    ('\u{1D79C}', 5189, 1),
+    ('\u{1D79D}', 5190, 1),
+    ('\u{1D79E}', 5191, 1),
+    ('\u{1D79F}', 5192, 1),
+    ('\u{1D7A0}', 5193, 1),
+    ('\u{1D7A1}', 5194, 1),
+    ('\u{1D7A2}', 5195, 1),
+    ('\u{1D7A3}', 5196, 1),
+    ('\u{1D7A4}', 5197, 1),
+    ('\u{1D7A5}', 5198, 1),
+    ('\u{1D7A6}', 5199, 1),
+    ('\u{1D7A7}', 5200, 1),
+    ('\u{1D7A8}', 5201, 1),
+    ('\u{1D7A9}', 5202, 1),
+    ('\u{1D7AA}', 5203, 1),
+    ('\u{1D7AB}', 5204, 1),
+    ('\u{1D7AC}', 5205, 1),
+    ('\u{1D7AD}', 5206, 1),
+    ('\u{1D7AE}', 5207, 1),
+    ('\u{1D7AF}', 5208, 1),
+    ('\u{1D7B0}', 5209, 1),
+    ('\u{1D7B1}', 5210, 1),
+    ('\u{1D7B2}', 5211, 1),
+    ('\u{1D7B3}', 5212, 1),
+    ('\u{1D7B4}', 5213, 1),
+    ('\u{1D7B5}', 5214, 1),
+    ('\u{1D7B6}', 5215, 1),
+    ('\u{1D7B7}', 5216, 1),
+    ('\u{1D7B8}', 5217, 1),
+    ('\u{1D7B9}', 5218, 1),
+    ('\u{1D7BA}', 5219, 1),
+    ('\u{1D7BB}', 5220, 1),
+    ('\u{1D7BC}', 5221, 1),
+    ('\u{1D7BD}', 5222, 1),
+    ('\u{1D7BE}', 5223, 1),
+    ('\u{1D7BF}', 5224, 1),
+    ('\u{1D7C0}', 5225, 1),
+    ('\u{1D7C1}', 5226, 1),
+    ('\u{1D7C2}', 5227, 1),
+    ('\u{1D7C3}', 5228, 1),
+    ('\u{1D7C4}', 5229, 1),
+    ('\u{1D7C5}', 5230, 1),
+    ('\u{1D7C6}', 5231, 1),
+    ('\u{1D7C7}', 5232, 1),
+    ('\u{1D7C8}', 5233, 1),
+    ('\u{1D7C9}', 5234, 1),
+    ('\u{1D7CA}', 5235, 1),
+    ('\u{1D7CB}', 5236, 1),
+    ('\u{1D7CE}', 5237, 1),
+    ('\u{1D7CF}', 5238, 1),
+    ('\u{1D7D0}', 5239, 1),
+    ('\u{1D7D1}', 5240, 1),
+    ('\u{1D7D2}', 5241, 1),
+    ('\u{1D7D3}', 5242, 1),
+    ('\u{1D7D4}', 5243, 1),
+    ('\u{1D7D5}', 5244, 1),
+    ('\u{1D7D6}', 5245, 1),
+    ('\u{1D7D7}', 5246, 1),
+    ('\u{1D7D8}', 5247, 1),
+    ('\u{1D7D9}', 5248, 1),
+    ('\u{1D7DA}', 5249, 1),
+    ('\u{1D7DB}', 5250, 1),
+    ('\u{1D7DC}', 5251, 1),
+    ('\u{1D7DD}', 5252, 1),
+    ('\u{1D7DE}', 5253, 1),
+    ('\u{1D7DF}', 5254, 1),
+    ('\u{1D7E0}', 5255, 1),
+    ('\u{1D7E1}', 5256, 1),
+    ('\u{1D7E2}', 5257, 1),
+    ('\u{1D7E3}', 5258, 1),
+    ('\u{1D7E4}', 5259, 1),
+    ('\u{1D7E5}', 5260, 1),
+    ('\u{1D7E6}', 5261, 1),
+    ('\u{1D7E7}', 5262, 1),
+    ('\u{1D7E8}', 5263, 1),
+    ('\u{1D7E9}', 5264, 1),
+    ('\u{1D7EA}', 5265, 1),
+    ('\u{1D7EB}', 5266, 1),
+    ('\u{1D7EC}', 5267, 1),
+    ('\u{1D7ED}', 5268, 1),
+    ('\u{1D7EE}', 5269, 1),
+    ('\u{1D7EF}', 5270, 1),
+    ('\u{1D7F0}', 5271, 1),
+    ('\u{1D7F1}', 5272, 1),
+    ('\u{1D7F2}', 5273, 1),
+    ('\u{1D7F3}', 5274, 1),
+    ('\u{1D7F4}', 5275, 1),
+    ('\u{1D7F5}', 5276, 1),
+    ('\u{1D7F6}', 5277, 1),
+    ('\u{1D7F7}', 5278, 1),
+    ('\u{1D7F8}', 5279, 1),
+    ('\u{1D7F9}', 5280, 1),
+    ('\u{1D7FA}', 5281, 1),
+    ('\u{1D7FB}', 5282, 1),
+    ('\u{1D7FC}', 5283, 1),
+    ('\u{1D7FD}', 5284, 1),
+    ('\u{1D7FE}', 5285, 1),
+    ('\u{1D7FF}', 5286, 1),
+    ('\u{1E030}', 5287, 1),
+    ('\u{1E031}', 5288, 1),
+    ('\u{1E032}', 5289, 1),
+    ('\u{1E033}', 5290, 1),
+    ('\u{1E034}', 5291, 1),
+    ('\u{1E035}', 5292, 1),
+    ('\u{1E036}', 5293, 1),
+    ('\u{1E037}', 5294, 1),
+    ('\u{1E038}', 5295, 1),
+    ('\u{1E039}', 5296, 1),
+    ('\u{1E03A}', 5297, 1),
+    ('\u{1E03B}', 5298, 1),
+    ('\u{1E03C}', 5299, 1),
+    ('\u{1E03D}', 5300, 1),
+    ('\u{1E03E}', 5301, 1),
+    ('\u{1E03F}', 5302, 1),
+    ('\u{1E040}', 5303, 1),
+    ('\u{1E041}', 5304, 1),
+    ('\u{1E042}', 5305, 1),
+    ('\u{1E043}', 5306, 1),
+    ('\u{1E044}', 5307, 1),
+    ('\u{1E045}', 5308, 1),
+    ('\u{1E046}', 5309, 1),
+    ('\u{1E047}', 5310, 1),
+    ('\u{1E048}', 5311, 1),
+    ('\u{1E049}', 5312, 1),
+    ('\u{1E04A}', 5313, 1),
+    ('\u{1E04B}', 5314, 1),
+    ('\u{1E04C}', 5315, 1),
+    ('\u{1E04D}', 5316, 1),
+    ('\u{1E04E}', 5317, 1),
+    ('\u{1E04F}', 5318, 1),
+    ('\u{1E050}', 5319, 1),
+    ('\u{1E051}', 5320, 1),
+    ('\u{1E052}', 5321, 1),
+    ('\u{1E053}', 5322, 1),
+    ('\u{1E054}', 5323, 1),
+    ('\u{1E055}', 5324, 1),
+    ('\u{1E056}', 5325, 1),
+    ('\u{1E057}', 5326, 1),
+    ('\u{1E058}', 5327, 1),
+    ('\u{1E059}', 5328, 1),
+    ('\u{1E05A}', 5329, 1),
+    ('\u{1E05B}', 5330, 1),
+    ('\u{1E05C}', 5331, 1),
+    ('\u{1E05D}', 5332, 1),
+    ('\u{1E05E}', 5333, 1),
+    ('\u{1E05F}', 5334, 1),
+    ('\u{1E060}', 5335, 1),
+    ('\u{1E061}', 5336, 1),
+    ('\u{1E062}', 5337, 1),
+    ('\u{1E063}', 5338, 1),
+    ('\u{1E064}', 5339, 1),
+    ('\u{1E065}', 5340, 1),
+    ('\u{1E066}', 5341, 1),
+    ('\u{1E067}', 5342, 1),
+    ('\u{1E068}', 5343, 1),
+    ('\u{1E069}', 5344, 1),
+    ('\u{1E06A}', 5345, 1),
+    ('\u{1E06B}', 5346, 1),
+    ('\u{1E06C}', 5347, 1),
+    ('\u{1E06D}', 5348, 1),
+    ('\u{1EE00}', 5349, 1),
+    ('\u{1EE01}', 5350, 1),
+    ('\u{1EE02}', 5351, 1),
+    ('\u{1EE03}', 5352, 1),
+    ('\u{1EE05}', 5353, 1),
+    ('\u{1EE06}', 5354, 1),
+    ('\u{1EE07}', 5355, 1),
+    ('\u{1EE08}', 5356, 1),
+    ('\u{1EE09}', 5357, 1),
+    ('\u{1EE0A}', 5358, 1),
+    ('\u{1EE0B}', 5359, 1),
+    ('\u{1EE0C}', 5360, 1),
+    ('\u{1EE0D}', 5361, 1),
+    ('\u{1EE0E}', 5362, 1),
+    ('\u{1EE0F}', 5363, 1),
+    ('\u{1EE10}', 5364, 1),
+    ('\u{1EE11}', 5365, 1),
+    ('\u{1EE12}', 5366, 1),
+    ('\u{1EE13}', 5367, 1),
+    ('\u{1EE14}', 5368, 1),
+    ('\u{1EE15}', 5369, 1),
+    ('\u{1EE16}', 5370, 1),
+    ('\u{1EE17}', 5371, 1),
+    ('\u{1EE18}', 5372, 1),
+    ('\u{1EE19}', 5373, 1),
+    ('\u{1EE1A}', 5374, 1),
+    ('\u{1EE1B}', 5375, 1),
+    ('\u{1EE1C}', 5376, 1),
+    ('\u{1EE1D}', 5377, 1),
+    ('\u{1EE1E}', 5378, 1),
+    ('\u{1EE1F}', 5379, 1),
+    ('\u{1EE21}', 5380, 1),
+    ('\u{1EE22}', 5381, 1),
+    ('\u{1EE24}', 5382, 1),
+    ('\u{1EE27}', 5383, 1),
+    ('\u{1EE29}', 5384, 1),
+    ('\u{1EE2A}', 5385, 1),
+    ('\u{1EE2B}', 5386, 1),
+    ('\u{1EE2C}', 5387, 1),
+    ('\u{1EE2D}', 5388, 1),
+    ('\u{1EE2E}', 5389, 1),
+    ('\u{1EE2F}', 5390, 1),
+    ('\u{1EE30}', 5391, 1),
+    ('\u{1EE31}', 5392, 1),
+    ('\u{1EE32}', 5393, 1),
+    ('\u{1EE34}', 5394, 1),
+    ('\u{1EE35}', 5395, 1),
+    ('\u{1EE36}', 5396, 1),
+    ('\u{1EE37}', 5397, 1),
+    ('\u{1EE39}', 5398, 1),
+    ('\u{1EE3B}', 5399, 1),
+    ('\u{1EE42}', 5400, 1),
+    ('\u{1EE47}', 5401, 1),
+    ('\u{1EE49}', 5402, 1),
+    ('\u{1EE4B}', 5403, 1),
+    ('\u{1EE4D}', 5404, 1),
+    ('\u{1EE4E}', 5405, 1),
+    ('\u{1EE4F}', 5406, 1),
+    ('\u{1EE51}', 5407, 1),
+    ('\u{1EE52}', 5408, 1),
+    ('\u{1EE54}', 5409, 1),
+    ('\u{1EE57}', 5410, 1),
+    ('\u{1EE59}', 5411, 1),
+    ('\u{1EE5B}', 5412, 1),
+    ('\u{1EE5D}', 5413, 1),
+    ('\u{1EE5F}', 5414, 1),
+    ('\u{1EE61}', 5415, 1),
+    ('\u{1EE62}', 5416, 1),
+    ('\u{1EE64}', 5417, 1),
+    ('\u{1EE67}', 5418, 1),
+    ('\u{1EE68}', 5419, 1),
+    ('\u{1EE69}', 5420, 1),
+    ('\u{1EE6A}', 5421, 1),
+    ('\u{1EE6C}', 5422, 1),
+    ('\u{1EE6D}', 5423, 1),
+    ('\u{1EE6E}', 5424, 1),
+    ('\u{1EE6F}', 5425, 1),
+    ('\u{1EE70}', 5426, 1),
+    ('\u{1EE71}', 5427, 1),
+    ('\u{1EE72}', 5428, 1),
+    ('\u{1EE74}', 5429, 1),
+    ('\u{1EE75}', 5430, 1),
+    ('\u{1EE76}', 5431, 1),
+    ('\u{1EE77}', 5432, 1),
+    ('\u{1EE79}', 5433, 1),
+    ('\u{1EE7A}', 5434, 1),
+    ('\u{1EE7B}', 5435, 1),
+    ('\u{1EE7C}', 5436, 1),
+    ('\u{1EE7E}', 5437, 1),
+    ('\u{1EE80}', 5438, 1),
+    ('\u{1EE81}', 5439, 1),
+    ('\u{1EE82}', 5440, 1),
+    ('\u{1EE83}', 5441, 1),
+    ('\u{1EE84}', 5442, 1),
+    ('\u{1EE85}', 5443, 1),
+    ('\u{1EE86}', 5444, 1),
+    ('\u{1EE87}', 5445, 1),
+    ('\u{1EE88}', 5446, 1),
+    ('\u{1EE89}', 5447, 1),
+    ('\u{1EE8B}', 5448, 1),
+    ('\u{1EE8C}', 5449, 1),
+    ('\u{1EE8D}', 5450, 1),
+    ('\u{1EE8E}', 5451, 1),
+    ('\u{1EE8F}', 5452, 1),
+    ('\u{1EE90}', 5453, 1),
+    ('\u{1EE91}', 5454, 1),
+    ('\u{1EE92}', 5455, 1),
+    ('\u{1EE93}', 5456, 1),
+    ('\u{1EE94}', 5457, 1),
+    ('\u{1EE95}', 5458, 1),
+    ('\u{1EE96}', 5459, 1),
+    ('\u{1EE97}', 5460, 1),
+    ('\u{1EE98}', 5461, 1),
+    ('\u{1EE99}', 5462, 1),
+    ('\u{1EE9A}', 5463, 1),
+    ('\u{1EE9B}', 5464, 1),
+    ('\u{1EEA1}', 5465, 1),
+    ('\u{1EEA2}', 5466, 1),
+    ('\u{1EEA3}', 5467, 1),
+    ('\u{1EEA5}', 5468, 1),
+    ('\u{1EEA6}', 5469, 1),
+    ('\u{1EEA7}', 5470, 1),
+    ('\u{1EEA8}', 5471, 1),
+    ('\u{1EEA9}', 5472, 1),
+    ('\u{1EEAB}', 5473, 1),
+    ('\u{1EEAC}', 5474, 1),
+    ('\u{1EEAD}', 5475, 1),
+    ('\u{1EEAE}', 5476, 1),
+    ('\u{1EEAF}', 5477, 1),
+    ('\u{1EEB0}', 5478, 1),
+    ('\u{1EEB1}', 5479, 1),
+    ('\u{1EEB2}', 5480, 1),
+    ('\u{1EEB3}', 5481, 1),
+    ('\u{1EEB4}', 5482, 1),
+    ('\u{1EEB5}', 5483, 1),
+    ('\u{1EEB6}', 5484, 1),
+    ('\u{1EEB7}', 5485, 1),
+    ('\u{1EEB8}', 5486, 1),
+    ('\u{1EEB9}', 5487, 1),
+    ('\u{1EEBA}', 5488, 1),
+    ('\u{1EEBB}', 5489, 1),
+    ('\u{1F100}', 5490, 2),
+    ('\u{1F101}', 5492, 2),
+    ('\u{1F102}', 5494, 2),
+    ('\u{1F103}', 5496, 2),
+    ('\u{1F104}', 5498, 2),
+    ('\u{1F105}', 5500, 2),
+    ('\u{1F106}', 5502, 2),
+    ('\u{1F107}', 5504, 2),
+    ('\u{1F108}', 5506, 2),
+    ('\u{1F109}', 5508, 2),
+    ('\u{1F10A}', 5510, 2),
+    ('\u{1F110}', 5512, 3),
+    ('\u{1F111}', 5515, 3),
+    ('\u{1F112}', 5518, 3),
+    ('\u{1F113}', 5521, 3),
+    ('\u{1F114}', 5524, 3),
+    ('\u{1F115}', 5527, 3),
+    ('\u{1F116}', 5530, 3),
+    ('\u{1F117}', 5533, 3),
+    ('\u{1F118}', 5536, 3),
+    ('\u{1F119}', 5539, 3),
+    ('\u{1F11A}', 5542, 3),
+    ('\u{1F11B}', 5545, 3),
+    ('\u{1F11C}', 5548, 3),
+    ('\u{1F11D}', 5551, 3),
+    ('\u{1F11E}', 5554, 3),
+    ('\u{1F11F}', 5557, 3),
+    ('\u{1F120}', 5560, 3),
+    ('\u{1F121}', 5563, 3),
+    ('\u{1F122}', 5566, 3),
+    ('\u{1F123}', 5569, 3),
+    ('\u{1F124}', 5572, 3),
+    ('\u{1F125}', 5575, 3),
+    ('\u{1F126}', 5578, 3),
+    ('\u{1F127}', 5581, 3),
+    ('\u{1F128}', 5584, 3),
+    ('\u{1F129}', 5587, 3),
+    ('\u{1F12A}', 5590, 3),
+    ('\u{1F12B}', 5593, 1),
+    ('\u{1F12C}', 5594, 1),
+    ('\u{1F12D}', 5595, 2),
+    ('\u{1F12E}', 5597, 2),
+    ('\u{1F130}', 5599, 1),
+    ('\u{1F131}', 5600, 1),
+    ('\u{1F132}', 5601, 1),
+    ('\u{1F133}', 5602, 1),
+    ('\u{1F134}', 5603, 1),
+    ('\u{1F135}', 5604, 1),
+    ('\u{1F136}', 5605, 1),
+    ('\u{1F137}', 5606, 1),
+    ('\u{1F138}', 5607, 1),
+    ('\u{1F139}', 5608, 1),
+    ('\u{1F13A}', 5609, 1),
+    ('\u{1F13B}', 5610, 1),
+    ('\u{1F13C}', 5611, 1),
+    ('\u{1F13D}', 5612, 1),
+    ('\u{1F13E}', 5613, 1),
+    ('\u{1F13F}', 5614, 1),
+    ('\u{1F140}', 5615, 1),
+    ('\u{1F141}', 5616, 1),
+    ('\u{1F142}', 5617, 1),
+    ('\u{1F143}', 5618, 1),
+    ('\u{1F144}', 5619, 1),
+    ('\u{1F145}', 5620, 1),
+    ('\u{1F146}', 5621, 1),
+    ('\u{1F147}', 5622, 1),
+    ('\u{1F148}', 5623, 1),
+    ('\u{1F149}', 5624, 1),
+    ('\u{1F14A}', 5625, 2),
+    ('\u{1F14B}', 5627, 2),
+    ('\u{1F14C}', 5629, 2),
+    ('\u{1F14D}', 5631, 2),
+    ('\u{1F14E}', 5633, 3),
+    ('\u{1F14F}', 5636, 2),
+    ('\u{1F16A}', 5638, 2),
+    ('\u{1F16B}', 5640, 2),
+    ('\u{1F16C}', 5642, 2),
+    ('\u{1F190}', 5644, 2),
+    ('\u{1F200}', 5646, 2),
+    ('\u{1F201}', 5648, 2),
+    ('\u{1F202}', 5650, 1),
+    ('\u{1F210}', 5651, 1),
+    ('\u{1F211}', 5652, 1),
+    ('\u{1F212}', 5653, 1),
+    ('\u{1F213}', 5654, 2),
+    ('\u{1F214}', 5656, 1),
+    ('\u{1F215}', 5657, 1),
+    ('\u{1F216}', 5658, 1),
+    ('\u{1F217}', 5659, 1),
+    ('\u{1F218}', 5660, 1),
+    ('\u{1F219}', 5661, 1),
+    ('\u{1F21A}', 5662, 1),
+    ('\u{1F21B}', 5663, 1),
+    ('\u{1F21C}', 5664, 1),
+    ('\u{1F21D}', 5665, 1),
+    ('\u{1F21E}', 5666, 1),
+    ('\u{1F21F}', 5667, 1),
+    ('\u{1F220}', 5668, 1),
+    ('\u{1F221}', 5669, 1),
+    ('\u{1F222}', 5670, 1),
+    ('\u{1F223}', 5671, 1),
+    ('\u{1F224}', 5672, 1),
+    ('\u{1F225}', 5673, 1),
+    ('\u{1F226}', 5674, 1),
+    ('\u{1F227}', 5675, 1),
+    ('\u{1F228}', 5676, 1),
+    ('\u{1F229}', 5677, 1),
+    ('\u{1F22A}', 5678, 1),
+    ('\u{1F22B}', 5679, 1),
+    ('\u{1F22C}', 5680, 1),
+    ('\u{1F22D}', 5681, 1),
+    ('\u{1F22E}', 5682, 1),
+    ('\u{1F22F}', 5683, 1),
+    ('\u{1F230}', 5684, 1),
+    ('\u{1F231}', 5685, 1),
+    ('\u{1F232}', 5686, 1),
+    ('\u{1F233}', 5687, 1),
+    ('\u{1F234}', 5688, 1),
+    ('\u{1F235}', 5689, 1),
+    ('\u{1F236}', 5690, 1),
+    ('\u{1F237}', 5691, 1),
+    ('\u{1F238}', 5692, 1),
+    ('\u{1F239}', 5693, 1),
+    ('\u{1F23A}', 5694, 1),
+    ('\u{1F23B}', 5695, 1),
+    ('\u{1F240}', 5696, 3),
+    ('\u{1F241}', 5699, 3),
+    ('\u{1F242}', 5702, 3),
+    ('\u{1F243}', 5705, 3),
+    ('\u{1F244}', 5708, 3),
+    ('\u{1F245}', 5711, 3),
+    ('\u{1F246}', 5714, 3),
+    ('\u{1F247}', 5717, 3),
+    ('\u{1F248}', 5720, 3),
+    ('\u{1F250}', 5723, 1),
+    ('\u{1F251}', 5724, 1),
+    ('\u{1FBF0}', 5725, 1),
+    ('\u{1FBF1}', 5726, 1),
+    ('\u{1FBF2}', 5727, 1),
+    ('\u{1FBF3}', 5728, 1),
+    ('\u{1FBF4}', 5729, 1),
+    ('\u{1FBF5}', 5730, 1),
+    ('\u{1FBF6}', 5731, 1),
+    ('\u{1FBF7}', 5732, 1),
+    ('\u{1FBF8}', 5733, 1),
+    ('\u{1FBF9}', 5734, 1),
+];
+
+pub(super) static COMPATIBLE_CHARS: [char; 5735] = [
+    '\u{0020}', '\u{0020}', '\u{0308}', '\u{0061}', '\u{0020}', '\u{0304}', '\u{0032}', '\u{0033}',
+    '\u{0020}', '\u{0301}', '\u{03BC}', '\u{0020}', '\u{0327}', '\u{0031}', '\u{006F}', '\u{0031}',
+    '\u{2044}', '\u{0034}', '\u{0031}', '\u{2044}', '\u{0032}', '\u{0033}', '\u{2044}', '\u{0034}',
+    '\u{0049}', '\u{004A}', '\u{0069}', '\u{006A}', '\u{004C}', '\u{00B7}', '\u{006C}', '\u{00B7}',
+    '\u{02BC}', '\u{006E}', '\u{0073}', '\u{0044}', '\u{005A}', '\u{030C}', '\u{0044}', '\u{007A}',
+    '\u{030C}', '\u{0064}', '\u{007A}', '\u{030C}', '\u{004C}', '\u{004A}', '\u{004C}', '\u{006A}',
+    '\u{006C}', '\u{006A}', '\u{004E}', '\u{004A}', '\u{004E}', '\u{006A}', '\u{006E}', '\u{006A}',
+    '\u{0044}', '\u{005A}', '\u{0044}', '\u{007A}', '\u{0064}', '\u{007A}', '\u{0068}', '\u{0266}',
+    '\u{006A}', '\u{0072}', '\u{0279}', '\u{027B}', '\u{0281}', '\u{0077}', '\u{0079}', '\u{0020}',
+    '\u{0306}', '\u{0020}', '\u{0307}', '\u{0020}', '\u{030A}', '\u{0020}', '\u{0328}', '\u{0020}',
+    '\u{0303}', '\u{0020}', '\u{030B}', '\u{0263}', '\u{006C}', '\u{0073}', '\u{0078}', '\u{0295}',
+    '\u{0020}', '\u{0345}', '\u{0020}', '\u{0301}', '\u{0020}', '\u{0308}', '\u{0301}', '\u{03B2}',
+    '\u{03B8}', '\u{03A5}', '\u{03A5}', '\u{0301}', '\u{03A5}', '\u{0308}', '\u{03C6}', '\u{03C0}',
+    '\u{03BA}', '\u{03C1}', '\u{03C2}', '\u{0398}', '\u{03B5}', '\u{03A3}', '\u{0565}', '\u{0582}',
+    '\u{0627}', '\u{0674}', '\u{0648}', '\u{0674}', '\u{06C7}', '\u{0674}', '\u{064A}', '\u{0674}',
+    '\u{0E4D}', '\u{0E32}', '\u{0ECD}', '\u{0EB2}', '\u{0EAB}', '\u{0E99}', '\u{0EAB}', '\u{0EA1}',
+    '\u{0F0B}', '\u{0FB2}', '\u{0F71}', '\u{0F80}', '\u{0FB3}', '\u{0F71}', '\u{0F80}', '\u{10DC}',
+    '\u{0041}', '\u{00C6}', '\u{0042}', '\u{0044}', '\u{0045}', '\u{018E}', '\u{0047}', '\u{0048}',
+    '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0222}',
+    '\u{0050}', '\u{0052}', '\u{0054}', '\u{0055}', '\u{0057}', '\u{0061}', '\u{0250}', '\u{0251}',
+    '\u{1D02}', '\u{0062}', '\u{0064}', '\u{0065}', '\u{0259}', '\u{025B}', '\u{025C}', '\u{0067}',
+    '\u{006B}', '\u{006D}', '\u{014B}', '\u{006F}', '\u{0254}', '\u{1D16}', '\u{1D17}', '\u{0070}',
+    '\u{0074}', '\u{0075}', '\u{1D1D}', '\u{026F}', '\u{0076}', '\u{1D25}', '\u{03B2}', '\u{03B3}',
+    '\u{03B4}', '\u{03C6}', '\u{03C7}', '\u{0069}', '\u{0072}', '\u{0075}', '\u{0076}', '\u{03B2}',
+    '\u{03B3}', '\u{03C1}', '\u{03C6}', '\u{03C7}', '\u{043D}', '\u{0252}', '\u{0063}', '\u{0255}',
+    '\u{00F0}', '\u{025C}', '\u{0066}', '\u{025F}', '\u{0261}', '\u{0265}', '\u{0268}', '\u{0269}',
+    '\u{026A}', '\u{1D7B}', '\u{029D}', '\u{026D}', '\u{1D85}', '\u{029F}', '\u{0271}', '\u{0270}',
+    '\u{0272}', '\u{0273}', '\u{0274}', '\u{0275}', '\u{0278}', '\u{0282}', '\u{0283}', '\u{01AB}',
+    '\u{0289}', '\u{028A}', '\u{1D1C}', '\u{028B}', '\u{028C}', '\u{007A}', '\u{0290}', '\u{0291}',
+    '\u{0292}', '\u{03B8}', '\u{0061}', '\u{02BE}', '\u{0073}', '\u{0307}', '\u{0020}', '\u{0313}',
+    '\u{0020}', '\u{0313}', '\u{0020}', '\u{0342}', '\u{0020}', '\u{0308}', '\u{0342}', '\u{0020}',
+    '\u{0313}', '\u{0300}', '\u{0020}', '\u{0313}', '\u{0301}', '\u{0020}', '\u{0313}', '\u{0342}',
+    '\u{0020}', '\u{0314}', '\u{0300}', '\u{0020}', '\u{0314}', '\u{0301}', '\u{0020}', '\u{0314}',
+    '\u{0342}', '\u{0020}', '\u{0308}', '\u{0300}', '\u{0020}', '\u{0308}', '\u{0301}', '\u{0020}',
+    '\u{0301}', '\u{0020}', '\u{0314}', '\u{0020}', '\u{0020}', '\u{0020}', '\u{0020}', '\u{0020}',
+    '\u{0020}', '\u{0020}', '\u{0020}', '\u{0020}', '\u{0020}', '\u{0020}', '\u{2010}', '\u{0020}',
+    '\u{0333}', '\u{002E}', '\u{002E}', '\u{002E}', '\u{002E}', '\u{002E}', '\u{002E}', '\u{0020}',
+    '\u{2032}', '\u{2032}', '\u{2032}', '\u{2032}', '\u{2032}', '\u{2035}', '\u{2035}', '\u{2035}',
+    '\u{2035}', '\u{2035}', '\u{0021}', '\u{0021}', '\u{0020}', '\u{0305}', '\u{003F}', '\u{003F}',
+    '\u{003F}', '\u{0021}', '\u{0021}', '\u{003F}', '\u{2032}', '\u{2032}', '\u{2032}', '\u{2032}',
+    '\u{0020}', '\u{0030}', '\u{0069}', '\u{0034}', '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}',
+    '\u{0039}', '\u{002B}', '\u{2212}', '\u{003D}', '\u{0028}', '\u{0029}', '\u{006E}', '\u{0030}',
+    '\u{0031}', '\u{0032}', '\u{0033}', '\u{0034}', '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}',
+    '\u{0039}', '\u{002B}', '\u{2212}', '\u{003D}', '\u{0028}', '\u{0029}', '\u{0061}', '\u{0065}',
+    '\u{006F}', '\u{0078}', '\u{0259}', '\u{0068}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}',
+    '\u{0070}', '\u{0073}', '\u{0074}', '\u{0052}', '\u{0073}', '\u{0061}', '\u{002F}', '\u{0063}',
+    '\u{0061}', '\u{002F}', '\u{0073}', '\u{0043}', '\u{00B0}', '\u{0043}', '\u{0063}', '\u{002F}',
+    '\u{006F}', '\u{0063}', '\u{002F}', '\u{0075}', '\u{0190}', '\u{00B0}', '\u{0046}', '\u{0067}',
+    '\u{0048}', '\u{0048}', '\u{0048}', '\u{0068}', '\u{0127}', '\u{0049}', '\u{0049}', '\u{004C}',
+    '\u{006C}', '\u{004E}', '\u{004E}', '\u{006F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0052}',
+    '\u{0052}', '\u{0053}', '\u{004D}', '\u{0054}', '\u{0045}', '\u{004C}', '\u{0054}', '\u{004D}',
+    '\u{005A}', '\u{005A}', '\u{0042}', '\u{0043}', '\u{0065}', '\u{0045}', '\u{0046}', '\u{004D}',
+    '\u{006F}', '\u{05D0}', '\u{05D1}', '\u{05D2}', '\u{05D3}', '\u{0069}', '\u{0046}', '\u{0041}',
+    '\u{0058}', '\u{03C0}', '\u{03B3}', '\u{0393}', '\u{03A0}', '\u{2211}', '\u{0044}', '\u{0064}',
+    '\u{0065}', '\u{0069}', '\u{006A}', '\u{0031}', '\u{2044}', '\u{0037}', '\u{0031}', '\u{2044}',
+    '\u{0039}', '\u{0031}', '\u{2044}', '\u{0031}', '\u{0030}', '\u{0031}', '\u{2044}', '\u{0033}',
+    '\u{0032}', '\u{2044}', '\u{0033}', '\u{0031}', '\u{2044}', '\u{0035}', '\u{0032}', '\u{2044}',
+    '\u{0035}', '\u{0033}', '\u{2044}', '\u{0035}', '\u{0034}', '\u{2044}', '\u{0035}', '\u{0031}',
+    '\u{2044}', '\u{0036}', '\u{0035}', '\u{2044}', '\u{0036}', '\u{0031}', '\u{2044}', '\u{0038}',
+    '\u{0033}', '\u{2044}', '\u{0038}', '\u{0035}', '\u{2044}', '\u{0038}', '\u{0037}', '\u{2044}',
+    '\u{0038}', '\u{0031}', '\u{2044}', '\u{0049}', '\u{0049}', '\u{0049}', '\u{0049}', '\u{0049}',
+    '\u{0049}', '\u{0049}', '\u{0056}', '\u{0056}', '\u{0056}', '\u{0049}', '\u{0056}', '\u{0049}',
+    '\u{0049}', '\u{0056}', '\u{0049}', '\u{0049}', '\u{0049}', '\u{0049}', '\u{0058}', '\u{0058}',
+    '\u{0058}', '\u{0049}', '\u{0058}', '\u{0049}', '\u{0049}', '\u{004C}', '\u{0043}', '\u{0044}',
+    '\u{004D}', '\u{0069}', '\u{0069}', '\u{0069}', '\u{0069}', '\u{0069}', '\u{0069}', '\u{0069}',
+    '\u{0076}', '\u{0076}', '\u{0076}', '\u{0069}', '\u{0076}', '\u{0069}', '\u{0069}', '\u{0076}',
+    '\u{0069}', '\u{0069}', '\u{0069}', '\u{0069}', '\u{0078}', '\u{0078}', '\u{0078}', '\u{0069}',
+    '\u{0078}', '\u{0069}', '\u{0069}', '\u{006C}', '\u{0063}', '\u{0064}', '\u{006D}', '\u{0030}',
+    '\u{2044}', '\u{0033}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222E}',
+    '\u{222E}', '\u{222E}', '\u{222E}', '\u{222E}', '\u{0031}', '\u{0032}', '\u{0033}', '\u{0034}',
+    '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}', '\u{0039}', '\u{0031}', '\u{0030}', '\u{0031}',
+    '\u{0031}', '\u{0031}', '\u{0032}', '\u{0031}', '\u{0033}', '\u{0031}', '\u{0034}', '\u{0031}',
+    '\u{0035}', '\u{0031}', '\u{0036}', '\u{0031}', '\u{0037}', '\u{0031}', '\u{0038}', '\u{0031}',
+    '\u{0039}', '\u{0032}', '\u{0030}', '\u{0028}', '\u{0031}', '\u{0029}', '\u{0028}', '\u{0032}',
+    '\u{0029}', '\u{0028}', '\u{0033}', '\u{0029}', '\u{0028}', '\u{0034}', '\u{0029}', '\u{0028}',
+    '\u{0035}', '\u{0029}', '\u{0028}', '\u{0036}', '\u{0029}', '\u{0028}', '\u{0037}', '\u{0029}',
+    '\u{0028}', '\u{0038}', '\u{0029}', '\u{0028}', '\u{0039}', '\u{0029}', '\u{0028}', '\u{0031}',
+    '\u{0030}', '\u{0029}', '\u{0028}', '\u{0031}', '\u{0031}', '\u{0029}', '\u{0028}', '\u{0031}',
+    '\u{0032}', '\u{0029}', '\u{0028}', '\u{0031}', '\u{0033}', '\u{0029}', '\u{0028}', '\u{0031}',
+    '\u{0034}', '\u{0029}', '\u{0028}', '\u{0031}', '\u{0035}', '\u{0029}', '\u{0028}', '\u{0031}',
+    '\u{0036}', '\u{0029}', '\u{0028}', '\u{0031}', '\u{0037}', '\u{0029}', '\u{0028}', '\u{0031}',
+    '\u{0038}', '\u{0029}', '\u{0028}', '\u{0031}', '\u{0039}', '\u{0029}', '\u{0028}', '\u{0032}',
+    '\u{0030}', '\u{0029}', '\u{0031}', '\u{002E}', '\u{0032}', '\u{002E}', '\u{0033}', '\u{002E}',
+    '\u{0034}', '\u{002E}', '\u{0035}', '\u{002E}', '\u{0036}', '\u{002E}', '\u{0037}', '\u{002E}',
+    '\u{0038}', '\u{002E}', '\u{0039}', '\u{002E}', '\u{0031}', '\u{0030}', '\u{002E}', '\u{0031}',
+    '\u{0031}', '\u{002E}', '\u{0031}', '\u{0032}', '\u{002E}', '\u{0031}', '\u{0033}', '\u{002E}',
+    '\u{0031}', '\u{0034}', '\u{002E}', '\u{0031}', '\u{0035}', '\u{002E}', '\u{0031}', '\u{0036}',
+    '\u{002E}', '\u{0031}', '\u{0037}', '\u{002E}', '\u{0031}', '\u{0038}', '\u{002E}', '\u{0031}',
+    '\u{0039}', '\u{002E}', '\u{0032}', '\u{0030}', '\u{002E}', '\u{0028}', '\u{0061}', '\u{0029}',
+    '\u{0028}', '\u{0062}', '\u{0029}', '\u{0028}', '\u{0063}', '\u{0029}', '\u{0028}', '\u{0064}',
+    '\u{0029}', '\u{0028}', '\u{0065}', '\u{0029}', '\u{0028}', '\u{0066}', '\u{0029}', '\u{0028}',
+    '\u{0067}', '\u{0029}', '\u{0028}', '\u{0068}', '\u{0029}', '\u{0028}', '\u{0069}', '\u{0029}',
+    '\u{0028}', '\u{006A}', '\u{0029}', '\u{0028}', '\u{006B}', '\u{0029}', '\u{0028}', '\u{006C}',
+    '\u{0029}', '\u{0028}', '\u{006D}', '\u{0029}', '\u{0028}', '\u{006E}', '\u{0029}', '\u{0028}',
+    '\u{006F}', '\u{0029}', '\u{0028}', '\u{0070}', '\u{0029}', '\u{0028}', '\u{0071}', '\u{0029}',
+    '\u{0028}', '\u{0072}', '\u{0029}', '\u{0028}', '\u{0073}', '\u{0029}', '\u{0028}', '\u{0074}',
+    '\u{0029}', '\u{0028}', '\u{0075}', '\u{0029}', '\u{0028}', '\u{0076}', '\u{0029}', '\u{0028}',
+    '\u{0077}', '\u{0029}', '\u{0028}', '\u{0078}', '\u{0029}', '\u{0028}', '\u{0079}', '\u{0029}',
+    '\u{0028}', '\u{007A}', '\u{0029}', '\u{0041}', '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}',
+    '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}',
+    '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}',
+    '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}', '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}',
+    '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}',
+    '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}',
+    '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0030}',
+    '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{003A}', '\u{003A}', '\u{003D}', '\u{003D}',
+    '\u{003D}', '\u{003D}', '\u{003D}', '\u{003D}', '\u{006A}', '\u{0056}', '\u{2D61}', '\u{6BCD}',
+    '\u{9F9F}', '\u{4E00}', '\u{4E28}', '\u{4E36}', '\u{4E3F}', '\u{4E59}', '\u{4E85}', '\u{4E8C}',
+    '\u{4EA0}', '\u{4EBA}', '\u{513F}', '\u{5165}', '\u{516B}', '\u{5182}', '\u{5196}', '\u{51AB}',
+    '\u{51E0}', '\u{51F5}', '\u{5200}', '\u{529B}', '\u{52F9}', '\u{5315}', '\u{531A}', '\u{5338}',
+    '\u{5341}', '\u{535C}', '\u{5369}', '\u{5382}', '\u{53B6}', '\u{53C8}', '\u{53E3}', '\u{56D7}',
+    '\u{571F}', '\u{58EB}', '\u{5902}', '\u{590A}', '\u{5915}', '\u{5927}', '\u{5973}', '\u{5B50}',
+    '\u{5B80}', '\u{5BF8}', '\u{5C0F}', '\u{5C22}', '\u{5C38}', '\u{5C6E}', '\u{5C71}', '\u{5DDB}',
+    '\u{5DE5}', '\u{5DF1}', '\u{5DFE}', '\u{5E72}', '\u{5E7A}', '\u{5E7F}', '\u{5EF4}', '\u{5EFE}',
+    '\u{5F0B}', '\u{5F13}', '\u{5F50}', '\u{5F61}', '\u{5F73}', '\u{5FC3}', '\u{6208}', '\u{6236}',
+    '\u{624B}', '\u{652F}', '\u{6534}', '\u{6587}', '\u{6597}', '\u{65A4}', '\u{65B9}', '\u{65E0}',
+    '\u{65E5}', '\u{66F0}', '\u{6708}', '\u{6728}', '\u{6B20}', '\u{6B62}', '\u{6B79}', '\u{6BB3}',
+    '\u{6BCB}', '\u{6BD4}', '\u{6BDB}', '\u{6C0F}', '\u{6C14}', '\u{6C34}', '\u{706B}', '\u{722A}',
+    '\u{7236}', '\u{723B}', '\u{723F}', '\u{7247}', '\u{7259}', '\u{725B}', '\u{72AC}', '\u{7384}',
+    '\u{7389}', '\u{74DC}', '\u{74E6}', '\u{7518}', '\u{751F}', '\u{7528}', '\u{7530}', '\u{758B}',
+    '\u{7592}', '\u{7676}', '\u{767D}', '\u{76AE}', '\u{76BF}', '\u{76EE}', '\u{77DB}', '\u{77E2}',
+    '\u{77F3}', '\u{793A}', '\u{79B8}', '\u{79BE}', '\u{7A74}', '\u{7ACB}', '\u{7AF9}', '\u{7C73}',
+    '\u{7CF8}', '\u{7F36}', '\u{7F51}', '\u{7F8A}', '\u{7FBD}', '\u{8001}', '\u{800C}', '\u{8012}',
+    '\u{8033}', '\u{807F}', '\u{8089}', '\u{81E3}', '\u{81EA}', '\u{81F3}', '\u{81FC}', '\u{820C}',
+    '\u{821B}', '\u{821F}', '\u{826E}', '\u{8272}', '\u{8278}', '\u{864D}', '\u{866B}', '\u{8840}',
+    '\u{884C}', '\u{8863}', '\u{897E}', '\u{898B}', '\u{89D2}', '\u{8A00}', '\u{8C37}', '\u{8C46}',
+    '\u{8C55}', '\u{8C78}', '\u{8C9D}', '\u{8D64}', '\u{8D70}', '\u{8DB3}', '\u{8EAB}', '\u{8ECA}',
+    '\u{8F9B}', '\u{8FB0}', '\u{8FB5}', '\u{9091}', '\u{9149}', '\u{91C6}', '\u{91CC}', '\u{91D1}',
+    '\u{9577}', '\u{9580}', '\u{961C}', '\u{96B6}', '\u{96B9}', '\u{96E8}', '\u{9751}', '\u{975E}',
+    '\u{9762}', '\u{9769}', '\u{97CB}', '\u{97ED}', '\u{97F3}', '\u{9801}', '\u{98A8}', '\u{98DB}',
+    '\u{98DF}', '\u{9996}', '\u{9999}', '\u{99AC}', '\u{9AA8}', '\u{9AD8}', '\u{9ADF}', '\u{9B25}',
+    '\u{9B2F}', '\u{9B32}', '\u{9B3C}', '\u{9B5A}', '\u{9CE5}', '\u{9E75}', '\u{9E7F}', '\u{9EA5}',
+    '\u{9EBB}', '\u{9EC3}', '\u{9ECD}', '\u{9ED1}', '\u{9EF9}', '\u{9EFD}', '\u{9F0E}', '\u{9F13}',
+    '\u{9F20}', '\u{9F3B}', '\u{9F4A}', '\u{9F52}', '\u{9F8D}', '\u{9F9C}', '\u{9FA0}', '\u{0020}',
+    '\u{3012}', '\u{5341}', '\u{5344}', '\u{5345}', '\u{0020}', '\u{3099}', '\u{0020}', '\u{309A}',
+    '\u{3088}', '\u{308A}', '\u{30B3}', '\u{30C8}', '\u{1100}', '\u{1101}', '\u{11AA}', '\u{1102}',
+    '\u{11AC}', '\u{11AD}', '\u{1103}', '\u{1104}', '\u{1105}', '\u{11B0}', '\u{11B1}', '\u{11B2}',
+    '\u{11B3}', '\u{11B4}', '\u{11B5}', '\u{111A}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}',
+    '\u{1109}', '\u{110A}', '\u{110B}', '\u{110C}', '\u{110D}', '\u{110E}', '\u{110F}', '\u{1110}',
+    '\u{1111}', '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}',
+    '\u{1167}', '\u{1168}', '\u{1169}', '\u{116A}', '\u{116B}', '\u{116C}', '\u{116D}', '\u{116E}',
+    '\u{116F}', '\u{1170}', '\u{1171}', '\u{1172}', '\u{1173}', '\u{1174}', '\u{1175}', '\u{1160}',
+    '\u{1114}', '\u{1115}', '\u{11C7}', '\u{11C8}', '\u{11CC}', '\u{11CE}', '\u{11D3}', '\u{11D7}',
+    '\u{11D9}', '\u{111C}', '\u{11DD}', '\u{11DF}', '\u{111D}', '\u{111E}', '\u{1120}', '\u{1122}',
+    '\u{1123}', '\u{1127}', '\u{1129}', '\u{112B}', '\u{112C}', '\u{112D}', '\u{112E}', '\u{112F}',
+    '\u{1132}', '\u{1136}', '\u{1140}', '\u{1147}', '\u{114C}', '\u{11F1}', '\u{11F2}', '\u{1157}',
+    '\u{1158}', '\u{1159}', '\u{1184}', '\u{1185}', '\u{1188}', '\u{1191}', '\u{1192}', '\u{1194}',
+    '\u{119E}', '\u{11A1}', '\u{4E00}', '\u{4E8C}', '\u{4E09}', '\u{56DB}', '\u{4E0A}', '\u{4E2D}',
+    '\u{4E0B}', '\u{7532}', '\u{4E59}', '\u{4E19}', '\u{4E01}', '\u{5929}', '\u{5730}', '\u{4EBA}',
+    '\u{0028}', '\u{1100}', '\u{0029}', '\u{0028}', '\u{1102}', '\u{0029}', '\u{0028}', '\u{1103}',
+    '\u{0029}', '\u{0028}', '\u{1105}', '\u{0029}', '\u{0028}', '\u{1106}', '\u{0029}', '\u{0028}',
+    '\u{1107}', '\u{0029}', '\u{0028}', '\u{1109}', '\u{0029}', '\u{0028}', '\u{110B}', '\u{0029}',
+    '\u{0028}', '\u{110C}', '\u{0029}', '\u{0028}', '\u{110E}', '\u{0029}', '\u{0028}', '\u{110F}',
+    '\u{0029}', '\u{0028}', '\u{1110}', '\u{0029}', '\u{0028}', '\u{1111}', '\u{0029}', '\u{0028}',
+    '\u{1112}', '\u{0029}', '\u{0028}', '\u{1100}', '\u{1161}', '\u{0029}', '\u{0028}', '\u{1102}',
+    '\u{1161}', '\u{0029}', '\u{0028}', '\u{1103}', '\u{1161}', '\u{0029}', '\u{0028}', '\u{1105}',
+    '\u{1161}', '\u{0029}', '\u{0028}', '\u{1106}', '\u{1161}', '\u{0029}', '\u{0028}', '\u{1107}',
+    '\u{1161}', '\u{0029}', '\u{0028}', '\u{1109}', '\u{1161}', '\u{0029}', '\u{0028}', '\u{110B}',
+    '\u{1161}', '\u{0029}', '\u{0028}', '\u{110C}', '\u{1161}', '\u{0029}', '\u{0028}', '\u{110E}',
+    '\u{1161}', '\u{0029}', '\u{0028}', '\u{110F}', '\u{1161}', '\u{0029}', '\u{0028}', '\u{1110}',
+    '\u{1161}', '\u{0029}', '\u{0028}', '\u{1111}', '\u{1161}', '\u{0029}', '\u{0028}', '\u{1112}',
+    '\u{1161}', '\u{0029}', '\u{0028}', '\u{110C}', '\u{116E}', '\u{0029}', '\u{0028}', '\u{110B}',
+    '\u{1169}', '\u{110C}', '\u{1165}', '\u{11AB}', '\u{0029}', '\u{0028}', '\u{110B}', '\u{1169}',
+    '\u{1112}', '\u{116E}', '\u{0029}', '\u{0028}', '\u{4E00}', '\u{0029}', '\u{0028}', '\u{4E8C}',
+    '\u{0029}', '\u{0028}', '\u{4E09}', '\u{0029}', '\u{0028}', '\u{56DB}', '\u{0029}', '\u{0028}',
+    '\u{4E94}', '\u{0029}', '\u{0028}', '\u{516D}', '\u{0029}', '\u{0028}', '\u{4E03}', '\u{0029}',
+    '\u{0028}', '\u{516B}', '\u{0029}', '\u{0028}', '\u{4E5D}', '\u{0029}', '\u{0028}', '\u{5341}',
+    '\u{0029}', '\u{0028}', '\u{6708}', '\u{0029}', '\u{0028}', '\u{706B}', '\u{0029}', '\u{0028}',
+    '\u{6C34}', '\u{0029}', '\u{0028}', '\u{6728}', '\u{0029}', '\u{0028}', '\u{91D1}', '\u{0029}',
+    '\u{0028}', '\u{571F}', '\u{0029}', '\u{0028}', '\u{65E5}', '\u{0029}', '\u{0028}', '\u{682A}',
+    '\u{0029}', '\u{0028}', '\u{6709}', '\u{0029}', '\u{0028}', '\u{793E}', '\u{0029}', '\u{0028}',
+    '\u{540D}', '\u{0029}', '\u{0028}', '\u{7279}', '\u{0029}', '\u{0028}', '\u{8CA1}', '\u{0029}',
+    '\u{0028}', '\u{795D}', '\u{0029}', '\u{0028}', '\u{52B4}', '\u{0029}', '\u{0028}', '\u{4EE3}',
+    '\u{0029}', '\u{0028}', '\u{547C}', '\u{0029}', '\u{0028}', '\u{5B66}', '\u{0029}', '\u{0028}',
+    '\u{76E3}', '\u{0029}', '\u{0028}', '\u{4F01}', '\u{0029}', '\u{0028}', '\u{8CC7}', '\u{0029}',
+    '\u{0028}', '\u{5354}', '\u{0029}', '\u{0028}', '\u{796D}', '\u{0029}', '\u{0028}', '\u{4F11}',
+    '\u{0029}', '\u{0028}', '\u{81EA}', '\u{0029}', '\u{0028}', '\u{81F3}', '\u{0029}', '\u{554F}',
+    '\u{5E7C}', '\u{6587}', '\u{7B8F}', '\u{0050}', '\u{0054}', '\u{0045}', '\u{0032}', '\u{0031}',
+    '\u{0032}', '\u{0032}', '\u{0032}', '\u{0033}', '\u{0032}', '\u{0034}', '\u{0032}', '\u{0035}',
+    '\u{0032}', '\u{0036}', '\u{0032}', '\u{0037}', '\u{0032}', '\u{0038}', '\u{0032}', '\u{0039}',
+    '\u{0033}', '\u{0030}', '\u{0033}', '\u{0031}', '\u{0033}', '\u{0032}', '\u{0033}', '\u{0033}',
+    '\u{0033}', '\u{0034}', '\u{0033}', '\u{0035}', '\u{1100}', '\u{1102}', '\u{1103}', '\u{1105}',
+    '\u{1106}', '\u{1107}', '\u{1109}', '\u{110B}', '\u{110C}', '\u{110E}', '\u{110F}', '\u{1110}',
+    '\u{1111}', '\u{1112}', '\u{1100}', '\u{1161}', '\u{1102}', '\u{1161}', '\u{1103}', '\u{1161}',
+    '\u{1105}', '\u{1161}', '\u{1106}', '\u{1161}', '\u{1107}', '\u{1161}', '\u{1109}', '\u{1161}',
+    '\u{110B}', '\u{1161}', '\u{110C}', '\u{1161}', '\u{110E}', '\u{1161}', '\u{110F}', '\u{1161}',
+    '\u{1110}', '\u{1161}', '\u{1111}', '\u{1161}', '\u{1112}', '\u{1161}', '\u{110E}', '\u{1161}',
+    '\u{11B7}', '\u{1100}', '\u{1169}', '\u{110C}', '\u{116E}', '\u{110B}', '\u{1174}', '\u{110B}',
+    '\u{116E}', '\u{4E00}', '\u{4E8C}', '\u{4E09}', '\u{56DB}', '\u{4E94}', '\u{516D}', '\u{4E03}',
+    '\u{516B}', '\u{4E5D}', '\u{5341}', '\u{6708}', '\u{706B}', '\u{6C34}', '\u{6728}', '\u{91D1}',
+    '\u{571F}', '\u{65E5}', '\u{682A}', '\u{6709}', '\u{793E}', '\u{540D}', '\u{7279}', '\u{8CA1}',
+    '\u{795D}', '\u{52B4}', '\u{79D8}', '\u{7537}', '\u{5973}', '\u{9069}', '\u{512A}', '\u{5370}',
+    '\u{6CE8}', '\u{9805}', '\u{4F11}', '\u{5199}', '\u{6B63}', '\u{4E0A}', '\u{4E2D}', '\u{4E0B}',
+    '\u{5DE6}', '\u{53F3}', '\u{533B}', '\u{5B97}', '\u{5B66}', '\u{76E3}', '\u{4F01}', '\u{8CC7}',
+    '\u{5354}', '\u{591C}', '\u{0033}', '\u{0036}', '\u{0033}', '\u{0037}', '\u{0033}', '\u{0038}',
+    '\u{0033}', '\u{0039}', '\u{0034}', '\u{0030}', '\u{0034}', '\u{0031}', '\u{0034}', '\u{0032}',
+    '\u{0034}', '\u{0033}', '\u{0034}', '\u{0034}', '\u{0034}', '\u{0035}', '\u{0034}', '\u{0036}',
+    '\u{0034}', '\u{0037}', '\u{0034}', '\u{0038}', '\u{0034}', '\u{0039}', '\u{0035}', '\u{0030}',
+    '\u{0031}', '\u{6708}', '\u{0032}', '\u{6708}', '\u{0033}', '\u{6708}', '\u{0034}', '\u{6708}',
+    '\u{0035}', '\u{6708}', '\u{0036}', '\u{6708}', '\u{0037}', '\u{6708}', '\u{0038}', '\u{6708}',
+    '\u{0039}', '\u{6708}', '\u{0031}', '\u{0030}', '\u{6708}', '\u{0031}', '\u{0031}', '\u{6708}',
+    '\u{0031}', '\u{0032}', '\u{6708}', '\u{0048}', '\u{0067}', '\u{0065}', '\u{0072}', '\u{0067}',
+    '\u{0065}', '\u{0056}', '\u{004C}', '\u{0054}', '\u{0044}', '\u{30A2}', '\u{30A4}', '\u{30A6}',
+    '\u{30A8}', '\u{30AA}', '\u{30AB}', '\u{30AD}', '\u{30AF}', '\u{30B1}', '\u{30B3}', '\u{30B5}',
+    '\u{30B7}', '\u{30B9}', '\u{30BB}', '\u{30BD}', '\u{30BF}', '\u{30C1}', '\u{30C4}', '\u{30C6}',
+    '\u{30C8}', '\u{30CA}', '\u{30CB}', '\u{30CC}', '\u{30CD}', '\u{30CE}', '\u{30CF}', '\u{30D2}',
+    '\u{30D5}', '\u{30D8}', '\u{30DB}', '\u{30DE}', '\u{30DF}', '\u{30E0}', '\u{30E1}', '\u{30E2}',
+    '\u{30E4}', '\u{30E6}', '\u{30E8}', '\u{30E9}', '\u{30EA}', '\u{30EB}', '\u{30EC}', '\u{30ED}',
+    '\u{30EF}', '\u{30F0}', '\u{30F1}', '\u{30F2}', '\u{4EE4}', '\u{548C}', '\u{30A2}', '\u{30CF}',
+    '\u{309A}', '\u{30FC}', '\u{30C8}', '\u{30A2}', '\u{30EB}', '\u{30D5}', '\u{30A1}', '\u{30A2}',
+    '\u{30F3}', '\u{30D8}', '\u{309A}', '\u{30A2}', '\u{30A2}', '\u{30FC}', '\u{30EB}', '\u{30A4}',
+    '\u{30CB}', '\u{30F3}', '\u{30AF}', '\u{3099}', '\u{30A4}', '\u{30F3}', '\u{30C1}', '\u{30A6}',
+    '\u{30A9}', '\u{30F3}', '\u{30A8}', '\u{30B9}', '\u{30AF}', '\u{30FC}', '\u{30C8}', '\u{3099}',
+    '\u{30A8}', '\u{30FC}', '\u{30AB}', '\u{30FC}', '\u{30AA}', '\u{30F3}', '\u{30B9}', '\u{30AA}',
+    '\u{30FC}', '\u{30E0}', '\u{30AB}', '\u{30A4}', '\u{30EA}', '\u{30AB}', '\u{30E9}', '\u{30C3}',
+    '\u{30C8}', '\u{30AB}', '\u{30ED}', '\u{30EA}', '\u{30FC}', '\u{30AB}', '\u{3099}', '\u{30ED}',
+    '\u{30F3}', '\u{30AB}', '\u{3099}', '\u{30F3}', '\u{30DE}', '\u{30AD}', '\u{3099}', '\u{30AB}',
+    '\u{3099}', '\u{30AD}', '\u{3099}', '\u{30CB}', '\u{30FC}', '\u{30AD}', '\u{30E5}', '\u{30EA}',
+    '\u{30FC}', '\u{30AD}', '\u{3099}', '\u{30EB}', '\u{30BF}', '\u{3099}', '\u{30FC}', '\u{30AD}',
+    '\u{30ED}', '\u{30AD}', '\u{30ED}', '\u{30AF}', '\u{3099}', '\u{30E9}', '\u{30E0}', '\u{30AD}',
+    '\u{30ED}', '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}', '\u{30AD}', '\u{30ED}', '\u{30EF}',
+    '\u{30C3}', '\u{30C8}', '\u{30AF}', '\u{3099}', '\u{30E9}', '\u{30E0}', '\u{30AF}', '\u{3099}',
+    '\u{30E9}', '\u{30E0}', '\u{30C8}', '\u{30F3}', '\u{30AF}', '\u{30EB}', '\u{30BB}', '\u{3099}',
+    '\u{30A4}', '\u{30ED}', '\u{30AF}', '\u{30ED}', '\u{30FC}', '\u{30CD}', '\u{30B1}', '\u{30FC}',
+    '\u{30B9}', '\u{30B3}', '\u{30EB}', '\u{30CA}', '\u{30B3}', '\u{30FC}', '\u{30DB}', '\u{309A}',
+    '\u{30B5}', '\u{30A4}', '\u{30AF}', '\u{30EB}', '\u{30B5}', '\u{30F3}', '\u{30C1}', '\u{30FC}',
+    '\u{30E0}', '\u{30B7}', '\u{30EA}', '\u{30F3}', '\u{30AF}', '\u{3099}', '\u{30BB}', '\u{30F3}',
+    '\u{30C1}', '\u{30BB}', '\u{30F3}', '\u{30C8}', '\u{30BF}', '\u{3099}', '\u{30FC}', '\u{30B9}',
+    '\u{30C6}', '\u{3099}', '\u{30B7}', '\u{30C8}', '\u{3099}', '\u{30EB}', '\u{30C8}', '\u{30F3}',
+    '\u{30CA}', '\u{30CE}', '\u{30CE}', '\u{30C3}', '\u{30C8}', '\u{30CF}', '\u{30A4}', '\u{30C4}',
+    '\u{30CF}', '\u{309A}', '\u{30FC}', '\u{30BB}', '\u{30F3}', '\u{30C8}', '\u{30CF}', '\u{309A}',
+    '\u{30FC}', '\u{30C4}', '\u{30CF}', '\u{3099}', '\u{30FC}', '\u{30EC}', '\u{30EB}', '\u{30D2}',
+    '\u{309A}', '\u{30A2}', '\u{30B9}', '\u{30C8}', '\u{30EB}', '\u{30D2}', '\u{309A}', '\u{30AF}',
+    '\u{30EB}', '\u{30D2}', '\u{309A}', '\u{30B3}', '\u{30D2}', '\u{3099}', '\u{30EB}', '\u{30D5}',
+    '\u{30A1}', '\u{30E9}', '\u{30C3}', '\u{30C8}', '\u{3099}', '\u{30D5}', '\u{30A3}', '\u{30FC}',
+    '\u{30C8}', '\u{30D5}', '\u{3099}', '\u{30C3}', '\u{30B7}', '\u{30A7}', '\u{30EB}', '\u{30D5}',
+    '\u{30E9}', '\u{30F3}', '\u{30D8}', '\u{30AF}', '\u{30BF}', '\u{30FC}', '\u{30EB}', '\u{30D8}',
+    '\u{309A}', '\u{30BD}', '\u{30D8}', '\u{309A}', '\u{30CB}', '\u{30D2}', '\u{30D8}', '\u{30EB}',
+    '\u{30C4}', '\u{30D8}', '\u{309A}', '\u{30F3}', '\u{30B9}', '\u{30D8}', '\u{309A}', '\u{30FC}',
+    '\u{30B7}', '\u{3099}', '\u{30D8}', '\u{3099}', '\u{30FC}', '\u{30BF}', '\u{30DB}', '\u{309A}',
+    '\u{30A4}', '\u{30F3}', '\u{30C8}', '\u{30DB}', '\u{3099}', '\u{30EB}', '\u{30C8}', '\u{30DB}',
+    '\u{30F3}', '\u{30DB}', '\u{309A}', '\u{30F3}', '\u{30C8}', '\u{3099}', '\u{30DB}', '\u{30FC}',
+    '\u{30EB}', '\u{30DB}', '\u{30FC}', '\u{30F3}', '\u{30DE}', '\u{30A4}', '\u{30AF}', '\u{30ED}',
+    '\u{30DE}', '\u{30A4}', '\u{30EB}', '\u{30DE}', '\u{30C3}', '\u{30CF}', '\u{30DE}', '\u{30EB}',
+    '\u{30AF}', '\u{30DE}', '\u{30F3}', '\u{30B7}', '\u{30E7}', '\u{30F3}', '\u{30DF}', '\u{30AF}',
+    '\u{30ED}', '\u{30F3}', '\u{30DF}', '\u{30EA}', '\u{30DF}', '\u{30EA}', '\u{30CF}', '\u{3099}',
+    '\u{30FC}', '\u{30EB}', '\u{30E1}', '\u{30AB}', '\u{3099}', '\u{30E1}', '\u{30AB}', '\u{3099}',
+    '\u{30C8}', '\u{30F3}', '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}', '\u{30E4}', '\u{30FC}',
+    '\u{30C8}', '\u{3099}', '\u{30E4}', '\u{30FC}', '\u{30EB}', '\u{30E6}', '\u{30A2}', '\u{30F3}',
+    '\u{30EA}', '\u{30C3}', '\u{30C8}', '\u{30EB}', '\u{30EA}', '\u{30E9}', '\u{30EB}', '\u{30D2}',
+    '\u{309A}', '\u{30FC}', '\u{30EB}', '\u{30FC}', '\u{30D5}', '\u{3099}', '\u{30EB}', '\u{30EC}',
+    '\u{30E0}', '\u{30EC}', '\u{30F3}', '\u{30C8}', '\u{30B1}', '\u{3099}', '\u{30F3}', '\u{30EF}',
+    '\u{30C3}', '\u{30C8}', '\u{0030}', '\u{70B9}', '\u{0031}', '\u{70B9}', '\u{0032}', '\u{70B9}',
+    '\u{0033}', '\u{70B9}', '\u{0034}', '\u{70B9}', '\u{0035}', '\u{70B9}', '\u{0036}', '\u{70B9}',
+    '\u{0037}', '\u{70B9}', '\u{0038}', '\u{70B9}', '\u{0039}', '\u{70B9}', '\u{0031}', '\u{0030}',
+    '\u{70B9}', '\u{0031}', '\u{0031}', '\u{70B9}', '\u{0031}', '\u{0032}', '\u{70B9}', '\u{0031}',
+    '\u{0033}', '\u{70B9}', '\u{0031}', '\u{0034}', '\u{70B9}', '\u{0031}', '\u{0035}', '\u{70B9}',
+    '\u{0031}', '\u{0036}', '\u{70B9}', '\u{0031}', '\u{0037}', '\u{70B9}', '\u{0031}', '\u{0038}',
+    '\u{70B9}', '\u{0031}', '\u{0039}', '\u{70B9}', '\u{0032}', '\u{0030}', '\u{70B9}', '\u{0032}',
+    '\u{0031}', '\u{70B9}', '\u{0032}', '\u{0032}', '\u{70B9}', '\u{0032}', '\u{0033}', '\u{70B9}',
+    '\u{0032}', '\u{0034}', '\u{70B9}', '\u{0068}', '\u{0050}', '\u{0061}', '\u{0064}', '\u{0061}',
+    '\u{0041}', '\u{0055}', '\u{0062}', '\u{0061}', '\u{0072}', '\u{006F}', '\u{0056}', '\u{0070}',
+    '\u{0063}', '\u{0064}', '\u{006D}', '\u{0064}', '\u{006D}', '\u{0032}', '\u{0064}', '\u{006D}',
+    '\u{0033}', '\u{0049}', '\u{0055}', '\u{5E73}', '\u{6210}', '\u{662D}', '\u{548C}', '\u{5927}',
+    '\u{6B63}', '\u{660E}', '\u{6CBB}', '\u{682A}', '\u{5F0F}', '\u{4F1A}', '\u{793E}', '\u{0070}',
+    '\u{0041}', '\u{006E}', '\u{0041}', '\u{03BC}', '\u{0041}', '\u{006D}', '\u{0041}', '\u{006B}',
+    '\u{0041}', '\u{004B}', '\u{0042}', '\u{004D}', '\u{0042}', '\u{0047}', '\u{0042}', '\u{0063}',
+    '\u{0061}', '\u{006C}', '\u{006B}', '\u{0063}', '\u{0061}', '\u{006C}', '\u{0070}', '\u{0046}',
+    '\u{006E}', '\u{0046}', '\u{03BC}', '\u{0046}', '\u{03BC}', '\u{0067}', '\u{006D}', '\u{0067}',
+    '\u{006B}', '\u{0067}', '\u{0048}', '\u{007A}', '\u{006B}', '\u{0048}', '\u{007A}', '\u{004D}',
+    '\u{0048}', '\u{007A}', '\u{0047}', '\u{0048}', '\u{007A}', '\u{0054}', '\u{0048}', '\u{007A}',
+    '\u{03BC}', '\u{006C}', '\u{006D}', '\u{006C}', '\u{0064}', '\u{006C}', '\u{006B}', '\u{006C}',
+    '\u{0066}', '\u{006D}', '\u{006E}', '\u{006D}', '\u{03BC}', '\u{006D}', '\u{006D}', '\u{006D}',
+    '\u{0063}', '\u{006D}', '\u{006B}', '\u{006D}', '\u{006D}', '\u{006D}', '\u{0032}', '\u{0063}',
+    '\u{006D}', '\u{0032}', '\u{006D}', '\u{0032}', '\u{006B}', '\u{006D}', '\u{0032}', '\u{006D}',
+    '\u{006D}', '\u{0033}', '\u{0063}', '\u{006D}', '\u{0033}', '\u{006D}', '\u{0033}', '\u{006B}',
+    '\u{006D}', '\u{0033}', '\u{006D}', '\u{2215}', '\u{0073}', '\u{006D}', '\u{2215}', '\u{0073}',
+    '\u{0032}', '\u{0050}', '\u{0061}', '\u{006B}', '\u{0050}', '\u{0061}', '\u{004D}', '\u{0050}',
+    '\u{0061}', '\u{0047}', '\u{0050}', '\u{0061}', '\u{0072}', '\u{0061}', '\u{0064}', '\u{0072}',
+    '\u{0061}', '\u{0064}', '\u{2215}', '\u{0073}', '\u{0072}', '\u{0061}', '\u{0064}', '\u{2215}',
+    '\u{0073}', '\u{0032}', '\u{0070}', '\u{0073}', '\u{006E}', '\u{0073}', '\u{03BC}', '\u{0073}',
+    '\u{006D}', '\u{0073}', '\u{0070}', '\u{0056}', '\u{006E}', '\u{0056}', '\u{03BC}', '\u{0056}',
+    '\u{006D}', '\u{0056}', '\u{006B}', '\u{0056}', '\u{004D}', '\u{0056}', '\u{0070}', '\u{0057}',
+    '\u{006E}', '\u{0057}', '\u{03BC}', '\u{0057}', '\u{006D}', '\u{0057}', '\u{006B}', '\u{0057}',
+    '\u{004D}', '\u{0057}', '\u{006B}', '\u{03A9}', '\u{004D}', '\u{03A9}', '\u{0061}', '\u{002E}',
+    '\u{006D}', '\u{002E}', '\u{0042}', '\u{0071}', '\u{0063}', '\u{0063}', '\u{0063}', '\u{0064}',
+    '\u{0043}', '\u{2215}', '\u{006B}', '\u{0067}', '\u{0043}', '\u{006F}', '\u{002E}', '\u{0064}',
+    '\u{0042}', '\u{0047}', '\u{0079}', '\u{0068}', '\u{0061}', '\u{0048}', '\u{0050}', '\u{0069}',
+    '\u{006E}', '\u{004B}', '\u{004B}', '\u{004B}', '\u{004D}', '\u{006B}', '\u{0074}', '\u{006C}',
+    '\u{006D}', '\u{006C}', '\u{006E}', '\u{006C}', '\u{006F}', '\u{0067}', '\u{006C}', '\u{0078}',
+    '\u{006D}', '\u{0062}', '\u{006D}', '\u{0069}', '\u{006C}', '\u{006D}', '\u{006F}', '\u{006C}',
+    '\u{0050}', '\u{0048}', '\u{0070}', '\u{002E}', '\u{006D}', '\u{002E}', '\u{0050}', '\u{0050}',
+    '\u{004D}', '\u{0050}', '\u{0052}', '\u{0073}', '\u{0072}', '\u{0053}', '\u{0076}', '\u{0057}',
+    '\u{0062}', '\u{0056}', '\u{2215}', '\u{006D}', '\u{0041}', '\u{2215}', '\u{006D}', '\u{0031}',
+    '\u{65E5}', '\u{0032}', '\u{65E5}', '\u{0033}', '\u{65E5}', '\u{0034}', '\u{65E5}', '\u{0035}',
+    '\u{65E5}', '\u{0036}', '\u{65E5}', '\u{0037}', '\u{65E5}', '\u{0038}', '\u{65E5}', '\u{0039}',
+    '\u{65E5}', '\u{0031}', '\u{0030}', '\u{65E5}', '\u{0031}', '\u{0031}', '\u{65E5}', '\u{0031}',
+    '\u{0032}', '\u{65E5}', '\u{0031}', '\u{0033}', '\u{65E5}', '\u{0031}', '\u{0034}', '\u{65E5}',
+    '\u{0031}', '\u{0035}', '\u{65E5}', '\u{0031}', '\u{0036}', '\u{65E5}', '\u{0031}', '\u{0037}',
+    '\u{65E5}', '\u{0031}', '\u{0038}', '\u{65E5}', '\u{0031}', '\u{0039}', '\u{65E5}', '\u{0032}',
+    '\u{0030}', '\u{65E5}', '\u{0032}', '\u{0031}', '\u{65E5}', '\u{0032}', '\u{0032}', '\u{65E5}',
+    '\u{0032}', '\u{0033}', '\u{65E5}', '\u{0032}', '\u{0034}', '\u{65E5}', '\u{0032}', '\u{0035}',
+    '\u{65E5}', '\u{0032}', '\u{0036}', '\u{65E5}', '\u{0032}', '\u{0037}', '\u{65E5}', '\u{0032}',
+    '\u{0038}', '\u{65E5}', '\u{0032}', '\u{0039}', '\u{65E5}', '\u{0033}', '\u{0030}', '\u{65E5}',
+    '\u{0033}', '\u{0031}', '\u{65E5}', '\u{0067}', '\u{0061}', '\u{006C}', '\u{044A}', '\u{044C}',
+    '\u{A76F}', '\u{0043}', '\u{0046}', '\u{0051}', '\u{0126}', '\u{0153}', '\u{A727}', '\u{AB37}',
+    '\u{026B}', '\u{AB52}', '\u{028D}', '\u{0066}', '\u{0066}', '\u{0066}', '\u{0069}', '\u{0066}',
+    '\u{006C}', '\u{0066}', '\u{0066}', '\u{0069}', '\u{0066}', '\u{0066}', '\u{006C}', '\u{0073}',
+    '\u{0074}', '\u{0073}', '\u{0074}', '\u{0574}', '\u{0576}', '\u{0574}', '\u{0565}', '\u{0574}',
+    '\u{056B}', '\u{057E}', '\u{0576}', '\u{0574}', '\u{056D}', '\u{05E2}', '\u{05D0}', '\u{05D3}',
+    '\u{05D4}', '\u{05DB}', '\u{05DC}', '\u{05DD}', '\u{05E8}', '\u{05EA}', '\u{002B}', '\u{05D0}',
+    '\u{05DC}', '\u{0671}', '\u{0671}', '\u{067B}', '\u{067B}', '\u{067B}', '\u{067B}', '\u{067E}',
+    '\u{067E}', '\u{067E}', '\u{067E}', '\u{0680}', '\u{0680}', '\u{0680}', '\u{0680}', '\u{067A}',
+    '\u{067A}', '\u{067A}', '\u{067A}', '\u{067F}', '\u{067F}', '\u{067F}', '\u{067F}', '\u{0679}',
+    '\u{0679}', '\u{0679}', '\u{0679}', '\u{06A4}', '\u{06A4}', '\u{06A4}', '\u{06A4}', '\u{06A6}',
+    '\u{06A6}', '\u{06A6}', '\u{06A6}', '\u{0684}', '\u{0684}', '\u{0684}', '\u{0684}', '\u{0683}',
+    '\u{0683}', '\u{0683}', '\u{0683}', '\u{0686}', '\u{0686}', '\u{0686}', '\u{0686}', '\u{0687}',
+    '\u{0687}', '\u{0687}', '\u{0687}', '\u{068D}', '\u{068D}', '\u{068C}', '\u{068C}', '\u{068E}',
+    '\u{068E}', '\u{0688}', '\u{0688}', '\u{0698}', '\u{0698}', '\u{0691}', '\u{0691}', '\u{06A9}',
+    '\u{06A9}', '\u{06A9}', '\u{06A9}', '\u{06AF}', '\u{06AF}', '\u{06AF}', '\u{06AF}', '\u{06B3}',
+    '\u{06B3}', '\u{06B3}', '\u{06B3}', '\u{06B1}', '\u{06B1}', '\u{06B1}', '\u{06B1}', '\u{06BA}',
+    '\u{06BA}', '\u{06BB}', '\u{06BB}', '\u{06BB}', '\u{06BB}', '\u{06D5}', '\u{0654}', '\u{06D5}',
+    '\u{0654}', '\u{06C1}', '\u{06C1}', '\u{06C1}', '\u{06C1}', '\u{06BE}', '\u{06BE}', '\u{06BE}',
+    '\u{06BE}', '\u{06D2}', '\u{06D2}', '\u{06D2}', '\u{0654}', '\u{06D2}', '\u{0654}', '\u{06AD}',
+    '\u{06AD}', '\u{06AD}', '\u{06AD}', '\u{06C7}', '\u{06C7}', '\u{06C6}', '\u{06C6}', '\u{06C8}',
+    '\u{06C8}', '\u{06C7}', '\u{0674}', '\u{06CB}', '\u{06CB}', '\u{06C5}', '\u{06C5}', '\u{06C9}',
+    '\u{06C9}', '\u{06D0}', '\u{06D0}', '\u{06D0}', '\u{06D0}', '\u{0649}', '\u{0649}', '\u{064A}',
+    '\u{0654}', '\u{0627}', '\u{064A}', '\u{0654}', '\u{0627}', '\u{064A}', '\u{0654}', '\u{06D5}',
+    '\u{064A}', '\u{0654}', '\u{06D5}', '\u{064A}', '\u{0654}', '\u{0648}', '\u{064A}', '\u{0654}',
+    '\u{0648}', '\u{064A}', '\u{0654}', '\u{06C7}', '\u{064A}', '\u{0654}', '\u{06C7}', '\u{064A}',
+    '\u{0654}', '\u{06C6}', '\u{064A}', '\u{0654}', '\u{06C6}', '\u{064A}', '\u{0654}', '\u{06C8}',
+    '\u{064A}', '\u{0654}', '\u{06C8}', '\u{064A}', '\u{0654}', '\u{06D0}', '\u{064A}', '\u{0654}',
+    '\u{06D0}', '\u{064A}', '\u{0654}', '\u{06D0}', '\u{064A}', '\u{0654}', '\u{0649}', '\u{064A}',
+    '\u{0654}', '\u{0649}', '\u{064A}', '\u{0654}', '\u{0649}', '\u{06CC}', '\u{06CC}', '\u{06CC}',
+    '\u{06CC}', '\u{064A}', '\u{0654}', '\u{062C}', '\u{064A}', '\u{0654}', '\u{062D}', '\u{064A}',
+    '\u{0654}', '\u{0645}', '\u{064A}', '\u{0654}', '\u{0649}', '\u{064A}', '\u{0654}', '\u{064A}',
+    '\u{0628}', '\u{062C}', '\u{0628}', '\u{062D}', '\u{0628}', '\u{062E}', '\u{0628}', '\u{0645}',
+    '\u{0628}', '\u{0649}', '\u{0628}', '\u{064A}', '\u{062A}', '\u{062C}', '\u{062A}', '\u{062D}',
+    '\u{062A}', '\u{062E}', '\u{062A}', '\u{0645}', '\u{062A}', '\u{0649}', '\u{062A}', '\u{064A}',
+    '\u{062B}', '\u{062C}', '\u{062B}', '\u{0645}', '\u{062B}', '\u{0649}', '\u{062B}', '\u{064A}',
+    '\u{062C}', '\u{062D}', '\u{062C}', '\u{0645}', '\u{062D}', '\u{062C}', '\u{062D}', '\u{0645}',
+    '\u{062E}', '\u{062C}', '\u{062E}', '\u{062D}', '\u{062E}', '\u{0645}', '\u{0633}', '\u{062C}',
+    '\u{0633}', '\u{062D}', '\u{0633}', '\u{062E}', '\u{0633}', '\u{0645}', '\u{0635}', '\u{062D}',
+    '\u{0635}', '\u{0645}', '\u{0636}', '\u{062C}', '\u{0636}', '\u{062D}', '\u{0636}', '\u{062E}',
+    '\u{0636}', '\u{0645}', '\u{0637}', '\u{062D}', '\u{0637}', '\u{0645}', '\u{0638}', '\u{0645}',
+    '\u{0639}', '\u{062C}', '\u{0639}', '\u{0645}', '\u{063A}', '\u{062C}', '\u{063A}', '\u{0645}',
+    '\u{0641}', '\u{062C}', '\u{0641}', '\u{062D}', '\u{0641}', '\u{062E}', '\u{0641}', '\u{0645}',
+    '\u{0641}', '\u{0649}', '\u{0641}', '\u{064A}', '\u{0642}', '\u{062D}', '\u{0642}', '\u{0645}',
+    '\u{0642}', '\u{0649}', '\u{0642}', '\u{064A}', '\u{0643}', '\u{0627}', '\u{0643}', '\u{062C}',
+    '\u{0643}', '\u{062D}', '\u{0643}', '\u{062E}', '\u{0643}', '\u{0644}', '\u{0643}', '\u{0645}',
+    '\u{0643}', '\u{0649}', '\u{0643}', '\u{064A}', '\u{0644}', '\u{062C}', '\u{0644}', '\u{062D}',
+    '\u{0644}', '\u{062E}', '\u{0644}', '\u{0645}', '\u{0644}', '\u{0649}', '\u{0644}', '\u{064A}',
+    '\u{0645}', '\u{062C}', '\u{0645}', '\u{062D}', '\u{0645}', '\u{062E}', '\u{0645}', '\u{0645}',
+    '\u{0645}', '\u{0649}', '\u{0645}', '\u{064A}', '\u{0646}', '\u{062C}', '\u{0646}', '\u{062D}',
+    '\u{0646}', '\u{062E}', '\u{0646}', '\u{0645}', '\u{0646}', '\u{0649}', '\u{0646}', '\u{064A}',
+    '\u{0647}', '\u{062C}', '\u{0647}', '\u{0645}', '\u{0647}', '\u{0649}', '\u{0647}', '\u{064A}',
+    '\u{064A}', '\u{062C}', '\u{064A}', '\u{062D}', '\u{064A}', '\u{062E}', '\u{064A}', '\u{0645}',
+    '\u{064A}', '\u{0649}', '\u{064A}', '\u{064A}', '\u{0630}', '\u{0670}', '\u{0631}', '\u{0670}',
+    '\u{0649}', '\u{0670}', '\u{0020}', '\u{064C}', '\u{0651}', '\u{0020}', '\u{064D}', '\u{0651}',
+    '\u{0020}', '\u{064E}', '\u{0651}', '\u{0020}', '\u{064F}', '\u{0651}', '\u{0020}', '\u{0650}',
+    '\u{0651}', '\u{0020}', '\u{0651}', '\u{0670}', '\u{064A}', '\u{0654}', '\u{0631}', '\u{064A}',
+    '\u{0654}', '\u{0632}', '\u{064A}', '\u{0654}', '\u{0645}', '\u{064A}', '\u{0654}', '\u{0646}',
+    '\u{064A}', '\u{0654}', '\u{0649}', '\u{064A}', '\u{0654}', '\u{064A}', '\u{0628}', '\u{0631}',
+    '\u{0628}', '\u{0632}', '\u{0628}', '\u{0645}', '\u{0628}', '\u{0646}', '\u{0628}', '\u{0649}',
+    '\u{0628}', '\u{064A}', '\u{062A}', '\u{0631}', '\u{062A}', '\u{0632}', '\u{062A}', '\u{0645}',
+    '\u{062A}', '\u{0646}', '\u{062A}', '\u{0649}', '\u{062A}', '\u{064A}', '\u{062B}', '\u{0631}',
+    '\u{062B}', '\u{0632}', '\u{062B}', '\u{0645}', '\u{062B}', '\u{0646}', '\u{062B}', '\u{0649}',
+    '\u{062B}', '\u{064A}', '\u{0641}', '\u{0649}', '\u{0641}', '\u{064A}', '\u{0642}', '\u{0649}',
+    '\u{0642}', '\u{064A}', '\u{0643}', '\u{0627}', '\u{0643}', '\u{0644}', '\u{0643}', '\u{0645}',
+    '\u{0643}', '\u{0649}', '\u{0643}', '\u{064A}', '\u{0644}', '\u{0645}', '\u{0644}', '\u{0649}',
+    '\u{0644}', '\u{064A}', '\u{0645}', '\u{0627}', '\u{0645}', '\u{0645}', '\u{0646}', '\u{0631}',
+    '\u{0646}', '\u{0632}', '\u{0646}', '\u{0645}', '\u{0646}', '\u{0646}', '\u{0646}', '\u{0649}',
+    '\u{0646}', '\u{064A}', '\u{0649}', '\u{0670}', '\u{064A}', '\u{0631}', '\u{064A}', '\u{0632}',
+    '\u{064A}', '\u{0645}', '\u{064A}', '\u{0646}', '\u{064A}', '\u{0649}', '\u{064A}', '\u{064A}',
+    '\u{064A}', '\u{0654}', '\u{062C}', '\u{064A}', '\u{0654}', '\u{062D}', '\u{064A}', '\u{0654}',
+    '\u{062E}', '\u{064A}', '\u{0654}', '\u{0645}', '\u{064A}', '\u{0654}', '\u{0647}', '\u{0628}',
+    '\u{062C}', '\u{0628}', '\u{062D}', '\u{0628}', '\u{062E}', '\u{0628}', '\u{0645}', '\u{0628}',
+    '\u{0647}', '\u{062A}', '\u{062C}', '\u{062A}', '\u{062D}', '\u{062A}', '\u{062E}', '\u{062A}',
+    '\u{0645}', '\u{062A}', '\u{0647}', '\u{062B}', '\u{0645}', '\u{062C}', '\u{062D}', '\u{062C}',
+    '\u{0645}', '\u{062D}', '\u{062C}', '\u{062D}', '\u{0645}', '\u{062E}', '\u{062C}', '\u{062E}',
+    '\u{0645}', '\u{0633}', '\u{062C}', '\u{0633}', '\u{062D}', '\u{0633}', '\u{062E}', '\u{0633}',
+    '\u{0645}', '\u{0635}', '\u{062D}', '\u{0635}', '\u{062E}', '\u{0635}', '\u{0645}', '\u{0636}',
+    '\u{062C}', '\u{0636}', '\u{062D}', '\u{0636}', '\u{062E}', '\u{0636}', '\u{0645}', '\u{0637}',
+    '\u{062D}', '\u{0638}', '\u{0645}', '\u{0639}', '\u{062C}', '\u{0639}', '\u{0645}', '\u{063A}',
+    '\u{062C}', '\u{063A}', '\u{0645}', '\u{0641}', '\u{062C}', '\u{0641}', '\u{062D}', '\u{0641}',
+    '\u{062E}', '\u{0641}', '\u{0645}', '\u{0642}', '\u{062D}', '\u{0642}', '\u{0645}', '\u{0643}',
+    '\u{062C}', '\u{0643}', '\u{062D}', '\u{0643}', '\u{062E}', '\u{0643}', '\u{0644}', '\u{0643}',
+    '\u{0645}', '\u{0644}', '\u{062C}', '\u{0644}', '\u{062D}', '\u{0644}', '\u{062E}', '\u{0644}',
+    '\u{0645}', '\u{0644}', '\u{0647}', '\u{0645}', '\u{062C}', '\u{0645}', '\u{062D}', '\u{0645}',
+    '\u{062E}', '\u{0645}', '\u{0645}', '\u{0646}', '\u{062C}', '\u{0646}', '\u{062D}', '\u{0646}',
+    '\u{062E}', '\u{0646}', '\u{0645}', '\u{0646}', '\u{0647}', '\u{0647}', '\u{062C}', '\u{0647}',
+    '\u{0645}', '\u{0647}', '\u{0670}', '\u{064A}', '\u{062C}', '\u{064A}', '\u{062D}', '\u{064A}',
+    '\u{062E}', '\u{064A}', '\u{0645}', '\u{064A}', '\u{0647}', '\u{064A}', '\u{0654}', '\u{0645}',
+    '\u{064A}', '\u{0654}', '\u{0647}', '\u{0628}', '\u{0645}', '\u{0628}', '\u{0647}', '\u{062A}',
+    '\u{0645}', '\u{062A}', '\u{0647}', '\u{062B}', '\u{0645}', '\u{062B}', '\u{0647}', '\u{0633}',
+    '\u{0645}', '\u{0633}', '\u{0647}', '\u{0634}', '\u{0645}', '\u{0634}', '\u{0647}', '\u{0643}',
+    '\u{0644}', '\u{0643}', '\u{0645}', '\u{0644}', '\u{0645}', '\u{0646}', '\u{0645}', '\u{0646}',
+    '\u{0647}', '\u{064A}', '\u{0645}', '\u{064A}', '\u{0647}', '\u{0640}', '\u{064E}', '\u{0651}',
+    '\u{0640}', '\u{064F}', '\u{0651}', '\u{0640}', '\u{0650}', '\u{0651}', '\u{0637}', '\u{0649}',
+    '\u{0637}', '\u{064A}', '\u{0639}', '\u{0649}', '\u{0639}', '\u{064A}', '\u{063A}', '\u{0649}',
+    '\u{063A}', '\u{064A}', '\u{0633}', '\u{0649}', '\u{0633}', '\u{064A}', '\u{0634}', '\u{0649}',
+    '\u{0634}', '\u{064A}', '\u{062D}', '\u{0649}', '\u{062D}', '\u{064A}', '\u{062C}', '\u{0649}',
+    '\u{062C}', '\u{064A}', '\u{062E}', '\u{0649}', '\u{062E}', '\u{064A}', '\u{0635}', '\u{0649}',
+    '\u{0635}', '\u{064A}', '\u{0636}', '\u{0649}', '\u{0636}', '\u{064A}', '\u{0634}', '\u{062C}',
+    '\u{0634}', '\u{062D}', '\u{0634}', '\u{062E}', '\u{0634}', '\u{0645}', '\u{0634}', '\u{0631}',
+    '\u{0633}', '\u{0631}', '\u{0635}', '\u{0631}', '\u{0636}', '\u{0631}', '\u{0637}', '\u{0649}',
+    '\u{0637}', '\u{064A}', '\u{0639}', '\u{0649}', '\u{0639}', '\u{064A}', '\u{063A}', '\u{0649}',
+    '\u{063A}', '\u{064A}', '\u{0633}', '\u{0649}', '\u{0633}', '\u{064A}', '\u{0634}', '\u{0649}',
+    '\u{0634}', '\u{064A}', '\u{062D}', '\u{0649}', '\u{062D}', '\u{064A}', '\u{062C}', '\u{0649}',
+    '\u{062C}', '\u{064A}', '\u{062E}', '\u{0649}', '\u{062E}', '\u{064A}', '\u{0635}', '\u{0649}',
+    '\u{0635}', '\u{064A}', '\u{0636}', '\u{0649}', '\u{0636}', '\u{064A}', '\u{0634}', '\u{062C}',
+    '\u{0634}', '\u{062D}', '\u{0634}', '\u{062E}', '\u{0634}', '\u{0645}', '\u{0634}', '\u{0631}',
+    '\u{0633}', '\u{0631}', '\u{0635}', '\u{0631}', '\u{0636}', '\u{0631}', '\u{0634}', '\u{062C}',
+    '\u{0634}', '\u{062D}', '\u{0634}', '\u{062E}', '\u{0634}', '\u{0645}', '\u{0633}', '\u{0647}',
+    '\u{0634}', '\u{0647}', '\u{0637}', '\u{0645}', '\u{0633}', '\u{062C}', '\u{0633}', '\u{062D}',
+    '\u{0633}', '\u{062E}', '\u{0634}', '\u{062C}', '\u{0634}', '\u{062D}', '\u{0634}', '\u{062E}',
+    '\u{0637}', '\u{0645}', '\u{0638}', '\u{0645}', '\u{0627}', '\u{064B}', '\u{0627}', '\u{064B}',
+    '\u{062A}', '\u{062C}', '\u{0645}', '\u{062A}', '\u{062D}', '\u{062C}', '\u{062A}', '\u{062D}',
+    '\u{062C}', '\u{062A}', '\u{062D}', '\u{0645}', '\u{062A}', '\u{062E}', '\u{0645}', '\u{062A}',
+    '\u{0645}', '\u{062C}', '\u{062A}', '\u{0645}', '\u{062D}', '\u{062A}', '\u{0645}', '\u{062E}',
+    '\u{062C}', '\u{0645}', '\u{062D}', '\u{062C}', '\u{0645}', '\u{062D}', '\u{062D}', '\u{0645}',
+    '\u{064A}', '\u{062D}', '\u{0645}', '\u{0649}', '\u{0633}', '\u{062D}', '\u{062C}', '\u{0633}',
+    '\u{062C}', '\u{062D}', '\u{0633}', '\u{062C}', '\u{0649}', '\u{0633}', '\u{0645}', '\u{062D}',
+    '\u{0633}', '\u{0645}', '\u{062D}', '\u{0633}', '\u{0645}', '\u{062C}', '\u{0633}', '\u{0645}',
+    '\u{0645}', '\u{0633}', '\u{0645}', '\u{0645}', '\u{0635}', '\u{062D}', '\u{062D}', '\u{0635}',
+    '\u{062D}', '\u{062D}', '\u{0635}', '\u{0645}', '\u{0645}', '\u{0634}', '\u{062D}', '\u{0645}',
+    '\u{0634}', '\u{062D}', '\u{0645}', '\u{0634}', '\u{062C}', '\u{064A}', '\u{0634}', '\u{0645}',
+    '\u{062E}', '\u{0634}', '\u{0645}', '\u{062E}', '\u{0634}', '\u{0645}', '\u{0645}', '\u{0634}',
+    '\u{0645}', '\u{0645}', '\u{0636}', '\u{062D}', '\u{0649}', '\u{0636}', '\u{062E}', '\u{0645}',
+    '\u{0636}', '\u{062E}', '\u{0645}', '\u{0637}', '\u{0645}', '\u{062D}', '\u{0637}', '\u{0645}',
+    '\u{062D}', '\u{0637}', '\u{0645}', '\u{0645}', '\u{0637}', '\u{0645}', '\u{064A}', '\u{0639}',
+    '\u{062C}', '\u{0645}', '\u{0639}', '\u{0645}', '\u{0645}', '\u{0639}', '\u{0645}', '\u{0645}',
+    '\u{0639}', '\u{0645}', '\u{0649}', '\u{063A}', '\u{0645}', '\u{0645}', '\u{063A}', '\u{0645}',
+    '\u{064A}', '\u{063A}', '\u{0645}', '\u{0649}', '\u{0641}', '\u{062E}', '\u{0645}', '\u{0641}',
+    '\u{062E}', '\u{0645}', '\u{0642}', '\u{0645}', '\u{062D}', '\u{0642}', '\u{0645}', '\u{0645}',
+    '\u{0644}', '\u{062D}', '\u{0645}', '\u{0644}', '\u{062D}', '\u{064A}', '\u{0644}', '\u{062D}',
+    '\u{0649}', '\u{0644}', '\u{062C}', '\u{062C}', '\u{0644}', '\u{062C}', '\u{062C}', '\u{0644}',
+    '\u{062E}', '\u{0645}', '\u{0644}', '\u{062E}', '\u{0645}', '\u{0644}', '\u{0645}', '\u{062D}',
+    '\u{0644}', '\u{0645}', '\u{062D}', '\u{0645}', '\u{062D}', '\u{062C}', '\u{0645}', '\u{062D}',
+    '\u{0645}', '\u{0645}', '\u{062D}', '\u{064A}', '\u{0645}', '\u{062C}', '\u{062D}', '\u{0645}',
+    '\u{062C}', '\u{0645}', '\u{0645}', '\u{062E}', '\u{062C}', '\u{0645}', '\u{062E}', '\u{0645}',
+    '\u{0645}', '\u{062C}', '\u{062E}', '\u{0647}', '\u{0645}', '\u{062C}', '\u{0647}', '\u{0645}',
+    '\u{0645}', '\u{0646}', '\u{062D}', '\u{0645}', '\u{0646}', '\u{062D}', '\u{0649}', '\u{0646}',
+    '\u{062C}', '\u{0645}', '\u{0646}', '\u{062C}', '\u{0645}', '\u{0646}', '\u{062C}', '\u{0649}',
+    '\u{0646}', '\u{0645}', '\u{064A}', '\u{0646}', '\u{0645}', '\u{0649}', '\u{064A}', '\u{0645}',
+    '\u{0645}', '\u{064A}', '\u{0645}', '\u{0645}', '\u{0628}', '\u{062E}', '\u{064A}', '\u{062A}',
+    '\u{062C}', '\u{064A}', '\u{062A}', '\u{062C}', '\u{0649}', '\u{062A}', '\u{062E}', '\u{064A}',
+    '\u{062A}', '\u{062E}', '\u{0649}', '\u{062A}', '\u{0645}', '\u{064A}', '\u{062A}', '\u{0645}',
+    '\u{0649}', '\u{062C}', '\u{0645}', '\u{064A}', '\u{062C}', '\u{062D}', '\u{0649}', '\u{062C}',
+    '\u{0645}', '\u{0649}', '\u{0633}', '\u{062E}', '\u{0649}', '\u{0635}', '\u{062D}', '\u{064A}',
+    '\u{0634}', '\u{062D}', '\u{064A}', '\u{0636}', '\u{062D}', '\u{064A}', '\u{0644}', '\u{062C}',
+    '\u{064A}', '\u{0644}', '\u{0645}', '\u{064A}', '\u{064A}', '\u{062D}', '\u{064A}', '\u{064A}',
+    '\u{062C}', '\u{064A}', '\u{064A}', '\u{0645}', '\u{064A}', '\u{0645}', '\u{0645}', '\u{064A}',
+    '\u{0642}', '\u{0645}', '\u{064A}', '\u{0646}', '\u{062D}', '\u{064A}', '\u{0642}', '\u{0645}',
+    '\u{062D}', '\u{0644}', '\u{062D}', '\u{0645}', '\u{0639}', '\u{0645}', '\u{064A}', '\u{0643}',
+    '\u{0645}', '\u{064A}', '\u{0646}', '\u{062C}', '\u{062D}', '\u{0645}', '\u{062E}', '\u{064A}',
+    '\u{0644}', '\u{062C}', '\u{0645}', '\u{0643}', '\u{0645}', '\u{0645}', '\u{0644}', '\u{062C}',
+    '\u{0645}', '\u{0646}', '\u{062C}', '\u{062D}', '\u{062C}', '\u{062D}', '\u{064A}', '\u{062D}',
+    '\u{062C}', '\u{064A}', '\u{0645}', '\u{062C}', '\u{064A}', '\u{0641}', '\u{0645}', '\u{064A}',
+    '\u{0628}', '\u{062D}', '\u{064A}', '\u{0643}', '\u{0645}', '\u{0645}', '\u{0639}', '\u{062C}',
+    '\u{0645}', '\u{0635}', '\u{0645}', '\u{0645}', '\u{0633}', '\u{062E}', '\u{064A}', '\u{0646}',
+    '\u{062C}', '\u{064A}', '\u{0635}', '\u{0644}', '\u{06D2}', '\u{0642}', '\u{0644}', '\u{06D2}',
+    '\u{0627}', '\u{0644}', '\u{0644}', '\u{0647}', '\u{0627}', '\u{0643}', '\u{0628}', '\u{0631}',
+    '\u{0645}', '\u{062D}', '\u{0645}', '\u{062F}', '\u{0635}', '\u{0644}', '\u{0639}', '\u{0645}',
+    '\u{0631}', '\u{0633}', '\u{0648}', '\u{0644}', '\u{0639}', '\u{0644}', '\u{064A}', '\u{0647}',
+    '\u{0648}', '\u{0633}', '\u{0644}', '\u{0645}', '\u{0635}', '\u{0644}', '\u{0649}', '\u{0635}',
+    '\u{0644}', '\u{0649}', '\u{0020}', '\u{0627}', '\u{0644}', '\u{0644}', '\u{0647}', '\u{0020}',
+    '\u{0639}', '\u{0644}', '\u{064A}', '\u{0647}', '\u{0020}', '\u{0648}', '\u{0633}', '\u{0644}',
+    '\u{0645}', '\u{062C}', '\u{0644}', '\u{0020}', '\u{062C}', '\u{0644}', '\u{0627}', '\u{0644}',
+    '\u{0647}', '\u{0631}', '\u{06CC}', '\u{0627}', '\u{0644}', '\u{002C}', '\u{3001}', '\u{3002}',
+    '\u{003A}', '\u{003B}', '\u{0021}', '\u{003F}', '\u{3016}', '\u{3017}', '\u{002E}', '\u{002E}',
+    '\u{002E}', '\u{002E}', '\u{002E}', '\u{2014}', '\u{2013}', '\u{005F}', '\u{005F}', '\u{0028}',
+    '\u{0029}', '\u{007B}', '\u{007D}', '\u{3014}', '\u{3015}', '\u{3010}', '\u{3011}', '\u{300A}',
+    '\u{300B}', '\u{3008}', '\u{3009}', '\u{300C}', '\u{300D}', '\u{300E}', '\u{300F}', '\u{005B}',
+    '\u{005D}', '\u{0020}', '\u{0305}', '\u{0020}', '\u{0305}', '\u{0020}', '\u{0305}', '\u{0020}',
+    '\u{0305}', '\u{005F}', '\u{005F}', '\u{005F}', '\u{002C}', '\u{3001}', '\u{002E}', '\u{003B}',
+    '\u{003A}', '\u{003F}', '\u{0021}', '\u{2014}', '\u{0028}', '\u{0029}', '\u{007B}', '\u{007D}',
+    '\u{3014}', '\u{3015}', '\u{0023}', '\u{0026}', '\u{002A}', '\u{002B}', '\u{002D}', '\u{003C}',
+    '\u{003E}', '\u{003D}', '\u{005C}', '\u{0024}', '\u{0025}', '\u{0040}', '\u{0020}', '\u{064B}',
+    '\u{0640}', '\u{064B}', '\u{0020}', '\u{064C}', '\u{0020}', '\u{064D}', '\u{0020}', '\u{064E}',
+    '\u{0640}', '\u{064E}', '\u{0020}', '\u{064F}', '\u{0640}', '\u{064F}', '\u{0020}', '\u{0650}',
+    '\u{0640}', '\u{0650}', '\u{0020}', '\u{0651}', '\u{0640}', '\u{0651}', '\u{0020}', '\u{0652}',
+    '\u{0640}', '\u{0652}', '\u{0621}', '\u{0627}', '\u{0653}', '\u{0627}', '\u{0653}', '\u{0627}',
+    '\u{0654}', '\u{0627}', '\u{0654}', '\u{0648}', '\u{0654}', '\u{0648}', '\u{0654}', '\u{0627}',
+    '\u{0655}', '\u{0627}', '\u{0655}', '\u{064A}', '\u{0654}', '\u{064A}', '\u{0654}', '\u{064A}',
+    '\u{0654}', '\u{064A}', '\u{0654}', '\u{0627}', '\u{0627}', '\u{0628}', '\u{0628}', '\u{0628}',
+    '\u{0628}', '\u{0629}', '\u{0629}', '\u{062A}', '\u{062A}', '\u{062A}', '\u{062A}', '\u{062B}',
+    '\u{062B}', '\u{062B}', '\u{062B}', '\u{062C}', '\u{062C}', '\u{062C}', '\u{062C}', '\u{062D}',
+    '\u{062D}', '\u{062D}', '\u{062D}', '\u{062E}', '\u{062E}', '\u{062E}', '\u{062E}', '\u{062F}',
+    '\u{062F}', '\u{0630}', '\u{0630}', '\u{0631}', '\u{0631}', '\u{0632}', '\u{0632}', '\u{0633}',
+    '\u{0633}', '\u{0633}', '\u{0633}', '\u{0634}', '\u{0634}', '\u{0634}', '\u{0634}', '\u{0635}',
+    '\u{0635}', '\u{0635}', '\u{0635}', '\u{0636}', '\u{0636}', '\u{0636}', '\u{0636}', '\u{0637}',
+    '\u{0637}', '\u{0637}', '\u{0637}', '\u{0638}', '\u{0638}', '\u{0638}', '\u{0638}', '\u{0639}',
+    '\u{0639}', '\u{0639}', '\u{0639}', '\u{063A}', '\u{063A}', '\u{063A}', '\u{063A}', '\u{0641}',
+    '\u{0641}', '\u{0641}', '\u{0641}', '\u{0642}', '\u{0642}', '\u{0642}', '\u{0642}', '\u{0643}',
+    '\u{0643}', '\u{0643}', '\u{0643}', '\u{0644}', '\u{0644}', '\u{0644}', '\u{0644}', '\u{0645}',
+    '\u{0645}', '\u{0645}', '\u{0645}', '\u{0646}', '\u{0646}', '\u{0646}', '\u{0646}', '\u{0647}',
+    '\u{0647}', '\u{0647}', '\u{0647}', '\u{0648}', '\u{0648}', '\u{0649}', '\u{0649}', '\u{064A}',
+    '\u{064A}', '\u{064A}', '\u{064A}', '\u{0644}', '\u{0627}', '\u{0653}', '\u{0644}', '\u{0627}',
+    '\u{0653}', '\u{0644}', '\u{0627}', '\u{0654}', '\u{0644}', '\u{0627}', '\u{0654}', '\u{0644}',
+    '\u{0627}', '\u{0655}', '\u{0644}', '\u{0627}', '\u{0655}', '\u{0644}', '\u{0627}', '\u{0644}',
+    '\u{0627}', '\u{0021}', '\u{0022}', '\u{0023}', '\u{0024}', '\u{0025}', '\u{0026}', '\u{0027}',
+    '\u{0028}', '\u{0029}', '\u{002A}', '\u{002B}', '\u{002C}', '\u{002D}', '\u{002E}', '\u{002F}',
+    '\u{0030}', '\u{0031}', '\u{0032}', '\u{0033}', '\u{0034}', '\u{0035}', '\u{0036}', '\u{0037}',
+    '\u{0038}', '\u{0039}', '\u{003A}', '\u{003B}', '\u{003C}', '\u{003D}', '\u{003E}', '\u{003F}',
+    '\u{0040}', '\u{0041}', '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}',
+    '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}',
+    '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}',
+    '\u{0058}', '\u{0059}', '\u{005A}', '\u{005B}', '\u{005C}', '\u{005D}', '\u{005E}', '\u{005F}',
+    '\u{0060}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}',
+    '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}',
+    '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}',
+    '\u{0078}', '\u{0079}', '\u{007A}', '\u{007B}', '\u{007C}', '\u{007D}', '\u{007E}', '\u{2985}',
+    '\u{2986}', '\u{3002}', '\u{300C}', '\u{300D}', '\u{3001}', '\u{30FB}', '\u{30F2}', '\u{30A1}',
+    '\u{30A3}', '\u{30A5}', '\u{30A7}', '\u{30A9}', '\u{30E3}', '\u{30E5}', '\u{30E7}', '\u{30C3}',
+    '\u{30FC}', '\u{30A2}', '\u{30A4}', '\u{30A6}', '\u{30A8}', '\u{30AA}', '\u{30AB}', '\u{30AD}',
+    '\u{30AF}', '\u{30B1}', '\u{30B3}', '\u{30B5}', '\u{30B7}', '\u{30B9}', '\u{30BB}', '\u{30BD}',
+    '\u{30BF}', '\u{30C1}', '\u{30C4}', '\u{30C6}', '\u{30C8}', '\u{30CA}', '\u{30CB}', '\u{30CC}',
+    '\u{30CD}', '\u{30CE}', '\u{30CF}', '\u{30D2}', '\u{30D5}', '\u{30D8}', '\u{30DB}', '\u{30DE}',
+    '\u{30DF}', '\u{30E0}', '\u{30E1}', '\u{30E2}', '\u{30E4}', '\u{30E6}', '\u{30E8}', '\u{30E9}',
+    '\u{30EA}', '\u{30EB}', '\u{30EC}', '\u{30ED}', '\u{30EF}', '\u{30F3}', '\u{3099}', '\u{309A}',
+    '\u{1160}', '\u{1100}', '\u{1101}', '\u{11AA}', '\u{1102}', '\u{11AC}', '\u{11AD}', '\u{1103}',
+    '\u{1104}', '\u{1105}', '\u{11B0}', '\u{11B1}', '\u{11B2}', '\u{11B3}', '\u{11B4}', '\u{11B5}',
+    '\u{111A}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110A}', '\u{110B}',
+    '\u{110C}', '\u{110D}', '\u{110E}', '\u{110F}', '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}',
+    '\u{1162}', '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}', '\u{1169}',
+    '\u{116A}', '\u{116B}', '\u{116C}', '\u{116D}', '\u{116E}', '\u{116F}', '\u{1170}', '\u{1171}',
+    '\u{1172}', '\u{1173}', '\u{1174}', '\u{1175}', '\u{00A2}', '\u{00A3}', '\u{00AC}', '\u{0020}',
+    '\u{0304}', '\u{00A6}', '\u{00A5}', '\u{20A9}', '\u{2502}', '\u{2190}', '\u{2191}', '\u{2192}',
+    '\u{2193}', '\u{25A0}', '\u{25CB}', '\u{02D0}', '\u{02D1}', '\u{00E6}', '\u{0299}', '\u{0253}',
+    '\u{02A3}', '\u{AB66}', '\u{02A5}', '\u{02A4}', '\u{0256}', '\u{0257}', '\u{1D91}', '\u{0258}',
+    '\u{025E}', '\u{02A9}', '\u{0264}', '\u{0262}', '\u{0260}', '\u{029B}', '\u{0127}', '\u{029C}',
+    '\u{0267}', '\u{0284}', '\u{02AA}', '\u{02AB}', '\u{026C}', '\u{1DF04}', '\u{A78E}', '\u{026E}',
+    '\u{1DF05}', '\u{028E}', '\u{1DF06}', '\u{00F8}', '\u{0276}', '\u{0277}', '\u{0071}', '\u{027A}',
+    '\u{1DF08}', '\u{027D}', '\u{027E}', '\u{0280}', '\u{02A8}', '\u{02A6}', '\u{AB67}', '\u{02A7}',
+    '\u{0288}', '\u{2C71}', '\u{028F}', '\u{02A1}', '\u{02A2}', '\u{0298}', '\u{01C0}', '\u{01C1}',
+    '\u{01C2}', '\u{1DF0A}', '\u{1DF1E}', '\u{0041}', '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}',
+    '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}',
+    '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}',
+    '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}', '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}',
+    '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}',
+    '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}',
+    '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}',
+    '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}',
+    '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}',
+    '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}',
+    '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}',
+    '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}',
+    '\u{0079}', '\u{007A}', '\u{0041}', '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}',
+    '\u{0047}', '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}',
+    '\u{004F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}',
+    '\u{0057}', '\u{0058}', '\u{0059}', '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}',
+    '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}',
+    '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}',
+    '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}', '\u{0043}',
+    '\u{0044}', '\u{0047}', '\u{004A}', '\u{004B}', '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}',
+    '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}', '\u{005A}',
+    '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0066}', '\u{0068}', '\u{0069}', '\u{006A}',
+    '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}',
+    '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}',
+    '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}',
+    '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}',
+    '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}',
+    '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}',
+    '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}',
+    '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}', '\u{0042}', '\u{0044}', '\u{0045}', '\u{0046}',
+    '\u{0047}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0050}',
+    '\u{0051}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}',
+    '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}',
+    '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}',
+    '\u{0079}', '\u{007A}', '\u{0041}', '\u{0042}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}',
+    '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004F}', '\u{0053}', '\u{0054}',
+    '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}', '\u{0061}', '\u{0062}', '\u{0063}',
+    '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}',
+    '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}',
+    '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}',
+    '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}',
+    '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}',
+    '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}',
+    '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}',
+    '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}',
+    '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}', '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}',
+    '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}',
+    '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}',
+    '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}', '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}',
+    '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}',
+    '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}',
+    '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}',
+    '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}',
+    '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}',
+    '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}',
+    '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}',
+    '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}',
+    '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}', '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}',
+    '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}',
+    '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}',
+    '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}', '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}',
+    '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}',
+    '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}',
+    '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}',
+    '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}',
+    '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}',
+    '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}',
+    '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}', '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}',
+    '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}',
+    '\u{0078}', '\u{0079}', '\u{007A}', '\u{0041}', '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}',
+    '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}',
+    '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}',
+    '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}', '\u{005A}', '\u{0061}', '\u{0062}', '\u{0063}',
+    '\u{0064}', '\u{0065}', '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}',
+    '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}', '\u{0072}', '\u{0073}',
+    '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}', '\u{0078}', '\u{0079}', '\u{007A}', '\u{0131}',
+    '\u{0237}', '\u{0391}', '\u{0392}', '\u{0393}', '\u{0394}', '\u{0395}', '\u{0396}', '\u{0397}',
+    '\u{0398}', '\u{0399}', '\u{039A}', '\u{039B}', '\u{039C}', '\u{039D}', '\u{039E}', '\u{039F}',
+    '\u{03A0}', '\u{03A1}', '\u{0398}', '\u{03A3}', '\u{03A4}', '\u{03A5}', '\u{03A6}', '\u{03A7}',
+    '\u{03A8}', '\u{03A9}', '\u{2207}', '\u{03B1}', '\u{03B2}', '\u{03B3}', '\u{03B4}', '\u{03B5}',
+    '\u{03B6}', '\u{03B7}', '\u{03B8}', '\u{03B9}', '\u{03BA}', '\u{03BB}', '\u{03BC}', '\u{03BD}',
+    '\u{03BE}', '\u{03BF}', '\u{03C0}', '\u{03C1}', '\u{03C2}', '\u{03C3}', '\u{03C4}', '\u{03C5}',
+    '\u{03C6}', '\u{03C7}', '\u{03C8}', '\u{03C9}', '\u{2202}', '\u{03B5}', '\u{03B8}', '\u{03BA}',
+    '\u{03C6}', '\u{03C1}', '\u{03C0}', '\u{0391}', '\u{0392}', '\u{0393}', '\u{0394}', '\u{0395}',
+    '\u{0396}', '\u{0397}', '\u{0398}', '\u{0399}', '\u{039A}', '\u{039B}', '\u{039C}', '\u{039D}',
+    '\u{039E}', '\u{039F}', '\u{03A0}', '\u{03A1}', '\u{0398}', '\u{03A3}', '\u{03A4}', '\u{03A5}',
+    '\u{03A6}', '\u{03A7}', '\u{03A8}', '\u{03A9}', '\u{2207}', '\u{03B1}', '\u{03B2}', '\u{03B3}',
+    '\u{03B4}', '\u{03B5}', '\u{03B6}', '\u{03B7}', '\u{03B8}', '\u{03B9}', '\u{03BA}', '\u{03BB}',
+    '\u{03BC}', '\u{03BD}', '\u{03BE}', '\u{03BF}', '\u{03C0}', '\u{03C1}', '\u{03C2}', '\u{03C3}',
+    '\u{03C4}', '\u{03C5}', '\u{03C6}', '\u{03C7}', '\u{03C8}', '\u{03C9}', '\u{2202}', '\u{03B5}',
+    '\u{03B8}', '\u{03BA}', '\u{03C6}', '\u{03C1}', '\u{03C0}', '\u{0391}', '\u{0392}', '\u{0393}',
+    '\u{0394}', '\u{0395}', '\u{0396}', '\u{0397}', '\u{0398}', '\u{0399}', '\u{039A}', '\u{039B}',
+    '\u{039C}', '\u{039D}', '\u{039E}', '\u{039F}', '\u{03A0}', '\u{03A1}', '\u{0398}', '\u{03A3}',
+    '\u{03A4}', '\u{03A5}', '\u{03A6}', '\u{03A7}', '\u{03A8}', '\u{03A9}', '\u{2207}', '\u{03B1}',
+    '\u{03B2}', '\u{03B3}', '\u{03B4}', '\u{03B5}', '\u{03B6}', '\u{03B7}', '\u{03B8}', '\u{03B9}',
+    '\u{03BA}', '\u{03BB}', '\u{03BC}', '\u{03BD}', '\u{03BE}', '\u{03BF}', '\u{03C0}', '\u{03C1}',
+    '\u{03C2}', '\u{03C3}', '\u{03C4}', '\u{03C5}', '\u{03C6}', '\u{03C7}', '\u{03C8}', '\u{03C9}',
+    '\u{2202}', '\u{03B5}', '\u{03B8}', '\u{03BA}', '\u{03C6}', '\u{03C1}', '\u{03C0}', '\u{0391}',
+    '\u{0392}', '\u{0393}', '\u{0394}', '\u{0395}', '\u{0396}', '\u{0397}', '\u{0398}', '\u{0399}',
+    '\u{039A}', '\u{039B}', '\u{039C}', '\u{039D}', '\u{039E}', '\u{039F}', '\u{03A0}', '\u{03A1}',
+    '\u{0398}', '\u{03A3}', '\u{03A4}', '\u{03A5}', '\u{03A6}', '\u{03A7}', '\u{03A8}', '\u{03A9}',
+    '\u{2207}', '\u{03B1}', '\u{03B2}', '\u{03B3}', '\u{03B4}', '\u{03B5}', '\u{03B6}', '\u{03B7}',
+    '\u{03B8}', '\u{03B9}', '\u{03BA}', '\u{03BB}', '\u{03BC}', '\u{03BD}', '\u{03BE}', '\u{03BF}',
+    '\u{03C0}', '\u{03C1}', '\u{03C2}', '\u{03C3}', '\u{03C4}', '\u{03C5}', '\u{03C6}', '\u{03C7}',
+    '\u{03C8}', '\u{03C9}', '\u{2202}', '\u{03B5}', '\u{03B8}', '\u{03BA}', '\u{03C6}', '\u{03C1}',
+    '\u{03C0}', '\u{0391}', '\u{0392}', '\u{0393}', '\u{0394}', '\u{0395}', '\u{0396}', '\u{0397}',
+    '\u{0398}', '\u{0399}', '\u{039A}', '\u{039B}', '\u{039C}', '\u{039D}', '\u{039E}', '\u{039F}',
+    '\u{03A0}', '\u{03A1}', '\u{0398}', '\u{03A3}', '\u{03A4}', '\u{03A5}', '\u{03A6}', '\u{03A7}',
+    '\u{03A8}', '\u{03A9}', '\u{2207}', '\u{03B1}', '\u{03B2}', '\u{03B3}', '\u{03B4}', '\u{03B5}',
+    '\u{03B6}', '\u{03B7}', '\u{03B8}', '\u{03B9}', '\u{03BA}', '\u{03BB}', '\u{03BC}', '\u{03BD}',
+    '\u{03BE}', '\u{03BF}', '\u{03C0}', '\u{03C1}', '\u{03C2}', '\u{03C3}', '\u{03C4}', '\u{03C5}',
+    '\u{03C6}', '\u{03C7}', '\u{03C8}', '\u{03C9}', '\u{2202}', '\u{03B5}', '\u{03B8}', '\u{03BA}',
+    '\u{03C6}', '\u{03C1}', '\u{03C0}', '\u{03DC}', '\u{03DD}', '\u{0030}', '\u{0031}', '\u{0032}',
+    '\u{0033}', '\u{0034}', '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}', '\u{0039}', '\u{0030}',
+    '\u{0031}', '\u{0032}', '\u{0033}', '\u{0034}', '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}',
+    '\u{0039}', '\u{0030}', '\u{0031}', '\u{0032}', '\u{0033}', '\u{0034}', '\u{0035}', '\u{0036}',
+    '\u{0037}', '\u{0038}', '\u{0039}', '\u{0030}', '\u{0031}', '\u{0032}', '\u{0033}', '\u{0034}',
+    '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}', '\u{0039}', '\u{0030}', '\u{0031}', '\u{0032}',
+    '\u{0033}', '\u{0034}', '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}', '\u{0039}', '\u{0430}',
+    '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}', '\u{0437}', '\u{0438}',
+    '\u{043A}', '\u{043B}', '\u{043C}', '\u{043E}', '\u{043F}', '\u{0440}', '\u{0441}', '\u{0442}',
+    '\u{0443}', '\u{0444}', '\u{0445}', '\u{0446}', '\u{0447}', '\u{0448}', '\u{044B}', '\u{044D}',
+    '\u{044E}', '\u{A689}', '\u{04D9}', '\u{0456}', '\u{0458}', '\u{04E9}', '\u{04AF}', '\u{04CF}',
+    '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}', '\u{0437}',
+    '\u{0438}', '\u{043A}', '\u{043B}', '\u{043E}', '\u{043F}', '\u{0441}', '\u{0443}', '\u{0444}',
+    '\u{0445}', '\u{0446}', '\u{0447}', '\u{0448}', '\u{044A}', '\u{044B}', '\u{0491}', '\u{0456}',
+    '\u{0455}', '\u{045F}', '\u{04AB}', '\u{A651}', '\u{04B1}', '\u{0627}', '\u{0628}', '\u{062C}',
+    '\u{062F}', '\u{0648}', '\u{0632}', '\u{062D}', '\u{0637}', '\u{064A}', '\u{0643}', '\u{0644}',
+    '\u{0645}', '\u{0646}', '\u{0633}', '\u{0639}', '\u{0641}', '\u{0635}', '\u{0642}', '\u{0631}',
+    '\u{0634}', '\u{062A}', '\u{062B}', '\u{062E}', '\u{0630}', '\u{0636}', '\u{0638}', '\u{063A}',
+    '\u{066E}', '\u{06BA}', '\u{06A1}', '\u{066F}', '\u{0628}', '\u{062C}', '\u{0647}', '\u{062D}',
+    '\u{064A}', '\u{0643}', '\u{0644}', '\u{0645}', '\u{0646}', '\u{0633}', '\u{0639}', '\u{0641}',
+    '\u{0635}', '\u{0642}', '\u{0634}', '\u{062A}', '\u{062B}', '\u{062E}', '\u{0636}', '\u{063A}',
+    '\u{062C}', '\u{062D}', '\u{064A}', '\u{0644}', '\u{0646}', '\u{0633}', '\u{0639}', '\u{0635}',
+    '\u{0642}', '\u{0634}', '\u{062E}', '\u{0636}', '\u{063A}', '\u{06BA}', '\u{066F}', '\u{0628}',
+    '\u{062C}', '\u{0647}', '\u{062D}', '\u{0637}', '\u{064A}', '\u{0643}', '\u{0645}', '\u{0646}',
+    '\u{0633}', '\u{0639}', '\u{0641}', '\u{0635}', '\u{0642}', '\u{0634}', '\u{062A}', '\u{062B}',
+    '\u{062E}', '\u{0636}', '\u{0638}', '\u{063A}', '\u{066E}', '\u{06A1}', '\u{0627}', '\u{0628}',
+    '\u{062C}', '\u{062F}', '\u{0647}', '\u{0648}', '\u{0632}', '\u{062D}', '\u{0637}', '\u{064A}',
+    '\u{0644}', '\u{0645}', '\u{0646}', '\u{0633}', '\u{0639}', '\u{0641}', '\u{0635}', '\u{0642}',
+    '\u{0631}', '\u{0634}', '\u{062A}', '\u{062B}', '\u{062E}', '\u{0630}', '\u{0636}', '\u{0638}',
+    '\u{063A}', '\u{0628}', '\u{062C}', '\u{062F}', '\u{0648}', '\u{0632}', '\u{062D}', '\u{0637}',
+    '\u{064A}', '\u{0644}', '\u{0645}', '\u{0646}', '\u{0633}', '\u{0639}', '\u{0641}', '\u{0635}',
+    '\u{0642}', '\u{0631}', '\u{0634}', '\u{062A}', '\u{062B}', '\u{062E}', '\u{0630}', '\u{0636}',
+    '\u{0638}', '\u{063A}', '\u{0030}', '\u{002E}', '\u{0030}', '\u{002C}', '\u{0031}', '\u{002C}',
+    '\u{0032}', '\u{002C}', '\u{0033}', '\u{002C}', '\u{0034}', '\u{002C}', '\u{0035}', '\u{002C}',
+    '\u{0036}', '\u{002C}', '\u{0037}', '\u{002C}', '\u{0038}', '\u{002C}', '\u{0039}', '\u{002C}',
+    '\u{0028}', '\u{0041}', '\u{0029}', '\u{0028}', '\u{0042}', '\u{0029}', '\u{0028}', '\u{0043}',
+    '\u{0029}', '\u{0028}', '\u{0044}', '\u{0029}', '\u{0028}', '\u{0045}', '\u{0029}', '\u{0028}',
+    '\u{0046}', '\u{0029}', '\u{0028}', '\u{0047}', '\u{0029}', '\u{0028}', '\u{0048}', '\u{0029}',
+    '\u{0028}', '\u{0049}', '\u{0029}', '\u{0028}', '\u{004A}', '\u{0029}', '\u{0028}', '\u{004B}',
+    '\u{0029}', '\u{0028}', '\u{004C}', '\u{0029}', '\u{0028}', '\u{004D}', '\u{0029}', '\u{0028}',
+    '\u{004E}', '\u{0029}', '\u{0028}', '\u{004F}', '\u{0029}', '\u{0028}', '\u{0050}', '\u{0029}',
+    '\u{0028}', '\u{0051}', '\u{0029}', '\u{0028}', '\u{0052}', '\u{0029}', '\u{0028}', '\u{0053}',
+    '\u{0029}', '\u{0028}', '\u{0054}', '\u{0029}', '\u{0028}', '\u{0055}', '\u{0029}', '\u{0028}',
+    '\u{0056}', '\u{0029}', '\u{0028}', '\u{0057}', '\u{0029}', '\u{0028}', '\u{0058}', '\u{0029}',
+    '\u{0028}', '\u{0059}', '\u{0029}', '\u{0028}', '\u{005A}', '\u{0029}', '\u{3014}', '\u{0053}',
+    '\u{3015}', '\u{0043}', '\u{0052}', '\u{0043}', '\u{0044}', '\u{0057}', '\u{005A}', '\u{0041}',
+    '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}', '\u{0048}', '\u{0049}',
+    '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}', '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}',
+    '\u{0052}', '\u{0053}', '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{005A}', '\u{0048}', '\u{0056}', '\u{004D}', '\u{0056}', '\u{0053}', '\u{0044}', '\u{0053}',
+    '\u{0053}', '\u{0050}', '\u{0050}', '\u{0056}', '\u{0057}', '\u{0043}', '\u{004D}', '\u{0043}',
+    '\u{004D}', '\u{0044}', '\u{004D}', '\u{0052}', '\u{0044}', '\u{004A}', '\u{307B}', '\u{304B}',
+    '\u{30B3}', '\u{30B3}', '\u{30B5}', '\u{624B}', '\u{5B57}', '\u{53CC}', '\u{30C6}', '\u{3099}',
+    '\u{4E8C}', '\u{591A}', '\u{89E3}', '\u{5929}', '\u{4EA4}', '\u{6620}', '\u{7121}', '\u{6599}',
+    '\u{524D}', '\u{5F8C}', '\u{518D}', '\u{65B0}', '\u{521D}', '\u{7D42}', '\u{751F}', '\u{8CA9}',
+    '\u{58F0}', '\u{5439}', '\u{6F14}', '\u{6295}', '\u{6355}', '\u{4E00}', '\u{4E09}', '\u{904A}',
+    '\u{5DE6}', '\u{4E2D}', '\u{53F3}', '\u{6307}', '\u{8D70}', '\u{6253}', '\u{7981}', '\u{7A7A}',
+    '\u{5408}', '\u{6E80}', '\u{6709}', '\u{6708}', '\u{7533}', '\u{5272}', '\u{55B6}', '\u{914D}',
+    '\u{3014}', '\u{672C}', '\u{3015}', '\u{3014}', '\u{4E09}', '\u{3015}', '\u{3014}', '\u{4E8C}',
+    '\u{3015}', '\u{3014}', '\u{5B89}', '\u{3015}', '\u{3014}', '\u{70B9}', '\u{3015}', '\u{3014}',
+    '\u{6253}', '\u{3015}', '\u{3014}', '\u{76D7}', '\u{3015}', '\u{3014}', '\u{52DD}', '\u{3015}',
+    '\u{3014}', '\u{6557}', '\u{3015}', '\u{5F97}', '\u{53EF}', '\u{0030}', '\u{0031}', '\u{0032}',
+    '\u{0033}', '\u{0034}', '\u{0035}', '\u{0036}', '\u{0037}', '\u{0038}', '\u{0039}',
+];
+
+/// Primary composites, sorted by the pair of characters they are composed from
+pub(super) static COMPOSITIONS: [(char, char, char); 941] = [
+    ('\u{003C}', '\u{0338}', '\u{226E}'),
+    ('\u{003D}', '\u{0338}', '\u{2260}'),
+    ('\u{003E}', '\u{0338}', '\u{226F}'),
+    ('\u{0041}', '\u{0300}', '\u{00C0}'),
+    ('\u{0041}', '\u{0301}', '\u{00C1}'),
+    ('\u{0041}', '\u{0302}', '\u{00C2}'),
+    ('\u{0041}', '\u{0303}', '\u{00C3}'),
+    ('\u{0041}', '\u{0304}', '\u{0100}'),
+    ('\u{0041}', '\u{0306}', '\u{0102}'),
+    ('\u{0041}', '\u{0307}', '\u{0226}'),
+    ('\u{0041}', '\u{0308}', '\u{00C4}'),
+    ('\u{0041}', '\u{0309}', '\u{1EA2}'),
+    ('\u{0041}', '\u{030A}', '\u{00C5}'),
+    ('\u{0041}', '\u{030C}', '\u{01CD}'),
+    ('\u{0041}', '\u{030F}', '\u{0200}'),
+    ('\u{0041}', '\u{0311}', '\u{0202}'),
+    ('\u{0041}', '\u{0323}', '\u{1EA0}'),
+    ('\u{0041}', '\u{0325}', '\u{1E00}'),
+    ('\u{0041}', '\u{0328}', '\u{0104}'),
+    ('\u{0042}', '\u{0307}', '\u{1E02}'),
+    ('\u{0042}', '\u{0323}', '\u{1E04}'),
+    ('\u{0042}', '\u{0331}', '\u{1E06}'),
+    ('\u{0043}', '\u{0301}', '\u{0106}'),
+    ('\u{0043}', '\u{0302}', '\u{0108}'),
+    ('\u{0043}', '\u{0307}', '\u{010A}'),
+    ('\u{0043}', '\u{030C}', '\u{010C}'),
+    ('\u{0043}', '\u{0327}', '\u{00C7}'),
+    ('\u{0044}', '\u{0307}', '\u{1E0A}'),
+    ('\u{0044}', '\u{030C}', '\u{010E}'),
+    ('\u{0044}', '\u{0323}', '\u{1E0C}'),
+    ('\u{0044}', '\u{0327}', '\u{1E10}'),
+    ('\u{0044}', '\u{032D}', '\u{1E12}'),
+    ('\u{0044}', '\u{0331}', '\u{1E0E}'),
+    ('\u{0045}', '\u{0300}', '\u{00C8}'),
+    ('\u{0045}', '\u{0301}', '\u{00C9}'),
+    ('\u{0045}', '\u{0302}', '\u{00CA}'),
+    ('\u{0045}', '\u{0303}', '\u{1EBC}'),
+    ('\u{0045}', '\u{0304}', '\u{0112}'),
+    ('\u{0045}', '\u{0306}', '\u{0114}'),
+    ('\u{0045}', '\u{0307}', '\u{0116}'),
+    ('\u{0045}', '\u{0308}', '\u{00CB}'),
+    ('\u{0045}', '\u{0309}', '\u{1EBA}'),
+    ('\u{0045}', '\u{030C}', '\u{011A}'),
+    ('\u{0045}', '\u{030F}', '\u{0204}'),
+    ('\u{0045}', '\u{0311}', '\u{0206}'),
+    ('\u{0045}', '\u{0323}', '\u{1EB8}'),
+    ('\u{0045}', '\u{0327}', '\u{0228}'),
+    ('\u{0045}', '\u{0328}', '\u{0118}'),
+    ('\u{0045}', '\u{032D}', '\u{1E18}'),
+    ('\u{0045}', '\u{0330}', '\u{1E1A}'),
+    ('\u{0046}', '\u{0307}', '\u{1E1E}'),
+    ('\u{0047}', '\u{0301}', '\u{01F4}'),
+    ('\u{0047}', '\u{0302}', '\u{011C}'),
+    ('\u{0047}', '\u{0304}', '\u{1E20}'),
+    ('\u{0047}', '\u{0306}', '\u{011E}'),
+    ('\u{0047}', '\u{0307}', '\u{0120}'),
+    ('\u{0047}', '\u{030C}', '\u{01E6}'),
+    ('\u{0047}', '\u{0327}', '\u{0122}'),
+    ('\u{0048}', '\u{0302}', '\u{0124}'),
+    ('\u{0048}', '\u{0307}', '\u{1E22}'),
+    ('\u{0048}', '\u{0308}', '\u{1E26}'),
+    ('\u{0048}', '\u{030C}', '\u{021E}'),
+    ('\u{0048}', '\u{0323}', '\u{1E24}'),
+    ('\u{0048}', '\u{0327}', '\u{1E28}'),
+    ('\u{0048}', '\u{032E}', '\u{1E2A}'),
+    ('\u{0049}', '\u{0300}', '\u{00CC}'),
+    ('\u{0049}', '\u{0301}', '\u{00CD}'),
+    ('\u{0049}', '\u{0302}', '\u{00CE}'),
+    ('\u{0049}', '\u{0303}', '\u{0128}'),
+    ('\u{0049}', '\u{0304}', '\u{012A}'),
+    ('\u{0049}', '\u{0306}', '\u{012C}'),
+    ('\u{0049}', '\u{0307}', '\u{0130}'),
+    ('\u{0049}', '\u{0308}', '\u{00CF}'),
+    ('\u{0049}', '\u{0309}', '\u{1EC8}'),
+    ('\u{0049}', '\u{030C}', '\u{01CF}'),
+    ('\u{0049}', '\u{030F}', '\u{0208}'),
+    ('\u{0049}', '\u{0311}', '\u{020A}'),
+    ('\u{0049}', '\u{0323}', '\u{1ECA}'),
+    ('\u{0049}', '\u{0328}', '\u{012E}'),
+    ('\u{0049}', '\u{0330}', '\u{1E2C}'),
+    ('\u{004A}', '\u{0302}', '\u{0134}'),
+    ('\u{004B}', '\u{0301}', '\u{1E30}'),
+    ('\u{004B}', '\u{030C}', '\u{01E8}'),
+    ('\u{004B}', '\u{0323}', '\u{1E32}'),
+    ('\u{004B}', '\u{0327}', '\u{0136}'),
+    ('\u{004B}', '\u{0331}', '\u{1E34}'),
+    ('\u{004C}', '\u{0301}', '\u{0139}'),
+    ('\u{004C}', '\u{030C}', '\u{013D}'),
+    ('\u{004C}', '\u{0323}', '\u{1E36}'),
+    ('\u{004C}', '\u{0327}', '\u{013B}'),
+    ('\u{004C}', '\u{032D}', '\u{1E3C}'),
+    ('\u{004C}', '\u{0331}', '\u{1E3A}'),
+    ('\u{004D}', '\u{0301}', '\u{1E3E}'),
+    ('\u{004D}', '\u{0307}', '\u{1E40}'),
+    ('\u{004D}', '\u{0323}', '\u{1E42}'),
+    ('\u{004E}', '\u{0300}', '\u{01F8}'),
+    ('\u{004E}', '\u{0301}', '\u{0143}'),
+    ('\u{004E}', '\u{0303}', '\u{00D1}'),
+    ('\u{004E}', '\u{0307}', '\u{1E44}'),
+    ('\u{004E}', '\u{030C}', '\u{0147}'),
+    ('\u{004E}', '\u{0323}', '\u{1E46}'),
+    ('\u{004E}', '\u{0327}', '\u{0145}'),
+    ('\u{004E}', '\u{032D}', '\u{1E4A}'),
+    ('\u{004E}', '\u{0331}', '\u{1E48}'),
+    ('\u{004F}', '\u{0300}', '\u{00D2}'),
+    ('\u{004F}', '\u{0301}', '\u{00D3}'),
+    ('\u{004F}', '\u{0302}', '\u{00D4}'),
+    ('\u{004F}', '\u{0303}', '\u{00D5}'),
+    ('\u{004F}', '\u{0304}', '\u{014C}'),
+    ('\u{004F}', '\u{0306}', '\u{014E}'),
+    ('\u{004F}', '\u{0307}', '\u{022E}'),
+    ('\u{004F}', '\u{0308}', '\u{00D6}'),
+    ('\u{004F}', '\u{0309}', '\u{1ECE}'),
+    ('\u{004F}', '\u{030B}', '\u{0150}'),
+    ('\u{004F}', '\u{030C}', '\u{01D1}'),
+    ('\u{004F}', '\u{030F}', '\u{020C}'),
+    ('\u{004F}', '\u{0311}', '\u{020E}'),
+    ('\u{004F}', '\u{031B}', '\u{01A0}'),
+    ('\u{004F}', '\u{0323}', '\u{1ECC}'),
+    ('\u{004F}', '\u{0328}', '\u{01EA}'),
+    ('\u{0050}', '\u{0301}', '\u{1E54}'),
+    ('\u{0050}', '\u{0307}', '\u{1E56}'),
+    ('\u{0052}', '\u{0301}', '\u{0154}'),
+    ('\u{0052}', '\u{0307}', '\u{1E58}'),
+    ('\u{0052}', '\u{030C}', '\u{0158}'),
+    ('\u{0052}', '\u{030F}', '\u{0210}'),
+    ('\u{0052}', '\u{0311}', '\u{0212}'),
+    ('\u{0052}', '\u{0323}', '\u{1E5A}'),
+    ('\u{0052}', '\u{0327}', '\u{0156}'),
+    ('\u{0052}', '\u{0331}', '\u{1E5E}'),
+    ('\u{0053}', '\u{0301}', '\u{015A}'),
+    ('\u{0053}', '\u{0302}', '\u{015C}'),
+    ('\u{0053}', '\u{0307}', '\u{1E60}'),
+    ('\u{0053}', '\u{030C}', '\u{0160}'),
+    ('\u{0053}', '\u{0323}', '\u{1E62}'),
+    ('\u{0053}', '\u{0326}', '\u{0218}'),
+    ('\u{0053}', '\u{0327}', '\u{015E}'),
+    ('\u{0054}', '\u{0307}', '\u{1E6A}'),
+    ('\u{0054}', '\u{030C}', '\u{0164}'),
+    ('\u{0054}', '\u{0323}', '\u{1E6C}'),
+    ('\u{0054}', '\u{0326}', '\u{021A}'),
+    ('\u{0054}', '\u{0327}', '\u{0162}'),
+    ('\u{0054}', '\u{032D}', '\u{1E70}'),
+    ('\u{0054}', '\u{0331}', '\u{1E6E}'),
+    ('\u{0055}', '\u{0300}', '\u{00D9}'),
+    ('\u{0055}', '\u{0301}', '\u{00DA}'),
+    ('\u{0055}', '\u{0302}', '\u{00DB}'),
+    ('\u{0055}', '\u{0303}', '\u{0168}'),
+    ('\u{0055}', '\u{0304}', '\u{016A}'),
+    ('\u{0055}', '\u{0306}', '\u{016C}'),
+    ('\u{0055}', '\u{0308}', '\u{00DC}'),
+    ('\u{0055}', '\u{0309}', '\u{1EE6}'),
+    ('\u{0055}', '\u{030A}', '\u{016E}'),
+    ('\u{0055}', '\u{030B}', '\u{0170}'),
+    ('\u{0055}', '\u{030C}', '\u{01D3}'),
+    ('\u{0055}', '\u{030F}', '\u{0214}'),
+    ('\u{0055}', '\u{0311}', '\u{0216}'),
+    ('\u{0055}', '\u{031B}', '\u{01AF}'),
+    ('\u{0055}', '\u{0323}', '\u{1EE4}'),
+    ('\u{0055}', '\u{0324}', '\u{1E72}'),
+    ('\u{0055}', '\u{0328}', '\u{0172}'),
+    ('\u{0055}', '\u{032D}', '\u{1E76}'),
+    ('\u{0055}', '\u{0330}', '\u{1E74}'),
+    ('\u{0056}', '\u{0303}', '\u{1E7C}'),
+    ('\u{0056}', '\u{0323}', '\u{1E7E}'),
+    ('\u{0057}', '\u{0300}', '\u{1E80}'),
+    ('\u{0057}', '\u{0301}', '\u{1E82}'),
+    ('\u{0057}', '\u{0302}', '\u{0174}'),
+    ('\u{0057}', '\u{0307}', '\u{1E86}'),
+    ('\u{0057}', '\u{0308}', '\u{1E84}'),
+    ('\u{0057}', '\u{0323}', '\u{1E88}'),
+    ('\u{0058}', '\u{0307}', '\u{1E8A}'),
+    ('\u{0058}', '\u{0308}', '\u{1E8C}'),
+    ('\u{0059}', '\u{0300}', '\u{1EF2}'),
+    ('\u{0059}', '\u{0301}', '\u{00DD}'),
+    ('\u{0059}', '\u{0302}', '\u{0176}'),
+    ('\u{0059}', '\u{0303}', '\u{1EF8}'),
+    ('\u{0059}', '\u{0304}', '\u{0232}'),
+    ('\u{0059}', '\u{0307}', '\u{1E8E}'),
+    ('\u{0059}', '\u{0308}', '\u{0178}'),
+    ('\u{0059}', '\u{0309}', '\u{1EF6}'),
+    ('\u{0059}', '\u{0323}', '\u{1EF4}'),
+    ('\u{005A}', '\u{0301}', '\u{0179}'),
+    ('\u{005A}', '\u{0302}', '\u{1E90}'),
+    ('\u{005A}', '\u{0307}', '\u{017B}'),
+    ('\u{005A}', '\u{030C}', '\u{017D}'),
+    ('\u{005A}', '\u{0323}', '\u{1E92}'),
+    ('\u{005A}', '\u{0331}', '\u{1E94}'),
+    ('\u{0061}', '\u{0300}', '\u{00E0}'),
+    ('\u{0061}', '\u{0301}', '\u{00E1}'),
+    ('\u{0061}', '\u{0302}', '\u{00E2}'),
+    ('\u{0061}', '\u{0303}', '\u{00E3}'),
+    ('\u{0061}', '\u{0304}', '\u{0101}'),
+    ('\u{0061}', '\u{0306}', '\u{0103}'),
+    ('\u{0061}', '\u{0307}', '\u{0227}'),
+    ('\u{0061}', '\u{0308}', '\u{00E4}'),
+    ('\u{0061}', '\u{0309}', '\u{1EA3}'),
+    ('\u{0061}', '\u{030A}', '\u{00E5}'),
+    ('\u{0061}', '\u{030C}', '\u{01CE}'),
+    ('\u{0061}', '\u{030F}', '\u{0201}'),
+    ('\u{0061}', '\u{0311}', '\u{0203}'),
+    ('\u{0061}', '\u{0323}', '\u{1EA1}'),
+    ('\u{0061}', '\u{0325}', '\u{1E01}'),
+    ('\u{0061}', '\u{0328}', '\u{0105}'),
+    ('\u{0062}', '\u{0307}', '\u{1E03}'),
+    ('\u{0062}', '\u{0323}', '\u{1E05}'),
+    ('\u{0062}', '\u{0331}', '\u{1E07}'),
+    ('\u{0063}', '\u{0301}', '\u{0107}'),
+    ('\u{0063}', '\u{0302}', '\u{0109}'),
+    ('\u{0063}', '\u{0307}', '\u{010B}'),
+    ('\u{0063}', '\u{030C}', '\u{010D}'),
+    ('\u{0063}', '\u{0327}', '\u{00E7}'),
+    ('\u{0064}', '\u{0307}', '\u{1E0B}'),
+    ('\u{0064}', '\u{030C}', '\u{010F}'),
+    ('\u{0064}', '\u{0323}', '\u{1E0D}'),
+    ('\u{0064}', '\u{0327}', '\u{1E11}'),
+    ('\u{0064}', '\u{032D}', '\u{1E13}'),
+    ('\u{0064}', '\u{0331}', '\u{1E0F}'),
+    ('\u{0065}', '\u{0300}', '\u{00E8}'),
+    ('\u{0065}', '\u{0301}', '\u{00E9}'),
+    ('\u{0065}', '\u{0302}', '\u{00EA}'),
+    ('\u{0065}', '\u{0303}', '\u{1EBD}'),
+    ('\u{0065}', '\u{0304}', '\u{0113}'),
+    ('\u{0065}', '\u{0306}', '\u{0115}'),
+    ('\u{0065}', '\u{0307}', '\u{0117}'),
+    ('\u{0065}', '\u{0308}', '\u{00EB}'),
+    ('\u{0065}', '\u{0309}', '\u{1EBB}'),
+    ('\u{0065}', '\u{030C}', '\u{011B}'),
+    ('\u{0065}', '\u{030F}', '\u{0205}'),
+    ('\u{0065}', '\u{0311}', '\u{0207}'),
+    ('\u{0065}', '\u{0323}', '\u{1EB9}'),
+    ('\u{0065}', '\u{0327}', '\u{0229}'),
+    ('\u{0065}', '\u{0328}', '\u{0119}'),
+    ('\u{0065}', '\u{032D}', '\u{1E19}'),
+    ('\u{0065}', '\u{0330}', '\u{1E1B}'),
+    ('\u{0066}', '\u{0307}', '\u{1E1F}'),
+    ('\u{0067}', '\u{0301}', '\u{01F5}'),
+    ('\u{0067}', '\u{0302}', '\u{011D}'),
+    ('\u{0067}', '\u{0304}', '\u{1E21}'),
+    ('\u{0067}', '\u{0306}', '\u{011F}'),
+    ('\u{0067}', '\u{0307}', '\u{0121}'),
+    ('\u{0067}', '\u{030C}', '\u{01E7}'),
+    ('\u{0067}', '\u{0327}', '\u{0123}'),
+    ('\u{0068}', '\u{0302}', '\u{0125}'),
+    ('\u{0068}', '\u{0307}', '\u{1E23}'),
+    ('\u{0068}', '\u{0308}', '\u{1E27}'),
+    ('\u{0068}', '\u{030C}', '\u{021F}'),
+    ('\u{0068}', '\u{0323}', '\u{1E25}'),
+    ('\u{0068}', '\u{0327}', '\u{1E29}'),
+    ('\u{0068}', '\u{032E}', '\u{1E2B}'),
+    ('\u{0068}', '\u{0331}', '\u{1E96}'),
+    ('\u{0069}', '\u{0300}', '\u{00EC}'),
+    ('\u{0069}', '\u{0301}', '\u{00ED}'),
+    ('\u{0069}', '\u{0302}', '\u{00EE}'),
+    ('\u{0069}', '\u{0303}', '\u{0129}'),
+    ('\u{0069}', '\u{0304}', '\u{012B}'),
+    ('\u{0069}', '\u{0306}', '\u{012D}'),
+    ('\u{0069}', '\u{0308}', '\u{00EF}'),
+    ('\u{0069}', '\u{0309}', '\u{1EC9}'),
+    ('\u{0069}', '\u{030C}', '\u{01D0}'),
+    ('\u{0069}', '\u{030F}', '\u{0209}'),
+    ('\u{0069}', '\u{0311}', '\u{020B}'),
+    ('\u{0069}', '\u{0323}', '\u{1ECB}'),
+    ('\u{0069}', '\u{0328}', '\u{012F}'),
+    ('\u{0069}', '\u{0330}', '\u{1E2D}'),
+    ('\u{006A}', '\u{0302}', '\u{0135}'),
+    ('\u{006A}', '\u{030C}', '\u{01F0}'),
+    ('\u{006B}', '\u{0301}', '\u{1E31}'),
+    ('\u{006B}', '\u{030C}', '\u{01E9}'),
+    ('\u{006B}', '\u{0323}', '\u{1E33}'),
+    ('\u{006B}', '\u{0327}', '\u{0137}'),
+    ('\u{006B}', '\u{0331}', '\u{1E35}'),
+    ('\u{006C}', '\u{0301}', '\u{013A}'),
+    ('\u{006C}', '\u{030C}', '\u{013E}'),
+    ('\u{006C}', '\u{0323}', '\u{1E37}'),
+    ('\u{006C}', '\u{0327}', '\u{013C}'),
+    ('\u{006C}', '\u{032D}', '\u{1E3D}'),
+    ('\u{006C}', '\u{0331}', '\u{1E3B}'),
+    ('\u{006D}', '\u{0301}', '\u{1E3F}'),
+    ('\u{006D}', '\u{0307}', '\u{1E41}'),
+    ('\u{006D}', '\u{0323}', '\u{1E43}'),
+    ('\u{006E}', '\u{0300}', '\u{01F9}'),
+    ('\u{006E}', '\u{0301}', '\u{0144}'),
+    ('\u{006E}', '\u{0303}', '\u{00F1}'),
+    ('\u{006E}', '\u{0307}', '\u{1E45}'),
+    ('\u{006E}', '\u{030C}', '\u{0148}'),
+    ('\u{006E}', '\u{0323}', '\u{1E47}'),
+    ('\u{006E}', '\u{0327}', '\u{0146}'),
+    ('\u{006E}', '\u{032D}', '\u{1E4B}'),
+    ('\u{006E}', '\u{0331}', '\u{1E49}'),
+    ('\u{006F}', '\u{0300}', '\u{00F2}'),
+    ('\u{006F}', '\u{0301}', '\u{00F3}'),
+    ('\u{006F}', '\u{0302}', '\u{00F4}'),
+    ('\u{006F}', '\u{0303}', '\u{00F5}'),
+    ('\u{006F}', '\u{0304}', '\u{014D}'),
+    ('\u{006F}', '\u{0306}', '\u{014F}'),
+    ('\u{006F}', '\u{0307}', '\u{022F}'),
+    ('\u{006F}', '\u{0308}', '\u{00F6}'),
+    ('\u{006F}', '\u{0309}', '\u{1ECF}'),
+    ('\u{006F}', '\u{030B}', '\u{0151}'),
+    ('\u{006F}', '\u{030C}', '\u{01D2}'),
+    ('\u{006F}', '\u{030F}', '\u{020D}'),
+    ('\u{006F}', '\u{0311}', '\u{020F}'),
+    ('\u{006F}', '\u{031B}', '\u{01A1}'),
+    ('\u{006F}', '\u{0323}', '\u{1ECD}'),
+    ('\u{006F}', '\u{0328}', '\u{01EB}'),
+    ('\u{0070}', '\u{0301}', '\u{1E55}'),
+    ('\u{0070}', '\u{0307}', '\u{1E57}'),
+    ('\u{0072}', '\u{0301}', '\u{0155}'),
+    ('\u{0072}', '\u{0307}', '\u{1E59}'),
+    ('\u{0072}', '\u{030C}', '\u{0159}'),
+    ('\u{0072}', '\u{030F}', '\u{0211}'),
+    ('\u{0072}', '\u{0311}', '\u{0213}'),
+    ('\u{0072}', '\u{0323}', '\u{1E5B}'),
+    ('\u{0072}', '\u{0327}', '\u{0157}'),
+    ('\u{0072}', '\u{0331}', '\u{1E5F}'),
+    ('\u{0073}', '\u{0301}', '\u{015B}'),
+    ('\u{0073}', '\u{0302}', '\u{015D}'),
+    ('\u{0073}', '\u{0307}', '\u{1E61}'),
+    ('\u{0073}', '\u{030C}', '\u{0161}'),
+    ('\u{0073}', '\u{0323}', '\u{1E63}'),
+    ('\u{0073}', '\u{0326}', '\u{0219}'),
+    ('\u{0073}', '\u{0327}', '\u{015F}'),
+    ('\u{0074}', '\u{0307}', '\u{1E6B}'),
+    ('\u{0074}', '\u{0308}', '\u{1E97}'),
+    ('\u{0074}', '\u{030C}', '\u{0165}'),
+    ('\u{0074}', '\u{0323}', '\u{1E6D}'),
+    ('\u{0074}', '\u{0326}', '\u{021B}'),
+    ('\u{0074}', '\u{0327}', '\u{0163}'),
+    ('\u{0074}', '\u{032D}', '\u{1E71}'),
+    ('\u{0074}', '\u{0331}', '\u{1E6F}'),
+    ('\u{0075}', '\u{0300}', '\u{00F9}'),
+    ('\u{0075}', '\u{0301}', '\u{00FA}'),
+    ('\u{0075}', '\u{0302}', '\u{00FB}'),
+    ('\u{0075}', '\u{0303}', '\u{0169}'),
+    ('\u{0075}', '\u{0304}', '\u{016B}'),
+    ('\u{0075}', '\u{0306}', '\u{016D}'),
+    ('\u{0075}', '\u{0308}', '\u{00FC}'),
+    ('\u{0075}', '\u{0309}', '\u{1EE7}'),
+    ('\u{0075}', '\u{030A}', '\u{016F}'),
+    ('\u{0075}', '\u{030B}', '\u{0171}'),
+    ('\u{0075}', '\u{030C}', '\u{01D4}'),
+    ('\u{0075}', '\u{030F}', '\u{0215}'),
+    ('\u{0075}', '\u{0311}', '\u{0217}'),
+    ('\u{0075}', '\u{031B}', '\u{01B0}'),
+    ('\u{0075}', '\u{0323}', '\u{1EE5}'),
+    ('\u{0075}', '\u{0324}', '\u{1E73}'),
+    ('\u{0075}', '\u{0328}', '\u{0173}'),
+    ('\u{0075}', '\u{032D}', '\u{1E77}'),
+    ('\u{0075}', '\u{0330}', '\u{1E75}'),
+    ('\u{0076}', '\u{0303}', '\u{1E7D}'),
+    ('\u{0076}', '\u{0323}', '\u{1E7F}'),
+    ('\u{0077}', '\u{0300}', '\u{1E81}'),
+    ('\u{0077}', '\u{0301}', '\u{1E83}'),
+    ('\u{0077}', '\u{0302}', '\u{0175}'),
+    ('\u{0077}', '\u{0307}', '\u{1E87}'),
+    ('\u{0077}', '\u{0308}', '\u{1E85}'),
+    ('\u{0077}', '\u{030A}', '\u{1E98}'),
+    ('\u{0077}', '\u{0323}', '\u{1E89}'),
+    ('\u{0078}', '\u{0307}', '\u{1E8B}'),
+    ('\u{0078}', '\u{0308}', '\u{1E8D}'),
+    ('\u{0079}', '\u{0300}', '\u{1EF3}'),
+    ('\u{0079}', '\u{0301}', '\u{00FD}'),
+    ('\u{0079}', '\u{0302}', '\u{0177}'),
+    ('\u{0079}', '\u{0303}', '\u{1EF9}'),
+    ('\u{0079}', '\u{0304}', '\u{0233}'),
+    ('\u{0079}', '\u{0307}', '\u{1E8F}'),
+    ('\u{0079}', '\u{0308}', '\u{00FF}'),
+    ('\u{0079}', '\u{0309}', '\u{1EF7}'),
+    ('\u{0079}', '\u{030A}', '\u{1E99}'),
+    ('\u{0079}', '\u{0323}', '\u{1EF5}'),
+    ('\u{007A}', '\u{0301}', '\u{017A}'),
+    ('\u{007A}', '\u{0302}', '\u{1E91}'),
+    ('\u{007A}', '\u{0307}', '\u{017C}'),
+    ('\u{007A}', '\u{030C}', '\u{017E}'),
+    ('\u{007A}', '\u{0323}', '\u{1E93}'),
+    ('\u{007A}', '\u{0331}', '\u{1E95}'),
+    ('\u{00A8}', '\u{0300}', '\u{1FED}'),
+    ('\u{00A8}', '\u{0301}', '\u{0385}'),
+    ('\u{00A8}', '\u{0342}', '\u{1FC1}'),
+    ('\u{00C2}', '\u{0300}', '\u{1EA6}'),
+    ('\u{00C2}', '\u{0301}', '\u{1EA4}'),
+    ('\u{00C2}', '\u{0303}', '\u{1EAA}'),
+    ('\u{00C2}', '\u{0309}', '\u{1EA8}'),
+    ('\u{00C4}', '\u{0304}', '\u{01DE}'),
+    ('\u{00C5}', '\u{0301}', '\u{01FA}'),
+    ('\u{00C6}', '\u{0301}', '\u{01FC}'),
+    ('\u{00C6}', '\u{0304}', '\u{01E2}'),
+    ('\u{00C7}', '\u{0301}', '\u{1E08}'),
+    ('\u{00CA}', '\u{0300}', '\u{1EC0}'),
+    ('\u{00CA}', '\u{0301}', '\u{1EBE}'),
+    ('\u{00CA}', '\u{0303}', '\u{1EC4}'),
+    ('\u{00CA}', '\u{0309}', '\u{1EC2}'),
+    ('\u{00CF}', '\u{0301}', '\u{1E2E}'),
+    ('\u{00D4}', '\u{0300}', '\u{1ED2}'),
+    ('\u{00D4}', '\u{0301}', '\u{1ED0}'),
+    ('\u{00D4}', '\u{0303}', '\u{1ED6}'),
+    ('\u{00D4}', '\u{0309}', '\u{1ED4}'),
+    ('\u{00D5}', '\u{0301}', '\u{1E4C}'),
+    ('\u{00D5}', '\u{0304}', '\u{022C}'),
+    ('\u{00D5}', '\u{0308}', '\u{1E4E}'),
+    ('\u{00D6}', '\u{0304}', '\u{022A}'),
+    ('\u{00D8}', '\u{0301}', '\u{01FE}'),
+    ('\u{00DC}', '\u{0300}', '\u{01DB}'),
+    ('\u{00DC}', '\u{0301}', '\u{01D7}'),
+    ('\u{00DC}', '\u{0304}', '\u{01D5}'),
+    ('\u{00DC}', '\u{030C}', '\u{01D9}'),
+    ('\u{00E2}', '\u{0300}', '\u{1EA7}'),
+    ('\u{00E2}', '\u{0301}', '\u{1EA5}'),
+    ('\u{00E2}', '\u{0303}', '\u{1EAB}'),
+    ('\u{00E2}', '\u{0309}', '\u{1EA9}'),
+    ('\u{00E4}', '\u{0304}', '\u{01DF}'),
+    ('\u{00E5}', '\u{0301}', '\u{01FB}'),
+    ('\u{00E6}', '\u{0301}', '\u{01FD}'),
+    ('\u{00E6}', '\u{0304}', '\u{01E3}'),
+    ('\u{00E7}', '\u{0301}', '\u{1E09}'),
+    ('\u{00EA}', '\u{0300}', '\u{1EC1}'),
+    ('\u{00EA}', '\u{0301}', '\u{1EBF}'),
+    ('\u{00EA}', '\u{0303}', '\u{1EC5}'),
+    ('\u{00EA}', '\u{0309}', '\u{1EC3}'),
+    ('\u{00EF}', '\u{0301}', '\u{1E2F}'),
+    ('\u{00F4}', '\u{0300}', '\u{1ED3}'),
+    ('\u{00F4}', '\u{0301}', '\u{1ED1}'),
+    ('\u{00F4}', '\u{0303}', '\u{1ED7}'),
+    ('\u{00F4}', '\u{0309}', '\u{1ED5}'),
+    ('\u{00F5}', '\u{0301}', '\u{1E4D}'),
+    ('\u{00F5}', '\u{0304}', '\u{022D}'),
+    ('\u{00F5}', '\u{0308}', '\u{1E4F}'),
+    ('\u{00F6}', '\u{0304}', '\u{022B}'),
+    ('\u{00F8}', '\u{0301}', '\u{01FF}'),
+    ('\u{00FC}', '\u{0300}', '\u{01DC}'),
+    ('\u{00FC}', '\u{0301}', '\u{01D8}'),
+    ('\u{00FC}', '\u{0304}', '\u{01D6}'),
+    ('\u{00FC}', '\u{030C}', '\u{01DA}'),
+    ('\u{0102}', '\u{0300}', '\u{1EB0}'),
+    ('\u{0102}', '\u{0301}', '\u{1EAE}'),
+    ('\u{0102}', '\u{0303}', '\u{1EB4}'),
+    ('\u{0102}', '\u{0309}', '\u{1EB2}'),
+    ('\u{0103}', '\u{0300}', '\u{1EB1}'),
+    ('\u{0103}', '\u{0301}', '\u{1EAF}'),
+    ('\u{0103}', '\u{0303}', '\u{1EB5}'),
+    ('\u{0103}', '\u{0309}', '\u{1EB3}'),
+    ('\u{0112}', '\u{0300}', '\u{1E14}'),
+    ('\u{0112}', '\u{0301}', '\u{1E16}'),
+    ('\u{0113}', '\u{0300}', '\u{1E15}'),
+    ('\u{0113}', '\u{0301}', '\u{1E17}'),
+    ('\u{014C}', '\u{0300}', '\u{1E50}'),
+    ('\u{014C}', '\u{0301}', '\u{1E52}'),
+    ('\u{014D}', '\u{0300}', '\u{1E51}'),
+    ('\u{014D}', '\u{0301}', '\u{1E53}'),
+    ('\u{015A}', '\u{0307}', '\u{1E64}'),
+    ('\u{015B}', '\u{0307}', '\u{1E65}'),
+    ('\u{0160}', '\u{0307}', '\u{1E66}'),
+    ('\u{0161}', '\u{0307}', '\u{1E67}'),
+    ('\u{0168}', '\u{0301}', '\u{1E78}'),
+    ('\u{0169}', '\u{0301}', '\u{1E79}'),
+    ('\u{016A}', '\u{0308}', '\u{1E7A}'),
+    ('\u{016B}', '\u{0308}', '\u{1E7B}'),
+    ('\u{017F}', '\u{0307}', '\u{1E9B}'),
+    ('\u{01A0}', '\u{0300}', '\u{1EDC}'),
+    ('\u{01A0}', '\u{0301}', '\u{1EDA}'),
+    ('\u{01A0}', '\u{0303}', '\u{1EE0}'),
+    ('\u{01A0}', '\u{0309}', '\u{1EDE}'),
+    ('\u{01A0}', '\u{0323}', '\u{1EE2}'),
+    ('\u{01A1}', '\u{0300}', '\u{1EDD}'),
+    ('\u{01A1}', '\u{0301}', '\u{1EDB}'),
+    ('\u{01A1}', '\u{0303}', '\u{1EE1}'),
+    ('\u{01A1}', '\u{0309}', '\u{1EDF}'),
+    ('\u{01A1}', '\u{0323}', '\u{1EE3}'),
+    ('\u{01AF}', '\u{0300}', '\u{1EEA}'),
+    ('\u{01AF}', '\u{0301}', '\u{1EE8}'),
+    ('\u{01AF}', '\u{0303}', '\u{1EEE}'),
+    ('\u{01AF}', '\u{0309}', '\u{1EEC}'),
+    ('\u{01AF}', '\u{0323}', '\u{1EF0}'),
+    ('\u{01B0}', '\u{0300}', '\u{1EEB}'),
+    ('\u{01B0}', '\u{0301}', '\u{1EE9}'),
+    ('\u{01B0}', '\u{0303}', '\u{1EEF}'),
+    ('\u{01B0}', '\u{0309}', '\u{1EED}'),
+    ('\u{01B0}', '\u{0323}', '\u{1EF1}'),
+    ('\u{01B7}', '\u{030C}', '\u{01EE}'),
+    ('\u{01EA}', '\u{0304}', '\u{01EC}'),
+    ('\u{01EB}', '\u{0304}', '\u{01ED}'),
+    ('\u{0226}', '\u{0304}', '\u{01E0}'),
+    ('\u{0227}', '\u{0304}', '\u{01E1}'),
+    ('\u{0228}', '\u{0306}', '\u{1E1C}'),
+    ('\u{0229}', '\u{0306}', '\u{1E1D}'),
+    ('\u{022E}', '\u{0304}', '\u{0230}'),
+    ('\u{022F}', '\u{0304}', '\u{0231}'),
+    ('\u{0292}', '\u{030C}', '\u{01EF}'),
+    ('\u{0391}', '\u{0300}', '\u{1FBA}'),
+    ('\u{0391}', '\u{0301}', '\u{0386}'),
+    ('\u{0391}', '\u{0304}', '\u{1FB9}'),
+    ('\u{0391}', '\u{0306}', '\u{1FB8}'),
+    ('\u{0391}', '\u{0313}', '\u{1F08}'),
+    ('\u{0391}', '\u{0314}', '\u{1F09}'),
+    ('\u{0391}', '\u{0345}', '\u{1FBC}'),
+    ('\u{0395}', '\u{0300}', '\u{1FC8}'),
+    ('\u{0395}', '\u{0301}', '\u{0388}'),
+    ('\u{0395}', '\u{0313}', '\u{1F18}'),
+    ('\u{0395}', '\u{0314}', '\u{1F19}'),
+    ('\u{0397}', '\u{0300}', '\u{1FCA}'),
+    ('\u{0397}', '\u{0301}', '\u{0389}'),
+    ('\u{0397}', '\u{0313}', '\u{1F28}'),
+    ('\u{0397}', '\u{0314}', '\u{1F29}'),
+    ('\u{0397}', '\u{0345}', '\u{1FCC}'),
+    ('\u{0399}', '\u{0300}', '\u{1FDA}'),
+    ('\u{0399}', '\u{0301}', '\u{038A}'),
+    ('\u{0399}', '\u{0304}', '\u{1FD9}'),
+    ('\u{0399}', '\u{0306}', '\u{1FD8}'),
+    ('\u{0399}', '\u{0308}', '\u{03AA}'),
+    ('\u{0399}', '\u{0313}', '\u{1F38}'),
+    ('\u{0399}', '\u{0314}', '\u{1F39}'),
+    ('\u{039F}', '\u{0300}', '\u{1FF8}'),
+    ('\u{039F}', '\u{0301}', '\u{038C}'),
+    ('\u{039F}', '\u{0313}', '\u{1F48}'),
+    ('\u{039F}', '\u{0314}', '\u{1F49}'),
+    ('\u{03A1}', '\u{0314}', '\u{1FEC}'),
+    ('\u{03A5}', '\u{0300}', '\u{1FEA}'),
+    ('\u{03A5}', '\u{0301}', '\u{038E}'),
+    ('\u{03A5}', '\u{0304}', '\u{1FE9}'),
+    ('\u{03A5}', '\u{0306}', '\u{1FE8}'),
+    ('\u{03A5}', '\u{0308}', '\u{03AB}'),
+    ('\u{03A5}', '\u{0314}', '\u{1F59}'),
+    ('\u{03A9}', '\u{0300}', '\u{1FFA}'),
+    ('\u{03A9}', '\u{0301}', '\u{038F}'),
+    ('\u{03A9}', '\u{0313}', '\u{1F68}'),
+    ('\u{03A9}', '\u{0314}', '\u{1F69}'),
+    ('\u{03A9}', '\u{0345}', '\u{1FFC}'),
+    ('\u{03AC}', '\u{0345}', '\u{1FB4}'),
+    ('\u{03AE}', '\u{0345}', '\u{1FC4}'),
+    ('\u{03B1}', '\u{0300}', '\u{1F70}'),
+    ('\u{03B1}', '\u{0301}', '\u{03AC}'),
+    ('\u{03B1}', '\u{0304}', '\u{1FB1}'),
+    ('\u{03B1}', '\u{0306}', '\u{1FB0}'),
+    ('\u{03B1}', '\u{0313}', '\u{1F00}'),
+    ('\u{03B1}', '\u{0314}', '\u{1F01}'),
+    ('\u{03B1}', '\u{0342}', '\u{1FB6}'),
+    ('\u{03B1}', '\u{0345}', '\u{1FB3}'),
+    ('\u{03B5}', '\u{0300}', '\u{1F72}'),
+    ('\u{03B5}', '\u{0301}', '\u{03AD}'),
+    ('\u{03B5}', '\u{0313}', '\u{1F10}'),
+    ('\u{03B5}', '\u{0314}', '\u{1F11}'),
+    ('\u{03B7}', '\u{0300}', '\u{1F74}'),
+    ('\u{03B7}', '\u{0301}', '\u{03AE}'),
+    ('\u{03B7}', '\u{0313}', '\u{1F20}'),
+    ('\u{03B7}', '\u{0314}', '\u{1F21}'),
+    ('\u{03B7}', '\u{0342}', '\u{1FC6}'),
+    ('\u{03B7}', '\u{0345}', '\u{1FC3}'),
+    ('\u{03B9}', '\u{0300}', '\u{1F76}'),
+    ('\u{03B9}', '\u{0301}', '\u{03AF}'),
+    ('\u{03B9}', '\u{0304}', '\u{1FD1}'),
+    ('\u{03B9}', '\u{0306}', '\u{1FD0}'),
+    ('\u{03B9}', '\u{0308}', '\u{03CA}'),
+    ('\u{03B9}', '\u{0313}', '\u{1F30}'),
+    ('\u{03B9}', '\u{0314}', '\u{1F31}'),
+    ('\u{03B9}', '\u{0342}', '\u{1FD6}'),
+    ('\u{03BF}', '\u{0300}', '\u{1F78}'),
+    ('\u{03BF}', '\u{0301}', '\u{03CC}'),
+    ('\u{03BF}', '\u{0313}', '\u{1F40}'),
+    ('\u{03BF}', '\u{0314}', '\u{1F41}'),
+    ('\u{03C1}', '\u{0313}', '\u{1FE4}'),
+    ('\u{03C1}', '\u{0314}', '\u{1FE5}'),
+    ('\u{03C5}', '\u{0300}', '\u{1F7A}'),
+    ('\u{03C5}', '\u{0301}', '\u{03CD}'),
+    ('\u{03C5}', '\u{0304}', '\u{1FE1}'),
+    ('\u{03C5}', '\u{0306}', '\u{1FE0}'),
+    ('\u{03C5}', '\u{0308}', '\u{03CB}'),
+    ('\u{03C5}', '\u{0313}', '\u{1F50}'),
+    ('\u{03C5}', '\u{0314}', '\u{1F51}'),
+    ('\u{03C5}', '\u{0342}', '\u{1FE6}'),
+    ('\u{03C9}', '\u{0300}', '\u{1F7C}'),
+    ('\u{03C9}', '\u{0301}', '\u{03CE}'),
+    ('\u{03C9}', '\u{0313}', '\u{1F60}'),
+    ('\u{03C9}', '\u{0314}', '\u{1F61}'),
+    ('\u{03C9}', '\u{0342}', '\u{1FF6}'),
+    ('\u{03C9}', '\u{0345}', '\u{1FF3}'),
+    ('\u{03CA}', '\u{0300}', '\u{1FD2}'),
+    ('\u{03CA}', '\u{0301}', '\u{0390}'),
+    ('\u{03CA}', '\u{0342}', '\u{1FD7}'),
+    ('\u{03CB}', '\u{0300}', '\u{1FE2}'),
+    ('\u{03CB}', '\u{0301}', '\u{03B0}'),
+    ('\u{03CB}', '\u{0342}', '\u{1FE7}'),
+    ('\u{03CE}', '\u{0345}', '\u{1FF4}'),
+    ('\u{03D2}', '\u{0301}', '\u{03D3}'),
+    ('\u{03D2}', '\u{0308}', '\u{03D4}'),
+    ('\u{0406}', '\u{0308}', '\u{0407}'),
+    ('\u{0410}', '\u{0306}', '\u{04D0}'),
+    ('\u{0410}', '\u{0308}', '\u{04D2}'),
+    ('\u{0413}', '\u{0301}', '\u{0403}'),
+    ('\u{0415}', '\u{0300}', '\u{0400}'),
+    ('\u{0415}', '\u{0306}', '\u{04D6}'),
+    ('\u{0415}', '\u{0308}', '\u{0401}'),
+    ('\u{0416}', '\u{0306}', '\u{04C1}'),
+    ('\u{0416}', '\u{0308}', '\u{04DC}'),
+    ('\u{0417}', '\u{0308}', '\u{04DE}'),
+    ('\u{0418}', '\u{0300}', '\u{040D}'),
+    ('\u{0418}', '\u{0304}', '\u{04E2}'),
+    ('\u{0418}', '\u{0306}', '\u{0419}'),
+    ('\u{0418}', '\u{0308}', '\u{04E4}'),
+    ('\u{041A}', '\u{0301}', '\u{040C}'),
+    ('\u{041E}', '\u{0308}', '\u{04E6}'),
+    ('\u{0423}', '\u{0304}', '\u{04EE}'),
+    ('\u{0423}', '\u{0306}', '\u{040E}'),
+    ('\u{0423}', '\u{0308}', '\u{04F0}'),
+    ('\u{0423}', '\u{030B}', '\u{04F2}'),
+    ('\u{0427}', '\u{0308}', '\u{04F4}'),
+    ('\u{042B}', '\u{0308}', '\u{04F8}'),
+    ('\u{042D}', '\u{0308}', '\u{04EC}'),
+    ('\u{0430}', '\u{0306}', '\u{04D1}'),
+    ('\u{0430}', '\u{0308}', '\u{04D3}'),
+    ('\u{0433}', '\u{0301}', '\u{0453}'),
+    ('\u{0435}', '\u{0300}', '\u{0450}'),
+    ('\u{0435}', '\u{0306}', '\u{04D7}'),
+    ('\u{0435}', '\u{0308}', '\u{0451}'),
+    ('\u{0436}', '\u{0306}', '\u{04C2}'),
+    ('\u{0436}', '\u{0308}', '\u{04DD}'),
+    ('\u{0437}', '\u{0308}', '\u{04DF}'),
+    ('\u{0438}', '\u{0300}', '\u{045D}'),
+    ('\u{0438}', '\u{0304}', '\u{04E3}'),
+    ('\u{0438}', '\u{0306}', '\u{0439}'),
+    ('\u{0438}', '\u{0308}', '\u{04E5}'),
+    ('\u{043A}', '\u{0301}', '\u{045C}'),
+    ('\u{043E}', '\u{0308}', '\u{04E7}'),
+    ('\u{0443}', '\u{0304}', '\u{04EF}'),
+    ('\u{0443}', '\u{0306}', '\u{045E}'),
+    ('\u{0443}', '\u{0308}', '\u{04F1}'),
+    ('\u{0443}', '\u{030B}', '\u{04F3}'),
+    ('\u{0447}', '\u{0308}', '\u{04F5}'),
+    ('\u{044B}', '\u{0308}', '\u{04F9}'),
+    ('\u{044D}', '\u{0308}', '\u{04ED}'),
+    ('\u{0456}', '\u{0308}', '\u{0457}'),
+    ('\u{0474}', '\u{030F}', '\u{0476}'),
+    ('\u{0475}', '\u{030F}', '\u{0477}'),
+    ('\u{04D8}', '\u{0308}', '\u{04DA}'),
+    ('\u{04D9}', '\u{0308}', '\u{04DB}'),
+    ('\u{04E8}', '\u{0308}', '\u{04EA}'),
+    ('\u{04E9}', '\u{0308}', '\u{04EB}'),
+    ('\u{0627}', '\u{0653}', '\u{0622}'),
+    ('\u{0627}', '\u{0654}', '\u{0623}'),
+    ('\u{0627}', '\u{0655}', '\u{0625}'),
+    ('\u{0648}', '\u{0654}', '\u{0624}'),
+    ('\u{064A}', '\u{0654}', '\u{0626}'),
+    ('\u{06C1}', '\u{0654}', '\u{06C2}'),
+    ('\u{06D2}', '\u{0654}', '\u{06D3}'),
+    ('\u{06D5}', '\u{0654}', '\u{06C0}'),
+    ('\u{0928}', '\u{093C}', '\u{0929}'),
+    ('\u{0930}', '\u{093C}', '\u{0931}'),
+    ('\u{0933}', '\u{093C}', '\u{0934}'),
+    ('\u{09C7}', '\u{09BE}', '\u{09CB}'),
+    ('\u{09C7}', '\u{09D7}', '\u{09CC}'),
+    ('\u{0B47}', '\u{0B3E}', '\u{0B4B}'),
+    ('\u{0B47}', '\u{0B56}', '\u{0B48}'),
+    ('\u{0B47}', '\u{0B57}', '\u{0B4C}'),
+    ('\u{0B92}', '\u{0BD7}', '\u{0B94}'),
+    ('\u{0BC6}', '\u{0BBE}', '\u{0BCA}'),
+    ('\u{0BC6}', '\u{0BD7}', '\u{0BCC}'),
+    ('\u{0BC7}', '\u{0BBE}', '\u{0BCB}'),
+    ('\u{0C46}', '\u{0C56}', '\u{0C48}'),
+    ('\u{0CBF}', '\u{0CD5}', '\u{0CC0}'),
+    ('\u{0CC6}', '\u{0CC2}', '\u{0CCA}'),
+    ('\u{0CC6}', '\u{0CD5}', '\u{0CC7}'),
+    ('\u{0CC6}', '\u{0CD6}', '\u{0CC8}'),
+    ('\u{0CCA}', '\u{0CD5}', '\u{0CCB}'),
+    ('\u{0D46}', '\u{0D3E}', '\u{0D4A}'),
+    ('\u{0D46}', '\u{0D57}', '\u{0D4C}'),
+    ('\u{0D47}', '\u{0D3E}', '\u{0D4B}'),
+    ('\u{0DD9}', '\u{0DCA}', '\u{0DDA}'),
+    ('\u{0DD9}', '\u{0DCF}', '\u{0DDC}'),
+    ('\u{0DD9}', '\u{0DDF}', '\u{0DDE}'),
+    ('\u{0DDC}', '\u{0DCA}', '\u{0DDD}'),
+    ('\u{1025}', '\u{102E}', '\u{1026}'),
+    ('\u{1B05}', '\u{1B35}', '\u{1B06}'),
+    ('\u{1B07}', '\u{1B35}', '\u{1B08}'),
+    ('\u{1B09}', '\u{1B35}', '\u{1B0A}'),
+    ('\u{1B0B}', '\u{1B35}', '\u{1B0C}'),
+    ('\u{1B0D}', '\u{1B35}', '\u{1B0E}'),
+    ('\u{1B11}', '\u{1B35}', '\u{1B12}'),
+    ('\u{1B3A}', '\u{1B35}', '\u{1B3B}'),
+    ('\u{1B3C}', '\u{1B35}', '\u{1B3D}'),
+    ('\u{1B3E}', '\u{1B35}', '\u{1B40}'),
+    ('\u{1B3F}', '\u{1B35}', '\u{1B41}'),
+    ('\u{1B42}', '\u{1B35}', '\u{1B43}'),
+    ('\u{1E36}', '\u{0304}', '\u{1E38}'),
+    ('\u{1E37}', '\u{0304}', '\u{1E39}'),
+    ('\u{1E5A}', '\u{0304}', '\u{1E5C}'),
+    ('\u{1E5B}', '\u{0304}', '\u{1E5D}'),
+    ('\u{1E62}', '\u{0307}', '\u{1E68}'),
+    ('\u{1E63}', '\u{0307}', '\u{1E69}'),
+    ('\u{1EA0}', '\u{0302}', '\u{1EAC}'),
+    ('\u{1EA0}', '\u{0306}', '\u{1EB6}'),
+    ('\u{1EA1}', '\u{0302}', '\u{1EAD}'),
+    ('\u{1EA1}', '\u{0306}', '\u{1EB7}'),
+    ('\u{1EB8}', '\u{0302}', '\u{1EC6}'),
+    ('\u{1EB9}', '\u{0302}', '\u{1EC7}'),
+    ('\u{1ECC}', '\u{0302}', '\u{1ED8}'),
+    ('\u{1ECD}', '\u{0302}', '\u{1ED9}'),
+    ('\u{1F00}', '\u{0300}', '\u{1F02}'),
+    ('\u{1F00}', '\u{0301}', '\u{1F04}'),
+    ('\u{1F00}', '\u{0342}', '\u{1F06}'),
+    ('\u{1F00}', '\u{0345}', '\u{1F80}'),
+    ('\u{1F01}', '\u{0300}', '\u{1F03}'),
+    ('\u{1F01}', '\u{0301}', '\u{1F05}'),
+    ('\u{1F01}', '\u{0342}', '\u{1F07}'),
+    ('\u{1F01}', '\u{0345}', '\u{1F81}'),
+    ('\u{1F02}', '\u{0345}', '\u{1F82}'),
+    ('\u{1F03}', '\u{0345}', '\u{1F83}'),
+    ('\u{1F04}', '\u{0345}', '\u{1F84}'),
+    ('\u{1F05}', '\u{0345}', '\u{1F85}'),
+    ('\u{1F06}', '\u{0345}', '\u{1F86}'),
+    ('\u{1F07}', '\u{0345}', '\u{1F87}'),
+    ('\u{1F08}', '\u{0300}', '\u{1F0A}'),
+    ('\u{1F08}', '\u{0301}', '\u{1F0C}'),
+    ('\u{1F08}', '\u{0342}', '\u{1F0E}'),
+    ('\u{1F08}', '\u{0345}', '\u{1F88}'),
+    ('\u{1F09}', '\u{0300}', '\u{1F0B}'),
+    ('\u{1F09}', '\u{0301}', '\u{1F0D}'),
+    ('\u{1F09}', '\u{0342}', '\u{1F0F}'),
+    ('\u{1F09}', '\u{0345}', '\u{1F89}'),
+    ('\u{1F0A}', '\u{0345}', '\u{1F8A}'),
+    ('\u{1F0B}', '\u{0345}', '\u{1F8B}'),
+    ('\u{1F0C}', '\u{0345}', '\u{1F8C}'),
+    ('\u{1F0D}', '\u{0345}', '\u{1F8D}'),
+    ('\u{1F0E}', '\u{0345}', '\u{1F8E}'),
+    ('\u{1F0F}', '\u{0345}', '\u{1F8F}'),
+    ('\u{1F10}', '\u{0300}', '\u{1F12}'),
+    ('\u{1F10}', '\u{0301}', '\u{1F14}'),
+    ('\u{1F11}', '\u{0300}', '\u{1F13}'),
+    ('\u{1F11}', '\u{0301}', '\u{1F15}'),
+    ('\u{1F18}', '\u{0300}', '\u{1F1A}'),
+    ('\u{1F18}', '\u{0301}', '\u{1F1C}'),
+    ('\u{1F19}', '\u{0300}', '\u{1F1B}'),
+    ('\u{1F19}', '\u{0301}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{0300}', '\u{1F22}'),
+    ('\u{1F20}', '\u{0301}', '\u{1F24}'),
+    ('\u{1F20}', '\u{0342}', '\u{1F26}'),
+    ('\u{1F20}', '\u{0345}', '\u{1F90}'),
+    ('\u{1F21}', '\u{0300}', '\u{1F23}'),
+    ('\u{1F21}', '\u{0301}', '\u{1F25}'),
+    ('\u{1F21}', '\u{0342}', '\u{1F27}'),
+    ('\u{1F21}', '\u{0345}', '\u{1F91}'),
+    ('\u{1F22}', '\u{0345}', '\u{1F92}'),
+    ('\u{1F23}', '\u{0345}', '\u{1F93}'),
+    ('\u{1F24}', '\u{0345}', '\u{1F94}'),
+    ('\u{1F25}', '\u{0345}', '\u{1F95}'),
+    ('\u{1F26}', '\u{0345}', '\u{1F96}'),
+    ('\u{1F27}', '\u{0345}', '\u{1F97}'),
+    ('\u{1F28}', '\u{0300}', '\u{1F2A}'),
+    ('\u{1F28}', '\u{0301}', '\u{1F2C}'),
+    ('\u{1F28}', '\u{0342}', '\u{1F2E}'),
+    ('\u{1F28}', '\u{0345}', '\u{1F98}'),
+    ('\u{1F29}', '\u{0300}', '\u{1F2B}'),
+    ('\u{1F29}', '\u{0301}', '\u{1F2D}'),
+    ('\u{1F29}', '\u{0342}', '\u{1F2F}'),
+    ('\u{1F29}', '\u{0345}', '\u{1F99}'),
+    ('\u{1F2A}', '\u{0345}', '\u{1F9A}'),
+    ('\u{1F2B}', '\u{0345}', '\u{1F9B}'),
+    ('\u{1F2C}', '\u{0345}', '\u{1F9C}'),
+    ('\u{1F2D}', '\u{0345}', '\u{1F9D}'),
+    ('\u{1F2E}', '\u{0345}', '\u{1F9E}'),
+    ('\u{1F2F}', '\u{0345}', '\u{1F9F}'),
+    ('\u{1F30}', '\u{0300}', '\u{1F32}'),
+    ('\u{1F30}', '\u{0301}', '\u{1F34}'),
+    ('\u{1F30}', '\u{0342}', '\u{1F36}'),
+    ('\u{1F31}', '\u{0300}', '\u{1F33}'),
+    ('\u{1F31}', '\u{0301}', '\u{1F35}'),
+    ('\u{1F31}', '\u{0342}', '\u{1F37}'),
+    ('\u{1F38}', '\u{0300}', '\u{1F3A}'),
+    ('\u{1F38}', '\u{0301}', '\u{1F3C}'),
+    ('\u{1F38}', '\u{0342}', '\u{1F3E}'),
+    ('\u{1F39}', '\u{0300}', '\u{1F3B}'),
+    ('\u{1F39}', '\u{0301}', '\u{1F3D}'),
+    ('\u{1F39}', '\u{0342}', '\u{1F3F}'),
+    ('\u{1F40}', '\u{0300}', '\u{1F42}'),
+    ('\u{1F40}', '\u{0301}', '\u{1F44}'),
+    ('\u{1F41}', '\u{0300}', '\u{1F43}'),
+    ('\u{1F41}', '\u{0301}', '\u{1F45}'),
+    ('\u{1F48}', '\u{0300}', '\u{1F4A}'),
+    ('\u{1F48}', '\u{0301}', '\u{1F4C}'),
+    ('\u{1F49}', '\u{0300}', '\u{1F4B}'),
+    ('\u{1F49}', '\u{0301}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{0300}', '\u{1F52}'),
+    ('\u{1F50}', '\u{0301}', '\u{1F54}'),
+    ('\u{1F50}', '\u{0342}', '\u{1F56}'),
+    ('\u{1F51}', '\u{0300}', '\u{1F53}'),
+    ('\u{1F51}', '\u{0301}', '\u{1F55}'),
+    ('\u{1F51}', '\u{0342}', '\u{1F57}'),
+    ('\u{1F59}', '\u{0300}', '\u{1F5B}'),
+    ('\u{1F59}', '\u{0301}', '\u{1F5D}'),
+    ('\u{1F59}', '\u{0342}', '\u{1F5F}'),
+    ('\u{1F60}', '\u{0300}', '\u{1F62}'),
+    ('\u{1F60}', '\u{0301}', '\u{1F64}'),
+    ('\u{1F60}', '\u{0342}', '\u{1F66}'),
+    ('\u{1F60}', '\u{0345}', '\u{1FA0}'),
+    ('\u{1F61}', '\u{0300}', '\u{1F63}'),
+    ('\u{1F61}', '\u{0301}', '\u{1F65}'),
+    ('\u{1F61}', '\u{0342}', '\u{1F67}'),
+    ('\u{1F61}', '\u{0345}', '\u{1FA1}'),
+    ('\u{1F62}', '\u{0345}', '\u{1FA2}'),
+    ('\u{1F63}', '\u{0345}', '\u{1FA3}'),
+    ('\u{1F64}', '\u{0345}', '\u{1FA4}'),
+    ('\u{1F65}', '\u{0345}', '\u{1FA5}'),
+    ('\u{1F66}', '\u{0345}', '\u{1FA6}'),
+    ('\u{1F67}', '\u{0345}', '\u{1FA7}'),
+    ('\u{1F68}', '\u{0300}', '\u{1F6A}'),
+    ('\u{1F68}', '\u{0301}', '\u{1F6C}'),
+    ('\u{1F68}', '\u{0342}', '\u{1F6E}'),
+    ('\u{1F68}', '\u{0345}', '\u{1FA8}'),
+    ('\u{1F69}', '\u{0300}', '\u{1F6B}'),
+    ('\u{1F69}', '\u{0301}', '\u{1F6D}'),
+    ('\u{1F69}', '\u{0342}', '\u{1F6F}'),
+    ('\u{1F69}', '\u{0345}', '\u{1FA9}'),
+    ('\u{1F6A}', '\u{0345}', '\u{1FAA}'),
+    ('\u{1F6B}', '\u{0345}', '\u{1FAB}'),
+    ('\u{1F6C}', '\u{0345}', '\u{1FAC}'),
+    ('\u{1F6D}', '\u{0345}', '\u{1FAD}'),
+    ('\u{1F6E}', '\u{0345}', '\u{1FAE}'),
+    ('\u{1F6F}', '\u{0345}', '\u{1FAF}'),
+    ('\u{1F70}', '\u{0345}', '\u{1FB2}'),
+    ('\u{1F74}', '\u{0345}', '\u{1FC2}'),
+    ('\u{1F7C}', '\u{0345}', '\u{1FF2}'),
+    ('\u{1FB6}', '\u{0345}', '\u{1FB7}'),
+    ('\u{1FBF}', '\u{0300}', '\u{1FCD}'),
+    ('\u{1FBF}', '\u{0301}', '\u{1FCE}'),
+    ('\u{1FBF}', '\u{0342}', '\u{1FCF}'),
+    ('\u{1FC6}', '\u{0345}', '\u{1FC7}'),
+    ('\u{1FF6}', '\u{0345}', '\u{1FF7}'),
+    ('\u{1FFE}', '\u{0300}', '\u{1FDD}'),
+    ('\u{1FFE}', '\u{0301}', '\u{1FDE}'),
+    ('\u{1FFE}', '\u{0342}', '\u{1FDF}'),
+    ('\u{2190}', '\u{0338}', '\u{219A}'),
+    ('\u{2192}', '\u{0338}', '\u{219B}'),
+    ('\u{2194}', '\u{0338}', '\u{21AE}'),
+    ('\u{21D0}', '\u{0338}', '\u{21CD}'),
+    ('\u{21D2}', '\u{0338}', '\u{21CF}'),
+    ('\u{21D4}', '\u{0338}', '\u{21CE}'),
+    ('\u{2203}', '\u{0338}', '\u{2204}'),
+    ('\u{2208}', '\u{0338}', '\u{2209}'),
+    ('\u{220B}', '\u{0338}', '\u{220C}'),
+    ('\u{2223}', '\u{0338}', '\u{2224}'),
+    ('\u{2225}', '\u{0338}', '\u{2226}'),
+    ('\u{223C}', '\u{0338}', '\u{2241}'),
+    ('\u{2243}', '\u{0338}', '\u{2244}'),
+    ('\u{2245}', '\u{0338}', '\u{2247}'),
+    ('\u{2248}', '\u{0338}', '\u{2249}'),
+    ('\u{224D}', '\u{0338}', '\u{226D}'),
+    ('\u{2261}', '\u{0338}', '\u{2262}'),
+    ('\u{2264}', '\u{0338}', '\u{2270}'),
+    ('\u{2265}', '\u{0338}', '\u{2271}'),
+    ('\u{2272}', '\u{0338}', '\u{2274}'),
+    ('\u{2273}', '\u{0338}', '\u{2275}'),
+    ('\u{2276}', '\u{0338}', '\u{2278}'),
+    ('\u{2277}', '\u{0338}', '\u{2279}'),
+    ('\u{227A}', '\u{0338}', '\u{2280}'),
+    ('\u{227B}', '\u{0338}', '\u{2281}'),
+    ('\u{227C}', '\u{0338}', '\u{22E0}'),
+    ('\u{227D}', '\u{0338}', '\u{22E1}'),
+    ('\u{2282}', '\u{0338}', '\u{2284}'),
+    ('\u{2283}', '\u{0338}', '\u{2285}'),
+    ('\u{2286}', '\u{0338}', '\u{2288}'),
+    ('\u{2287}', '\u{0338}', '\u{2289}'),
+    ('\u{2291}', '\u{0338}', '\u{22E2}'),
+    ('\u{2292}', '\u{0338}', '\u{22E3}'),
+    ('\u{22A2}', '\u{0338}', '\u{22AC}'),
+    ('\u{22A8}', '\u{0338}', '\u{22AD}'),
+    ('\u{22A9}', '\u{0338}', '\u{22AE}'),
+    ('\u{22AB}', '\u{0338}', '\u{22AF}'),
+    ('\u{22B2}', '\u{0338}', '\u{22EA}'),
+    ('\u{22B3}', '\u{0338}', '\u{22EB}'),
+    ('\u{22B4}', '\u{0338}', '\u{22EC}'),
+    ('\u{22B5}', '\u{0338}', '\u{22ED}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'),
+    ('\u{304B}', '\u{3099}', '\u{304C}'),
+    ('\u{304D}', '\u{3099}', '\u{304E}'),
+    ('\u{304F}', '\u{3099}', '\u{3050}'),
+    ('\u{3051}', '\u{3099}', '\u{3052}'),
+    ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'),
+    ('\u{3057}', '\u{3099}', '\u{3058}'),
+    ('\u{3059}', '\u{3099}', '\u{305A}'),
+    ('\u{305B}', '\u{3099}', '\u{305C}'),
+    ('\u{305D}', '\u{3099}', '\u{305E}'),
+    ('\u{305F}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'),
+    ('\u{3064}', '\u{3099}', '\u{3065}'),
+    ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'),
+    ('\u{306F}', '\u{3099}', '\u{3070}'),
+    ('\u{306F}', '\u{309A}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'),
+    ('\u{3072}', '\u{309A}', '\u{3074}'),
+    ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309A}', '\u{3077}'),
+    ('\u{3078}', '\u{3099}', '\u{3079}'),
+    ('\u{3078}', '\u{309A}', '\u{307A}'),
+    ('\u{307B}', '\u{3099}', '\u{307C}'),
+    ('\u{307B}', '\u{309A}', '\u{307D}'),
+    ('\u{309D}', '\u{3099}', '\u{309E}'),
+    ('\u{30A6}', '\u{3099}', '\u{30F4}'),
+    ('\u{30AB}', '\u{3099}', '\u{30AC}'),
+    ('\u{30AD}', '\u{3099}', '\u{30AE}'),
+    ('\u{30AF}', '\u{3099}', '\u{30B0}'),
+    ('\u{30B1}', '\u{3099}', '\u{30B2}'),
+    ('\u{30B3}', '\u{3099}', '\u{30B4}'),
+    ('\u{30B5}', '\u{3099}', '\u{30B6}'),
+    ('\u{30B7}', '\u{3099}', '\u{30B8}'),
+    ('\u{30B9}', '\u{3099}', '\u{30BA}'),
+    ('\u{30BB}', '\u{3099}', '\u{30BC}'),
+    ('\u{30BD}', '\u{3099}', '\u{30BE}'),
+    ('\u{30BF}', '\u{3099}', '\u{30C0}'),
+    ('\u{30C1}', '\u{3099}', '\u{30C2}'),
+    ('\u{30C4}', '\u{3099}', '\u{30C5}'),
+    ('\u{30C6}', '\u{3099}', '\u{30C7}'),
+    ('\u{30C8}', '\u{3099}', '\u{30C9}'),
+    ('\u{30CF}', '\u{3099}', '\u{30D0}'),
+    ('\u{30CF}', '\u{309A}', '\u{30D1}'),
+    ('\u{30D2}', '\u{3099}', '\u{30D3}'),
+    ('\u{30D2}', '\u{309A}', '\u{30D4}'),
+    ('\u{30D5}', '\u{3099}', '\u{30D6}'),
+    ('\u{30D5}', '\u{309A}', '\u{30D7}'),
+    ('\u{30D8}', '\u{3099}', '\u{30D9}'),
+    ('\u{30D8}', '\u{309A}', '\u{30DA}'),
+    ('\u{30DB}', '\u{3099}', '\u{30DC}'),
+    ('\u{30DB}', '\u{309A}', '\u{30DD}'),
+    ('\u{30EF}', '\u{3099}', '\u{30F7}'),
+    ('\u{30F0}', '\u{3099}', '\u{30F8}'),
+    ('\u{30F1}', '\u{3099}', '\u{30F9}'),
+    ('\u{30F2}', '\u{3099}', '\u{30FA}'),
+    ('\u{30FD}', '\u{3099}', '\u{30FE}'),
+    ('\u{11099}', '\u{110BA}', '\u{1109A}'),
+    ('\u{1109B}', '\u{110BA}', '\u{1109C}'),
+    ('\u{110A5}', '\u{110BA}', '\u{110AB}'),
+    ('\u{11131}', '\u{11127}', '\u{1112E}'),
+    ('\u{11132}', '\u{11127}', '\u{1112F}'),
+    ('\u{11347}', '\u{1133E}', '\u{1134B}'),
+    ('\u{11347}', '\u{11357}', '\u{1134C}'),
+    ('\u{114B9}', '\u{114B0}', '\u{114BC}'),
+    ('\u{114B9}', '\u{114BA}', '\u{114BB}'),
+    ('\u{114B9}', '\u{114BD}', '\u{114BE}'),
+    ('\u{115B8}', '\u{115AF}', '\u{115BA}'),
+    ('\u{115B9}', '\u{115AF}', '\u{115BB}'),
+    ('\u{11935}', '\u{11930}', '\u{11938}'),
+];
+
+/// Ranges of characters with NFD_Quick_Check=No
+pub(super) static NFD_QC_NO: [(u32, u32); 231] = [
+    (0x00C0, 0x00C5),
+    (0x00C7, 0x00CF),
+    (0x00D1, 0x00D6),
+    (0x00D9, 0x00DD),
+    (0x00E0, 0x00E5),
+    (0x00E7, 0x00EF),
+    (0x00F1, 0x00F6),
+    (0x00F9, 0x00FD),
+    (0x00FF, 0x010F),
+    (0x0112, 0x0125),
+    (0x0128, 0x0130),
+    (0x0134, 0x0137),
+    (0x0139, 0x013E),
+    (0x0143, 0x0148),
+    (0x014C, 0x0151),
+    (0x0154, 0x0165),
+    (0x0168, 0x017E),
+    (0x01A0, 0x01A1),
+    (0x01AF, 0x01B0),
+    (0x01CD, 0x01DC),
+    (0x01DE, 0x01E3),
+    (0x01E6, 0x01F0),
+    (0x01F4, 0x01F5),
+    (0x01F8, 0x021B),
+    (0x021E, 0x021F),
+    (0x0226, 0x0233),
+    (0x0340, 0x0341),
+    (0x0343, 0x0344),
+    (0x0374, 0x0374),
+    (0x037E, 0x037E),
+    (0x0385, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x0390),
+    (0x03AA, 0x03B0),
+    (0x03CA, 0x03CE),
+    (0x03D3, 0x03D4),
+    (0x0400, 0x0401),
+    (0x0403, 0x0403),
+    (0x0407, 0x0407),
+    (0x040C, 0x040E),
+    (0x0419, 0x0419),
+    (0x0439, 0x0439),
+    (0x0450, 0x0451),
+    (0x0453, 0x0453),
+    (0x0457, 0x0457),
+    (0x045C, 0x045E),
+    (0x0476, 0x0477),
+    (0x04C1, 0x04C2),
+    (0x04D0, 0x04D3),
+    (0x04D6, 0x04D7),
+    (0x04DA, 0x04DF),
+    (0x04E2, 0x04E7),
+    (0x04EA, 0x04F5),
+    (0x04F8, 0x04F9),
+    (0x0622, 0x0626),
+    (0x06C0, 0x06C0),
+    (0x06C2, 0x06C2),
+    (0x06D3, 0x06D3),
+    (0x0929, 0x0929),
+    (0x0931, 0x0931),
+    (0x0934, 0x0934),
+    (0x0958, 0x095F),
+    (0x09CB, 0x09CC),
+    (0x09DC, 0x09DD),
+    (0x09DF, 0x09DF),
+    (0x0A33, 0x0A33),
+    (0x0A36, 0x0A36),
+    (0x0A59, 0x0A5B),
+    (0x0A5E, 0x0A5E),
+    (0x0B48, 0x0B48),
+    (0x0B4B, 0x0B4C),
+    (0x0B5C, 0x0B5D),
+    (0x0B94, 0x0B94),
+    (0x0BCA, 0x0BCC),
+    (0x0C48, 0x0C48),
+    (0x0CC0, 0x0CC0),
+    (0x0CC7, 0x0CC8),
+    (0x0CCA, 0x0CCB),
+    (0x0D4A, 0x0D4C),
+    (0x0DDA, 0x0DDA),
+    (0x0DDC, 0x0DDE),
+    (0x0F43, 0x0F43),
+    (0x0F4D, 0x0F4D),
+    (0x0F52, 0x0F52),
+    (0x0F57, 0x0F57),
+    (0x0F5C, 0x0F5C),
+    (0x0F69, 0x0F69),
+    (0x0F73, 0x0F73),
+    (0x0F75, 0x0F76),
+    (0x0F78, 0x0F78),
+    (0x0F81, 0x0F81),
+    (0x0F93, 0x0F93),
+    (0x0F9D, 0x0F9D),
+    (0x0FA2, 0x0FA2),
+    (0x0FA7, 0x0FA7),
+    (0x0FAC, 0x0FAC),
+    (0x0FB9, 0x0FB9),
+    (0x1026, 0x1026),
+    (0x1B06, 0x1B06),
+    (0x1B08, 0x1B08),
+    (0x1B0A, 0x1B0A),
+    (0x1B0C, 0x1B0C),
+    (0x1B0E, 0x1B0E),
+    (0x1B12, 0x1B12),
+    (0x1B3B, 0x1B3B),
+    (0x1B3D, 0x1B3D),
+    (0x1B40, 0x1B41),
+    (0x1B43, 0x1B43),
+    (0x1E00, 0x1E99),
+    (0x1E9B, 0x1E9B),
+    (0x1EA0, 0x1EF9),
+    (0x1F00, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC1, 0x1FC4),
+    (0x1FC6, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FDD, 0x1FEF),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFD),
+    (0x2000, 0x2001),
+    (0x2126, 0x2126),
+    (0x212A, 0x212B),
+    (0x219A, 0x219B),
+    (0x21AE, 0x21AE),
+    (0x21CD, 0x21CF),
+    (0x2204, 0x2204),
+    (0x2209, 0x2209),
+    (0x220C, 0x220C),
+    (0x2224, 0x2224),
+    (0x2226, 0x2226),
+    (0x2241, 0x2241),
+    (0x2244, 0x2244),
+    (0x2247, 0x2247),
+    (0x2249, 0x2249),
+    (0x2260, 0x2260),
+    (0x2262, 0x2262),
+    (0x226D, 0x2271),
+    (0x2274, 0x2275),
+    (0x2278, 0x2279),
+    (0x2280, 0x2281),
+    (0x2284, 0x2285),
+    (0x2288, 0x2289),
+    (0x22AC, 0x22AF),
+    (0x22E0, 0x22E3),
+    (0x22EA, 0x22ED),
+    (0x2329, 0x232A),
+    (0x2ADC, 0x2ADC),
+    (0x304C, 0x304C),
+    (0x304E, 0x304E),
+    (0x3050, 0x3050),
+    (0x3052, 0x3052),
+    (0x3054, 0x3054),
+    (0x3056, 0x3056),
+    (0x3058, 0x3058),
+    (0x305A, 0x305A),
+    (0x305C, 0x305C),
+    (0x305E, 0x305E),
+    (0x3060, 0x3060),
+    (0x3062, 0x3062),
+    (0x3065, 0x3065),
+    (0x3067, 0x3067),
+    (0x3069, 0x3069),
+    (0x3070, 0x3071),
+    (0x3073, 0x3074),
+    (0x3076, 0x3077),
+    (0x3079, 0x307A),
+    (0x307C, 0x307D),
+    (0x3094, 0x3094),
+    (0x309E, 0x309E),
+    (0x30AC, 0x30AC),
+    (0x30AE, 0x30AE),
+    (0x30B0, 0x30B0),
+    (0x30B2, 0x30B2),
+    (0x30B4, 0x30B4),
+    (0x30B6, 0x30B6),
+    (0x30B8, 0x30B8),
+    (0x30BA, 0x30BA),
+    (0x30BC, 0x30BC),
+    (0x30BE, 0x30BE),
+    (0x30C0, 0x30C0),
+    (0x30C2, 0x30C2),
+    (0x30C5, 0x30C5),
+    (0x30C7, 0x30C7),
+    (0x30C9, 0x30C9),
+    (0x30D0, 0x30D1),
+    (0x30D3, 0x30D4),
+    (0x30D6, 0x30D7),
+    (0x30D9, 0x30DA),
+    (0x30DC, 0x30DD),
+    (0x30F4, 0x30F4),
+    (0x30F7, 0x30FA),
+    (0x30FE, 0x30FE),
+    (0xF900, 0xFA0D),
+    (0xFA10, 0xFA10),
+    (0xFA12, 0xFA12),
+    (0xFA15, 0xFA1E),
+    (0xFA20, 0xFA20),
+    (0xFA22, 0xFA22),
+    (0xFA25, 0xFA26),
+    (0xFA2A, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB1D, 0xFB1D),
+    (0xFB1F, 0xFB1F),
+    (0xFB2A, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFB4E),
+    (0x1109A, 0x1109A),
+    (0x1109C, 0x1109C),
+    (0x110AB, 0x110AB),
+    (0x1112E, 0x1112F),
+    (0x1134B, 0x1134C),
+    (0x114BB, 0x114BC),
+    (0x114BE, 0x114BE),
+    (0x115BA, 0x115BB),
+    (0x11938, 0x11938),
+    (0x1D15E, 0x1D164),
+    (0x1D1BB, 0x1D1C0),
+    (0x2F800, 0x2FA1D),
+];
+
+/// Ranges of characters with NFKD_Quick_Check=No
+pub(super) static NFKD_QC_NO: [(u32, u32); 396] = [
+    (0x00A0, 0x00A0),
+    (0x00A8, 0x00A8),
+    (0x00AA, 0x00AA),
+    (0x00AF, 0x00AF),
+    (0x00B2, 0x00B5),
+    (0x00B8, 0x00BA),
+    (0x00BC, 0x00BE),
+    (0x00C0, 0x00C5),
+    (0x00C7, 0x00CF),
+    (0x00D1, 0x00D6),
+    (0x00D9, 0x00DD),
+    (0x00E0, 0x00E5),
+    (0x00E7, 0x00EF),
+    (0x00F1, 0x00F6),
+    (0x00F9, 0x00FD),
+    (0x00FF, 0x010F),
+    (0x0112, 0x0125),
+    (0x0128, 0x0130),
+    (0x0132, 0x0137),
+    (0x0139, 0x0140),
+    (0x0143, 0x0149),
+    (0x014C, 0x0151),
+    (0x0154, 0x0165),
+    (0x0168, 0x017F),
+    (0x01A0, 0x01A1),
+    (0x01AF, 0x01B0),
+    (0x01C4, 0x01DC),
+    (0x01DE, 0x01E3),
+    (0x01E6, 0x01F5),
+    (0x01F8, 0x021B),
+    (0x021E, 0x021F),
+    (0x0226, 0x0233),
+    (0x02B0, 0x02B8),
+    (0x02D8, 0x02DD),
+    (0x02E0, 0x02E4),
+    (0x0340, 0x0341),
+    (0x0343, 0x0344),
+    (0x0374, 0x0374),
+    (0x037A, 0x037A),
+    (0x037E, 0x037E),
+    (0x0384, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x0390),
+    (0x03AA, 0x03B0),
+    (0x03CA, 0x03CE),
+    (0x03D0, 0x03D6),
+    (0x03F0, 0x03F2),
+    (0x03F4, 0x03F5),
+    (0x03F9, 0x03F9),
+    (0x0400, 0x0401),
+    (0x0403, 0x0403),
+    (0x0407, 0x0407),
+    (0x040C, 0x040E),
+    (0x0419, 0x0419),
+    (0x0439, 0x0439),
+    (0x0450, 0x0451),
+    (0x0453, 0x0453),
+    (0x0457, 0x0457),
+    (0x045C, 0x045E),
+    (0x0476, 0x0477),
+    (0x04C1, 0x04C2),
+    (0x04D0, 0x04D3),
+    (0x04D6, 0x04D7),
+    (0x04DA, 0x04DF),
+    (0x04E2, 0x04E7),
+    (0x04EA, 0x04F5),
+    (0x04F8, 0x04F9),
+    (0x0587, 0x0587),
+    (0x0622, 0x0626),
+    (0x0675, 0x0678),
+    (0x06C0, 0x06C0),
+    (0x06C2, 0x06C2),
+    (0x06D3, 0x06D3),
+    (0x0929, 0x0929),
+    (0x0931, 0x0931),
+    (0x0934, 0x0934),
+    (0x0958, 0x095F),
+    (0x09CB, 0x09CC),
+    (0x09DC, 0x09DD),
+    (0x09DF, 0x09DF),
+    (0x0A33, 0x0A33),
+    (0x0A36, 0x0A36),
+    (0x0A59, 0x0A5B),
+    (0x0A5E, 0x0A5E),
+    (0x0B48, 0x0B48),
+    (0x0B4B, 0x0B4C),
+    (0x0B5C, 0x0B5D),
+    (0x0B94, 0x0B94),
+    (0x0BCA, 0x0BCC),
+    (0x0C48, 0x0C48),
+    (0x0CC0, 0x0CC0),
+    (0x0CC7, 0x0CC8),
+    (0x0CCA, 0x0CCB),
+    (0x0D4A, 0x0D4C),
+    (0x0DDA, 0x0DDA),
+    (0x0DDC, 0x0DDE),
+    (0x0E33, 0x0E33),
+    (0x0EB3, 0x0EB3),
+    (0x0EDC, 0x0EDD),
+    (0x0F0C, 0x0F0C),
+    (0x0F43, 0x0F43),
+    (0x0F4D, 0x0F4D),
+    (0x0F52, 0x0F52),
+    (0x0F57, 0x0F57),
+    (0x0F5C, 0x0F5C),
+    (0x0F69, 0x0F69),
+    (0x0F73, 0x0F73),
+    (0x0F75, 0x0F79),
+    (0x0F81, 0x0F81),
+    (0x0F93, 0x0F93),
+    (0x0F9D, 0x0F9D),
+    (0x0FA2, 0x0FA2),
+    (0x0FA7, 0x0FA7),
+    (0x0FAC, 0x0FAC),
+    (0x0FB9, 0x0FB9),
+    (0x1026, 0x1026),
+    (0x10FC, 0x10FC),
+    (0x1B06, 0x1B06),
+    (0x1B08, 0x1B08),
+    (0x1B0A, 0x1B0A),
+    (0x1B0C, 0x1B0C),
+    (0x1B0E, 0x1B0E),
+    (0x1B12, 0x1B12),
+    (0x1B3B, 0x1B3B),
+    (0x1B3D, 0x1B3D),
+    (0x1B40, 0x1B41),
+    (0x1B43, 0x1B43),
+    (0x1D2C, 0x1D2E),
+    (0x1D30, 0x1D3A),
+    (0x1D3C, 0x1D4D),
+    (0x1D4F, 0x1D6A),
+    (0x1D78, 0x1D78),
+    (0x1D9B, 0x1DBF),
+    (0x1E00, 0x1E9B),
+    (0x1EA0, 0x1EF9),
+    (0x1F00, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FC4),
+    (0x1FC6, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FDD, 0x1FEF),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFE),
+    (0x2000, 0x200A),
+    (0x2011, 0x2011),
+    (0x2017, 0x2017),
+    (0x2024, 0x2026),
+    (0x202F, 0x202F),
+    (0x2033, 0x2034),
+    (0x2036, 0x2037),
+    (0x203C, 0x203C),
+    (0x203E, 0x203E),
+    (0x2047, 0x2049),
+    (0x2057, 0x2057),
+    (0x205F, 0x205F),
+    (0x2070, 0x2071),
+    (0x2074, 0x208E),
+    (0x2090, 0x209C),
+    (0x20A8, 0x20A8),
+    (0x2100, 0x2103),
+    (0x2105, 0x2107),
+    (0x2109, 0x2113),
+    (0x2115, 0x2116),
+    (0x2119, 0x211D),
+    (0x2120, 0x2122),
+    (0x2124, 0x2124),
+    (0x2126, 0x2126),
+    (0x2128, 0x2128),
+    (0x212A, 0x212D),
+    (0x212F, 0x2131),
+    (0x2133, 0x2139),
+    (0x213B, 0x2140),
+    (0x2145, 0x2149),
+    (0x2150, 0x217F),
+    (0x2189, 0x2189),
+    (0x219A, 0x219B),
+    (0x21AE, 0x21AE),
+    (0x21CD, 0x21CF),
+    (0x2204, 0x2204),
+    (0x2209, 0x2209),
+    (0x220C, 0x220C),
+    (0x2224, 0x2224),
+    (0x2226, 0x2226),
+    (0x222C, 0x222D),
+    (0x222F, 0x2230),
+    (0x2241, 0x2241),
+    (0x2244, 0x2244),
+    (0x2247, 0x2247),
+    (0x2249, 0x2249),
+    (0x2260, 0x2260),
+    (0x2262, 0x2262),
+    (0x226D, 0x2271),
+    (0x2274, 0x2275),
+    (0x2278, 0x2279),
+    (0x2280, 0x2281),
+    (0x2284, 0x2285),
+    (0x2288, 0x2289),
+    (0x22AC, 0x22AF),
+    (0x22E0, 0x22E3),
+    (0x22EA, 0x22ED),
+    (0x2329, 0x232A),
+    (0x2460, 0x24EA),
+    (0x2A0C, 0x2A0C),
+    (0x2A74, 0x2A76),
+    (0x2ADC, 0x2ADC),
+    (0x2C7C, 0x2C7D),
+    (0x2D6F, 0x2D6F),
+    (0x2E9F, 0x2E9F),
+    (0x2EF3, 0x2EF3),
+    (0x2F00, 0x2FD5),
+    (0x3000, 0x3000),
+    (0x3036, 0x3036),
+    (0x3038, 0x303A),
+    (0x304C, 0x304C),
+    (0x304E, 0x304E),
+    (0x3050, 0x3050),
+    (0x3052, 0x3052),
+    (0x3054, 0x3054),
+    (0x3056, 0x3056),
+    (0x3058, 0x3058),
+    (0x305A, 0x305A),
+    (0x305C, 0x305C),
+    (0x305E, 0x305E),
+    (0x3060, 0x3060),
+    (0x3062, 0x3062),
+    (0x3065, 0x3065),
+    (0x3067, 0x3067),
+    (0x3069, 0x3069),
+    (0x3070, 0x3071),
+    (0x3073, 0x3074),
+    (0x3076, 0x3077),
+    (0x3079, 0x307A),
+    (0x307C, 0x307D),
+    (0x3094, 0x3094),
+    (0x309B, 0x309C),
+    (0x309E, 0x309F),
+    (0x30AC, 0x30AC),
+    (0x30AE, 0x30AE),
+    (0x30B0, 0x30B0),
+    (0x30B2, 0x30B2),
+    (0x30B4, 0x30B4),
+    (0x30B6, 0x30B6),
+    (0x30B8, 0x30B8),
+    (0x30BA, 0x30BA),
+    (0x30BC, 0x30BC),
+    (0x30BE, 0x30BE),
+    (0x30C0, 0x30C0),
+    (0x30C2, 0x30C2),
+    (0x30C5, 0x30C5),
+    (0x30C7, 0x30C7),
+    (0x30C9, 0x30C9),
+    (0x30D0, 0x30D1),
+    (0x30D3, 0x30D4),
+    (0x30D6, 0x30D7),
+    (0x30D9, 0x30DA),
+    (0x30DC, 0x30DD),
+    (0x30F4, 0x30F4),
+    (0x30F7, 0x30FA),
+    (0x30FE, 0x30FF),
+    (0x3131, 0x318E),
+    (0x3192, 0x319F),
+    (0x3200, 0x321E),
+    (0x3220, 0x3247),
+    (0x3250, 0x327E),
+    (0x3280, 0x33FF),
+    (0xA69C, 0xA69D),
+    (0xA770, 0xA770),
+    (0xA7F2, 0xA7F4),
+    (0xA7F8, 0xA7F9),
+    (0xAB5C, 0xAB5F),
+    (0xAB69, 0xAB69),
+    (0xF900, 0xFA0D),
+    (0xFA10, 0xFA10),
+    (0xFA12, 0xFA12),
+    (0xFA15, 0xFA1E),
+    (0xFA20, 0xFA20),
+    (0xFA22, 0xFA22),
+    (0xFA25, 0xFA26),
+    (0xFA2A, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFB1D, 0xFB1D),
+    (0xFB1F, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFBB1),
+    (0xFBD3, 0xFD3D),
+    (0xFD50, 0xFD8F),
+    (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDFC),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE44),
+    (0xFE47, 0xFE52),
+    (0xFE54, 0xFE66),
+    (0xFE68, 0xFE6B),
+    (0xFE70, 0xFE72),
+    (0xFE74, 0xFE74),
+    (0xFE76, 0xFEFC),
+    (0xFF01, 0xFFBE),
+    (0xFFC2, 0xFFC7),
+    (0xFFCA, 0xFFCF),
+    (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC),
+    (0xFFE0, 0xFFE6),
+    (0xFFE8, 0xFFEE),
+    (0x10781, 0x10785),
+    (0x10787, 0x107B0),
+    (0x107B2, 0x107BA),
+    (0x1109A, 0x1109A),
+    (0x1109C, 0x1109C),
+    (0x110AB, 0x110AB),
+    (0x1112E, 0x1112F),
+    (0x1134B, 0x1134C),
+    (0x114BB, 0x114BC),
+    (0x114BE, 0x114BE),
+    (0x115BA, 0x115BB),
+    (0x11938, 0x11938),
+    (0x1D15E, 0x1D164),
+    (0x1D1BB, 0x1D1C0),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D7CB),
+    (0x1D7CE, 0x1D7FF),
+    (0x1E030, 0x1E06D),
+    (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F),
+    (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32),
+    (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39),
+    (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42),
+    (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F),
+    (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54),
+    (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59),
+    (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62),
+    (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A),
+    (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77),
+    (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B),
+    (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9),
+    (0x1EEAB, 0x1EEBB),
+    (0x1F100, 0x1F10A),
+    (0x1F110, 0x1F12E),
+    (0x1F130, 0x1F14F),
+    (0x1F16A, 0x1F16C),
+    (0x1F190, 0x1F190),
+    (0x1F200, 0x1F202),
+    (0x1F210, 0x1F23B),
+    (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251),
+    (0x1FBF0, 0x1FBF9),
+    (0x2F800, 0x2FA1D),
+];
+
+/// Ranges of characters with NFC_Quick_Check=No
+pub(super) static NFC_QC_NO: [(u32, u32); 73] = [
+    (0x0340, 0x0341),
+    (0x0343, 0x0344),
+    (0x0374, 0x0374),
+    (0x037E, 0x037E),
+    (0x0387, 0x0387),
+    (0x0958, 0x095F),
+    (0x09DC, 0x09DD),
+    (0x09DF, 0x09DF),
+    (0x0A33, 0x0A33),
+    (0x0A36, 0x0A36),
+    (0x0A59, 0x0A5B),
+    (0x0A5E, 0x0A5E),
+    (0x0B5C, 0x0B5D),
+    (0x0F43, 0x0F43),
+    (0x0F4D, 0x0F4D),
+    (0x0F52, 0x0F52),
+    (0x0F57, 0x0F57),
+    (0x0F5C, 0x0F5C),
+    (0x0F69, 0x0F69),
+    (0x0F73, 0x0F73),
+    (0x0F75, 0x0F76),
+    (0x0F78, 0x0F78),
+    (0x0F81, 0x0F81),
+    (0x0F93, 0x0F93),
+    (0x0F9D, 0x0F9D),
+    (0x0FA2, 0x0FA2),
+    (0x0FA7, 0x0FA7),
+    (0x0FAC, 0x0FAC),
+    (0x0FB9, 0x0FB9),
+    (0x1F71, 0x1F71),
+    (0x1F73, 0x1F73),
+    (0x1F75, 0x1F75),
+    (0x1F77, 0x1F77),
+    (0x1F79, 0x1F79),
+    (0x1F7B, 0x1F7B),
+    (0x1F7D, 0x1F7D),
+    (0x1FBB, 0x1FBB),
+    (0x1FBE, 0x1FBE),
+    (0x1FC9, 0x1FC9),
+    (0x1FCB, 0x1FCB),
+    (0x1FD3, 0x1FD3),
+    (0x1FDB, 0x1FDB),
+    (0x1FE3, 0x1FE3),
+    (0x1FEB, 0x1FEB),
+    (0x1FEE, 0x1FEF),
+    (0x1FF9, 0x1FF9),
+    (0x1FFB, 0x1FFB),
+    (0x1FFD, 0x1FFD),
+    (0x2000, 0x2001),
+    (0x2126, 0x2126),
+    (0x212A, 0x212B),
+    (0x2329, 0x232A),
+    (0x2ADC, 0x2ADC),
+    (0xF900, 0xFA0D),
+    (0xFA10, 0xFA10),
+    (0xFA12, 0xFA12),
+    (0xFA15, 0xFA1E),
+    (0xFA20, 0xFA20),
+    (0xFA22, 0xFA22),
+    (0xFA25, 0xFA26),
+    (0xFA2A, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB1D, 0xFB1D),
+    (0xFB1F, 0xFB1F),
+    (0xFB2A, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFB4E),
+    (0x1D15E, 0x1D164),
+    (0x1D1BB, 0x1D1C0),
+    (0x2F800, 0x2FA1D),
+];
+
+/// Ranges of characters with NFKC_Quick_Check=No
+pub(super) static NFKC_QC_NO: [(u32, u32); 255] = [
+    (0x00A0, 0x00A0),
+    (0x00A8, 0x00A8),
+    (0x00AA, 0x00AA),
+    (0x00AF, 0x00AF),
+    (0x00B2, 0x00B5),
+    (0x00B8, 0x00BA),
+    (0x00BC, 0x00BE),
+    (0x0132, 0x0133),
+    (0x013F, 0x0140),
+    (0x0149, 0x0149),
+    (0x017F, 0x017F),
+    (0x01C4, 0x01CC),
+    (0x01F1, 0x01F3),
+    (0x02B0, 0x02B8),
+    (0x02D8, 0x02DD),
+    (0x02E0, 0x02E4),
+    (0x0340, 0x0341),
+    (0x0343, 0x0344),
+    (0x0374, 0x0374),
+    (0x037A, 0x037A),
+    (0x037E, 0x037E),
+    (0x0384, 0x0385),
+    (0x0387, 0x0387),
+    (0x03D0, 0x03D6),
+    (0x03F0, 0x03F2),
+    (0x03F4, 0x03F5),
+    (0x03F9, 0x03F9),
+    (0x0587, 0x0587),
+    (0x0675, 0x0678),
+    (0x0958, 0x095F),
+    (0x09DC, 0x09DD),
+    (0x09DF, 0x09DF),
+    (0x0A33, 0x0A33),
+    (0x0A36, 0x0A36),
+    (0x0A59, 0x0A5B),
+    (0x0A5E, 0x0A5E),
+    (0x0B5C, 0x0B5D),
+    (0x0E33, 0x0E33),
+    (0x0EB3, 0x0EB3),
+    (0x0EDC, 0x0EDD),
+    (0x0F0C, 0x0F0C),
+    (0x0F43, 0x0F43),
+    (0x0F4D, 0x0F4D),
+    (0x0F52, 0x0F52),
+    (0x0F57, 0x0F57),
+    (0x0F5C, 0x0F5C),
+    (0x0F69, 0x0F69),
+    (0x0F73, 0x0F73),
+    (0x0F75, 0x0F79),
+    (0x0F81, 0x0F81),
+    (0x0F93, 0x0F93),
+    (0x0F9D, 0x0F9D),
+    (0x0FA2, 0x0FA2),
+    (0x0FA7, 0x0FA7),
+    (0x0FAC, 0x0FAC),
+    (0x0FB9, 0x0FB9),
+    (0x10FC, 0x10FC),
+    (0x1D2C, 0x1D2E),
+    (0x1D30, 0x1D3A),
+    (0x1D3C, 0x1D4D),
+    (0x1D4F, 0x1D6A),
+    (0x1D78, 0x1D78),
+    (0x1D9B, 0x1DBF),
+    (0x1E9A, 0x1E9B),
+    (0x1F71, 0x1F71),
+    (0x1F73, 0x1F73),
+    (0x1F75, 0x1F75),
+    (0x1F77, 0x1F77),
+    (0x1F79, 0x1F79),
+    (0x1F7B, 0x1F7B),
+    (0x1F7D, 0x1F7D),
+    (0x1FBB, 0x1FBB),
+    (0x1FBD, 0x1FC1),
+    (0x1FC9, 0x1FC9),
+    (0x1FCB, 0x1FCB),
+    (0x1FCD, 0x1FCF),
+    (0x1FD3, 0x1FD3),
+    (0x1FDB, 0x1FDB),
+    (0x1FDD, 0x1FDF),
+    (0x1FE3, 0x1FE3),
+    (0x1FEB, 0x1FEB),
+    (0x1FED, 0x1FEF),
+    (0x1FF9, 0x1FF9),
+    (0x1FFB, 0x1FFB),
+    (0x1FFD, 0x1FFE),
+    (0x2000, 0x200A),
+    (0x2011, 0x2011),
+    (0x2017, 0x2017),
+    (0x2024, 0x2026),
+    (0x202F, 0x202F),
+    (0x2033, 0x2034),
+    (0x2036, 0x2037),
+    (0x203C, 0x203C),
+    (0x203E, 0x203E),
+    (0x2047, 0x2049),
+    (0x2057, 0x2057),
+    (0x205F, 0x205F),
+    (0x2070, 0x2071),
+    (0x2074, 0x208E),
+    (0x2090, 0x209C),
+    (0x20A8, 0x20A8),
+    (0x2100, 0x2103),
+    (0x2105, 0x2107),
+    (0x2109, 0x2113),
+    (0x2115, 0x2116),
+    (0x2119, 0x211D),
+    (0x2120, 0x2122),
+    (0x2124, 0x2124),
+    (0x2126, 0x2126),
+    (0x2128, 0x2128),
+    (0x212A, 0x212D),
+    (0x212F, 0x2131),
+    (0x2133, 0x2139),
+    (0x213B, 0x2140),
+    (0x2145, 0x2149),
+    (0x2150, 0x217F),
+    (0x2189, 0x2189),
+    (0x222C, 0x222D),
+    (0x222F, 0x2230),
+    (0x2329, 0x232A),
+    (0x2460, 0x24EA),
+    (0x2A0C, 0x2A0C),
+    (0x2A74, 0x2A76),
+    (0x2ADC, 0x2ADC),
+    (0x2C7C, 0x2C7D),
+    (0x2D6F, 0x2D6F),
+    (0x2E9F, 0x2E9F),
+    (0x2EF3, 0x2EF3),
+    (0x2F00, 0x2FD5),
+    (0x3000, 0x3000),
+    (0x3036, 0x3036),
+    (0x3038, 0x303A),
+    (0x309B, 0x309C),
+    (0x309F, 0x309F),
+    (0x30FF, 0x30FF),
+    (0x3131, 0x318E),
+    (0x3192, 0x319F),
+    (0x3200, 0x321E),
+    (0x3220, 0x3247),
+    (0x3250, 0x327E),
+    (0x3280, 0x33FF),
+    (0xA69C, 0xA69D),
+    (0xA770, 0xA770),
+    (0xA7F2, 0xA7F4),
+    (0xA7F8, 0xA7F9),
+    (0xAB5C, 0xAB5F),
+    (0xAB69, 0xAB69),
+    (0xF900, 0xFA0D),
+    (0xFA10, 0xFA10),
+    (0xFA12, 0xFA12),
+    (0xFA15, 0xFA1E),
+    (0xFA20, 0xFA20),
+    (0xFA22, 0xFA22),
+    (0xFA25, 0xFA26),
+    (0xFA2A, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFB1D, 0xFB1D),
+    (0xFB1F, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFBB1),
+    (0xFBD3, 0xFD3D),
+    (0xFD50, 0xFD8F),
+    (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDFC),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE44),
+    (0xFE47, 0xFE52),
+    (0xFE54, 0xFE66),
+    (0xFE68, 0xFE6B),
+    (0xFE70, 0xFE72),
+    (0xFE74, 0xFE74),
+    (0xFE76, 0xFEFC),
+    (0xFF01, 0xFFBE),
+    (0xFFC2, 0xFFC7),
+    (0xFFCA, 0xFFCF),
+    (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC),
+    (0xFFE0, 0xFFE6),
+    (0xFFE8, 0xFFEE),
+    (0x10781, 0x10785),
+    (0x10787, 0x107B0),
+    (0x107B2, 0x107BA),
+    (0x1D15E, 0x1D164),
+    (0x1D1BB, 0x1D1C0),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D7CB),
+    (0x1D7CE, 0x1D7FF),
+    (0x1E030, 0x1E06D),
+    (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F),
+    (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32),
+    (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39),
+    (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42),
+    (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F),
+    (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54),
+    (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59),
+    (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62),
+    (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A),
+    (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77),
+    (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B),
+    (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9),
+    (0x1EEAB, 0x1EEBB),
+    (0x1F100, 0x1F10A),
+    (0x1F110, 0x1F12E),
+    (0x1F130, 0x1F14F),
+    (0x1F16A, 0x1F16C),
+    (0x1F190, 0x1F190),
+    (0x1F200, 0x1F202),
+    (0x1F210, 0x1F23B),
+    (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251),
+    (0x1FBF0, 0x1FBF9),
+    (0x2F800, 0x2FA1D),
+];
+
+/// Ranges of characters with NFC_Quick_Check=Maybe, which is also NFKC_Quick_Check=Maybe unless NFKC_Quick_Check=No
+pub(super) static NFC_QC_MAYBE: [(u32, u32); 42] = [
+    (0x0300, 0x0304),
+    (0x0306, 0x030C),
+    (0x030F, 0x030F),
+    (0x0311, 0x0311),
+    (0x0313, 0x0314),
+    (0x031B, 0x031B),
+    (0x0323, 0x0328),
+    (0x032D, 0x032E),
+    (0x0330, 0x0331),
+    (0x0338, 0x0338),
+    (0x0342, 0x0342),
+    (0x0345, 0x0345),
+    (0x0653, 0x0655),
+    (0x093C, 0x093C),
+    (0x09BE, 0x09BE),
+    (0x09D7, 0x09D7),
+    (0x0B3E, 0x0B3E),
+    (0x0B56, 0x0B57),
+    (0x0BBE, 0x0BBE),
+    (0x0BD7, 0x0BD7),
+    (0x0C56, 0x0C56),
+    (0x0CC2, 0x0CC2),
+    (0x0CD5, 0x0CD6),
+    (0x0D3E, 0x0D3E),
+    (0x0D57, 0x0D57),
+    (0x0DCA, 0x0DCA),
+    (0x0DCF, 0x0DCF),
+    (0x0DDF, 0x0DDF),
+    (0x102E, 0x102E),
+    (0x1161, 0x1175),
+    (0x11A8, 0x11C2),
+    (0x1B35, 0x1B35),
+    (0x3099, 0x309A),
+    (0x110BA, 0x110BA),
+    (0x11127, 0x11127),
+    (0x1133E, 0x1133E),
+    (0x11357, 0x11357),
+    (0x114B0, 0x114B0),
+    (0x114BA, 0x114BA),
+    (0x114BD, 0x114BD),
+    (0x115AF, 0x115AF),
+    (0x11930, 0x11930),
+];
+
@@ -1,6 +1,8 @@
+use core::cmp::Ordering;
+
 use crate::{
     str::BasicStr,
-    traits::{CharTraits, DecodeRev, IntoChars},
+    traits::{Char, CharTraits, DecodeRev, IntoChars},
 };
 
 ///
@@ -11,6 +13,17 @@ use crate::{
 /// Additionally, if a slice of characters that is valid according to [`CharTraits::validate_range`], is passed to either `first_match` or `first_match_unchecked`,
 ///  the resulting slice, if any, shall be valid/
 pub unsafe trait Pattern<CharT, CharTraits> {
+    /// The [`Searcher`] that finds successive matches of `self`
+    type Searcher<'a>: Searcher<'a, CharT>
+    where
+        CharT: 'a,
+        CharTraits: 'a;
+
+    /// Creates a [`Searcher`] for the matches of `self` in `haystack`
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<CharT, CharTraits>) -> Self::Searcher<'a>
+    where
+        Self: Sized;
+
     /// Finds the first match of `self` in `slice` and returns a slice over that pattern, or None if no such match exists
     /// This function may (but is not required to) return `None` if `slice` is not valid, according to [`CharTraits::validate_range`]
     ///
@@ -26,6 +39,17 @@ pub unsafe trait Pattern<CharT, CharTraits> {
     unsafe fn first_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]> {
         self.first_match(slice)
     }
+
+    /// Finds a match of `self` that begins at the start of `slice`, or None if no such match exists.
+    ///
+    /// The default implementation returns the result of [`Pattern::first_match_unchecked`] if it begins at the start of `slice`.
+    ///
+    /// # Safety
+    /// The behaviour may be undefined or an implementation-defined result may result if `slice` is not valid, according to [`CharTraits::validate_range`]
+    unsafe fn prefix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]> {
+        self.first_match_unchecked(slice)
+            .filter(|pat| pat.as_ptr() == slice.as_ptr())
+    }
 }
 
 ///
@@ -51,26 +75,527 @@ pub unsafe trait RevPattern<CharT, CharTraits> {
     unsafe fn last_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]> {
         self.last_match(slice)
     }
+
+    /// Finds a match of `self` that ends at the end of `slice`, or None if no such match exists.
+    ///
+    /// The default implementation returns the result of [`RevPattern::last_match_unchecked`] if it ends at the end of `slice`.
+    ///
+    /// # Safety
+    /// The behaviour may be undefined or an implementation-defined result may result if `slice` is not valid, according to [`CharTraits::validate_range`]
+    unsafe fn suffix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]> {
+        self.last_match_unchecked(slice)
+            .filter(|pat| pat.as_ptr_range().end == slice.as_ptr_range().end)
+    }
 }
 
 /// A trait for pattern types that can be matched both forwards and in reverse
 ///
+/// # Safety
+/// The requirements of both [`Pattern`] and [`RevPattern`] shall be upheld,
+/// and `first_match` and `last_match` shall agree on the set of matches in any given slice.
 pub unsafe trait BidirectionalPattern<CharT, CharTraits>:
     Pattern<CharT, CharTraits> + RevPattern<CharT, CharTraits>
 {
 }
 
-unsafe impl<Traits: CharTraits> Pattern<Traits::Char, Traits> for BasicStr<Traits::Char, Traits> {
-    fn first_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
-        if slice.len() < self.len() {
-            None
+/// A [`BidirectionalPattern`] that matches single characters, such as a `char`, a set of `char`s or a predicate.
+///
+/// Its matches never overlap, so a [`StepSearcher`] finds the same matches from either end, and is a [`DoubleEndedSearcher`].
+///
+/// # Safety
+/// Each match shall be exactly one character, and whether a character matches shall not depend on the characters around it.
+pub unsafe trait CharPattern<CharT, CharTraits>:
+    BidirectionalPattern<CharT, CharTraits>
+{
+}
+
+/// A search for the successive matches of a pattern in a haystack, created by [`Pattern::into_searcher`].
+///
+/// Matches are returned as the range of units `start..end` of the haystack that they cover.
+///
+/// # Safety
+/// Both ends of each match shall be character boundaries of the haystack, with `start <= end <= haystack().len()`.
+/// Matches shall be returned in order, and shall not overlap.
+pub unsafe trait Searcher<'a, CharT> {
+    /// Returns the haystack being searched
+    fn haystack(&self) -> &'a [CharT];
+
+    /// Returns the next match, or `None` if there are no more matches
+    fn next_match(&mut self) -> Option<(usize, usize)>;
+}
+
+/// A [`Searcher`] that can also find matches starting from the end of the haystack
+///
+/// # Safety
+/// The requirements of [`Searcher`] shall be upheld by [`ReverseSearcher::next_match_back`], except that matches are returned in reverse order.
+/// Additionally, no match shall overlap a match returned from the other end.
+pub unsafe trait ReverseSearcher<'a, CharT>: Searcher<'a, CharT> {
+    /// Returns the last match that has not yet been returned, or `None` if there are no more matches
+    fn next_match_back(&mut self) -> Option<(usize, usize)>;
+}
+
+/// A [`ReverseSearcher`] that finds the same matches, whichever end they are searched from.
+///
+/// Iterators over the matches of a pattern are double-ended if its searcher implements this trait.
+/// A substring needle does not, as `"aa"` matches `"aaa"` at `0` searching forwards, but at `1` searching backwards.
+///
+/// # Safety
+/// Searching forwards, backwards, or from both ends shall find the same matches
+pub unsafe trait DoubleEndedSearcher<'a, CharT>: ReverseSearcher<'a, CharT> {}
+
+/// Returns the length of the first character of `chars`, which shall be valid and non-empty
+fn first_char_len<Traits: CharTraits>(chars: &[Traits::Char]) -> usize {
+    (1..chars.len())
+        .find(|&n| Traits::is_char_boundary(chars, n))
+        .unwrap_or(chars.len())
+}
+
+/// Returns the length of the last character of `chars`, which shall be valid and non-empty.
+///
+/// A valid suffix of `chars` need not begin on a character boundary in an encoding that is not self-synchronizing,
+///  so `chars` shall begin on a character boundary.
+fn last_char_len<Traits: CharTraits>(chars: &[Traits::Char]) -> usize {
+    (1..chars.len())
+        .find(|&n| Traits::is_char_boundary(chars, chars.len() - n))
+        .unwrap_or(chars.len())
+}
+
+/// The part of a haystack that a searcher has not yet searched
+#[derive(Clone, Copy, Debug)]
+struct Window {
+    begin: usize,
+    end: usize,
+    finished: bool,
+}
+
+impl Window {
+    fn new(len: usize) -> Self {
+        Self {
+            begin: 0,
+            end: len,
+            finished: false,
+        }
+    }
+
+    fn get<C>(self, haystack: &[C]) -> Option<&[C]> {
+        (!self.finished).then(|| &haystack[self.begin..self.end])
+    }
+
+    /// Removes the match at `start..end` of `haystack`, and everything before it, from the window.
+    ///
+    /// An empty match must not be found again, so the character that follows it is also removed.
+    fn take_front<Traits: CharTraits>(
+        &mut self,
+        haystack: &[Traits::Char],
+        start: usize,
+        end: usize,
+    ) -> (usize, usize) {
+        self.begin = end;
+        if start == end {
+            if self.begin == self.end {
+                self.finished = true;
+            } else {
+                self.begin += first_char_len::<Traits>(&haystack[self.begin..self.end]);
+            }
+        }
+        (start, end)
+    }
+
+    /// Removes the match at `start..end` of `haystack`, and everything after it, from the window.
+    ///
+    /// An empty match must not be found again, so the character that precedes it is also removed.
+    fn take_back<Traits: CharTraits>(
+        &mut self,
+        haystack: &[Traits::Char],
+        start: usize,
+        end: usize,
+    ) -> (usize, usize) {
+        self.end = start;
+        if start == end {
+            if self.begin == self.end {
+                self.finished = true;
+            } else {
+                // The start of the window is a character boundary to decode from
+                self.end -= last_char_len::<Traits>(&haystack[self.begin..self.end]);
+            }
+        }
+        (start, end)
+    }
+}
+
+/// A [`Searcher`] that finds each match by calling [`Pattern::first_match_unchecked`], or [`RevPattern::last_match_unchecked`] from the end.
+///
+/// This is the searcher for patterns that have nothing to precompute for a search, such as characters and predicates.
+pub struct StepSearcher<'a, P, CharT, Traits> {
+    haystack: &'a BasicStr<CharT, Traits>,
+    pat: P,
+    window: Window,
+}
+
+impl<'a, P, CharT, Traits> StepSearcher<'a, P, CharT, Traits> {
+    /// Creates a searcher for the matches of `pat` in `haystack`
+    pub fn new(haystack: &'a BasicStr<CharT, Traits>, pat: P) -> Self {
+        Self {
+            haystack,
+            pat,
+            window: Window::new(haystack.len()),
+        }
+    }
+
+    /// Returns the range of `haystack` covered by `pat`, which shall be a subslice of it
+    fn range_of(&self, pat: &[CharT]) -> (usize, usize) {
+        // Safety:
+        // Guaranteed by the `Pattern` impl
+        let start = unsafe { pat.as_ptr().offset_from(self.haystack.as_ptr()) } as usize;
+        (start, start + pat.len())
+    }
+}
+
+unsafe impl<'a, P, CharT, Traits> Searcher<'a, CharT> for StepSearcher<'a, P, CharT, Traits>
+where
+    P: Pattern<CharT, Traits>,
+    Traits: CharTraits<Char = CharT>,
+{
+    fn haystack(&self) -> &'a [CharT] {
+        self.haystack.as_chars()
+    }
+
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let window = self.window.get(self.haystack.as_chars())?;
+
+        // Safety:
+        // `window` is on character boundaries of `haystack`, which is valid by invariant
+        let Some(pat) = (unsafe { self.pat.first_match_unchecked(window) }) else {
+            self.window.finished = true;
+            return None;
+        };
+        let (start, end) = self.range_of(pat);
+        Some(
+            self.window
+                .take_front::<Traits>(self.haystack.as_chars(), start, end),
+        )
+    }
+}
+
+unsafe impl<'a, P, CharT, Traits> ReverseSearcher<'a, CharT> for StepSearcher<'a, P, CharT, Traits>
+where
+    P: Pattern<CharT, Traits> + RevPattern<CharT, Traits>,
+    Traits: CharTraits<Char = CharT>,
+{
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        let window = self.window.get(self.haystack.as_chars())?;
+
+        // Safety:
+        // `window` is on character boundaries of `haystack`, which is valid by invariant
+        let Some(pat) = (unsafe { self.pat.last_match_unchecked(window) }) else {
+            self.window.finished = true;
+            return None;
+        };
+        let (start, end) = self.range_of(pat);
+        Some(
+            self.window
+                .take_back::<Traits>(self.haystack.as_chars(), start, end),
+        )
+    }
+}
+
+unsafe impl<'a, P, CharT, Traits> DoubleEndedSearcher<'a, CharT>
+    for StepSearcher<'a, P, CharT, Traits>
+where
+    P: CharPattern<CharT, Traits>,
+    Traits: CharTraits<Char = CharT>,
+{
+}
+
+/// The units of a needle or haystack, read forwards, or backwards if `REV` is `true`
+#[derive(Clone, Copy)]
+struct Units<'a, C, const REV: bool>(&'a [C]);
+
+impl<C: Char, const REV: bool> Units<'_, C, REV> {
+    fn len(self) -> usize {
+        self.0.len()
+    }
+
+    fn at(self, i: usize) -> C {
+        if REV {
+            self.0[self.0.len() - 1 - i]
+        } else {
+            self.0[i]
+        }
+    }
+
+    /// Returns the first index in `from..to` of a unit equal to `c`
+    fn find(self, from: usize, to: usize, c: C) -> Option<usize> {
+        if REV {
+            let len = self.0.len();
+            self.0[len - to..len - from]
+                .iter()
+                .rposition(|&x| x == c)
+                .map(|i| to - 1 - i)
         } else {
-            for i in 0..(slice.len() - self.len()) {
-                let sliced = &slice[i..][..self.len()];
-                if self.as_chars() == sliced {
-                    return Some(sliced);
+            self.0[from..to]
+                .iter()
+                .position(|&x| x == c)
+                .map(|i| from + i)
+        }
+    }
+}
+
+/// Returns the start and period of the maximal suffix of `needle`, by the order of units if `greater` is `false`, or the reverse order otherwise
+fn maximal_suffix<C: Char, const REV: bool>(
+    needle: Units<C, REV>,
+    greater: bool,
+) -> (usize, usize) {
+    let (mut left, mut right, mut offset, mut period) = (0, 1, 0, 1);
+    while right + offset < needle.len() {
+        let a = needle.at(right + offset).into_int();
+        let b = needle.at(left + offset).into_int();
+        match a.cmp(&b) {
+            Ordering::Equal => {
+                if offset + 1 == period {
+                    right += period;
+                    offset = 0;
+                } else {
+                    offset += 1;
+                }
+            }
+            ord if (ord == Ordering::Less) != greater => {
+                right += offset + 1;
+                offset = 0;
+                period = right - left;
+            }
+            _ => {
+                left = right;
+                right += 1;
+                offset = 0;
+                period = 1;
+            }
+        }
+    }
+    (left, period)
+}
+
+/// The critical factorization of a needle, used by the Two-Way algorithm of Crochemore and Perrin
+#[derive(Clone, Copy, Debug)]
+struct TwoWay {
+    crit_pos: usize,
+    period: usize,
+    /// Whether the needle has no period shorter than half its length, in which case no memory of previous partial matches is kept
+    long_period: bool,
+}
+
+impl TwoWay {
+    fn new<C: Char, const REV: bool>(needle: Units<C, REV>) -> Self {
+        let m = needle.len();
+        let (crit_less, period_less) = maximal_suffix(needle, false);
+        let (crit_greater, period_greater) = maximal_suffix(needle, true);
+        let (crit_pos, period) = if crit_less > crit_greater {
+            (crit_less, period_less)
+        } else {
+            (crit_greater, period_greater)
+        };
+
+        // The period of the maximal suffix is the period of the needle if the left part repeats after it
+        if crit_pos + period <= m && (0..crit_pos).all(|i| needle.at(i) == needle.at(i + period)) {
+            Self {
+                crit_pos,
+                period,
+                long_period: false,
+            }
+        } else {
+            Self {
+                crit_pos,
+                period: crit_pos.max(m - crit_pos) + 1,
+                long_period: true,
+            }
+        }
+    }
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`, in `O(n + m)` time and constant space.
+///
+/// Candidate positions are first filtered by scanning for the first unit of `needle`.
+fn two_way_search<C: Char, const REV: bool>(
+    needle: Units<C, REV>,
+    haystack: Units<C, REV>,
+    factorization: &TwoWay,
+) -> Option<usize> {
+    let (m, n) = (needle.len(), haystack.len());
+    if m == 0 {
+        return Some(0);
+    } else if m > n {
+        return None;
+    }
+    let first = needle.at(0);
+    if m == 1 {
+        return haystack.find(0, n, first);
+    }
+
+    let &TwoWay {
+        crit_pos,
+        period,
+        long_period,
+    } = factorization;
+    let mut pos = 0;
+    // The length of the prefix of `needle` already known to match at `pos`
+    let mut memory = 0;
+    'search: while pos + m <= n {
+        if memory == 0 {
+            pos = haystack.find(pos, n - m + 1, first)?;
+        }
+
+        for i in crit_pos.max(memory)..m {
+            if needle.at(i) != haystack.at(pos + i) {
+                pos += i - crit_pos + 1;
+                memory = 0;
+                continue 'search;
+            }
+        }
+        for i in (memory..crit_pos).rev() {
+            if needle.at(i) != haystack.at(pos + i) {
+                pos += period;
+                if !long_period {
+                    memory = m - period;
                 }
+                continue 'search;
             }
+        }
+        return Some(pos);
+    }
+    None
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack` that begins on a character boundary.
+///
+/// In an encoding that is not self-synchronizing, the units of `needle` can also occur across characters of `haystack`.
+fn find_on_boundary<Traits: CharTraits>(
+    needle: &[Traits::Char],
+    haystack: &[Traits::Char],
+    factorization: &TwoWay,
+) -> Option<usize> {
+    let mut from = 0;
+    loop {
+        let pos = from
+            + two_way_search(
+                Units::<_, false>(needle),
+                Units(&haystack[from..]),
+                factorization,
+            )?;
+        if Traits::is_char_boundary(haystack, pos) {
+            return Some(pos);
+        }
+        from = pos + 1;
+    }
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack` that begins on a character boundary
+fn rfind_on_boundary<Traits: CharTraits>(
+    needle: &[Traits::Char],
+    haystack: &[Traits::Char],
+    factorization: &TwoWay,
+) -> Option<usize> {
+    let mut to = haystack.len();
+    loop {
+        // Search the reversed haystack for the reversed needle
+        let pos = two_way_search(
+            Units::<_, true>(needle),
+            Units(&haystack[..to]),
+            factorization,
+        )?;
+        let start = to - pos - needle.len();
+        if Traits::is_char_boundary(haystack, start) {
+            return Some(start);
+        }
+        to = start + needle.len() - 1;
+    }
+}
+
+/// The [`Searcher`] for a [`BasicStr`] needle, which factorizes the needle once for the whole search
+pub struct StrSearcher<'a, 'b, CharT, Traits> {
+    haystack: &'a BasicStr<CharT, Traits>,
+    needle: &'b BasicStr<CharT, Traits>,
+    forward: TwoWay,
+    backward: TwoWay,
+    window: Window,
+}
+
+impl<'a, 'b, Traits: CharTraits> StrSearcher<'a, 'b, Traits::Char, Traits> {
+    /// Creates a searcher for the matches of `needle` in `haystack`
+    pub fn new(
+        haystack: &'a BasicStr<Traits::Char, Traits>,
+        needle: &'b BasicStr<Traits::Char, Traits>,
+    ) -> Self {
+        Self {
+            haystack,
+            needle,
+            forward: TwoWay::new(Units::<_, false>(needle.as_chars())),
+            backward: TwoWay::new(Units::<_, true>(needle.as_chars())),
+            window: Window::new(haystack.len()),
+        }
+    }
+}
+
+unsafe impl<'a, Traits: CharTraits> Searcher<'a, Traits::Char>
+    for StrSearcher<'a, '_, Traits::Char, Traits>
+{
+    fn haystack(&self) -> &'a [Traits::Char] {
+        self.haystack.as_chars()
+    }
+
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let window = self.window.get(self.haystack.as_chars())?;
+        let Some(pos) = find_on_boundary::<Traits>(self.needle.as_chars(), window, &self.forward)
+        else {
+            self.window.finished = true;
+            return None;
+        };
+        let start = self.window.begin + pos;
+        Some(self.window.take_front::<Traits>(
+            self.haystack.as_chars(),
+            start,
+            start + self.needle.len(),
+        ))
+    }
+}
+
+unsafe impl<'a, Traits: CharTraits> ReverseSearcher<'a, Traits::Char>
+    for StrSearcher<'a, '_, Traits::Char, Traits>
+{
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        let window = self.window.get(self.haystack.as_chars())?;
+        let Some(pos) = rfind_on_boundary::<Traits>(self.needle.as_chars(), window, &self.backward)
+        else {
+            self.window.finished = true;
+            return None;
+        };
+        let start = self.window.begin + pos;
+        Some(self.window.take_back::<Traits>(
+            self.haystack.as_chars(),
+            start,
+            start + self.needle.len(),
+        ))
+    }
+}
+
+unsafe impl<Traits: CharTraits> Pattern<Traits::Char, Traits> for BasicStr<Traits::Char, Traits> {
+    type Searcher<'a>
+        = StrSearcher<'a, 'a, Traits::Char, Traits>
+    where
+        Traits: 'a;
+
+    fn first_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        let needle = Units::<_, false>(self.as_chars());
+        let pos = find_on_boundary::<Traits>(self.as_chars(), slice, &TwoWay::new(needle))?;
+        Some(&slice[pos..][..self.len()])
+    }
+
+    unsafe fn prefix_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        if slice.starts_with(self.as_chars()) {
+            Some(&slice[..self.len()])
+        } else {
             None
         }
     }
@@ -80,15 +605,19 @@ unsafe impl<Traits: CharTraits> RevPattern<Traits::Char, Traits>
     for BasicStr<Traits::Char, Traits>
 {
     fn last_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
-        if slice.len() < self.len() {
-            None
+        let needle = Units::<_, true>(self.as_chars());
+        let pos = rfind_on_boundary::<Traits>(self.as_chars(), slice, &TwoWay::new(needle))?;
+        Some(&slice[pos..][..self.len()])
+    }
+
+    unsafe fn suffix_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        let start = slice.len().checked_sub(self.len())?;
+        if slice.ends_with(self.as_chars()) && Traits::is_char_boundary(slice, start) {
+            Some(&slice[start..])
         } else {
-            for i in (0..(slice.len() - self.len())).rev() {
-                let sliced = &slice[..i][..self.len()];
-                if self.as_chars() == sliced {
-                    return Some(sliced);
-                }
-            }
             None
         }
     }
@@ -100,6 +629,16 @@ unsafe impl<Traits: CharTraits> BidirectionalPattern<Traits::Char, Traits>
 }
 
 unsafe impl<Traits: CharTraits + IntoChars> Pattern<Traits::Char, Traits> for char {
+    type Searcher<'a>
+        = StepSearcher<'a, Self, Traits::Char, Traits>
+    where
+        Traits::Char: 'a,
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<Traits::Char, Traits>) -> Self::Searcher<'a> {
+        StepSearcher::new(haystack, self)
+    }
+
     unsafe fn first_match_unchecked<'a>(
         &self,
         mut slice: &'a [Traits::Char],
@@ -133,7 +672,7 @@ unsafe impl<Traits: CharTraits + DecodeRev> RevPattern<Traits::Char, Traits> for
         while !slice.is_empty() {
             let (c, rest) = Traits::decode_back_unchecked(slice);
             if c == *self {
-                let pos = slice.as_ptr().offset_from(rest.as_ptr()) as usize;
+                let pos = rest.len();
                 return Some(&slice[pos..]);
             }
             slice = rest;
@@ -144,7 +683,7 @@ unsafe impl<Traits: CharTraits + DecodeRev> RevPattern<Traits::Char, Traits> for
     fn last_match<'a>(&self, mut slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
         while let Some((c, rest)) = Traits::decode_back(slice) {
             if c == *self {
-                let pos = unsafe { slice.as_ptr().offset_from(rest.as_ptr()) } as usize;
+                let pos = rest.len();
                 return Some(&slice[pos..]);
             }
             slice = rest;
@@ -155,9 +694,21 @@ unsafe impl<Traits: CharTraits + DecodeRev> RevPattern<Traits::Char, Traits> for
 
 unsafe impl<Traits: CharTraits + DecodeRev> BidirectionalPattern<Traits::Char, Traits> for char {}
 
+unsafe impl<Traits: CharTraits + DecodeRev> CharPattern<Traits::Char, Traits> for char {}
+
 unsafe impl<Traits: CharTraits + IntoChars, F: Fn(char) -> bool> Pattern<Traits::Char, Traits>
     for F
 {
+    type Searcher<'a>
+        = StepSearcher<'a, Self, Traits::Char, Traits>
+    where
+        Traits::Char: 'a,
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<Traits::Char, Traits>) -> Self::Searcher<'a> {
+        StepSearcher::new(haystack, self)
+    }
+
     fn first_match<'a>(&self, mut slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
         while let Some((c, rest)) = Traits::decode_buf(slice) {
             if (*self)(c) {
@@ -193,7 +744,7 @@ unsafe impl<Traits: CharTraits + DecodeRev, F: Fn(char) -> bool> RevPattern<Trai
         while !slice.is_empty() {
             let (c, rest) = Traits::decode_back_unchecked(slice);
             if (*self)(c) {
-                let pos = slice.as_ptr().offset_from(rest.as_ptr()) as usize;
+                let pos = rest.len();
                 return Some(&slice[pos..]);
             }
             slice = rest;
@@ -204,7 +755,7 @@ unsafe impl<Traits: CharTraits + DecodeRev, F: Fn(char) -> bool> RevPattern<Trai
     fn last_match<'a>(&self, mut slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
         while let Some((c, rest)) = Traits::decode_back(slice) {
             if (*self)(c) {
-                let pos = unsafe { slice.as_ptr().offset_from(rest.as_ptr()) } as usize;
+                let pos = rest.len();
                 return Some(&slice[pos..]);
             }
             slice = rest;
@@ -218,7 +769,17 @@ unsafe impl<Traits: CharTraits + DecodeRev, F: Fn(char) -> bool>
 {
 }
 
+unsafe impl<Traits: CharTraits + DecodeRev, F: Fn(char) -> bool> CharPattern<Traits::Char, Traits>
+    for F
+{
+}
+
 unsafe impl<Traits: CharTraits + IntoChars> Pattern<Traits::Char, Traits> for [char] {
+    type Searcher<'a>
+        = StepSearcher<'a, &'a [char], Traits::Char, Traits>
+    where
+        Traits: 'a;
+
     fn first_match<'a>(&self, mut slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
         while let Some((c, rest)) = Traits::decode_buf(slice) {
             if self.contains(&c) {
@@ -252,7 +813,7 @@ unsafe impl<Traits: CharTraits + DecodeRev> RevPattern<Traits::Char, Traits> for
         while !slice.is_empty() {
             let (c, rest) = Traits::decode_back_unchecked(slice);
             if self.contains(&c) {
-                let pos = slice.as_ptr().offset_from(rest.as_ptr()) as usize;
+                let pos = rest.len();
                 return Some(&slice[pos..]);
             }
             slice = rest;
@@ -263,7 +824,7 @@ unsafe impl<Traits: CharTraits + DecodeRev> RevPattern<Traits::Char, Traits> for
     fn last_match<'a>(&self, mut slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
         while let Some((c, rest)) = Traits::decode_back(slice) {
             if self.contains(&c) {
-                let pos = unsafe { slice.as_ptr().offset_from(rest.as_ptr()) } as usize;
+                let pos = rest.len();
                 return Some(&slice[pos..]);
             }
             slice = rest;
@@ -277,10 +838,23 @@ unsafe impl<Traits: CharTraits + DecodeRev> BidirectionalPattern<Traits::Char, T
 {
 }
 
+unsafe impl<Traits: CharTraits + DecodeRev> CharPattern<Traits::Char, Traits> for [char] {}
+
 unsafe impl<CharT, Traits, const N: usize> Pattern<CharT, Traits> for [char; N]
 where
     [char]: Pattern<CharT, Traits>,
+    Traits: CharTraits<Char = CharT>,
 {
+    type Searcher<'a>
+        = StepSearcher<'a, Self, CharT, Traits>
+    where
+        CharT: 'a,
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<CharT, Traits>) -> Self::Searcher<'a> {
+        StepSearcher::new(haystack, self)
+    }
+
     fn first_match<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]> {
         <[char]>::first_match(self, slice)
     }
@@ -303,15 +877,30 @@ where
     }
 }
 
-unsafe impl<CharT, Traits, const N: usize> BidirectionalPattern<CharT, Traits> for [char; N] where
-    [char]: BidirectionalPattern<CharT, Traits>
+unsafe impl<CharT, Traits, const N: usize> BidirectionalPattern<CharT, Traits> for [char; N]
+where
+    [char]: BidirectionalPattern<CharT, Traits>,
+    Traits: CharTraits<Char = CharT>,
+{
+}
+
+unsafe impl<CharT, Traits, const N: usize> CharPattern<CharT, Traits> for [char; N]
+where
+    [char]: CharPattern<CharT, Traits>,
+    Traits: CharTraits<Char = CharT>,
 {
 }
 
 macro_rules! impl_ref_ref_mut{
-    ($($ty:ty),*) => {
+    ($($ty:ty => $searcher:ty),*) => {
         $(
-            unsafe impl<CharT, Traits> Pattern<CharT, Traits> for &$ty where $ty: Pattern<CharT, Traits>{
+            unsafe impl<'b, CharT, Traits> Pattern<CharT, Traits> for &'b $ty where $ty: Pattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{
+                type Searcher<'a> = $searcher where CharT: 'a, Traits: 'a;
+
+                fn into_searcher<'a>(self, haystack: &'a BasicStr<CharT, Traits>) -> Self::Searcher<'a>{
+                    <$searcher>::new(haystack, self)
+                }
+
                 fn first_match<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <$ty as Pattern<CharT, Traits>>::first_match(self,slice)
                 }
@@ -319,9 +908,19 @@ macro_rules! impl_ref_ref_mut{
                 unsafe fn first_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <$ty as Pattern<CharT, Traits>>::first_match_unchecked(self,slice)
                 }
+
+                unsafe fn prefix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <$ty as Pattern<CharT, Traits>>::prefix_match_unchecked(self,slice)
+                }
             }
 
-            unsafe impl<CharT, Traits> Pattern<CharT, Traits> for &mut $ty where $ty: Pattern<CharT, Traits>{
+            unsafe impl<'b, CharT, Traits> Pattern<CharT, Traits> for &'b mut $ty where $ty: Pattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{
+                type Searcher<'a> = $searcher where CharT: 'a, Traits: 'a;
+
+                fn into_searcher<'a>(self, haystack: &'a BasicStr<CharT, Traits>) -> Self::Searcher<'a>{
+                    <$searcher>::new(haystack, self)
+                }
+
                 fn first_match<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <$ty as Pattern<CharT, Traits>>::first_match(self,slice)
                 }
@@ -329,6 +928,10 @@ macro_rules! impl_ref_ref_mut{
                 unsafe fn first_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <$ty as Pattern<CharT, Traits>>::first_match_unchecked(self,slice)
                 }
+
+                unsafe fn prefix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <$ty as Pattern<CharT, Traits>>::prefix_match_unchecked(self,slice)
+                }
             }
 
             unsafe impl<CharT, Traits> RevPattern<CharT, Traits> for &$ty where $ty: RevPattern<CharT, Traits>{
@@ -339,6 +942,10 @@ macro_rules! impl_ref_ref_mut{
                 unsafe fn last_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <$ty as RevPattern<CharT, Traits>>::last_match_unchecked(self,slice)
                 }
+
+                unsafe fn suffix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <$ty as RevPattern<CharT, Traits>>::suffix_match_unchecked(self,slice)
+                }
             }
 
             unsafe impl<CharT, Traits> RevPattern<CharT, Traits> for &mut $ty where $ty: RevPattern<CharT, Traits>{
@@ -349,22 +956,40 @@ macro_rules! impl_ref_ref_mut{
                 unsafe fn last_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <$ty as RevPattern<CharT, Traits>>::last_match_unchecked(self,slice)
                 }
+
+                unsafe fn suffix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <$ty as RevPattern<CharT, Traits>>::suffix_match_unchecked(self,slice)
+                }
             }
 
-            unsafe impl<CharT, Traits> BidirectionalPattern<CharT, Traits> for &$ty where $ty: BidirectionalPattern<CharT, Traits>{}
+            unsafe impl<CharT, Traits> BidirectionalPattern<CharT, Traits> for &$ty where $ty: BidirectionalPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
 
-            unsafe impl<CharT, Traits> BidirectionalPattern<CharT, Traits> for &mut $ty where $ty: BidirectionalPattern<CharT, Traits>{}
+            unsafe impl<CharT, Traits> BidirectionalPattern<CharT, Traits> for &mut $ty where $ty: BidirectionalPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
+
+            unsafe impl<CharT, Traits> CharPattern<CharT, Traits> for &$ty where $ty: CharPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
+
+            unsafe impl<CharT, Traits> CharPattern<CharT, Traits> for &mut $ty where $ty: CharPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
 
         )*
     }
 }
 
-impl_ref_ref_mut!(char, [char], BasicStr<CharT,Traits>);
+impl_ref_ref_mut!(
+    char => StepSearcher<'a, Self, CharT, Traits>,
+    [char] => StepSearcher<'a, Self, CharT, Traits>,
+    BasicStr<CharT, Traits> => StrSearcher<'a, 'b, CharT, Traits>
+);
 
 macro_rules! impl_ref_ref_mut_array{
     ($([$ty:ty ; _]),*) => {
         $(
-            unsafe impl<CharT, Traits,const N: usize> Pattern<CharT, Traits> for &[$ty;N] where [$ty;N]: Pattern<CharT, Traits>{
+            unsafe impl<CharT, Traits,const N: usize> Pattern<CharT, Traits> for &[$ty;N] where [$ty;N]: Pattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{
+                type Searcher<'a> = StepSearcher<'a, Self, CharT, Traits> where CharT: 'a, Traits: 'a;
+
+                fn into_searcher<'a>(self, haystack: &'a BasicStr<CharT, Traits>) -> Self::Searcher<'a>{
+                    StepSearcher::new(haystack, self)
+                }
+
                 fn first_match<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <[$ty;N] as Pattern<CharT, Traits>>::first_match(self,slice)
                 }
@@ -372,9 +997,19 @@ macro_rules! impl_ref_ref_mut_array{
                 unsafe fn first_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <[$ty;N] as Pattern<CharT, Traits>>::first_match_unchecked(self,slice)
                 }
+
+                unsafe fn prefix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <[$ty;N] as Pattern<CharT, Traits>>::prefix_match_unchecked(self,slice)
+                }
             }
 
-            unsafe impl<CharT, Traits,const N: usize> Pattern<CharT, Traits> for &mut [$ty;N] where [$ty;N]: Pattern<CharT, Traits>{
+            unsafe impl<CharT, Traits,const N: usize> Pattern<CharT, Traits> for &mut [$ty;N] where [$ty;N]: Pattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{
+                type Searcher<'a> = StepSearcher<'a, Self, CharT, Traits> where CharT: 'a, Traits: 'a;
+
+                fn into_searcher<'a>(self, haystack: &'a BasicStr<CharT, Traits>) -> Self::Searcher<'a>{
+                    StepSearcher::new(haystack, self)
+                }
+
                 fn first_match<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <[$ty;N] as Pattern<CharT, Traits>>::first_match(self,slice)
                 }
@@ -382,6 +1017,10 @@ macro_rules! impl_ref_ref_mut_array{
                 unsafe fn first_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <[$ty;N] as Pattern<CharT, Traits>>::first_match_unchecked(self,slice)
                 }
+
+                unsafe fn prefix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <[$ty;N] as Pattern<CharT, Traits>>::prefix_match_unchecked(self,slice)
+                }
             }
 
             unsafe impl<CharT, Traits, const N: usize> RevPattern<CharT, Traits> for &[$ty;N] where [$ty;N]: RevPattern<CharT, Traits>{
@@ -392,6 +1031,10 @@ macro_rules! impl_ref_ref_mut_array{
                 unsafe fn last_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <[$ty;N] as RevPattern<CharT, Traits>>::last_match_unchecked(self,slice)
                 }
+
+                unsafe fn suffix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <[$ty;N] as RevPattern<CharT, Traits>>::suffix_match_unchecked(self,slice)
+                }
             }
 
             unsafe impl<CharT, Traits, const N: usize> RevPattern<CharT, Traits> for &mut [$ty;N] where [$ty;N]: RevPattern<CharT, Traits>{
@@ -402,14 +1045,135 @@ macro_rules! impl_ref_ref_mut_array{
                 unsafe fn last_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
                     <[$ty;N] as RevPattern<CharT, Traits>>::last_match_unchecked(self,slice)
                 }
+
+                unsafe fn suffix_match_unchecked<'a>(&self, slice: &'a [CharT]) -> Option<&'a [CharT]>{
+                    <[$ty;N] as RevPattern<CharT, Traits>>::suffix_match_unchecked(self,slice)
+                }
             }
 
-            unsafe impl<CharT, Traits,const N: usize> BidirectionalPattern<CharT, Traits> for &[$ty;N] where [$ty;N]: BidirectionalPattern<CharT, Traits>{}
+            unsafe impl<CharT, Traits,const N: usize> BidirectionalPattern<CharT, Traits> for &[$ty;N] where [$ty;N]: BidirectionalPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
+
+            unsafe impl<CharT, Traits,const N: usize> BidirectionalPattern<CharT, Traits> for &mut [$ty;N] where [$ty;N]: BidirectionalPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
 
-            unsafe impl<CharT, Traits,const N: usize> BidirectionalPattern<CharT, Traits> for &mut [$ty;N] where [$ty;N]: BidirectionalPattern<CharT, Traits>{}
+            unsafe impl<CharT, Traits,const N: usize> CharPattern<CharT, Traits> for &[$ty;N] where [$ty;N]: CharPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
+
+            unsafe impl<CharT, Traits,const N: usize> CharPattern<CharT, Traits> for &mut [$ty;N] where [$ty;N]: CharPattern<CharT, Traits>, Traits: CharTraits<Char = CharT>{}
 
         )*
     }
 }
 
 impl_ref_ref_mut_array!([char; _]);
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::{Pattern, ReverseSearcher, Searcher};
+    use crate::str::{Str, U16Str};
+
+    /// Calls `f` with every string of up to `max_len` characters from `alphabet`
+    fn for_each_string(alphabet: &[char], max_len: usize, f: &mut dyn FnMut(&str)) {
+        let mut s = String::new();
+        fn go(alphabet: &[char], s: &mut String, max_len: usize, f: &mut dyn FnMut(&str)) {
+            f(s);
+            if max_len == 0 {
+                return;
+            }
+            for &c in alphabet {
+                s.push(c);
+                go(alphabet, s, max_len - 1, f);
+                s.pop();
+            }
+        }
+        go(alphabet, &mut s, max_len, f);
+    }
+
+    #[test]
+    fn test_substring_search() {
+        let mut needles = Vec::new();
+        for_each_string(&['a', 'b'], 5, &mut |needle| {
+            needles.push(needle.to_owned())
+        });
+        for_each_string(&['a', 'b'], 9, &mut |haystack| {
+            let s = Str::from_str(haystack);
+            for needle in &needles {
+                let pat = Str::from_str(needle);
+                assert_eq!(
+                    s.find(pat),
+                    haystack.find(&**needle),
+                    "{haystack:?}.find({needle:?})"
+                );
+                assert_eq!(
+                    s.rfind(pat),
+                    haystack.rfind(&**needle),
+                    "{haystack:?}.rfind({needle:?})"
+                );
+            }
+        });
+
+        let mut needles = Vec::new();
+        for_each_string(&['a', '\u{e9}', '\u{1f600}'], 3, &mut |needle| {
+            needles.push(needle.to_owned())
+        });
+        for_each_string(&['a', '\u{e9}', '\u{1f600}'], 5, &mut |haystack| {
+            let s = Str::from_str(haystack);
+            let units: Vec<u16> = haystack.encode_utf16().collect();
+            let u16s = U16Str::from_chars(&units).unwrap();
+            for needle in &needles {
+                let pat = Str::from_str(needle);
+                assert_eq!(s.find(pat), haystack.find(&**needle));
+                assert_eq!(s.rfind(pat), haystack.rfind(&**needle));
+
+                let needle_units: Vec<u16> = needle.encode_utf16().collect();
+                let pat = U16Str::from_chars(&needle_units).unwrap();
+                let utf16_index = |i: usize| haystack[..i].encode_utf16().count();
+                assert_eq!(u16s.find(pat), haystack.find(&**needle).map(utf16_index));
+                assert_eq!(u16s.rfind(pat), haystack.rfind(&**needle).map(utf16_index));
+            }
+        });
+    }
+
+    #[test]
+    fn test_substring_match_indices() {
+        for haystack in ["", "a", "a\u{e9}b", "aaaa", "abababa"] {
+            let s = Str::from_str(haystack);
+            for needle in ["", "a", "aa", "aba", "\u{e9}"] {
+                let pat = Str::from_str(needle);
+                let indices: Vec<_> = s.match_indices(pat).map(|(i, _)| i).collect();
+                let expected: Vec<_> = haystack.match_indices(needle).map(|(i, _)| i).collect();
+                assert_eq!(indices, expected, "{haystack:?}.match_indices({needle:?})");
+
+                let indices: Vec<_> = s.rmatch_indices(pat).map(|(i, _)| i).collect();
+                let expected: Vec<_> = haystack.rmatch_indices(needle).map(|(i, _)| i).collect();
+                assert_eq!(indices, expected, "{haystack:?}.rmatch_indices({needle:?})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_str_searcher() {
+        let haystack = Str::from_str("aaaaa");
+        let needle = Str::from_str("aa");
+
+        let mut searcher = needle.into_searcher(haystack);
+        assert_eq!(searcher.haystack(), b"aaaaa");
+        assert_eq!(searcher.next_match(), Some((0, 2)));
+        assert_eq!(searcher.next_match(), Some((2, 4)));
+        assert_eq!(searcher.next_match(), None);
+
+        let mut searcher = needle.into_searcher(haystack);
+        assert_eq!(searcher.next_match_back(), Some((3, 5)));
+        assert_eq!(searcher.next_match_back(), Some((1, 3)));
+        assert_eq!(searcher.next_match_back(), None);
+
+        let mut searcher = needle.into_searcher(haystack);
+        assert_eq!(searcher.next_match(), Some((0, 2)));
+        assert_eq!(searcher.next_match_back(), Some((3, 5)));
+        assert_eq!(searcher.next_match(), None);
+        assert_eq!(searcher.next_match_back(), None);
+
+        let mut searcher = Str::from_str("").into_searcher(Str::from_str("\u{e9}"));
+        assert_eq!(searcher.next_match(), Some((0, 0)));
+        assert_eq!(searcher.next_match_back(), Some((2, 2)));
+        assert_eq!(searcher.next_match(), None);
+    }
+}
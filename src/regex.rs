@@ -0,0 +1,1719 @@
+//! Regular expressions over strings in any encoding
+//!
+//! A [`Regex`] is compiled to a program for a Pike VM, which simulates the NFA of the expression over the characters of a string,
+//!  decoding them with [`IntoChars`] so that a [`BasicStr`] in any encoding can be searched without transcoding it.
+//! The VM never backtracks, so a search takes time linear in the length of the haystack, whatever the expression.
+//!
+//! # Syntax
+//! The syntax is a subset of that of the `regex` crate:
+//! * A literal character matches itself, and `\` followed by punctuation matches the punctuation.
+//!   `\n`, `\r`, `\t`, `\f`, `\v`, `\0`, `\x7F`, `\x{10FFFF}`, `\u00E9` and `\u{10FFFF}` match the given character.
+//! * `.` matches any character except `\n`, or any character at all with the `s` flag.
+//! * `[a-z_]` matches any of a set of characters, and `[^a-z_]` any character not in the set.
+//! * `\d` matches a decimal digit (General_Category=Nd), `\s` a White_Space character,
+//!   and `\w` a word character (Alphabetic, a mark, a decimal digit, a connector punctuation, or a join control).
+//!   `\D`, `\S` and `\W` match their complements.
+//! * `\p{..}` matches a character with a Unicode property, and `\P{..}` one without it, where the property is a General_Category,
+//!   by short or long name (`\p{Lu}`, `\p{Uppercase_Letter}`, `\pL`), or one of
+//!   `Alphabetic`, `Lowercase`, `Uppercase`, `White_Space`, `Extended_Pictographic`, `ASCII`, `Assigned` and `Any`.
+//!   The properties are those of Unicode 15.0.0, like the other tables of the crate.
+//! * `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}` repeat the preceding item, as many times as possible, or as few when followed by `?`.
+//! * `a|b` matches either alternative, preferring the first.
+//! * `(..)` is a capture group, `(?P<name>..)` or `(?<name>..)` a named capture group, and `(?:..)` a group that does not capture.
+//! * `^` and `$` match at the start and end of the haystack, or of a line with the `m` flag.
+//!   `\A` and `\z` always match at the start and end of the haystack, and `\b` and `\B` at a word boundary and anywhere else.
+//! * `(?flags)` sets flags until the end of the enclosing group, and `(?flags:..)` within the group, where `-` clears the flags after it.
+//!   The flags are `i`, which matches letters case-insensitively by their simple case folding, `m` and `s`.
+//!
+//! Matches are leftmost-first: of the matches starting at the leftmost position, the one preferred by the expression is found.
+//!
+//! As a [`Pattern`], a [`Regex`] searches the whole haystack with the searcher of [`Pattern::into_searcher`],
+//!  but [`Pattern::first_match`], [`Pattern::prefix_match_unchecked`] and the methods built on them, like [`BasicStr::starts_with`] and [`BasicStr::trim_start_matches`],
+//!  are only given a slice of the haystack, so `^`, `\A`, `\b` and `\B` treat the ends of the slice as the ends of the haystack.
+
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Range;
+
+use crate::case::{simple_fold, simple_foldings, single_char_mappings};
+use crate::pattern::{Pattern, Searcher};
+use crate::str::BasicStr;
+use crate::traits::IntoChars;
+
+#[rustfmt::skip]
+mod tables;
+
+/// The largest count that may be given to a counted repetition
+const MAX_REPEAT: u32 = 1000;
+
+/// The largest number of instructions that a compiled expression may have
+const MAX_INSTS: usize = 1 << 20;
+
+/// The General_Category property of a character
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// Lu
+    UppercaseLetter,
+    /// Ll
+    LowercaseLetter,
+    /// Lt
+    TitlecaseLetter,
+    /// Lm
+    ModifierLetter,
+    /// Lo
+    OtherLetter,
+    /// Mn
+    NonspacingMark,
+    /// Mc
+    SpacingMark,
+    /// Me
+    EnclosingMark,
+    /// Nd
+    DecimalNumber,
+    /// Nl
+    LetterNumber,
+    /// No
+    OtherNumber,
+    /// Pc
+    ConnectorPunctuation,
+    /// Pd
+    DashPunctuation,
+    /// Ps
+    OpenPunctuation,
+    /// Pe
+    ClosePunctuation,
+    /// Pi
+    InitialPunctuation,
+    /// Pf
+    FinalPunctuation,
+    /// Po
+    OtherPunctuation,
+    /// Sm
+    MathSymbol,
+    /// Sc
+    CurrencySymbol,
+    /// Sk
+    ModifierSymbol,
+    /// So
+    OtherSymbol,
+    /// Zs
+    SpaceSeparator,
+    /// Zl
+    LineSeparator,
+    /// Zp
+    ParagraphSeparator,
+    /// Cc
+    Control,
+    /// Cf
+    Format,
+    /// Cs, which no `char` has
+    Surrogate,
+    /// Co
+    PrivateUse,
+    /// Cn
+    Unassigned,
+}
+
+fn range_cmp(lo: u32, hi: u32, c: u32) -> Ordering {
+    if hi < c {
+        Ordering::Less
+    } else if lo > c {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Returns `true` if `c` is in one of the sorted ranges of `table`
+fn in_table(table: &[(u32, u32)], c: char) -> bool {
+    table
+        .binary_search_by(|&(lo, hi)| range_cmp(lo, hi, c as u32))
+        .is_ok()
+}
+
+/// Returns the General_Category of `c`
+pub fn general_category(c: char) -> GeneralCategory {
+    tables::GENERAL_CATEGORY
+        .binary_search_by(|&(lo, hi, _)| range_cmp(lo, hi, c as u32))
+        .map_or(GeneralCategory::Unassigned, |i| {
+            tables::GENERAL_CATEGORY[i].2
+        })
+}
+
+/// Returns `true` if `c` is a word character, as matched by `\w`
+fn is_word_char(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_alphanumeric() || c == '_';
+    }
+    use GeneralCategory::*;
+    in_table(&tables::ALPHABETIC, c)
+        || matches!(
+            general_category(c),
+            NonspacingMark | SpacingMark | EnclosingMark | DecimalNumber | ConnectorPunctuation
+        )
+        || c == '\u{200C}'
+        || c == '\u{200D}'
+}
+
+/// The kinds of error in a regular expression
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The expression ended in the middle of an escape, group or repetition
+    UnexpectedEnd,
+    /// A group was not closed
+    UnclosedGroup,
+    /// A `)` has no matching `(`
+    UnopenedGroup,
+    /// A class was not closed
+    UnclosedClass,
+    /// A class range ends before it starts, or has an end that is not a single character
+    InvalidClassRange,
+    /// An escape sequence is not recognized
+    InvalidEscape,
+    /// An escape sequence names a value that is not a Unicode scalar value
+    InvalidCodePoint,
+    /// A Unicode property is not recognized
+    UnknownProperty,
+    /// A repetition operator does not follow an item to repeat
+    NothingToRepeat,
+    /// A counted repetition is malformed, has a minimum above its maximum, or a count that is too large
+    InvalidRepetition,
+    /// A group name is empty or contains a character that is not allowed
+    InvalidGroupName,
+    /// Two groups have the same name
+    DuplicateGroupName,
+    /// A flag is not recognized, or is negated twice
+    InvalidFlag,
+    /// The compiled expression would be too large
+    TooBig,
+}
+
+impl ErrorKind {
+    fn description(self) -> &'static str {
+        match self {
+            ErrorKind::UnexpectedEnd => "unexpected end of expression",
+            ErrorKind::UnclosedGroup => "unclosed group",
+            ErrorKind::UnopenedGroup => "unopened group",
+            ErrorKind::UnclosedClass => "unclosed class",
+            ErrorKind::InvalidClassRange => "invalid class range",
+            ErrorKind::InvalidEscape => "invalid escape sequence",
+            ErrorKind::InvalidCodePoint => "invalid code point",
+            ErrorKind::UnknownProperty => "unknown Unicode property",
+            ErrorKind::NothingToRepeat => "repetition operator without an item to repeat",
+            ErrorKind::InvalidRepetition => "invalid repetition",
+            ErrorKind::InvalidGroupName => "invalid group name",
+            ErrorKind::DuplicateGroupName => "duplicate group name",
+            ErrorKind::InvalidFlag => "invalid flag",
+            ErrorKind::TooBig => "compiled expression is too large",
+        }
+    }
+}
+
+/// The error returned when a regular expression is invalid
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Error {
+    kind: ErrorKind,
+    offset: usize,
+}
+
+impl Error {
+    /// Returns the kind of the error
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the expression at which the error was found
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind.description(), self.offset)
+    }
+}
+
+/// Returns the bit of `gc` in a set of general categories
+const fn gc_bit(gc: GeneralCategory) -> u32 {
+    1 << gc as u32
+}
+
+/// The General_Category values and groups, by short and long name, as sets of general categories
+const CATEGORY_NAMES: &[(&str, &str, u32)] = {
+    use GeneralCategory::*;
+    const LU: u32 = gc_bit(UppercaseLetter);
+    const LL: u32 = gc_bit(LowercaseLetter);
+    const LT: u32 = gc_bit(TitlecaseLetter);
+    const LM: u32 = gc_bit(ModifierLetter);
+    const LO: u32 = gc_bit(OtherLetter);
+    const MN: u32 = gc_bit(NonspacingMark);
+    const MC: u32 = gc_bit(SpacingMark);
+    const ME: u32 = gc_bit(EnclosingMark);
+    const ND: u32 = gc_bit(DecimalNumber);
+    const NL: u32 = gc_bit(LetterNumber);
+    const NO: u32 = gc_bit(OtherNumber);
+    const PC: u32 = gc_bit(ConnectorPunctuation);
+    const PD: u32 = gc_bit(DashPunctuation);
+    const PS: u32 = gc_bit(OpenPunctuation);
+    const PE: u32 = gc_bit(ClosePunctuation);
+    const PI: u32 = gc_bit(InitialPunctuation);
+    const PF: u32 = gc_bit(FinalPunctuation);
+    const PO: u32 = gc_bit(OtherPunctuation);
+    const SM: u32 = gc_bit(MathSymbol);
+    const SC: u32 = gc_bit(CurrencySymbol);
+    const SK: u32 = gc_bit(ModifierSymbol);
+    const SO: u32 = gc_bit(OtherSymbol);
+    const ZS: u32 = gc_bit(SpaceSeparator);
+    const ZL: u32 = gc_bit(LineSeparator);
+    const ZP: u32 = gc_bit(ParagraphSeparator);
+    const CC: u32 = gc_bit(Control);
+    const CF: u32 = gc_bit(Format);
+    const CS: u32 = gc_bit(Surrogate);
+    const CO: u32 = gc_bit(PrivateUse);
+    const CN: u32 = gc_bit(Unassigned);
+    &[
+        ("l", "letter", LU | LL | LT | LM | LO),
+        ("lc", "casedletter", LU | LL | LT),
+        ("lu", "uppercaseletter", LU),
+        ("ll", "lowercaseletter", LL),
+        ("lt", "titlecaseletter", LT),
+        ("lm", "modifierletter", LM),
+        ("lo", "otherletter", LO),
+        ("m", "mark", MN | MC | ME),
+        ("m", "combiningmark", MN | MC | ME),
+        ("mn", "nonspacingmark", MN),
+        ("mc", "spacingmark", MC),
+        ("me", "enclosingmark", ME),
+        ("n", "number", ND | NL | NO),
+        ("nd", "decimalnumber", ND),
+        ("nd", "digit", ND),
+        ("nl", "letternumber", NL),
+        ("no", "othernumber", NO),
+        ("p", "punctuation", PC | PD | PS | PE | PI | PF | PO),
+        ("p", "punct", PC | PD | PS | PE | PI | PF | PO),
+        ("pc", "connectorpunctuation", PC),
+        ("pd", "dashpunctuation", PD),
+        ("ps", "openpunctuation", PS),
+        ("pe", "closepunctuation", PE),
+        ("pi", "initialpunctuation", PI),
+        ("pf", "finalpunctuation", PF),
+        ("po", "otherpunctuation", PO),
+        ("s", "symbol", SM | SC | SK | SO),
+        ("sm", "mathsymbol", SM),
+        ("sc", "currencysymbol", SC),
+        ("sk", "modifiersymbol", SK),
+        ("so", "othersymbol", SO),
+        ("z", "separator", ZS | ZL | ZP),
+        ("zs", "spaceseparator", ZS),
+        ("zl", "lineseparator", ZL),
+        ("zp", "paragraphseparator", ZP),
+        ("c", "other", CC | CF | CS | CO | CN),
+        ("cc", "control", CC),
+        ("cc", "cntrl", CC),
+        ("cf", "format", CF),
+        ("cs", "surrogate", CS),
+        ("co", "privateuse", CO),
+        ("cn", "unassigned", CN),
+    ]
+};
+
+/// A Unicode property that a character may have
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Property {
+    /// Any of a set of general categories, by their bits
+    Categories(u32),
+    Alphabetic,
+    Lowercase,
+    Uppercase,
+    WhiteSpace,
+    Word,
+    ExtendedPictographic,
+    Ascii,
+    Any,
+}
+
+impl Property {
+    /// Looks up a property by name, ignoring case, spaces, `_` and `-`
+    fn from_name(name: &str) -> Option<Self> {
+        let name: String = name
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_' | '-'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let name = name
+            .strip_prefix("gc=")
+            .or_else(|| name.strip_prefix("generalcategory="))
+            .unwrap_or(&name);
+        let prop = match name {
+            "alphabetic" | "alpha" => Property::Alphabetic,
+            "lowercase" | "lower" => Property::Lowercase,
+            "uppercase" | "upper" => Property::Uppercase,
+            "whitespace" | "wspace" | "space" => Property::WhiteSpace,
+            "extendedpictographic" | "extpict" => Property::ExtendedPictographic,
+            "ascii" => Property::Ascii,
+            "any" => Property::Any,
+            "assigned" => Property::Categories(!gc_bit(GeneralCategory::Unassigned)),
+            _ => {
+                let &(_, _, bits) = CATEGORY_NAMES
+                    .iter()
+                    .find(|&&(short, long, _)| name == short || name == long)?;
+                Property::Categories(bits)
+            }
+        };
+        Some(prop)
+    }
+
+    fn matches(self, c: char) -> bool {
+        match self {
+            Property::Categories(bits) => bits & gc_bit(general_category(c)) != 0,
+            Property::Alphabetic => in_table(&tables::ALPHABETIC, c),
+            Property::Lowercase => in_table(&tables::LOWERCASE, c),
+            Property::Uppercase => in_table(&tables::UPPERCASE, c),
+            Property::WhiteSpace => in_table(&tables::WHITE_SPACE, c),
+            Property::Word => is_word_char(c),
+            Property::ExtendedPictographic => crate::segment::is_extended_pictographic(c),
+            Property::Ascii => c.is_ascii(),
+            Property::Any => true,
+        }
+    }
+}
+
+/// An item of a character class
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ClassItem {
+    Range(char, char),
+    Property(Property, bool),
+}
+
+impl ClassItem {
+    fn matches(self, c: char) -> bool {
+        match self {
+            ClassItem::Range(lo, hi) => lo <= c && c <= hi,
+            ClassItem::Property(prop, negated) => prop.matches(c) != negated,
+        }
+    }
+}
+
+/// A set of characters
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Class {
+    items: Vec<ClassItem>,
+    negated: bool,
+    case_insensitive: bool,
+}
+
+impl Class {
+    /// Adds to the ranges of the class every character with the same simple case folding as a character in them
+    fn close_over_case(&mut self) {
+        let in_ranges = |c: char| {
+            self.items
+                .iter()
+                .any(|item| matches!(item, ClassItem::Range(..)) && item.matches(c))
+        };
+        let mut folded: Vec<char> = simple_foldings()
+            .iter()
+            .filter(|&&(c, folded)| in_ranges(c) || in_ranges(folded))
+            .map(|&(_, folded)| folded)
+            .collect();
+        folded.sort_unstable();
+        folded.dedup();
+        let mut extra: Vec<char> = simple_foldings()
+            .iter()
+            .filter(|(_, c)| folded.binary_search(c).is_ok())
+            .map(|&(c, _)| c)
+            .chain(folded.iter().copied())
+            .filter(|&c| !in_ranges(c))
+            .collect();
+        extra.sort_unstable();
+        extra.dedup();
+        self.items
+            .extend(extra.into_iter().map(|c| ClassItem::Range(c, c)));
+    }
+
+    fn matches(&self, c: char) -> bool {
+        let contains = |c: char| self.items.iter().any(|item| item.matches(c));
+        // The ranges are closed over case folding when the class is built, but properties cannot be
+        let has_property = |c: char| {
+            self.items
+                .iter()
+                .any(|item| matches!(item, ClassItem::Property(..)) && item.matches(c))
+        };
+        let found = contains(c)
+            || (self.case_insensitive && {
+                let [lower, upper] = single_char_mappings(c);
+                [Some(simple_fold(c)), lower, upper]
+                    .into_iter()
+                    .flatten()
+                    .any(has_property)
+            });
+        found != self.negated
+    }
+}
+
+/// An assertion about the position between two characters
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Look {
+    Start,
+    End,
+    StartLine,
+    EndLine,
+    WordBoundary,
+    NotWordBoundary,
+}
+
+impl Look {
+    /// Returns `true` if the assertion holds between `prev` and `next`, which are `None` at the ends of the haystack
+    fn holds(self, prev: Option<char>, next: Option<char>) -> bool {
+        let is_word = |c: Option<char>| c.is_some_and(is_word_char);
+        match self {
+            Look::Start => prev.is_none(),
+            Look::End => next.is_none(),
+            Look::StartLine => prev.is_none_or(|c| c == '\n'),
+            Look::EndLine => next.is_none_or(|c| c == '\n'),
+            Look::WordBoundary => is_word(prev) != is_word(next),
+            Look::NotWordBoundary => is_word(prev) == is_word(next),
+        }
+    }
+}
+
+/// A parsed regular expression
+#[derive(Clone, Debug)]
+enum Node {
+    Empty,
+    Char(char),
+    /// A character that matches any character with the same simple case folding
+    Folded(char),
+    Class(Class),
+    Any {
+        dot_all: bool,
+    },
+    Look(Look),
+    Group(Box<Node>, Option<usize>),
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct Flags {
+    case_insensitive: bool,
+    multi_line: bool,
+    dot_all: bool,
+}
+
+struct Parser<'p> {
+    pattern: &'p str,
+    pos: usize,
+    flags: Flags,
+    /// The number of capture groups so far, including the implicit group of the whole match
+    captures: usize,
+    names: Vec<(String, usize)>,
+}
+
+impl<'p> Parser<'p> {
+    fn error(&self, kind: ErrorKind, offset: usize) -> Error {
+        Error { kind, offset }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.pos..].chars().next()
+    }
+
+    fn peek2(&self) -> Option<char> {
+        self.pattern[self.pos..].chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.pos += c.len_utf8();
+        }
+        eaten
+    }
+
+    fn expect_bump(&mut self) -> Result<char, Error> {
+        self.bump()
+            .ok_or_else(|| self.error(ErrorKind::UnexpectedEnd, self.pos))
+    }
+
+    fn parse(&mut self) -> Result<Node, Error> {
+        let node = self.parse_alt()?;
+        match self.peek() {
+            None => Ok(node),
+            Some(_) => Err(self.error(ErrorKind::UnopenedGroup, self.pos)),
+        }
+    }
+
+    fn parse_alt(&mut self) -> Result<Node, Error> {
+        let mut alts = vec![self.parse_concat()?];
+        while self.eat('|') {
+            alts.push(self.parse_concat()?);
+        }
+        Ok(if alts.len() == 1 {
+            alts.pop().unwrap()
+        } else {
+            Node::Alt(alts)
+        })
+    }
+
+    fn parse_concat(&mut self) -> Result<Node, Error> {
+        let mut items = Vec::new();
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            let atom = self.parse_atom()?;
+            items.push(self.parse_repeats(atom)?);
+        }
+        Ok(Node::Concat(items))
+    }
+
+    fn parse_repeats(&mut self, mut node: Node) -> Result<Node, Error> {
+        loop {
+            let start = self.pos;
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    self.bump();
+                    self.parse_counted(start)?
+                }
+                _ => return Ok(node),
+            };
+            if self.pos == start {
+                self.bump();
+            }
+            let greedy = !self.eat('?');
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+                greedy,
+            };
+        }
+    }
+
+    /// Parses the rest of a counted repetition, which began at `start`
+    fn parse_counted(&mut self, start: usize) -> Result<(u32, Option<u32>), Error> {
+        let invalid = |this: &Self| this.error(ErrorKind::InvalidRepetition, start);
+        let min = self.parse_count()?.ok_or_else(|| invalid(self))?;
+        let max = if self.eat(',') {
+            self.parse_count()?
+        } else {
+            Some(min)
+        };
+        match self.bump() {
+            Some('}') => {}
+            None => return Err(self.error(ErrorKind::UnexpectedEnd, self.pos)),
+            Some(_) => return Err(invalid(self)),
+        }
+        if min > MAX_REPEAT || max.is_some_and(|max| max < min || max > MAX_REPEAT) {
+            return Err(invalid(self));
+        }
+        Ok((min, max))
+    }
+
+    fn parse_count(&mut self) -> Result<Option<u32>, Error> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        // Counts that overflow are larger than `MAX_REPEAT`, and so rejected anyway
+        Ok(Some(
+            self.pattern[start..self.pos].parse().unwrap_or(u32::MAX),
+        ))
+    }
+
+    fn literal(&self, c: char) -> Node {
+        if self.flags.case_insensitive {
+            Node::Folded(simple_fold(c))
+        } else {
+            Node::Char(c)
+        }
+    }
+
+    fn class(&self, items: Vec<ClassItem>, negated: bool) -> Node {
+        let mut class = Class {
+            items,
+            negated,
+            case_insensitive: self.flags.case_insensitive,
+        };
+        if class.case_insensitive {
+            class.close_over_case();
+        }
+        Node::Class(class)
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, Error> {
+        let start = self.pos;
+        match self.expect_bump()? {
+            '(' => self.parse_group(start),
+            '[' => self.parse_class(start),
+            '.' => Ok(Node::Any {
+                dot_all: self.flags.dot_all,
+            }),
+            '^' if self.flags.multi_line => Ok(Node::Look(Look::StartLine)),
+            '^' => Ok(Node::Look(Look::Start)),
+            '$' if self.flags.multi_line => Ok(Node::Look(Look::EndLine)),
+            '$' => Ok(Node::Look(Look::End)),
+            '*' | '+' | '?' | '{' => Err(self.error(ErrorKind::NothingToRepeat, start)),
+            '\\' => {
+                let c = self.expect_bump()?;
+                match c {
+                    'A' => Ok(Node::Look(Look::Start)),
+                    'z' => Ok(Node::Look(Look::End)),
+                    'b' => Ok(Node::Look(Look::WordBoundary)),
+                    'B' => Ok(Node::Look(Look::NotWordBoundary)),
+                    _ => match self.parse_class_escape(c, start)? {
+                        Some(item) => Ok(self.class(vec![item], false)),
+                        None => {
+                            let c = self.parse_char_escape(c, start)?;
+                            Ok(self.literal(c))
+                        }
+                    },
+                }
+            }
+            c => Ok(self.literal(c)),
+        }
+    }
+
+    /// Parses an escape that matches a set of characters, after the `\` at `start` and `c`
+    fn parse_class_escape(&mut self, c: char, start: usize) -> Result<Option<ClassItem>, Error> {
+        let (prop, negated) = match c {
+            'd' | 'D' => (
+                Property::Categories(gc_bit(GeneralCategory::DecimalNumber)),
+                c == 'D',
+            ),
+            's' | 'S' => (Property::WhiteSpace, c == 'S'),
+            'w' | 'W' => (Property::Word, c == 'W'),
+            'p' | 'P' => {
+                let name = if self.eat('{') {
+                    let name_start = self.pos;
+                    while self.expect_bump()? != '}' {}
+                    &self.pattern[name_start..self.pos - 1]
+                } else {
+                    let name_start = self.pos;
+                    self.expect_bump()?;
+                    &self.pattern[name_start..self.pos]
+                };
+                let prop = Property::from_name(name)
+                    .ok_or_else(|| self.error(ErrorKind::UnknownProperty, start))?;
+                (prop, c == 'P')
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(ClassItem::Property(prop, negated)))
+    }
+
+    /// Parses an escape that matches a single character, after the `\` at `start` and `c`
+    fn parse_char_escape(&mut self, c: char, start: usize) -> Result<char, Error> {
+        match c {
+            'n' => Ok('\n'),
+            'r' => Ok('\r'),
+            't' => Ok('\t'),
+            'f' => Ok('\x0C'),
+            'v' => Ok('\x0B'),
+            '0' => Ok('\0'),
+            'x' => self.parse_hex(start, 2),
+            'u' => self.parse_hex(start, 4),
+            c if c.is_ascii_punctuation() => Ok(c),
+            _ => Err(self.error(ErrorKind::InvalidEscape, start)),
+        }
+    }
+
+    /// Parses a code point, as `digits` hexadecimal digits or any number of them in braces
+    fn parse_hex(&mut self, start: usize, digits: usize) -> Result<char, Error> {
+        let hex_start = self.pos;
+        let hex = if self.eat('{') {
+            while self.expect_bump()? != '}' {}
+            &self.pattern[hex_start + 1..self.pos - 1]
+        } else {
+            for _ in 0..digits {
+                self.expect_bump()?;
+            }
+            &self.pattern[hex_start..self.pos]
+        };
+        if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(self.error(ErrorKind::InvalidEscape, start));
+        }
+        u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error(ErrorKind::InvalidCodePoint, start))
+    }
+
+    /// Parses the rest of a class, which began at `start`
+    fn parse_class(&mut self, start: usize) -> Result<Node, Error> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        let mut first = true;
+        loop {
+            let item_start = self.pos;
+            let c = self
+                .bump()
+                .ok_or_else(|| self.error(ErrorKind::UnclosedClass, start))?;
+            let lo = match c {
+                ']' if !first => break,
+                '\\' => {
+                    let c = self.expect_bump()?;
+                    if let Some(item) = self.parse_class_escape(c, item_start)? {
+                        items.push(item);
+                        first = false;
+                        continue;
+                    }
+                    self.parse_char_escape(c, item_start)?
+                }
+                c => c,
+            };
+            first = false;
+            if self.peek() == Some('-') && !matches!(self.peek2(), None | Some(']')) {
+                self.bump();
+                let hi = match self.expect_bump()? {
+                    '\\' => {
+                        let escape_start = self.pos - 1;
+                        let c = self.expect_bump()?;
+                        if self.parse_class_escape(c, escape_start)?.is_some() {
+                            return Err(self.error(ErrorKind::InvalidClassRange, item_start));
+                        }
+                        self.parse_char_escape(c, escape_start)?
+                    }
+                    c => c,
+                };
+                if hi < lo {
+                    return Err(self.error(ErrorKind::InvalidClassRange, item_start));
+                }
+                items.push(ClassItem::Range(lo, hi));
+            } else {
+                items.push(ClassItem::Range(lo, lo));
+            }
+        }
+        Ok(self.class(items, negated))
+    }
+
+    /// Parses the rest of a group, which began at `start`
+    fn parse_group(&mut self, start: usize) -> Result<Node, Error> {
+        let saved = self.flags;
+        let capture = if self.eat('?') {
+            if self.eat(':') {
+                None
+            } else if self.eat('P') || self.peek() == Some('<') {
+                if !self.eat('<') {
+                    return Err(self.error(ErrorKind::InvalidGroupName, self.pos));
+                }
+                let name_start = self.pos;
+                while self.expect_bump()? != '>' {}
+                let name = &self.pattern[name_start..self.pos - 1];
+                let valid = name.chars().enumerate().all(|(i, c)| {
+                    c == '_' || c.is_ascii_alphabetic() || (i != 0 && c.is_ascii_digit())
+                });
+                if name.is_empty() || !valid {
+                    return Err(self.error(ErrorKind::InvalidGroupName, name_start));
+                }
+                if self.names.iter().any(|(n, _)| n == name) {
+                    return Err(self.error(ErrorKind::DuplicateGroupName, name_start));
+                }
+                self.names.push((name.to_owned(), self.captures));
+                self.captures += 1;
+                Some(self.captures - 1)
+            } else {
+                let mut negate = false;
+                loop {
+                    let flag_start = self.pos;
+                    match self.expect_bump()? {
+                        'i' => self.flags.case_insensitive = !negate,
+                        'm' => self.flags.multi_line = !negate,
+                        's' => self.flags.dot_all = !negate,
+                        '-' if !negate => negate = true,
+                        ':' => break,
+                        // The flags apply until the end of the enclosing group
+                        ')' => return Ok(Node::Empty),
+                        _ => return Err(self.error(ErrorKind::InvalidFlag, flag_start)),
+                    }
+                }
+                None
+            }
+        } else {
+            self.captures += 1;
+            Some(self.captures - 1)
+        };
+        let body = self.parse_alt()?;
+        if !self.eat(')') {
+            return Err(self.error(ErrorKind::UnclosedGroup, start));
+        }
+        self.flags = saved;
+        Ok(Node::Group(Box::new(body), capture))
+    }
+}
+
+/// An instruction of the Pike VM
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Inst {
+    Char(char),
+    /// Matches a character whose simple case folding is the given character
+    Folded(char),
+    Class(usize),
+    Any,
+    AnyNotNewline,
+    Look(Look),
+    /// Records the current position in a capture slot
+    Save(usize),
+    /// Continues at both targets, preferring the first
+    Split(usize, usize),
+    Jmp(usize),
+    Match,
+}
+
+struct Compiler {
+    insts: Vec<Inst>,
+    classes: Vec<Class>,
+}
+
+impl Compiler {
+    fn push(&mut self, inst: Inst) -> usize {
+        self.insts.push(inst);
+        self.insts.len() - 1
+    }
+
+    fn split(greedy: bool, body: usize, skip: usize) -> Inst {
+        if greedy {
+            Inst::Split(body, skip)
+        } else {
+            Inst::Split(skip, body)
+        }
+    }
+
+    fn compile(&mut self, node: &Node) -> Result<(), Error> {
+        if self.insts.len() > MAX_INSTS {
+            return Err(Error {
+                kind: ErrorKind::TooBig,
+                offset: 0,
+            });
+        }
+        match node {
+            Node::Empty => {}
+            &Node::Char(c) => {
+                self.push(Inst::Char(c));
+            }
+            &Node::Folded(c) => {
+                self.push(Inst::Folded(c));
+            }
+            Node::Class(class) => {
+                self.classes.push(class.clone());
+                self.push(Inst::Class(self.classes.len() - 1));
+            }
+            &Node::Any { dot_all } => {
+                self.push(if dot_all {
+                    Inst::Any
+                } else {
+                    Inst::AnyNotNewline
+                });
+            }
+            &Node::Look(look) => {
+                self.push(Inst::Look(look));
+            }
+            Node::Group(body, None) => self.compile(body)?,
+            &Node::Group(ref body, Some(idx)) => {
+                self.push(Inst::Save(2 * idx));
+                self.compile(body)?;
+                self.push(Inst::Save(2 * idx + 1));
+            }
+            Node::Concat(items) => {
+                for item in items {
+                    self.compile(item)?;
+                }
+            }
+            Node::Alt(alts) => {
+                let mut jumps = Vec::new();
+                let (last, rest) = alts.split_last().unwrap();
+                for alt in rest {
+                    let split = self.push(Inst::Match);
+                    self.compile(alt)?;
+                    jumps.push(self.push(Inst::Match));
+                    self.insts[split] = Inst::Split(split + 1, self.insts.len());
+                }
+                self.compile(last)?;
+                let end = self.insts.len();
+                for jump in jumps {
+                    self.insts[jump] = Inst::Jmp(end);
+                }
+            }
+            &Node::Repeat {
+                ref node,
+                min,
+                max,
+                greedy,
+            } => {
+                match max {
+                    None if min == 0 => {
+                        // `e*` is compiled as `(e+)?`, so that an iteration of `e` matching the empty
+                        //  string ends the loop rather than the thread, as its way back is already taken
+                        let skip = self.push(Inst::Match);
+                        self.compile(node)?;
+                        self.push(Self::split(greedy, skip + 1, self.insts.len() + 1));
+                        self.insts[skip] = Self::split(greedy, skip + 1, self.insts.len());
+                    }
+                    None => {
+                        // `e{n,}` is compiled as `e{n-1}e+`, rather than `e{n}e*`, whose extra way into the loop
+                        //  would let an empty iteration of `e` leave it before the other alternatives of `e` are tried
+                        for _ in 1..min {
+                            self.compile(node)?;
+                        }
+                        let body = self.insts.len();
+                        self.compile(node)?;
+                        self.push(Self::split(greedy, body, self.insts.len() + 1));
+                    }
+                    Some(max) => {
+                        for _ in 0..min {
+                            self.compile(node)?;
+                        }
+                        let mut splits = Vec::new();
+                        for _ in min..max {
+                            splits.push(self.push(Inst::Match));
+                            self.compile(node)?;
+                        }
+                        let end = self.insts.len();
+                        for split in splits {
+                            self.insts[split] = Self::split(greedy, split + 1, end);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A set of threads of the Pike VM, one per instruction, with their capture slots
+struct Threads {
+    sparse: Vec<usize>,
+    dense: Vec<usize>,
+    slots: Vec<Option<usize>>,
+    slot_count: usize,
+}
+
+impl Threads {
+    fn new(inst_count: usize, slot_count: usize) -> Self {
+        Threads {
+            sparse: vec![0; inst_count],
+            dense: Vec::with_capacity(inst_count),
+            slots: vec![None; inst_count * slot_count],
+            slot_count,
+        }
+    }
+
+    /// Adds a thread at `pc`, returning `false` if there already was one
+    fn insert(&mut self, pc: usize) -> bool {
+        let i = self.sparse[pc];
+        if i < self.dense.len() && self.dense[i] == pc {
+            return false;
+        }
+        self.sparse[pc] = self.dense.len();
+        self.dense.push(pc);
+        true
+    }
+
+    fn slots(&self, pc: usize) -> &[Option<usize>] {
+        &self.slots[pc * self.slot_count..][..self.slot_count]
+    }
+
+    fn slots_mut(&mut self, pc: usize) -> &mut [Option<usize>] {
+        &mut self.slots[pc * self.slot_count..][..self.slot_count]
+    }
+}
+
+/// A step of computing the epsilon closure of a thread
+enum Frame {
+    Explore(usize),
+    /// Restores a capture slot once the threads that follow a [`Inst::Save`] have been explored
+    Restore(usize, Option<usize>),
+}
+
+/// The memory used by a search, which a [`RegexSearcher`] reuses for its successive searches
+struct Cache {
+    clist: Threads,
+    nlist: Threads,
+    stack: Vec<Frame>,
+    scratch: Vec<Option<usize>>,
+}
+
+impl Cache {
+    fn new(regex: &Regex) -> Self {
+        let slot_count = 2 * regex.captures;
+        Cache {
+            clist: Threads::new(regex.insts.len(), slot_count),
+            nlist: Threads::new(regex.insts.len(), slot_count),
+            stack: Vec::new(),
+            scratch: vec![None; slot_count],
+        }
+    }
+}
+
+/// Decodes the character at `pos` of `chars`, returning it and its length, or `None` at the end of `chars`
+///
+/// # Safety
+/// `chars[pos..]` shall be valid
+unsafe fn decode_at<Traits: IntoChars>(
+    chars: &[Traits::Char],
+    pos: usize,
+) -> Option<(char, usize)> {
+    let rest = &chars[pos..];
+    if rest.is_empty() {
+        return None;
+    }
+    let (c, tail) = Traits::decode_buf_unchecked(rest);
+    Some((c, rest.len() - tail.len()))
+}
+
+/// A compiled regular expression
+///
+/// A [`Regex`] searches a [`BasicStr`] in any encoding that implements [`IntoChars`],
+///  and implements [`Pattern`] so that it can be used with methods like [`BasicStr::find`] and [`BasicStr::split`].
+/// See the [module documentation](self) for the syntax.
+#[derive(Clone, Debug)]
+pub struct Regex {
+    pattern: String,
+    insts: Vec<Inst>,
+    classes: Vec<Class>,
+    captures: usize,
+    names: Vec<(String, usize)>,
+}
+
+impl Regex {
+    /// Compiles a regular expression
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        let mut parser = Parser {
+            pattern,
+            pos: 0,
+            flags: Flags::default(),
+            captures: 1,
+            names: Vec::new(),
+        };
+        let node = parser.parse()?;
+        let mut compiler = Compiler {
+            insts: Vec::new(),
+            classes: Vec::new(),
+        };
+        compiler.push(Inst::Save(0));
+        compiler.compile(&node)?;
+        compiler.push(Inst::Save(1));
+        compiler.push(Inst::Match);
+        if compiler.insts.len() > MAX_INSTS {
+            return Err(Error {
+                kind: ErrorKind::TooBig,
+                offset: 0,
+            });
+        }
+        Ok(Regex {
+            pattern: pattern.to_owned(),
+            insts: compiler.insts,
+            classes: compiler.classes,
+            captures: parser.captures,
+            names: parser.names,
+        })
+    }
+
+    /// Returns the expression that the [`Regex`] was compiled from
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the number of capture groups, including the group of the whole match
+    pub fn captures_len(&self) -> usize {
+        self.captures
+    }
+
+    /// Returns the index of the capture group named `name`
+    pub fn capture_index(&self, name: &str) -> Option<usize> {
+        self.names
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, idx)| idx)
+    }
+
+    /// Returns `true` if the [`Regex`] matches anywhere in `haystack`
+    pub fn is_match<Traits: IntoChars>(&self, haystack: &BasicStr<Traits::Char, Traits>) -> bool {
+        // SAFETY: A `BasicStr` is valid
+        unsafe { self.search::<Traits>(&mut Cache::new(self), haystack.as_chars(), 0, None, false) }
+            .is_some()
+    }
+
+    /// Returns the offset and contents of the leftmost-first match in `haystack`
+    pub fn find<'a, Traits: IntoChars>(
+        &self,
+        haystack: &'a BasicStr<Traits::Char, Traits>,
+    ) -> Option<(usize, &'a BasicStr<Traits::Char, Traits>)> {
+        // SAFETY: A `BasicStr` is valid
+        let slots = unsafe {
+            self.search::<Traits>(&mut Cache::new(self), haystack.as_chars(), 0, None, false)
+        }?;
+        let (start, end) = (slots[0]?, slots[1]?);
+        // SAFETY: The VM only records positions between characters
+        let m = unsafe { BasicStr::from_chars_unchecked(&haystack.as_chars()[start..end]) };
+        Some((start, m))
+    }
+
+    /// Returns the capture groups of the leftmost-first match in `haystack`
+    pub fn captures<'a, Traits: IntoChars>(
+        &'a self,
+        haystack: &'a BasicStr<Traits::Char, Traits>,
+    ) -> Option<Captures<'a, Traits::Char, Traits>> {
+        // SAFETY: A `BasicStr` is valid
+        let slots = unsafe {
+            self.search::<Traits>(&mut Cache::new(self), haystack.as_chars(), 0, None, false)
+        }?;
+        Some(Captures {
+            haystack,
+            slots,
+            names: &self.names,
+        })
+    }
+
+    /// Returns an iterator over the capture groups of the successive non-overlapping matches in `haystack`
+    pub fn captures_iter<'a, Traits: IntoChars>(
+        &'a self,
+        haystack: &'a BasicStr<Traits::Char, Traits>,
+    ) -> CapturesIter<'a, Traits::Char, Traits> {
+        CapturesIter(RegexSearcher::new(haystack, self))
+    }
+
+    /// Searches `chars` from `start` for the leftmost-first match, returning its capture slots.
+    ///
+    /// `prev` is the character before `start`, or `None` if `start` is the start of the haystack.
+    /// If `anchored` is `true`, only a match that starts at `start` is found.
+    ///
+    /// # Safety
+    /// `chars[start..]` shall be valid
+    unsafe fn search<Traits: IntoChars>(
+        &self,
+        cache: &mut Cache,
+        chars: &[Traits::Char],
+        start: usize,
+        mut prev: Option<char>,
+        anchored: bool,
+    ) -> Option<Vec<Option<usize>>> {
+        let Cache {
+            clist,
+            nlist,
+            stack,
+            scratch,
+        } = cache;
+        clist.dense.clear();
+        nlist.dense.clear();
+        let mut matched = None;
+        let mut pos = start;
+        let mut cur = decode_at::<Traits>(chars, pos);
+        loop {
+            if matched.is_none() && (!anchored || pos == start) {
+                scratch.fill(None);
+                let next = cur.map(|(c, _)| c);
+                self.add(clist, stack, 0, pos, prev, next, scratch);
+            }
+            if clist.dense.is_empty() {
+                break;
+            }
+            let Some((c, len)) = cur else {
+                // Only a thread at `Match` can still succeed
+                matched = clist
+                    .dense
+                    .iter()
+                    .find(|&&pc| self.insts[pc] == Inst::Match)
+                    .map(|&pc| clist.slots(pc).to_vec())
+                    .or(matched);
+                break;
+            };
+            let next = decode_at::<Traits>(chars, pos + len);
+            for i in 0..clist.dense.len() {
+                let pc = clist.dense[i];
+                let consumed = match self.insts[pc] {
+                    Inst::Match => {
+                        // Threads after this one have lower priority
+                        matched = Some(clist.slots(pc).to_vec());
+                        break;
+                    }
+                    Inst::Char(x) => c == x,
+                    Inst::Folded(x) => simple_fold(c) == x,
+                    Inst::Class(idx) => self.classes[idx].matches(c),
+                    Inst::Any => true,
+                    Inst::AnyNotNewline => c != '\n',
+                    Inst::Look(_) | Inst::Save(_) | Inst::Split(..) | Inst::Jmp(_) => false,
+                };
+                if consumed {
+                    scratch.copy_from_slice(clist.slots(pc));
+                    let after = next.map(|(c, _)| c);
+                    self.add(nlist, stack, pc + 1, pos + len, Some(c), after, scratch);
+                }
+            }
+            pos += len;
+            prev = Some(c);
+            cur = next;
+            core::mem::swap(clist, nlist);
+            nlist.dense.clear();
+        }
+        matched
+    }
+
+    /// Adds the threads reachable from `pc` without consuming a character to `list`, in order of priority
+    #[allow(clippy::too_many_arguments)]
+    fn add(
+        &self,
+        list: &mut Threads,
+        stack: &mut Vec<Frame>,
+        pc: usize,
+        at: usize,
+        prev: Option<char>,
+        next: Option<char>,
+        slots: &mut [Option<usize>],
+    ) {
+        stack.push(Frame::Explore(pc));
+        while let Some(frame) = stack.pop() {
+            let pc = match frame {
+                Frame::Restore(slot, value) => {
+                    slots[slot] = value;
+                    continue;
+                }
+                Frame::Explore(pc) => pc,
+            };
+            if !list.insert(pc) {
+                continue;
+            }
+            match self.insts[pc] {
+                Inst::Jmp(to) => stack.push(Frame::Explore(to)),
+                Inst::Split(first, second) => {
+                    stack.push(Frame::Explore(second));
+                    stack.push(Frame::Explore(first));
+                }
+                Inst::Save(slot) => {
+                    stack.push(Frame::Restore(slot, slots[slot]));
+                    slots[slot] = Some(at);
+                    stack.push(Frame::Explore(pc + 1));
+                }
+                Inst::Look(look) => {
+                    if look.holds(prev, next) {
+                        stack.push(Frame::Explore(pc + 1));
+                    }
+                }
+                _ => list.slots_mut(pc).copy_from_slice(slots),
+            }
+        }
+    }
+}
+
+/// The capture groups of a match of a [`Regex`]
+#[derive(Clone)]
+pub struct Captures<'a, CharT, Traits> {
+    haystack: &'a BasicStr<CharT, Traits>,
+    slots: Vec<Option<usize>>,
+    names: &'a [(String, usize)],
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<'a, CharT, Traits> Captures<'a, CharT, Traits> {
+    /// Returns the number of capture groups, including the group of the whole match
+    pub fn len(&self) -> usize {
+        self.slots.len() / 2
+    }
+
+    /// Returns the range of the haystack matched by group `i`, or `None` if the group did not participate in the match
+    pub fn get_range(&self, i: usize) -> Option<Range<usize>> {
+        let start = (*self.slots.get(2 * i)?)?;
+        let end = self.slots[2 * i + 1]?;
+        Some(start..end)
+    }
+
+    /// Returns the part of the haystack matched by group `i`, or `None` if the group did not participate in the match.
+    ///
+    /// Group 0 is the whole match.
+    pub fn get(&self, i: usize) -> Option<&'a BasicStr<CharT, Traits>> {
+        let range = self.get_range(i)?;
+        // SAFETY: The VM only records positions between characters
+        Some(unsafe { BasicStr::from_chars_unchecked(&self.haystack.as_chars()[range]) })
+    }
+
+    /// Returns the part of the haystack matched by the group named `name`
+    pub fn name(&self, name: &str) -> Option<&'a BasicStr<CharT, Traits>> {
+        let &(_, i) = self.names.iter().find(|(n, _)| n == name)?;
+        self.get(i)
+    }
+}
+
+/// The [`Searcher`] for a [`Regex`], which finds its successive non-overlapping matches
+///
+/// An empty match is never found immediately after another match.
+pub struct RegexSearcher<'a, R, CharT, Traits> {
+    regex: R,
+    haystack: &'a BasicStr<CharT, Traits>,
+    pos: usize,
+    /// The character before `pos`
+    prev: Option<char>,
+    last_end: Option<usize>,
+    finished: bool,
+    cache: Cache,
+}
+
+impl<'a, R: Borrow<Regex>, Traits: IntoChars> RegexSearcher<'a, R, Traits::Char, Traits> {
+    /// Creates a searcher for `regex` over `haystack`
+    pub fn new(haystack: &'a BasicStr<Traits::Char, Traits>, regex: R) -> Self {
+        RegexSearcher {
+            cache: Cache::new(regex.borrow()),
+            regex,
+            haystack,
+            pos: 0,
+            prev: None,
+            last_end: None,
+            finished: false,
+        }
+    }
+
+    fn next_slots(&mut self) -> Option<Vec<Option<usize>>> {
+        let chars = self.haystack.as_chars();
+        while !self.finished {
+            // SAFETY: `pos` is always a character boundary of `haystack`
+            let Some(slots) = (unsafe {
+                self.regex.borrow().search::<Traits>(
+                    &mut self.cache,
+                    chars,
+                    self.pos,
+                    self.prev,
+                    false,
+                )
+            }) else {
+                self.finished = true;
+                break;
+            };
+            let (start, end) = (slots[0].unwrap(), slots[1].unwrap());
+            if end > self.pos {
+                // SAFETY: The match ends at a character boundary
+                let consumed =
+                    unsafe { BasicStr::<_, Traits>::from_chars_unchecked(&chars[self.pos..end]) };
+                self.prev = consumed.unicode_iter().last();
+                self.pos = end;
+            }
+            if start == end {
+                // Step over the next character, so that the same empty match is not found again
+                // SAFETY: `end` is a character boundary
+                match unsafe { decode_at::<Traits>(chars, end) } {
+                    Some((c, len)) => {
+                        self.prev = Some(c);
+                        self.pos += len;
+                    }
+                    None => self.finished = true,
+                }
+                if self.last_end == Some(end) {
+                    continue;
+                }
+            }
+            self.last_end = Some(end);
+            return Some(slots);
+        }
+        None
+    }
+}
+
+unsafe impl<'a, R: Borrow<Regex>, Traits: IntoChars> Searcher<'a, Traits::Char>
+    for RegexSearcher<'a, R, Traits::Char, Traits>
+{
+    fn haystack(&self) -> &'a [Traits::Char] {
+        self.haystack.as_chars()
+    }
+
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let slots = self.next_slots()?;
+        Some((slots[0]?, slots[1]?))
+    }
+}
+
+/// An iterator over the capture groups of the matches of a [`Regex`], created by [`Regex::captures_iter`]
+pub struct CapturesIter<'a, CharT, Traits>(RegexSearcher<'a, &'a Regex, CharT, Traits>);
+
+impl<'a, Traits: IntoChars> Iterator for CapturesIter<'a, Traits::Char, Traits> {
+    type Item = Captures<'a, Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slots = self.0.next_slots()?;
+        Some(Captures {
+            haystack: self.0.haystack,
+            slots,
+            names: &self.0.regex.names,
+        })
+    }
+}
+
+unsafe impl<Traits: IntoChars> Pattern<Traits::Char, Traits> for Regex {
+    type Searcher<'a>
+        = RegexSearcher<'a, Self, Traits::Char, Traits>
+    where
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<Traits::Char, Traits>) -> Self::Searcher<'a> {
+        RegexSearcher::new(haystack, self)
+    }
+
+    fn first_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        Traits::validate_range(slice).ok()?;
+        unsafe { Pattern::<Traits::Char, Traits>::first_match_unchecked(self, slice) }
+    }
+
+    // The characters around `slice` are unknown, so the anchors match at its ends
+    unsafe fn first_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        let slots = self.search::<Traits>(&mut Cache::new(self), slice, 0, None, false)?;
+        Some(&slice[slots[0]?..slots[1]?])
+    }
+
+    unsafe fn prefix_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        let slots = self.search::<Traits>(&mut Cache::new(self), slice, 0, None, true)?;
+        Some(&slice[slots[0]?..slots[1]?])
+    }
+}
+
+unsafe impl<Traits: IntoChars> Pattern<Traits::Char, Traits> for &Regex {
+    type Searcher<'a>
+        = RegexSearcher<'a, Self, Traits::Char, Traits>
+    where
+        Traits: 'a;
+
+    fn into_searcher<'a>(self, haystack: &'a BasicStr<Traits::Char, Traits>) -> Self::Searcher<'a> {
+        RegexSearcher::new(haystack, self)
+    }
+
+    fn first_match<'a>(&self, slice: &'a [Traits::Char]) -> Option<&'a [Traits::Char]> {
+        Pattern::<Traits::Char, Traits>::first_match(*self, slice)
+    }
+
+    unsafe fn first_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        Pattern::<Traits::Char, Traits>::first_match_unchecked(*self, slice)
+    }
+
+    unsafe fn prefix_match_unchecked<'a>(
+        &self,
+        slice: &'a [Traits::Char],
+    ) -> Option<&'a [Traits::Char]> {
+        Pattern::<Traits::Char, Traits>::prefix_match_unchecked(*self, slice)
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::{general_category, ErrorKind, GeneralCategory, Regex};
+    use crate::str::{Str, U16Str};
+    use core::ops::Range;
+
+    fn find<'a>(re: &str, haystack: &'a str) -> Option<&'a str> {
+        let re = Regex::new(re).unwrap();
+        let (start, m) = re.find(Str::from_str(haystack))?;
+        assert_eq!(&haystack[start..start + m.len()], m.as_str());
+        Some(&haystack[start..start + m.len()])
+    }
+
+    #[test]
+    fn test_general_category() {
+        assert_eq!(general_category('A'), GeneralCategory::UppercaseLetter);
+        assert_eq!(general_category('\u{660}'), GeneralCategory::DecimalNumber);
+        assert_eq!(general_category(' '), GeneralCategory::SpaceSeparator);
+        assert_eq!(general_category('\u{E000}'), GeneralCategory::PrivateUse);
+        assert_eq!(general_category('\u{378}'), GeneralCategory::Unassigned);
+        // Added in Unicode 15.0.0, and in 16.0.0, which the tables predate
+        assert_eq!(general_category('\u{11F04}'), GeneralCategory::OtherLetter);
+        assert_eq!(general_category('\u{10D4A}'), GeneralCategory::Unassigned);
+    }
+
+    #[test]
+    fn test_regex_find() {
+        let cases: &[(&str, &str, Option<&str>)] = &[
+            ("abc", "xxabcxx", Some("abc")),
+            ("a.c", "a\nc abc", Some("abc")),
+            ("(?s)a.c", "a\nc abc", Some("a\nc")),
+            ("a|ab", "ab", Some("a")),
+            ("ab|a", "ab", Some("ab")),
+            ("a+", "baaab", Some("aaa")),
+            ("a+?", "baaab", Some("a")),
+            ("a*", "baaab", Some("")),
+            ("x{2,3}", "xxxxx", Some("xxx")),
+            ("x{2,3}?", "xxxxx", Some("xx")),
+            ("x{2}y", "xy xxy", Some("xxy")),
+            ("(ab)+", "abababa", Some("ababab")),
+            ("[a-c]+", "xxcabz", Some("cab")),
+            ("[^a-c ]+", "abc def", Some("def")),
+            ("[-a]+", "b-a-", Some("-a-")),
+            ("[]a]+", "b]a", Some("]a")),
+            (r"\d+", "abc \u{661}\u{662}3", Some("\u{661}\u{662}3")),
+            (r"\w+", "  caf\u{e9}_1!", Some("caf\u{e9}_1")),
+            (r"\s+", "a \t\u{3000}b", Some(" \t\u{3000}")),
+            (r"\p{Lu}\p{Ll}+", "the Quick", Some("Quick")),
+            (r"\pL+", "12\u{3b1}\u{3b2}3", Some("\u{3b1}\u{3b2}")),
+            (r"\P{L}+", "ab12c", Some("12")),
+            (
+                r"\p{Extended_Pictographic}",
+                "a\u{1F600}",
+                Some("\u{1F600}"),
+            ),
+            (
+                r"\p{Alphabetic}\w",
+                "\u{11F04}\u{11F04}",
+                Some("\u{11F04}\u{11F04}"),
+            ),
+            (r"\p{Alphabetic}|\w", "\u{10D4A}", None),
+            (r"\x41\u{42}C", "ABC", Some("ABC")),
+            (r"\.\*", "a.*", Some(".*")),
+            ("^abc", "xabc", None),
+            ("^abc", "abcx", Some("abc")),
+            ("abc$", "abcx", None),
+            ("(?m)^b$", "a\nb\nc", Some("b")),
+            (r"\bfoo\b", "foobar foo", Some("foo")),
+            (r"\Boo\B", "oof foob", Some("oo")),
+            (r"\Afoo\z", "foo", Some("foo")),
+            ("(?i)stra\u{df}e", "STRA\u{df}E", Some("STRA\u{df}E")),
+            ("(?i)k", "\u{212A}", Some("\u{212A}")),
+            ("(?i)[a-c]+", "xABCd", Some("ABC")),
+            ("(?i)[\u{212A}]", "k", Some("k")),
+            ("(?i)[^k]", "\u{212A}K!", Some("!")),
+            ("(?i)[\u{3c2}]+", "\u{3a3}\u{3c3}", Some("\u{3a3}\u{3c3}")),
+            ("(?i)\\p{Lu}+", "1a\u{1c5}!", Some("a\u{1c5}")),
+            ("a(?i:b)c", "aBc aBC", Some("aBc")),
+            ("a(?i)b(?-i)c", "aBC aBc", Some("aBc")),
+            ("", "abc", Some("")),
+        ];
+        for &(re, haystack, expected) in cases {
+            assert_eq!(find(re, haystack), expected, "{:?} in {:?}", re, haystack);
+        }
+    }
+
+    #[test]
+    fn test_regex_captures() {
+        let re = Regex::new(r"(?P<year>\d{4})-(?<month>\d{2})(-(\d{2}))?").unwrap();
+        assert_eq!(re.captures_len(), 5);
+        assert_eq!(re.capture_index("month"), Some(2));
+
+        let units: Vec<u16> = "on 2024-06, then 2025-01-31".encode_utf16().collect();
+        let haystack = U16Str::from_chars(&units).unwrap();
+        let caps = re.captures(haystack).unwrap();
+        assert_eq!(caps.len(), 5);
+        assert_eq!(caps.get_range(0), Some(3..10));
+        assert_eq!(caps.name("year").unwrap().as_chars(), &units[3..7]);
+        assert_eq!(caps.get(2).unwrap().as_chars(), &units[8..10]);
+        assert_eq!(caps.get(3), None);
+
+        let all: Vec<_> = re
+            .captures_iter(haystack)
+            .map(|caps| String::from_utf16(caps.get(4).map_or(&[], |day| day.as_chars())).unwrap())
+            .collect();
+        assert_eq!(all, ["", "31"]);
+
+        // An iteration that matches the empty string ends a loop
+        let range = |re: &str, haystack: &str, i| {
+            let units: Vec<u16> = haystack.encode_utf16().collect();
+            let haystack = U16Str::from_chars(&units).unwrap();
+            Regex::new(re)
+                .unwrap()
+                .captures(haystack)
+                .unwrap()
+                .get_range(i)
+        };
+        assert_eq!(range("(|a)*", "aa", 0), Some(0..0));
+        assert_eq!(range(r"(\b|b)*", "b", 0), Some(0..0));
+        assert_eq!(range(r"(\b)*", "b", 1), Some(0..0));
+        assert_eq!(range("(a|)*b", "aab", 0), Some(0..3));
+
+        // The spans of every group agree with those found by the `regex` crate
+        let cases: &[(&str, &str, &[Range<usize>])] = &[
+            ("((?:a??b?)+)", "ba", &[0..2, 0..2]),
+            ("(.*)+", "ab", &[0..2, 0..2]),
+            ("(a*)+", "b", &[0..0, 0..0]),
+            ("(a|)*b", "aab", &[0..3, 1..2]),
+            ("(a?)+?b", "aab", &[0..3, 1..2]),
+            ("(a*){2,}", "aa", &[0..2, 2..2]),
+            ("(a*)*?x", "aax", &[0..3, 0..2]),
+            ("(?:(a)|b)*", "ab", &[0..2, 0..1]),
+        ];
+        for &(re, haystack, expected) in cases {
+            let re = Regex::new(re).unwrap();
+            let caps = re.captures(Str::from_str(haystack)).unwrap();
+            let spans: Vec<_> = (0..caps.len())
+                .map(|i| caps.get_range(i).unwrap())
+                .collect();
+            assert_eq!(spans, expected, "{} on {haystack:?}", re.as_str());
+        }
+    }
+
+    #[test]
+    fn test_regex_pattern() {
+        let re = Regex::new(r"\s*[,;]\s*").unwrap();
+        let units: Vec<u16> = "a , \u{1F600};c".encode_utf16().collect();
+        let haystack = U16Str::from_chars(&units).unwrap();
+        let pieces: Vec<String> = haystack
+            .split(&re)
+            .map(|piece| String::from_utf16(piece.as_chars()).unwrap())
+            .collect();
+        assert_eq!(pieces, ["a", "\u{1F600}", "c"]);
+        assert_eq!(haystack.find(&re), Some(1));
+        assert!(haystack.starts_with(Regex::new("a").unwrap()));
+        assert!(!haystack.starts_with(&re));
+
+        let empty = Regex::new("a*").unwrap();
+        let found: Vec<_> = Str::from_str("baaab\u{e9}")
+            .match_indices(&empty)
+            .map(|(i, m)| (i, m.as_str()))
+            .collect();
+        assert_eq!(found, [(0, ""), (1, "aaa"), (5, ""), (7, "")]);
+
+        let words = Regex::new(r"\b").unwrap();
+        let pieces: Vec<_> = Str::from_str("ab cd")
+            .split(words)
+            .map(|piece| piece.as_str())
+            .collect();
+        assert_eq!(pieces, ["", "ab", " ", "cd", ""]);
+    }
+
+    #[test]
+    fn test_regex_errors() {
+        let cases: &[(&str, ErrorKind, usize)] = &[
+            ("(a", ErrorKind::UnclosedGroup, 0),
+            ("a)", ErrorKind::UnopenedGroup, 1),
+            ("[ab", ErrorKind::UnclosedClass, 0),
+            ("a[z-a]", ErrorKind::InvalidClassRange, 2),
+            (r"\p{Nope}", ErrorKind::UnknownProperty, 0),
+            (r"\q", ErrorKind::InvalidEscape, 0),
+            (r"\u{D800}", ErrorKind::InvalidCodePoint, 0),
+            ("*a", ErrorKind::NothingToRepeat, 0),
+            ("a{3,1}", ErrorKind::InvalidRepetition, 1),
+            ("a{1001}", ErrorKind::InvalidRepetition, 1),
+            ("(?P<n>a)(?P<n>b)", ErrorKind::DuplicateGroupName, 12),
+            ("(?P<1n>a)", ErrorKind::InvalidGroupName, 4),
+            ("(?x)", ErrorKind::InvalidFlag, 2),
+            ("a\\", ErrorKind::UnexpectedEnd, 2),
+            ("(((a{1000}){1000}){1000})", ErrorKind::TooBig, 0),
+        ];
+        for &(re, kind, offset) in cases {
+            let err = Regex::new(re).unwrap_err();
+            assert_eq!((err.kind(), err.offset()), (kind, offset), "{:?}", re);
+        }
+    }
+}
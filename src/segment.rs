@@ -0,0 +1,847 @@
+//! Text segmentation (UAX #29) for [`BasicStr`]
+//!
+//! The tables are generated by `tools/gen_segmentation_tables.py`.
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+use crate::str::BasicStr;
+use crate::traits::{DecodeRev, IntoChars};
+
+#[rustfmt::skip]
+mod tables;
+
+/// The Grapheme_Cluster_Break of a character, merged with Extended_Pictographic
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum GraphemeCat {
+    Any,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+fn range_cmp(lo: u32, hi: u32, c: u32) -> Ordering {
+    if hi < c {
+        Ordering::Less
+    } else if lo > c {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+fn lookup<T: Copy>(table: &[(u32, u32, T)], c: char) -> Option<T> {
+    table
+        .binary_search_by(|&(lo, hi, _)| range_cmp(lo, hi, c as u32))
+        .ok()
+        .map(|i| table[i].2)
+}
+
+fn grapheme_cat(c: char) -> GraphemeCat {
+    lookup(&tables::GRAPHEME_CAT, c).unwrap_or(GraphemeCat::Any)
+}
+
+pub(crate) fn is_extended_pictographic(c: char) -> bool {
+    grapheme_cat(c) == GraphemeCat::ExtendedPictographic
+}
+
+/// Whether there is a grapheme cluster boundary between two characters, or the rule that decides it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Boundary {
+    Break,
+    Keep,
+    /// GB12 and GB13: depends on the parity of the preceding Regional_Indicator run
+    RegionalIndicator,
+    /// GB11: depends on whether the ZWJ follows an Extended_Pictographic and Extend characters
+    Emoji,
+}
+
+fn pair_boundary(prev: GraphemeCat, next: GraphemeCat) -> Boundary {
+    use GraphemeCat::*;
+    match (prev, next) {
+        (Cr, Lf) => Boundary::Keep,
+        (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => Boundary::Break,
+        (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => Boundary::Keep,
+        (_, Extend | Zwj | SpacingMark) | (Prepend, _) => Boundary::Keep,
+        (Zwj, ExtendedPictographic) => Boundary::Emoji,
+        (RegionalIndicator, RegionalIndicator) => Boundary::RegionalIndicator,
+        _ => Boundary::Break,
+    }
+}
+
+/// The context needed to find the end of a grapheme cluster when scanning forwards
+#[derive(Copy, Clone, Debug)]
+struct GraphemeState {
+    ri_odd: bool,
+    pict: bool,
+    pict_zwj: bool,
+}
+
+impl GraphemeState {
+    fn new() -> Self {
+        Self {
+            ri_odd: false,
+            pict: false,
+            pict_zwj: false,
+        }
+    }
+
+    fn push(&mut self, cat: GraphemeCat) {
+        self.ri_odd = cat == GraphemeCat::RegionalIndicator && !self.ri_odd;
+        self.pict_zwj = cat == GraphemeCat::Zwj && self.pict;
+        self.pict =
+            cat == GraphemeCat::ExtendedPictographic || (self.pict && cat == GraphemeCat::Extend);
+    }
+
+    fn is_break(&self, prev: GraphemeCat, next: GraphemeCat) -> bool {
+        match pair_boundary(prev, next) {
+            Boundary::Break => true,
+            Boundary::Keep => false,
+            Boundary::RegionalIndicator => !self.ri_odd,
+            Boundary::Emoji => !self.pict_zwj,
+        }
+    }
+}
+
+/// Decides a contextual `boundary` by scanning `before`, the characters preceding it, backwards
+///
+/// # Safety
+/// `before` shall be valid according to [`CharTraits::validate_range`][crate::traits::CharTraits::validate_range]
+unsafe fn is_break_back<Traits: DecodeRev>(
+    boundary: Boundary,
+    mut before: &[Traits::Char],
+) -> bool {
+    let mut prev = || {
+        if before.is_empty() {
+            None
+        } else {
+            let (c, rest) = Traits::decode_back_unchecked(before);
+            before = rest;
+            Some(grapheme_cat(c))
+        }
+    };
+    match boundary {
+        Boundary::Break => true,
+        Boundary::Keep => false,
+        Boundary::RegionalIndicator => {
+            let mut odd = false;
+            while prev() == Some(GraphemeCat::RegionalIndicator) {
+                odd = !odd;
+            }
+            !odd
+        }
+        Boundary::Emoji => {
+            // Skip the ZWJ
+            prev();
+            loop {
+                match prev() {
+                    Some(GraphemeCat::Extend) => {}
+                    cat => break cat != Some(GraphemeCat::ExtendedPictographic),
+                }
+            }
+        }
+    }
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the extended grapheme clusters of `self`, as defined by UAX #29
+    pub fn graphemes(&self) -> Graphemes<'_, Traits::Char, Traits> {
+        Graphemes(self.as_chars(), PhantomData)
+    }
+
+    /// Returns an iterator over the extended grapheme clusters of `self`, and their offsets in `self`
+    pub fn grapheme_indices(&self) -> GraphemeIndices<'_, Traits::Char, Traits> {
+        GraphemeIndices {
+            front_offset: 0,
+            iter: self.graphemes(),
+        }
+    }
+}
+
+/// An iterator over the extended grapheme clusters of a [`BasicStr`]
+pub struct Graphemes<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
+
+impl<CharT, Traits> Clone for Graphemes<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<'a, CharT, Traits> Graphemes<'a, CharT, Traits> {
+    /// Returns the part of the string that has not been iterated over
+    pub fn as_basic_str(&self) -> &'a BasicStr<CharT, Traits> {
+        // SAFETY:
+        // The remainder is split from a valid `BasicStr` on grapheme cluster boundaries
+        unsafe { BasicStr::from_chars_unchecked(self.0) }
+    }
+}
+
+impl<'a, Traits: IntoChars + 'a> Iterator for Graphemes<'a, Traits::Char, Traits> {
+    type Item = &'a BasicStr<Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        // SAFETY (for each call below):
+        // `self.0` and its suffixes produced by `decode_buf_unchecked` are valid and on character boundaries
+        let (c, mut rest) = unsafe { Traits::decode_buf_unchecked(self.0) };
+        let mut prev = grapheme_cat(c);
+        let mut state = GraphemeState::new();
+        state.push(prev);
+        while !rest.is_empty() {
+            let (c, after) = unsafe { Traits::decode_buf_unchecked(rest) };
+            let next = grapheme_cat(c);
+            if state.is_break(prev, next) {
+                break;
+            }
+            state.push(next);
+            prev = next;
+            rest = after;
+        }
+
+        let (cluster, rest) = self.0.split_at(self.0.len() - rest.len());
+        self.0 = rest;
+        Some(unsafe { BasicStr::from_chars_unchecked(cluster) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.0.len().div_ceil(Traits::max_encoding_len()),
+            Some(self.0.len()),
+        )
+    }
+}
+
+impl<'a, Traits: DecodeRev + 'a> DoubleEndedIterator for Graphemes<'a, Traits::Char, Traits> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        // SAFETY (for each call below):
+        // `self.0` and its prefixes produced by `decode_back_unchecked` are valid and on character boundaries
+        let (c, mut before) = unsafe { Traits::decode_back_unchecked(self.0) };
+        let mut next = grapheme_cat(c);
+        while !before.is_empty() {
+            let (c, rest) = unsafe { Traits::decode_back_unchecked(before) };
+            let prev = grapheme_cat(c);
+            if unsafe { is_break_back::<Traits>(pair_boundary(prev, next), before) } {
+                break;
+            }
+            next = prev;
+            before = rest;
+        }
+
+        let (rest, cluster) = self.0.split_at(before.len());
+        self.0 = rest;
+        Some(unsafe { BasicStr::from_chars_unchecked(cluster) })
+    }
+}
+
+/// An iterator over the extended grapheme clusters of a [`BasicStr`], and their offsets
+pub struct GraphemeIndices<'a, CharT, Traits> {
+    front_offset: usize,
+    iter: Graphemes<'a, CharT, Traits>,
+}
+
+impl<CharT, Traits> Clone for GraphemeIndices<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self {
+            front_offset: self.front_offset,
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, CharT, Traits> GraphemeIndices<'a, CharT, Traits> {
+    /// Returns the part of the string that has not been iterated over
+    pub fn as_basic_str(&self) -> &'a BasicStr<CharT, Traits> {
+        self.iter.as_basic_str()
+    }
+}
+
+impl<'a, Traits: IntoChars + 'a> Iterator for GraphemeIndices<'a, Traits::Char, Traits> {
+    type Item = (usize, &'a BasicStr<Traits::Char, Traits>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cluster = self.iter.next()?;
+        let offset = self.front_offset;
+        self.front_offset += cluster.len();
+        Some((offset, cluster))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, Traits: DecodeRev + 'a> DoubleEndedIterator for GraphemeIndices<'a, Traits::Char, Traits> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let cluster = self.iter.next_back()?;
+        Some((self.front_offset + self.iter.0.len(), cluster))
+    }
+}
+
+/// The Word_Break of a character
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum WordCat {
+    Other,
+    Cr,
+    Lf,
+    Newline,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+}
+
+fn word_cat(c: char) -> WordCat {
+    lookup(&tables::WORD_CAT, c).unwrap_or(WordCat::Other)
+}
+
+/// AHLetter
+fn is_ah_letter(cat: WordCat) -> bool {
+    matches!(cat, WordCat::ALetter | WordCat::HebrewLetter)
+}
+
+/// MidNumLetQ
+fn is_mid_num_let_q(cat: WordCat) -> bool {
+    matches!(cat, WordCat::MidNumLet | WordCat::SingleQuote)
+}
+
+/// Characters that are ignored after any other character (WB4)
+fn is_word_ignorable(cat: WordCat) -> bool {
+    matches!(cat, WordCat::Extend | WordCat::Format | WordCat::Zwj)
+}
+
+/// Returns the category of the first character of `buf` that is not ignored by WB4
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`][crate::traits::CharTraits::validate_range]
+unsafe fn next_word_cat<Traits: IntoChars>(mut buf: &[Traits::Char]) -> Option<WordCat> {
+    while !buf.is_empty() {
+        let (c, rest) = Traits::decode_buf_unchecked(buf);
+        let cat = word_cat(c);
+        if !is_word_ignorable(cat) {
+            return Some(cat);
+        }
+        buf = rest;
+    }
+    None
+}
+
+/// Returns the length of the first word-bounded piece of `buf`, which shall not be empty
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`][crate::traits::CharTraits::validate_range]
+unsafe fn word_end<Traits: IntoChars>(buf: &[Traits::Char]) -> usize {
+    use WordCat::*;
+
+    let (c, mut rest) = Traits::decode_buf_unchecked(buf);
+    // The previous character, the previous character not ignored by WB4, and the one before that
+    let mut raw = word_cat(c);
+    let mut prev = raw;
+    let mut prev2 = None;
+    let mut ri_odd = raw == RegionalIndicator;
+    while !rest.is_empty() {
+        let (c, after) = Traits::decode_buf_unchecked(rest);
+        let cat = word_cat(c);
+        let lookahead = || next_word_cat::<Traits>(after);
+        let keep = match (raw, cat) {
+            (Cr, Lf) => true,
+            (Newline | Cr | Lf, _) | (_, Newline | Cr | Lf) => false,
+            (Zwj, _) if grapheme_cat(c) == GraphemeCat::ExtendedPictographic => true,
+            (WSegSpace, WSegSpace) => true,
+            (_, Extend | Format | Zwj) => true,
+            _ => match (prev, cat) {
+                (a, b) if is_ah_letter(a) && is_ah_letter(b) => true,
+                (HebrewLetter, SingleQuote) => true,
+                (a, b) if is_ah_letter(a) && (b == MidLetter || is_mid_num_let_q(b)) => {
+                    lookahead().is_some_and(is_ah_letter)
+                }
+                (a, b) if (a == MidLetter || is_mid_num_let_q(a)) && is_ah_letter(b) => {
+                    prev2.is_some_and(is_ah_letter)
+                }
+                (HebrewLetter, DoubleQuote) => lookahead() == Some(HebrewLetter),
+                (DoubleQuote, HebrewLetter) => prev2 == Some(HebrewLetter),
+                (a, b)
+                    if (a == Numeric || is_ah_letter(a)) && (b == Numeric || is_ah_letter(b)) =>
+                {
+                    true
+                }
+                (a, Numeric) if a == MidNum || is_mid_num_let_q(a) => prev2 == Some(Numeric),
+                (Numeric, b) if b == MidNum || is_mid_num_let_q(b) => lookahead() == Some(Numeric),
+                (Katakana, Katakana) => true,
+                (a, ExtendNumLet)
+                    if is_ah_letter(a) || matches!(a, Numeric | Katakana | ExtendNumLet) =>
+                {
+                    true
+                }
+                (ExtendNumLet, b) if is_ah_letter(b) || matches!(b, Numeric | Katakana) => true,
+                (RegionalIndicator, RegionalIndicator) => ri_odd,
+                _ => false,
+            },
+        };
+        if !keep {
+            break;
+        }
+        if !is_word_ignorable(cat) {
+            prev2 = Some(prev);
+            prev = cat;
+            ri_odd = cat == RegionalIndicator && !ri_odd;
+        }
+        raw = cat;
+        rest = after;
+    }
+    buf.len() - rest.len()
+}
+
+/// The Sentence_Break of a character
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SentenceCat {
+    Other,
+    Cr,
+    Lf,
+    Extend,
+    Sep,
+    Format,
+    Sp,
+    Lower,
+    Upper,
+    OLetter,
+    Numeric,
+    ATerm,
+    SContinue,
+    STerm,
+    Close,
+}
+
+fn sentence_cat(c: char) -> SentenceCat {
+    lookup(&tables::SENTENCE_CAT, c).unwrap_or(SentenceCat::Other)
+}
+
+/// ParaSep
+fn is_para_sep(cat: SentenceCat) -> bool {
+    matches!(cat, SentenceCat::Sep | SentenceCat::Cr | SentenceCat::Lf)
+}
+
+/// Returns whether the first character of `buf` that is not Other, Extend, Format, Sp, Numeric,
+/// SContinue or Close is Lower (SB8)
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`][crate::traits::CharTraits::validate_range]
+unsafe fn lower_follows<Traits: IntoChars>(mut buf: &[Traits::Char]) -> bool {
+    while !buf.is_empty() {
+        let (c, rest) = Traits::decode_buf_unchecked(buf);
+        match sentence_cat(c) {
+            SentenceCat::Lower => return true,
+            SentenceCat::OLetter
+            | SentenceCat::Upper
+            | SentenceCat::ATerm
+            | SentenceCat::STerm
+            | SentenceCat::Sep
+            | SentenceCat::Cr
+            | SentenceCat::Lf => return false,
+            _ => buf = rest,
+        }
+    }
+    false
+}
+
+/// Returns the length of the first sentence of `buf`, which shall not be empty
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`][crate::traits::CharTraits::validate_range]
+unsafe fn sentence_end<Traits: IntoChars>(buf: &[Traits::Char]) -> usize {
+    use SentenceCat::*;
+
+    let (c, mut rest) = Traits::decode_buf_unchecked(buf);
+    let mut raw = sentence_cat(c);
+    // The previous character not ignored by SB5, and the one before the last ATerm or STerm
+    let mut prev = raw;
+    let mut before_term = None;
+    // Whether the previous characters match `SATerm Close*` or `SATerm Close* Sp+`,
+    //  and the terminator of that sequence
+    let mut term = None;
+    let mut after_sp = false;
+    if matches!(raw, ATerm | STerm) {
+        term = Some(raw);
+    }
+    while !rest.is_empty() {
+        let (c, after) = Traits::decode_buf_unchecked(rest);
+        let cat = sentence_cat(c);
+        let keep = match (raw, cat) {
+            (Cr, Lf) => true,
+            (Sep | Cr | Lf, _) => false,
+            (_, Extend | Format) => true,
+            _ => match (prev, cat, term) {
+                (ATerm, Numeric, _) => true,
+                (ATerm, Upper, _) if matches!(before_term, Some(Upper | Lower)) => true,
+                (_, _, None) => true,
+                (_, _, Some(ATerm)) if lower_follows::<Traits>(rest) => true,
+                (_, SContinue | ATerm | STerm, Some(_)) => true,
+                (_, Close | Sp, Some(_)) if !after_sp => true,
+                (_, _, Some(_)) => cat == Sp || is_para_sep(cat),
+            },
+        };
+        if !keep {
+            break;
+        }
+        if !matches!(cat, Extend | Format) {
+            match cat {
+                ATerm | STerm => {
+                    before_term = Some(prev);
+                    term = Some(cat);
+                    after_sp = false;
+                }
+                Close if !after_sp => {}
+                Sp => after_sp = true,
+                _ => term = None,
+            }
+            prev = cat;
+        }
+        raw = cat;
+        rest = after;
+    }
+    buf.len() - rest.len()
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the pieces of `self` separated by word boundaries, as defined by UAX #29.
+    ///
+    /// Concatenating the pieces yields `self`, including whitespace and punctuation.
+    pub fn split_word_bounds(&self) -> WordBounds<'_, Traits::Char, Traits> {
+        WordBounds(self.as_chars(), PhantomData)
+    }
+
+    /// Returns an iterator over the words of `self`, which are the pieces yielded by
+    /// [`BasicStr::split_word_bounds`] that contain an alphanumeric character
+    pub fn unicode_words(&self) -> UnicodeWords<'_, Traits::Char, Traits> {
+        UnicodeWords(self.split_word_bounds())
+    }
+
+    /// Returns an iterator over the sentences of `self`, as defined by UAX #29.
+    ///
+    /// Each sentence includes its trailing whitespace and paragraph separator, so that concatenating the sentences yields `self`.
+    pub fn sentences(&self) -> Sentences<'_, Traits::Char, Traits> {
+        Sentences(self.as_chars(), PhantomData)
+    }
+}
+
+/// An iterator over the pieces of a [`BasicStr`] separated by word boundaries
+pub struct WordBounds<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
+
+impl<CharT, Traits> Clone for WordBounds<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<'a, CharT, Traits> WordBounds<'a, CharT, Traits> {
+    /// Returns the part of the string that has not been iterated over
+    pub fn as_basic_str(&self) -> &'a BasicStr<CharT, Traits> {
+        // SAFETY:
+        // The remainder is split from a valid `BasicStr` on word boundaries
+        unsafe { BasicStr::from_chars_unchecked(self.0) }
+    }
+}
+
+impl<'a, Traits: IntoChars + 'a> Iterator for WordBounds<'a, Traits::Char, Traits> {
+    type Item = &'a BasicStr<Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        // SAFETY:
+        // `self.0` is a non-empty suffix of a valid `BasicStr` on a character boundary,
+        //  and `word_end` returns the position of a character boundary in it
+        let (piece, rest) = self.0.split_at(unsafe { word_end::<Traits>(self.0) });
+        self.0 = rest;
+        Some(unsafe { BasicStr::from_chars_unchecked(piece) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.0.len().div_ceil(Traits::max_encoding_len()),
+            Some(self.0.len()),
+        )
+    }
+}
+
+/// Returns `true` if `c` has the Alphabetic property or is a number
+fn is_alphanumeric(c: char) -> bool {
+    tables::ALPHANUMERIC
+        .binary_search_by(|&(lo, hi)| range_cmp(lo, hi, c as u32))
+        .is_ok()
+}
+
+/// An iterator over the words of a [`BasicStr`]
+pub struct UnicodeWords<'a, CharT, Traits>(WordBounds<'a, CharT, Traits>);
+
+impl<CharT, Traits> Clone for UnicodeWords<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, Traits: IntoChars + 'a> Iterator for UnicodeWords<'a, Traits::Char, Traits> {
+    type Item = &'a BasicStr<Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .by_ref()
+            .find(|piece| piece.unicode_iter().any(is_alphanumeric))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+/// An iterator over the sentences of a [`BasicStr`]
+pub struct Sentences<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
+
+impl<CharT, Traits> Clone for Sentences<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<'a, CharT, Traits> Sentences<'a, CharT, Traits> {
+    /// Returns the part of the string that has not been iterated over
+    pub fn as_basic_str(&self) -> &'a BasicStr<CharT, Traits> {
+        // SAFETY:
+        // The remainder is split from a valid `BasicStr` on sentence boundaries
+        unsafe { BasicStr::from_chars_unchecked(self.0) }
+    }
+}
+
+impl<'a, Traits: IntoChars + 'a> Iterator for Sentences<'a, Traits::Char, Traits> {
+    type Item = &'a BasicStr<Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        // SAFETY:
+        // `self.0` is a non-empty suffix of a valid `BasicStr` on a character boundary,
+        //  and `sentence_end` returns the position of a character boundary in it
+        let (piece, rest) = self.0.split_at(unsafe { sentence_end::<Traits>(self.0) });
+        self.0 = rest;
+        Some(unsafe { BasicStr::from_chars_unchecked(piece) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.0.len().div_ceil(Traits::max_encoding_len()),
+            Some(self.0.len()),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use crate::str::{Str, U16Str};
+
+    fn graphemes(s: &str) -> Vec<&str> {
+        Str::from_str(s).graphemes().map(|g| g.as_str()).collect()
+    }
+
+    #[test]
+    fn test_graphemes() {
+        // Each case is the clusters of the concatenated string
+        let cases: &[&[&str]] = &[
+            &["a", "b", "c"],
+            &["\r\n", "\n", "\r"],
+            &["e\u{301}\u{308}", "x"],
+            // Hangul syllables and jamo
+            &["\u{1100}\u{1161}\u{11a8}", "\u{ac00}\u{11a8}", "\u{1100}"],
+            // Flags are pairs of regional indicators
+            &["\u{1f1fa}\u{1f1f8}", "\u{1f1eb}\u{1f1f7}", "\u{1f1e9}"],
+            // Emoji ZWJ sequences and modifiers
+            &[
+                "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+                "\u{1f44d}\u{1f3fd}",
+            ],
+            &["a\u{200d}", "\u{1f469}"],
+            // Combining marks and a virama attach to the preceding consonant, but do not join it to the next
+            &["\u{915}\u{94d}", "\u{937}\u{93f}", "\u{915}"],
+            &["\u{915}\u{93c}\u{200d}\u{94d}", "\u{924}"],
+            &["\u{915}\u{93c}", "\u{924}"],
+            // Prepend and SpacingMark
+            &["\u{600}1", "\u{915}\u{93e}"],
+        ];
+
+        for clusters in cases {
+            let s = clusters.concat();
+            assert_eq!(graphemes(&s), *clusters, "{s:?}");
+
+            let mut rev: Vec<&str> = Str::from_str(&s)
+                .graphemes()
+                .rev()
+                .map(|g| g.as_str())
+                .collect();
+            rev.reverse();
+            assert_eq!(rev, *clusters, "{s:?}");
+
+            let utf16: Vec<u16> = s.encode_utf16().collect();
+            let u16str = U16Str::from_chars(&utf16).unwrap();
+            let lens: Vec<usize> = u16str.graphemes().map(|g| g.len()).collect();
+            let expected: Vec<usize> = clusters.iter().map(|c| c.encode_utf16().count()).collect();
+            assert_eq!(lens, expected, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_grapheme_indices() {
+        let s = Str::from_str("\u{1f1fa}\u{1f1f8}\u{1f1eb}e\u{301}!");
+        let expected = [
+            (0, "\u{1f1fa}\u{1f1f8}"),
+            (8, "\u{1f1eb}"),
+            (12, "e\u{301}"),
+            (15, "!"),
+        ];
+
+        let indices: Vec<_> = s.grapheme_indices().map(|(i, g)| (i, g.as_str())).collect();
+        assert_eq!(indices, expected);
+
+        let mut iter = s.grapheme_indices();
+        assert_eq!(iter.next().map(|(i, g)| (i, g.as_str())), Some(expected[0]));
+        assert_eq!(
+            iter.next_back().map(|(i, g)| (i, g.as_str())),
+            Some(expected[3])
+        );
+        assert_eq!(
+            iter.next_back().map(|(i, g)| (i, g.as_str())),
+            Some(expected[2])
+        );
+        assert_eq!(iter.as_basic_str().as_str(), "\u{1f1eb}");
+        assert_eq!(iter.next().map(|(i, g)| (i, g.as_str())), Some(expected[1]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_word_bounds() {
+        let s =
+            "The quick (\"brown\") fox can't jump 32.3 feet, right?\r\n\u{1f1fa}\u{1f1f8}\u{1f1eb}";
+        let bounds: Vec<&str> = Str::from_str(s)
+            .split_word_bounds()
+            .map(|w| w.as_str())
+            .collect();
+        assert_eq!(
+            bounds,
+            [
+                "The",
+                " ",
+                "quick",
+                " ",
+                "(",
+                "\"",
+                "brown",
+                "\"",
+                ")",
+                " ",
+                "fox",
+                " ",
+                "can't",
+                " ",
+                "jump",
+                " ",
+                "32.3",
+                " ",
+                "feet",
+                ",",
+                " ",
+                "right",
+                "?",
+                "\r\n",
+                "\u{1f1fa}\u{1f1f8}",
+                "\u{1f1eb}"
+            ]
+        );
+        assert_eq!(bounds.concat(), s);
+
+        let words: Vec<&str> = Str::from_str(s)
+            .unicode_words()
+            .map(|w| w.as_str())
+            .collect();
+        assert_eq!(
+            words,
+            ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
+        );
+
+        let utf16: Vec<u16> = "\u{5d0}\"\u{5d1} \u{30a2}\u{30a4}_1 e\u{301}t\u{e9}"
+            .encode_utf16()
+            .collect();
+        let words: Vec<String> = U16Str::from_chars(&utf16)
+            .unwrap()
+            .unicode_words()
+            .map(|w| String::from_utf16(w.as_chars()).unwrap())
+            .collect();
+        assert_eq!(
+            words,
+            ["\u{5d0}\"\u{5d1}", "\u{30a2}\u{30a4}_1", "e\u{301}t\u{e9}"]
+        );
+
+        // U+140D5 was assigned as a letter in Unicode 16.0.0, and Unicode 15.0.0 has U+2160 as a number
+        let words: Vec<&str> = Str::from_str("\u{140D5} \u{2160} \u{B2}")
+            .unicode_words()
+            .map(|w| w.as_str())
+            .collect();
+        assert_eq!(words, ["\u{2160}", "\u{B2}"]);
+    }
+
+    #[test]
+    fn test_sentences() {
+        let s =
+            "Mr. Smith went to Washington. He said \"Hi!\" to everyone.\nThe end etc. of it? Yes.";
+        let sentences: Vec<&str> = Str::from_str(s).sentences().map(|s| s.as_str()).collect();
+        assert_eq!(
+            sentences,
+            [
+                "Mr. ",
+                "Smith went to Washington. ",
+                "He said \"Hi!\" ",
+                "to everyone.\n",
+                "The end etc. of it? ",
+                "Yes."
+            ]
+        );
+
+        let utf16: Vec<u16> = "U.S.A. is big. 3.5 is a number.".encode_utf16().collect();
+        let lens: Vec<usize> = U16Str::from_chars(&utf16)
+            .unwrap()
+            .sentences()
+            .map(|s| s.len())
+            .collect();
+        assert_eq!(lens, [15, 16]);
+    }
+}
@@ -0,0 +1,5754 @@
+// Generated by tools/gen_segmentation_tables.py. Do not edit.
+
+// Unicode Character Database version 15.0.0
+
+use super::{GraphemeCat as G, SentenceCat as S, WordCat as W};
+
+/// Ranges of characters with a Grapheme_Cluster_Break other than Any, or Extended_Pictographic, and their category
+pub(super) static GRAPHEME_CAT: [(u32, u32, G); 1449] = [
+    (0x0000, 0x0009, G::Control),
+    (0x000A, 0x000A, G::Lf),
+    (0x000B, 0x000C, G::Control),
+    (0x000D, 0x000D, G::Cr),
+    (0x000E, 0x001F, G::Control),
+    (0x007F, 0x009F, G::Control),
+    (0x00A9, 0x00A9, G::ExtendedPictographic),
+    (0x00AD, 0x00AD, G::Control),
+    (0x00AE, 0x00AE, G::ExtendedPictographic),
+    (0x0300, 0x036F, G::Extend),
+    (0x0483, 0x0489, G::Extend),
+    (0x0591, 0x05BD, G::Extend),
+    (0x05BF, 0x05BF, G::Extend),
+    (0x05C1, 0x05C2, G::Extend),
+    (0x05C4, 0x05C5, G::Extend),
+    (0x05C7, 0x05C7, G::Extend),
+    (0x0600, 0x0605, G::Prepend),
+    (0x0610, 0x061A, G::Extend),
+    (0x061C, 0x061C, G::Control),
+    (0x064B, 0x065F, G::Extend),
+    (0x0670, 0x0670, G::Extend),
+    (0x06D6, 0x06DC, G::Extend),
+    (0x06DD, 0x06DD, G::Prepend),
+    (0x06DF, 0x06E4, G::Extend),
+    (0x06E7, 0x06E8, G::Extend),
+    (0x06EA, 0x06ED, G::Extend),
+    (0x070F, 0x070F, G::Prepend),
+    (0x0711, 0x0711, G::Extend),
+    (0x0730, 0x074A, G::Extend),
+    (0x07A6, 0x07B0, G::Extend),
+    (0x07EB, 0x07F3, G::Extend),
+    (0x07FD, 0x07FD, G::Extend),
+    (0x0816, 0x0819, G::Extend),
+    (0x081B, 0x0823, G::Extend),
+    (0x0825, 0x0827, G::Extend),
+    (0x0829, 0x082D, G::Extend),
+    (0x0859, 0x085B, G::Extend),
+    (0x0890, 0x0891, G::Prepend),
+    (0x0898, 0x089F, G::Extend),
+    (0x08CA, 0x08E1, G::Extend),
+    (0x08E2, 0x08E2, G::Prepend),
+    (0x08E3, 0x0902, G::Extend),
+    (0x0903, 0x0903, G::SpacingMark),
+    (0x093A, 0x093A, G::Extend),
+    (0x093B, 0x093B, G::SpacingMark),
+    (0x093C, 0x093C, G::Extend),
+    (0x093E, 0x0940, G::SpacingMark),
+    (0x0941, 0x0948, G::Extend),
+    (0x0949, 0x094C, G::SpacingMark),
+    (0x094D, 0x094D, G::Extend),
+    (0x094E, 0x094F, G::SpacingMark),
+    (0x0951, 0x0957, G::Extend),
+    (0x0962, 0x0963, G::Extend),
+    (0x0981, 0x0981, G::Extend),
+    (0x0982, 0x0983, G::SpacingMark),
+    (0x09BC, 0x09BC, G::Extend),
+    (0x09BE, 0x09BE, G::Extend),
+    (0x09BF, 0x09C0, G::SpacingMark),
+    (0x09C1, 0x09C4, G::Extend),
+    (0x09C7, 0x09C8, G::SpacingMark),
+    (0x09CB, 0x09CC, G::SpacingMark),
+    (0x09CD, 0x09CD, G::Extend),
+    (0x09D7, 0x09D7, G::Extend),
+    (0x09E2, 0x09E3, G::Extend),
+    (0x09FE, 0x09FE, G::Extend),
+    (0x0A01, 0x0A02, G::Extend),
+    (0x0A03, 0x0A03, G::SpacingMark),
+    (0x0A3C, 0x0A3C, G::Extend),
+    (0x0A3E, 0x0A40, G::SpacingMark),
+    (0x0A41, 0x0A42, G::Extend),
+    (0x0A47, 0x0A48, G::Extend),
+    (0x0A4B, 0x0A4D, G::Extend),
+    (0x0A51, 0x0A51, G::Extend),
+    (0x0A70, 0x0A71, G::Extend),
+    (0x0A75, 0x0A75, G::Extend),
+    (0x0A81, 0x0A82, G::Extend),
+    (0x0A83, 0x0A83, G::SpacingMark),
+    (0x0ABC, 0x0ABC, G::Extend),
+    (0x0ABE, 0x0AC0, G::SpacingMark),
+    (0x0AC1, 0x0AC5, G::Extend),
+    (0x0AC7, 0x0AC8, G::Extend),
+    (0x0AC9, 0x0AC9, G::SpacingMark),
+    (0x0ACB, 0x0ACC, G::SpacingMark),
+    (0x0ACD, 0x0ACD, G::Extend),
+    (0x0AE2, 0x0AE3, G::Extend),
+    (0x0AFA, 0x0AFF, G::Extend),
+    (0x0B01, 0x0B01, G::Extend),
+    (0x0B02, 0x0B03, G::SpacingMark),
+    (0x0B3C, 0x0B3C, G::Extend),
+    (0x0B3E, 0x0B3F, G::Extend),
+    (0x0B40, 0x0B40, G::SpacingMark),
+    (0x0B41, 0x0B44, G::Extend),
+    (0x0B47, 0x0B48, G::SpacingMark),
+    (0x0B4B, 0x0B4C, G::SpacingMark),
+    (0x0B4D, 0x0B4D, G::Extend),
+    (0x0B55, 0x0B57, G::Extend),
+    (0x0B62, 0x0B63, G::Extend),
+    (0x0B82, 0x0B82, G::Extend),
+    (0x0BBE, 0x0BBE, G::Extend),
+    (0x0BBF, 0x0BBF, G::SpacingMark),
+    (0x0BC0, 0x0BC0, G::Extend),
+    (0x0BC1, 0x0BC2, G::SpacingMark),
+    (0x0BC6, 0x0BC8, G::SpacingMark),
+    (0x0BCA, 0x0BCC, G::SpacingMark),
+    (0x0BCD, 0x0BCD, G::Extend),
+    (0x0BD7, 0x0BD7, G::Extend),
+    (0x0C00, 0x0C00, G::Extend),
+    (0x0C01, 0x0C03, G::SpacingMark),
+    (0x0C04, 0x0C04, G::Extend),
+    (0x0C3C, 0x0C3C, G::Extend),
+    (0x0C3E, 0x0C40, G::Extend),
+    (0x0C41, 0x0C44, G::SpacingMark),
+    (0x0C46, 0x0C48, G::Extend),
+    (0x0C4A, 0x0C4D, G::Extend),
+    (0x0C55, 0x0C56, G::Extend),
+    (0x0C62, 0x0C63, G::Extend),
+    (0x0C81, 0x0C81, G::Extend),
+    (0x0C82, 0x0C83, G::SpacingMark),
+    (0x0CBC, 0x0CBC, G::Extend),
+    (0x0CBE, 0x0CBE, G::SpacingMark),
+    (0x0CBF, 0x0CBF, G::Extend),
+    (0x0CC0, 0x0CC1, G::SpacingMark),
+    (0x0CC2, 0x0CC2, G::Extend),
+    (0x0CC3, 0x0CC4, G::SpacingMark),
+    (0x0CC6, 0x0CC6, G::Extend),
+    (0x0CC7, 0x0CC8, G::SpacingMark),
+    (0x0CCA, 0x0CCB, G::SpacingMark),
+    (0x0CCC, 0x0CCD, G::Extend),
+    (0x0CD5, 0x0CD6, G::Extend),
+    (0x0CE2, 0x0CE3, G::Extend),
+    (0x0CF3, 0x0CF3, G::SpacingMark),
+    (0x0D00, 0x0D01, G::Extend),
+    (0x0D02, 0x0D03, G::SpacingMark),
+    (0x0D3B, 0x0D3C, G::Extend),
+    (0x0D3E, 0x0D3E, G::Extend),
+    (0x0D3F, 0x0D40, G::SpacingMark),
+    (0x0D41, 0x0D44, G::Extend),
+    (0x0D46, 0x0D48, G::SpacingMark),
+    (0x0D4A, 0x0D4C, G::SpacingMark),
+    (0x0D4D, 0x0D4D, G::Extend),
+    (0x0D4E, 0x0D4E, G::Prepend),
+    (0x0D57, 0x0D57, G::Extend),
+    (0x0D62, 0x0D63, G::Extend),
+    (0x0D81, 0x0D81, G::Extend),
+    (0x0D82, 0x0D83, G::SpacingMark),
+    (0x0DCA, 0x0DCA, G::Extend),
+    (0x0DCF, 0x0DCF, G::Extend),
+    (0x0DD0, 0x0DD1, G::SpacingMark),
+    (0x0DD2, 0x0DD4, G::Extend),
+    (0x0DD6, 0x0DD6, G::Extend),
+    (0x0DD8, 0x0DDE, G::SpacingMark),
+    (0x0DDF, 0x0DDF, G::Extend),
+    (0x0DF2, 0x0DF3, G::SpacingMark),
+    (0x0E31, 0x0E31, G::Extend),
+    (0x0E33, 0x0E33, G::SpacingMark),
+    (0x0E34, 0x0E3A, G::Extend),
+    (0x0E47, 0x0E4E, G::Extend),
+    (0x0EB1, 0x0EB1, G::Extend),
+    (0x0EB3, 0x0EB3, G::SpacingMark),
+    (0x0EB4, 0x0EBC, G::Extend),
+    (0x0EC8, 0x0ECE, G::Extend),
+    (0x0F18, 0x0F19, G::Extend),
+    (0x0F35, 0x0F35, G::Extend),
+    (0x0F37, 0x0F37, G::Extend),
+    (0x0F39, 0x0F39, G::Extend),
+    (0x0F3E, 0x0F3F, G::SpacingMark),
+    (0x0F71, 0x0F7E, G::Extend),
+    (0x0F7F, 0x0F7F, G::SpacingMark),
+    (0x0F80, 0x0F84, G::Extend),
+    (0x0F86, 0x0F87, G::Extend),
+    (0x0F8D, 0x0F97, G::Extend),
+    (0x0F99, 0x0FBC, G::Extend),
+    (0x0FC6, 0x0FC6, G::Extend),
+    (0x102D, 0x1030, G::Extend),
+    (0x1031, 0x1031, G::SpacingMark),
+    (0x1032, 0x1037, G::Extend),
+    (0x1039, 0x103A, G::Extend),
+    (0x103B, 0x103C, G::SpacingMark),
+    (0x103D, 0x103E, G::Extend),
+    (0x1056, 0x1057, G::SpacingMark),
+    (0x1058, 0x1059, G::Extend),
+    (0x105E, 0x1060, G::Extend),
+    (0x1071, 0x1074, G::Extend),
+    (0x1082, 0x1082, G::Extend),
+    (0x1084, 0x1084, G::SpacingMark),
+    (0x1085, 0x1086, G::Extend),
+    (0x108D, 0x108D, G::Extend),
+    (0x109D, 0x109D, G::Extend),
+    (0x1100, 0x115F, G::L),
+    (0x1160, 0x11A7, G::V),
+    (0x11A8, 0x11FF, G::T),
+    (0x135D, 0x135F, G::Extend),
+    (0x1712, 0x1714, G::Extend),
+    (0x1715, 0x1715, G::SpacingMark),
+    (0x1732, 0x1733, G::Extend),
+    (0x1734, 0x1734, G::SpacingMark),
+    (0x1752, 0x1753, G::Extend),
+    (0x1772, 0x1773, G::Extend),
+    (0x17B4, 0x17B5, G::Extend),
+    (0x17B6, 0x17B6, G::SpacingMark),
+    (0x17B7, 0x17BD, G::Extend),
+    (0x17BE, 0x17C5, G::SpacingMark),
+    (0x17C6, 0x17C6, G::Extend),
+    (0x17C7, 0x17C8, G::SpacingMark),
+    (0x17C9, 0x17D3, G::Extend),
+    (0x17DD, 0x17DD, G::Extend),
+    (0x180B, 0x180D, G::Extend),
+    (0x180E, 0x180E, G::Control),
+    (0x180F, 0x180F, G::Extend),
+    (0x1885, 0x1886, G::Extend),
+    (0x18A9, 0x18A9, G::Extend),
+    (0x1920, 0x1922, G::Extend),
+    (0x1923, 0x1926, G::SpacingMark),
+    (0x1927, 0x1928, G::Extend),
+    (0x1929, 0x192B, G::SpacingMark),
+    (0x1930, 0x1931, G::SpacingMark),
+    (0x1932, 0x1932, G::Extend),
+    (0x1933, 0x1938, G::SpacingMark),
+    (0x1939, 0x193B, G::Extend),
+    (0x1A17, 0x1A18, G::Extend),
+    (0x1A19, 0x1A1A, G::SpacingMark),
+    (0x1A1B, 0x1A1B, G::Extend),
+    (0x1A55, 0x1A55, G::SpacingMark),
+    (0x1A56, 0x1A56, G::Extend),
+    (0x1A57, 0x1A57, G::SpacingMark),
+    (0x1A58, 0x1A5E, G::Extend),
+    (0x1A60, 0x1A60, G::Extend),
+    (0x1A62, 0x1A62, G::Extend),
+    (0x1A65, 0x1A6C, G::Extend),
+    (0x1A6D, 0x1A72, G::SpacingMark),
+    (0x1A73, 0x1A7C, G::Extend),
+    (0x1A7F, 0x1A7F, G::Extend),
+    (0x1AB0, 0x1ACE, G::Extend),
+    (0x1B00, 0x1B03, G::Extend),
+    (0x1B04, 0x1B04, G::SpacingMark),
+    (0x1B34, 0x1B3A, G::Extend),
+    (0x1B3B, 0x1B3B, G::SpacingMark),
+    (0x1B3C, 0x1B3C, G::Extend),
+    (0x1B3D, 0x1B41, G::SpacingMark),
+    (0x1B42, 0x1B42, G::Extend),
+    (0x1B43, 0x1B44, G::SpacingMark),
+    (0x1B6B, 0x1B73, G::Extend),
+    (0x1B80, 0x1B81, G::Extend),
+    (0x1B82, 0x1B82, G::SpacingMark),
+    (0x1BA1, 0x1BA1, G::SpacingMark),
+    (0x1BA2, 0x1BA5, G::Extend),
+    (0x1BA6, 0x1BA7, G::SpacingMark),
+    (0x1BA8, 0x1BA9, G::Extend),
+    (0x1BAA, 0x1BAA, G::SpacingMark),
+    (0x1BAB, 0x1BAD, G::Extend),
+    (0x1BE6, 0x1BE6, G::Extend),
+    (0x1BE7, 0x1BE7, G::SpacingMark),
+    (0x1BE8, 0x1BE9, G::Extend),
+    (0x1BEA, 0x1BEC, G::SpacingMark),
+    (0x1BED, 0x1BED, G::Extend),
+    (0x1BEE, 0x1BEE, G::SpacingMark),
+    (0x1BEF, 0x1BF1, G::Extend),
+    (0x1BF2, 0x1BF3, G::SpacingMark),
+    (0x1C24, 0x1C2B, G::SpacingMark),
+    (0x1C2C, 0x1C33, G::Extend),
+    (0x1C34, 0x1C35, G::SpacingMark),
+    (0x1C36, 0x1C37, G::Extend),
+    (0x1CD0, 0x1CD2, G::Extend),
+    (0x1CD4, 0x1CE0, G::Extend),
+    (0x1CE1, 0x1CE1, G::SpacingMark),
+    (0x1CE2, 0x1CE8, G::Extend),
+    (0x1CED, 0x1CED, G::Extend),
+    (0x1CF4, 0x1CF4, G::Extend),
+    (0x1CF7, 0x1CF7, G::SpacingMark),
+    (0x1CF8, 0x1CF9, G::Extend),
+    (0x1DC0, 0x1DFF, G::Extend),
+    (0x200B, 0x200B, G::Control),
+    (0x200C, 0x200C, G::Extend),
+    (0x200D, 0x200D, G::Zwj),
+    (0x200E, 0x200F, G::Control),
+    (0x2028, 0x202E, G::Control),
+    (0x203C, 0x203C, G::ExtendedPictographic),
+    (0x2049, 0x2049, G::ExtendedPictographic),
+    (0x2060, 0x206F, G::Control),
+    (0x20D0, 0x20F0, G::Extend),
+    (0x2122, 0x2122, G::ExtendedPictographic),
+    (0x2139, 0x2139, G::ExtendedPictographic),
+    (0x2194, 0x2199, G::ExtendedPictographic),
+    (0x21A9, 0x21AA, G::ExtendedPictographic),
+    (0x231A, 0x231B, G::ExtendedPictographic),
+    (0x2328, 0x2328, G::ExtendedPictographic),
+    (0x2388, 0x2388, G::ExtendedPictographic),
+    (0x23CF, 0x23CF, G::ExtendedPictographic),
+    (0x23E9, 0x23F3, G::ExtendedPictographic),
+    (0x23F8, 0x23FA, G::ExtendedPictographic),
+    (0x24C2, 0x24C2, G::ExtendedPictographic),
+    (0x25AA, 0x25AB, G::ExtendedPictographic),
+    (0x25B6, 0x25B6, G::ExtendedPictographic),
+    (0x25C0, 0x25C0, G::ExtendedPictographic),
+    (0x25FB, 0x25FE, G::ExtendedPictographic),
+    (0x2600, 0x2605, G::ExtendedPictographic),
+    (0x2607, 0x2612, G::ExtendedPictographic),
+    (0x2614, 0x2685, G::ExtendedPictographic),
+    (0x2690, 0x2705, G::ExtendedPictographic),
+    (0x2708, 0x2712, G::ExtendedPictographic),
+    (0x2714, 0x2714, G::ExtendedPictographic),
+    (0x2716, 0x2716, G::ExtendedPictographic),
+    (0x271D, 0x271D, G::ExtendedPictographic),
+    (0x2721, 0x2721, G::ExtendedPictographic),
+    (0x2728, 0x2728, G::ExtendedPictographic),
+    (0x2733, 0x2734, G::ExtendedPictographic),
+    (0x2744, 0x2744, G::ExtendedPictographic),
+    (0x2747, 0x2747, G::ExtendedPictographic),
+    (0x274C, 0x274C, G::ExtendedPictographic),
+    (0x274E, 0x274E, G::ExtendedPictographic),
+    (0x2753, 0x2755, G::ExtendedPictographic),
+    (0x2757, 0x2757, G::ExtendedPictographic),
+    (0x2763, 0x2767, G::ExtendedPictographic),
+    (0x2795, 0x2797, G::ExtendedPictographic),
+    (0x27A1, 0x27A1, G::ExtendedPictographic),
+    (0x27B0, 0x27B0, G::ExtendedPictographic),
+    (0x27BF, 0x27BF, G::ExtendedPictographic),
+    (0x2934, 0x2935, G::ExtendedPictographic),
+    (0x2B05, 0x2B07, G::ExtendedPictographic),
+    (0x2B1B, 0x2B1C, G::ExtendedPictographic),
+    (0x2B50, 0x2B50, G::ExtendedPictographic),
+    (0x2B55, 0x2B55, G::ExtendedPictographic),
+    (0x2CEF, 0x2CF1, G::Extend),
+    (0x2D7F, 0x2D7F, G::Extend),
+    (0x2DE0, 0x2DFF, G::Extend),
+    (0x302A, 0x302F, G::Extend),
+    (0x3030, 0x3030, G::ExtendedPictographic),
+    (0x303D, 0x303D, G::ExtendedPictographic),
+    (0x3099, 0x309A, G::Extend),
+    (0x3297, 0x3297, G::ExtendedPictographic),
+    (0x3299, 0x3299, G::ExtendedPictographic),
+    (0xA66F, 0xA672, G::Extend),
+    (0xA674, 0xA67D, G::Extend),
+    (0xA69E, 0xA69F, G::Extend),
+    (0xA6F0, 0xA6F1, G::Extend),
+    (0xA802, 0xA802, G::Extend),
+    (0xA806, 0xA806, G::Extend),
+    (0xA80B, 0xA80B, G::Extend),
+    (0xA823, 0xA824, G::SpacingMark),
+    (0xA825, 0xA826, G::Extend),
+    (0xA827, 0xA827, G::SpacingMark),
+    (0xA82C, 0xA82C, G::Extend),
+    (0xA880, 0xA881, G::SpacingMark),
+    (0xA8B4, 0xA8C3, G::SpacingMark),
+    (0xA8C4, 0xA8C5, G::Extend),
+    (0xA8E0, 0xA8F1, G::Extend),
+    (0xA8FF, 0xA8FF, G::Extend),
+    (0xA926, 0xA92D, G::Extend),
+    (0xA947, 0xA951, G::Extend),
+    (0xA952, 0xA953, G::SpacingMark),
+    (0xA960, 0xA97C, G::L),
+    (0xA980, 0xA982, G::Extend),
+    (0xA983, 0xA983, G::SpacingMark),
+    (0xA9B3, 0xA9B3, G::Extend),
+    (0xA9B4, 0xA9B5, G::SpacingMark),
+    (0xA9B6, 0xA9B9, G::Extend),
+    (0xA9BA, 0xA9BB, G::SpacingMark),
+    (0xA9BC, 0xA9BD, G::Extend),
+    (0xA9BE, 0xA9C0, G::SpacingMark),
+    (0xA9E5, 0xA9E5, G::Extend),
+    (0xAA29, 0xAA2E, G::Extend),
+    (0xAA2F, 0xAA30, G::SpacingMark),
+    (0xAA31, 0xAA32, G::Extend),
+    (0xAA33, 0xAA34, G::SpacingMark),
+    (0xAA35, 0xAA36, G::Extend),
+    (0xAA43, 0xAA43, G::Extend),
+    (0xAA4C, 0xAA4C, G::Extend),
+    (0xAA4D, 0xAA4D, G::SpacingMark),
+    (0xAA7C, 0xAA7C, G::Extend),
+    (0xAAB0, 0xAAB0, G::Extend),
+    (0xAAB2, 0xAAB4, G::Extend),
+    (0xAAB7, 0xAAB8, G::Extend),
+    (0xAABE, 0xAABF, G::Extend),
+    (0xAAC1, 0xAAC1, G::Extend),
+    (0xAAEB, 0xAAEB, G::SpacingMark),
+    (0xAAEC, 0xAAED, G::Extend),
+    (0xAAEE, 0xAAEF, G::SpacingMark),
+    (0xAAF5, 0xAAF5, G::SpacingMark),
+    (0xAAF6, 0xAAF6, G::Extend),
+    (0xABE3, 0xABE4, G::SpacingMark),
+    (0xABE5, 0xABE5, G::Extend),
+    (0xABE6, 0xABE7, G::SpacingMark),
+    (0xABE8, 0xABE8, G::Extend),
+    (0xABE9, 0xABEA, G::SpacingMark),
+    (0xABEC, 0xABEC, G::SpacingMark),
+    (0xABED, 0xABED, G::Extend),
+    (0xAC00, 0xAC00, G::Lv),
+    (0xAC01, 0xAC1B, G::Lvt),
+    (0xAC1C, 0xAC1C, G::Lv),
+    (0xAC1D, 0xAC37, G::Lvt),
+    (0xAC38, 0xAC38, G::Lv),
+    (0xAC39, 0xAC53, G::Lvt),
+    (0xAC54, 0xAC54, G::Lv),
+    (0xAC55, 0xAC6F, G::Lvt),
+    (0xAC70, 0xAC70, G::Lv),
+    (0xAC71, 0xAC8B, G::Lvt),
+    (0xAC8C, 0xAC8C, G::Lv),
+    (0xAC8D, 0xACA7, G::Lvt),
+    (0xACA8, 0xACA8, G::Lv),
+    (0xACA9, 0xACC3, G::Lvt),
+    (0xACC4, 0xACC4, G::Lv),
+    (0xACC5, 0xACDF, G::Lvt),
+    (0xACE0, 0xACE0, G::Lv),
+    (0xACE1, 0xACFB, G::Lvt),
+    (0xACFC, 0xACFC, G::Lv),
+    (0xACFD, 0xAD17, G::Lvt),
+    (0xAD18, 0xAD18, G::Lv),
+    (0xAD19, 0xAD33, G::Lvt),
+    (0xAD34, 0xAD34, G::Lv),
+    (0xAD35, 0xAD4F, G::Lvt),
+    (0xAD50, 0xAD50, G::Lv),
+    (0xAD51, 0xAD6B, G::Lvt),
+    (0xAD6C, 0xAD6C, G::Lv),
+    (0xAD6D, 0xAD87, G::Lvt),
+    (0xAD88, 0xAD88, G::Lv),
+    (0xAD89, 0xADA3, G::Lvt),
+    (0xADA4, 0xADA4, G::Lv),
+    (0xADA5, 0xADBF, G::Lvt),
+    (0xADC0, 0xADC0, G::Lv),
+    (0xADC1, 0xADDB, G::Lvt),
+    (0xADDC, 0xADDC, G::Lv),
+    (0xADDD, 0xADF7, G::Lvt),
+    (0xADF8, 0xADF8, G::Lv),
+    (0xADF9, 0xAE13, G::Lvt),
+    (0xAE14, 0xAE14, G::Lv),
+    (0xAE15, 0xAE2F, G::Lvt),
+    (0xAE30, 0xAE30, G::Lv),
+    (0xAE31, 0xAE4B, G::Lvt),
+    (0xAE4C, 0xAE4C, G::Lv),
+    (0xAE4D, 0xAE67, G::Lvt),
+    (0xAE68, 0xAE68, G::Lv),
+    (0xAE69, 0xAE83, G::Lvt),
+    (0xAE84, 0xAE84, G::Lv),
+    (0xAE85, 0xAE9F, G::Lvt),
+    (0xAEA0, 0xAEA0, G::Lv),
+    (0xAEA1, 0xAEBB, G::Lvt),
+    (0xAEBC, 0xAEBC, G::Lv),
+    (0xAEBD, 0xAED7, G::Lvt),
+    (0xAED8, 0xAED8, G::Lv),
+    (0xAED9, 0xAEF3, G::Lvt),
+    (0xAEF4, 0xAEF4, G::Lv),
+    (0xAEF5, 0xAF0F, G::Lvt),
+    (0xAF10, 0xAF10, G::Lv),
+    (0xAF11, 0xAF2B, G::Lvt),
+    (0xAF2C, 0xAF2C, G::Lv),
+    (0xAF2D, 0xAF47, G::Lvt),
+    (0xAF48, 0xAF48, G::Lv),
+    (0xAF49, 0xAF63, G::Lvt),
+    (0xAF64, 0xAF64, G::Lv),
+    (0xAF65, 0xAF7F, G::Lvt),
+    (0xAF80, 0xAF80, G::Lv),
+    (0xAF81, 0xAF9B, G::Lvt),
+    (0xAF9C, 0xAF9C, G::Lv),
+    (0xAF9D, 0xAFB7, G::Lvt),
+    (0xAFB8, 0xAFB8, G::Lv),
+    (0xAFB9, 0xAFD3, G::Lvt),
+    (0xAFD4, 0xAFD4, G::Lv),
+    (0xAFD5, 0xAFEF, G::Lvt),
+    (0xAFF0, 0xAFF0, G::Lv),
+    (0xAFF1, 0xB00B, G::Lvt),
+    (0xB00C, 0xB00C, G::Lv),
+    (0xB00D, 0xB027, G::Lvt),
+    (0xB028, 0xB028, G::Lv),
+    (0xB029, 0xB043, G::Lvt),
+    (0xB044, 0xB044, G::Lv),
+    (0xB045, 0xB05F, G::Lvt),
+    (0xB060, 0xB060, G::Lv),
+    (0xB061, 0xB07B, G::Lvt),
+    (0xB07C, 0xB07C, G::Lv),
+    (0xB07D, 0xB097, G::Lvt),
+    (0xB098, 0xB098, G::Lv),
+    (0xB099, 0xB0B3, G::Lvt),
+    (0xB0B4, 0xB0B4, G::Lv),
+    (0xB0B5, 0xB0CF, G::Lvt),
+    (0xB0D0, 0xB0D0, G::Lv),
+    (0xB0D1, 0xB0EB, G::Lvt),
+    (0xB0EC, 0xB0EC, G::Lv),
+    (0xB0ED, 0xB107, G::Lvt),
+    (0xB108, 0xB108, G::Lv),
+    (0xB109, 0xB123, G::Lvt),
+    (0xB124, 0xB124, G::Lv),
+    (0xB125, 0xB13F, G::Lvt),
+    (0xB140, 0xB140, G::Lv),
+    (0xB141, 0xB15B, G::Lvt),
+    (0xB15C, 0xB15C, G::Lv),
+    (0xB15D, 0xB177, G::Lvt),
+    (0xB178, 0xB178, G::Lv),
+    (0xB179, 0xB193, G::Lvt),
+    (0xB194, 0xB194, G::Lv),
+    (0xB195, 0xB1AF, G::Lvt),
+    (0xB1B0, 0xB1B0, G::Lv),
+    (0xB1B1, 0xB1CB, G::Lvt),
+    (0xB1CC, 0xB1CC, G::Lv),
+    (0xB1CD, 0xB1E7, G::Lvt),
+    (0xB1E8, 0xB1E8, G::Lv),
+    (0xB1E9, 0xB203, G::Lvt),
+    (0xB204, 0xB204, G::Lv),
+    (0xB205, 0xB21F, G::Lvt),
+    (0xB220, 0xB220, G::Lv),
+    (0xB221, 0xB23B, G::Lvt),
+    (0xB23C, 0xB23C, G::Lv),
+    (0xB23D, 0xB257, G::Lvt),
+    (0xB258, 0xB258, G::Lv),
+    (0xB259, 0xB273, G::Lvt),
+    (0xB274, 0xB274, G::Lv),
+    (0xB275, 0xB28F, G::Lvt),
+    (0xB290, 0xB290, G::Lv),
+    (0xB291, 0xB2AB, G::Lvt),
+    (0xB2AC, 0xB2AC, G::Lv),
+    (0xB2AD, 0xB2C7, G::Lvt),
+    (0xB2C8, 0xB2C8, G::Lv),
+    (0xB2C9, 0xB2E3, G::Lvt),
+    (0xB2E4, 0xB2E4, G::Lv),
+    (0xB2E5, 0xB2FF, G::Lvt),
+    (0xB300, 0xB300, G::Lv),
+    (0xB301, 0xB31B, G::Lvt),
+    (0xB31C, 0xB31C, G::Lv),
+    (0xB31D, 0xB337, G::Lvt),
+    (0xB338, 0xB338, G::Lv),
+    (0xB339, 0xB353, G::Lvt),
+    (0xB354, 0xB354, G::Lv),
+    (0xB355, 0xB36F, G::Lvt),
+    (0xB370, 0xB370, G::Lv),
+    (0xB371, 0xB38B, G::Lvt),
+    (0xB38C, 0xB38C, G::Lv),
+    (0xB38D, 0xB3A7, G::Lvt),
+    (0xB3A8, 0xB3A8, G::Lv),
+    (0xB3A9, 0xB3C3, G::Lvt),
+    (0xB3C4, 0xB3C4, G::Lv),
+    (0xB3C5, 0xB3DF, G::Lvt),
+    (0xB3E0, 0xB3E0, G::Lv),
+    (0xB3E1, 0xB3FB, G::Lvt),
+    (0xB3FC, 0xB3FC, G::Lv),
+    (0xB3FD, 0xB417, G::Lvt),
+    (0xB418, 0xB418, G::Lv),
+    (0xB419, 0xB433, G::Lvt),
+    (0xB434, 0xB434, G::Lv),
+    (0xB435, 0xB44F, G::Lvt),
+    (0xB450, 0xB450, G::Lv),
+    (0xB451, 0xB46B, G::Lvt),
+    (0xB46C, 0xB46C, G::Lv),
+    (0xB46D, 0xB487, G::Lvt),
+    (0xB488, 0xB488, G::Lv),
+    (0xB489, 0xB4A3, G::Lvt),
+    (0xB4A4, 0xB4A4, G::Lv),
+    (0xB4A5, 0xB4BF, G::Lvt),
+    (0xB4C0, 0xB4C0, G::Lv),
+    (0xB4C1, 0xB4DB, G::Lvt),
+    (0xB4DC, 0xB4DC, G::Lv),
+    (0xB4DD, 0xB4F7, G::Lvt),
+    (0xB4F8, 0xB4F8, G::Lv),
+    (0xB4F9, 0xB513, G::Lvt),
+    (0xB514, 0xB514, G::Lv),
+    (0xB515, 0xB52F, G::Lvt),
+    (0xB530, 0xB530, G::Lv),
+    (0xB531, 0xB54B, G::Lvt),
+    (0xB54C, 0xB54C, G::Lv),
+    (0xB54D, 0xB567, G::Lvt),
+    (0xB568, 0xB568, G::Lv),
+    (0xB569, 0xB583, G::Lvt),
+    (0xB584, 0xB584, G::Lv),
+    (0xB585, 0xB59F, G::Lvt),
+    (0xB5A0, 0xB5A0, G::Lv),
+    (0xB5A1, 0xB5BB, G::Lvt),
+    (0xB5BC, 0xB5BC, G::Lv),
+    (0xB5BD, 0xB5D7, G::Lvt),
+    (0xB5D8, 0xB5D8, G::Lv),
+    (0xB5D9, 0xB5F3, G::Lvt),
+    (0xB5F4, 0xB5F4, G::Lv),
+    (0xB5F5, 0xB60F, G::Lvt),
+    (0xB610, 0xB610, G::Lv),
+    (0xB611, 0xB62B, G::Lvt),
+    (0xB62C, 0xB62C, G::Lv),
+    (0xB62D, 0xB647, G::Lvt),
+    (0xB648, 0xB648, G::Lv),
+    (0xB649, 0xB663, G::Lvt),
+    (0xB664, 0xB664, G::Lv),
+    (0xB665, 0xB67F, G::Lvt),
+    (0xB680, 0xB680, G::Lv),
+    (0xB681, 0xB69B, G::Lvt),
+    (0xB69C, 0xB69C, G::Lv),
+    (0xB69D, 0xB6B7, G::Lvt),
+    (0xB6B8, 0xB6B8, G::Lv),
+    (0xB6B9, 0xB6D3, G::Lvt),
+    (0xB6D4, 0xB6D4, G::Lv),
+    (0xB6D5, 0xB6EF, G::Lvt),
+    (0xB6F0, 0xB6F0, G::Lv),
+    (0xB6F1, 0xB70B, G::Lvt),
+    (0xB70C, 0xB70C, G::Lv),
+    (0xB70D, 0xB727, G::Lvt),
+    (0xB728, 0xB728, G::Lv),
+    (0xB729, 0xB743, G::Lvt),
+    (0xB744, 0xB744, G::Lv),
+    (0xB745, 0xB75F, G::Lvt),
+    (0xB760, 0xB760, G::Lv),
+    (0xB761, 0xB77B, G::Lvt),
+    (0xB77C, 0xB77C, G::Lv),
+    (0xB77D, 0xB797, G::Lvt),
+    (0xB798, 0xB798, G::Lv),
+    (0xB799, 0xB7B3, G::Lvt),
+    (0xB7B4, 0xB7B4, G::Lv),
+    (0xB7B5, 0xB7CF, G::Lvt),
+    (0xB7D0, 0xB7D0, G::Lv),
+    (0xB7D1, 0xB7EB, G::Lvt),
+    (0xB7EC, 0xB7EC, G::Lv),
+    (0xB7ED, 0xB807, G::Lvt),
+    (0xB808, 0xB808, G::Lv),
+    (0xB809, 0xB823, G::Lvt),
+    (0xB824, 0xB824, G::Lv),
+    (0xB825, 0xB83F, G::Lvt),
+    (0xB840, 0xB840, G::Lv),
+    (0xB841, 0xB85B, G::Lvt),
+    (0xB85C, 0xB85C, G::Lv),
+    (0xB85D, 0xB877, G::Lvt),
+    (0xB878, 0xB878, G::Lv),
+    (0xB879, 0xB893, G::Lvt),
+    (0xB894, 0xB894, G::Lv),
+    (0xB895, 0xB8AF, G::Lvt),
+    (0xB8B0, 0xB8B0, G::Lv),
+    (0xB8B1, 0xB8CB, G::Lvt),
+    (0xB8CC, 0xB8CC, G::Lv),
+    (0xB8CD, 0xB8E7, G::Lvt),
+    (0xB8E8, 0xB8E8, G::Lv),
+    (0xB8E9, 0xB903, G::Lvt),
+    (0xB904, 0xB904, G::Lv),
+    (0xB905, 0xB91F, G::Lvt),
+    (0xB920, 0xB920, G::Lv),
+    (0xB921, 0xB93B, G::Lvt),
+    (0xB93C, 0xB93C, G::Lv),
+    (0xB93D, 0xB957, G::Lvt),
+    (0xB958, 0xB958, G::Lv),
+    (0xB959, 0xB973, G::Lvt),
+    (0xB974, 0xB974, G::Lv),
+    (0xB975, 0xB98F, G::Lvt),
+    (0xB990, 0xB990, G::Lv),
+    (0xB991, 0xB9AB, G::Lvt),
+    (0xB9AC, 0xB9AC, G::Lv),
+    (0xB9AD, 0xB9C7, G::Lvt),
+    (0xB9C8, 0xB9C8, G::Lv),
+    (0xB9C9, 0xB9E3, G::Lvt),
+    (0xB9E4, 0xB9E4, G::Lv),
+    (0xB9E5, 0xB9FF, G::Lvt),
+    (0xBA00, 0xBA00, G::Lv),
+    (0xBA01, 0xBA1B, G::Lvt),
+    (0xBA1C, 0xBA1C, G::Lv),
+    (0xBA1D, 0xBA37, G::Lvt),
+    (0xBA38, 0xBA38, G::Lv),
+    (0xBA39, 0xBA53, G::Lvt),
+    (0xBA54, 0xBA54, G::Lv),
+    (0xBA55, 0xBA6F, G::Lvt),
+    (0xBA70, 0xBA70, G::Lv),
+    (0xBA71, 0xBA8B, G::Lvt),
+    (0xBA8C, 0xBA8C, G::Lv),
+    (0xBA8D, 0xBAA7, G::Lvt),
+    (0xBAA8, 0xBAA8, G::Lv),
+    (0xBAA9, 0xBAC3, G::Lvt),
+    (0xBAC4, 0xBAC4, G::Lv),
+    (0xBAC5, 0xBADF, G::Lvt),
+    (0xBAE0, 0xBAE0, G::Lv),
+    (0xBAE1, 0xBAFB, G::Lvt),
+    (0xBAFC, 0xBAFC, G::Lv),
+    (0xBAFD, 0xBB17, G::Lvt),
+    (0xBB18, 0xBB18, G::Lv),
+    (0xBB19, 0xBB33, G::Lvt),
+    (0xBB34, 0xBB34, G::Lv),
+    (0xBB35, 0xBB4F, G::Lvt),
+    (0xBB50, 0xBB50, G::Lv),
+    (0xBB51, 0xBB6B, G::Lvt),
+    (0xBB6C, 0xBB6C, G::Lv),
+    (0xBB6D, 0xBB87, G::Lvt),
+    (0xBB88, 0xBB88, G::Lv),
+    (0xBB89, 0xBBA3, G::Lvt),
+    (0xBBA4, 0xBBA4, G::Lv),
+    (0xBBA5, 0xBBBF, G::Lvt),
+    (0xBBC0, 0xBBC0, G::Lv),
+    (0xBBC1, 0xBBDB, G::Lvt),
+    (0xBBDC, 0xBBDC, G::Lv),
+    (0xBBDD, 0xBBF7, G::Lvt),
+    (0xBBF8, 0xBBF8, G::Lv),
+    (0xBBF9, 0xBC13, G::Lvt),
+    (0xBC14, 0xBC14, G::Lv),
+    (0xBC15, 0xBC2F, G::Lvt),
+    (0xBC30, 0xBC30, G::Lv),
+    (0xBC31, 0xBC4B, G::Lvt),
+    (0xBC4C, 0xBC4C, G::Lv),
+    (0xBC4D, 0xBC67, G::Lvt),
+    (0xBC68, 0xBC68, G::Lv),
+    (0xBC69, 0xBC83, G::Lvt),
+    (0xBC84, 0xBC84, G::Lv),
+    (0xBC85, 0xBC9F, G::Lvt),
+    (0xBCA0, 0xBCA0, G::Lv),
+    (0xBCA1, 0xBCBB, G::Lvt),
+    (0xBCBC, 0xBCBC, G::Lv),
+    (0xBCBD, 0xBCD7, G::Lvt),
+    (0xBCD8, 0xBCD8, G::Lv),
+    (0xBCD9, 0xBCF3, G::Lvt),
+    (0xBCF4, 0xBCF4, G::Lv),
+    (0xBCF5, 0xBD0F, G::Lvt),
+    (0xBD10, 0xBD10, G::Lv),
+    (0xBD11, 0xBD2B, G::Lvt),
+    (0xBD2C, 0xBD2C, G::Lv),
+    (0xBD2D, 0xBD47, G::Lvt),
+    (0xBD48, 0xBD48, G::Lv),
+    (0xBD49, 0xBD63, G::Lvt),
+    (0xBD64, 0xBD64, G::Lv),
+    (0xBD65, 0xBD7F, G::Lvt),
+    (0xBD80, 0xBD80, G::Lv),
+    (0xBD81, 0xBD9B, G::Lvt),
+    (0xBD9C, 0xBD9C, G::Lv),
+    (0xBD9D, 0xBDB7, G::Lvt),
+    (0xBDB8, 0xBDB8, G::Lv),
+    (0xBDB9, 0xBDD3, G::Lvt),
+    (0xBDD4, 0xBDD4, G::Lv),
+    (0xBDD5, 0xBDEF, G::Lvt),
+    (0xBDF0, 0xBDF0, G::Lv),
+    (0xBDF1, 0xBE0B, G::Lvt),
+    (0xBE0C, 0xBE0C, G::Lv),
+    (0xBE0D, 0xBE27, G::Lvt),
+    (0xBE28, 0xBE28, G::Lv),
+    (0xBE29, 0xBE43, G::Lvt),
+    (0xBE44, 0xBE44, G::Lv),
+    (0xBE45, 0xBE5F, G::Lvt),
+    (0xBE60, 0xBE60, G::Lv),
+    (0xBE61, 0xBE7B, G::Lvt),
+    (0xBE7C, 0xBE7C, G::Lv),
+    (0xBE7D, 0xBE97, G::Lvt),
+    (0xBE98, 0xBE98, G::Lv),
+    (0xBE99, 0xBEB3, G::Lvt),
+    (0xBEB4, 0xBEB4, G::Lv),
+    (0xBEB5, 0xBECF, G::Lvt),
+    (0xBED0, 0xBED0, G::Lv),
+    (0xBED1, 0xBEEB, G::Lvt),
+    (0xBEEC, 0xBEEC, G::Lv),
+    (0xBEED, 0xBF07, G::Lvt),
+    (0xBF08, 0xBF08, G::Lv),
+    (0xBF09, 0xBF23, G::Lvt),
+    (0xBF24, 0xBF24, G::Lv),
+    (0xBF25, 0xBF3F, G::Lvt),
+    (0xBF40, 0xBF40, G::Lv),
+    (0xBF41, 0xBF5B, G::Lvt),
+    (0xBF5C, 0xBF5C, G::Lv),
+    (0xBF5D, 0xBF77, G::Lvt),
+    (0xBF78, 0xBF78, G::Lv),
+    (0xBF79, 0xBF93, G::Lvt),
+    (0xBF94, 0xBF94, G::Lv),
+    (0xBF95, 0xBFAF, G::Lvt),
+    (0xBFB0, 0xBFB0, G::Lv),
+    (0xBFB1, 0xBFCB, G::Lvt),
+    (0xBFCC, 0xBFCC, G::Lv),
+    (0xBFCD, 0xBFE7, G::Lvt),
+    (0xBFE8, 0xBFE8, G::Lv),
+    (0xBFE9, 0xC003, G::Lvt),
+    (0xC004, 0xC004, G::Lv),
+    (0xC005, 0xC01F, G::Lvt),
+    (0xC020, 0xC020, G::Lv),
+    (0xC021, 0xC03B, G::Lvt),
+    (0xC03C, 0xC03C, G::Lv),
+    (0xC03D, 0xC057, G::Lvt),
+    (0xC058, 0xC058, G::Lv),
+    (0xC059, 0xC073, G::Lvt),
+    (0xC074, 0xC074, G::Lv),
+    (0xC075, 0xC08F, G::Lvt),
+    (0xC090, 0xC090, G::Lv),
+    (0xC091, 0xC0AB, G::Lvt),
+    (0xC0AC, 0xC0AC, G::Lv),
+    (0xC0AD, 0xC0C7, G::Lvt),
+    (0xC0C8, 0xC0C8, G::Lv),
+    (0xC0C9, 0xC0E3, G::Lvt),
+    (0xC0E4, 0xC0E4, G::Lv),
+    (0xC0E5, 0xC0FF, G::Lvt),
+    (0xC100, 0xC100, G::Lv),
+    (0xC101, 0xC11B, G::Lvt),
+    (0xC11C, 0xC11C, G::Lv),
+    (0xC11D, 0xC137, G::Lvt),
+    (0xC138, 0xC138, G::Lv),
+    (0xC139, 0xC153, G::Lvt),
+    (0xC154, 0xC154, G::Lv),
+    (0xC155, 0xC16F, G::Lvt),
+    (0xC170, 0xC170, G::Lv),
+    (0xC171, 0xC18B, G::Lvt),
+    (0xC18C, 0xC18C, G::Lv),
+    (0xC18D, 0xC1A7, G::Lvt),
+    (0xC1A8, 0xC1A8, G::Lv),
+    (0xC1A9, 0xC1C3, G::Lvt),
+    (0xC1C4, 0xC1C4, G::Lv),
+    (0xC1C5, 0xC1DF, G::Lvt),
+    (0xC1E0, 0xC1E0, G::Lv),
+    (0xC1E1, 0xC1FB, G::Lvt),
+    (0xC1FC, 0xC1FC, G::Lv),
+    (0xC1FD, 0xC217, G::Lvt),
+    (0xC218, 0xC218, G::Lv),
+    (0xC219, 0xC233, G::Lvt),
+    (0xC234, 0xC234, G::Lv),
+    (0xC235, 0xC24F, G::Lvt),
+    (0xC250, 0xC250, G::Lv),
+    (0xC251, 0xC26B, G::Lvt),
+    (0xC26C, 0xC26C, G::Lv),
+    (0xC26D, 0xC287, G::Lvt),
+    (0xC288, 0xC288, G::Lv),
+    (0xC289, 0xC2A3, G::Lvt),
+    (0xC2A4, 0xC2A4, G::Lv),
+    (0xC2A5, 0xC2BF, G::Lvt),
+    (0xC2C0, 0xC2C0, G::Lv),
+    (0xC2C1, 0xC2DB, G::Lvt),
+    (0xC2DC, 0xC2DC, G::Lv),
+    (0xC2DD, 0xC2F7, G::Lvt),
+    (0xC2F8, 0xC2F8, G::Lv),
+    (0xC2F9, 0xC313, G::Lvt),
+    (0xC314, 0xC314, G::Lv),
+    (0xC315, 0xC32F, G::Lvt),
+    (0xC330, 0xC330, G::Lv),
+    (0xC331, 0xC34B, G::Lvt),
+    (0xC34C, 0xC34C, G::Lv),
+    (0xC34D, 0xC367, G::Lvt),
+    (0xC368, 0xC368, G::Lv),
+    (0xC369, 0xC383, G::Lvt),
+    (0xC384, 0xC384, G::Lv),
+    (0xC385, 0xC39F, G::Lvt),
+    (0xC3A0, 0xC3A0, G::Lv),
+    (0xC3A1, 0xC3BB, G::Lvt),
+    (0xC3BC, 0xC3BC, G::Lv),
+    (0xC3BD, 0xC3D7, G::Lvt),
+    (0xC3D8, 0xC3D8, G::Lv),
+    (0xC3D9, 0xC3F3, G::Lvt),
+    (0xC3F4, 0xC3F4, G::Lv),
+    (0xC3F5, 0xC40F, G::Lvt),
+    (0xC410, 0xC410, G::Lv),
+    (0xC411, 0xC42B, G::Lvt),
+    (0xC42C, 0xC42C, G::Lv),
+    (0xC42D, 0xC447, G::Lvt),
+    (0xC448, 0xC448, G::Lv),
+    (0xC449, 0xC463, G::Lvt),
+    (0xC464, 0xC464, G::Lv),
+    (0xC465, 0xC47F, G::Lvt),
+    (0xC480, 0xC480, G::Lv),
+    (0xC481, 0xC49B, G::Lvt),
+    (0xC49C, 0xC49C, G::Lv),
+    (0xC49D, 0xC4B7, G::Lvt),
+    (0xC4B8, 0xC4B8, G::Lv),
+    (0xC4B9, 0xC4D3, G::Lvt),
+    (0xC4D4, 0xC4D4, G::Lv),
+    (0xC4D5, 0xC4EF, G::Lvt),
+    (0xC4F0, 0xC4F0, G::Lv),
+    (0xC4F1, 0xC50B, G::Lvt),
+    (0xC50C, 0xC50C, G::Lv),
+    (0xC50D, 0xC527, G::Lvt),
+    (0xC528, 0xC528, G::Lv),
+    (0xC529, 0xC543, G::Lvt),
+    (0xC544, 0xC544, G::Lv),
+    (0xC545, 0xC55F, G::Lvt),
+    (0xC560, 0xC560, G::Lv),
+    (0xC561, 0xC57B, G::Lvt),
+    (0xC57C, 0xC57C, G::Lv),
+    (0xC57D, 0xC597, G::Lvt),
+    (0xC598, 0xC598, G::Lv),
+    (0xC599, 0xC5B3, G::Lvt),
+    (0xC5B4, 0xC5B4, G::Lv),
+    (0xC5B5, 0xC5CF, G::Lvt),
+    (0xC5D0, 0xC5D0, G::Lv),
+    (0xC5D1, 0xC5EB, G::Lvt),
+    (0xC5EC, 0xC5EC, G::Lv),
+    (0xC5ED, 0xC607, G::Lvt),
+    (0xC608, 0xC608, G::Lv),
+    (0xC609, 0xC623, G::Lvt),
+    (0xC624, 0xC624, G::Lv),
+    (0xC625, 0xC63F, G::Lvt),
+    (0xC640, 0xC640, G::Lv),
+    (0xC641, 0xC65B, G::Lvt),
+    (0xC65C, 0xC65C, G::Lv),
+    (0xC65D, 0xC677, G::Lvt),
+    (0xC678, 0xC678, G::Lv),
+    (0xC679, 0xC693, G::Lvt),
+    (0xC694, 0xC694, G::Lv),
+    (0xC695, 0xC6AF, G::Lvt),
+    (0xC6B0, 0xC6B0, G::Lv),
+    (0xC6B1, 0xC6CB, G::Lvt),
+    (0xC6CC, 0xC6CC, G::Lv),
+    (0xC6CD, 0xC6E7, G::Lvt),
+    (0xC6E8, 0xC6E8, G::Lv),
+    (0xC6E9, 0xC703, G::Lvt),
+    (0xC704, 0xC704, G::Lv),
+    (0xC705, 0xC71F, G::Lvt),
+    (0xC720, 0xC720, G::Lv),
+    (0xC721, 0xC73B, G::Lvt),
+    (0xC73C, 0xC73C, G::Lv),
+    (0xC73D, 0xC757, G::Lvt),
+    (0xC758, 0xC758, G::Lv),
+    (0xC759, 0xC773, G::Lvt),
+    (0xC774, 0xC774, G::Lv),
+    (0xC775, 0xC78F, G::Lvt),
+    (0xC790, 0xC790, G::Lv),
+    (0xC791, 0xC7AB, G::Lvt),
+    (0xC7AC, 0xC7AC, G::Lv),
+    (0xC7AD, 0xC7C7, G::Lvt),
+    (0xC7C8, 0xC7C8, G::Lv),
+    (0xC7C9, 0xC7E3, G::Lvt),
+    (0xC7E4, 0xC7E4, G::Lv),
+    (0xC7E5, 0xC7FF, G::Lvt),
+    (0xC800, 0xC800, G::Lv),
+    (0xC801, 0xC81B, G::Lvt),
+    (0xC81C, 0xC81C, G::Lv),
+    (0xC81D, 0xC837, G::Lvt),
+    (0xC838, 0xC838, G::Lv),
+    (0xC839, 0xC853, G::Lvt),
+    (0xC854, 0xC854, G::Lv),
+    (0xC855, 0xC86F, G::Lvt),
+    (0xC870, 0xC870, G::Lv),
+    (0xC871, 0xC88B, G::Lvt),
+    (0xC88C, 0xC88C, G::Lv),
+    (0xC88D, 0xC8A7, G::Lvt),
+    (0xC8A8, 0xC8A8, G::Lv),
+    (0xC8A9, 0xC8C3, G::Lvt),
+    (0xC8C4, 0xC8C4, G::Lv),
+    (0xC8C5, 0xC8DF, G::Lvt),
+    (0xC8E0, 0xC8E0, G::Lv),
+    (0xC8E1, 0xC8FB, G::Lvt),
+    (0xC8FC, 0xC8FC, G::Lv),
+    (0xC8FD, 0xC917, G::Lvt),
+    (0xC918, 0xC918, G::Lv),
+    (0xC919, 0xC933, G::Lvt),
+    (0xC934, 0xC934, G::Lv),
+    (0xC935, 0xC94F, G::Lvt),
+    (0xC950, 0xC950, G::Lv),
+    (0xC951, 0xC96B, G::Lvt),
+    (0xC96C, 0xC96C, G::Lv),
+    (0xC96D, 0xC987, G::Lvt),
+    (0xC988, 0xC988, G::Lv),
+    (0xC989, 0xC9A3, G::Lvt),
+    (0xC9A4, 0xC9A4, G::Lv),
+    (0xC9A5, 0xC9BF, G::Lvt),
+    (0xC9C0, 0xC9C0, G::Lv),
+    (0xC9C1, 0xC9DB, G::Lvt),
+    (0xC9DC, 0xC9DC, G::Lv),
+    (0xC9DD, 0xC9F7, G::Lvt),
+    (0xC9F8, 0xC9F8, G::Lv),
+    (0xC9F9, 0xCA13, G::Lvt),
+    (0xCA14, 0xCA14, G::Lv),
+    (0xCA15, 0xCA2F, G::Lvt),
+    (0xCA30, 0xCA30, G::Lv),
+    (0xCA31, 0xCA4B, G::Lvt),
+    (0xCA4C, 0xCA4C, G::Lv),
+    (0xCA4D, 0xCA67, G::Lvt),
+    (0xCA68, 0xCA68, G::Lv),
+    (0xCA69, 0xCA83, G::Lvt),
+    (0xCA84, 0xCA84, G::Lv),
+    (0xCA85, 0xCA9F, G::Lvt),
+    (0xCAA0, 0xCAA0, G::Lv),
+    (0xCAA1, 0xCABB, G::Lvt),
+    (0xCABC, 0xCABC, G::Lv),
+    (0xCABD, 0xCAD7, G::Lvt),
+    (0xCAD8, 0xCAD8, G::Lv),
+    (0xCAD9, 0xCAF3, G::Lvt),
+    (0xCAF4, 0xCAF4, G::Lv),
+    (0xCAF5, 0xCB0F, G::Lvt),
+    (0xCB10, 0xCB10, G::Lv),
+    (0xCB11, 0xCB2B, G::Lvt),
+    (0xCB2C, 0xCB2C, G::Lv),
+    (0xCB2D, 0xCB47, G::Lvt),
+    (0xCB48, 0xCB48, G::Lv),
+    (0xCB49, 0xCB63, G::Lvt),
+    (0xCB64, 0xCB64, G::Lv),
+    (0xCB65, 0xCB7F, G::Lvt),
+    (0xCB80, 0xCB80, G::Lv),
+    (0xCB81, 0xCB9B, G::Lvt),
+    (0xCB9C, 0xCB9C, G::Lv),
+    (0xCB9D, 0xCBB7, G::Lvt),
+    (0xCBB8, 0xCBB8, G::Lv),
+    (0xCBB9, 0xCBD3, G::Lvt),
+    (0xCBD4, 0xCBD4, G::Lv),
+    (0xCBD5, 0xCBEF, G::Lvt),
+    (0xCBF0, 0xCBF0, G::Lv),
+    (0xCBF1, 0xCC0B, G::Lvt),
+    (0xCC0C, 0xCC0C, G::Lv),
+    (0xCC0D, 0xCC27, G::Lvt),
+    (0xCC28, 0xCC28, G::Lv),
+    (0xCC29, 0xCC43, G::Lvt),
+    (0xCC44, 0xCC44, G::Lv),
+    (0xCC45, 0xCC5F, G::Lvt),
+    (0xCC60, 0xCC60, G::Lv),
+    (0xCC61, 0xCC7B, G::Lvt),
+    (0xCC7C, 0xCC7C, G::Lv),
+    (0xCC7D, 0xCC97, G::Lvt),
+    (0xCC98, 0xCC98, G::Lv),
+    (0xCC99, 0xCCB3, G::Lvt),
+    (0xCCB4, 0xCCB4, G::Lv),
+    (0xCCB5, 0xCCCF, G::Lvt),
+    (0xCCD0, 0xCCD0, G::Lv),
+    (0xCCD1, 0xCCEB, G::Lvt),
+    (0xCCEC, 0xCCEC, G::Lv),
+    (0xCCED, 0xCD07, G::Lvt),
+    (0xCD08, 0xCD08, G::Lv),
+    (0xCD09, 0xCD23, G::Lvt),
+    (0xCD24, 0xCD24, G::Lv),
+    (0xCD25, 0xCD3F, G::Lvt),
+    (0xCD40, 0xCD40, G::Lv),
+    (0xCD41, 0xCD5B, G::Lvt),
+    (0xCD5C, 0xCD5C, G::Lv),
+    (0xCD5D, 0xCD77, G::Lvt),
+    (0xCD78, 0xCD78, G::Lv),
+    (0xCD79, 0xCD93, G::Lvt),
+    (0xCD94, 0xCD94, G::Lv),
+    (0xCD95, 0xCDAF, G::Lvt),
+    (0xCDB0, 0xCDB0, G::Lv),
+    (0xCDB1, 0xCDCB, G::Lvt),
+    (0xCDCC, 0xCDCC, G::Lv),
+    (0xCDCD, 0xCDE7, G::Lvt),
+    (0xCDE8, 0xCDE8, G::Lv),
+    (0xCDE9, 0xCE03, G::Lvt),
+    (0xCE04, 0xCE04, G::Lv),
+    (0xCE05, 0xCE1F, G::Lvt),
+    (0xCE20, 0xCE20, G::Lv),
+    (0xCE21, 0xCE3B, G::Lvt),
+    (0xCE3C, 0xCE3C, G::Lv),
+    (0xCE3D, 0xCE57, G::Lvt),
+    (0xCE58, 0xCE58, G::Lv),
+    (0xCE59, 0xCE73, G::Lvt),
+    (0xCE74, 0xCE74, G::Lv),
+    (0xCE75, 0xCE8F, G::Lvt),
+    (0xCE90, 0xCE90, G::Lv),
+    (0xCE91, 0xCEAB, G::Lvt),
+    (0xCEAC, 0xCEAC, G::Lv),
+    (0xCEAD, 0xCEC7, G::Lvt),
+    (0xCEC8, 0xCEC8, G::Lv),
+    (0xCEC9, 0xCEE3, G::Lvt),
+    (0xCEE4, 0xCEE4, G::Lv),
+    (0xCEE5, 0xCEFF, G::Lvt),
+    (0xCF00, 0xCF00, G::Lv),
+    (0xCF01, 0xCF1B, G::Lvt),
+    (0xCF1C, 0xCF1C, G::Lv),
+    (0xCF1D, 0xCF37, G::Lvt),
+    (0xCF38, 0xCF38, G::Lv),
+    (0xCF39, 0xCF53, G::Lvt),
+    (0xCF54, 0xCF54, G::Lv),
+    (0xCF55, 0xCF6F, G::Lvt),
+    (0xCF70, 0xCF70, G::Lv),
+    (0xCF71, 0xCF8B, G::Lvt),
+    (0xCF8C, 0xCF8C, G::Lv),
+    (0xCF8D, 0xCFA7, G::Lvt),
+    (0xCFA8, 0xCFA8, G::Lv),
+    (0xCFA9, 0xCFC3, G::Lvt),
+    (0xCFC4, 0xCFC4, G::Lv),
+    (0xCFC5, 0xCFDF, G::Lvt),
+    (0xCFE0, 0xCFE0, G::Lv),
+    (0xCFE1, 0xCFFB, G::Lvt),
+    (0xCFFC, 0xCFFC, G::Lv),
+    (0xCFFD, 0xD017, G::Lvt),
+    (0xD018, 0xD018, G::Lv),
+    (0xD019, 0xD033, G::Lvt),
+    (0xD034, 0xD034, G::Lv),
+    (0xD035, 0xD04F, G::Lvt),
+    (0xD050, 0xD050, G::Lv),
+    (0xD051, 0xD06B, G::Lvt),
+    (0xD06C, 0xD06C, G::Lv),
+    (0xD06D, 0xD087, G::Lvt),
+    (0xD088, 0xD088, G::Lv),
+    (0xD089, 0xD0A3, G::Lvt),
+    (0xD0A4, 0xD0A4, G::Lv),
+    (0xD0A5, 0xD0BF, G::Lvt),
+    (0xD0C0, 0xD0C0, G::Lv),
+    (0xD0C1, 0xD0DB, G::Lvt),
+    (0xD0DC, 0xD0DC, G::Lv),
+    (0xD0DD, 0xD0F7, G::Lvt),
+    (0xD0F8, 0xD0F8, G::Lv),
+    (0xD0F9, 0xD113, G::Lvt),
+    (0xD114, 0xD114, G::Lv),
+    (0xD115, 0xD12F, G::Lvt),
+    (0xD130, 0xD130, G::Lv),
+    (0xD131, 0xD14B, G::Lvt),
+    (0xD14C, 0xD14C, G::Lv),
+    (0xD14D, 0xD167, G::Lvt),
+    (0xD168, 0xD168, G::Lv),
+    (0xD169, 0xD183, G::Lvt),
+    (0xD184, 0xD184, G::Lv),
+    (0xD185, 0xD19F, G::Lvt),
+    (0xD1A0, 0xD1A0, G::Lv),
+    (0xD1A1, 0xD1BB, G::Lvt),
+    (0xD1BC, 0xD1BC, G::Lv),
+    (0xD1BD, 0xD1D7, G::Lvt),
+    (0xD1D8, 0xD1D8, G::Lv),
+    (0xD1D9, 0xD1F3, G::Lvt),
+    (0xD1F4, 0xD1F4, G::Lv),
+    (0xD1F5, 0xD20F, G::Lvt),
+    (0xD210, 0xD210, G::Lv),
+    (0xD211, 0xD22B, G::Lvt),
+    (0xD22C, 0xD22C, G::Lv),
+    (0xD22D, 0xD247, G::Lvt),
+    (0xD248, 0xD248, G::Lv),
+    (0xD249, 0xD263, G::Lvt),
+    (0xD264, 0xD264, G::Lv),
+    (0xD265, 0xD27F, G::Lvt),
+    (0xD280, 0xD280, G::Lv),
+    (0xD281, 0xD29B, G::Lvt),
+    (0xD29C, 0xD29C, G::Lv),
+    (0xD29D, 0xD2B7, G::Lvt),
+    (0xD2B8, 0xD2B8, G::Lv),
+    (0xD2B9, 0xD2D3, G::Lvt),
+    (0xD2D4, 0xD2D4, G::Lv),
+    (0xD2D5, 0xD2EF, G::Lvt),
+    (0xD2F0, 0xD2F0, G::Lv),
+    (0xD2F1, 0xD30B, G::Lvt),
+    (0xD30C, 0xD30C, G::Lv),
+    (0xD30D, 0xD327, G::Lvt),
+    (0xD328, 0xD328, G::Lv),
+    (0xD329, 0xD343, G::Lvt),
+    (0xD344, 0xD344, G::Lv),
+    (0xD345, 0xD35F, G::Lvt),
+    (0xD360, 0xD360, G::Lv),
+    (0xD361, 0xD37B, G::Lvt),
+    (0xD37C, 0xD37C, G::Lv),
+    (0xD37D, 0xD397, G::Lvt),
+    (0xD398, 0xD398, G::Lv),
+    (0xD399, 0xD3B3, G::Lvt),
+    (0xD3B4, 0xD3B4, G::Lv),
+    (0xD3B5, 0xD3CF, G::Lvt),
+    (0xD3D0, 0xD3D0, G::Lv),
+    (0xD3D1, 0xD3EB, G::Lvt),
+    (0xD3EC, 0xD3EC, G::Lv),
+    (0xD3ED, 0xD407, G::Lvt),
+    (0xD408, 0xD408, G::Lv),
+    (0xD409, 0xD423, G::Lvt),
+    (0xD424, 0xD424, G::Lv),
+    (0xD425, 0xD43F, G::Lvt),
+    (0xD440, 0xD440, G::Lv),
+    (0xD441, 0xD45B, G::Lvt),
+    (0xD45C, 0xD45C, G::Lv),
+    (0xD45D, 0xD477, G::Lvt),
+    (0xD478, 0xD478, G::Lv),
+    (0xD479, 0xD493, G::Lvt),
+    (0xD494, 0xD494, G::Lv),
+    (0xD495, 0xD4AF, G::Lvt),
+    (0xD4B0, 0xD4B0, G::Lv),
+    (0xD4B1, 0xD4CB, G::Lvt),
+    (0xD4CC, 0xD4CC, G::Lv),
+    (0xD4CD, 0xD4E7, G::Lvt),
+    (0xD4E8, 0xD4E8, G::Lv),
+    (0xD4E9, 0xD503, G::Lvt),
+    (0xD504, 0xD504, G::Lv),
+    (0xD505, 0xD51F, G::Lvt),
+    (0xD520, 0xD520, G::Lv),
+    (0xD521, 0xD53B, G::Lvt),
+    (0xD53C, 0xD53C, G::Lv),
+    (0xD53D, 0xD557, G::Lvt),
+    (0xD558, 0xD558, G::Lv),
+    (0xD559, 0xD573, G::Lvt),
+    (0xD574, 0xD574, G::Lv),
+    (0xD575, 0xD58F, G::Lvt),
+    (0xD590, 0xD590, G::Lv),
+    (0xD591, 0xD5AB, G::Lvt),
+    (0xD5AC, 0xD5AC, G::Lv),
+    (0xD5AD, 0xD5C7, G::Lvt),
+    (0xD5C8, 0xD5C8, G::Lv),
+    (0xD5C9, 0xD5E3, G::Lvt),
+    (0xD5E4, 0xD5E4, G::Lv),
+    (0xD5E5, 0xD5FF, G::Lvt),
+    (0xD600, 0xD600, G::Lv),
+    (0xD601, 0xD61B, G::Lvt),
+    (0xD61C, 0xD61C, G::Lv),
+    (0xD61D, 0xD637, G::Lvt),
+    (0xD638, 0xD638, G::Lv),
+    (0xD639, 0xD653, G::Lvt),
+    (0xD654, 0xD654, G::Lv),
+    (0xD655, 0xD66F, G::Lvt),
+    (0xD670, 0xD670, G::Lv),
+    (0xD671, 0xD68B, G::Lvt),
+    (0xD68C, 0xD68C, G::Lv),
+    (0xD68D, 0xD6A7, G::Lvt),
+    (0xD6A8, 0xD6A8, G::Lv),
+    (0xD6A9, 0xD6C3, G::Lvt),
+    (0xD6C4, 0xD6C4, G::Lv),
+    (0xD6C5, 0xD6DF, G::Lvt),
+    (0xD6E0, 0xD6E0, G::Lv),
+    (0xD6E1, 0xD6FB, G::Lvt),
+    (0xD6FC, 0xD6FC, G::Lv),
+    (0xD6FD, 0xD717, G::Lvt),
+    (0xD718, 0xD718, G::Lv),
+    (0xD719, 0xD733, G::Lvt),
+    (0xD734, 0xD734, G::Lv),
+    (0xD735, 0xD74F, G::Lvt),
+    (0xD750, 0xD750, G::Lv),
+    (0xD751, 0xD76B, G::Lvt),
+    (0xD76C, 0xD76C, G::Lv),
+    (0xD76D, 0xD787, G::Lvt),
+    (0xD788, 0xD788, G::Lv),
+    (0xD789, 0xD7A3, G::Lvt),
+    (0xD7B0, 0xD7C6, G::V),
+    (0xD7CB, 0xD7FB, G::T),
+    (0xFB1E, 0xFB1E, G::Extend),
+    (0xFE00, 0xFE0F, G::Extend),
+    (0xFE20, 0xFE2F, G::Extend),
+    (0xFEFF, 0xFEFF, G::Control),
+    (0xFF9E, 0xFF9F, G::Extend),
+    (0xFFF0, 0xFFFB, G::Control),
+    (0x101FD, 0x101FD, G::Extend),
+    (0x102E0, 0x102E0, G::Extend),
+    (0x10376, 0x1037A, G::Extend),
+    (0x10A01, 0x10A03, G::Extend),
+    (0x10A05, 0x10A06, G::Extend),
+    (0x10A0C, 0x10A0F, G::Extend),
+    (0x10A38, 0x10A3A, G::Extend),
+    (0x10A3F, 0x10A3F, G::Extend),
+    (0x10AE5, 0x10AE6, G::Extend),
+    (0x10D24, 0x10D27, G::Extend),
+    (0x10EAB, 0x10EAC, G::Extend),
+    (0x10EFD, 0x10EFF, G::Extend),
+    (0x10F46, 0x10F50, G::Extend),
+    (0x10F82, 0x10F85, G::Extend),
+    (0x11000, 0x11000, G::SpacingMark),
+    (0x11001, 0x11001, G::Extend),
+    (0x11002, 0x11002, G::SpacingMark),
+    (0x11038, 0x11046, G::Extend),
+    (0x11070, 0x11070, G::Extend),
+    (0x11073, 0x11074, G::Extend),
+    (0x1107F, 0x11081, G::Extend),
+    (0x11082, 0x11082, G::SpacingMark),
+    (0x110B0, 0x110B2, G::SpacingMark),
+    (0x110B3, 0x110B6, G::Extend),
+    (0x110B7, 0x110B8, G::SpacingMark),
+    (0x110B9, 0x110BA, G::Extend),
+    (0x110BD, 0x110BD, G::Prepend),
+    (0x110C2, 0x110C2, G::Extend),
+    (0x110CD, 0x110CD, G::Prepend),
+    (0x11100, 0x11102, G::Extend),
+    (0x11127, 0x1112B, G::Extend),
+    (0x1112C, 0x1112C, G::SpacingMark),
+    (0x1112D, 0x11134, G::Extend),
+    (0x11145, 0x11146, G::SpacingMark),
+    (0x11173, 0x11173, G::Extend),
+    (0x11180, 0x11181, G::Extend),
+    (0x11182, 0x11182, G::SpacingMark),
+    (0x111B3, 0x111B5, G::SpacingMark),
+    (0x111B6, 0x111BE, G::Extend),
+    (0x111BF, 0x111C0, G::SpacingMark),
+    (0x111C2, 0x111C3, G::Prepend),
+    (0x111C9, 0x111CC, G::Extend),
+    (0x111CE, 0x111CE, G::SpacingMark),
+    (0x111CF, 0x111CF, G::Extend),
+    (0x1122C, 0x1122E, G::SpacingMark),
+    (0x1122F, 0x11231, G::Extend),
+    (0x11232, 0x11233, G::SpacingMark),
+    (0x11234, 0x11234, G::Extend),
+    (0x11235, 0x11235, G::SpacingMark),
+    (0x11236, 0x11237, G::Extend),
+    (0x1123E, 0x1123E, G::Extend),
+    (0x11241, 0x11241, G::Extend),
+    (0x112DF, 0x112DF, G::Extend),
+    (0x112E0, 0x112E2, G::SpacingMark),
+    (0x112E3, 0x112EA, G::Extend),
+    (0x11300, 0x11301, G::Extend),
+    (0x11302, 0x11303, G::SpacingMark),
+    (0x1133B, 0x1133C, G::Extend),
+    (0x1133E, 0x1133E, G::Extend),
+    (0x1133F, 0x1133F, G::SpacingMark),
+    (0x11340, 0x11340, G::Extend),
+    (0x11341, 0x11344, G::SpacingMark),
+    (0x11347, 0x11348, G::SpacingMark),
+    (0x1134B, 0x1134D, G::SpacingMark),
+    (0x11357, 0x11357, G::Extend),
+    (0x11362, 0x11363, G::SpacingMark),
+    (0x11366, 0x1136C, G::Extend),
+    (0x11370, 0x11374, G::Extend),
+    (0x11435, 0x11437, G::SpacingMark),
+    (0x11438, 0x1143F, G::Extend),
+    (0x11440, 0x11441, G::SpacingMark),
+    (0x11442, 0x11444, G::Extend),
+    (0x11445, 0x11445, G::SpacingMark),
+    (0x11446, 0x11446, G::Extend),
+    (0x1145E, 0x1145E, G::Extend),
+    (0x114B0, 0x114B0, G::Extend),
+    (0x114B1, 0x114B2, G::SpacingMark),
+    (0x114B3, 0x114B8, G::Extend),
+    (0x114B9, 0x114B9, G::SpacingMark),
+    (0x114BA, 0x114BA, G::Extend),
+    (0x114BB, 0x114BC, G::SpacingMark),
+    (0x114BD, 0x114BD, G::Extend),
+    (0x114BE, 0x114BE, G::SpacingMark),
+    (0x114BF, 0x114C0, G::Extend),
+    (0x114C1, 0x114C1, G::SpacingMark),
+    (0x114C2, 0x114C3, G::Extend),
+    (0x115AF, 0x115AF, G::Extend),
+    (0x115B0, 0x115B1, G::SpacingMark),
+    (0x115B2, 0x115B5, G::Extend),
+    (0x115B8, 0x115BB, G::SpacingMark),
+    (0x115BC, 0x115BD, G::Extend),
+    (0x115BE, 0x115BE, G::SpacingMark),
+    (0x115BF, 0x115C0, G::Extend),
+    (0x115DC, 0x115DD, G::Extend),
+    (0x11630, 0x11632, G::SpacingMark),
+    (0x11633, 0x1163A, G::Extend),
+    (0x1163B, 0x1163C, G::SpacingMark),
+    (0x1163D, 0x1163D, G::Extend),
+    (0x1163E, 0x1163E, G::SpacingMark),
+    (0x1163F, 0x11640, G::Extend),
+    (0x116AB, 0x116AB, G::Extend),
+    (0x116AC, 0x116AC, G::SpacingMark),
+    (0x116AD, 0x116AD, G::Extend),
+    (0x116AE, 0x116AF, G::SpacingMark),
+    (0x116B0, 0x116B5, G::Extend),
+    (0x116B6, 0x116B6, G::SpacingMark),
+    (0x116B7, 0x116B7, G::Extend),
+    (0x1171D, 0x1171F, G::Extend),
+    (0x11722, 0x11725, G::Extend),
+    (0x11726, 0x11726, G::SpacingMark),
+    (0x11727, 0x1172B, G::Extend),
+    (0x1182C, 0x1182E, G::SpacingMark),
+    (0x1182F, 0x11837, G::Extend),
+    (0x11838, 0x11838, G::SpacingMark),
+    (0x11839, 0x1183A, G::Extend),
+    (0x11930, 0x11930, G::Extend),
+    (0x11931, 0x11935, G::SpacingMark),
+    (0x11937, 0x11938, G::SpacingMark),
+    (0x1193B, 0x1193C, G::Extend),
+    (0x1193D, 0x1193D, G::SpacingMark),
+    (0x1193E, 0x1193E, G::Extend),
+    (0x1193F, 0x1193F, G::Prepend),
+    (0x11940, 0x11940, G::SpacingMark),
+    (0x11941, 0x11941, G::Prepend),
+    (0x11942, 0x11942, G::SpacingMark),
+    (0x11943, 0x11943, G::Extend),
+    (0x119D1, 0x119D3, G::SpacingMark),
+    (0x119D4, 0x119D7, G::Extend),
+    (0x119DA, 0x119DB, G::Extend),
+    (0x119DC, 0x119DF, G::SpacingMark),
+    (0x119E0, 0x119E0, G::Extend),
+    (0x119E4, 0x119E4, G::SpacingMark),
+    (0x11A01, 0x11A0A, G::Extend),
+    (0x11A33, 0x11A38, G::Extend),
+    (0x11A39, 0x11A39, G::SpacingMark),
+    (0x11A3A, 0x11A3A, G::Prepend),
+    (0x11A3B, 0x11A3E, G::Extend),
+    (0x11A47, 0x11A47, G::Extend),
+    (0x11A51, 0x11A56, G::Extend),
+    (0x11A57, 0x11A58, G::SpacingMark),
+    (0x11A59, 0x11A5B, G::Extend),
+    (0x11A84, 0x11A89, G::Prepend),
+    (0x11A8A, 0x11A96, G::Extend),
+    (0x11A97, 0x11A97, G::SpacingMark),
+    (0x11A98, 0x11A99, G::Extend),
+    (0x11C2F, 0x11C2F, G::SpacingMark),
+    (0x11C30, 0x11C36, G::Extend),
+    (0x11C38, 0x11C3D, G::Extend),
+    (0x11C3E, 0x11C3E, G::SpacingMark),
+    (0x11C3F, 0x11C3F, G::Extend),
+    (0x11C92, 0x11CA7, G::Extend),
+    (0x11CA9, 0x11CA9, G::SpacingMark),
+    (0x11CAA, 0x11CB0, G::Extend),
+    (0x11CB1, 0x11CB1, G::SpacingMark),
+    (0x11CB2, 0x11CB3, G::Extend),
+    (0x11CB4, 0x11CB4, G::SpacingMark),
+    (0x11CB5, 0x11CB6, G::Extend),
+    (0x11D31, 0x11D36, G::Extend),
+    (0x11D3A, 0x11D3A, G::Extend),
+    (0x11D3C, 0x11D3D, G::Extend),
+    (0x11D3F, 0x11D45, G::Extend),
+    (0x11D46, 0x11D46, G::Prepend),
+    (0x11D47, 0x11D47, G::Extend),
+    (0x11D8A, 0x11D8E, G::SpacingMark),
+    (0x11D90, 0x11D91, G::Extend),
+    (0x11D93, 0x11D94, G::SpacingMark),
+    (0x11D95, 0x11D95, G::Extend),
+    (0x11D96, 0x11D96, G::SpacingMark),
+    (0x11D97, 0x11D97, G::Extend),
+    (0x11EF3, 0x11EF4, G::Extend),
+    (0x11EF5, 0x11EF6, G::SpacingMark),
+    (0x11F00, 0x11F01, G::Extend),
+    (0x11F02, 0x11F02, G::Prepend),
+    (0x11F03, 0x11F03, G::SpacingMark),
+    (0x11F34, 0x11F35, G::SpacingMark),
+    (0x11F36, 0x11F3A, G::Extend),
+    (0x11F3E, 0x11F3F, G::SpacingMark),
+    (0x11F40, 0x11F40, G::Extend),
+    (0x11F41, 0x11F41, G::SpacingMark),
+    (0x11F42, 0x11F42, G::Extend),
+    (0x13430, 0x1343F, G::Control),
+    (0x13440, 0x13440, G::Extend),
+    (0x13447, 0x13455, G::Extend),
+    (0x16AF0, 0x16AF4, G::Extend),
+    (0x16B30, 0x16B36, G::Extend),
+    (0x16F4F, 0x16F4F, G::Extend),
+    (0x16F51, 0x16F87, G::SpacingMark),
+    (0x16F8F, 0x16F92, G::Extend),
+    (0x16FE4, 0x16FE4, G::Extend),
+    (0x16FF0, 0x16FF1, G::SpacingMark),
+    (0x1BC9D, 0x1BC9E, G::Extend),
+    (0x1BCA0, 0x1BCA3, G::Control),
+    (0x1CF00, 0x1CF2D, G::Extend),
+    (0x1CF30, 0x1CF46, G::Extend),
+    (0x1D165, 0x1D165, G::Extend),
+    (0x1D166, 0x1D166, G::SpacingMark),
+    (0x1D167, 0x1D169, G::Extend),
+    (0x1D16D, 0x1D16D, G::SpacingMark),
+    (0x1D16E, 0x1D172, G::Extend),
+    (0x1D173, 0x1D17A, G::Control),
+    (0x1D17B, 0x1D182, G::Extend),
+    (0x1D185, 0x1D18B, G::Extend),
+    (0x1D1AA, 0x1D1AD, G::Extend),
+    (0x1D242, 0x1D244, G::Extend),
+    (0x1DA00, 0x1DA36, G::Extend),
+    (0x1DA3B, 0x1DA6C, G::Extend),
+    (0x1DA75, 0x1DA75, G::Extend),
+    (0x1DA84, 0x1DA84, G::Extend),
+    (0x1DA9B, 0x1DA9F, G::Extend),
+    (0x1DAA1, 0x1DAAF, G::Extend),
+    (0x1E000, 0x1E006, G::Extend),
+    (0x1E008, 0x1E018, G::Extend),
+    (0x1E01B, 0x1E021, G::Extend),
+    (0x1E023, 0x1E024, G::Extend),
+    (0x1E026, 0x1E02A, G::Extend),
+    (0x1E08F, 0x1E08F, G::Extend),
+    (0x1E130, 0x1E136, G::Extend),
+    (0x1E2AE, 0x1E2AE, G::Extend),
+    (0x1E2EC, 0x1E2EF, G::Extend),
+    (0x1E4EC, 0x1E4EF, G::Extend),
+    (0x1E8D0, 0x1E8D6, G::Extend),
+    (0x1E944, 0x1E94A, G::Extend),
+    (0x1F000, 0x1F0FF, G::ExtendedPictographic),
+    (0x1F10D, 0x1F10F, G::ExtendedPictographic),
+    (0x1F12F, 0x1F12F, G::ExtendedPictographic),
+    (0x1F16C, 0x1F171, G::ExtendedPictographic),
+    (0x1F17E, 0x1F17F, G::ExtendedPictographic),
+    (0x1F18E, 0x1F18E, G::ExtendedPictographic),
+    (0x1F191, 0x1F19A, G::ExtendedPictographic),
+    (0x1F1AD, 0x1F1E5, G::ExtendedPictographic),
+    (0x1F1E6, 0x1F1FF, G::RegionalIndicator),
+    (0x1F201, 0x1F20F, G::ExtendedPictographic),
+    (0x1F21A, 0x1F21A, G::ExtendedPictographic),
+    (0x1F22F, 0x1F22F, G::ExtendedPictographic),
+    (0x1F232, 0x1F23A, G::ExtendedPictographic),
+    (0x1F23C, 0x1F23F, G::ExtendedPictographic),
+    (0x1F249, 0x1F3FA, G::ExtendedPictographic),
+    (0x1F3FB, 0x1F3FF, G::Extend),
+    (0x1F400, 0x1F53D, G::ExtendedPictographic),
+    (0x1F546, 0x1F64F, G::ExtendedPictographic),
+    (0x1F680, 0x1F6FF, G::ExtendedPictographic),
+    (0x1F774, 0x1F77F, G::ExtendedPictographic),
+    (0x1F7D5, 0x1F7FF, G::ExtendedPictographic),
+    (0x1F80C, 0x1F80F, G::ExtendedPictographic),
+    (0x1F848, 0x1F84F, G::ExtendedPictographic),
+    (0x1F85A, 0x1F85F, G::ExtendedPictographic),
+    (0x1F888, 0x1F88F, G::ExtendedPictographic),
+    (0x1F8AE, 0x1F8FF, G::ExtendedPictographic),
+    (0x1F90C, 0x1F93A, G::ExtendedPictographic),
+    (0x1F93C, 0x1F945, G::ExtendedPictographic),
+    (0x1F947, 0x1FAFF, G::ExtendedPictographic),
+    (0x1FC00, 0x1FFFD, G::ExtendedPictographic),
+    (0xE0000, 0xE001F, G::Control),
+    (0xE0020, 0xE007F, G::Extend),
+    (0xE0080, 0xE00FF, G::Control),
+    (0xE0100, 0xE01EF, G::Extend),
+    (0xE01F0, 0xE0FFF, G::Control),
+];
+
+/// Ranges of characters with a Word_Break other than Other, and their category
+pub(super) static WORD_CAT: [(u32, u32, W); 1053] = [
+    (0x000A, 0x000A, W::Lf),
+    (0x000B, 0x000C, W::Newline),
+    (0x000D, 0x000D, W::Cr),
+    (0x0020, 0x0020, W::WSegSpace),
+    (0x0022, 0x0022, W::DoubleQuote),
+    (0x0027, 0x0027, W::SingleQuote),
+    (0x002C, 0x002C, W::MidNum),
+    (0x002E, 0x002E, W::MidNumLet),
+    (0x0030, 0x0039, W::Numeric),
+    (0x003A, 0x003A, W::MidLetter),
+    (0x003B, 0x003B, W::MidNum),
+    (0x0041, 0x005A, W::ALetter),
+    (0x005F, 0x005F, W::ExtendNumLet),
+    (0x0061, 0x007A, W::ALetter),
+    (0x0085, 0x0085, W::Newline),
+    (0x00AA, 0x00AA, W::ALetter),
+    (0x00AD, 0x00AD, W::Format),
+    (0x00B5, 0x00B5, W::ALetter),
+    (0x00B7, 0x00B7, W::MidLetter),
+    (0x00BA, 0x00BA, W::ALetter),
+    (0x00C0, 0x00D6, W::ALetter),
+    (0x00D8, 0x00F6, W::ALetter),
+    (0x00F8, 0x02D7, W::ALetter),
+    (0x02DE, 0x02FF, W::ALetter),
+    (0x0300, 0x036F, W::Extend),
+    (0x0370, 0x0374, W::ALetter),
+    (0x0376, 0x0377, W::ALetter),
+    (0x037A, 0x037D, W::ALetter),
+    (0x037E, 0x037E, W::MidNum),
+    (0x037F, 0x037F, W::ALetter),
+    (0x0386, 0x0386, W::ALetter),
+    (0x0387, 0x0387, W::MidLetter),
+    (0x0388, 0x038A, W::ALetter),
+    (0x038C, 0x038C, W::ALetter),
+    (0x038E, 0x03A1, W::ALetter),
+    (0x03A3, 0x03F5, W::ALetter),
+    (0x03F7, 0x0481, W::ALetter),
+    (0x0483, 0x0489, W::Extend),
+    (0x048A, 0x052F, W::ALetter),
+    (0x0531, 0x0556, W::ALetter),
+    (0x0559, 0x055C, W::ALetter),
+    (0x055E, 0x055E, W::ALetter),
+    (0x055F, 0x055F, W::MidLetter),
+    (0x0560, 0x0588, W::ALetter),
+    (0x0589, 0x0589, W::MidNum),
+    (0x058A, 0x058A, W::ALetter),
+    (0x0591, 0x05BD, W::Extend),
+    (0x05BF, 0x05BF, W::Extend),
+    (0x05C1, 0x05C2, W::Extend),
+    (0x05C4, 0x05C5, W::Extend),
+    (0x05C7, 0x05C7, W::Extend),
+    (0x05D0, 0x05EA, W::HebrewLetter),
+    (0x05EF, 0x05F2, W::HebrewLetter),
+    (0x05F3, 0x05F3, W::ALetter),
+    (0x05F4, 0x05F4, W::MidLetter),
+    (0x0600, 0x0605, W::Format),
+    (0x060C, 0x060D, W::MidNum),
+    (0x0610, 0x061A, W::Extend),
+    (0x061C, 0x061C, W::Format),
+    (0x0620, 0x064A, W::ALetter),
+    (0x064B, 0x065F, W::Extend),
+    (0x0660, 0x0669, W::Numeric),
+    (0x066B, 0x066B, W::Numeric),
+    (0x066C, 0x066C, W::MidNum),
+    (0x066E, 0x066F, W::ALetter),
+    (0x0670, 0x0670, W::Extend),
+    (0x0671, 0x06D3, W::ALetter),
+    (0x06D5, 0x06D5, W::ALetter),
+    (0x06D6, 0x06DC, W::Extend),
+    (0x06DD, 0x06DD, W::Format),
+    (0x06DF, 0x06E4, W::Extend),
+    (0x06E5, 0x06E6, W::ALetter),
+    (0x06E7, 0x06E8, W::Extend),
+    (0x06EA, 0x06ED, W::Extend),
+    (0x06EE, 0x06EF, W::ALetter),
+    (0x06F0, 0x06F9, W::Numeric),
+    (0x06FA, 0x06FC, W::ALetter),
+    (0x06FF, 0x06FF, W::ALetter),
+    (0x070F, 0x070F, W::Format),
+    (0x0710, 0x0710, W::ALetter),
+    (0x0711, 0x0711, W::Extend),
+    (0x0712, 0x072F, W::ALetter),
+    (0x0730, 0x074A, W::Extend),
+    (0x074D, 0x07A5, W::ALetter),
+    (0x07A6, 0x07B0, W::Extend),
+    (0x07B1, 0x07B1, W::ALetter),
+    (0x07C0, 0x07C9, W::Numeric),
+    (0x07CA, 0x07EA, W::ALetter),
+    (0x07EB, 0x07F3, W::Extend),
+    (0x07F4, 0x07F5, W::ALetter),
+    (0x07F8, 0x07F8, W::MidNum),
+    (0x07FA, 0x07FA, W::ALetter),
+    (0x07FD, 0x07FD, W::Extend),
+    (0x0800, 0x0815, W::ALetter),
+    (0x0816, 0x0819, W::Extend),
+    (0x081A, 0x081A, W::ALetter),
+    (0x081B, 0x0823, W::Extend),
+    (0x0824, 0x0824, W::ALetter),
+    (0x0825, 0x0827, W::Extend),
+    (0x0828, 0x0828, W::ALetter),
+    (0x0829, 0x082D, W::Extend),
+    (0x0840, 0x0858, W::ALetter),
+    (0x0859, 0x085B, W::Extend),
+    (0x0860, 0x086A, W::ALetter),
+    (0x0870, 0x0887, W::ALetter),
+    (0x0889, 0x088E, W::ALetter),
+    (0x0890, 0x0891, W::Format),
+    (0x0898, 0x089F, W::Extend),
+    (0x08A0, 0x08C9, W::ALetter),
+    (0x08CA, 0x08E1, W::Extend),
+    (0x08E2, 0x08E2, W::Format),
+    (0x08E3, 0x0903, W::Extend),
+    (0x0904, 0x0939, W::ALetter),
+    (0x093A, 0x093C, W::Extend),
+    (0x093D, 0x093D, W::ALetter),
+    (0x093E, 0x094F, W::Extend),
+    (0x0950, 0x0950, W::ALetter),
+    (0x0951, 0x0957, W::Extend),
+    (0x0958, 0x0961, W::ALetter),
+    (0x0962, 0x0963, W::Extend),
+    (0x0966, 0x096F, W::Numeric),
+    (0x0971, 0x0980, W::ALetter),
+    (0x0981, 0x0983, W::Extend),
+    (0x0985, 0x098C, W::ALetter),
+    (0x098F, 0x0990, W::ALetter),
+    (0x0993, 0x09A8, W::ALetter),
+    (0x09AA, 0x09B0, W::ALetter),
+    (0x09B2, 0x09B2, W::ALetter),
+    (0x09B6, 0x09B9, W::ALetter),
+    (0x09BC, 0x09BC, W::Extend),
+    (0x09BD, 0x09BD, W::ALetter),
+    (0x09BE, 0x09C4, W::Extend),
+    (0x09C7, 0x09C8, W::Extend),
+    (0x09CB, 0x09CD, W::Extend),
+    (0x09CE, 0x09CE, W::ALetter),
+    (0x09D7, 0x09D7, W::Extend),
+    (0x09DC, 0x09DD, W::ALetter),
+    (0x09DF, 0x09E1, W::ALetter),
+    (0x09E2, 0x09E3, W::Extend),
+    (0x09E6, 0x09EF, W::Numeric),
+    (0x09F0, 0x09F1, W::ALetter),
+    (0x09FC, 0x09FC, W::ALetter),
+    (0x09FE, 0x09FE, W::Extend),
+    (0x0A01, 0x0A03, W::Extend),
+    (0x0A05, 0x0A0A, W::ALetter),
+    (0x0A0F, 0x0A10, W::ALetter),
+    (0x0A13, 0x0A28, W::ALetter),
+    (0x0A2A, 0x0A30, W::ALetter),
+    (0x0A32, 0x0A33, W::ALetter),
+    (0x0A35, 0x0A36, W::ALetter),
+    (0x0A38, 0x0A39, W::ALetter),
+    (0x0A3C, 0x0A3C, W::Extend),
+    (0x0A3E, 0x0A42, W::Extend),
+    (0x0A47, 0x0A48, W::Extend),
+    (0x0A4B, 0x0A4D, W::Extend),
+    (0x0A51, 0x0A51, W::Extend),
+    (0x0A59, 0x0A5C, W::ALetter),
+    (0x0A5E, 0x0A5E, W::ALetter),
+    (0x0A66, 0x0A6F, W::Numeric),
+    (0x0A70, 0x0A71, W::Extend),
+    (0x0A72, 0x0A74, W::ALetter),
+    (0x0A75, 0x0A75, W::Extend),
+    (0x0A81, 0x0A83, W::Extend),
+    (0x0A85, 0x0A8D, W::ALetter),
+    (0x0A8F, 0x0A91, W::ALetter),
+    (0x0A93, 0x0AA8, W::ALetter),
+    (0x0AAA, 0x0AB0, W::ALetter),
+    (0x0AB2, 0x0AB3, W::ALetter),
+    (0x0AB5, 0x0AB9, W::ALetter),
+    (0x0ABC, 0x0ABC, W::Extend),
+    (0x0ABD, 0x0ABD, W::ALetter),
+    (0x0ABE, 0x0AC5, W::Extend),
+    (0x0AC7, 0x0AC9, W::Extend),
+    (0x0ACB, 0x0ACD, W::Extend),
+    (0x0AD0, 0x0AD0, W::ALetter),
+    (0x0AE0, 0x0AE1, W::ALetter),
+    (0x0AE2, 0x0AE3, W::Extend),
+    (0x0AE6, 0x0AEF, W::Numeric),
+    (0x0AF9, 0x0AF9, W::ALetter),
+    (0x0AFA, 0x0AFF, W::Extend),
+    (0x0B01, 0x0B03, W::Extend),
+    (0x0B05, 0x0B0C, W::ALetter),
+    (0x0B0F, 0x0B10, W::ALetter),
+    (0x0B13, 0x0B28, W::ALetter),
+    (0x0B2A, 0x0B30, W::ALetter),
+    (0x0B32, 0x0B33, W::ALetter),
+    (0x0B35, 0x0B39, W::ALetter),
+    (0x0B3C, 0x0B3C, W::Extend),
+    (0x0B3D, 0x0B3D, W::ALetter),
+    (0x0B3E, 0x0B44, W::Extend),
+    (0x0B47, 0x0B48, W::Extend),
+    (0x0B4B, 0x0B4D, W::Extend),
+    (0x0B55, 0x0B57, W::Extend),
+    (0x0B5C, 0x0B5D, W::ALetter),
+    (0x0B5F, 0x0B61, W::ALetter),
+    (0x0B62, 0x0B63, W::Extend),
+    (0x0B66, 0x0B6F, W::Numeric),
+    (0x0B71, 0x0B71, W::ALetter),
+    (0x0B82, 0x0B82, W::Extend),
+    (0x0B83, 0x0B83, W::ALetter),
+    (0x0B85, 0x0B8A, W::ALetter),
+    (0x0B8E, 0x0B90, W::ALetter),
+    (0x0B92, 0x0B95, W::ALetter),
+    (0x0B99, 0x0B9A, W::ALetter),
+    (0x0B9C, 0x0B9C, W::ALetter),
+    (0x0B9E, 0x0B9F, W::ALetter),
+    (0x0BA3, 0x0BA4, W::ALetter),
+    (0x0BA8, 0x0BAA, W::ALetter),
+    (0x0BAE, 0x0BB9, W::ALetter),
+    (0x0BBE, 0x0BC2, W::Extend),
+    (0x0BC6, 0x0BC8, W::Extend),
+    (0x0BCA, 0x0BCD, W::Extend),
+    (0x0BD0, 0x0BD0, W::ALetter),
+    (0x0BD7, 0x0BD7, W::Extend),
+    (0x0BE6, 0x0BEF, W::Numeric),
+    (0x0C00, 0x0C04, W::Extend),
+    (0x0C05, 0x0C0C, W::ALetter),
+    (0x0C0E, 0x0C10, W::ALetter),
+    (0x0C12, 0x0C28, W::ALetter),
+    (0x0C2A, 0x0C39, W::ALetter),
+    (0x0C3C, 0x0C3C, W::Extend),
+    (0x0C3D, 0x0C3D, W::ALetter),
+    (0x0C3E, 0x0C44, W::Extend),
+    (0x0C46, 0x0C48, W::Extend),
+    (0x0C4A, 0x0C4D, W::Extend),
+    (0x0C55, 0x0C56, W::Extend),
+    (0x0C58, 0x0C5A, W::ALetter),
+    (0x0C5D, 0x0C5D, W::ALetter),
+    (0x0C60, 0x0C61, W::ALetter),
+    (0x0C62, 0x0C63, W::Extend),
+    (0x0C66, 0x0C6F, W::Numeric),
+    (0x0C80, 0x0C80, W::ALetter),
+    (0x0C81, 0x0C83, W::Extend),
+    (0x0C85, 0x0C8C, W::ALetter),
+    (0x0C8E, 0x0C90, W::ALetter),
+    (0x0C92, 0x0CA8, W::ALetter),
+    (0x0CAA, 0x0CB3, W::ALetter),
+    (0x0CB5, 0x0CB9, W::ALetter),
+    (0x0CBC, 0x0CBC, W::Extend),
+    (0x0CBD, 0x0CBD, W::ALetter),
+    (0x0CBE, 0x0CC4, W::Extend),
+    (0x0CC6, 0x0CC8, W::Extend),
+    (0x0CCA, 0x0CCD, W::Extend),
+    (0x0CD5, 0x0CD6, W::Extend),
+    (0x0CDD, 0x0CDE, W::ALetter),
+    (0x0CE0, 0x0CE1, W::ALetter),
+    (0x0CE2, 0x0CE3, W::Extend),
+    (0x0CE6, 0x0CEF, W::Numeric),
+    (0x0CF1, 0x0CF2, W::ALetter),
+    (0x0CF3, 0x0CF3, W::Extend),
+    (0x0D00, 0x0D03, W::Extend),
+    (0x0D04, 0x0D0C, W::ALetter),
+    (0x0D0E, 0x0D10, W::ALetter),
+    (0x0D12, 0x0D3A, W::ALetter),
+    (0x0D3B, 0x0D3C, W::Extend),
+    (0x0D3D, 0x0D3D, W::ALetter),
+    (0x0D3E, 0x0D44, W::Extend),
+    (0x0D46, 0x0D48, W::Extend),
+    (0x0D4A, 0x0D4D, W::Extend),
+    (0x0D4E, 0x0D4E, W::ALetter),
+    (0x0D54, 0x0D56, W::ALetter),
+    (0x0D57, 0x0D57, W::Extend),
+    (0x0D5F, 0x0D61, W::ALetter),
+    (0x0D62, 0x0D63, W::Extend),
+    (0x0D66, 0x0D6F, W::Numeric),
+    (0x0D7A, 0x0D7F, W::ALetter),
+    (0x0D81, 0x0D83, W::Extend),
+    (0x0D85, 0x0D96, W::ALetter),
+    (0x0D9A, 0x0DB1, W::ALetter),
+    (0x0DB3, 0x0DBB, W::ALetter),
+    (0x0DBD, 0x0DBD, W::ALetter),
+    (0x0DC0, 0x0DC6, W::ALetter),
+    (0x0DCA, 0x0DCA, W::Extend),
+    (0x0DCF, 0x0DD4, W::Extend),
+    (0x0DD6, 0x0DD6, W::Extend),
+    (0x0DD8, 0x0DDF, W::Extend),
+    (0x0DE6, 0x0DEF, W::Numeric),
+    (0x0DF2, 0x0DF3, W::Extend),
+    (0x0E31, 0x0E31, W::Extend),
+    (0x0E34, 0x0E3A, W::Extend),
+    (0x0E47, 0x0E4E, W::Extend),
+    (0x0E50, 0x0E59, W::Numeric),
+    (0x0EB1, 0x0EB1, W::Extend),
+    (0x0EB4, 0x0EBC, W::Extend),
+    (0x0EC8, 0x0ECE, W::Extend),
+    (0x0ED0, 0x0ED9, W::Numeric),
+    (0x0F00, 0x0F00, W::ALetter),
+    (0x0F18, 0x0F19, W::Extend),
+    (0x0F20, 0x0F29, W::Numeric),
+    (0x0F35, 0x0F35, W::Extend),
+    (0x0F37, 0x0F37, W::Extend),
+    (0x0F39, 0x0F39, W::Extend),
+    (0x0F3E, 0x0F3F, W::Extend),
+    (0x0F40, 0x0F47, W::ALetter),
+    (0x0F49, 0x0F6C, W::ALetter),
+    (0x0F71, 0x0F84, W::Extend),
+    (0x0F86, 0x0F87, W::Extend),
+    (0x0F88, 0x0F8C, W::ALetter),
+    (0x0F8D, 0x0F97, W::Extend),
+    (0x0F99, 0x0FBC, W::Extend),
+    (0x0FC6, 0x0FC6, W::Extend),
+    (0x102B, 0x103E, W::Extend),
+    (0x1040, 0x1049, W::Numeric),
+    (0x1056, 0x1059, W::Extend),
+    (0x105E, 0x1060, W::Extend),
+    (0x1062, 0x1064, W::Extend),
+    (0x1067, 0x106D, W::Extend),
+    (0x1071, 0x1074, W::Extend),
+    (0x1082, 0x108D, W::Extend),
+    (0x108F, 0x108F, W::Extend),
+    (0x1090, 0x1099, W::Numeric),
+    (0x109A, 0x109D, W::Extend),
+    (0x10A0, 0x10C5, W::ALetter),
+    (0x10C7, 0x10C7, W::ALetter),
+    (0x10CD, 0x10CD, W::ALetter),
+    (0x10D0, 0x10FA, W::ALetter),
+    (0x10FC, 0x1248, W::ALetter),
+    (0x124A, 0x124D, W::ALetter),
+    (0x1250, 0x1256, W::ALetter),
+    (0x1258, 0x1258, W::ALetter),
+    (0x125A, 0x125D, W::ALetter),
+    (0x1260, 0x1288, W::ALetter),
+    (0x128A, 0x128D, W::ALetter),
+    (0x1290, 0x12B0, W::ALetter),
+    (0x12B2, 0x12B5, W::ALetter),
+    (0x12B8, 0x12BE, W::ALetter),
+    (0x12C0, 0x12C0, W::ALetter),
+    (0x12C2, 0x12C5, W::ALetter),
+    (0x12C8, 0x12D6, W::ALetter),
+    (0x12D8, 0x1310, W::ALetter),
+    (0x1312, 0x1315, W::ALetter),
+    (0x1318, 0x135A, W::ALetter),
+    (0x135D, 0x135F, W::Extend),
+    (0x1380, 0x138F, W::ALetter),
+    (0x13A0, 0x13F5, W::ALetter),
+    (0x13F8, 0x13FD, W::ALetter),
+    (0x1401, 0x166C, W::ALetter),
+    (0x166F, 0x167F, W::ALetter),
+    (0x1680, 0x1680, W::WSegSpace),
+    (0x1681, 0x169A, W::ALetter),
+    (0x16A0, 0x16EA, W::ALetter),
+    (0x16EE, 0x16F8, W::ALetter),
+    (0x1700, 0x1711, W::ALetter),
+    (0x1712, 0x1715, W::Extend),
+    (0x171F, 0x1731, W::ALetter),
+    (0x1732, 0x1734, W::Extend),
+    (0x1740, 0x1751, W::ALetter),
+    (0x1752, 0x1753, W::Extend),
+    (0x1760, 0x176C, W::ALetter),
+    (0x176E, 0x1770, W::ALetter),
+    (0x1772, 0x1773, W::Extend),
+    (0x17B4, 0x17D3, W::Extend),
+    (0x17DD, 0x17DD, W::Extend),
+    (0x17E0, 0x17E9, W::Numeric),
+    (0x180B, 0x180D, W::Extend),
+    (0x180E, 0x180E, W::Format),
+    (0x180F, 0x180F, W::Extend),
+    (0x1810, 0x1819, W::Numeric),
+    (0x1820, 0x1878, W::ALetter),
+    (0x1880, 0x1884, W::ALetter),
+    (0x1885, 0x1886, W::Extend),
+    (0x1887, 0x18A8, W::ALetter),
+    (0x18A9, 0x18A9, W::Extend),
+    (0x18AA, 0x18AA, W::ALetter),
+    (0x18B0, 0x18F5, W::ALetter),
+    (0x1900, 0x191E, W::ALetter),
+    (0x1920, 0x192B, W::Extend),
+    (0x1930, 0x193B, W::Extend),
+    (0x1946, 0x194F, W::Numeric),
+    (0x19D0, 0x19D9, W::Numeric),
+    (0x1A00, 0x1A16, W::ALetter),
+    (0x1A17, 0x1A1B, W::Extend),
+    (0x1A55, 0x1A5E, W::Extend),
+    (0x1A60, 0x1A7C, W::Extend),
+    (0x1A7F, 0x1A7F, W::Extend),
+    (0x1A80, 0x1A89, W::Numeric),
+    (0x1A90, 0x1A99, W::Numeric),
+    (0x1AB0, 0x1ACE, W::Extend),
+    (0x1B00, 0x1B04, W::Extend),
+    (0x1B05, 0x1B33, W::ALetter),
+    (0x1B34, 0x1B44, W::Extend),
+    (0x1B45, 0x1B4C, W::ALetter),
+    (0x1B50, 0x1B59, W::Numeric),
+    (0x1B6B, 0x1B73, W::Extend),
+    (0x1B80, 0x1B82, W::Extend),
+    (0x1B83, 0x1BA0, W::ALetter),
+    (0x1BA1, 0x1BAD, W::Extend),
+    (0x1BAE, 0x1BAF, W::ALetter),
+    (0x1BB0, 0x1BB9, W::Numeric),
+    (0x1BBA, 0x1BE5, W::ALetter),
+    (0x1BE6, 0x1BF3, W::Extend),
+    (0x1C00, 0x1C23, W::ALetter),
+    (0x1C24, 0x1C37, W::Extend),
+    (0x1C40, 0x1C49, W::Numeric),
+    (0x1C4D, 0x1C4F, W::ALetter),
+    (0x1C50, 0x1C59, W::Numeric),
+    (0x1C5A, 0x1C7D, W::ALetter),
+    (0x1C80, 0x1C88, W::ALetter),
+    (0x1C90, 0x1CBA, W::ALetter),
+    (0x1CBD, 0x1CBF, W::ALetter),
+    (0x1CD0, 0x1CD2, W::Extend),
+    (0x1CD4, 0x1CE8, W::Extend),
+    (0x1CE9, 0x1CEC, W::ALetter),
+    (0x1CED, 0x1CED, W::Extend),
+    (0x1CEE, 0x1CF3, W::ALetter),
+    (0x1CF4, 0x1CF4, W::Extend),
+    (0x1CF5, 0x1CF6, W::ALetter),
+    (0x1CF7, 0x1CF9, W::Extend),
+    (0x1CFA, 0x1CFA, W::ALetter),
+    (0x1D00, 0x1DBF, W::ALetter),
+    (0x1DC0, 0x1DFF, W::Extend),
+    (0x1E00, 0x1F15, W::ALetter),
+    (0x1F18, 0x1F1D, W::ALetter),
+    (0x1F20, 0x1F45, W::ALetter),
+    (0x1F48, 0x1F4D, W::ALetter),
+    (0x1F50, 0x1F57, W::ALetter),
+    (0x1F59, 0x1F59, W::ALetter),
+    (0x1F5B, 0x1F5B, W::ALetter),
+    (0x1F5D, 0x1F5D, W::ALetter),
+    (0x1F5F, 0x1F7D, W::ALetter),
+    (0x1F80, 0x1FB4, W::ALetter),
+    (0x1FB6, 0x1FBC, W::ALetter),
+    (0x1FBE, 0x1FBE, W::ALetter),
+    (0x1FC2, 0x1FC4, W::ALetter),
+    (0x1FC6, 0x1FCC, W::ALetter),
+    (0x1FD0, 0x1FD3, W::ALetter),
+    (0x1FD6, 0x1FDB, W::ALetter),
+    (0x1FE0, 0x1FEC, W::ALetter),
+    (0x1FF2, 0x1FF4, W::ALetter),
+    (0x1FF6, 0x1FFC, W::ALetter),
+    (0x2000, 0x2006, W::WSegSpace),
+    (0x2008, 0x200A, W::WSegSpace),
+    (0x200C, 0x200C, W::Extend),
+    (0x200D, 0x200D, W::Zwj),
+    (0x200E, 0x200F, W::Format),
+    (0x2018, 0x2019, W::MidNumLet),
+    (0x2024, 0x2024, W::MidNumLet),
+    (0x2027, 0x2027, W::MidLetter),
+    (0x2028, 0x2029, W::Newline),
+    (0x202A, 0x202E, W::Format),
+    (0x202F, 0x202F, W::ExtendNumLet),
+    (0x203F, 0x2040, W::ExtendNumLet),
+    (0x2044, 0x2044, W::MidNum),
+    (0x2054, 0x2054, W::ExtendNumLet),
+    (0x205F, 0x205F, W::WSegSpace),
+    (0x2060, 0x2064, W::Format),
+    (0x2066, 0x206F, W::Format),
+    (0x2071, 0x2071, W::ALetter),
+    (0x207F, 0x207F, W::ALetter),
+    (0x2090, 0x209C, W::ALetter),
+    (0x20D0, 0x20F0, W::Extend),
+    (0x2102, 0x2102, W::ALetter),
+    (0x2107, 0x2107, W::ALetter),
+    (0x210A, 0x2113, W::ALetter),
+    (0x2115, 0x2115, W::ALetter),
+    (0x2119, 0x211D, W::ALetter),
+    (0x2124, 0x2124, W::ALetter),
+    (0x2126, 0x2126, W::ALetter),
+    (0x2128, 0x2128, W::ALetter),
+    (0x212A, 0x212D, W::ALetter),
+    (0x212F, 0x2139, W::ALetter),
+    (0x213C, 0x213F, W::ALetter),
+    (0x2145, 0x2149, W::ALetter),
+    (0x214E, 0x214E, W::ALetter),
+    (0x2160, 0x2188, W::ALetter),
+    (0x24B6, 0x24E9, W::ALetter),
+    (0x2C00, 0x2CE4, W::ALetter),
+    (0x2CEB, 0x2CEE, W::ALetter),
+    (0x2CEF, 0x2CF1, W::Extend),
+    (0x2CF2, 0x2CF3, W::ALetter),
+    (0x2D00, 0x2D25, W::ALetter),
+    (0x2D27, 0x2D27, W::ALetter),
+    (0x2D2D, 0x2D2D, W::ALetter),
+    (0x2D30, 0x2D67, W::ALetter),
+    (0x2D6F, 0x2D6F, W::ALetter),
+    (0x2D7F, 0x2D7F, W::Extend),
+    (0x2D80, 0x2D96, W::ALetter),
+    (0x2DA0, 0x2DA6, W::ALetter),
+    (0x2DA8, 0x2DAE, W::ALetter),
+    (0x2DB0, 0x2DB6, W::ALetter),
+    (0x2DB8, 0x2DBE, W::ALetter),
+    (0x2DC0, 0x2DC6, W::ALetter),
+    (0x2DC8, 0x2DCE, W::ALetter),
+    (0x2DD0, 0x2DD6, W::ALetter),
+    (0x2DD8, 0x2DDE, W::ALetter),
+    (0x2DE0, 0x2DFF, W::Extend),
+    (0x2E2F, 0x2E2F, W::ALetter),
+    (0x3000, 0x3000, W::WSegSpace),
+    (0x3005, 0x3005, W::ALetter),
+    (0x302A, 0x302F, W::Extend),
+    (0x3031, 0x3035, W::Katakana),
+    (0x303B, 0x303C, W::ALetter),
+    (0x3099, 0x309A, W::Extend),
+    (0x309B, 0x309C, W::Katakana),
+    (0x30A0, 0x30FA, W::Katakana),
+    (0x30FC, 0x30FF, W::Katakana),
+    (0x3105, 0x312F, W::ALetter),
+    (0x3131, 0x318E, W::ALetter),
+    (0x31A0, 0x31BF, W::ALetter),
+    (0x31F0, 0x31FF, W::Katakana),
+    (0x32D0, 0x32FE, W::Katakana),
+    (0x3300, 0x3357, W::Katakana),
+    (0xA000, 0xA48C, W::ALetter),
+    (0xA4D0, 0xA4FD, W::ALetter),
+    (0xA500, 0xA60C, W::ALetter),
+    (0xA610, 0xA61F, W::ALetter),
+    (0xA620, 0xA629, W::Numeric),
+    (0xA62A, 0xA62B, W::ALetter),
+    (0xA640, 0xA66E, W::ALetter),
+    (0xA66F, 0xA672, W::Extend),
+    (0xA674, 0xA67D, W::Extend),
+    (0xA67F, 0xA69D, W::ALetter),
+    (0xA69E, 0xA69F, W::Extend),
+    (0xA6A0, 0xA6EF, W::ALetter),
+    (0xA6F0, 0xA6F1, W::Extend),
+    (0xA708, 0xA7CA, W::ALetter),
+    (0xA7D0, 0xA7D1, W::ALetter),
+    (0xA7D3, 0xA7D3, W::ALetter),
+    (0xA7D5, 0xA7D9, W::ALetter),
+    (0xA7F2, 0xA801, W::ALetter),
+    (0xA802, 0xA802, W::Extend),
+    (0xA803, 0xA805, W::ALetter),
+    (0xA806, 0xA806, W::Extend),
+    (0xA807, 0xA80A, W::ALetter),
+    (0xA80B, 0xA80B, W::Extend),
+    (0xA80C, 0xA822, W::ALetter),
+    (0xA823, 0xA827, W::Extend),
+    (0xA82C, 0xA82C, W::Extend),
+    (0xA840, 0xA873, W::ALetter),
+    (0xA880, 0xA881, W::Extend),
+    (0xA882, 0xA8B3, W::ALetter),
+    (0xA8B4, 0xA8C5, W::Extend),
+    (0xA8D0, 0xA8D9, W::Numeric),
+    (0xA8E0, 0xA8F1, W::Extend),
+    (0xA8F2, 0xA8F7, W::ALetter),
+    (0xA8FB, 0xA8FB, W::ALetter),
+    (0xA8FD, 0xA8FE, W::ALetter),
+    (0xA8FF, 0xA8FF, W::Extend),
+    (0xA900, 0xA909, W::Numeric),
+    (0xA90A, 0xA925, W::ALetter),
+    (0xA926, 0xA92D, W::Extend),
+    (0xA930, 0xA946, W::ALetter),
+    (0xA947, 0xA953, W::Extend),
+    (0xA960, 0xA97C, W::ALetter),
+    (0xA980, 0xA983, W::Extend),
+    (0xA984, 0xA9B2, W::ALetter),
+    (0xA9B3, 0xA9C0, W::Extend),
+    (0xA9CF, 0xA9CF, W::ALetter),
+    (0xA9D0, 0xA9D9, W::Numeric),
+    (0xA9E5, 0xA9E5, W::Extend),
+    (0xA9F0, 0xA9F9, W::Numeric),
+    (0xAA00, 0xAA28, W::ALetter),
+    (0xAA29, 0xAA36, W::Extend),
+    (0xAA40, 0xAA42, W::ALetter),
+    (0xAA43, 0xAA43, W::Extend),
+    (0xAA44, 0xAA4B, W::ALetter),
+    (0xAA4C, 0xAA4D, W::Extend),
+    (0xAA50, 0xAA59, W::Numeric),
+    (0xAA7B, 0xAA7D, W::Extend),
+    (0xAAB0, 0xAAB0, W::Extend),
+    (0xAAB2, 0xAAB4, W::Extend),
+    (0xAAB7, 0xAAB8, W::Extend),
+    (0xAABE, 0xAABF, W::Extend),
+    (0xAAC1, 0xAAC1, W::Extend),
+    (0xAAE0, 0xAAEA, W::ALetter),
+    (0xAAEB, 0xAAEF, W::Extend),
+    (0xAAF2, 0xAAF4, W::ALetter),
+    (0xAAF5, 0xAAF6, W::Extend),
+    (0xAB01, 0xAB06, W::ALetter),
+    (0xAB09, 0xAB0E, W::ALetter),
+    (0xAB11, 0xAB16, W::ALetter),
+    (0xAB20, 0xAB26, W::ALetter),
+    (0xAB28, 0xAB2E, W::ALetter),
+    (0xAB30, 0xAB69, W::ALetter),
+    (0xAB70, 0xABE2, W::ALetter),
+    (0xABE3, 0xABEA, W::Extend),
+    (0xABEC, 0xABED, W::Extend),
+    (0xABF0, 0xABF9, W::Numeric),
+    (0xAC00, 0xD7A3, W::ALetter),
+    (0xD7B0, 0xD7C6, W::ALetter),
+    (0xD7CB, 0xD7FB, W::ALetter),
+    (0xFB00, 0xFB06, W::ALetter),
+    (0xFB13, 0xFB17, W::ALetter),
+    (0xFB1D, 0xFB1D, W::HebrewLetter),
+    (0xFB1E, 0xFB1E, W::Extend),
+    (0xFB1F, 0xFB28, W::HebrewLetter),
+    (0xFB2A, 0xFB36, W::HebrewLetter),
+    (0xFB38, 0xFB3C, W::HebrewLetter),
+    (0xFB3E, 0xFB3E, W::HebrewLetter),
+    (0xFB40, 0xFB41, W::HebrewLetter),
+    (0xFB43, 0xFB44, W::HebrewLetter),
+    (0xFB46, 0xFB4F, W::HebrewLetter),
+    (0xFB50, 0xFBB1, W::ALetter),
+    (0xFBD3, 0xFD3D, W::ALetter),
+    (0xFD50, 0xFD8F, W::ALetter),
+    (0xFD92, 0xFDC7, W::ALetter),
+    (0xFDF0, 0xFDFB, W::ALetter),
+    (0xFE00, 0xFE0F, W::Extend),
+    (0xFE10, 0xFE10, W::MidNum),
+    (0xFE13, 0xFE13, W::MidLetter),
+    (0xFE14, 0xFE14, W::MidNum),
+    (0xFE20, 0xFE2F, W::Extend),
+    (0xFE33, 0xFE34, W::ExtendNumLet),
+    (0xFE4D, 0xFE4F, W::ExtendNumLet),
+    (0xFE50, 0xFE50, W::MidNum),
+    (0xFE52, 0xFE52, W::MidNumLet),
+    (0xFE54, 0xFE54, W::MidNum),
+    (0xFE55, 0xFE55, W::MidLetter),
+    (0xFE70, 0xFE74, W::ALetter),
+    (0xFE76, 0xFEFC, W::ALetter),
+    (0xFEFF, 0xFEFF, W::Format),
+    (0xFF07, 0xFF07, W::MidNumLet),
+    (0xFF0C, 0xFF0C, W::MidNum),
+    (0xFF0E, 0xFF0E, W::MidNumLet),
+    (0xFF10, 0xFF19, W::Numeric),
+    (0xFF1A, 0xFF1A, W::MidLetter),
+    (0xFF1B, 0xFF1B, W::MidNum),
+    (0xFF21, 0xFF3A, W::ALetter),
+    (0xFF3F, 0xFF3F, W::ExtendNumLet),
+    (0xFF41, 0xFF5A, W::ALetter),
+    (0xFF66, 0xFF9D, W::Katakana),
+    (0xFF9E, 0xFF9F, W::Extend),
+    (0xFFA0, 0xFFBE, W::ALetter),
+    (0xFFC2, 0xFFC7, W::ALetter),
+    (0xFFCA, 0xFFCF, W::ALetter),
+    (0xFFD2, 0xFFD7, W::ALetter),
+    (0xFFDA, 0xFFDC, W::ALetter),
+    (0xFFF9, 0xFFFB, W::Format),
+    (0x10000, 0x1000B, W::ALetter),
+    (0x1000D, 0x10026, W::ALetter),
+    (0x10028, 0x1003A, W::ALetter),
+    (0x1003C, 0x1003D, W::ALetter),
+    (0x1003F, 0x1004D, W::ALetter),
+    (0x10050, 0x1005D, W::ALetter),
+    (0x10080, 0x100FA, W::ALetter),
+    (0x10140, 0x10174, W::ALetter),
+    (0x101FD, 0x101FD, W::Extend),
+    (0x10280, 0x1029C, W::ALetter),
+    (0x102A0, 0x102D0, W::ALetter),
+    (0x102E0, 0x102E0, W::Extend),
+    (0x10300, 0x1031F, W::ALetter),
+    (0x1032D, 0x1034A, W::ALetter),
+    (0x10350, 0x10375, W::ALetter),
+    (0x10376, 0x1037A, W::Extend),
+    (0x10380, 0x1039D, W::ALetter),
+    (0x103A0, 0x103C3, W::ALetter),
+    (0x103C8, 0x103CF, W::ALetter),
+    (0x103D1, 0x103D5, W::ALetter),
+    (0x10400, 0x1049D, W::ALetter),
+    (0x104A0, 0x104A9, W::Numeric),
+    (0x104B0, 0x104D3, W::ALetter),
+    (0x104D8, 0x104FB, W::ALetter),
+    (0x10500, 0x10527, W::ALetter),
+    (0x10530, 0x10563, W::ALetter),
+    (0x10570, 0x1057A, W::ALetter),
+    (0x1057C, 0x1058A, W::ALetter),
+    (0x1058C, 0x10592, W::ALetter),
+    (0x10594, 0x10595, W::ALetter),
+    (0x10597, 0x105A1, W::ALetter),
+    (0x105A3, 0x105B1, W::ALetter),
+    (0x105B3, 0x105B9, W::ALetter),
+    (0x105BB, 0x105BC, W::ALetter),
+    (0x10600, 0x10736, W::ALetter),
+    (0x10740, 0x10755, W::ALetter),
+    (0x10760, 0x10767, W::ALetter),
+    (0x10780, 0x10785, W::ALetter),
+    (0x10787, 0x107B0, W::ALetter),
+    (0x107B2, 0x107BA, W::ALetter),
+    (0x10800, 0x10805, W::ALetter),
+    (0x10808, 0x10808, W::ALetter),
+    (0x1080A, 0x10835, W::ALetter),
+    (0x10837, 0x10838, W::ALetter),
+    (0x1083C, 0x1083C, W::ALetter),
+    (0x1083F, 0x10855, W::ALetter),
+    (0x10860, 0x10876, W::ALetter),
+    (0x10880, 0x1089E, W::ALetter),
+    (0x108E0, 0x108F2, W::ALetter),
+    (0x108F4, 0x108F5, W::ALetter),
+    (0x10900, 0x10915, W::ALetter),
+    (0x10920, 0x10939, W::ALetter),
+    (0x10980, 0x109B7, W::ALetter),
+    (0x109BE, 0x109BF, W::ALetter),
+    (0x10A00, 0x10A00, W::ALetter),
+    (0x10A01, 0x10A03, W::Extend),
+    (0x10A05, 0x10A06, W::Extend),
+    (0x10A0C, 0x10A0F, W::Extend),
+    (0x10A10, 0x10A13, W::ALetter),
+    (0x10A15, 0x10A17, W::ALetter),
+    (0x10A19, 0x10A35, W::ALetter),
+    (0x10A38, 0x10A3A, W::Extend),
+    (0x10A3F, 0x10A3F, W::Extend),
+    (0x10A60, 0x10A7C, W::ALetter),
+    (0x10A80, 0x10A9C, W::ALetter),
+    (0x10AC0, 0x10AC7, W::ALetter),
+    (0x10AC9, 0x10AE4, W::ALetter),
+    (0x10AE5, 0x10AE6, W::Extend),
+    (0x10B00, 0x10B35, W::ALetter),
+    (0x10B40, 0x10B55, W::ALetter),
+    (0x10B60, 0x10B72, W::ALetter),
+    (0x10B80, 0x10B91, W::ALetter),
+    (0x10C00, 0x10C48, W::ALetter),
+    (0x10C80, 0x10CB2, W::ALetter),
+    (0x10CC0, 0x10CF2, W::ALetter),
+    (0x10D00, 0x10D23, W::ALetter),
+    (0x10D24, 0x10D27, W::Extend),
+    (0x10D30, 0x10D39, W::Numeric),
+    (0x10E80, 0x10EA9, W::ALetter),
+    (0x10EAB, 0x10EAC, W::Extend),
+    (0x10EB0, 0x10EB1, W::ALetter),
+    (0x10EFD, 0x10EFF, W::Extend),
+    (0x10F00, 0x10F1C, W::ALetter),
+    (0x10F27, 0x10F27, W::ALetter),
+    (0x10F30, 0x10F45, W::ALetter),
+    (0x10F46, 0x10F50, W::Extend),
+    (0x10F70, 0x10F81, W::ALetter),
+    (0x10F82, 0x10F85, W::Extend),
+    (0x10FB0, 0x10FC4, W::ALetter),
+    (0x10FE0, 0x10FF6, W::ALetter),
+    (0x11000, 0x11002, W::Extend),
+    (0x11003, 0x11037, W::ALetter),
+    (0x11038, 0x11046, W::Extend),
+    (0x11066, 0x1106F, W::Numeric),
+    (0x11070, 0x11070, W::Extend),
+    (0x11071, 0x11072, W::ALetter),
+    (0x11073, 0x11074, W::Extend),
+    (0x11075, 0x11075, W::ALetter),
+    (0x1107F, 0x11082, W::Extend),
+    (0x11083, 0x110AF, W::ALetter),
+    (0x110B0, 0x110BA, W::Extend),
+    (0x110BD, 0x110BD, W::Format),
+    (0x110C2, 0x110C2, W::Extend),
+    (0x110CD, 0x110CD, W::Format),
+    (0x110D0, 0x110E8, W::ALetter),
+    (0x110F0, 0x110F9, W::Numeric),
+    (0x11100, 0x11102, W::Extend),
+    (0x11103, 0x11126, W::ALetter),
+    (0x11127, 0x11134, W::Extend),
+    (0x11136, 0x1113F, W::Numeric),
+    (0x11144, 0x11144, W::ALetter),
+    (0x11145, 0x11146, W::Extend),
+    (0x11147, 0x11147, W::ALetter),
+    (0x11150, 0x11172, W::ALetter),
+    (0x11173, 0x11173, W::Extend),
+    (0x11176, 0x11176, W::ALetter),
+    (0x11180, 0x11182, W::Extend),
+    (0x11183, 0x111B2, W::ALetter),
+    (0x111B3, 0x111C0, W::Extend),
+    (0x111C1, 0x111C4, W::ALetter),
+    (0x111C9, 0x111CC, W::Extend),
+    (0x111CE, 0x111CF, W::Extend),
+    (0x111D0, 0x111D9, W::Numeric),
+    (0x111DA, 0x111DA, W::ALetter),
+    (0x111DC, 0x111DC, W::ALetter),
+    (0x11200, 0x11211, W::ALetter),
+    (0x11213, 0x1122B, W::ALetter),
+    (0x1122C, 0x11237, W::Extend),
+    (0x1123E, 0x1123E, W::Extend),
+    (0x1123F, 0x11240, W::ALetter),
+    (0x11241, 0x11241, W::Extend),
+    (0x11280, 0x11286, W::ALetter),
+    (0x11288, 0x11288, W::ALetter),
+    (0x1128A, 0x1128D, W::ALetter),
+    (0x1128F, 0x1129D, W::ALetter),
+    (0x1129F, 0x112A8, W::ALetter),
+    (0x112B0, 0x112DE, W::ALetter),
+    (0x112DF, 0x112EA, W::Extend),
+    (0x112F0, 0x112F9, W::Numeric),
+    (0x11300, 0x11303, W::Extend),
+    (0x11305, 0x1130C, W::ALetter),
+    (0x1130F, 0x11310, W::ALetter),
+    (0x11313, 0x11328, W::ALetter),
+    (0x1132A, 0x11330, W::ALetter),
+    (0x11332, 0x11333, W::ALetter),
+    (0x11335, 0x11339, W::ALetter),
+    (0x1133B, 0x1133C, W::Extend),
+    (0x1133D, 0x1133D, W::ALetter),
+    (0x1133E, 0x11344, W::Extend),
+    (0x11347, 0x11348, W::Extend),
+    (0x1134B, 0x1134D, W::Extend),
+    (0x11350, 0x11350, W::ALetter),
+    (0x11357, 0x11357, W::Extend),
+    (0x1135D, 0x11361, W::ALetter),
+    (0x11362, 0x11363, W::Extend),
+    (0x11366, 0x1136C, W::Extend),
+    (0x11370, 0x11374, W::Extend),
+    (0x11400, 0x11434, W::ALetter),
+    (0x11435, 0x11446, W::Extend),
+    (0x11447, 0x1144A, W::ALetter),
+    (0x11450, 0x11459, W::Numeric),
+    (0x1145E, 0x1145E, W::Extend),
+    (0x1145F, 0x11461, W::ALetter),
+    (0x11480, 0x114AF, W::ALetter),
+    (0x114B0, 0x114C3, W::Extend),
+    (0x114C4, 0x114C5, W::ALetter),
+    (0x114C7, 0x114C7, W::ALetter),
+    (0x114D0, 0x114D9, W::Numeric),
+    (0x11580, 0x115AE, W::ALetter),
+    (0x115AF, 0x115B5, W::Extend),
+    (0x115B8, 0x115C0, W::Extend),
+    (0x115D8, 0x115DB, W::ALetter),
+    (0x115DC, 0x115DD, W::Extend),
+    (0x11600, 0x1162F, W::ALetter),
+    (0x11630, 0x11640, W::Extend),
+    (0x11644, 0x11644, W::ALetter),
+    (0x11650, 0x11659, W::Numeric),
+    (0x11680, 0x116AA, W::ALetter),
+    (0x116AB, 0x116B7, W::Extend),
+    (0x116B8, 0x116B8, W::ALetter),
+    (0x116C0, 0x116C9, W::Numeric),
+    (0x1171D, 0x1172B, W::Extend),
+    (0x11730, 0x11739, W::Numeric),
+    (0x11800, 0x1182B, W::ALetter),
+    (0x1182C, 0x1183A, W::Extend),
+    (0x118A0, 0x118DF, W::ALetter),
+    (0x118E0, 0x118E9, W::Numeric),
+    (0x118FF, 0x11906, W::ALetter),
+    (0x11909, 0x11909, W::ALetter),
+    (0x1190C, 0x11913, W::ALetter),
+    (0x11915, 0x11916, W::ALetter),
+    (0x11918, 0x1192F, W::ALetter),
+    (0x11930, 0x11935, W::Extend),
+    (0x11937, 0x11938, W::Extend),
+    (0x1193B, 0x1193E, W::Extend),
+    (0x1193F, 0x1193F, W::ALetter),
+    (0x11940, 0x11940, W::Extend),
+    (0x11941, 0x11941, W::ALetter),
+    (0x11942, 0x11943, W::Extend),
+    (0x11950, 0x11959, W::Numeric),
+    (0x119A0, 0x119A7, W::ALetter),
+    (0x119AA, 0x119D0, W::ALetter),
+    (0x119D1, 0x119D7, W::Extend),
+    (0x119DA, 0x119E0, W::Extend),
+    (0x119E1, 0x119E1, W::ALetter),
+    (0x119E3, 0x119E3, W::ALetter),
+    (0x119E4, 0x119E4, W::Extend),
+    (0x11A00, 0x11A00, W::ALetter),
+    (0x11A01, 0x11A0A, W::Extend),
+    (0x11A0B, 0x11A32, W::ALetter),
+    (0x11A33, 0x11A39, W::Extend),
+    (0x11A3A, 0x11A3A, W::ALetter),
+    (0x11A3B, 0x11A3E, W::Extend),
+    (0x11A47, 0x11A47, W::Extend),
+    (0x11A50, 0x11A50, W::ALetter),
+    (0x11A51, 0x11A5B, W::Extend),
+    (0x11A5C, 0x11A89, W::ALetter),
+    (0x11A8A, 0x11A99, W::Extend),
+    (0x11A9D, 0x11A9D, W::ALetter),
+    (0x11AB0, 0x11AF8, W::ALetter),
+    (0x11C00, 0x11C08, W::ALetter),
+    (0x11C0A, 0x11C2E, W::ALetter),
+    (0x11C2F, 0x11C36, W::Extend),
+    (0x11C38, 0x11C3F, W::Extend),
+    (0x11C40, 0x11C40, W::ALetter),
+    (0x11C50, 0x11C59, W::Numeric),
+    (0x11C72, 0x11C8F, W::ALetter),
+    (0x11C92, 0x11CA7, W::Extend),
+    (0x11CA9, 0x11CB6, W::Extend),
+    (0x11D00, 0x11D06, W::ALetter),
+    (0x11D08, 0x11D09, W::ALetter),
+    (0x11D0B, 0x11D30, W::ALetter),
+    (0x11D31, 0x11D36, W::Extend),
+    (0x11D3A, 0x11D3A, W::Extend),
+    (0x11D3C, 0x11D3D, W::Extend),
+    (0x11D3F, 0x11D45, W::Extend),
+    (0x11D46, 0x11D46, W::ALetter),
+    (0x11D47, 0x11D47, W::Extend),
+    (0x11D50, 0x11D59, W::Numeric),
+    (0x11D60, 0x11D65, W::ALetter),
+    (0x11D67, 0x11D68, W::ALetter),
+    (0x11D6A, 0x11D89, W::ALetter),
+    (0x11D8A, 0x11D8E, W::Extend),
+    (0x11D90, 0x11D91, W::Extend),
+    (0x11D93, 0x11D97, W::Extend),
+    (0x11D98, 0x11D98, W::ALetter),
+    (0x11DA0, 0x11DA9, W::Numeric),
+    (0x11EE0, 0x11EF2, W::ALetter),
+    (0x11EF3, 0x11EF6, W::Extend),
+    (0x11F00, 0x11F01, W::Extend),
+    (0x11F02, 0x11F02, W::ALetter),
+    (0x11F03, 0x11F03, W::Extend),
+    (0x11F04, 0x11F10, W::ALetter),
+    (0x11F12, 0x11F33, W::ALetter),
+    (0x11F34, 0x11F3A, W::Extend),
+    (0x11F3E, 0x11F42, W::Extend),
+    (0x11F50, 0x11F59, W::Numeric),
+    (0x11FB0, 0x11FB0, W::ALetter),
+    (0x12000, 0x12399, W::ALetter),
+    (0x12400, 0x1246E, W::ALetter),
+    (0x12480, 0x12543, W::ALetter),
+    (0x12F90, 0x12FF0, W::ALetter),
+    (0x13000, 0x1342F, W::ALetter),
+    (0x13430, 0x1343F, W::Format),
+    (0x13440, 0x13440, W::Extend),
+    (0x13441, 0x13446, W::ALetter),
+    (0x13447, 0x13455, W::Extend),
+    (0x14400, 0x14646, W::ALetter),
+    (0x16800, 0x16A38, W::ALetter),
+    (0x16A40, 0x16A5E, W::ALetter),
+    (0x16A60, 0x16A69, W::Numeric),
+    (0x16A70, 0x16ABE, W::ALetter),
+    (0x16AC0, 0x16AC9, W::Numeric),
+    (0x16AD0, 0x16AED, W::ALetter),
+    (0x16AF0, 0x16AF4, W::Extend),
+    (0x16B00, 0x16B2F, W::ALetter),
+    (0x16B30, 0x16B36, W::Extend),
+    (0x16B40, 0x16B43, W::ALetter),
+    (0x16B50, 0x16B59, W::Numeric),
+    (0x16B63, 0x16B77, W::ALetter),
+    (0x16B7D, 0x16B8F, W::ALetter),
+    (0x16E40, 0x16E7F, W::ALetter),
+    (0x16F00, 0x16F4A, W::ALetter),
+    (0x16F4F, 0x16F4F, W::Extend),
+    (0x16F50, 0x16F50, W::ALetter),
+    (0x16F51, 0x16F87, W::Extend),
+    (0x16F8F, 0x16F92, W::Extend),
+    (0x16F93, 0x16F9F, W::ALetter),
+    (0x16FE0, 0x16FE1, W::ALetter),
+    (0x16FE3, 0x16FE3, W::ALetter),
+    (0x16FE4, 0x16FE4, W::Extend),
+    (0x16FF0, 0x16FF1, W::Extend),
+    (0x1AFF0, 0x1AFF3, W::Katakana),
+    (0x1AFF5, 0x1AFFB, W::Katakana),
+    (0x1AFFD, 0x1AFFE, W::Katakana),
+    (0x1B000, 0x1B000, W::Katakana),
+    (0x1B120, 0x1B122, W::Katakana),
+    (0x1B155, 0x1B155, W::Katakana),
+    (0x1B164, 0x1B167, W::Katakana),
+    (0x1BC00, 0x1BC6A, W::ALetter),
+    (0x1BC70, 0x1BC7C, W::ALetter),
+    (0x1BC80, 0x1BC88, W::ALetter),
+    (0x1BC90, 0x1BC99, W::ALetter),
+    (0x1BC9D, 0x1BC9E, W::Extend),
+    (0x1BCA0, 0x1BCA3, W::Format),
+    (0x1CF00, 0x1CF2D, W::Extend),
+    (0x1CF30, 0x1CF46, W::Extend),
+    (0x1D165, 0x1D169, W::Extend),
+    (0x1D16D, 0x1D172, W::Extend),
+    (0x1D173, 0x1D17A, W::Format),
+    (0x1D17B, 0x1D182, W::Extend),
+    (0x1D185, 0x1D18B, W::Extend),
+    (0x1D1AA, 0x1D1AD, W::Extend),
+    (0x1D242, 0x1D244, W::Extend),
+    (0x1D400, 0x1D454, W::ALetter),
+    (0x1D456, 0x1D49C, W::ALetter),
+    (0x1D49E, 0x1D49F, W::ALetter),
+    (0x1D4A2, 0x1D4A2, W::ALetter),
+    (0x1D4A5, 0x1D4A6, W::ALetter),
+    (0x1D4A9, 0x1D4AC, W::ALetter),
+    (0x1D4AE, 0x1D4B9, W::ALetter),
+    (0x1D4BB, 0x1D4BB, W::ALetter),
+    (0x1D4BD, 0x1D4C3, W::ALetter),
+    (0x1D4C5, 0x1D505, W::ALetter),
+    (0x1D507, 0x1D50A, W::ALetter),
+    (0x1D50D, 0x1D514, W::ALetter),
+    (0x1D516, 0x1D51C, W::ALetter),
+    (0x1D51E, 0x1D539, W::ALetter),
+    (0x1D53B, 0x1D53E, W::ALetter),
+    (0x1D540, 0x1D544, W::ALetter),
+    (0x1D546, 0x1D546, W::ALetter),
+    (0x1D54A, 0x1D550, W::ALetter),
+    (0x1D552, 0x1D6A5, W::ALetter),
+    (0x1D6A8, 0x1D6C0, W::ALetter),
+    (0x1D6C2, 0x1D6DA, W::ALetter),
+    (0x1D6DC, 0x1D6FA, W::ALetter),
+    (0x1D6FC, 0x1D714, W::ALetter),
+    (0x1D716, 0x1D734, W::ALetter),
+    (0x1D736, 0x1D74E, W::ALetter),
+    (0x1D750, 0x1D76E, W::ALetter),
+    (0x1D770, 0x1D788, W::ALetter),
+    (0x1D78A, 0x1D7A8, W::ALetter),
+    (0x1D7AA, 0x1D7C2, W::ALetter),
+    (0x1D7C4, 0x1D7CB, W::ALetter),
+    (0x1D7CE, 0x1D7FF, W::Numeric),
+    (0x1DA00, 0x1DA36, W::Extend),
+    (0x1DA3B, 0x1DA6C, W::Extend),
+    (0x1DA75, 0x1DA75, W::Extend),
+    (0x1DA84, 0x1DA84, W::Extend),
+    (0x1DA9B, 0x1DA9F, W::Extend),
+    (0x1DAA1, 0x1DAAF, W::Extend),
+    (0x1DF00, 0x1DF1E, W::ALetter),
+    (0x1DF25, 0x1DF2A, W::ALetter),
+    (0x1E000, 0x1E006, W::Extend),
+    (0x1E008, 0x1E018, W::Extend),
+    (0x1E01B, 0x1E021, W::Extend),
+    (0x1E023, 0x1E024, W::Extend),
+    (0x1E026, 0x1E02A, W::Extend),
+    (0x1E030, 0x1E06D, W::ALetter),
+    (0x1E08F, 0x1E08F, W::Extend),
+    (0x1E100, 0x1E12C, W::ALetter),
+    (0x1E130, 0x1E136, W::Extend),
+    (0x1E137, 0x1E13D, W::ALetter),
+    (0x1E140, 0x1E149, W::Numeric),
+    (0x1E14E, 0x1E14E, W::ALetter),
+    (0x1E290, 0x1E2AD, W::ALetter),
+    (0x1E2AE, 0x1E2AE, W::Extend),
+    (0x1E2C0, 0x1E2EB, W::ALetter),
+    (0x1E2EC, 0x1E2EF, W::Extend),
+    (0x1E2F0, 0x1E2F9, W::Numeric),
+    (0x1E4D0, 0x1E4EB, W::ALetter),
+    (0x1E4EC, 0x1E4EF, W::Extend),
+    (0x1E4F0, 0x1E4F9, W::Numeric),
+    (0x1E7E0, 0x1E7E6, W::ALetter),
+    (0x1E7E8, 0x1E7EB, W::ALetter),
+    (0x1E7ED, 0x1E7EE, W::ALetter),
+    (0x1E7F0, 0x1E7FE, W::ALetter),
+    (0x1E800, 0x1E8C4, W::ALetter),
+    (0x1E8D0, 0x1E8D6, W::Extend),
+    (0x1E900, 0x1E943, W::ALetter),
+    (0x1E944, 0x1E94A, W::Extend),
+    (0x1E94B, 0x1E94B, W::ALetter),
+    (0x1E950, 0x1E959, W::Numeric),
+    (0x1EE00, 0x1EE03, W::ALetter),
+    (0x1EE05, 0x1EE1F, W::ALetter),
+    (0x1EE21, 0x1EE22, W::ALetter),
+    (0x1EE24, 0x1EE24, W::ALetter),
+    (0x1EE27, 0x1EE27, W::ALetter),
+    (0x1EE29, 0x1EE32, W::ALetter),
+    (0x1EE34, 0x1EE37, W::ALetter),
+    (0x1EE39, 0x1EE39, W::ALetter),
+    (0x1EE3B, 0x1EE3B, W::ALetter),
+    (0x1EE42, 0x1EE42, W::ALetter),
+    (0x1EE47, 0x1EE47, W::ALetter),
+    (0x1EE49, 0x1EE49, W::ALetter),
+    (0x1EE4B, 0x1EE4B, W::ALetter),
+    (0x1EE4D, 0x1EE4F, W::ALetter),
+    (0x1EE51, 0x1EE52, W::ALetter),
+    (0x1EE54, 0x1EE54, W::ALetter),
+    (0x1EE57, 0x1EE57, W::ALetter),
+    (0x1EE59, 0x1EE59, W::ALetter),
+    (0x1EE5B, 0x1EE5B, W::ALetter),
+    (0x1EE5D, 0x1EE5D, W::ALetter),
+    (0x1EE5F, 0x1EE5F, W::ALetter),
+    (0x1EE61, 0x1EE62, W::ALetter),
+    (0x1EE64, 0x1EE64, W::ALetter),
+    (0x1EE67, 0x1EE6A, W::ALetter),
+    (0x1EE6C, 0x1EE72, W::ALetter),
+    (0x1EE74, 0x1EE77, W::ALetter),
+    (0x1EE79, 0x1EE7C, W::ALetter),
+    (0x1EE7E, 0x1EE7E, W::ALetter),
+    (0x1EE80, 0x1EE89, W::ALetter),
+    (0x1EE8B, 0x1EE9B, W::ALetter),
+    (0x1EEA1, 0x1EEA3, W::ALetter),
+    (0x1EEA5, 0x1EEA9, W::ALetter),
+    (0x1EEAB, 0x1EEBB, W::ALetter),
+    (0x1F130, 0x1F149, W::ALetter),
+    (0x1F150, 0x1F169, W::ALetter),
+    (0x1F170, 0x1F189, W::ALetter),
+    (0x1F1E6, 0x1F1FF, W::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, W::Extend),
+    (0x1FBF0, 0x1FBF9, W::Numeric),
+    (0xE0001, 0xE0001, W::Format),
+    (0xE0020, 0xE007F, W::Extend),
+    (0xE0100, 0xE01EF, W::Extend),
+];
+
+/// Ranges of characters with a Sentence_Break other than Other, and their category
+pub(super) static SENTENCE_CAT: [(u32, u32, S); 2421] = [
+    (0x0009, 0x0009, S::Sp),
+    (0x000A, 0x000A, S::Lf),
+    (0x000B, 0x000C, S::Sp),
+    (0x000D, 0x000D, S::Cr),
+    (0x0020, 0x0020, S::Sp),
+    (0x0021, 0x0021, S::STerm),
+    (0x0022, 0x0022, S::Close),
+    (0x0027, 0x0029, S::Close),
+    (0x002C, 0x002D, S::SContinue),
+    (0x002E, 0x002E, S::ATerm),
+    (0x0030, 0x0039, S::Numeric),
+    (0x003A, 0x003A, S::SContinue),
+    (0x003F, 0x003F, S::STerm),
+    (0x0041, 0x005A, S::Upper),
+    (0x005B, 0x005B, S::Close),
+    (0x005D, 0x005D, S::Close),
+    (0x0061, 0x007A, S::Lower),
+    (0x007B, 0x007B, S::Close),
+    (0x007D, 0x007D, S::Close),
+    (0x0085, 0x0085, S::Sep),
+    (0x00A0, 0x00A0, S::Sp),
+    (0x00AA, 0x00AA, S::Lower),
+    (0x00AB, 0x00AB, S::Close),
+    (0x00AD, 0x00AD, S::Format),
+    (0x00B5, 0x00B5, S::Lower),
+    (0x00BA, 0x00BA, S::Lower),
+    (0x00BB, 0x00BB, S::Close),
+    (0x00C0, 0x00D6, S::Upper),
+    (0x00D8, 0x00DE, S::Upper),
+    (0x00DF, 0x00F6, S::Lower),
+    (0x00F8, 0x00FF, S::Lower),
+    (0x0100, 0x0100, S::Upper),
+    (0x0101, 0x0101, S::Lower),
+    (0x0102, 0x0102, S::Upper),
+    (0x0103, 0x0103, S::Lower),
+    (0x0104, 0x0104, S::Upper),
+    (0x0105, 0x0105, S::Lower),
+    (0x0106, 0x0106, S::Upper),
+    (0x0107, 0x0107, S::Lower),
+    (0x0108, 0x0108, S::Upper),
+    (0x0109, 0x0109, S::Lower),
+    (0x010A, 0x010A, S::Upper),
+    (0x010B, 0x010B, S::Lower),
+    (0x010C, 0x010C, S::Upper),
+    (0x010D, 0x010D, S::Lower),
+    (0x010E, 0x010E, S::Upper),
+    (0x010F, 0x010F, S::Lower),
+    (0x0110, 0x0110, S::Upper),
+    (0x0111, 0x0111, S::Lower),
+    (0x0112, 0x0112, S::Upper),
+    (0x0113, 0x0113, S::Lower),
+    (0x0114, 0x0114, S::Upper),
+    (0x0115, 0x0115, S::Lower),
+    (0x0116, 0x0116, S::Upper),
+    (0x0117, 0x0117, S::Lower),
+    (0x0118, 0x0118, S::Upper),
+    (0x0119, 0x0119, S::Lower),
+    (0x011A, 0x011A, S::Upper),
+    (0x011B, 0x011B, S::Lower),
+    (0x011C, 0x011C, S::Upper),
+    (0x011D, 0x011D, S::Lower),
+    (0x011E, 0x011E, S::Upper),
+    (0x011F, 0x011F, S::Lower),
+    (0x0120, 0x0120, S::Upper),
+    (0x0121, 0x0121, S::Lower),
+    (0x0122, 0x0122, S::Upper),
+    (0x0123, 0x0123, S::Lower),
+    (0x0124, 0x0124, S::Upper),
+    (0x0125, 0x0125, S::Lower),
+    (0x0126, 0x0126, S::Upper),
+    (0x0127, 0x0127, S::Lower),
+    (0x0128, 0x0128, S::Upper),
+    (0x0129, 0x0129, S::Lower),
+    (0x012A, 0x012A, S::Upper),
+    (0x012B, 0x012B, S::Lower),
+    (0x012C, 0x012C, S::Upper),
+    (0x012D, 0x012D, S::Lower),
+    (0x012E, 0x012E, S::Upper),
+    (0x012F, 0x012F, S::Lower),
+    (0x0130, 0x0130, S::Upper),
+    (0x0131, 0x0131, S::Lower),
+    (0x0132, 0x0132, S::Upper),
+    (0x0133, 0x0133, S::Lower),
+    (0x0134, 0x0134, S::Upper),
+    (0x0135, 0x0135, S::Lower),
+    (0x0136, 0x0136, S::Upper),
+    (0x0137, 0x0138, S::Lower),
+    (0x0139, 0x0139, S::Upper),
+    (0x013A, 0x013A, S::Lower),
+    (0x013B, 0x013B, S::Upper),
+    (0x013C, 0x013C, S::Lower),
+    (0x013D, 0x013D, S::Upper),
+    (0x013E, 0x013E, S::Lower),
+    (0x013F, 0x013F, S::Upper),
+    (0x0140, 0x0140, S::Lower),
+    (0x0141, 0x0141, S::Upper),
+    (0x0142, 0x0142, S::Lower),
+    (0x0143, 0x0143, S::Upper),
+    (0x0144, 0x0144, S::Lower),
+    (0x0145, 0x0145, S::Upper),
+    (0x0146, 0x0146, S::Lower),
+    (0x0147, 0x0147, S::Upper),
+    (0x0148, 0x0149, S::Lower),
+    (0x014A, 0x014A, S::Upper),
+    (0x014B, 0x014B, S::Lower),
+    (0x014C, 0x014C, S::Upper),
+    (0x014D, 0x014D, S::Lower),
+    (0x014E, 0x014E, S::Upper),
+    (0x014F, 0x014F, S::Lower),
+    (0x0150, 0x0150, S::Upper),
+    (0x0151, 0x0151, S::Lower),
+    (0x0152, 0x0152, S::Upper),
+    (0x0153, 0x0153, S::Lower),
+    (0x0154, 0x0154, S::Upper),
+    (0x0155, 0x0155, S::Lower),
+    (0x0156, 0x0156, S::Upper),
+    (0x0157, 0x0157, S::Lower),
+    (0x0158, 0x0158, S::Upper),
+    (0x0159, 0x0159, S::Lower),
+    (0x015A, 0x015A, S::Upper),
+    (0x015B, 0x015B, S::Lower),
+    (0x015C, 0x015C, S::Upper),
+    (0x015D, 0x015D, S::Lower),
+    (0x015E, 0x015E, S::Upper),
+    (0x015F, 0x015F, S::Lower),
+    (0x0160, 0x0160, S::Upper),
+    (0x0161, 0x0161, S::Lower),
+    (0x0162, 0x0162, S::Upper),
+    (0x0163, 0x0163, S::Lower),
+    (0x0164, 0x0164, S::Upper),
+    (0x0165, 0x0165, S::Lower),
+    (0x0166, 0x0166, S::Upper),
+    (0x0167, 0x0167, S::Lower),
+    (0x0168, 0x0168, S::Upper),
+    (0x0169, 0x0169, S::Lower),
+    (0x016A, 0x016A, S::Upper),
+    (0x016B, 0x016B, S::Lower),
+    (0x016C, 0x016C, S::Upper),
+    (0x016D, 0x016D, S::Lower),
+    (0x016E, 0x016E, S::Upper),
+    (0x016F, 0x016F, S::Lower),
+    (0x0170, 0x0170, S::Upper),
+    (0x0171, 0x0171, S::Lower),
+    (0x0172, 0x0172, S::Upper),
+    (0x0173, 0x0173, S::Lower),
+    (0x0174, 0x0174, S::Upper),
+    (0x0175, 0x0175, S::Lower),
+    (0x0176, 0x0176, S::Upper),
+    (0x0177, 0x0177, S::Lower),
+    (0x0178, 0x0179, S::Upper),
+    (0x017A, 0x017A, S::Lower),
+    (0x017B, 0x017B, S::Upper),
+    (0x017C, 0x017C, S::Lower),
+    (0x017D, 0x017D, S::Upper),
+    (0x017E, 0x0180, S::Lower),
+    (0x0181, 0x0182, S::Upper),
+    (0x0183, 0x0183, S::Lower),
+    (0x0184, 0x0184, S::Upper),
+    (0x0185, 0x0185, S::Lower),
+    (0x0186, 0x0187, S::Upper),
+    (0x0188, 0x0188, S::Lower),
+    (0x0189, 0x018B, S::Upper),
+    (0x018C, 0x018D, S::Lower),
+    (0x018E, 0x0191, S::Upper),
+    (0x0192, 0x0192, S::Lower),
+    (0x0193, 0x0194, S::Upper),
+    (0x0195, 0x0195, S::Lower),
+    (0x0196, 0x0198, S::Upper),
+    (0x0199, 0x019B, S::Lower),
+    (0x019C, 0x019D, S::Upper),
+    (0x019E, 0x019E, S::Lower),
+    (0x019F, 0x01A0, S::Upper),
+    (0x01A1, 0x01A1, S::Lower),
+    (0x01A2, 0x01A2, S::Upper),
+    (0x01A3, 0x01A3, S::Lower),
+    (0x01A4, 0x01A4, S::Upper),
+    (0x01A5, 0x01A5, S::Lower),
+    (0x01A6, 0x01A7, S::Upper),
+    (0x01A8, 0x01A8, S::Lower),
+    (0x01A9, 0x01A9, S::Upper),
+    (0x01AA, 0x01AB, S::Lower),
+    (0x01AC, 0x01AC, S::Upper),
+    (0x01AD, 0x01AD, S::Lower),
+    (0x01AE, 0x01AF, S::Upper),
+    (0x01B0, 0x01B0, S::Lower),
+    (0x01B1, 0x01B3, S::Upper),
+    (0x01B4, 0x01B4, S::Lower),
+    (0x01B5, 0x01B5, S::Upper),
+    (0x01B6, 0x01B6, S::Lower),
+    (0x01B7, 0x01B8, S::Upper),
+    (0x01B9, 0x01BA, S::Lower),
+    (0x01BB, 0x01BB, S::OLetter),
+    (0x01BC, 0x01BC, S::Upper),
+    (0x01BD, 0x01BF, S::Lower),
+    (0x01C0, 0x01C3, S::OLetter),
+    (0x01C4, 0x01C5, S::Upper),
+    (0x01C6, 0x01C6, S::Lower),
+    (0x01C7, 0x01C8, S::Upper),
+    (0x01C9, 0x01C9, S::Lower),
+    (0x01CA, 0x01CB, S::Upper),
+    (0x01CC, 0x01CC, S::Lower),
+    (0x01CD, 0x01CD, S::Upper),
+    (0x01CE, 0x01CE, S::Lower),
+    (0x01CF, 0x01CF, S::Upper),
+    (0x01D0, 0x01D0, S::Lower),
+    (0x01D1, 0x01D1, S::Upper),
+    (0x01D2, 0x01D2, S::Lower),
+    (0x01D3, 0x01D3, S::Upper),
+    (0x01D4, 0x01D4, S::Lower),
+    (0x01D5, 0x01D5, S::Upper),
+    (0x01D6, 0x01D6, S::Lower),
+    (0x01D7, 0x01D7, S::Upper),
+    (0x01D8, 0x01D8, S::Lower),
+    (0x01D9, 0x01D9, S::Upper),
+    (0x01DA, 0x01DA, S::Lower),
+    (0x01DB, 0x01DB, S::Upper),
+    (0x01DC, 0x01DD, S::Lower),
+    (0x01DE, 0x01DE, S::Upper),
+    (0x01DF, 0x01DF, S::Lower),
+    (0x01E0, 0x01E0, S::Upper),
+    (0x01E1, 0x01E1, S::Lower),
+    (0x01E2, 0x01E2, S::Upper),
+    (0x01E3, 0x01E3, S::Lower),
+    (0x01E4, 0x01E4, S::Upper),
+    (0x01E5, 0x01E5, S::Lower),
+    (0x01E6, 0x01E6, S::Upper),
+    (0x01E7, 0x01E7, S::Lower),
+    (0x01E8, 0x01E8, S::Upper),
+    (0x01E9, 0x01E9, S::Lower),
+    (0x01EA, 0x01EA, S::Upper),
+    (0x01EB, 0x01EB, S::Lower),
+    (0x01EC, 0x01EC, S::Upper),
+    (0x01ED, 0x01ED, S::Lower),
+    (0x01EE, 0x01EE, S::Upper),
+    (0x01EF, 0x01F0, S::Lower),
+    (0x01F1, 0x01F2, S::Upper),
+    (0x01F3, 0x01F3, S::Lower),
+    (0x01F4, 0x01F4, S::Upper),
+    (0x01F5, 0x01F5, S::Lower),
+    (0x01F6, 0x01F8, S::Upper),
+    (0x01F9, 0x01F9, S::Lower),
+    (0x01FA, 0x01FA, S::Upper),
+    (0x01FB, 0x01FB, S::Lower),
+    (0x01FC, 0x01FC, S::Upper),
+    (0x01FD, 0x01FD, S::Lower),
+    (0x01FE, 0x01FE, S::Upper),
+    (0x01FF, 0x01FF, S::Lower),
+    (0x0200, 0x0200, S::Upper),
+    (0x0201, 0x0201, S::Lower),
+    (0x0202, 0x0202, S::Upper),
+    (0x0203, 0x0203, S::Lower),
+    (0x0204, 0x0204, S::Upper),
+    (0x0205, 0x0205, S::Lower),
+    (0x0206, 0x0206, S::Upper),
+    (0x0207, 0x0207, S::Lower),
+    (0x0208, 0x0208, S::Upper),
+    (0x0209, 0x0209, S::Lower),
+    (0x020A, 0x020A, S::Upper),
+    (0x020B, 0x020B, S::Lower),
+    (0x020C, 0x020C, S::Upper),
+    (0x020D, 0x020D, S::Lower),
+    (0x020E, 0x020E, S::Upper),
+    (0x020F, 0x020F, S::Lower),
+    (0x0210, 0x0210, S::Upper),
+    (0x0211, 0x0211, S::Lower),
+    (0x0212, 0x0212, S::Upper),
+    (0x0213, 0x0213, S::Lower),
+    (0x0214, 0x0214, S::Upper),
+    (0x0215, 0x0215, S::Lower),
+    (0x0216, 0x0216, S::Upper),
+    (0x0217, 0x0217, S::Lower),
+    (0x0218, 0x0218, S::Upper),
+    (0x0219, 0x0219, S::Lower),
+    (0x021A, 0x021A, S::Upper),
+    (0x021B, 0x021B, S::Lower),
+    (0x021C, 0x021C, S::Upper),
+    (0x021D, 0x021D, S::Lower),
+    (0x021E, 0x021E, S::Upper),
+    (0x021F, 0x021F, S::Lower),
+    (0x0220, 0x0220, S::Upper),
+    (0x0221, 0x0221, S::Lower),
+    (0x0222, 0x0222, S::Upper),
+    (0x0223, 0x0223, S::Lower),
+    (0x0224, 0x0224, S::Upper),
+    (0x0225, 0x0225, S::Lower),
+    (0x0226, 0x0226, S::Upper),
+    (0x0227, 0x0227, S::Lower),
+    (0x0228, 0x0228, S::Upper),
+    (0x0229, 0x0229, S::Lower),
+    (0x022A, 0x022A, S::Upper),
+    (0x022B, 0x022B, S::Lower),
+    (0x022C, 0x022C, S::Upper),
+    (0x022D, 0x022D, S::Lower),
+    (0x022E, 0x022E, S::Upper),
+    (0x022F, 0x022F, S::Lower),
+    (0x0230, 0x0230, S::Upper),
+    (0x0231, 0x0231, S::Lower),
+    (0x0232, 0x0232, S::Upper),
+    (0x0233, 0x0239, S::Lower),
+    (0x023A, 0x023B, S::Upper),
+    (0x023C, 0x023C, S::Lower),
+    (0x023D, 0x023E, S::Upper),
+    (0x023F, 0x0240, S::Lower),
+    (0x0241, 0x0241, S::Upper),
+    (0x0242, 0x0242, S::Lower),
+    (0x0243, 0x0246, S::Upper),
+    (0x0247, 0x0247, S::Lower),
+    (0x0248, 0x0248, S::Upper),
+    (0x0249, 0x0249, S::Lower),
+    (0x024A, 0x024A, S::Upper),
+    (0x024B, 0x024B, S::Lower),
+    (0x024C, 0x024C, S::Upper),
+    (0x024D, 0x024D, S::Lower),
+    (0x024E, 0x024E, S::Upper),
+    (0x024F, 0x0293, S::Lower),
+    (0x0294, 0x0294, S::OLetter),
+    (0x0295, 0x02B8, S::Lower),
+    (0x02B9, 0x02BF, S::OLetter),
+    (0x02C0, 0x02C1, S::Lower),
+    (0x02C6, 0x02D1, S::OLetter),
+    (0x02E0, 0x02E4, S::Lower),
+    (0x02EC, 0x02EC, S::OLetter),
+    (0x02EE, 0x02EE, S::OLetter),
+    (0x0300, 0x036F, S::Extend),
+    (0x0370, 0x0370, S::Upper),
+    (0x0371, 0x0371, S::Lower),
+    (0x0372, 0x0372, S::Upper),
+    (0x0373, 0x0373, S::Lower),
+    (0x0374, 0x0374, S::OLetter),
+    (0x0376, 0x0376, S::Upper),
+    (0x0377, 0x0377, S::Lower),
+    (0x037A, 0x037D, S::Lower),
+    (0x037F, 0x037F, S::Upper),
+    (0x0386, 0x0386, S::Upper),
+    (0x0388, 0x038A, S::Upper),
+    (0x038C, 0x038C, S::Upper),
+    (0x038E, 0x038F, S::Upper),
+    (0x0390, 0x0390, S::Lower),
+    (0x0391, 0x03A1, S::Upper),
+    (0x03A3, 0x03AB, S::Upper),
+    (0x03AC, 0x03CE, S::Lower),
+    (0x03CF, 0x03CF, S::Upper),
+    (0x03D0, 0x03D1, S::Lower),
+    (0x03D2, 0x03D4, S::Upper),
+    (0x03D5, 0x03D7, S::Lower),
+    (0x03D8, 0x03D8, S::Upper),
+    (0x03D9, 0x03D9, S::Lower),
+    (0x03DA, 0x03DA, S::Upper),
+    (0x03DB, 0x03DB, S::Lower),
+    (0x03DC, 0x03DC, S::Upper),
+    (0x03DD, 0x03DD, S::Lower),
+    (0x03DE, 0x03DE, S::Upper),
+    (0x03DF, 0x03DF, S::Lower),
+    (0x03E0, 0x03E0, S::Upper),
+    (0x03E1, 0x03E1, S::Lower),
+    (0x03E2, 0x03E2, S::Upper),
+    (0x03E3, 0x03E3, S::Lower),
+    (0x03E4, 0x03E4, S::Upper),
+    (0x03E5, 0x03E5, S::Lower),
+    (0x03E6, 0x03E6, S::Upper),
+    (0x03E7, 0x03E7, S::Lower),
+    (0x03E8, 0x03E8, S::Upper),
+    (0x03E9, 0x03E9, S::Lower),
+    (0x03EA, 0x03EA, S::Upper),
+    (0x03EB, 0x03EB, S::Lower),
+    (0x03EC, 0x03EC, S::Upper),
+    (0x03ED, 0x03ED, S::Lower),
+    (0x03EE, 0x03EE, S::Upper),
+    (0x03EF, 0x03F3, S::Lower),
+    (0x03F4, 0x03F4, S::Upper),
+    (0x03F5, 0x03F5, S::Lower),
+    (0x03F7, 0x03F7, S::Upper),
+    (0x03F8, 0x03F8, S::Lower),
+    (0x03F9, 0x03FA, S::Upper),
+    (0x03FB, 0x03FC, S::Lower),
+    (0x03FD, 0x042F, S::Upper),
+    (0x0430, 0x045F, S::Lower),
+    (0x0460, 0x0460, S::Upper),
+    (0x0461, 0x0461, S::Lower),
+    (0x0462, 0x0462, S::Upper),
+    (0x0463, 0x0463, S::Lower),
+    (0x0464, 0x0464, S::Upper),
+    (0x0465, 0x0465, S::Lower),
+    (0x0466, 0x0466, S::Upper),
+    (0x0467, 0x0467, S::Lower),
+    (0x0468, 0x0468, S::Upper),
+    (0x0469, 0x0469, S::Lower),
+    (0x046A, 0x046A, S::Upper),
+    (0x046B, 0x046B, S::Lower),
+    (0x046C, 0x046C, S::Upper),
+    (0x046D, 0x046D, S::Lower),
+    (0x046E, 0x046E, S::Upper),
+    (0x046F, 0x046F, S::Lower),
+    (0x0470, 0x0470, S::Upper),
+    (0x0471, 0x0471, S::Lower),
+    (0x0472, 0x0472, S::Upper),
+    (0x0473, 0x0473, S::Lower),
+    (0x0474, 0x0474, S::Upper),
+    (0x0475, 0x0475, S::Lower),
+    (0x0476, 0x0476, S::Upper),
+    (0x0477, 0x0477, S::Lower),
+    (0x0478, 0x0478, S::Upper),
+    (0x0479, 0x0479, S::Lower),
+    (0x047A, 0x047A, S::Upper),
+    (0x047B, 0x047B, S::Lower),
+    (0x047C, 0x047C, S::Upper),
+    (0x047D, 0x047D, S::Lower),
+    (0x047E, 0x047E, S::Upper),
+    (0x047F, 0x047F, S::Lower),
+    (0x0480, 0x0480, S::Upper),
+    (0x0481, 0x0481, S::Lower),
+    (0x0483, 0x0489, S::Extend),
+    (0x048A, 0x048A, S::Upper),
+    (0x048B, 0x048B, S::Lower),
+    (0x048C, 0x048C, S::Upper),
+    (0x048D, 0x048D, S::Lower),
+    (0x048E, 0x048E, S::Upper),
+    (0x048F, 0x048F, S::Lower),
+    (0x0490, 0x0490, S::Upper),
+    (0x0491, 0x0491, S::Lower),
+    (0x0492, 0x0492, S::Upper),
+    (0x0493, 0x0493, S::Lower),
+    (0x0494, 0x0494, S::Upper),
+    (0x0495, 0x0495, S::Lower),
+    (0x0496, 0x0496, S::Upper),
+    (0x0497, 0x0497, S::Lower),
+    (0x0498, 0x0498, S::Upper),
+    (0x0499, 0x0499, S::Lower),
+    (0x049A, 0x049A, S::Upper),
+    (0x049B, 0x049B, S::Lower),
+    (0x049C, 0x049C, S::Upper),
+    (0x049D, 0x049D, S::Lower),
+    (0x049E, 0x049E, S::Upper),
+    (0x049F, 0x049F, S::Lower),
+    (0x04A0, 0x04A0, S::Upper),
+    (0x04A1, 0x04A1, S::Lower),
+    (0x04A2, 0x04A2, S::Upper),
+    (0x04A3, 0x04A3, S::Lower),
+    (0x04A4, 0x04A4, S::Upper),
+    (0x04A5, 0x04A5, S::Lower),
+    (0x04A6, 0x04A6, S::Upper),
+    (0x04A7, 0x04A7, S::Lower),
+    (0x04A8, 0x04A8, S::Upper),
+    (0x04A9, 0x04A9, S::Lower),
+    (0x04AA, 0x04AA, S::Upper),
+    (0x04AB, 0x04AB, S::Lower),
+    (0x04AC, 0x04AC, S::Upper),
+    (0x04AD, 0x04AD, S::Lower),
+    (0x04AE, 0x04AE, S::Upper),
+    (0x04AF, 0x04AF, S::Lower),
+    (0x04B0, 0x04B0, S::Upper),
+    (0x04B1, 0x04B1, S::Lower),
+    (0x04B2, 0x04B2, S::Upper),
+    (0x04B3, 0x04B3, S::Lower),
+    (0x04B4, 0x04B4, S::Upper),
+    (0x04B5, 0x04B5, S::Lower),
+    (0x04B6, 0x04B6, S::Upper),
+    (0x04B7, 0x04B7, S::Lower),
+    (0x04B8, 0x04B8, S::Upper),
+    (0x04B9, 0x04B9, S::Lower),
+    (0x04BA, 0x04BA, S::Upper),
+    (0x04BB, 0x04BB, S::Lower),
+    (0x04BC, 0x04BC, S::Upper),
+    (0x04BD, 0x04BD, S::Lower),
+    (0x04BE, 0x04BE, S::Upper),
+    (0x04BF, 0x04BF, S::Lower),
+    (0x04C0, 0x04C1, S::Upper),
+    (0x04C2, 0x04C2, S::Lower),
+    (0x04C3, 0x04C3, S::Upper),
+    (0x04C4, 0x04C4, S::Lower),
+    (0x04C5, 0x04C5, S::Upper),
+    (0x04C6, 0x04C6, S::Lower),
+    (0x04C7, 0x04C7, S::Upper),
+    (0x04C8, 0x04C8, S::Lower),
+    (0x04C9, 0x04C9, S::Upper),
+    (0x04CA, 0x04CA, S::Lower),
+    (0x04CB, 0x04CB, S::Upper),
+    (0x04CC, 0x04CC, S::Lower),
+    (0x04CD, 0x04CD, S::Upper),
+    (0x04CE, 0x04CF, S::Lower),
+    (0x04D0, 0x04D0, S::Upper),
+    (0x04D1, 0x04D1, S::Lower),
+    (0x04D2, 0x04D2, S::Upper),
+    (0x04D3, 0x04D3, S::Lower),
+    (0x04D4, 0x04D4, S::Upper),
+    (0x04D5, 0x04D5, S::Lower),
+    (0x04D6, 0x04D6, S::Upper),
+    (0x04D7, 0x04D7, S::Lower),
+    (0x04D8, 0x04D8, S::Upper),
+    (0x04D9, 0x04D9, S::Lower),
+    (0x04DA, 0x04DA, S::Upper),
+    (0x04DB, 0x04DB, S::Lower),
+    (0x04DC, 0x04DC, S::Upper),
+    (0x04DD, 0x04DD, S::Lower),
+    (0x04DE, 0x04DE, S::Upper),
+    (0x04DF, 0x04DF, S::Lower),
+    (0x04E0, 0x04E0, S::Upper),
+    (0x04E1, 0x04E1, S::Lower),
+    (0x04E2, 0x04E2, S::Upper),
+    (0x04E3, 0x04E3, S::Lower),
+    (0x04E4, 0x04E4, S::Upper),
+    (0x04E5, 0x04E5, S::Lower),
+    (0x04E6, 0x04E6, S::Upper),
+    (0x04E7, 0x04E7, S::Lower),
+    (0x04E8, 0x04E8, S::Upper),
+    (0x04E9, 0x04E9, S::Lower),
+    (0x04EA, 0x04EA, S::Upper),
+    (0x04EB, 0x04EB, S::Lower),
+    (0x04EC, 0x04EC, S::Upper),
+    (0x04ED, 0x04ED, S::Lower),
+    (0x04EE, 0x04EE, S::Upper),
+    (0x04EF, 0x04EF, S::Lower),
+    (0x04F0, 0x04F0, S::Upper),
+    (0x04F1, 0x04F1, S::Lower),
+    (0x04F2, 0x04F2, S::Upper),
+    (0x04F3, 0x04F3, S::Lower),
+    (0x04F4, 0x04F4, S::Upper),
+    (0x04F5, 0x04F5, S::Lower),
+    (0x04F6, 0x04F6, S::Upper),
+    (0x04F7, 0x04F7, S::Lower),
+    (0x04F8, 0x04F8, S::Upper),
+    (0x04F9, 0x04F9, S::Lower),
+    (0x04FA, 0x04FA, S::Upper),
+    (0x04FB, 0x04FB, S::Lower),
+    (0x04FC, 0x04FC, S::Upper),
+    (0x04FD, 0x04FD, S::Lower),
+    (0x04FE, 0x04FE, S::Upper),
+    (0x04FF, 0x04FF, S::Lower),
+    (0x0500, 0x0500, S::Upper),
+    (0x0501, 0x0501, S::Lower),
+    (0x0502, 0x0502, S::Upper),
+    (0x0503, 0x0503, S::Lower),
+    (0x0504, 0x0504, S::Upper),
+    (0x0505, 0x0505, S::Lower),
+    (0x0506, 0x0506, S::Upper),
+    (0x0507, 0x0507, S::Lower),
+    (0x0508, 0x0508, S::Upper),
+    (0x0509, 0x0509, S::Lower),
+    (0x050A, 0x050A, S::Upper),
+    (0x050B, 0x050B, S::Lower),
+    (0x050C, 0x050C, S::Upper),
+    (0x050D, 0x050D, S::Lower),
+    (0x050E, 0x050E, S::Upper),
+    (0x050F, 0x050F, S::Lower),
+    (0x0510, 0x0510, S::Upper),
+    (0x0511, 0x0511, S::Lower),
+    (0x0512, 0x0512, S::Upper),
+    (0x0513, 0x0513, S::Lower),
+    (0x0514, 0x0514, S::Upper),
+    (0x0515, 0x0515, S::Lower),
+    (0x0516, 0x0516, S::Upper),
+    (0x0517, 0x0517, S::Lower),
+    (0x0518, 0x0518, S::Upper),
+    (0x0519, 0x0519, S::Lower),
+    (0x051A, 0x051A, S::Upper),
+    (0x051B, 0x051B, S::Lower),
+    (0x051C, 0x051C, S::Upper),
+    (0x051D, 0x051D, S::Lower),
+    (0x051E, 0x051E, S::Upper),
+    (0x051F, 0x051F, S::Lower),
+    (0x0520, 0x0520, S::Upper),
+    (0x0521, 0x0521, S::Lower),
+    (0x0522, 0x0522, S::Upper),
+    (0x0523, 0x0523, S::Lower),
+    (0x0524, 0x0524, S::Upper),
+    (0x0525, 0x0525, S::Lower),
+    (0x0526, 0x0526, S::Upper),
+    (0x0527, 0x0527, S::Lower),
+    (0x0528, 0x0528, S::Upper),
+    (0x0529, 0x0529, S::Lower),
+    (0x052A, 0x052A, S::Upper),
+    (0x052B, 0x052B, S::Lower),
+    (0x052C, 0x052C, S::Upper),
+    (0x052D, 0x052D, S::Lower),
+    (0x052E, 0x052E, S::Upper),
+    (0x052F, 0x052F, S::Lower),
+    (0x0531, 0x0556, S::Upper),
+    (0x0559, 0x0559, S::OLetter),
+    (0x055D, 0x055D, S::SContinue),
+    (0x0560, 0x0588, S::Lower),
+    (0x0589, 0x0589, S::STerm),
+    (0x0591, 0x05BD, S::Extend),
+    (0x05BF, 0x05BF, S::Extend),
+    (0x05C1, 0x05C2, S::Extend),
+    (0x05C4, 0x05C5, S::Extend),
+    (0x05C7, 0x05C7, S::Extend),
+    (0x05D0, 0x05EA, S::OLetter),
+    (0x05EF, 0x05F3, S::OLetter),
+    (0x0600, 0x0605, S::Format),
+    (0x060C, 0x060D, S::SContinue),
+    (0x0610, 0x061A, S::Extend),
+    (0x061C, 0x061C, S::Format),
+    (0x061D, 0x061F, S::STerm),
+    (0x0620, 0x064A, S::OLetter),
+    (0x064B, 0x065F, S::Extend),
+    (0x0660, 0x0669, S::Numeric),
+    (0x066B, 0x066C, S::Numeric),
+    (0x066E, 0x066F, S::OLetter),
+    (0x0670, 0x0670, S::Extend),
+    (0x0671, 0x06D3, S::OLetter),
+    (0x06D4, 0x06D4, S::STerm),
+    (0x06D5, 0x06D5, S::OLetter),
+    (0x06D6, 0x06DC, S::Extend),
+    (0x06DD, 0x06DD, S::Format),
+    (0x06DF, 0x06E4, S::Extend),
+    (0x06E5, 0x06E6, S::OLetter),
+    (0x06E7, 0x06E8, S::Extend),
+    (0x06EA, 0x06ED, S::Extend),
+    (0x06EE, 0x06EF, S::OLetter),
+    (0x06F0, 0x06F9, S::Numeric),
+    (0x06FA, 0x06FC, S::OLetter),
+    (0x06FF, 0x06FF, S::OLetter),
+    (0x0700, 0x0702, S::STerm),
+    (0x070F, 0x070F, S::Format),
+    (0x0710, 0x0710, S::OLetter),
+    (0x0711, 0x0711, S::Extend),
+    (0x0712, 0x072F, S::OLetter),
+    (0x0730, 0x074A, S::Extend),
+    (0x074D, 0x07A5, S::OLetter),
+    (0x07A6, 0x07B0, S::Extend),
+    (0x07B1, 0x07B1, S::OLetter),
+    (0x07C0, 0x07C9, S::Numeric),
+    (0x07CA, 0x07EA, S::OLetter),
+    (0x07EB, 0x07F3, S::Extend),
+    (0x07F4, 0x07F5, S::OLetter),
+    (0x07F8, 0x07F8, S::SContinue),
+    (0x07F9, 0x07F9, S::STerm),
+    (0x07FA, 0x07FA, S::OLetter),
+    (0x07FD, 0x07FD, S::Extend),
+    (0x0800, 0x0815, S::OLetter),
+    (0x0816, 0x0819, S::Extend),
+    (0x081A, 0x081A, S::OLetter),
+    (0x081B, 0x0823, S::Extend),
+    (0x0824, 0x0824, S::OLetter),
+    (0x0825, 0x0827, S::Extend),
+    (0x0828, 0x0828, S::OLetter),
+    (0x0829, 0x082D, S::Extend),
+    (0x0837, 0x0837, S::STerm),
+    (0x0839, 0x0839, S::STerm),
+    (0x083D, 0x083E, S::STerm),
+    (0x0840, 0x0858, S::OLetter),
+    (0x0859, 0x085B, S::Extend),
+    (0x0860, 0x086A, S::OLetter),
+    (0x0870, 0x0887, S::OLetter),
+    (0x0889, 0x088E, S::OLetter),
+    (0x0890, 0x0891, S::Format),
+    (0x0898, 0x089F, S::Extend),
+    (0x08A0, 0x08C9, S::OLetter),
+    (0x08CA, 0x08E1, S::Extend),
+    (0x08E2, 0x08E2, S::Format),
+    (0x08E3, 0x0903, S::Extend),
+    (0x0904, 0x0939, S::OLetter),
+    (0x093A, 0x093C, S::Extend),
+    (0x093D, 0x093D, S::OLetter),
+    (0x093E, 0x094F, S::Extend),
+    (0x0950, 0x0950, S::OLetter),
+    (0x0951, 0x0957, S::Extend),
+    (0x0958, 0x0961, S::OLetter),
+    (0x0962, 0x0963, S::Extend),
+    (0x0964, 0x0965, S::STerm),
+    (0x0966, 0x096F, S::Numeric),
+    (0x0971, 0x0980, S::OLetter),
+    (0x0981, 0x0983, S::Extend),
+    (0x0985, 0x098C, S::OLetter),
+    (0x098F, 0x0990, S::OLetter),
+    (0x0993, 0x09A8, S::OLetter),
+    (0x09AA, 0x09B0, S::OLetter),
+    (0x09B2, 0x09B2, S::OLetter),
+    (0x09B6, 0x09B9, S::OLetter),
+    (0x09BC, 0x09BC, S::Extend),
+    (0x09BD, 0x09BD, S::OLetter),
+    (0x09BE, 0x09C4, S::Extend),
+    (0x09C7, 0x09C8, S::Extend),
+    (0x09CB, 0x09CD, S::Extend),
+    (0x09CE, 0x09CE, S::OLetter),
+    (0x09D7, 0x09D7, S::Extend),
+    (0x09DC, 0x09DD, S::OLetter),
+    (0x09DF, 0x09E1, S::OLetter),
+    (0x09E2, 0x09E3, S::Extend),
+    (0x09E6, 0x09EF, S::Numeric),
+    (0x09F0, 0x09F1, S::OLetter),
+    (0x09FC, 0x09FC, S::OLetter),
+    (0x09FE, 0x09FE, S::Extend),
+    (0x0A01, 0x0A03, S::Extend),
+    (0x0A05, 0x0A0A, S::OLetter),
+    (0x0A0F, 0x0A10, S::OLetter),
+    (0x0A13, 0x0A28, S::OLetter),
+    (0x0A2A, 0x0A30, S::OLetter),
+    (0x0A32, 0x0A33, S::OLetter),
+    (0x0A35, 0x0A36, S::OLetter),
+    (0x0A38, 0x0A39, S::OLetter),
+    (0x0A3C, 0x0A3C, S::Extend),
+    (0x0A3E, 0x0A42, S::Extend),
+    (0x0A47, 0x0A48, S::Extend),
+    (0x0A4B, 0x0A4D, S::Extend),
+    (0x0A51, 0x0A51, S::Extend),
+    (0x0A59, 0x0A5C, S::OLetter),
+    (0x0A5E, 0x0A5E, S::OLetter),
+    (0x0A66, 0x0A6F, S::Numeric),
+    (0x0A70, 0x0A71, S::Extend),
+    (0x0A72, 0x0A74, S::OLetter),
+    (0x0A75, 0x0A75, S::Extend),
+    (0x0A81, 0x0A83, S::Extend),
+    (0x0A85, 0x0A8D, S::OLetter),
+    (0x0A8F, 0x0A91, S::OLetter),
+    (0x0A93, 0x0AA8, S::OLetter),
+    (0x0AAA, 0x0AB0, S::OLetter),
+    (0x0AB2, 0x0AB3, S::OLetter),
+    (0x0AB5, 0x0AB9, S::OLetter),
+    (0x0ABC, 0x0ABC, S::Extend),
+    (0x0ABD, 0x0ABD, S::OLetter),
+    (0x0ABE, 0x0AC5, S::Extend),
+    (0x0AC7, 0x0AC9, S::Extend),
+    (0x0ACB, 0x0ACD, S::Extend),
+    (0x0AD0, 0x0AD0, S::OLetter),
+    (0x0AE0, 0x0AE1, S::OLetter),
+    (0x0AE2, 0x0AE3, S::Extend),
+    (0x0AE6, 0x0AEF, S::Numeric),
+    (0x0AF9, 0x0AF9, S::OLetter),
+    (0x0AFA, 0x0AFF, S::Extend),
+    (0x0B01, 0x0B03, S::Extend),
+    (0x0B05, 0x0B0C, S::OLetter),
+    (0x0B0F, 0x0B10, S::OLetter),
+    (0x0B13, 0x0B28, S::OLetter),
+    (0x0B2A, 0x0B30, S::OLetter),
+    (0x0B32, 0x0B33, S::OLetter),
+    (0x0B35, 0x0B39, S::OLetter),
+    (0x0B3C, 0x0B3C, S::Extend),
+    (0x0B3D, 0x0B3D, S::OLetter),
+    (0x0B3E, 0x0B44, S::Extend),
+    (0x0B47, 0x0B48, S::Extend),
+    (0x0B4B, 0x0B4D, S::Extend),
+    (0x0B55, 0x0B57, S::Extend),
+    (0x0B5C, 0x0B5D, S::OLetter),
+    (0x0B5F, 0x0B61, S::OLetter),
+    (0x0B62, 0x0B63, S::Extend),
+    (0x0B66, 0x0B6F, S::Numeric),
+    (0x0B71, 0x0B71, S::OLetter),
+    (0x0B82, 0x0B82, S::Extend),
+    (0x0B83, 0x0B83, S::OLetter),
+    (0x0B85, 0x0B8A, S::OLetter),
+    (0x0B8E, 0x0B90, S::OLetter),
+    (0x0B92, 0x0B95, S::OLetter),
+    (0x0B99, 0x0B9A, S::OLetter),
+    (0x0B9C, 0x0B9C, S::OLetter),
+    (0x0B9E, 0x0B9F, S::OLetter),
+    (0x0BA3, 0x0BA4, S::OLetter),
+    (0x0BA8, 0x0BAA, S::OLetter),
+    (0x0BAE, 0x0BB9, S::OLetter),
+    (0x0BBE, 0x0BC2, S::Extend),
+    (0x0BC6, 0x0BC8, S::Extend),
+    (0x0BCA, 0x0BCD, S::Extend),
+    (0x0BD0, 0x0BD0, S::OLetter),
+    (0x0BD7, 0x0BD7, S::Extend),
+    (0x0BE6, 0x0BEF, S::Numeric),
+    (0x0C00, 0x0C04, S::Extend),
+    (0x0C05, 0x0C0C, S::OLetter),
+    (0x0C0E, 0x0C10, S::OLetter),
+    (0x0C12, 0x0C28, S::OLetter),
+    (0x0C2A, 0x0C39, S::OLetter),
+    (0x0C3C, 0x0C3C, S::Extend),
+    (0x0C3D, 0x0C3D, S::OLetter),
+    (0x0C3E, 0x0C44, S::Extend),
+    (0x0C46, 0x0C48, S::Extend),
+    (0x0C4A, 0x0C4D, S::Extend),
+    (0x0C55, 0x0C56, S::Extend),
+    (0x0C58, 0x0C5A, S::OLetter),
+    (0x0C5D, 0x0C5D, S::OLetter),
+    (0x0C60, 0x0C61, S::OLetter),
+    (0x0C62, 0x0C63, S::Extend),
+    (0x0C66, 0x0C6F, S::Numeric),
+    (0x0C80, 0x0C80, S::OLetter),
+    (0x0C81, 0x0C83, S::Extend),
+    (0x0C85, 0x0C8C, S::OLetter),
+    (0x0C8E, 0x0C90, S::OLetter),
+    (0x0C92, 0x0CA8, S::OLetter),
+    (0x0CAA, 0x0CB3, S::OLetter),
+    (0x0CB5, 0x0CB9, S::OLetter),
+    (0x0CBC, 0x0CBC, S::Extend),
+    (0x0CBD, 0x0CBD, S::OLetter),
+    (0x0CBE, 0x0CC4, S::Extend),
+    (0x0CC6, 0x0CC8, S::Extend),
+    (0x0CCA, 0x0CCD, S::Extend),
+    (0x0CD5, 0x0CD6, S::Extend),
+    (0x0CDD, 0x0CDE, S::OLetter),
+    (0x0CE0, 0x0CE1, S::OLetter),
+    (0x0CE2, 0x0CE3, S::Extend),
+    (0x0CE6, 0x0CEF, S::Numeric),
+    (0x0CF1, 0x0CF2, S::OLetter),
+    (0x0CF3, 0x0CF3, S::Extend),
+    (0x0D00, 0x0D03, S::Extend),
+    (0x0D04, 0x0D0C, S::OLetter),
+    (0x0D0E, 0x0D10, S::OLetter),
+    (0x0D12, 0x0D3A, S::OLetter),
+    (0x0D3B, 0x0D3C, S::Extend),
+    (0x0D3D, 0x0D3D, S::OLetter),
+    (0x0D3E, 0x0D44, S::Extend),
+    (0x0D46, 0x0D48, S::Extend),
+    (0x0D4A, 0x0D4D, S::Extend),
+    (0x0D4E, 0x0D4E, S::OLetter),
+    (0x0D54, 0x0D56, S::OLetter),
+    (0x0D57, 0x0D57, S::Extend),
+    (0x0D5F, 0x0D61, S::OLetter),
+    (0x0D62, 0x0D63, S::Extend),
+    (0x0D66, 0x0D6F, S::Numeric),
+    (0x0D7A, 0x0D7F, S::OLetter),
+    (0x0D81, 0x0D83, S::Extend),
+    (0x0D85, 0x0D96, S::OLetter),
+    (0x0D9A, 0x0DB1, S::OLetter),
+    (0x0DB3, 0x0DBB, S::OLetter),
+    (0x0DBD, 0x0DBD, S::OLetter),
+    (0x0DC0, 0x0DC6, S::OLetter),
+    (0x0DCA, 0x0DCA, S::Extend),
+    (0x0DCF, 0x0DD4, S::Extend),
+    (0x0DD6, 0x0DD6, S::Extend),
+    (0x0DD8, 0x0DDF, S::Extend),
+    (0x0DE6, 0x0DEF, S::Numeric),
+    (0x0DF2, 0x0DF3, S::Extend),
+    (0x0E01, 0x0E30, S::OLetter),
+    (0x0E31, 0x0E31, S::Extend),
+    (0x0E32, 0x0E33, S::OLetter),
+    (0x0E34, 0x0E3A, S::Extend),
+    (0x0E40, 0x0E46, S::OLetter),
+    (0x0E47, 0x0E4E, S::Extend),
+    (0x0E50, 0x0E59, S::Numeric),
+    (0x0E81, 0x0E82, S::OLetter),
+    (0x0E84, 0x0E84, S::OLetter),
+    (0x0E86, 0x0E8A, S::OLetter),
+    (0x0E8C, 0x0EA3, S::OLetter),
+    (0x0EA5, 0x0EA5, S::OLetter),
+    (0x0EA7, 0x0EB0, S::OLetter),
+    (0x0EB1, 0x0EB1, S::Extend),
+    (0x0EB2, 0x0EB3, S::OLetter),
+    (0x0EB4, 0x0EBC, S::Extend),
+    (0x0EBD, 0x0EBD, S::OLetter),
+    (0x0EC0, 0x0EC4, S::OLetter),
+    (0x0EC6, 0x0EC6, S::OLetter),
+    (0x0EC8, 0x0ECE, S::Extend),
+    (0x0ED0, 0x0ED9, S::Numeric),
+    (0x0EDC, 0x0EDF, S::OLetter),
+    (0x0F00, 0x0F00, S::OLetter),
+    (0x0F18, 0x0F19, S::Extend),
+    (0x0F20, 0x0F29, S::Numeric),
+    (0x0F35, 0x0F35, S::Extend),
+    (0x0F37, 0x0F37, S::Extend),
+    (0x0F39, 0x0F39, S::Extend),
+    (0x0F3A, 0x0F3D, S::Close),
+    (0x0F3E, 0x0F3F, S::Extend),
+    (0x0F40, 0x0F47, S::OLetter),
+    (0x0F49, 0x0F6C, S::OLetter),
+    (0x0F71, 0x0F84, S::Extend),
+    (0x0F86, 0x0F87, S::Extend),
+    (0x0F88, 0x0F8C, S::OLetter),
+    (0x0F8D, 0x0F97, S::Extend),
+    (0x0F99, 0x0FBC, S::Extend),
+    (0x0FC6, 0x0FC6, S::Extend),
+    (0x1000, 0x102A, S::OLetter),
+    (0x102B, 0x103E, S::Extend),
+    (0x103F, 0x103F, S::OLetter),
+    (0x1040, 0x1049, S::Numeric),
+    (0x104A, 0x104B, S::STerm),
+    (0x1050, 0x1055, S::OLetter),
+    (0x1056, 0x1059, S::Extend),
+    (0x105A, 0x105D, S::OLetter),
+    (0x105E, 0x1060, S::Extend),
+    (0x1061, 0x1061, S::OLetter),
+    (0x1062, 0x1064, S::Extend),
+    (0x1065, 0x1066, S::OLetter),
+    (0x1067, 0x106D, S::Extend),
+    (0x106E, 0x1070, S::OLetter),
+    (0x1071, 0x1074, S::Extend),
+    (0x1075, 0x1081, S::OLetter),
+    (0x1082, 0x108D, S::Extend),
+    (0x108E, 0x108E, S::OLetter),
+    (0x108F, 0x108F, S::Extend),
+    (0x1090, 0x1099, S::Numeric),
+    (0x109A, 0x109D, S::Extend),
+    (0x10A0, 0x10C5, S::Upper),
+    (0x10C7, 0x10C7, S::Upper),
+    (0x10CD, 0x10CD, S::Upper),
+    (0x10D0, 0x10FA, S::OLetter),
+    (0x10FC, 0x10FC, S::Lower),
+    (0x10FD, 0x1248, S::OLetter),
+    (0x124A, 0x124D, S::OLetter),
+    (0x1250, 0x1256, S::OLetter),
+    (0x1258, 0x1258, S::OLetter),
+    (0x125A, 0x125D, S::OLetter),
+    (0x1260, 0x1288, S::OLetter),
+    (0x128A, 0x128D, S::OLetter),
+    (0x1290, 0x12B0, S::OLetter),
+    (0x12B2, 0x12B5, S::OLetter),
+    (0x12B8, 0x12BE, S::OLetter),
+    (0x12C0, 0x12C0, S::OLetter),
+    (0x12C2, 0x12C5, S::OLetter),
+    (0x12C8, 0x12D6, S::OLetter),
+    (0x12D8, 0x1310, S::OLetter),
+    (0x1312, 0x1315, S::OLetter),
+    (0x1318, 0x135A, S::OLetter),
+    (0x135D, 0x135F, S::Extend),
+    (0x1362, 0x1362, S::STerm),
+    (0x1367, 0x1368, S::STerm),
+    (0x1380, 0x138F, S::OLetter),
+    (0x13A0, 0x13F5, S::Upper),
+    (0x13F8, 0x13FD, S::Lower),
+    (0x1401, 0x166C, S::OLetter),
+    (0x166E, 0x166E, S::STerm),
+    (0x166F, 0x167F, S::OLetter),
+    (0x1680, 0x1680, S::Sp),
+    (0x1681, 0x169A, S::OLetter),
+    (0x169B, 0x169C, S::Close),
+    (0x16A0, 0x16EA, S::OLetter),
+    (0x16EE, 0x16F8, S::OLetter),
+    (0x1700, 0x1711, S::OLetter),
+    (0x1712, 0x1715, S::Extend),
+    (0x171F, 0x1731, S::OLetter),
+    (0x1732, 0x1734, S::Extend),
+    (0x1735, 0x1736, S::STerm),
+    (0x1740, 0x1751, S::OLetter),
+    (0x1752, 0x1753, S::Extend),
+    (0x1760, 0x176C, S::OLetter),
+    (0x176E, 0x1770, S::OLetter),
+    (0x1772, 0x1773, S::Extend),
+    (0x1780, 0x17B3, S::OLetter),
+    (0x17B4, 0x17D3, S::Extend),
+    (0x17D7, 0x17D7, S::OLetter),
+    (0x17DC, 0x17DC, S::OLetter),
+    (0x17DD, 0x17DD, S::Extend),
+    (0x17E0, 0x17E9, S::Numeric),
+    (0x1802, 0x1802, S::SContinue),
+    (0x1803, 0x1803, S::STerm),
+    (0x1808, 0x1808, S::SContinue),
+    (0x1809, 0x1809, S::STerm),
+    (0x180B, 0x180D, S::Extend),
+    (0x180E, 0x180E, S::Format),
+    (0x180F, 0x180F, S::Extend),
+    (0x1810, 0x1819, S::Numeric),
+    (0x1820, 0x1878, S::OLetter),
+    (0x1880, 0x1884, S::OLetter),
+    (0x1885, 0x1886, S::Extend),
+    (0x1887, 0x18A8, S::OLetter),
+    (0x18A9, 0x18A9, S::Extend),
+    (0x18AA, 0x18AA, S::OLetter),
+    (0x18B0, 0x18F5, S::OLetter),
+    (0x1900, 0x191E, S::OLetter),
+    (0x1920, 0x192B, S::Extend),
+    (0x1930, 0x193B, S::Extend),
+    (0x1944, 0x1945, S::STerm),
+    (0x1946, 0x194F, S::Numeric),
+    (0x1950, 0x196D, S::OLetter),
+    (0x1970, 0x1974, S::OLetter),
+    (0x1980, 0x19AB, S::OLetter),
+    (0x19B0, 0x19C9, S::OLetter),
+    (0x19D0, 0x19D9, S::Numeric),
+    (0x1A00, 0x1A16, S::OLetter),
+    (0x1A17, 0x1A1B, S::Extend),
+    (0x1A20, 0x1A54, S::OLetter),
+    (0x1A55, 0x1A5E, S::Extend),
+    (0x1A60, 0x1A7C, S::Extend),
+    (0x1A7F, 0x1A7F, S::Extend),
+    (0x1A80, 0x1A89, S::Numeric),
+    (0x1A90, 0x1A99, S::Numeric),
+    (0x1AA7, 0x1AA7, S::OLetter),
+    (0x1AA8, 0x1AAB, S::STerm),
+    (0x1AB0, 0x1ACE, S::Extend),
+    (0x1B00, 0x1B04, S::Extend),
+    (0x1B05, 0x1B33, S::OLetter),
+    (0x1B34, 0x1B44, S::Extend),
+    (0x1B45, 0x1B4C, S::OLetter),
+    (0x1B50, 0x1B59, S::Numeric),
+    (0x1B5A, 0x1B5B, S::STerm),
+    (0x1B5E, 0x1B5F, S::STerm),
+    (0x1B6B, 0x1B73, S::Extend),
+    (0x1B7D, 0x1B7E, S::STerm),
+    (0x1B80, 0x1B82, S::Extend),
+    (0x1B83, 0x1BA0, S::OLetter),
+    (0x1BA1, 0x1BAD, S::Extend),
+    (0x1BAE, 0x1BAF, S::OLetter),
+    (0x1BB0, 0x1BB9, S::Numeric),
+    (0x1BBA, 0x1BE5, S::OLetter),
+    (0x1BE6, 0x1BF3, S::Extend),
+    (0x1C00, 0x1C23, S::OLetter),
+    (0x1C24, 0x1C37, S::Extend),
+    (0x1C3B, 0x1C3C, S::STerm),
+    (0x1C40, 0x1C49, S::Numeric),
+    (0x1C4D, 0x1C4F, S::OLetter),
+    (0x1C50, 0x1C59, S::Numeric),
+    (0x1C5A, 0x1C7D, S::OLetter),
+    (0x1C7E, 0x1C7F, S::STerm),
+    (0x1C80, 0x1C88, S::Lower),
+    (0x1C90, 0x1CBA, S::OLetter),
+    (0x1CBD, 0x1CBF, S::OLetter),
+    (0x1CD0, 0x1CD2, S::Extend),
+    (0x1CD4, 0x1CE8, S::Extend),
+    (0x1CE9, 0x1CEC, S::OLetter),
+    (0x1CED, 0x1CED, S::Extend),
+    (0x1CEE, 0x1CF3, S::OLetter),
+    (0x1CF4, 0x1CF4, S::Extend),
+    (0x1CF5, 0x1CF6, S::OLetter),
+    (0x1CF7, 0x1CF9, S::Extend),
+    (0x1CFA, 0x1CFA, S::OLetter),
+    (0x1D00, 0x1DBF, S::Lower),
+    (0x1DC0, 0x1DFF, S::Extend),
+    (0x1E00, 0x1E00, S::Upper),
+    (0x1E01, 0x1E01, S::Lower),
+    (0x1E02, 0x1E02, S::Upper),
+    (0x1E03, 0x1E03, S::Lower),
+    (0x1E04, 0x1E04, S::Upper),
+    (0x1E05, 0x1E05, S::Lower),
+    (0x1E06, 0x1E06, S::Upper),
+    (0x1E07, 0x1E07, S::Lower),
+    (0x1E08, 0x1E08, S::Upper),
+    (0x1E09, 0x1E09, S::Lower),
+    (0x1E0A, 0x1E0A, S::Upper),
+    (0x1E0B, 0x1E0B, S::Lower),
+    (0x1E0C, 0x1E0C, S::Upper),
+    (0x1E0D, 0x1E0D, S::Lower),
+    (0x1E0E, 0x1E0E, S::Upper),
+    (0x1E0F, 0x1E0F, S::Lower),
+    (0x1E10, 0x1E10, S::Upper),
+    (0x1E11, 0x1E11, S::Lower),
+    (0x1E12, 0x1E12, S::Upper),
+    (0x1E13, 0x1E13, S::Lower),
+    (0x1E14, 0x1E14, S::Upper),
+    (0x1E15, 0x1E15, S::Lower),
+    (0x1E16, 0x1E16, S::Upper),
+    (0x1E17, 0x1E17, S::Lower),
+    (0x1E18, 0x1E18, S::Upper),
+    (0x1E19, 0x1E19, S::Lower),
+    (0x1E1A, 0x1E1A, S::Upper),
+    (0x1E1B, 0x1E1B, S::Lower),
+    (0x1E1C, 0x1E1C, S::Upper),
+    (0x1E1D, 0x1E1D, S::Lower),
+    (0x1E1E, 0x1E1E, S::Upper),
+    (0x1E1F, 0x1E1F, S::Lower),
+    (0x1E20, 0x1E20, S::Upper),
+    (0x1E21, 0x1E21, S::Lower),
+    (0x1E22, 0x1E22, S::Upper),
+    (0x1E23, 0x1E23, S::Lower),
+    (0x1E24, 0x1E24, S::Upper),
+    (0x1E25, 0x1E25, S::Lower),
+    (0x1E26, 0x1E26, S::Upper),
+    (0x1E27, 0x1E27, S::Lower),
+    (0x1E28, 0x1E28, S::Upper),
+    (0x1E29, 0x1E29, S::Lower),
+    (0x1E2A, 0x1E2A, S::Upper),
+    (0x1E2B, 0x1E2B, S::Lower),
+    (0x1E2C, 0x1E2C, S::Upper),
+    (0x1E2D, 0x1E2D, S::Lower),
+    (0x1E2E, 0x1E2E, S::Upper),
+    (0x1E2F, 0x1E2F, S::Lower),
+    (0x1E30, 0x1E30, S::Upper),
+    (0x1E31, 0x1E31, S::Lower),
+    (0x1E32, 0x1E32, S::Upper),
+    (0x1E33, 0x1E33, S::Lower),
+    (0x1E34, 0x1E34, S::Upper),
+    (0x1E35, 0x1E35, S::Lower),
+    (0x1E36, 0x1E36, S::Upper),
+    (0x1E37, 0x1E37, S::Lower),
+    (0x1E38, 0x1E38, S::Upper),
+    (0x1E39, 0x1E39, S::Lower),
+    (0x1E3A, 0x1E3A, S::Upper),
+    (0x1E3B, 0x1E3B, S::Lower),
+    (0x1E3C, 0x1E3C, S::Upper),
+    (0x1E3D, 0x1E3D, S::Lower),
+    (0x1E3E, 0x1E3E, S::Upper),
+    (0x1E3F, 0x1E3F, S::Lower),
+    (0x1E40, 0x1E40, S::Upper),
+    (0x1E41, 0x1E41, S::Lower),
+    (0x1E42, 0x1E42, S::Upper),
+    (0x1E43, 0x1E43, S::Lower),
+    (0x1E44, 0x1E44, S::Upper),
+    (0x1E45, 0x1E45, S::Lower),
+    (0x1E46, 0x1E46, S::Upper),
+    (0x1E47, 0x1E47, S::Lower),
+    (0x1E48, 0x1E48, S::Upper),
+    (0x1E49, 0x1E49, S::Lower),
+    (0x1E4A, 0x1E4A, S::Upper),
+    (0x1E4B, 0x1E4B, S::Lower),
+    (0x1E4C, 0x1E4C, S::Upper),
+    (0x1E4D, 0x1E4D, S::Lower),
+    (0x1E4E, 0x1E4E, S::Upper),
+    (0x1E4F, 0x1E4F, S::Lower),
+    (0x1E50, 0x1E50, S::Upper),
+    (0x1E51, 0x1E51, S::Lower),
+    (0x1E52, 0x1E52, S::Upper),
+    (0x1E53, 0x1E53, S::Lower),
+    (0x1E54, 0x1E54, S::Upper),
+    (0x1E55, 0x1E55, S::Lower),
+    (0x1E56, 0x1E56, S::Upper),
+    (0x1E57, 0x1E57, S::Lower),
+    (0x1E58, 0x1E58, S::Upper),
+    (0x1E59, 0x1E59, S::Lower),
+    (0x1E5A, 0x1E5A, S::Upper),
+    (0x1E5B, 0x1E5B, S::Lower),
+    (0x1E5C, 0x1E5C, S::Upper),
+    (0x1E5D, 0x1E5D, S::Lower),
+    (0x1E5E, 0x1E5E, S::Upper),
+    (0x1E5F, 0x1E5F, S::Lower),
+    (0x1E60, 0x1E60, S::Upper),
+    (0x1E61, 0x1E61, S::Lower),
+    (0x1E62, 0x1E62, S::Upper),
+    (0x1E63, 0x1E63, S::Lower),
+    (0x1E64, 0x1E64, S::Upper),
+    (0x1E65, 0x1E65, S::Lower),
+    (0x1E66, 0x1E66, S::Upper),
+    (0x1E67, 0x1E67, S::Lower),
+    (0x1E68, 0x1E68, S::Upper),
+    (0x1E69, 0x1E69, S::Lower),
+    (0x1E6A, 0x1E6A, S::Upper),
+    (0x1E6B, 0x1E6B, S::Lower),
+    (0x1E6C, 0x1E6C, S::Upper),
+    (0x1E6D, 0x1E6D, S::Lower),
+    (0x1E6E, 0x1E6E, S::Upper),
+    (0x1E6F, 0x1E6F, S::Lower),
+    (0x1E70, 0x1E70, S::Upper),
+    (0x1E71, 0x1E71, S::Lower),
+    (0x1E72, 0x1E72, S::Upper),
+    (0x1E73, 0x1E73, S::Lower),
+    (0x1E74, 0x1E74, S::Upper),
+    (0x1E75, 0x1E75, S::Lower),
+    (0x1E76, 0x1E76, S::Upper),
+    (0x1E77, 0x1E77, S::Lower),
+    (0x1E78, 0x1E78, S::Upper),
+    (0x1E79, 0x1E79, S::Lower),
+    (0x1E7A, 0x1E7A, S::Upper),
+    (0x1E7B, 0x1E7B, S::Lower),
+    (0x1E7C, 0x1E7C, S::Upper),
+    (0x1E7D, 0x1E7D, S::Lower),
+    (0x1E7E, 0x1E7E, S::Upper),
+    (0x1E7F, 0x1E7F, S::Lower),
+    (0x1E80, 0x1E80, S::Upper),
+    (0x1E81, 0x1E81, S::Lower),
+    (0x1E82, 0x1E82, S::Upper),
+    (0x1E83, 0x1E83, S::Lower),
+    (0x1E84, 0x1E84, S::Upper),
+    (0x1E85, 0x1E85, S::Lower),
+    (0x1E86, 0x1E86, S::Upper),
+    (0x1E87, 0x1E87, S::Lower),
+    (0x1E88, 0x1E88, S::Upper),
+    (0x1E89, 0x1E89, S::Lower),
+    (0x1E8A, 0x1E8A, S::Upper),
+    (0x1E8B, 0x1E8B, S::Lower),
+    (0x1E8C, 0x1E8C, S::Upper),
+    (0x1E8D, 0x1E8D, S::Lower),
+    (0x1E8E, 0x1E8E, S::Upper),
+    (0x1E8F, 0x1E8F, S::Lower),
+    (0x1E90, 0x1E90, S::Upper),
+    (0x1E91, 0x1E91, S::Lower),
+    (0x1E92, 0x1E92, S::Upper),
+    (0x1E93, 0x1E93, S::Lower),
+    (0x1E94, 0x1E94, S::Upper),
+    (0x1E95, 0x1E9D, S::Lower),
+    (0x1E9E, 0x1E9E, S::Upper),
+    (0x1E9F, 0x1E9F, S::Lower),
+    (0x1EA0, 0x1EA0, S::Upper),
+    (0x1EA1, 0x1EA1, S::Lower),
+    (0x1EA2, 0x1EA2, S::Upper),
+    (0x1EA3, 0x1EA3, S::Lower),
+    (0x1EA4, 0x1EA4, S::Upper),
+    (0x1EA5, 0x1EA5, S::Lower),
+    (0x1EA6, 0x1EA6, S::Upper),
+    (0x1EA7, 0x1EA7, S::Lower),
+    (0x1EA8, 0x1EA8, S::Upper),
+    (0x1EA9, 0x1EA9, S::Lower),
+    (0x1EAA, 0x1EAA, S::Upper),
+    (0x1EAB, 0x1EAB, S::Lower),
+    (0x1EAC, 0x1EAC, S::Upper),
+    (0x1EAD, 0x1EAD, S::Lower),
+    (0x1EAE, 0x1EAE, S::Upper),
+    (0x1EAF, 0x1EAF, S::Lower),
+    (0x1EB0, 0x1EB0, S::Upper),
+    (0x1EB1, 0x1EB1, S::Lower),
+    (0x1EB2, 0x1EB2, S::Upper),
+    (0x1EB3, 0x1EB3, S::Lower),
+    (0x1EB4, 0x1EB4, S::Upper),
+    (0x1EB5, 0x1EB5, S::Lower),
+    (0x1EB6, 0x1EB6, S::Upper),
+    (0x1EB7, 0x1EB7, S::Lower),
+    (0x1EB8, 0x1EB8, S::Upper),
+    (0x1EB9, 0x1EB9, S::Lower),
+    (0x1EBA, 0x1EBA, S::Upper),
+    (0x1EBB, 0x1EBB, S::Lower),
+    (0x1EBC, 0x1EBC, S::Upper),
+    (0x1EBD, 0x1EBD, S::Lower),
+    (0x1EBE, 0x1EBE, S::Upper),
+    (0x1EBF, 0x1EBF, S::Lower),
+    (0x1EC0, 0x1EC0, S::Upper),
+    (0x1EC1, 0x1EC1, S::Lower),
+    (0x1EC2, 0x1EC2, S::Upper),
+    (0x1EC3, 0x1EC3, S::Lower),
+    (0x1EC4, 0x1EC4, S::Upper),
+    (0x1EC5, 0x1EC5, S::Lower),
+    (0x1EC6, 0x1EC6, S::Upper),
+    (0x1EC7, 0x1EC7, S::Lower),
+    (0x1EC8, 0x1EC8, S::Upper),
+    (0x1EC9, 0x1EC9, S::Lower),
+    (0x1ECA, 0x1ECA, S::Upper),
+    (0x1ECB, 0x1ECB, S::Lower),
+    (0x1ECC, 0x1ECC, S::Upper),
+    (0x1ECD, 0x1ECD, S::Lower),
+    (0x1ECE, 0x1ECE, S::Upper),
+    (0x1ECF, 0x1ECF, S::Lower),
+    (0x1ED0, 0x1ED0, S::Upper),
+    (0x1ED1, 0x1ED1, S::Lower),
+    (0x1ED2, 0x1ED2, S::Upper),
+    (0x1ED3, 0x1ED3, S::Lower),
+    (0x1ED4, 0x1ED4, S::Upper),
+    (0x1ED5, 0x1ED5, S::Lower),
+    (0x1ED6, 0x1ED6, S::Upper),
+    (0x1ED7, 0x1ED7, S::Lower),
+    (0x1ED8, 0x1ED8, S::Upper),
+    (0x1ED9, 0x1ED9, S::Lower),
+    (0x1EDA, 0x1EDA, S::Upper),
+    (0x1EDB, 0x1EDB, S::Lower),
+    (0x1EDC, 0x1EDC, S::Upper),
+    (0x1EDD, 0x1EDD, S::Lower),
+    (0x1EDE, 0x1EDE, S::Upper),
+    (0x1EDF, 0x1EDF, S::Lower),
+    (0x1EE0, 0x1EE0, S::Upper),
+    (0x1EE1, 0x1EE1, S::Lower),
+    (0x1EE2, 0x1EE2, S::Upper),
+    (0x1EE3, 0x1EE3, S::Lower),
+    (0x1EE4, 0x1EE4, S::Upper),
+    (0x1EE5, 0x1EE5, S::Lower),
+    (0x1EE6, 0x1EE6, S::Upper),
+    (0x1EE7, 0x1EE7, S::Lower),
+    (0x1EE8, 0x1EE8, S::Upper),
+    (0x1EE9, 0x1EE9, S::Lower),
+    (0x1EEA, 0x1EEA, S::Upper),
+    (0x1EEB, 0x1EEB, S::Lower),
+    (0x1EEC, 0x1EEC, S::Upper),
+    (0x1EED, 0x1EED, S::Lower),
+    (0x1EEE, 0x1EEE, S::Upper),
+    (0x1EEF, 0x1EEF, S::Lower),
+    (0x1EF0, 0x1EF0, S::Upper),
+    (0x1EF1, 0x1EF1, S::Lower),
+    (0x1EF2, 0x1EF2, S::Upper),
+    (0x1EF3, 0x1EF3, S::Lower),
+    (0x1EF4, 0x1EF4, S::Upper),
+    (0x1EF5, 0x1EF5, S::Lower),
+    (0x1EF6, 0x1EF6, S::Upper),
+    (0x1EF7, 0x1EF7, S::Lower),
+    (0x1EF8, 0x1EF8, S::Upper),
+    (0x1EF9, 0x1EF9, S::Lower),
+    (0x1EFA, 0x1EFA, S::Upper),
+    (0x1EFB, 0x1EFB, S::Lower),
+    (0x1EFC, 0x1EFC, S::Upper),
+    (0x1EFD, 0x1EFD, S::Lower),
+    (0x1EFE, 0x1EFE, S::Upper),
+    (0x1EFF, 0x1F07, S::Lower),
+    (0x1F08, 0x1F0F, S::Upper),
+    (0x1F10, 0x1F15, S::Lower),
+    (0x1F18, 0x1F1D, S::Upper),
+    (0x1F20, 0x1F27, S::Lower),
+    (0x1F28, 0x1F2F, S::Upper),
+    (0x1F30, 0x1F37, S::Lower),
+    (0x1F38, 0x1F3F, S::Upper),
+    (0x1F40, 0x1F45, S::Lower),
+    (0x1F48, 0x1F4D, S::Upper),
+    (0x1F50, 0x1F57, S::Lower),
+    (0x1F59, 0x1F59, S::Upper),
+    (0x1F5B, 0x1F5B, S::Upper),
+    (0x1F5D, 0x1F5D, S::Upper),
+    (0x1F5F, 0x1F5F, S::Upper),
+    (0x1F60, 0x1F67, S::Lower),
+    (0x1F68, 0x1F6F, S::Upper),
+    (0x1F70, 0x1F7D, S::Lower),
+    (0x1F80, 0x1F87, S::Lower),
+    (0x1F88, 0x1F8F, S::Upper),
+    (0x1F90, 0x1F97, S::Lower),
+    (0x1F98, 0x1F9F, S::Upper),
+    (0x1FA0, 0x1FA7, S::Lower),
+    (0x1FA8, 0x1FAF, S::Upper),
+    (0x1FB0, 0x1FB4, S::Lower),
+    (0x1FB6, 0x1FB7, S::Lower),
+    (0x1FB8, 0x1FBC, S::Upper),
+    (0x1FBE, 0x1FBE, S::Lower),
+    (0x1FC2, 0x1FC4, S::Lower),
+    (0x1FC6, 0x1FC7, S::Lower),
+    (0x1FC8, 0x1FCC, S::Upper),
+    (0x1FD0, 0x1FD3, S::Lower),
+    (0x1FD6, 0x1FD7, S::Lower),
+    (0x1FD8, 0x1FDB, S::Upper),
+    (0x1FE0, 0x1FE7, S::Lower),
+    (0x1FE8, 0x1FEC, S::Upper),
+    (0x1FF2, 0x1FF4, S::Lower),
+    (0x1FF6, 0x1FF7, S::Lower),
+    (0x1FF8, 0x1FFC, S::Upper),
+    (0x2000, 0x200A, S::Sp),
+    (0x200B, 0x200B, S::Format),
+    (0x200C, 0x200D, S::Extend),
+    (0x200E, 0x200F, S::Format),
+    (0x2013, 0x2014, S::SContinue),
+    (0x2018, 0x201F, S::Close),
+    (0x2024, 0x2024, S::ATerm),
+    (0x2028, 0x2029, S::Sep),
+    (0x202A, 0x202E, S::Format),
+    (0x202F, 0x202F, S::Sp),
+    (0x2039, 0x203A, S::Close),
+    (0x203C, 0x203D, S::STerm),
+    (0x2045, 0x2046, S::Close),
+    (0x2047, 0x2049, S::STerm),
+    (0x205F, 0x205F, S::Sp),
+    (0x2060, 0x2064, S::Format),
+    (0x2066, 0x206F, S::Format),
+    (0x2071, 0x2071, S::Lower),
+    (0x207D, 0x207E, S::Close),
+    (0x207F, 0x207F, S::Lower),
+    (0x208D, 0x208E, S::Close),
+    (0x2090, 0x209C, S::Lower),
+    (0x20D0, 0x20F0, S::Extend),
+    (0x2102, 0x2102, S::Upper),
+    (0x2107, 0x2107, S::Upper),
+    (0x210A, 0x210A, S::Lower),
+    (0x210B, 0x210D, S::Upper),
+    (0x210E, 0x210F, S::Lower),
+    (0x2110, 0x2112, S::Upper),
+    (0x2113, 0x2113, S::Lower),
+    (0x2115, 0x2115, S::Upper),
+    (0x2119, 0x211D, S::Upper),
+    (0x2124, 0x2124, S::Upper),
+    (0x2126, 0x2126, S::Upper),
+    (0x2128, 0x2128, S::Upper),
+    (0x212A, 0x212D, S::Upper),
+    (0x212F, 0x212F, S::Lower),
+    (0x2130, 0x2133, S::Upper),
+    (0x2134, 0x2134, S::Lower),
+    (0x2135, 0x2138, S::OLetter),
+    (0x2139, 0x2139, S::Lower),
+    (0x213C, 0x213D, S::Lower),
+    (0x213E, 0x213F, S::Upper),
+    (0x2145, 0x2145, S::Upper),
+    (0x2146, 0x2149, S::Lower),
+    (0x214E, 0x214E, S::Lower),
+    (0x2160, 0x216F, S::Upper),
+    (0x2170, 0x217F, S::Lower),
+    (0x2180, 0x2182, S::OLetter),
+    (0x2183, 0x2183, S::Upper),
+    (0x2184, 0x2184, S::Lower),
+    (0x2185, 0x2188, S::OLetter),
+    (0x2308, 0x230B, S::Close),
+    (0x2329, 0x232A, S::Close),
+    (0x24B6, 0x24CF, S::Upper),
+    (0x24D0, 0x24E9, S::Lower),
+    (0x275B, 0x2760, S::Close),
+    (0x2768, 0x2775, S::Close),
+    (0x27C5, 0x27C6, S::Close),
+    (0x27E6, 0x27EF, S::Close),
+    (0x2983, 0x2998, S::Close),
+    (0x29D8, 0x29DB, S::Close),
+    (0x29FC, 0x29FD, S::Close),
+    (0x2C00, 0x2C2F, S::Upper),
+    (0x2C30, 0x2C5F, S::Lower),
+    (0x2C60, 0x2C60, S::Upper),
+    (0x2C61, 0x2C61, S::Lower),
+    (0x2C62, 0x2C64, S::Upper),
+    (0x2C65, 0x2C66, S::Lower),
+    (0x2C67, 0x2C67, S::Upper),
+    (0x2C68, 0x2C68, S::Lower),
+    (0x2C69, 0x2C69, S::Upper),
+    (0x2C6A, 0x2C6A, S::Lower),
+    (0x2C6B, 0x2C6B, S::Upper),
+    (0x2C6C, 0x2C6C, S::Lower),
+    (0x2C6D, 0x2C70, S::Upper),
+    (0x2C71, 0x2C71, S::Lower),
+    (0x2C72, 0x2C72, S::Upper),
+    (0x2C73, 0x2C74, S::Lower),
+    (0x2C75, 0x2C75, S::Upper),
+    (0x2C76, 0x2C7D, S::Lower),
+    (0x2C7E, 0x2C80, S::Upper),
+    (0x2C81, 0x2C81, S::Lower),
+    (0x2C82, 0x2C82, S::Upper),
+    (0x2C83, 0x2C83, S::Lower),
+    (0x2C84, 0x2C84, S::Upper),
+    (0x2C85, 0x2C85, S::Lower),
+    (0x2C86, 0x2C86, S::Upper),
+    (0x2C87, 0x2C87, S::Lower),
+    (0x2C88, 0x2C88, S::Upper),
+    (0x2C89, 0x2C89, S::Lower),
+    (0x2C8A, 0x2C8A, S::Upper),
+    (0x2C8B, 0x2C8B, S::Lower),
+    (0x2C8C, 0x2C8C, S::Upper),
+    (0x2C8D, 0x2C8D, S::Lower),
+    (0x2C8E, 0x2C8E, S::Upper),
+    (0x2C8F, 0x2C8F, S::Lower),
+    (0x2C90, 0x2C90, S::Upper),
+    (0x2C91, 0x2C91, S::Lower),
+    (0x2C92, 0x2C92, S::Upper),
+    (0x2C93, 0x2C93, S::Lower),
+    (0x2C94, 0x2C94, S::Upper),
+    (0x2C95, 0x2C95, S::Lower),
+    (0x2C96, 0x2C96, S::Upper),
+    (0x2C97, 0x2C97, S::Lower),
+    (0x2C98, 0x2C98, S::Upper),
+    (0x2C99, 0x2C99, S::Lower),
+    (0x2C9A, 0x2C9A, S::Upper),
+    (0x2C9B, 0x2C9B, S::Lower),
+    (0x2C9C, 0x2C9C, S::Upper),
+    (0x2C9D, 0x2C9D, S::Lower),
+    (0x2C9E, 0x2C9E, S::Upper),
+    (0x2C9F, 0x2C9F, S::Lower),
+    (0x2CA0, 0x2CA0, S::Upper),
+    (0x2CA1, 0x2CA1, S::Lower),
+    (0x2CA2, 0x2CA2, S::Upper),
+    (0x2CA3, 0x2CA3, S::Lower),
+    (0x2CA4, 0x2CA4, S::Upper),
+    (0x2CA5, 0x2CA5, S::Lower),
+    (0x2CA6, 0x2CA6, S::Upper),
+    (0x2CA7, 0x2CA7, S::Lower),
+    (0x2CA8, 0x2CA8, S::Upper),
+    (0x2CA9, 0x2CA9, S::Lower),
+    (0x2CAA, 0x2CAA, S::Upper),
+    (0x2CAB, 0x2CAB, S::Lower),
+    (0x2CAC, 0x2CAC, S::Upper),
+    (0x2CAD, 0x2CAD, S::Lower),
+    (0x2CAE, 0x2CAE, S::Upper),
+    (0x2CAF, 0x2CAF, S::Lower),
+    (0x2CB0, 0x2CB0, S::Upper),
+    (0x2CB1, 0x2CB1, S::Lower),
+    (0x2CB2, 0x2CB2, S::Upper),
+    (0x2CB3, 0x2CB3, S::Lower),
+    (0x2CB4, 0x2CB4, S::Upper),
+    (0x2CB5, 0x2CB5, S::Lower),
+    (0x2CB6, 0x2CB6, S::Upper),
+    (0x2CB7, 0x2CB7, S::Lower),
+    (0x2CB8, 0x2CB8, S::Upper),
+    (0x2CB9, 0x2CB9, S::Lower),
+    (0x2CBA, 0x2CBA, S::Upper),
+    (0x2CBB, 0x2CBB, S::Lower),
+    (0x2CBC, 0x2CBC, S::Upper),
+    (0x2CBD, 0x2CBD, S::Lower),
+    (0x2CBE, 0x2CBE, S::Upper),
+    (0x2CBF, 0x2CBF, S::Lower),
+    (0x2CC0, 0x2CC0, S::Upper),
+    (0x2CC1, 0x2CC1, S::Lower),
+    (0x2CC2, 0x2CC2, S::Upper),
+    (0x2CC3, 0x2CC3, S::Lower),
+    (0x2CC4, 0x2CC4, S::Upper),
+    (0x2CC5, 0x2CC5, S::Lower),
+    (0x2CC6, 0x2CC6, S::Upper),
+    (0x2CC7, 0x2CC7, S::Lower),
+    (0x2CC8, 0x2CC8, S::Upper),
+    (0x2CC9, 0x2CC9, S::Lower),
+    (0x2CCA, 0x2CCA, S::Upper),
+    (0x2CCB, 0x2CCB, S::Lower),
+    (0x2CCC, 0x2CCC, S::Upper),
+    (0x2CCD, 0x2CCD, S::Lower),
+    (0x2CCE, 0x2CCE, S::Upper),
+    (0x2CCF, 0x2CCF, S::Lower),
+    (0x2CD0, 0x2CD0, S::Upper),
+    (0x2CD1, 0x2CD1, S::Lower),
+    (0x2CD2, 0x2CD2, S::Upper),
+    (0x2CD3, 0x2CD3, S::Lower),
+    (0x2CD4, 0x2CD4, S::Upper),
+    (0x2CD5, 0x2CD5, S::Lower),
+    (0x2CD6, 0x2CD6, S::Upper),
+    (0x2CD7, 0x2CD7, S::Lower),
+    (0x2CD8, 0x2CD8, S::Upper),
+    (0x2CD9, 0x2CD9, S::Lower),
+    (0x2CDA, 0x2CDA, S::Upper),
+    (0x2CDB, 0x2CDB, S::Lower),
+    (0x2CDC, 0x2CDC, S::Upper),
+    (0x2CDD, 0x2CDD, S::Lower),
+    (0x2CDE, 0x2CDE, S::Upper),
+    (0x2CDF, 0x2CDF, S::Lower),
+    (0x2CE0, 0x2CE0, S::Upper),
+    (0x2CE1, 0x2CE1, S::Lower),
+    (0x2CE2, 0x2CE2, S::Upper),
+    (0x2CE3, 0x2CE4, S::Lower),
+    (0x2CEB, 0x2CEB, S::Upper),
+    (0x2CEC, 0x2CEC, S::Lower),
+    (0x2CED, 0x2CED, S::Upper),
+    (0x2CEE, 0x2CEE, S::Lower),
+    (0x2CEF, 0x2CF1, S::Extend),
+    (0x2CF2, 0x2CF2, S::Upper),
+    (0x2CF3, 0x2CF3, S::Lower),
+    (0x2D00, 0x2D25, S::Lower),
+    (0x2D27, 0x2D27, S::Lower),
+    (0x2D2D, 0x2D2D, S::Lower),
+    (0x2D30, 0x2D67, S::OLetter),
+    (0x2D6F, 0x2D6F, S::OLetter),
+    (0x2D7F, 0x2D7F, S::Extend),
+    (0x2D80, 0x2D96, S::OLetter),
+    (0x2DA0, 0x2DA6, S::OLetter),
+    (0x2DA8, 0x2DAE, S::OLetter),
+    (0x2DB0, 0x2DB6, S::OLetter),
+    (0x2DB8, 0x2DBE, S::OLetter),
+    (0x2DC0, 0x2DC6, S::OLetter),
+    (0x2DC8, 0x2DCE, S::OLetter),
+    (0x2DD0, 0x2DD6, S::OLetter),
+    (0x2DD8, 0x2DDE, S::OLetter),
+    (0x2DE0, 0x2DFF, S::Extend),
+    (0x2E00, 0x2E0D, S::Close),
+    (0x2E1C, 0x2E1D, S::Close),
+    (0x2E20, 0x2E29, S::Close),
+    (0x2E2E, 0x2E2E, S::STerm),
+    (0x2E2F, 0x2E2F, S::OLetter),
+    (0x2E3C, 0x2E3C, S::STerm),
+    (0x2E42, 0x2E42, S::Close),
+    (0x2E53, 0x2E54, S::STerm),
+    (0x2E55, 0x2E5C, S::Close),
+    (0x3000, 0x3000, S::Sp),
+    (0x3001, 0x3001, S::SContinue),
+    (0x3002, 0x3002, S::STerm),
+    (0x3005, 0x3007, S::OLetter),
+    (0x3008, 0x3011, S::Close),
+    (0x3014, 0x301B, S::Close),
+    (0x301D, 0x301F, S::Close),
+    (0x3021, 0x3029, S::OLetter),
+    (0x302A, 0x302F, S::Extend),
+    (0x3031, 0x3035, S::OLetter),
+    (0x3038, 0x303C, S::OLetter),
+    (0x3041, 0x3096, S::OLetter),
+    (0x3099, 0x309A, S::Extend),
+    (0x309D, 0x309F, S::OLetter),
+    (0x30A1, 0x30FA, S::OLetter),
+    (0x30FC, 0x30FF, S::OLetter),
+    (0x3105, 0x312F, S::OLetter),
+    (0x3131, 0x318E, S::OLetter),
+    (0x31A0, 0x31BF, S::OLetter),
+    (0x31F0, 0x31FF, S::OLetter),
+    (0x3400, 0x4DBF, S::OLetter),
+    (0x4E00, 0xA48C, S::OLetter),
+    (0xA4D0, 0xA4FD, S::OLetter),
+    (0xA4FF, 0xA4FF, S::STerm),
+    (0xA500, 0xA60C, S::OLetter),
+    (0xA60E, 0xA60F, S::STerm),
+    (0xA610, 0xA61F, S::OLetter),
+    (0xA620, 0xA629, S::Numeric),
+    (0xA62A, 0xA62B, S::OLetter),
+    (0xA640, 0xA640, S::Upper),
+    (0xA641, 0xA641, S::Lower),
+    (0xA642, 0xA642, S::Upper),
+    (0xA643, 0xA643, S::Lower),
+    (0xA644, 0xA644, S::Upper),
+    (0xA645, 0xA645, S::Lower),
+    (0xA646, 0xA646, S::Upper),
+    (0xA647, 0xA647, S::Lower),
+    (0xA648, 0xA648, S::Upper),
+    (0xA649, 0xA649, S::Lower),
+    (0xA64A, 0xA64A, S::Upper),
+    (0xA64B, 0xA64B, S::Lower),
+    (0xA64C, 0xA64C, S::Upper),
+    (0xA64D, 0xA64D, S::Lower),
+    (0xA64E, 0xA64E, S::Upper),
+    (0xA64F, 0xA64F, S::Lower),
+    (0xA650, 0xA650, S::Upper),
+    (0xA651, 0xA651, S::Lower),
+    (0xA652, 0xA652, S::Upper),
+    (0xA653, 0xA653, S::Lower),
+    (0xA654, 0xA654, S::Upper),
+    (0xA655, 0xA655, S::Lower),
+    (0xA656, 0xA656, S::Upper),
+    (0xA657, 0xA657, S::Lower),
+    (0xA658, 0xA658, S::Upper),
+    (0xA659, 0xA659, S::Lower),
+    (0xA65A, 0xA65A, S::Upper),
+    (0xA65B, 0xA65B, S::Lower),
+    (0xA65C, 0xA65C, S::Upper),
+    (0xA65D, 0xA65D, S::Lower),
+    (0xA65E, 0xA65E, S::Upper),
+    (0xA65F, 0xA65F, S::Lower),
+    (0xA660, 0xA660, S::Upper),
+    (0xA661, 0xA661, S::Lower),
+    (0xA662, 0xA662, S::Upper),
+    (0xA663, 0xA663, S::Lower),
+    (0xA664, 0xA664, S::Upper),
+    (0xA665, 0xA665, S::Lower),
+    (0xA666, 0xA666, S::Upper),
+    (0xA667, 0xA667, S::Lower),
+    (0xA668, 0xA668, S::Upper),
+    (0xA669, 0xA669, S::Lower),
+    (0xA66A, 0xA66A, S::Upper),
+    (0xA66B, 0xA66B, S::Lower),
+    (0xA66C, 0xA66C, S::Upper),
+    (0xA66D, 0xA66D, S::Lower),
+    (0xA66E, 0xA66E, S::OLetter),
+    (0xA66F, 0xA672, S::Extend),
+    (0xA674, 0xA67D, S::Extend),
+    (0xA67F, 0xA67F, S::OLetter),
+    (0xA680, 0xA680, S::Upper),
+    (0xA681, 0xA681, S::Lower),
+    (0xA682, 0xA682, S::Upper),
+    (0xA683, 0xA683, S::Lower),
+    (0xA684, 0xA684, S::Upper),
+    (0xA685, 0xA685, S::Lower),
+    (0xA686, 0xA686, S::Upper),
+    (0xA687, 0xA687, S::Lower),
+    (0xA688, 0xA688, S::Upper),
+    (0xA689, 0xA689, S::Lower),
+    (0xA68A, 0xA68A, S::Upper),
+    (0xA68B, 0xA68B, S::Lower),
+    (0xA68C, 0xA68C, S::Upper),
+    (0xA68D, 0xA68D, S::Lower),
+    (0xA68E, 0xA68E, S::Upper),
+    (0xA68F, 0xA68F, S::Lower),
+    (0xA690, 0xA690, S::Upper),
+    (0xA691, 0xA691, S::Lower),
+    (0xA692, 0xA692, S::Upper),
+    (0xA693, 0xA693, S::Lower),
+    (0xA694, 0xA694, S::Upper),
+    (0xA695, 0xA695, S::Lower),
+    (0xA696, 0xA696, S::Upper),
+    (0xA697, 0xA697, S::Lower),
+    (0xA698, 0xA698, S::Upper),
+    (0xA699, 0xA699, S::Lower),
+    (0xA69A, 0xA69A, S::Upper),
+    (0xA69B, 0xA69D, S::Lower),
+    (0xA69E, 0xA69F, S::Extend),
+    (0xA6A0, 0xA6EF, S::OLetter),
+    (0xA6F0, 0xA6F1, S::Extend),
+    (0xA6F3, 0xA6F3, S::STerm),
+    (0xA6F7, 0xA6F7, S::STerm),
+    (0xA717, 0xA71F, S::OLetter),
+    (0xA722, 0xA722, S::Upper),
+    (0xA723, 0xA723, S::Lower),
+    (0xA724, 0xA724, S::Upper),
+    (0xA725, 0xA725, S::Lower),
+    (0xA726, 0xA726, S::Upper),
+    (0xA727, 0xA727, S::Lower),
+    (0xA728, 0xA728, S::Upper),
+    (0xA729, 0xA729, S::Lower),
+    (0xA72A, 0xA72A, S::Upper),
+    (0xA72B, 0xA72B, S::Lower),
+    (0xA72C, 0xA72C, S::Upper),
+    (0xA72D, 0xA72D, S::Lower),
+    (0xA72E, 0xA72E, S::Upper),
+    (0xA72F, 0xA731, S::Lower),
+    (0xA732, 0xA732, S::Upper),
+    (0xA733, 0xA733, S::Lower),
+    (0xA734, 0xA734, S::Upper),
+    (0xA735, 0xA735, S::Lower),
+    (0xA736, 0xA736, S::Upper),
+    (0xA737, 0xA737, S::Lower),
+    (0xA738, 0xA738, S::Upper),
+    (0xA739, 0xA739, S::Lower),
+    (0xA73A, 0xA73A, S::Upper),
+    (0xA73B, 0xA73B, S::Lower),
+    (0xA73C, 0xA73C, S::Upper),
+    (0xA73D, 0xA73D, S::Lower),
+    (0xA73E, 0xA73E, S::Upper),
+    (0xA73F, 0xA73F, S::Lower),
+    (0xA740, 0xA740, S::Upper),
+    (0xA741, 0xA741, S::Lower),
+    (0xA742, 0xA742, S::Upper),
+    (0xA743, 0xA743, S::Lower),
+    (0xA744, 0xA744, S::Upper),
+    (0xA745, 0xA745, S::Lower),
+    (0xA746, 0xA746, S::Upper),
+    (0xA747, 0xA747, S::Lower),
+    (0xA748, 0xA748, S::Upper),
+    (0xA749, 0xA749, S::Lower),
+    (0xA74A, 0xA74A, S::Upper),
+    (0xA74B, 0xA74B, S::Lower),
+    (0xA74C, 0xA74C, S::Upper),
+    (0xA74D, 0xA74D, S::Lower),
+    (0xA74E, 0xA74E, S::Upper),
+    (0xA74F, 0xA74F, S::Lower),
+    (0xA750, 0xA750, S::Upper),
+    (0xA751, 0xA751, S::Lower),
+    (0xA752, 0xA752, S::Upper),
+    (0xA753, 0xA753, S::Lower),
+    (0xA754, 0xA754, S::Upper),
+    (0xA755, 0xA755, S::Lower),
+    (0xA756, 0xA756, S::Upper),
+    (0xA757, 0xA757, S::Lower),
+    (0xA758, 0xA758, S::Upper),
+    (0xA759, 0xA759, S::Lower),
+    (0xA75A, 0xA75A, S::Upper),
+    (0xA75B, 0xA75B, S::Lower),
+    (0xA75C, 0xA75C, S::Upper),
+    (0xA75D, 0xA75D, S::Lower),
+    (0xA75E, 0xA75E, S::Upper),
+    (0xA75F, 0xA75F, S::Lower),
+    (0xA760, 0xA760, S::Upper),
+    (0xA761, 0xA761, S::Lower),
+    (0xA762, 0xA762, S::Upper),
+    (0xA763, 0xA763, S::Lower),
+    (0xA764, 0xA764, S::Upper),
+    (0xA765, 0xA765, S::Lower),
+    (0xA766, 0xA766, S::Upper),
+    (0xA767, 0xA767, S::Lower),
+    (0xA768, 0xA768, S::Upper),
+    (0xA769, 0xA769, S::Lower),
+    (0xA76A, 0xA76A, S::Upper),
+    (0xA76B, 0xA76B, S::Lower),
+    (0xA76C, 0xA76C, S::Upper),
+    (0xA76D, 0xA76D, S::Lower),
+    (0xA76E, 0xA76E, S::Upper),
+    (0xA76F, 0xA778, S::Lower),
+    (0xA779, 0xA779, S::Upper),
+    (0xA77A, 0xA77A, S::Lower),
+    (0xA77B, 0xA77B, S::Upper),
+    (0xA77C, 0xA77C, S::Lower),
+    (0xA77D, 0xA77E, S::Upper),
+    (0xA77F, 0xA77F, S::Lower),
+    (0xA780, 0xA780, S::Upper),
+    (0xA781, 0xA781, S::Lower),
+    (0xA782, 0xA782, S::Upper),
+    (0xA783, 0xA783, S::Lower),
+    (0xA784, 0xA784, S::Upper),
+    (0xA785, 0xA785, S::Lower),
+    (0xA786, 0xA786, S::Upper),
+    (0xA787, 0xA787, S::Lower),
+    (0xA788, 0xA788, S::OLetter),
+    (0xA78B, 0xA78B, S::Upper),
+    (0xA78C, 0xA78C, S::Lower),
+    (0xA78D, 0xA78D, S::Upper),
+    (0xA78E, 0xA78E, S::Lower),
+    (0xA78F, 0xA78F, S::OLetter),
+    (0xA790, 0xA790, S::Upper),
+    (0xA791, 0xA791, S::Lower),
+    (0xA792, 0xA792, S::Upper),
+    (0xA793, 0xA795, S::Lower),
+    (0xA796, 0xA796, S::Upper),
+    (0xA797, 0xA797, S::Lower),
+    (0xA798, 0xA798, S::Upper),
+    (0xA799, 0xA799, S::Lower),
+    (0xA79A, 0xA79A, S::Upper),
+    (0xA79B, 0xA79B, S::Lower),
+    (0xA79C, 0xA79C, S::Upper),
+    (0xA79D, 0xA79D, S::Lower),
+    (0xA79E, 0xA79E, S::Upper),
+    (0xA79F, 0xA79F, S::Lower),
+    (0xA7A0, 0xA7A0, S::Upper),
+    (0xA7A1, 0xA7A1, S::Lower),
+    (0xA7A2, 0xA7A2, S::Upper),
+    (0xA7A3, 0xA7A3, S::Lower),
+    (0xA7A4, 0xA7A4, S::Upper),
+    (0xA7A5, 0xA7A5, S::Lower),
+    (0xA7A6, 0xA7A6, S::Upper),
+    (0xA7A7, 0xA7A7, S::Lower),
+    (0xA7A8, 0xA7A8, S::Upper),
+    (0xA7A9, 0xA7A9, S::Lower),
+    (0xA7AA, 0xA7AE, S::Upper),
+    (0xA7AF, 0xA7AF, S::Lower),
+    (0xA7B0, 0xA7B4, S::Upper),
+    (0xA7B5, 0xA7B5, S::Lower),
+    (0xA7B6, 0xA7B6, S::Upper),
+    (0xA7B7, 0xA7B7, S::Lower),
+    (0xA7B8, 0xA7B8, S::Upper),
+    (0xA7B9, 0xA7B9, S::Lower),
+    (0xA7BA, 0xA7BA, S::Upper),
+    (0xA7BB, 0xA7BB, S::Lower),
+    (0xA7BC, 0xA7BC, S::Upper),
+    (0xA7BD, 0xA7BD, S::Lower),
+    (0xA7BE, 0xA7BE, S::Upper),
+    (0xA7BF, 0xA7BF, S::Lower),
+    (0xA7C0, 0xA7C0, S::Upper),
+    (0xA7C1, 0xA7C1, S::Lower),
+    (0xA7C2, 0xA7C2, S::Upper),
+    (0xA7C3, 0xA7C3, S::Lower),
+    (0xA7C4, 0xA7C7, S::Upper),
+    (0xA7C8, 0xA7C8, S::Lower),
+    (0xA7C9, 0xA7C9, S::Upper),
+    (0xA7CA, 0xA7CA, S::Lower),
+    (0xA7D0, 0xA7D0, S::Upper),
+    (0xA7D1, 0xA7D1, S::Lower),
+    (0xA7D3, 0xA7D3, S::Lower),
+    (0xA7D5, 0xA7D5, S::Lower),
+    (0xA7D6, 0xA7D6, S::Upper),
+    (0xA7D7, 0xA7D7, S::Lower),
+    (0xA7D8, 0xA7D8, S::Upper),
+    (0xA7D9, 0xA7D9, S::Lower),
+    (0xA7F2, 0xA7F4, S::Lower),
+    (0xA7F5, 0xA7F5, S::Upper),
+    (0xA7F6, 0xA7F6, S::Lower),
+    (0xA7F7, 0xA7F7, S::OLetter),
+    (0xA7F8, 0xA7FA, S::Lower),
+    (0xA7FB, 0xA801, S::OLetter),
+    (0xA802, 0xA802, S::Extend),
+    (0xA803, 0xA805, S::OLetter),
+    (0xA806, 0xA806, S::Extend),
+    (0xA807, 0xA80A, S::OLetter),
+    (0xA80B, 0xA80B, S::Extend),
+    (0xA80C, 0xA822, S::OLetter),
+    (0xA823, 0xA827, S::Extend),
+    (0xA82C, 0xA82C, S::Extend),
+    (0xA840, 0xA873, S::OLetter),
+    (0xA876, 0xA877, S::STerm),
+    (0xA880, 0xA881, S::Extend),
+    (0xA882, 0xA8B3, S::OLetter),
+    (0xA8B4, 0xA8C5, S::Extend),
+    (0xA8CE, 0xA8CF, S::STerm),
+    (0xA8D0, 0xA8D9, S::Numeric),
+    (0xA8E0, 0xA8F1, S::Extend),
+    (0xA8F2, 0xA8F7, S::OLetter),
+    (0xA8FB, 0xA8FB, S::OLetter),
+    (0xA8FD, 0xA8FE, S::OLetter),
+    (0xA8FF, 0xA8FF, S::Extend),
+    (0xA900, 0xA909, S::Numeric),
+    (0xA90A, 0xA925, S::OLetter),
+    (0xA926, 0xA92D, S::Extend),
+    (0xA92F, 0xA92F, S::STerm),
+    (0xA930, 0xA946, S::OLetter),
+    (0xA947, 0xA953, S::Extend),
+    (0xA960, 0xA97C, S::OLetter),
+    (0xA980, 0xA983, S::Extend),
+    (0xA984, 0xA9B2, S::OLetter),
+    (0xA9B3, 0xA9C0, S::Extend),
+    (0xA9C8, 0xA9C9, S::STerm),
+    (0xA9CF, 0xA9CF, S::OLetter),
+    (0xA9D0, 0xA9D9, S::Numeric),
+    (0xA9E0, 0xA9E4, S::OLetter),
+    (0xA9E5, 0xA9E5, S::Extend),
+    (0xA9E6, 0xA9EF, S::OLetter),
+    (0xA9F0, 0xA9F9, S::Numeric),
+    (0xA9FA, 0xA9FE, S::OLetter),
+    (0xAA00, 0xAA28, S::OLetter),
+    (0xAA29, 0xAA36, S::Extend),
+    (0xAA40, 0xAA42, S::OLetter),
+    (0xAA43, 0xAA43, S::Extend),
+    (0xAA44, 0xAA4B, S::OLetter),
+    (0xAA4C, 0xAA4D, S::Extend),
+    (0xAA50, 0xAA59, S::Numeric),
+    (0xAA5D, 0xAA5F, S::STerm),
+    (0xAA60, 0xAA76, S::OLetter),
+    (0xAA7A, 0xAA7A, S::OLetter),
+    (0xAA7B, 0xAA7D, S::Extend),
+    (0xAA7E, 0xAAAF, S::OLetter),
+    (0xAAB0, 0xAAB0, S::Extend),
+    (0xAAB1, 0xAAB1, S::OLetter),
+    (0xAAB2, 0xAAB4, S::Extend),
+    (0xAAB5, 0xAAB6, S::OLetter),
+    (0xAAB7, 0xAAB8, S::Extend),
+    (0xAAB9, 0xAABD, S::OLetter),
+    (0xAABE, 0xAABF, S::Extend),
+    (0xAAC0, 0xAAC0, S::OLetter),
+    (0xAAC1, 0xAAC1, S::Extend),
+    (0xAAC2, 0xAAC2, S::OLetter),
+    (0xAADB, 0xAADD, S::OLetter),
+    (0xAAE0, 0xAAEA, S::OLetter),
+    (0xAAEB, 0xAAEF, S::Extend),
+    (0xAAF0, 0xAAF1, S::STerm),
+    (0xAAF2, 0xAAF4, S::OLetter),
+    (0xAAF5, 0xAAF6, S::Extend),
+    (0xAB01, 0xAB06, S::OLetter),
+    (0xAB09, 0xAB0E, S::OLetter),
+    (0xAB11, 0xAB16, S::OLetter),
+    (0xAB20, 0xAB26, S::OLetter),
+    (0xAB28, 0xAB2E, S::OLetter),
+    (0xAB30, 0xAB5A, S::Lower),
+    (0xAB5C, 0xAB69, S::Lower),
+    (0xAB70, 0xABBF, S::Lower),
+    (0xABC0, 0xABE2, S::OLetter),
+    (0xABE3, 0xABEA, S::Extend),
+    (0xABEB, 0xABEB, S::STerm),
+    (0xABEC, 0xABED, S::Extend),
+    (0xABF0, 0xABF9, S::Numeric),
+    (0xAC00, 0xD7A3, S::OLetter),
+    (0xD7B0, 0xD7C6, S::OLetter),
+    (0xD7CB, 0xD7FB, S::OLetter),
+    (0xF900, 0xFA6D, S::OLetter),
+    (0xFA70, 0xFAD9, S::OLetter),
+    (0xFB00, 0xFB06, S::Lower),
+    (0xFB13, 0xFB17, S::Lower),
+    (0xFB1D, 0xFB1D, S::OLetter),
+    (0xFB1E, 0xFB1E, S::Extend),
+    (0xFB1F, 0xFB28, S::OLetter),
+    (0xFB2A, 0xFB36, S::OLetter),
+    (0xFB38, 0xFB3C, S::OLetter),
+    (0xFB3E, 0xFB3E, S::OLetter),
+    (0xFB40, 0xFB41, S::OLetter),
+    (0xFB43, 0xFB44, S::OLetter),
+    (0xFB46, 0xFBB1, S::OLetter),
+    (0xFBD3, 0xFD3D, S::OLetter),
+    (0xFD3E, 0xFD3F, S::Close),
+    (0xFD50, 0xFD8F, S::OLetter),
+    (0xFD92, 0xFDC7, S::OLetter),
+    (0xFDF0, 0xFDFB, S::OLetter),
+    (0xFE00, 0xFE0F, S::Extend),
+    (0xFE10, 0xFE11, S::SContinue),
+    (0xFE13, 0xFE13, S::SContinue),
+    (0xFE17, 0xFE18, S::Close),
+    (0xFE20, 0xFE2F, S::Extend),
+    (0xFE31, 0xFE32, S::SContinue),
+    (0xFE35, 0xFE44, S::Close),
+    (0xFE47, 0xFE48, S::Close),
+    (0xFE50, 0xFE51, S::SContinue),
+    (0xFE52, 0xFE52, S::ATerm),
+    (0xFE55, 0xFE55, S::SContinue),
+    (0xFE56, 0xFE57, S::STerm),
+    (0xFE58, 0xFE58, S::SContinue),
+    (0xFE59, 0xFE5E, S::Close),
+    (0xFE63, 0xFE63, S::SContinue),
+    (0xFE70, 0xFE74, S::OLetter),
+    (0xFE76, 0xFEFC, S::OLetter),
+    (0xFEFF, 0xFEFF, S::Format),
+    (0xFF01, 0xFF01, S::STerm),
+    (0xFF08, 0xFF09, S::Close),
+    (0xFF0C, 0xFF0D, S::SContinue),
+    (0xFF0E, 0xFF0E, S::ATerm),
+    (0xFF10, 0xFF19, S::Numeric),
+    (0xFF1A, 0xFF1A, S::SContinue),
+    (0xFF1F, 0xFF1F, S::STerm),
+    (0xFF21, 0xFF3A, S::Upper),
+    (0xFF3B, 0xFF3B, S::Close),
+    (0xFF3D, 0xFF3D, S::Close),
+    (0xFF41, 0xFF5A, S::Lower),
+    (0xFF5B, 0xFF5B, S::Close),
+    (0xFF5D, 0xFF5D, S::Close),
+    (0xFF5F, 0xFF60, S::Close),
+    (0xFF61, 0xFF61, S::STerm),
+    (0xFF62, 0xFF63, S::Close),
+    (0xFF64, 0xFF64, S::SContinue),
+    (0xFF66, 0xFF9D, S::OLetter),
+    (0xFF9E, 0xFF9F, S::Extend),
+    (0xFFA0, 0xFFBE, S::OLetter),
+    (0xFFC2, 0xFFC7, S::OLetter),
+    (0xFFCA, 0xFFCF, S::OLetter),
+    (0xFFD2, 0xFFD7, S::OLetter),
+    (0xFFDA, 0xFFDC, S::OLetter),
+    (0xFFF9, 0xFFFB, S::Format),
+    (0x10000, 0x1000B, S::OLetter),
+    (0x1000D, 0x10026, S::OLetter),
+    (0x10028, 0x1003A, S::OLetter),
+    (0x1003C, 0x1003D, S::OLetter),
+    (0x1003F, 0x1004D, S::OLetter),
+    (0x10050, 0x1005D, S::OLetter),
+    (0x10080, 0x100FA, S::OLetter),
+    (0x10140, 0x10174, S::OLetter),
+    (0x101FD, 0x101FD, S::Extend),
+    (0x10280, 0x1029C, S::OLetter),
+    (0x102A0, 0x102D0, S::OLetter),
+    (0x102E0, 0x102E0, S::Extend),
+    (0x10300, 0x1031F, S::OLetter),
+    (0x1032D, 0x1034A, S::OLetter),
+    (0x10350, 0x10375, S::OLetter),
+    (0x10376, 0x1037A, S::Extend),
+    (0x10380, 0x1039D, S::OLetter),
+    (0x103A0, 0x103C3, S::OLetter),
+    (0x103C8, 0x103CF, S::OLetter),
+    (0x103D1, 0x103D5, S::OLetter),
+    (0x10400, 0x10427, S::Upper),
+    (0x10428, 0x1044F, S::Lower),
+    (0x10450, 0x1049D, S::OLetter),
+    (0x104A0, 0x104A9, S::Numeric),
+    (0x104B0, 0x104D3, S::Upper),
+    (0x104D8, 0x104FB, S::Lower),
+    (0x10500, 0x10527, S::OLetter),
+    (0x10530, 0x10563, S::OLetter),
+    (0x10570, 0x1057A, S::Upper),
+    (0x1057C, 0x1058A, S::Upper),
+    (0x1058C, 0x10592, S::Upper),
+    (0x10594, 0x10595, S::Upper),
+    (0x10597, 0x105A1, S::Lower),
+    (0x105A3, 0x105B1, S::Lower),
+    (0x105B3, 0x105B9, S::Lower),
+    (0x105BB, 0x105BC, S::Lower),
+    (0x10600, 0x10736, S::OLetter),
+    (0x10740, 0x10755, S::OLetter),
+    (0x10760, 0x10767, S::OLetter),
+    (0x10780, 0x10780, S::Lower),
+    (0x10781, 0x10782, S::OLetter),
+    (0x10783, 0x10785, S::Lower),
+    (0x10787, 0x107B0, S::Lower),
+    (0x107B2, 0x107BA, S::Lower),
+    (0x10800, 0x10805, S::OLetter),
+    (0x10808, 0x10808, S::OLetter),
+    (0x1080A, 0x10835, S::OLetter),
+    (0x10837, 0x10838, S::OLetter),
+    (0x1083C, 0x1083C, S::OLetter),
+    (0x1083F, 0x10855, S::OLetter),
+    (0x10860, 0x10876, S::OLetter),
+    (0x10880, 0x1089E, S::OLetter),
+    (0x108E0, 0x108F2, S::OLetter),
+    (0x108F4, 0x108F5, S::OLetter),
+    (0x10900, 0x10915, S::OLetter),
+    (0x10920, 0x10939, S::OLetter),
+    (0x10980, 0x109B7, S::OLetter),
+    (0x109BE, 0x109BF, S::OLetter),
+    (0x10A00, 0x10A00, S::OLetter),
+    (0x10A01, 0x10A03, S::Extend),
+    (0x10A05, 0x10A06, S::Extend),
+    (0x10A0C, 0x10A0F, S::Extend),
+    (0x10A10, 0x10A13, S::OLetter),
+    (0x10A15, 0x10A17, S::OLetter),
+    (0x10A19, 0x10A35, S::OLetter),
+    (0x10A38, 0x10A3A, S::Extend),
+    (0x10A3F, 0x10A3F, S::Extend),
+    (0x10A56, 0x10A57, S::STerm),
+    (0x10A60, 0x10A7C, S::OLetter),
+    (0x10A80, 0x10A9C, S::OLetter),
+    (0x10AC0, 0x10AC7, S::OLetter),
+    (0x10AC9, 0x10AE4, S::OLetter),
+    (0x10AE5, 0x10AE6, S::Extend),
+    (0x10B00, 0x10B35, S::OLetter),
+    (0x10B40, 0x10B55, S::OLetter),
+    (0x10B60, 0x10B72, S::OLetter),
+    (0x10B80, 0x10B91, S::OLetter),
+    (0x10C00, 0x10C48, S::OLetter),
+    (0x10C80, 0x10CB2, S::Upper),
+    (0x10CC0, 0x10CF2, S::Lower),
+    (0x10D00, 0x10D23, S::OLetter),
+    (0x10D24, 0x10D27, S::Extend),
+    (0x10D30, 0x10D39, S::Numeric),
+    (0x10E80, 0x10EA9, S::OLetter),
+    (0x10EAB, 0x10EAC, S::Extend),
+    (0x10EB0, 0x10EB1, S::OLetter),
+    (0x10EFD, 0x10EFF, S::Extend),
+    (0x10F00, 0x10F1C, S::OLetter),
+    (0x10F27, 0x10F27, S::OLetter),
+    (0x10F30, 0x10F45, S::OLetter),
+    (0x10F46, 0x10F50, S::Extend),
+    (0x10F55, 0x10F59, S::STerm),
+    (0x10F70, 0x10F81, S::OLetter),
+    (0x10F82, 0x10F85, S::Extend),
+    (0x10F86, 0x10F89, S::STerm),
+    (0x10FB0, 0x10FC4, S::OLetter),
+    (0x10FE0, 0x10FF6, S::OLetter),
+    (0x11000, 0x11002, S::Extend),
+    (0x11003, 0x11037, S::OLetter),
+    (0x11038, 0x11046, S::Extend),
+    (0x11047, 0x11048, S::STerm),
+    (0x11066, 0x1106F, S::Numeric),
+    (0x11070, 0x11070, S::Extend),
+    (0x11071, 0x11072, S::OLetter),
+    (0x11073, 0x11074, S::Extend),
+    (0x11075, 0x11075, S::OLetter),
+    (0x1107F, 0x11082, S::Extend),
+    (0x11083, 0x110AF, S::OLetter),
+    (0x110B0, 0x110BA, S::Extend),
+    (0x110BD, 0x110BD, S::Format),
+    (0x110BE, 0x110C1, S::STerm),
+    (0x110C2, 0x110C2, S::Extend),
+    (0x110CD, 0x110CD, S::Format),
+    (0x110D0, 0x110E8, S::OLetter),
+    (0x110F0, 0x110F9, S::Numeric),
+    (0x11100, 0x11102, S::Extend),
+    (0x11103, 0x11126, S::OLetter),
+    (0x11127, 0x11134, S::Extend),
+    (0x11136, 0x1113F, S::Numeric),
+    (0x11141, 0x11143, S::STerm),
+    (0x11144, 0x11144, S::OLetter),
+    (0x11145, 0x11146, S::Extend),
+    (0x11147, 0x11147, S::OLetter),
+    (0x11150, 0x11172, S::OLetter),
+    (0x11173, 0x11173, S::Extend),
+    (0x11176, 0x11176, S::OLetter),
+    (0x11180, 0x11182, S::Extend),
+    (0x11183, 0x111B2, S::OLetter),
+    (0x111B3, 0x111C0, S::Extend),
+    (0x111C1, 0x111C4, S::OLetter),
+    (0x111C5, 0x111C6, S::STerm),
+    (0x111C9, 0x111CC, S::Extend),
+    (0x111CD, 0x111CD, S::STerm),
+    (0x111CE, 0x111CF, S::Extend),
+    (0x111D0, 0x111D9, S::Numeric),
+    (0x111DA, 0x111DA, S::OLetter),
+    (0x111DC, 0x111DC, S::OLetter),
+    (0x111DE, 0x111DF, S::STerm),
+    (0x11200, 0x11211, S::OLetter),
+    (0x11213, 0x1122B, S::OLetter),
+    (0x1122C, 0x11237, S::Extend),
+    (0x11238, 0x11239, S::STerm),
+    (0x1123B, 0x1123C, S::STerm),
+    (0x1123E, 0x1123E, S::Extend),
+    (0x1123F, 0x11240, S::OLetter),
+    (0x11241, 0x11241, S::Extend),
+    (0x11280, 0x11286, S::OLetter),
+    (0x11288, 0x11288, S::OLetter),
+    (0x1128A, 0x1128D, S::OLetter),
+    (0x1128F, 0x1129D, S::OLetter),
+    (0x1129F, 0x112A8, S::OLetter),
+    (0x112A9, 0x112A9, S::STerm),
+    (0x112B0, 0x112DE, S::OLetter),
+    (0x112DF, 0x112EA, S::Extend),
+    (0x112F0, 0x112F9, S::Numeric),
+    (0x11300, 0x11303, S::Extend),
+    (0x11305, 0x1130C, S::OLetter),
+    (0x1130F, 0x11310, S::OLetter),
+    (0x11313, 0x11328, S::OLetter),
+    (0x1132A, 0x11330, S::OLetter),
+    (0x11332, 0x11333, S::OLetter),
+    (0x11335, 0x11339, S::OLetter),
+    (0x1133B, 0x1133C, S::Extend),
+    (0x1133D, 0x1133D, S::OLetter),
+    (0x1133E, 0x11344, S::Extend),
+    (0x11347, 0x11348, S::Extend),
+    (0x1134B, 0x1134D, S::Extend),
+    (0x11350, 0x11350, S::OLetter),
+    (0x11357, 0x11357, S::Extend),
+    (0x1135D, 0x11361, S::OLetter),
+    (0x11362, 0x11363, S::Extend),
+    (0x11366, 0x1136C, S::Extend),
+    (0x11370, 0x11374, S::Extend),
+    (0x11400, 0x11434, S::OLetter),
+    (0x11435, 0x11446, S::Extend),
+    (0x11447, 0x1144A, S::OLetter),
+    (0x1144B, 0x1144C, S::STerm),
+    (0x11450, 0x11459, S::Numeric),
+    (0x1145E, 0x1145E, S::Extend),
+    (0x1145F, 0x11461, S::OLetter),
+    (0x11480, 0x114AF, S::OLetter),
+    (0x114B0, 0x114C3, S::Extend),
+    (0x114C4, 0x114C5, S::OLetter),
+    (0x114C7, 0x114C7, S::OLetter),
+    (0x114D0, 0x114D9, S::Numeric),
+    (0x11580, 0x115AE, S::OLetter),
+    (0x115AF, 0x115B5, S::Extend),
+    (0x115B8, 0x115C0, S::Extend),
+    (0x115C2, 0x115C3, S::STerm),
+    (0x115C9, 0x115D7, S::STerm),
+    (0x115D8, 0x115DB, S::OLetter),
+    (0x115DC, 0x115DD, S::Extend),
+    (0x11600, 0x1162F, S::OLetter),
+    (0x11630, 0x11640, S::Extend),
+    (0x11641, 0x11642, S::STerm),
+    (0x11644, 0x11644, S::OLetter),
+    (0x11650, 0x11659, S::Numeric),
+    (0x11680, 0x116AA, S::OLetter),
+    (0x116AB, 0x116B7, S::Extend),
+    (0x116B8, 0x116B8, S::OLetter),
+    (0x116C0, 0x116C9, S::Numeric),
+    (0x11700, 0x1171A, S::OLetter),
+    (0x1171D, 0x1172B, S::Extend),
+    (0x11730, 0x11739, S::Numeric),
+    (0x1173C, 0x1173E, S::STerm),
+    (0x11740, 0x11746, S::OLetter),
+    (0x11800, 0x1182B, S::OLetter),
+    (0x1182C, 0x1183A, S::Extend),
+    (0x118A0, 0x118BF, S::Upper),
+    (0x118C0, 0x118DF, S::Lower),
+    (0x118E0, 0x118E9, S::Numeric),
+    (0x118FF, 0x11906, S::OLetter),
+    (0x11909, 0x11909, S::OLetter),
+    (0x1190C, 0x11913, S::OLetter),
+    (0x11915, 0x11916, S::OLetter),
+    (0x11918, 0x1192F, S::OLetter),
+    (0x11930, 0x11935, S::Extend),
+    (0x11937, 0x11938, S::Extend),
+    (0x1193B, 0x1193E, S::Extend),
+    (0x1193F, 0x1193F, S::OLetter),
+    (0x11940, 0x11940, S::Extend),
+    (0x11941, 0x11941, S::OLetter),
+    (0x11942, 0x11943, S::Extend),
+    (0x11944, 0x11944, S::STerm),
+    (0x11946, 0x11946, S::STerm),
+    (0x11950, 0x11959, S::Numeric),
+    (0x119A0, 0x119A7, S::OLetter),
+    (0x119AA, 0x119D0, S::OLetter),
+    (0x119D1, 0x119D7, S::Extend),
+    (0x119DA, 0x119E0, S::Extend),
+    (0x119E1, 0x119E1, S::OLetter),
+    (0x119E3, 0x119E3, S::OLetter),
+    (0x119E4, 0x119E4, S::Extend),
+    (0x11A00, 0x11A00, S::OLetter),
+    (0x11A01, 0x11A0A, S::Extend),
+    (0x11A0B, 0x11A32, S::OLetter),
+    (0x11A33, 0x11A39, S::Extend),
+    (0x11A3A, 0x11A3A, S::OLetter),
+    (0x11A3B, 0x11A3E, S::Extend),
+    (0x11A42, 0x11A43, S::STerm),
+    (0x11A47, 0x11A47, S::Extend),
+    (0x11A50, 0x11A50, S::OLetter),
+    (0x11A51, 0x11A5B, S::Extend),
+    (0x11A5C, 0x11A89, S::OLetter),
+    (0x11A8A, 0x11A99, S::Extend),
+    (0x11A9B, 0x11A9C, S::STerm),
+    (0x11A9D, 0x11A9D, S::OLetter),
+    (0x11AB0, 0x11AF8, S::OLetter),
+    (0x11C00, 0x11C08, S::OLetter),
+    (0x11C0A, 0x11C2E, S::OLetter),
+    (0x11C2F, 0x11C36, S::Extend),
+    (0x11C38, 0x11C3F, S::Extend),
+    (0x11C40, 0x11C40, S::OLetter),
+    (0x11C41, 0x11C42, S::STerm),
+    (0x11C50, 0x11C59, S::Numeric),
+    (0x11C72, 0x11C8F, S::OLetter),
+    (0x11C92, 0x11CA7, S::Extend),
+    (0x11CA9, 0x11CB6, S::Extend),
+    (0x11D00, 0x11D06, S::OLetter),
+    (0x11D08, 0x11D09, S::OLetter),
+    (0x11D0B, 0x11D30, S::OLetter),
+    (0x11D31, 0x11D36, S::Extend),
+    (0x11D3A, 0x11D3A, S::Extend),
+    (0x11D3C, 0x11D3D, S::Extend),
+    (0x11D3F, 0x11D45, S::Extend),
+    (0x11D46, 0x11D46, S::OLetter),
+    (0x11D47, 0x11D47, S::Extend),
+    (0x11D50, 0x11D59, S::Numeric),
+    (0x11D60, 0x11D65, S::OLetter),
+    (0x11D67, 0x11D68, S::OLetter),
+    (0x11D6A, 0x11D89, S::OLetter),
+    (0x11D8A, 0x11D8E, S::Extend),
+    (0x11D90, 0x11D91, S::Extend),
+    (0x11D93, 0x11D97, S::Extend),
+    (0x11D98, 0x11D98, S::OLetter),
+    (0x11DA0, 0x11DA9, S::Numeric),
+    (0x11EE0, 0x11EF2, S::OLetter),
+    (0x11EF3, 0x11EF6, S::Extend),
+    (0x11EF7, 0x11EF8, S::STerm),
+    (0x11F00, 0x11F01, S::Extend),
+    (0x11F02, 0x11F02, S::OLetter),
+    (0x11F03, 0x11F03, S::Extend),
+    (0x11F04, 0x11F10, S::OLetter),
+    (0x11F12, 0x11F33, S::OLetter),
+    (0x11F34, 0x11F3A, S::Extend),
+    (0x11F3E, 0x11F42, S::Extend),
+    (0x11F43, 0x11F44, S::STerm),
+    (0x11F50, 0x11F59, S::Numeric),
+    (0x11FB0, 0x11FB0, S::OLetter),
+    (0x12000, 0x12399, S::OLetter),
+    (0x12400, 0x1246E, S::OLetter),
+    (0x12480, 0x12543, S::OLetter),
+    (0x12F90, 0x12FF0, S::OLetter),
+    (0x13000, 0x1342F, S::OLetter),
+    (0x13430, 0x1343F, S::Format),
+    (0x13440, 0x13440, S::Extend),
+    (0x13441, 0x13446, S::OLetter),
+    (0x13447, 0x13455, S::Extend),
+    (0x14400, 0x14646, S::OLetter),
+    (0x16800, 0x16A38, S::OLetter),
+    (0x16A40, 0x16A5E, S::OLetter),
+    (0x16A60, 0x16A69, S::Numeric),
+    (0x16A6E, 0x16A6F, S::STerm),
+    (0x16A70, 0x16ABE, S::OLetter),
+    (0x16AC0, 0x16AC9, S::Numeric),
+    (0x16AD0, 0x16AED, S::OLetter),
+    (0x16AF0, 0x16AF4, S::Extend),
+    (0x16AF5, 0x16AF5, S::STerm),
+    (0x16B00, 0x16B2F, S::OLetter),
+    (0x16B30, 0x16B36, S::Extend),
+    (0x16B37, 0x16B38, S::STerm),
+    (0x16B40, 0x16B43, S::OLetter),
+    (0x16B44, 0x16B44, S::STerm),
+    (0x16B50, 0x16B59, S::Numeric),
+    (0x16B63, 0x16B77, S::OLetter),
+    (0x16B7D, 0x16B8F, S::OLetter),
+    (0x16E40, 0x16E5F, S::Upper),
+    (0x16E60, 0x16E7F, S::Lower),
+    (0x16E98, 0x16E98, S::STerm),
+    (0x16F00, 0x16F4A, S::OLetter),
+    (0x16F4F, 0x16F4F, S::Extend),
+    (0x16F50, 0x16F50, S::OLetter),
+    (0x16F51, 0x16F87, S::Extend),
+    (0x16F8F, 0x16F92, S::Extend),
+    (0x16F93, 0x16F9F, S::OLetter),
+    (0x16FE0, 0x16FE1, S::OLetter),
+    (0x16FE3, 0x16FE3, S::OLetter),
+    (0x16FE4, 0x16FE4, S::Extend),
+    (0x16FF0, 0x16FF1, S::Extend),
+    (0x17000, 0x187F7, S::OLetter),
+    (0x18800, 0x18CD5, S::OLetter),
+    (0x18D00, 0x18D08, S::OLetter),
+    (0x1AFF0, 0x1AFF3, S::OLetter),
+    (0x1AFF5, 0x1AFFB, S::OLetter),
+    (0x1AFFD, 0x1AFFE, S::OLetter),
+    (0x1B000, 0x1B122, S::OLetter),
+    (0x1B132, 0x1B132, S::OLetter),
+    (0x1B150, 0x1B152, S::OLetter),
+    (0x1B155, 0x1B155, S::OLetter),
+    (0x1B164, 0x1B167, S::OLetter),
+    (0x1B170, 0x1B2FB, S::OLetter),
+    (0x1BC00, 0x1BC6A, S::OLetter),
+    (0x1BC70, 0x1BC7C, S::OLetter),
+    (0x1BC80, 0x1BC88, S::OLetter),
+    (0x1BC90, 0x1BC99, S::OLetter),
+    (0x1BC9D, 0x1BC9E, S::Extend),
+    (0x1BC9F, 0x1BC9F, S::STerm),
+    (0x1BCA0, 0x1BCA3, S::Format),
+    (0x1CF00, 0x1CF2D, S::Extend),
+    (0x1CF30, 0x1CF46, S::Extend),
+    (0x1D165, 0x1D169, S::Extend),
+    (0x1D16D, 0x1D172, S::Extend),
+    (0x1D173, 0x1D17A, S::Format),
+    (0x1D17B, 0x1D182, S::Extend),
+    (0x1D185, 0x1D18B, S::Extend),
+    (0x1D1AA, 0x1D1AD, S::Extend),
+    (0x1D242, 0x1D244, S::Extend),
+    (0x1D400, 0x1D419, S::Upper),
+    (0x1D41A, 0x1D433, S::Lower),
+    (0x1D434, 0x1D44D, S::Upper),
+    (0x1D44E, 0x1D454, S::Lower),
+    (0x1D456, 0x1D467, S::Lower),
+    (0x1D468, 0x1D481, S::Upper),
+    (0x1D482, 0x1D49B, S::Lower),
+    (0x1D49C, 0x1D49C, S::Upper),
+    (0x1D49E, 0x1D49F, S::Upper),
+    (0x1D4A2, 0x1D4A2, S::Upper),
+    (0x1D4A5, 0x1D4A6, S::Upper),
+    (0x1D4A9, 0x1D4AC, S::Upper),
+    (0x1D4AE, 0x1D4B5, S::Upper),
+    (0x1D4B6, 0x1D4B9, S::Lower),
+    (0x1D4BB, 0x1D4BB, S::Lower),
+    (0x1D4BD, 0x1D4C3, S::Lower),
+    (0x1D4C5, 0x1D4CF, S::Lower),
+    (0x1D4D0, 0x1D4E9, S::Upper),
+    (0x1D4EA, 0x1D503, S::Lower),
+    (0x1D504, 0x1D505, S::Upper),
+    (0x1D507, 0x1D50A, S::Upper),
+    (0x1D50D, 0x1D514, S::Upper),
+    (0x1D516, 0x1D51C, S::Upper),
+    (0x1D51E, 0x1D537, S::Lower),
+    (0x1D538, 0x1D539, S::Upper),
+    (0x1D53B, 0x1D53E, S::Upper),
+    (0x1D540, 0x1D544, S::Upper),
+    (0x1D546, 0x1D546, S::Upper),
+    (0x1D54A, 0x1D550, S::Upper),
+    (0x1D552, 0x1D56B, S::Lower),
+    (0x1D56C, 0x1D585, S::Upper),
+    (0x1D586, 0x1D59F, S::Lower),
+    (0x1D5A0, 0x1D5B9, S::Upper),
+    (0x1D5BA, 0x1D5D3, S::Lower),
+    (0x1D5D4, 0x1D5ED, S::Upper),
+    (0x1D5EE, 0x1D607, S::Lower),
+    (0x1D608, 0x1D621, S::Upper),
+    (0x1D622, 0x1D63B, S::Lower),
+    (0x1D63C, 0x1D655, S::Upper),
+    (0x1D656, 0x1D66F, S::Lower),
+    (0x1D670, 0x1D689, S::Upper),
+    (0x1D68A, 0x1D6A5, S::Lower),
+    (0x1D6A8, 0x1D6C0, S::Upper),
+    (0x1D6C2, 0x1D6DA, S::Lower),
+    (0x1D6DC, 0x1D6E1, S::Lower),
+    (0x1D6E2, 0x1D6FA, S::Upper),
+    (0x1D6FC, 0x1D714, S::Lower),
+    (0x1D716, 0x1D71B, S::Lower),
+    (0x1D71C, 0x1D734, S::Upper),
+    (0x1D736, 0x1D74E, S::Lower),
+    (0x1D750, 0x1D755, S::Lower),
+    (0x1D756, 0x1D76E, S::Upper),
+    (0x1D770, 0x1D788, S::Lower),
+    (0x1D78A, 0x1D78F, S::Lower),
+    (0x1D790, 0x1D7A8, S::Upper),
+    (0x1D7AA, 0x1D7C2, S::Lower),
+    (0x1D7C4, 0x1D7C9, S::Lower),
+    (0x1D7CA, 0x1D7CA, S::Upper),
+    (0x1D7CB, 0x1D7CB, S::Lower),
+    (0x1D7CE, 0x1D7FF, S::Numeric),
+    (0x1DA00, 0x1DA36, S::Extend),
+    (0x1DA3B, 0x1DA6C, S::Extend),
+    (0x1DA75, 0x1DA75, S::Extend),
+    (0x1DA84, 0x1DA84, S::Extend),
+    (0x1DA88, 0x1DA88, S::STerm),
+    (0x1DA9B, 0x1DA9F, S::Extend),
+    (0x1DAA1, 0x1DAAF, S::Extend),
+    (0x1DF00, 0x1DF09, S::Lower),
+    (0x1DF0A, 0x1DF0A, S::OLetter),
+    (0x1DF0B, 0x1DF1E, S::Lower),
+    (0x1DF25, 0x1DF2A, S::Lower),
+    (0x1E000, 0x1E006, S::Extend),
+    (0x1E008, 0x1E018, S::Extend),
+    (0x1E01B, 0x1E021, S::Extend),
+    (0x1E023, 0x1E024, S::Extend),
+    (0x1E026, 0x1E02A, S::Extend),
+    (0x1E030, 0x1E06D, S::Lower),
+    (0x1E08F, 0x1E08F, S::Extend),
+    (0x1E100, 0x1E12C, S::OLetter),
+    (0x1E130, 0x1E136, S::Extend),
+    (0x1E137, 0x1E13D, S::OLetter),
+    (0x1E140, 0x1E149, S::Numeric),
+    (0x1E14E, 0x1E14E, S::OLetter),
+    (0x1E290, 0x1E2AD, S::OLetter),
+    (0x1E2AE, 0x1E2AE, S::Extend),
+    (0x1E2C0, 0x1E2EB, S::OLetter),
+    (0x1E2EC, 0x1E2EF, S::Extend),
+    (0x1E2F0, 0x1E2F9, S::Numeric),
+    (0x1E4D0, 0x1E4EB, S::OLetter),
+    (0x1E4EC, 0x1E4EF, S::Extend),
+    (0x1E4F0, 0x1E4F9, S::Numeric),
+    (0x1E7E0, 0x1E7E6, S::OLetter),
+    (0x1E7E8, 0x1E7EB, S::OLetter),
+    (0x1E7ED, 0x1E7EE, S::OLetter),
+    (0x1E7F0, 0x1E7FE, S::OLetter),
+    (0x1E800, 0x1E8C4, S::OLetter),
+    (0x1E8D0, 0x1E8D6, S::Extend),
+    (0x1E900, 0x1E921, S::Upper),
+    (0x1E922, 0x1E943, S::Lower),
+    (0x1E944, 0x1E94A, S::Extend),
+    (0x1E94B, 0x1E94B, S::OLetter),
+    (0x1E950, 0x1E959, S::Numeric),
+    (0x1EE00, 0x1EE03, S::OLetter),
+    (0x1EE05, 0x1EE1F, S::OLetter),
+    (0x1EE21, 0x1EE22, S::OLetter),
+    (0x1EE24, 0x1EE24, S::OLetter),
+    (0x1EE27, 0x1EE27, S::OLetter),
+    (0x1EE29, 0x1EE32, S::OLetter),
+    (0x1EE34, 0x1EE37, S::OLetter),
+    (0x1EE39, 0x1EE39, S::OLetter),
+    (0x1EE3B, 0x1EE3B, S::OLetter),
+    (0x1EE42, 0x1EE42, S::OLetter),
+    (0x1EE47, 0x1EE47, S::OLetter),
+    (0x1EE49, 0x1EE49, S::OLetter),
+    (0x1EE4B, 0x1EE4B, S::OLetter),
+    (0x1EE4D, 0x1EE4F, S::OLetter),
+    (0x1EE51, 0x1EE52, S::OLetter),
+    (0x1EE54, 0x1EE54, S::OLetter),
+    (0x1EE57, 0x1EE57, S::OLetter),
+    (0x1EE59, 0x1EE59, S::OLetter),
+    (0x1EE5B, 0x1EE5B, S::OLetter),
+    (0x1EE5D, 0x1EE5D, S::OLetter),
+    (0x1EE5F, 0x1EE5F, S::OLetter),
+    (0x1EE61, 0x1EE62, S::OLetter),
+    (0x1EE64, 0x1EE64, S::OLetter),
+    (0x1EE67, 0x1EE6A, S::OLetter),
+    (0x1EE6C, 0x1EE72, S::OLetter),
+    (0x1EE74, 0x1EE77, S::OLetter),
+    (0x1EE79, 0x1EE7C, S::OLetter),
+    (0x1EE7E, 0x1EE7E, S::OLetter),
+    (0x1EE80, 0x1EE89, S::OLetter),
+    (0x1EE8B, 0x1EE9B, S::OLetter),
+    (0x1EEA1, 0x1EEA3, S::OLetter),
+    (0x1EEA5, 0x1EEA9, S::OLetter),
+    (0x1EEAB, 0x1EEBB, S::OLetter),
+    (0x1F130, 0x1F149, S::Upper),
+    (0x1F150, 0x1F169, S::Upper),
+    (0x1F170, 0x1F189, S::Upper),
+    (0x1F676, 0x1F678, S::Close),
+    (0x1FBF0, 0x1FBF9, S::Numeric),
+    (0x20000, 0x2A6DF, S::OLetter),
+    (0x2A700, 0x2B739, S::OLetter),
+    (0x2B740, 0x2B81D, S::OLetter),
+    (0x2B820, 0x2CEA1, S::OLetter),
+    (0x2CEB0, 0x2EBE0, S::OLetter),
+    (0x2F800, 0x2FA1D, S::OLetter),
+    (0x30000, 0x3134A, S::OLetter),
+    (0x31350, 0x323AF, S::OLetter),
+    (0xE0001, 0xE0001, S::Format),
+    (0xE0020, 0xE007F, S::Extend),
+    (0xE0100, 0xE01EF, S::Extend),
+];
+
+/// Ranges of characters with the Alphabetic property or a General_Category of Nd, Nl or No
+pub(super) static ALPHANUMERIC: [(u32, u32); 809] = [
+    (0x0030, 0x0039),
+    (0x0041, 0x005A),
+    (0x0061, 0x007A),
+    (0x00AA, 0x00AA),
+    (0x00B2, 0x00B3),
+    (0x00B5, 0x00B5),
+    (0x00B9, 0x00BA),
+    (0x00BC, 0x00BE),
+    (0x00C0, 0x00D6),
+    (0x00D8, 0x00F6),
+    (0x00F8, 0x02C1),
+    (0x02C6, 0x02D1),
+    (0x02E0, 0x02E4),
+    (0x02EC, 0x02EC),
+    (0x02EE, 0x02EE),
+    (0x0345, 0x0345),
+    (0x0370, 0x0374),
+    (0x0376, 0x0377),
+    (0x037A, 0x037D),
+    (0x037F, 0x037F),
+    (0x0386, 0x0386),
+    (0x0388, 0x038A),
+    (0x038C, 0x038C),
+    (0x038E, 0x03A1),
+    (0x03A3, 0x03F5),
+    (0x03F7, 0x0481),
+    (0x048A, 0x052F),
+    (0x0531, 0x0556),
+    (0x0559, 0x0559),
+    (0x0560, 0x0588),
+    (0x05B0, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x05D0, 0x05EA),
+    (0x05EF, 0x05F2),
+    (0x0610, 0x061A),
+    (0x0620, 0x0657),
+    (0x0659, 0x0669),
+    (0x066E, 0x06D3),
+    (0x06D5, 0x06DC),
+    (0x06E1, 0x06E8),
+    (0x06ED, 0x06FC),
+    (0x06FF, 0x06FF),
+    (0x0710, 0x073F),
+    (0x074D, 0x07B1),
+    (0x07C0, 0x07EA),
+    (0x07F4, 0x07F5),
+    (0x07FA, 0x07FA),
+    (0x0800, 0x0817),
+    (0x081A, 0x082C),
+    (0x0840, 0x0858),
+    (0x0860, 0x086A),
+    (0x0870, 0x0887),
+    (0x0889, 0x088E),
+    (0x08A0, 0x08C9),
+    (0x08D4, 0x08DF),
+    (0x08E3, 0x08E9),
+    (0x08F0, 0x093B),
+    (0x093D, 0x094C),
+    (0x094E, 0x0950),
+    (0x0955, 0x0963),
+    (0x0966, 0x096F),
+    (0x0971, 0x0983),
+    (0x0985, 0x098C),
+    (0x098F, 0x0990),
+    (0x0993, 0x09A8),
+    (0x09AA, 0x09B0),
+    (0x09B2, 0x09B2),
+    (0x09B6, 0x09B9),
+    (0x09BD, 0x09C4),
+    (0x09C7, 0x09C8),
+    (0x09CB, 0x09CC),
+    (0x09CE, 0x09CE),
+    (0x09D7, 0x09D7),
+    (0x09DC, 0x09DD),
+    (0x09DF, 0x09E3),
+    (0x09E6, 0x09F1),
+    (0x09F4, 0x09F9),
+    (0x09FC, 0x09FC),
+    (0x0A01, 0x0A03),
+    (0x0A05, 0x0A0A),
+    (0x0A0F, 0x0A10),
+    (0x0A13, 0x0A28),
+    (0x0A2A, 0x0A30),
+    (0x0A32, 0x0A33),
+    (0x0A35, 0x0A36),
+    (0x0A38, 0x0A39),
+    (0x0A3E, 0x0A42),
+    (0x0A47, 0x0A48),
+    (0x0A4B, 0x0A4C),
+    (0x0A51, 0x0A51),
+    (0x0A59, 0x0A5C),
+    (0x0A5E, 0x0A5E),
+    (0x0A66, 0x0A75),
+    (0x0A81, 0x0A83),
+    (0x0A85, 0x0A8D),
+    (0x0A8F, 0x0A91),
+    (0x0A93, 0x0AA8),
+    (0x0AAA, 0x0AB0),
+    (0x0AB2, 0x0AB3),
+    (0x0AB5, 0x0AB9),
+    (0x0ABD, 0x0AC5),
+    (0x0AC7, 0x0AC9),
+    (0x0ACB, 0x0ACC),
+    (0x0AD0, 0x0AD0),
+    (0x0AE0, 0x0AE3),
+    (0x0AE6, 0x0AEF),
+    (0x0AF9, 0x0AFC),
+    (0x0B01, 0x0B03),
+    (0x0B05, 0x0B0C),
+    (0x0B0F, 0x0B10),
+    (0x0B13, 0x0B28),
+    (0x0B2A, 0x0B30),
+    (0x0B32, 0x0B33),
+    (0x0B35, 0x0B39),
+    (0x0B3D, 0x0B44),
+    (0x0B47, 0x0B48),
+    (0x0B4B, 0x0B4C),
+    (0x0B56, 0x0B57),
+    (0x0B5C, 0x0B5D),
+    (0x0B5F, 0x0B63),
+    (0x0B66, 0x0B6F),
+    (0x0B71, 0x0B77),
+    (0x0B82, 0x0B83),
+    (0x0B85, 0x0B8A),
+    (0x0B8E, 0x0B90),
+    (0x0B92, 0x0B95),
+    (0x0B99, 0x0B9A),
+    (0x0B9C, 0x0B9C),
+    (0x0B9E, 0x0B9F),
+    (0x0BA3, 0x0BA4),
+    (0x0BA8, 0x0BAA),
+    (0x0BAE, 0x0BB9),
+    (0x0BBE, 0x0BC2),
+    (0x0BC6, 0x0BC8),
+    (0x0BCA, 0x0BCC),
+    (0x0BD0, 0x0BD0),
+    (0x0BD7, 0x0BD7),
+    (0x0BE6, 0x0BF2),
+    (0x0C00, 0x0C0C),
+    (0x0C0E, 0x0C10),
+    (0x0C12, 0x0C28),
+    (0x0C2A, 0x0C39),
+    (0x0C3D, 0x0C44),
+    (0x0C46, 0x0C48),
+    (0x0C4A, 0x0C4C),
+    (0x0C55, 0x0C56),
+    (0x0C58, 0x0C5A),
+    (0x0C5D, 0x0C5D),
+    (0x0C60, 0x0C63),
+    (0x0C66, 0x0C6F),
+    (0x0C78, 0x0C7E),
+    (0x0C80, 0x0C83),
+    (0x0C85, 0x0C8C),
+    (0x0C8E, 0x0C90),
+    (0x0C92, 0x0CA8),
+    (0x0CAA, 0x0CB3),
+    (0x0CB5, 0x0CB9),
+    (0x0CBD, 0x0CC4),
+    (0x0CC6, 0x0CC8),
+    (0x0CCA, 0x0CCC),
+    (0x0CD5, 0x0CD6),
+    (0x0CDD, 0x0CDE),
+    (0x0CE0, 0x0CE3),
+    (0x0CE6, 0x0CEF),
+    (0x0CF1, 0x0CF3),
+    (0x0D00, 0x0D0C),
+    (0x0D0E, 0x0D10),
+    (0x0D12, 0x0D3A),
+    (0x0D3D, 0x0D44),
+    (0x0D46, 0x0D48),
+    (0x0D4A, 0x0D4C),
+    (0x0D4E, 0x0D4E),
+    (0x0D54, 0x0D63),
+    (0x0D66, 0x0D78),
+    (0x0D7A, 0x0D7F),
+    (0x0D81, 0x0D83),
+    (0x0D85, 0x0D96),
+    (0x0D9A, 0x0DB1),
+    (0x0DB3, 0x0DBB),
+    (0x0DBD, 0x0DBD),
+    (0x0DC0, 0x0DC6),
+    (0x0DCF, 0x0DD4),
+    (0x0DD6, 0x0DD6),
+    (0x0DD8, 0x0DDF),
+    (0x0DE6, 0x0DEF),
+    (0x0DF2, 0x0DF3),
+    (0x0E01, 0x0E3A),
+    (0x0E40, 0x0E46),
+    (0x0E4D, 0x0E4D),
+    (0x0E50, 0x0E59),
+    (0x0E81, 0x0E82),
+    (0x0E84, 0x0E84),
+    (0x0E86, 0x0E8A),
+    (0x0E8C, 0x0EA3),
+    (0x0EA5, 0x0EA5),
+    (0x0EA7, 0x0EB9),
+    (0x0EBB, 0x0EBD),
+    (0x0EC0, 0x0EC4),
+    (0x0EC6, 0x0EC6),
+    (0x0ECD, 0x0ECD),
+    (0x0ED0, 0x0ED9),
+    (0x0EDC, 0x0EDF),
+    (0x0F00, 0x0F00),
+    (0x0F20, 0x0F33),
+    (0x0F40, 0x0F47),
+    (0x0F49, 0x0F6C),
+    (0x0F71, 0x0F83),
+    (0x0F88, 0x0F97),
+    (0x0F99, 0x0FBC),
+    (0x1000, 0x1036),
+    (0x1038, 0x1038),
+    (0x103B, 0x1049),
+    (0x1050, 0x109D),
+    (0x10A0, 0x10C5),
+    (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD),
+    (0x10D0, 0x10FA),
+    (0x10FC, 0x1248),
+    (0x124A, 0x124D),
+    (0x1250, 0x1256),
+    (0x1258, 0x1258),
+    (0x125A, 0x125D),
+    (0x1260, 0x1288),
+    (0x128A, 0x128D),
+    (0x1290, 0x12B0),
+    (0x12B2, 0x12B5),
+    (0x12B8, 0x12BE),
+    (0x12C0, 0x12C0),
+    (0x12C2, 0x12C5),
+    (0x12C8, 0x12D6),
+    (0x12D8, 0x1310),
+    (0x1312, 0x1315),
+    (0x1318, 0x135A),
+    (0x1369, 0x137C),
+    (0x1380, 0x138F),
+    (0x13A0, 0x13F5),
+    (0x13F8, 0x13FD),
+    (0x1401, 0x166C),
+    (0x166F, 0x167F),
+    (0x1681, 0x169A),
+    (0x16A0, 0x16EA),
+    (0x16EE, 0x16F8),
+    (0x1700, 0x1713),
+    (0x171F, 0x1733),
+    (0x1740, 0x1753),
+    (0x1760, 0x176C),
+    (0x176E, 0x1770),
+    (0x1772, 0x1773),
+    (0x1780, 0x17B3),
+    (0x17B6, 0x17C8),
+    (0x17D7, 0x17D7),
+    (0x17DC, 0x17DC),
+    (0x17E0, 0x17E9),
+    (0x17F0, 0x17F9),
+    (0x1810, 0x1819),
+    (0x1820, 0x1878),
+    (0x1880, 0x18AA),
+    (0x18B0, 0x18F5),
+    (0x1900, 0x191E),
+    (0x1920, 0x192B),
+    (0x1930, 0x1938),
+    (0x1946, 0x196D),
+    (0x1970, 0x1974),
+    (0x1980, 0x19AB),
+    (0x19B0, 0x19C9),
+    (0x19D0, 0x19DA),
+    (0x1A00, 0x1A1B),
+    (0x1A20, 0x1A5E),
+    (0x1A61, 0x1A74),
+    (0x1A80, 0x1A89),
+    (0x1A90, 0x1A99),
+    (0x1AA7, 0x1AA7),
+    (0x1ABF, 0x1AC0),
+    (0x1ACC, 0x1ACE),
+    (0x1B00, 0x1B33),
+    (0x1B35, 0x1B43),
+    (0x1B45, 0x1B4C),
+    (0x1B50, 0x1B59),
+    (0x1B80, 0x1BA9),
+    (0x1BAC, 0x1BE5),
+    (0x1BE7, 0x1BF1),
+    (0x1C00, 0x1C36),
+    (0x1C40, 0x1C49),
+    (0x1C4D, 0x1C7D),
+    (0x1C80, 0x1C88),
+    (0x1C90, 0x1CBA),
+    (0x1CBD, 0x1CBF),
+    (0x1CE9, 0x1CEC),
+    (0x1CEE, 0x1CF3),
+    (0x1CF5, 0x1CF6),
+    (0x1CFA, 0x1CFA),
+    (0x1D00, 0x1DBF),
+    (0x1DE7, 0x1DF4),
+    (0x1E00, 0x1F15),
+    (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D),
+    (0x1F50, 0x1F57),
+    (0x1F59, 0x1F59),
+    (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D),
+    (0x1F80, 0x1FB4),
+    (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE),
+    (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FDB),
+    (0x1FE0, 0x1FEC),
+    (0x1FF2, 0x1FF4),
+    (0x1FF6, 0x1FFC),
+    (0x2070, 0x2071),
+    (0x2074, 0x2079),
+    (0x207F, 0x2089),
+    (0x2090, 0x209C),
+    (0x2102, 0x2102),
+    (0x2107, 0x2107),
+    (0x210A, 0x2113),
+    (0x2115, 0x2115),
+    (0x2119, 0x211D),
+    (0x2124, 0x2124),
+    (0x2126, 0x2126),
+    (0x2128, 0x2128),
+    (0x212A, 0x212D),
+    (0x212F, 0x2139),
+    (0x213C, 0x213F),
+    (0x2145, 0x2149),
+    (0x214E, 0x214E),
+    (0x2150, 0x2189),
+    (0x2460, 0x249B),
+    (0x24B6, 0x24FF),
+    (0x2776, 0x2793),
+    (0x2C00, 0x2CE4),
+    (0x2CEB, 0x2CEE),
+    (0x2CF2, 0x2CF3),
+    (0x2CFD, 0x2CFD),
+    (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27),
+    (0x2D2D, 0x2D2D),
+    (0x2D30, 0x2D67),
+    (0x2D6F, 0x2D6F),
+    (0x2D80, 0x2D96),
+    (0x2DA0, 0x2DA6),
+    (0x2DA8, 0x2DAE),
+    (0x2DB0, 0x2DB6),
+    (0x2DB8, 0x2DBE),
+    (0x2DC0, 0x2DC6),
+    (0x2DC8, 0x2DCE),
+    (0x2DD0, 0x2DD6),
+    (0x2DD8, 0x2DDE),
+    (0x2DE0, 0x2DFF),
+    (0x2E2F, 0x2E2F),
+    (0x3005, 0x3007),
+    (0x3021, 0x3029),
+    (0x3031, 0x3035),
+    (0x3038, 0x303C),
+    (0x3041, 0x3096),
+    (0x309D, 0x309F),
+    (0x30A1, 0x30FA),
+    (0x30FC, 0x30FF),
+    (0x3105, 0x312F),
+    (0x3131, 0x318E),
+    (0x3192, 0x3195),
+    (0x31A0, 0x31BF),
+    (0x31F0, 0x31FF),
+    (0x3220, 0x3229),
+    (0x3248, 0x324F),
+    (0x3251, 0x325F),
+    (0x3280, 0x3289),
+    (0x32B1, 0x32BF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0xA48C),
+    (0xA4D0, 0xA4FD),
+    (0xA500, 0xA60C),
+    (0xA610, 0xA62B),
+    (0xA640, 0xA66E),
+    (0xA674, 0xA67B),
+    (0xA67F, 0xA6EF),
+    (0xA717, 0xA71F),
+    (0xA722, 0xA788),
+    (0xA78B, 0xA7CA),
+    (0xA7D0, 0xA7D1),
+    (0xA7D3, 0xA7D3),
+    (0xA7D5, 0xA7D9),
+    (0xA7F2, 0xA805),
+    (0xA807, 0xA827),
+    (0xA830, 0xA835),
+    (0xA840, 0xA873),
+    (0xA880, 0xA8C3),
+    (0xA8C5, 0xA8C5),
+    (0xA8D0, 0xA8D9),
+    (0xA8F2, 0xA8F7),
+    (0xA8FB, 0xA8FB),
+    (0xA8FD, 0xA92A),
+    (0xA930, 0xA952),
+    (0xA960, 0xA97C),
+    (0xA980, 0xA9B2),
+    (0xA9B4, 0xA9BF),
+    (0xA9CF, 0xA9D9),
+    (0xA9E0, 0xA9FE),
+    (0xAA00, 0xAA36),
+    (0xAA40, 0xAA4D),
+    (0xAA50, 0xAA59),
+    (0xAA60, 0xAA76),
+    (0xAA7A, 0xAABE),
+    (0xAAC0, 0xAAC0),
+    (0xAAC2, 0xAAC2),
+    (0xAADB, 0xAADD),
+    (0xAAE0, 0xAAEF),
+    (0xAAF2, 0xAAF5),
+    (0xAB01, 0xAB06),
+    (0xAB09, 0xAB0E),
+    (0xAB11, 0xAB16),
+    (0xAB20, 0xAB26),
+    (0xAB28, 0xAB2E),
+    (0xAB30, 0xAB5A),
+    (0xAB5C, 0xAB69),
+    (0xAB70, 0xABEA),
+    (0xABF0, 0xABF9),
+    (0xAC00, 0xD7A3),
+    (0xD7B0, 0xD7C6),
+    (0xD7CB, 0xD7FB),
+    (0xF900, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17),
+    (0xFB1D, 0xFB28),
+    (0xFB2A, 0xFB36),
+    (0xFB38, 0xFB3C),
+    (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44),
+    (0xFB46, 0xFBB1),
+    (0xFBD3, 0xFD3D),
+    (0xFD50, 0xFD8F),
+    (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDFB),
+    (0xFE70, 0xFE74),
+    (0xFE76, 0xFEFC),
+    (0xFF10, 0xFF19),
+    (0xFF21, 0xFF3A),
+    (0xFF41, 0xFF5A),
+    (0xFF66, 0xFFBE),
+    (0xFFC2, 0xFFC7),
+    (0xFFCA, 0xFFCF),
+    (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC),
+    (0x10000, 0x1000B),
+    (0x1000D, 0x10026),
+    (0x10028, 0x1003A),
+    (0x1003C, 0x1003D),
+    (0x1003F, 0x1004D),
+    (0x10050, 0x1005D),
+    (0x10080, 0x100FA),
+    (0x10107, 0x10133),
+    (0x10140, 0x10178),
+    (0x1018A, 0x1018B),
+    (0x10280, 0x1029C),
+    (0x102A0, 0x102D0),
+    (0x102E1, 0x102FB),
+    (0x10300, 0x10323),
+    (0x1032D, 0x1034A),
+    (0x10350, 0x1037A),
+    (0x10380, 0x1039D),
+    (0x103A0, 0x103C3),
+    (0x103C8, 0x103CF),
+    (0x103D1, 0x103D5),
+    (0x10400, 0x1049D),
+    (0x104A0, 0x104A9),
+    (0x104B0, 0x104D3),
+    (0x104D8, 0x104FB),
+    (0x10500, 0x10527),
+    (0x10530, 0x10563),
+    (0x10570, 0x1057A),
+    (0x1057C, 0x1058A),
+    (0x1058C, 0x10592),
+    (0x10594, 0x10595),
+    (0x10597, 0x105A1),
+    (0x105A3, 0x105B1),
+    (0x105B3, 0x105B9),
+    (0x105BB, 0x105BC),
+    (0x10600, 0x10736),
+    (0x10740, 0x10755),
+    (0x10760, 0x10767),
+    (0x10780, 0x10785),
+    (0x10787, 0x107B0),
+    (0x107B2, 0x107BA),
+    (0x10800, 0x10805),
+    (0x10808, 0x10808),
+    (0x1080A, 0x10835),
+    (0x10837, 0x10838),
+    (0x1083C, 0x1083C),
+    (0x1083F, 0x10855),
+    (0x10858, 0x10876),
+    (0x10879, 0x1089E),
+    (0x108A7, 0x108AF),
+    (0x108E0, 0x108F2),
+    (0x108F4, 0x108F5),
+    (0x108FB, 0x1091B),
+    (0x10920, 0x10939),
+    (0x10980, 0x109B7),
+    (0x109BC, 0x109CF),
+    (0x109D2, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A13),
+    (0x10A15, 0x10A17),
+    (0x10A19, 0x10A35),
+    (0x10A40, 0x10A48),
+    (0x10A60, 0x10A7E),
+    (0x10A80, 0x10A9F),
+    (0x10AC0, 0x10AC7),
+    (0x10AC9, 0x10AE4),
+    (0x10AEB, 0x10AEF),
+    (0x10B00, 0x10B35),
+    (0x10B40, 0x10B55),
+    (0x10B58, 0x10B72),
+    (0x10B78, 0x10B91),
+    (0x10BA9, 0x10BAF),
+    (0x10C00, 0x10C48),
+    (0x10C80, 0x10CB2),
+    (0x10CC0, 0x10CF2),
+    (0x10CFA, 0x10D27),
+    (0x10D30, 0x10D39),
+    (0x10E60, 0x10E7E),
+    (0x10E80, 0x10EA9),
+    (0x10EAB, 0x10EAC),
+    (0x10EB0, 0x10EB1),
+    (0x10F00, 0x10F27),
+    (0x10F30, 0x10F45),
+    (0x10F51, 0x10F54),
+    (0x10F70, 0x10F81),
+    (0x10FB0, 0x10FCB),
+    (0x10FE0, 0x10FF6),
+    (0x11000, 0x11045),
+    (0x11052, 0x1106F),
+    (0x11071, 0x11075),
+    (0x11080, 0x110B8),
+    (0x110C2, 0x110C2),
+    (0x110D0, 0x110E8),
+    (0x110F0, 0x110F9),
+    (0x11100, 0x11132),
+    (0x11136, 0x1113F),
+    (0x11144, 0x11147),
+    (0x11150, 0x11172),
+    (0x11176, 0x11176),
+    (0x11180, 0x111BF),
+    (0x111C1, 0x111C4),
+    (0x111CE, 0x111DA),
+    (0x111DC, 0x111DC),
+    (0x111E1, 0x111F4),
+    (0x11200, 0x11211),
+    (0x11213, 0x11234),
+    (0x11237, 0x11237),
+    (0x1123E, 0x11241),
+    (0x11280, 0x11286),
+    (0x11288, 0x11288),
+    (0x1128A, 0x1128D),
+    (0x1128F, 0x1129D),
+    (0x1129F, 0x112A8),
+    (0x112B0, 0x112E8),
+    (0x112F0, 0x112F9),
+    (0x11300, 0x11303),
+    (0x11305, 0x1130C),
+    (0x1130F, 0x11310),
+    (0x11313, 0x11328),
+    (0x1132A, 0x11330),
+    (0x11332, 0x11333),
+    (0x11335, 0x11339),
+    (0x1133D, 0x11344),
+    (0x11347, 0x11348),
+    (0x1134B, 0x1134C),
+    (0x11350, 0x11350),
+    (0x11357, 0x11357),
+    (0x1135D, 0x11363),
+    (0x11400, 0x11441),
+    (0x11443, 0x11445),
+    (0x11447, 0x1144A),
+    (0x11450, 0x11459),
+    (0x1145F, 0x11461),
+    (0x11480, 0x114C1),
+    (0x114C4, 0x114C5),
+    (0x114C7, 0x114C7),
+    (0x114D0, 0x114D9),
+    (0x11580, 0x115B5),
+    (0x115B8, 0x115BE),
+    (0x115D8, 0x115DD),
+    (0x11600, 0x1163E),
+    (0x11640, 0x11640),
+    (0x11644, 0x11644),
+    (0x11650, 0x11659),
+    (0x11680, 0x116B5),
+    (0x116B8, 0x116B8),
+    (0x116C0, 0x116C9),
+    (0x11700, 0x1171A),
+    (0x1171D, 0x1172A),
+    (0x11730, 0x1173B),
+    (0x11740, 0x11746),
+    (0x11800, 0x11838),
+    (0x118A0, 0x118F2),
+    (0x118FF, 0x11906),
+    (0x11909, 0x11909),
+    (0x1190C, 0x11913),
+    (0x11915, 0x11916),
+    (0x11918, 0x11935),
+    (0x11937, 0x11938),
+    (0x1193B, 0x1193C),
+    (0x1193F, 0x11942),
+    (0x11950, 0x11959),
+    (0x119A0, 0x119A7),
+    (0x119AA, 0x119D7),
+    (0x119DA, 0x119DF),
+    (0x119E1, 0x119E1),
+    (0x119E3, 0x119E4),
+    (0x11A00, 0x11A32),
+    (0x11A35, 0x11A3E),
+    (0x11A50, 0x11A97),
+    (0x11A9D, 0x11A9D),
+    (0x11AB0, 0x11AF8),
+    (0x11C00, 0x11C08),
+    (0x11C0A, 0x11C36),
+    (0x11C38, 0x11C3E),
+    (0x11C40, 0x11C40),
+    (0x11C50, 0x11C6C),
+    (0x11C72, 0x11C8F),
+    (0x11C92, 0x11CA7),
+    (0x11CA9, 0x11CB6),
+    (0x11D00, 0x11D06),
+    (0x11D08, 0x11D09),
+    (0x11D0B, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D41),
+    (0x11D43, 0x11D43),
+    (0x11D46, 0x11D47),
+    (0x11D50, 0x11D59),
+    (0x11D60, 0x11D65),
+    (0x11D67, 0x11D68),
+    (0x11D6A, 0x11D8E),
+    (0x11D90, 0x11D91),
+    (0x11D93, 0x11D96),
+    (0x11D98, 0x11D98),
+    (0x11DA0, 0x11DA9),
+    (0x11EE0, 0x11EF6),
+    (0x11F00, 0x11F10),
+    (0x11F12, 0x11F3A),
+    (0x11F3E, 0x11F40),
+    (0x11F50, 0x11F59),
+    (0x11FB0, 0x11FB0),
+    (0x11FC0, 0x11FD4),
+    (0x12000, 0x12399),
+    (0x12400, 0x1246E),
+    (0x12480, 0x12543),
+    (0x12F90, 0x12FF0),
+    (0x13000, 0x1342F),
+    (0x13441, 0x13446),
+    (0x14400, 0x14646),
+    (0x16800, 0x16A38),
+    (0x16A40, 0x16A5E),
+    (0x16A60, 0x16A69),
+    (0x16A70, 0x16ABE),
+    (0x16AC0, 0x16AC9),
+    (0x16AD0, 0x16AED),
+    (0x16B00, 0x16B2F),
+    (0x16B40, 0x16B43),
+    (0x16B50, 0x16B59),
+    (0x16B5B, 0x16B61),
+    (0x16B63, 0x16B77),
+    (0x16B7D, 0x16B8F),
+    (0x16E40, 0x16E96),
+    (0x16F00, 0x16F4A),
+    (0x16F4F, 0x16F87),
+    (0x16F8F, 0x16F9F),
+    (0x16FE0, 0x16FE1),
+    (0x16FE3, 0x16FE3),
+    (0x16FF0, 0x16FF1),
+    (0x17000, 0x187F7),
+    (0x18800, 0x18CD5),
+    (0x18D00, 0x18D08),
+    (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122),
+    (0x1B132, 0x1B132),
+    (0x1B150, 0x1B152),
+    (0x1B155, 0x1B155),
+    (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB),
+    (0x1BC00, 0x1BC6A),
+    (0x1BC70, 0x1BC7C),
+    (0x1BC80, 0x1BC88),
+    (0x1BC90, 0x1BC99),
+    (0x1BC9E, 0x1BC9E),
+    (0x1D2C0, 0x1D2D3),
+    (0x1D2E0, 0x1D2F3),
+    (0x1D360, 0x1D378),
+    (0x1D400, 0x1D454),
+    (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F),
+    (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6),
+    (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9),
+    (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3),
+    (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A),
+    (0x1D50D, 0x1D514),
+    (0x1D516, 0x1D51C),
+    (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E),
+    (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546),
+    (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5),
+    (0x1D6A8, 0x1D6C0),
+    (0x1D6C2, 0x1D6DA),
+    (0x1D6DC, 0x1D6FA),
+    (0x1D6FC, 0x1D714),
+    (0x1D716, 0x1D734),
+    (0x1D736, 0x1D74E),
+    (0x1D750, 0x1D76E),
+    (0x1D770, 0x1D788),
+    (0x1D78A, 0x1D7A8),
+    (0x1D7AA, 0x1D7C2),
+    (0x1D7C4, 0x1D7CB),
+    (0x1D7CE, 0x1D7FF),
+    (0x1DF00, 0x1DF1E),
+    (0x1DF25, 0x1DF2A),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E030, 0x1E06D),
+    (0x1E08F, 0x1E08F),
+    (0x1E100, 0x1E12C),
+    (0x1E137, 0x1E13D),
+    (0x1E140, 0x1E149),
+    (0x1E14E, 0x1E14E),
+    (0x1E290, 0x1E2AD),
+    (0x1E2C0, 0x1E2EB),
+    (0x1E2F0, 0x1E2F9),
+    (0x1E4D0, 0x1E4EB),
+    (0x1E4F0, 0x1E4F9),
+    (0x1E7E0, 0x1E7E6),
+    (0x1E7E8, 0x1E7EB),
+    (0x1E7ED, 0x1E7EE),
+    (0x1E7F0, 0x1E7FE),
+    (0x1E800, 0x1E8C4),
+    (0x1E8C7, 0x1E8CF),
+    (0x1E900, 0x1E943),
+    (0x1E947, 0x1E947),
+    (0x1E94B, 0x1E94B),
+    (0x1E950, 0x1E959),
+    (0x1EC71, 0x1ECAB),
+    (0x1ECAD, 0x1ECAF),
+    (0x1ECB1, 0x1ECB4),
+    (0x1ED01, 0x1ED2D),
+    (0x1ED2F, 0x1ED3D),
+    (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F),
+    (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24),
+    (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32),
+    (0x1EE34, 0x1EE37),
+    (0x1EE39, 0x1EE39),
+    (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42),
+    (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49),
+    (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F),
+    (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54),
+    (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59),
+    (0x1EE5B, 0x1EE5B),
+    (0x1EE5D, 0x1EE5D),
+    (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62),
+    (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A),
+    (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77),
+    (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E),
+    (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B),
+    (0x1EEA1, 0x1EEA3),
+    (0x1EEA5, 0x1EEA9),
+    (0x1EEAB, 0x1EEBB),
+    (0x1F100, 0x1F10C),
+    (0x1F130, 0x1F149),
+    (0x1F150, 0x1F169),
+    (0x1F170, 0x1F189),
+    (0x1FBF0, 0x1FBF9),
+    (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B739),
+    (0x2B740, 0x2B81D),
+    (0x2B820, 0x2CEA1),
+    (0x2CEB0, 0x2EBE0),
+    (0x2F800, 0x2FA1D),
+    (0x30000, 0x3134A),
+    (0x31350, 0x323AF),
+];
+
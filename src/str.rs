@@ -1,15 +1,19 @@
 use core::cmp::Ordering;
 use core::hash::Hash;
-use core::ops::{Index, IndexMut};
+use core::ops::{Bound, Index, IndexMut, RangeBounds};
 use core::{marker::PhantomData, slice::SliceIndex};
 
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
 #[cfg(feature = "pattern")]
-use crate::pattern::{BidirectionalPattern, Pattern, RevPattern};
-use crate::traits::IntoChars;
-use crate::traits::{Char, CharTraits, DebugStr, DisplayStr};
+use crate::pattern::{
+    BidirectionalPattern, DoubleEndedSearcher, Pattern, RevPattern, ReverseSearcher, Searcher,
+};
+use crate::traits::{Char, CharTraits, DebugStr, DisplayStr, ValidationError};
+use crate::traits::{DecodeRev, IntoChars};
 
 #[cfg(feature = "utf")]
 use crate::utf::UtfCharTraits;
@@ -78,6 +82,10 @@ impl<CharT, Traits> BasicStr<CharT, Traits> {
         self.1.len()
     }
 
+    pub const fn is_empty(&self) -> bool {
+        self.1.is_empty()
+    }
+
     pub const fn as_ptr(&self) -> *const CharT {
         self.1.as_ptr()
     }
@@ -89,10 +97,7 @@ impl<CharT, Traits> BasicStr<CharT, Traits> {
 
 #[cfg(feature = "pattern")]
 impl<CharT, Traits> BasicStr<CharT, Traits> {
-    pub fn split_once<'a, P: Pattern<CharT, Traits>>(
-        &'a self,
-        delimiter: P,
-    ) -> Option<(&'a Self, &'a Self)> {
+    pub fn split_once<P: Pattern<CharT, Traits>>(&self, delimiter: P) -> Option<(&Self, &Self)> {
         // Safety:
         // `self` is valid by invariant
         let pat = unsafe { delimiter.first_match_unchecked(&self.1) }?;
@@ -125,10 +130,10 @@ impl<CharT, Traits> BasicStr<CharT, Traits> {
         Some(begin)
     }
 
-    pub fn rsplit_once<'a, P: RevPattern<CharT, Traits>>(
-        &'a self,
+    pub fn rsplit_once<P: RevPattern<CharT, Traits>>(
+        &self,
         delimiter: P,
-    ) -> Option<(&'a Self, &'a Self)> {
+    ) -> Option<(&Self, &Self)> {
         // Safety:
         // `self` is valid by invariant
         let pat = unsafe { delimiter.last_match_unchecked(&self.1) }?;
@@ -161,128 +166,604 @@ impl<CharT, Traits> BasicStr<CharT, Traits> {
         Some(begin)
     }
 
-    pub fn split<P: Pattern<CharT, Traits>>(&self, pat: P) -> Split<P, CharT, Traits> {
-        Split(Some(self), pat)
+    pub fn split<P: Pattern<CharT, Traits>>(&self, pat: P) -> Split<'_, P, CharT, Traits> {
+        Split::new(self, pat, true)
+    }
+
+    pub fn rsplit<'a, P: Pattern<CharT, Traits>>(&'a self, pat: P) -> RSplit<'a, P, CharT, Traits>
+    where
+        P::Searcher<'a>: ReverseSearcher<'a, CharT>,
+    {
+        RSplit(Split::new(self, pat, true))
+    }
+
+    /// Returns an iterator over at most `n` substrings of `self`, separated by matches of `pat`.
+    ///
+    /// The last substring contains the remainder of `self`.
+    pub fn splitn<P: Pattern<CharT, Traits>>(
+        &self,
+        n: usize,
+        pat: P,
+    ) -> SplitN<'_, P, CharT, Traits> {
+        SplitN(Split::new(self, pat, true), n)
+    }
+
+    /// Returns an iterator over at most `n` substrings of `self`, separated by matches of `pat`, starting from the end.
+    ///
+    /// The last substring contains the remainder of `self`.
+    pub fn rsplitn<'a, P: Pattern<CharT, Traits>>(
+        &'a self,
+        n: usize,
+        pat: P,
+    ) -> RSplitN<'a, P, CharT, Traits>
+    where
+        P::Searcher<'a>: ReverseSearcher<'a, CharT>,
+    {
+        RSplitN(Split::new(self, pat, true), n)
+    }
+
+    /// Returns an iterator over the substrings of `self` that are terminated by matches of `pat`.
+    ///
+    /// Equivalent to [`BasicStr::split`], except that a trailing empty substring is skipped.
+    pub fn split_terminator<P: Pattern<CharT, Traits>>(
+        &self,
+        pat: P,
+    ) -> SplitTerminator<'_, P, CharT, Traits> {
+        SplitTerminator(Split::new(self, pat, false))
+    }
+
+    /// Returns an iterator over the substrings of `self` separated by matches of `pat`, with each substring including the match that ends it.
+    ///
+    /// A trailing empty substring is skipped.
+    pub fn split_inclusive<P: Pattern<CharT, Traits>>(
+        &self,
+        pat: P,
+    ) -> SplitInclusive<'_, P, CharT, Traits> {
+        SplitInclusive(Split::new(self, pat, false))
+    }
+
+    /// Returns `true` if `pat` matches anywhere in `self`
+    pub fn contains<P: Pattern<CharT, Traits>>(&self, pat: P) -> bool {
+        // Safety:
+        // `self` is valid by invariant
+        unsafe { pat.first_match_unchecked(&self.1) }.is_some()
+    }
+
+    /// Returns `true` if `pat` matches at the start of `self`
+    pub fn starts_with<P: Pattern<CharT, Traits>>(&self, pat: P) -> bool {
+        // Safety:
+        // `self` is valid by invariant
+        unsafe { pat.prefix_match_unchecked(&self.1) }.is_some()
+    }
+
+    /// Returns `true` if `pat` matches at the end of `self`
+    pub fn ends_with<P: RevPattern<CharT, Traits>>(&self, pat: P) -> bool {
+        // Safety:
+        // `self` is valid by invariant
+        unsafe { pat.suffix_match_unchecked(&self.1) }.is_some()
+    }
+
+    /// Returns `self` with a single match of `pat` removed from the start, or `None` if `self` does not start with a match of `pat`
+    pub fn strip_prefix<P: Pattern<CharT, Traits>>(&self, pat: P) -> Option<&Self> {
+        // Safety:
+        // `self` is valid by invariant
+        let pat = unsafe { pat.prefix_match_unchecked(&self.1) }?;
+
+        // Safety:
+        // Guaranteed by the `Pattern` impl
+        Some(unsafe { Self::from_chars_unchecked(self.1.get_unchecked(pat.len()..)) })
+    }
+
+    /// Returns `self` with a single match of `pat` removed from the end, or `None` if `self` does not end with a match of `pat`
+    pub fn strip_suffix<P: RevPattern<CharT, Traits>>(&self, pat: P) -> Option<&Self> {
+        // Safety:
+        // `self` is valid by invariant
+        let pat = unsafe { pat.suffix_match_unchecked(&self.1) }?;
+
+        // Safety:
+        // Guaranteed by the `Pattern` impl
+        Some(unsafe { Self::from_chars_unchecked(self.1.get_unchecked(..self.len() - pat.len())) })
+    }
+
+    /// Returns `self` with all matches of `pat` repeatedly removed from the start
+    pub fn trim_start_matches<P: Pattern<CharT, Traits>>(&self, pat: P) -> &Self {
+        self.trim_start_matches_by(&pat)
+    }
+
+    /// Returns `self` with all matches of `pat` repeatedly removed from the end
+    pub fn trim_end_matches<P: RevPattern<CharT, Traits>>(&self, pat: P) -> &Self {
+        self.trim_end_matches_by(&pat)
+    }
+
+    /// Returns `self` with all matches of `pat` repeatedly removed from both the start and the end
+    pub fn trim_matches<P: BidirectionalPattern<CharT, Traits>>(&self, pat: P) -> &Self {
+        self.trim_start_matches_by(&pat).trim_end_matches_by(&pat)
     }
 
-    pub fn rsplit<P: RevPattern<CharT, Traits>>(&self, pat: P) -> RSplit<P, CharT, Traits> {
-        RSplit(Some(self), pat)
+    fn trim_start_matches_by<P: Pattern<CharT, Traits>>(&self, pat: &P) -> &Self {
+        let mut rest = self;
+        // Safety:
+        // `rest` is valid by invariant
+        while let Some(m) = unsafe { pat.prefix_match_unchecked(&rest.1) } {
+            if m.is_empty() {
+                break;
+            }
+            // Safety:
+            // Guaranteed by the `Pattern` impl
+            rest = unsafe { Self::from_chars_unchecked(rest.1.get_unchecked(m.len()..)) };
+        }
+        rest
+    }
+
+    fn trim_end_matches_by<P: RevPattern<CharT, Traits>>(&self, pat: &P) -> &Self {
+        let mut rest = self;
+        // Safety:
+        // `rest` is valid by invariant
+        while let Some(m) = unsafe { pat.suffix_match_unchecked(&rest.1) } {
+            if m.is_empty() {
+                break;
+            }
+            // Safety:
+            // Guaranteed by the `Pattern` impl
+            rest =
+                unsafe { Self::from_chars_unchecked(rest.1.get_unchecked(..rest.len() - m.len())) };
+        }
+        rest
     }
 }
 
 #[cfg(feature = "pattern")]
-pub struct Split<'a, P, CharT, Traits>(Option<&'a BasicStr<CharT, Traits>>, P);
+impl<Traits: CharTraits> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the non-overlapping matches of `pat` in `self`
+    pub fn matches<P: Pattern<Traits::Char, Traits>>(
+        &self,
+        pat: P,
+    ) -> Matches<'_, P, Traits::Char, Traits> {
+        Matches(MatchIndices::new(self, pat))
+    }
+
+    /// Returns an iterator over the non-overlapping matches of `pat` in `self`, starting from the end
+    pub fn rmatches<'a, P: Pattern<Traits::Char, Traits>>(
+        &'a self,
+        pat: P,
+    ) -> RMatches<'a, P, Traits::Char, Traits>
+    where
+        P::Searcher<'a>: ReverseSearcher<'a, Traits::Char>,
+    {
+        RMatches(MatchIndices::new(self, pat))
+    }
+
+    /// Returns an iterator over the non-overlapping matches of `pat` in `self`, together with the index at which each match begins
+    pub fn match_indices<P: Pattern<Traits::Char, Traits>>(
+        &self,
+        pat: P,
+    ) -> MatchIndices<'_, P, Traits::Char, Traits> {
+        MatchIndices::new(self, pat)
+    }
+
+    /// Returns an iterator over the non-overlapping matches of `pat` in `self`, together with the index at which each match begins, starting from the end
+    pub fn rmatch_indices<'a, P: Pattern<Traits::Char, Traits>>(
+        &'a self,
+        pat: P,
+    ) -> RMatchIndices<'a, P, Traits::Char, Traits>
+    where
+        P::Searcher<'a>: ReverseSearcher<'a, Traits::Char>,
+    {
+        RMatchIndices(MatchIndices::new(self, pat))
+    }
+}
 
 #[cfg(feature = "pattern")]
-pub struct RSplit<'a, P, CharT, Traits>(Option<&'a BasicStr<CharT, Traits>>, P);
+pub struct Split<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a> {
+    searcher: P::Searcher<'a>,
+    start: usize,
+    end: usize,
+    allow_trailing_empty: bool,
+    finished: bool,
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Split<'a, P, CharT, Traits> {
+    fn new(haystack: &'a BasicStr<CharT, Traits>, pat: P, allow_trailing_empty: bool) -> Self {
+        Self {
+            searcher: pat.into_searcher(haystack),
+            start: 0,
+            end: haystack.len(),
+            allow_trailing_empty,
+            finished: false,
+        }
+    }
+
+    fn piece(&self, start: usize, end: usize) -> &'a BasicStr<CharT, Traits> {
+        // Safety:
+        // Guaranteed by the `Searcher` impl
+        unsafe { BasicStr::from_chars_unchecked(&self.searcher.haystack()[start..end]) }
+    }
+
+    /// Returns the part of the haystack that has not been split, unless it is an empty trailing piece that is skipped
+    fn remainder(&mut self) -> Option<&'a BasicStr<CharT, Traits>> {
+        if self.finished {
+            return None;
+        }
+        self.finished = true;
+        (self.allow_trailing_empty || self.start < self.end)
+            .then(|| self.piece(self.start, self.end))
+    }
+
+    fn next_piece(&mut self) -> Option<&'a BasicStr<CharT, Traits>> {
+        if self.finished {
+            return None;
+        }
+        match self.searcher.next_match() {
+            Some((start, end)) => {
+                let piece = self.piece(self.start, start);
+                self.start = end;
+                Some(piece)
+            }
+            None => self.remainder(),
+        }
+    }
+
+    fn next_piece_inclusive(&mut self) -> Option<&'a BasicStr<CharT, Traits>> {
+        if self.finished {
+            return None;
+        }
+        match self.searcher.next_match() {
+            Some((_, end)) => {
+                let piece = self.piece(self.start, end);
+                self.start = end;
+                Some(piece)
+            }
+            None => self.remainder(),
+        }
+    }
+}
 
 #[cfg(feature = "pattern")]
-impl<'a, P, CharT, Traits> Iterator for Split<'a, P, CharT, Traits>
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Split<'a, P, CharT, Traits>
 where
-    P: Pattern<CharT, Traits>,
+    P::Searcher<'a>: ReverseSearcher<'a, CharT>,
 {
-    type Item = &'a BasicStr<CharT, Traits>;
+    fn next_piece_back(&mut self) -> Option<&'a BasicStr<CharT, Traits>> {
+        if self.finished {
+            return None;
+        }
+        if !self.allow_trailing_empty {
+            // Only the last piece can be a skipped trailing piece
+            self.allow_trailing_empty = true;
+            match self.next_piece_back() {
+                Some(piece) if !piece.is_empty() => return Some(piece),
+                _ if self.finished => return None,
+                _ => {}
+            }
+        }
+        match self.searcher.next_match_back() {
+            Some((start, end)) => {
+                let piece = self.piece(end, self.end);
+                self.end = start;
+                Some(piece)
+            }
+            None => {
+                self.finished = true;
+                Some(self.piece(self.start, self.end))
+            }
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(test) = self.0.take() {
-            let pat = unsafe { self.1.first_match_unchecked(test.as_chars()) };
-
-            if let Some(pat) = pat {
-                // Safety:
-                // Guaranteed by the `Pattern` impl
-                let begin = unsafe { pat.as_ptr().offset_from(test.as_ptr()) } as usize;
-
-                let end = begin + pat.len();
-                self.0 = Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[end..]) });
-                Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[..begin]) })
-            } else {
-                Some(test)
+    fn next_piece_inclusive_back(&mut self) -> Option<&'a BasicStr<CharT, Traits>> {
+        if self.finished {
+            return None;
+        }
+        if !self.allow_trailing_empty {
+            // Only the last piece can be a skipped trailing piece
+            self.allow_trailing_empty = true;
+            match self.next_piece_inclusive_back() {
+                Some(piece) if !piece.is_empty() => return Some(piece),
+                _ if self.finished => return None,
+                _ => {}
+            }
+        }
+        match self.searcher.next_match_back() {
+            Some((_, end)) => {
+                let piece = self.piece(end, self.end);
+                self.end = end;
+                Some(piece)
+            }
+            None => {
+                self.finished = true;
+                Some(self.piece(self.start, self.end))
             }
-        } else {
-            None
         }
     }
 }
 
 #[cfg(feature = "pattern")]
-impl<'a, P, CharT, Traits> Iterator for RSplit<'a, P, CharT, Traits>
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for Split<'a, P, CharT, Traits> {
+    type Item = &'a BasicStr<CharT, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_piece()
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for Split<'a, P, CharT, Traits>
 where
-    P: RevPattern<CharT, Traits>,
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_piece_back()
+    }
+}
+
+#[cfg(feature = "pattern")]
+pub struct RSplit<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    Split<'a, P, CharT, Traits>,
+);
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for RSplit<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: ReverseSearcher<'a, CharT>,
 {
     type Item = &'a BasicStr<CharT, Traits>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(test) = self.0.take() {
-            let pat = unsafe { self.1.last_match_unchecked(test.as_chars()) };
+        self.0.next_piece_back()
+    }
+}
 
-            if let Some(pat) = pat {
-                // Safety:
-                // Guaranteed by the `Pattern` impl
-                let begin = unsafe { pat.as_ptr().offset_from(test.as_ptr()) } as usize;
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for RSplit<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_piece()
+    }
+}
+
+#[cfg(feature = "pattern")]
+pub struct SplitN<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    Split<'a, P, CharT, Traits>,
+    usize,
+);
+
+#[cfg(feature = "pattern")]
+pub struct RSplitN<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    Split<'a, P, CharT, Traits>,
+    usize,
+);
 
-                let end = begin + pat.len();
-                self.0 = Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[..begin]) });
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for SplitN<'a, P, CharT, Traits> {
+    type Item = &'a BasicStr<CharT, Traits>;
 
-                Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[end..]) })
-            } else {
-                Some(test)
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.1 {
+            0 => None,
+            1 => {
+                self.1 = 0;
+                self.0.remainder()
+            }
+            _ => {
+                self.1 -= 1;
+                self.0.next_piece()
             }
-        } else {
-            None
         }
     }
 }
 
 #[cfg(feature = "pattern")]
-impl<'a, P, CharT, Traits> DoubleEndedIterator for Split<'a, P, CharT, Traits>
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for RSplitN<'a, P, CharT, Traits>
 where
-    P: BidirectionalPattern<CharT, Traits>,
+    P::Searcher<'a>: ReverseSearcher<'a, CharT>,
 {
-    fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
-        if let Some(test) = self.0.take() {
-            let pat = unsafe { self.1.last_match_unchecked(test.as_chars()) };
-
-            if let Some(pat) = pat {
-                // Safety:
-                // Guaranteed by the `Pattern` impl
-                let begin = unsafe { pat.as_ptr().offset_from(test.as_ptr()) } as usize;
-
-                let end = begin + pat.len();
-                self.0 = Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[..begin]) });
+    type Item = &'a BasicStr<CharT, Traits>;
 
-                Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[end..]) })
-            } else {
-                Some(test)
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.1 {
+            0 => None,
+            1 => {
+                self.1 = 0;
+                self.0.remainder()
+            }
+            _ => {
+                self.1 -= 1;
+                self.0.next_piece_back()
             }
-        } else {
-            None
         }
     }
 }
 
 #[cfg(feature = "pattern")]
-impl<'a, P, CharT, Traits> DoubleEndedIterator for RSplit<'a, P, CharT, Traits>
+pub struct SplitTerminator<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    Split<'a, P, CharT, Traits>,
+);
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator
+    for SplitTerminator<'a, P, CharT, Traits>
+{
+    type Item = &'a BasicStr<CharT, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_piece()
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for SplitTerminator<'a, P, CharT, Traits>
 where
-    P: BidirectionalPattern<CharT, Traits>,
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(test) = self.0.take() {
-            let pat = unsafe { self.1.first_match_unchecked(test.as_chars()) };
-
-            if let Some(pat) = pat {
-                // Safety:
-                // Guaranteed by the `Pattern` impl
-                let begin = unsafe { pat.as_ptr().offset_from(test.as_ptr()) } as usize;
-
-                let end = begin + pat.len();
-                self.0 = Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[end..]) });
-                Some(unsafe { BasicStr::from_chars_unchecked(&test.as_chars()[..begin]) })
-            } else {
-                Some(test)
-            }
-        } else {
-            None
-        }
+        self.0.next_piece_back()
+    }
+}
+
+#[cfg(feature = "pattern")]
+pub struct SplitInclusive<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    Split<'a, P, CharT, Traits>,
+);
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator
+    for SplitInclusive<'a, P, CharT, Traits>
+{
+    type Item = &'a BasicStr<CharT, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_piece_inclusive()
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for SplitInclusive<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_piece_inclusive_back()
+    }
+}
+
+#[cfg(feature = "pattern")]
+pub struct MatchIndices<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(P::Searcher<'a>);
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> MatchIndices<'a, P, CharT, Traits> {
+    fn new(haystack: &'a BasicStr<CharT, Traits>, pat: P) -> Self {
+        Self(pat.into_searcher(haystack))
+    }
+
+    fn to_match(&self, (start, end): (usize, usize)) -> (usize, &'a BasicStr<CharT, Traits>) {
+        // Safety:
+        // Guaranteed by the `Searcher` impl
+        (start, unsafe {
+            BasicStr::from_chars_unchecked(&self.0.haystack()[start..end])
+        })
+    }
+
+    fn next_match(&mut self) -> Option<(usize, &'a BasicStr<CharT, Traits>)> {
+        let m = self.0.next_match()?;
+        Some(self.to_match(m))
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> MatchIndices<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: ReverseSearcher<'a, CharT>,
+{
+    fn next_match_back(&mut self) -> Option<(usize, &'a BasicStr<CharT, Traits>)> {
+        let m = self.0.next_match_back()?;
+        Some(self.to_match(m))
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for MatchIndices<'a, P, CharT, Traits> {
+    type Item = (usize, &'a BasicStr<CharT, Traits>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_match()
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for MatchIndices<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_match_back()
+    }
+}
+
+#[cfg(feature = "pattern")]
+pub struct RMatchIndices<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    MatchIndices<'a, P, CharT, Traits>,
+);
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for RMatchIndices<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: ReverseSearcher<'a, CharT>,
+{
+    type Item = (usize, &'a BasicStr<CharT, Traits>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_match_back()
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for RMatchIndices<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_match()
+    }
+}
+
+#[cfg(feature = "pattern")]
+pub struct Matches<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    MatchIndices<'a, P, CharT, Traits>,
+);
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for Matches<'a, P, CharT, Traits> {
+    type Item = &'a BasicStr<CharT, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_match().map(|(_, pat)| pat)
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for Matches<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_match_back().map(|(_, pat)| pat)
+    }
+}
+
+#[cfg(feature = "pattern")]
+pub struct RMatches<'a, P: Pattern<CharT, Traits>, CharT: 'a, Traits: 'a>(
+    MatchIndices<'a, P, CharT, Traits>,
+);
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> Iterator for RMatches<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: ReverseSearcher<'a, CharT>,
+{
+    type Item = &'a BasicStr<CharT, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_match_back().map(|(_, pat)| pat)
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'a, P: Pattern<CharT, Traits>, CharT, Traits> DoubleEndedIterator
+    for RMatches<'a, P, CharT, Traits>
+where
+    P::Searcher<'a>: DoubleEndedSearcher<'a, CharT>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_match().map(|(_, pat)| pat)
     }
 }
 
@@ -294,7 +775,7 @@ impl<CharT, Traits> From<Box<BasicStr<CharT, Traits>>> for Box<[CharT]> {
     }
 }
 
-#[cfg(all(feature = "alloc"))]
+#[cfg(feature = "alloc")]
 impl<CharT, Traits> BasicStr<CharT, Traits> {
     #[cfg(feature = "allocator-api")]
     pub fn into_boxed_chars<A: alloc::alloc::Allocator>(
@@ -325,6 +806,9 @@ impl<CharT, Traits: CharTraits<Char = CharT>> BasicStr<CharT, Traits> {
         Ok(unsafe { Self::from_chars_unchecked_mut(chars) })
     }
 
+    /// Slices `self` by `range`, returning `None` if it is out of bounds or [`CharTraits::validate_subrange`] rejects the result.
+    ///
+    /// See [`BasicStr::slice_checked`] for a version that checks character boundaries exactly for any [`IntoChars`] traits.
     pub fn get<I: SliceIndex<[CharT], Output = [CharT]>>(&self, range: I) -> Option<&Self> {
         let range = self.1.get(range)?;
 
@@ -383,6 +867,11 @@ impl<CharT, Traits: CharTraits<Char = CharT>> BasicStr<CharT, Traits> {
         Self::from_chars_unchecked_mut(self.1.get_unchecked_mut(range))
     }
 
+    /// Splits `self` at `mid`.
+    ///
+    /// # Panics
+    /// Panics if `mid` is out of bounds, or [`CharTraits::validate_subrange`] rejects either half.
+    /// See [`BasicStr::split_at_checked`] for a non-panicking version.
     pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
         let (left, right) = self.1.split_at(mid);
 
@@ -397,18 +886,128 @@ impl<CharT, Traits: CharTraits<Char = CharT>> BasicStr<CharT, Traits> {
         }
     }
 
-    pub fn split_at_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
-        let (left, right) = self.1.split_at_mut(mid);
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut Self, &mut Self) {
+        let (left, right) = self.1.split_at_mut(mid);
+
+        unsafe { Traits::validate_subrange(left) }.unwrap();
+        unsafe { Traits::validate_subrange(right) }.unwrap();
+
+        unsafe {
+            (
+                Self::from_chars_unchecked_mut(left),
+                Self::from_chars_unchecked_mut(right),
+            )
+        }
+    }
+}
+
+/// A piece of a possibly invalid range of characters, consisting of a valid prefix followed by an invalid sequence
+pub struct Chunk<'a, CharT, Traits> {
+    valid: &'a BasicStr<CharT, Traits>,
+    invalid: &'a [CharT],
+}
+
+impl<'a, CharT, Traits> Chunk<'a, CharT, Traits> {
+    /// The valid characters of the chunk
+    pub fn valid(&self) -> &'a BasicStr<CharT, Traits> {
+        self.valid
+    }
+
+    /// The invalid sequence that follows the valid characters.
+    ///
+    /// This is empty only for the last chunk of the range, if the range ends with valid characters
+    pub fn invalid(&self) -> &'a [CharT] {
+        self.invalid
+    }
+}
+
+/// An iterator over the [`Chunk`]s of a possibly invalid range of characters
+pub struct Chunks<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
+
+impl<'a, Traits: CharTraits + 'a> Iterator for Chunks<'a, Traits::Char, Traits> {
+    type Item = Chunk<'a, Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        match Traits::validate_range(self.0) {
+            Ok(()) => {
+                // SAFETY:
+                // The whole remainder was just validated
+                let valid = unsafe { BasicStr::from_chars_unchecked(self.0) };
+                self.0 = &[];
+                Some(Chunk {
+                    valid,
+                    invalid: &[],
+                })
+            }
+            Err(e) => {
+                let (valid, rest) = self.0.split_at(e.first_error_pos());
+                // A missing length means the range ends in the middle of a character
+                let invalid_len = e
+                    .first_error_len()
+                    .map_or(rest.len(), |len| len.clamp(1, rest.len()));
+                let (invalid, rest) = rest.split_at(invalid_len);
+                self.0 = rest;
+
+                // SAFETY:
+                // Validation only failed after `first_error_pos`, so the prefix is valid
+                let valid = unsafe { BasicStr::from_chars_unchecked(valid) };
+                Some(Chunk { valid, invalid })
+            }
+        }
+    }
+}
+
+impl<Traits: CharTraits> BasicStr<Traits::Char, Traits> {
+    /// Splits a possibly invalid range of characters into valid and invalid pieces.
+    ///
+    /// Uses [`ValidationError::first_error_pos`] and [`ValidationError::first_error_len`] to resynchronize after each invalid sequence.
+    pub fn chunks(chars: &[Traits::Char]) -> Chunks<'_, Traits::Char, Traits> {
+        Chunks(chars, PhantomData)
+    }
+}
 
-        unsafe { Traits::validate_subrange(left) }.unwrap();
-        unsafe { Traits::validate_subrange(right) }.unwrap();
+#[cfg(feature = "alloc")]
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Converts a slice of `CharT` into a [`BasicStr`], replacing each invalid sequence with U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// Borrows from `chars` if it is valid, and allocates a new [`BasicString`](crate::string::BasicString) otherwise.
+    pub fn from_chars_lossy(chars: &[Traits::Char]) -> Cow<'_, Self> {
+        let mut chunks = Self::chunks(chars);
+
+        let first = match chunks.next() {
+            Some(chunk) => chunk,
+            // SAFETY: The empty range is valid
+            None => return Cow::Borrowed(unsafe { Self::from_chars_unchecked(&[]) }),
+        };
+
+        if first.invalid().is_empty() {
+            return Cow::Borrowed(first.valid());
+        }
 
-        unsafe {
-            (
-                Self::from_chars_unchecked_mut(left),
-                Self::from_chars_unchecked_mut(right),
-            )
+        let mut string = crate::string::BasicString::with_capacity(chars.len());
+        for chunk in core::iter::once(first).chain(chunks) {
+            string.push_str(chunk.valid());
+            if !chunk.invalid().is_empty() {
+                string.push(char::REPLACEMENT_CHARACTER);
+            }
         }
+
+        Cow::Owned(string)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<CharT: Char, Traits> ToOwned for BasicStr<CharT, Traits> {
+    type Owned = crate::string::BasicString<CharT, Traits>;
+
+    fn to_owned(&self) -> Self::Owned {
+        // SAFETY:
+        // `self` is valid by invariant
+        unsafe { crate::string::BasicString::from_chars_unchecked(self.as_chars().to_vec()) }
     }
 }
 
@@ -601,13 +1200,29 @@ impl<CharT: Copy> Iterator for Chars<'_, CharT> {
 impl<CharT: Copy> ExactSizeIterator for Chars<'_, CharT> {}
 
 impl<CharT, Traits> BasicStr<CharT, Traits> {
-    pub fn chars(&self) -> Chars<CharT> {
+    pub fn chars(&self) -> Chars<'_, CharT> {
         Chars(self.1.iter())
     }
 }
 
+/// An iterator over the [`char`]s of a [`BasicStr`]
 pub struct UnicodeIter<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
 
+impl<CharT, Traits> Clone for UnicodeIter<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<'a, CharT, Traits> UnicodeIter<'a, CharT, Traits> {
+    /// Returns the part of the string that has not been iterated over
+    pub fn as_basic_str(&self) -> &'a BasicStr<CharT, Traits> {
+        // SAFETY:
+        // The remainder is between two character boundaries of the original string
+        unsafe { BasicStr::from_chars_unchecked(self.0) }
+    }
+}
+
 impl<Traits: IntoChars> Iterator for UnicodeIter<'_, Traits::Char, Traits> {
     type Item = char;
     fn next(&mut self) -> Option<char> {
@@ -625,14 +1240,823 @@ impl<Traits: IntoChars> Iterator for UnicodeIter<'_, Traits::Char, Traits> {
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         (
-            self.0.len() / Traits::max_encoding_len(),
-            Some(self.0.len()),
+            self.0.len().div_ceil(Traits::max_encoding_len()),
+            Some(self.0.len() / Traits::min_encoding_len()),
         )
     }
+
+    fn count(self) -> usize {
+        Traits::char_count(self.0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<char> {
+        for _ in 0..n {
+            if self.0.is_empty() {
+                return None;
+            }
+            self.0 = &self.0[Traits::first_char_len(self.0)..];
+        }
+        self.next()
+    }
+}
+
+impl<Traits: DecodeRev> DoubleEndedIterator for UnicodeIter<'_, Traits::Char, Traits> {
+    fn next_back(&mut self) -> Option<char> {
+        if self.0.is_empty() {
+            None
+        } else {
+            // SAFETY:
+            // We've still got some buffer left, and we know it to be valid, since it came from `BasicStr`
+            let (ch, rest) = unsafe { Traits::decode_back_unchecked(self.0) };
+
+            self.0 = rest;
+            Some(ch)
+        }
+    }
 }
 
 impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
-    pub fn unicode_iter(&self) -> UnicodeIter<Traits::Char, Traits> {
+    pub fn unicode_iter(&self) -> UnicodeIter<'_, Traits::Char, Traits> {
         UnicodeIter(&self.1, PhantomData)
     }
 }
+
+impl<Traits: CharTraits> BasicStr<Traits::Char, Traits> {
+    /// Returns whether `idx` is the index of the first `CharT` of a character of `self`, or `self.len()`.
+    ///
+    /// Unlike [`CharTraits::validate_subrange`], this is exact for every [`CharTraits`].
+    pub fn is_char_boundary(&self, idx: usize) -> bool {
+        Traits::is_char_boundary(&self.1, idx)
+    }
+
+    /// Returns the greatest character boundary that is less than or equal to `idx`, or `self.len()` if `idx` is past the end
+    pub fn floor_char_boundary(&self, mut idx: usize) -> usize {
+        if idx >= self.len() {
+            return self.len();
+        }
+        while !self.is_char_boundary(idx) {
+            idx -= 1;
+        }
+        idx
+    }
+
+    /// Returns the least character boundary that is greater than or equal to `idx`, or `self.len()` if `idx` is past the end
+    pub fn ceil_char_boundary(&self, mut idx: usize) -> usize {
+        if idx >= self.len() {
+            return self.len();
+        }
+        while !self.is_char_boundary(idx) {
+            idx += 1;
+        }
+        idx
+    }
+
+    /// Slices `self` by `range`, returning `None` if either end of the range is out of bounds or is not a character boundary.
+    ///
+    /// Unlike [`BasicStr::get`], this never accepts a range that splits a character.
+    pub fn slice_checked<R: RangeBounds<usize>>(&self, range: R) -> Option<&Self> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        if start > end || !self.is_char_boundary(start) || !self.is_char_boundary(end) {
+            return None;
+        }
+
+        // SAFETY:
+        // Both ends are character boundaries of `self`, so the range consists of whole characters
+        Some(unsafe { Self::from_chars_unchecked(&self.1[start..end]) })
+    }
+
+    /// Splits `self` at `mid`, returning `None` if `mid` is out of bounds or is not a character boundary.
+    ///
+    /// Unlike [`BasicStr::split_at`], this never accepts an index that splits a character.
+    pub fn split_at_checked(&self, mid: usize) -> Option<(&Self, &Self)> {
+        if !self.is_char_boundary(mid) {
+            return None;
+        }
+        let (left, right) = self.1.split_at(mid);
+
+        // SAFETY:
+        // `mid` is a character boundary of `self`, so both halves consist of whole characters
+        unsafe {
+            Some((
+                Self::from_chars_unchecked(left),
+                Self::from_chars_unchecked(right),
+            ))
+        }
+    }
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the characters of `self`, and the index of the first `CharT` of each
+    pub fn char_indices(&self) -> CharIndices<'_, Traits::Char, Traits> {
+        CharIndices {
+            front_offset: 0,
+            iter: self.unicode_iter(),
+        }
+    }
+}
+
+/// An iterator over the characters of a [`BasicStr`] and their indices
+pub struct CharIndices<'a, CharT, Traits> {
+    front_offset: usize,
+    iter: UnicodeIter<'a, CharT, Traits>,
+}
+
+impl<CharT, Traits> Clone for CharIndices<'_, CharT, Traits> {
+    fn clone(&self) -> Self {
+        Self {
+            front_offset: self.front_offset,
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, CharT, Traits> CharIndices<'a, CharT, Traits> {
+    /// Returns the index of the next character, or the length of the string if there are none left
+    pub fn offset(&self) -> usize {
+        self.front_offset
+    }
+
+    /// Returns the part of the string that has not been iterated over
+    pub fn as_basic_str(&self) -> &'a BasicStr<CharT, Traits> {
+        // SAFETY:
+        // The remainder is between two character boundaries of the original string
+        unsafe { BasicStr::from_chars_unchecked(self.iter.0) }
+    }
+}
+
+impl<Traits: IntoChars> Iterator for CharIndices<'_, Traits::Char, Traits> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.iter.0.len();
+        let c = self.iter.next()?;
+        let offset = self.front_offset;
+        self.front_offset += len - self.iter.0.len();
+        Some((offset, c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<Traits: DecodeRev> DoubleEndedIterator for CharIndices<'_, Traits::Char, Traits> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let c = self.iter.next_back()?;
+        Some((self.front_offset + self.iter.0.len(), c))
+    }
+}
+
+/// The size of the buffer used by [`EncodeAs`] to hold the encoding of a single `char`
+const ENCODE_BUF_LEN: usize = 4;
+
+/// An iterator over the code units of a [`BasicStr`] transcoded from `From` into `To`
+pub struct EncodeAs<'a, From: IntoChars, To: IntoChars> {
+    iter: UnicodeIter<'a, From::Char, From>,
+    buf: [To::Char; ENCODE_BUF_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl<From: IntoChars, To: IntoChars> Iterator for EncodeAs<'_, From, To> {
+    type Item = To::Char;
+
+    fn next(&mut self) -> Option<To::Char> {
+        if self.pos == self.len {
+            let c = self.iter.next()?;
+            self.len = To::encode(c, &mut self.buf).len();
+            self.pos = 0;
+        }
+        let unit = self.buf[self.pos];
+        self.pos += 1;
+        Some(unit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.len - self.pos;
+        let (lower, upper) = self.iter.size_hint();
+        (
+            buffered + lower,
+            upper
+                .and_then(|upper| upper.checked_mul(To::max_encoding_len()))
+                .and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns an iterator over the code units of `self` transcoded into the encoding of `To`.
+    ///
+    /// # Panics
+    /// Panics if [`IntoChars::max_encoding_len`] of `To` is greater than 4
+    pub fn encode_as<To: IntoChars>(&self) -> EncodeAs<'_, Traits, To> {
+        assert!(
+            To::max_encoding_len() <= ENCODE_BUF_LEN,
+            "encode_as only supports encodings with a maximum encoding length of at most {}",
+            ENCODE_BUF_LEN
+        );
+        EncodeAs {
+            iter: self.unicode_iter(),
+            buf: [To::zero_term(); ENCODE_BUF_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Transcodes `self` into a new [`BasicString`](crate::string::BasicString) encoded according to `To`.
+    ///
+    /// The length of the result is computed up front by [`IntoChars::transcoded_len`], so only a single allocation is performed.
+    /// The characters are then encoded by [`IntoChars::transcode_from`], which copies runs of ASCII between UTF-8 and UTF-16
+    ///  without decoding them.
+    #[cfg(feature = "alloc")]
+    pub fn transcode<To: IntoChars>(&self) -> crate::string::BasicString<To::Char, To> {
+        let len = To::transcoded_len::<Traits>(self.as_chars());
+        let mut chars = alloc::vec![To::zero_term(); len];
+        let written = To::transcode_from::<Traits>(self.as_chars(), &mut chars);
+        debug_assert_eq!(written, len);
+
+        // SAFETY:
+        // `chars` consists entirely of characters encoded by `To::encode`
+        unsafe { crate::string::BasicString::from_chars_unchecked(chars) }
+    }
+}
+
+/// Returns the suffix of `buf` that remains after removing the leading characters that satisfy `pred`
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`]
+unsafe fn trim_start_by<Traits: IntoChars>(
+    mut buf: &[Traits::Char],
+    pred: impl Fn(char) -> bool,
+) -> &[Traits::Char] {
+    while !buf.is_empty() {
+        let (c, rest) = Traits::decode_buf_unchecked(buf);
+        if !pred(c) {
+            break;
+        }
+        buf = rest;
+    }
+    buf
+}
+
+/// Returns the prefix of `buf` that remains after removing the trailing characters that satisfy `pred`
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`]
+unsafe fn trim_end_by<Traits: DecodeRev>(
+    mut buf: &[Traits::Char],
+    pred: impl Fn(char) -> bool,
+) -> &[Traits::Char] {
+    while !buf.is_empty() {
+        let (c, rest) = Traits::decode_back_unchecked(buf);
+        if !pred(c) {
+            break;
+        }
+        buf = rest;
+    }
+    buf
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns `self` with leading whitespace removed, as defined by [`char::is_whitespace`]
+    pub fn trim_start(&self) -> &Self {
+        // SAFETY:
+        // `self` is valid by invariant, and `trim_start_by` only removes whole characters
+        unsafe { Self::from_chars_unchecked(trim_start_by::<Traits>(&self.1, char::is_whitespace)) }
+    }
+
+    /// Returns an iterator over the lines of `self`.
+    ///
+    /// Lines are terminated by either `\n` or `\r\n`, which are not included in the lines, and the last line may be unterminated.
+    pub fn lines(&self) -> Lines<'_, Traits::Char, Traits> {
+        Lines(&self.1, PhantomData)
+    }
+
+    /// Returns an iterator over the non-empty substrings of `self` separated by whitespace, as defined by [`char::is_whitespace`]
+    pub fn split_whitespace(&self) -> SplitWhitespace<'_, Traits::Char, Traits> {
+        SplitWhitespace(&self.1, PhantomData)
+    }
+
+    /// Returns an iterator over the non-empty substrings of `self` separated by ASCII whitespace, as defined by [`char::is_ascii_whitespace`]
+    pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_, Traits::Char, Traits> {
+        SplitAsciiWhitespace(&self.1, PhantomData)
+    }
+}
+
+impl<Traits: DecodeRev> BasicStr<Traits::Char, Traits> {
+    /// Returns `self` with trailing whitespace removed, as defined by [`char::is_whitespace`]
+    pub fn trim_end(&self) -> &Self {
+        // SAFETY:
+        // `self` is valid by invariant, and `trim_end_by` only removes whole characters
+        unsafe { Self::from_chars_unchecked(trim_end_by::<Traits>(&self.1, char::is_whitespace)) }
+    }
+
+    /// Returns `self` with leading and trailing whitespace removed, as defined by [`char::is_whitespace`]
+    pub fn trim(&self) -> &Self {
+        self.trim_start().trim_end()
+    }
+}
+
+pub struct Lines<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
+
+impl<'a, Traits: IntoChars + 'a> Iterator for Lines<'a, Traits::Char, Traits> {
+    type Item = &'a BasicStr<Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let mut buf = self.0;
+        let mut cr_pos = None;
+        while !buf.is_empty() {
+            let pos = self.0.len() - buf.len();
+            // SAFETY:
+            // `buf` is a suffix of a valid `BasicStr` on a character boundary
+            let (c, rest) = unsafe { Traits::decode_buf_unchecked(buf) };
+            if c == '\n' {
+                let line = &self.0[..cr_pos.unwrap_or(pos)];
+                self.0 = rest;
+                // SAFETY:
+                // `line` ends on a character boundary
+                return Some(unsafe { BasicStr::from_chars_unchecked(line) });
+            }
+            cr_pos = (c == '\r').then_some(pos);
+            buf = rest;
+        }
+
+        let line = core::mem::take(&mut self.0);
+        // SAFETY:
+        // `line` is the remainder of a valid `BasicStr`
+        Some(unsafe { BasicStr::from_chars_unchecked(line) })
+    }
+}
+
+impl<'a, Traits: DecodeRev + 'a> DoubleEndedIterator for Lines<'a, Traits::Char, Traits> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        // SAFETY (for each call below):
+        // `self.0` and its prefixes produced by `decode_back_unchecked` are valid and on character boundaries
+        let mut line = self.0;
+        if let ('\n', rest) = unsafe { Traits::decode_back_unchecked(line) } {
+            line = rest;
+            if let Some(('\r', rest)) = Traits::decode_back(line) {
+                line = rest;
+            }
+        }
+
+        let start = unsafe { trim_end_by::<Traits>(line, |c| c != '\n') }.len();
+        self.0 = &self.0[..start];
+        Some(unsafe { BasicStr::from_chars_unchecked(&line[start..]) })
+    }
+}
+
+/// Removes and returns the first non-empty substring of `buf` separated by characters that satisfy `pred`
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`]
+unsafe fn next_piece<'a, Traits: IntoChars>(
+    buf: &mut &'a [Traits::Char],
+    pred: fn(char) -> bool,
+) -> Option<&'a [Traits::Char]> {
+    let rest = trim_start_by::<Traits>(buf, pred);
+    if rest.is_empty() {
+        *buf = rest;
+        None
+    } else {
+        let end = rest.len() - trim_start_by::<Traits>(rest, |c| !pred(c)).len();
+        let (piece, rest) = rest.split_at(end);
+        *buf = rest;
+        Some(piece)
+    }
+}
+
+/// Removes and returns the last non-empty substring of `buf` separated by characters that satisfy `pred`
+///
+/// # Safety
+/// `buf` shall be valid according to [`CharTraits::validate_range`]
+unsafe fn next_piece_back<'a, Traits: DecodeRev>(
+    buf: &mut &'a [Traits::Char],
+    pred: fn(char) -> bool,
+) -> Option<&'a [Traits::Char]> {
+    let rest = trim_end_by::<Traits>(buf, pred);
+    if rest.is_empty() {
+        *buf = rest;
+        None
+    } else {
+        let begin = trim_end_by::<Traits>(rest, |c| !pred(c)).len();
+        let (rest, piece) = rest.split_at(begin);
+        *buf = rest;
+        Some(piece)
+    }
+}
+
+pub struct SplitWhitespace<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
+
+impl<'a, Traits: IntoChars + 'a> Iterator for SplitWhitespace<'a, Traits::Char, Traits> {
+    type Item = &'a BasicStr<Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY:
+        // `self.0` is valid, and `next_piece` splits it on character boundaries
+        let piece = unsafe { next_piece::<Traits>(&mut self.0, char::is_whitespace) }?;
+        Some(unsafe { BasicStr::from_chars_unchecked(piece) })
+    }
+}
+
+impl<'a, Traits: DecodeRev + 'a> DoubleEndedIterator for SplitWhitespace<'a, Traits::Char, Traits> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY:
+        // `self.0` is valid, and `next_piece_back` splits it on character boundaries
+        let piece = unsafe { next_piece_back::<Traits>(&mut self.0, char::is_whitespace) }?;
+        Some(unsafe { BasicStr::from_chars_unchecked(piece) })
+    }
+}
+
+pub struct SplitAsciiWhitespace<'a, CharT, Traits>(&'a [CharT], PhantomData<Traits>);
+
+impl<'a, Traits: IntoChars + 'a> Iterator for SplitAsciiWhitespace<'a, Traits::Char, Traits> {
+    type Item = &'a BasicStr<Traits::Char, Traits>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY:
+        // `self.0` is valid, and `next_piece` splits it on character boundaries
+        let piece = unsafe { next_piece::<Traits>(&mut self.0, |c| c.is_ascii_whitespace()) }?;
+        Some(unsafe { BasicStr::from_chars_unchecked(piece) })
+    }
+}
+
+impl<'a, Traits: DecodeRev + 'a> DoubleEndedIterator
+    for SplitAsciiWhitespace<'a, Traits::Char, Traits>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY:
+        // `self.0` is valid, and `next_piece_back` splits it on character boundaries
+        let piece = unsafe { next_piece_back::<Traits>(&mut self.0, |c| c.is_ascii_whitespace()) }?;
+        Some(unsafe { BasicStr::from_chars_unchecked(piece) })
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::{Str, U16Str};
+    use crate::utf::UtfCharTraits;
+    use core::ops::Bound;
+
+    const SAMPLE: &str = "a\u{7f}\u{80}\u{7ff}\u{800}\u{ffff}\u{10000}\u{10ffff}";
+
+    #[test]
+    fn test_encode_as() {
+        let s = Str::from_str(SAMPLE);
+        let units: Vec<u16> = s.encode_as::<UtfCharTraits<u16>>().collect();
+        assert_eq!(units, SAMPLE.encode_utf16().collect::<Vec<_>>());
+
+        let u16s = U16Str::from_chars(&units).unwrap();
+        let bytes: Vec<u8> = u16s.encode_as::<UtfCharTraits<u8>>().collect();
+        assert_eq!(bytes, SAMPLE.as_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_transcode() {
+        let s = Str::from_str(SAMPLE);
+        let u16s = s.transcode::<UtfCharTraits<u16>>();
+        assert_eq!(
+            u16s.as_chars(),
+            &SAMPLE.encode_utf16().collect::<Vec<_>>()[..]
+        );
+
+        let u32s = u16s.transcode::<UtfCharTraits<char>>();
+        assert_eq!(u32s.as_chars(), &SAMPLE.chars().collect::<Vec<_>>()[..]);
+
+        let back = super::U32Str::new(u32s.as_chars()).transcode::<UtfCharTraits<u8>>();
+        assert_eq!(back.as_chars(), SAMPLE.as_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_transcode_ascii_runs() {
+        for sample in [
+            "",
+            "ascii only",
+            "caf\u{e9} au lait",
+            "\u{1F600}x\u{e9}",
+            SAMPLE,
+        ] {
+            let units: Vec<u16> = sample.encode_utf16().collect();
+            let u16s = Str::from_str(sample).transcode::<UtfCharTraits<u16>>();
+            assert_eq!(u16s.as_chars(), &units[..]);
+            let back = U16Str::from_chars(&units)
+                .unwrap()
+                .transcode::<UtfCharTraits<u8>>();
+            assert_eq!(back.as_chars(), sample.as_bytes());
+            let same = Str::from_str(sample).transcode::<UtfCharTraits<u8>>();
+            assert_eq!(same.as_chars(), sample.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_chunks() {
+        let chunks: Vec<_> = Str::chunks(b"ab\xffc\xe2\x82")
+            .map(|chunk| (chunk.valid().as_str(), chunk.invalid()))
+            .collect();
+        assert_eq!(chunks, [("ab", &b"\xff"[..]), ("c", &b"\xe2\x82"[..])]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_from_chars_lossy() {
+        use alloc::borrow::Cow;
+
+        assert!(matches!(Str::from_chars_lossy(b"abc"), Cow::Borrowed(_)));
+
+        let bytes = b"a\xf0\x9f\x98b\xc0\xafc";
+        let lossy = Str::from_chars_lossy(bytes);
+        assert_eq!(
+            lossy.as_str(),
+            alloc::string::String::from_utf8_lossy(bytes)
+        );
+
+        let units = [0x61, 0xD800, 0x62, 0xDC00];
+        let lossy = crate::string::U16String::from_chars_lossy(units.to_vec());
+        assert_eq!(lossy.as_chars(), &[0x61, 0xFFFD, 0x62, 0xFFFD]);
+    }
+
+    #[cfg(feature = "pattern")]
+    #[test]
+    fn test_search() {
+        let s = Str::from_str("a,b,,c,");
+        let pieces = |it: &mut dyn Iterator<Item = &Str>| {
+            it.map(|piece| piece.as_str().to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(s.contains(',') && !s.contains('x'));
+        assert!(s.starts_with(Str::from_str("a,")) && !s.starts_with(','));
+        assert!(s.ends_with(',') && !s.ends_with('c'));
+        assert_eq!(s.strip_prefix('a').map(Str::as_str), Some(",b,,c,"));
+        assert_eq!(
+            s.strip_suffix(Str::from_str("c,")).map(Str::as_str),
+            Some("a,b,,")
+        );
+        assert_eq!(s.strip_suffix('c'), None);
+
+        assert_eq!(pieces(&mut s.splitn(3, ',')), ["a", "b", ",c,"]);
+        assert_eq!(pieces(&mut s.rsplitn(2, ',')), ["", "a,b,,c"]);
+        assert_eq!(pieces(&mut s.split_terminator(',')), ["a", "b", "", "c"]);
+        assert_eq!(pieces(&mut s.split_inclusive(',')), ["a,", "b,", ",", "c,"]);
+        assert_eq!(
+            pieces(&mut s.split_inclusive(',').rev()),
+            ["c,", ",", "b,", "a,"]
+        );
+        assert_eq!(
+            pieces(&mut Str::from_str("a,b").split_inclusive(',').rev()),
+            ["b", "a,"]
+        );
+        let mut split = s.split_inclusive(',');
+        assert_eq!(split.next_back().map(Str::as_str), Some("c,"));
+        assert_eq!(split.next().map(Str::as_str), Some("a,"));
+        assert_eq!(split.next_back().map(Str::as_str), Some(","));
+        assert_eq!(split.next().map(Str::as_str), Some("b,"));
+        assert_eq!(split.next_back(), None);
+        assert_eq!(pieces(&mut s.matches(|c: char| c != ',')), ["a", "b", "c"]);
+        assert_eq!(pieces(&mut s.rmatches(|c: char| c != ',')), ["c", "b", "a"]);
+        assert_eq!(
+            s.match_indices(',').map(|(i, _)| i).collect::<Vec<_>>(),
+            [1, 3, 4, 6]
+        );
+        assert_eq!(
+            s.rmatch_indices(',').map(|(i, _)| i).collect::<Vec<_>>(),
+            [6, 4, 3, 1]
+        );
+        assert_eq!(s.trim_matches(',').as_str(), "a,b,,c");
+        assert_eq!(s.trim_start_matches('a').as_str(), ",b,,c,");
+        assert_eq!(s.trim_end_matches([',', 'c']).as_str(), "a,b");
+
+        let units: Vec<u16> = "\u{1F600}x\u{1F600}".encode_utf16().collect();
+        let u16s = U16Str::from_chars(&units).unwrap();
+        assert_eq!(u16s.matches('\u{1F600}').count(), 2);
+        assert_eq!(u16s.trim_matches('\u{1F600}').as_chars(), &[b'x' as u16]);
+        assert_eq!(
+            u16s.match_indices('x').map(|(i, _)| i).collect::<Vec<_>>(),
+            [2]
+        );
+    }
+
+    #[cfg(feature = "pattern")]
+    #[test]
+    fn test_split() {
+        fn collect<'a>(it: impl Iterator<Item = &'a Str>) -> Vec<&'a str> {
+            it.map(Str::as_str).collect()
+        }
+
+        for haystack in ["", ",", "a,b,,c,", ",\u{e9},", "aaa", "a\u{1f600}b"] {
+            let s = Str::from_str(haystack);
+            for needle in ["", ",", "aa", "\u{e9}"] {
+                let pat = Str::from_str(needle);
+                let msg = format!("{haystack:?} split by {needle:?}");
+                assert_eq!(
+                    collect(s.split(pat)),
+                    haystack.split(needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+                assert_eq!(
+                    collect(s.rsplit(pat)),
+                    haystack.rsplit(needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+                assert_eq!(
+                    collect(s.splitn(2, pat)),
+                    haystack.splitn(2, needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+                assert_eq!(
+                    collect(s.rsplitn(2, pat)),
+                    haystack.rsplitn(2, needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+                assert_eq!(
+                    collect(s.split_terminator(pat)),
+                    haystack.split_terminator(needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+                assert_eq!(
+                    collect(s.split_inclusive(pat)),
+                    haystack.split_inclusive(needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+                assert_eq!(
+                    collect(s.matches(pat)),
+                    haystack.matches(needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+                assert_eq!(
+                    collect(s.rmatches(pat)),
+                    haystack.rmatches(needle).collect::<Vec<_>>(),
+                    "{msg}"
+                );
+            }
+
+            assert_eq!(
+                collect(s.split(',').rev()),
+                haystack.split(',').rev().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                collect(s.rsplit(',').rev()),
+                haystack.rsplit(',').rev().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                collect(s.split_terminator(',').rev()),
+                haystack.split_terminator(',').rev().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                collect(s.split_inclusive(',').rev()),
+                haystack.split_inclusive(',').rev().collect::<Vec<_>>()
+            );
+        }
+
+        let s = Str::from_str("a,b,c,d");
+        let mut split = s.split(',');
+        assert_eq!(split.next().map(Str::as_str), Some("a"));
+        assert_eq!(split.next_back().map(Str::as_str), Some("d"));
+        assert_eq!(split.next().map(Str::as_str), Some("b"));
+        assert_eq!(split.next_back().map(Str::as_str), Some("c"));
+        assert_eq!(split.next(), None);
+        assert_eq!(split.next_back(), None);
+
+        let mut split = s.split_inclusive(',');
+        assert_eq!(split.next_back().map(Str::as_str), Some("d"));
+        assert_eq!(split.next().map(Str::as_str), Some("a,"));
+        assert_eq!(split.next_back().map(Str::as_str), Some("c,"));
+        assert_eq!(split.next().map(Str::as_str), Some("b,"));
+        assert_eq!(split.next_back(), None);
+
+        let mut matches = s.match_indices(|c: char| c != ',');
+        assert_eq!(matches.next_back().map(|(i, _)| i), Some(6));
+        assert_eq!(matches.next().map(|(i, _)| i), Some(0));
+        assert_eq!(matches.map(|(i, _)| i).collect::<Vec<_>>(), [2, 4]);
+    }
+
+    #[test]
+    fn test_whitespace_and_lines() {
+        let text = "\u{3000} key = value \r\n\nlast\r";
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let u16s = U16Str::from_chars(&units).unwrap();
+        let to_string = |s: &U16Str| String::from_utf16(s.as_chars()).unwrap();
+
+        assert_eq!(to_string(u16s.trim_start()), text.trim_start());
+        assert_eq!(to_string(u16s.trim_end()), text.trim_end());
+        assert_eq!(to_string(u16s.trim()), text.trim());
+
+        let lines: Vec<_> = u16s.lines().map(to_string).collect();
+        assert_eq!(lines, text.lines().collect::<Vec<_>>());
+        let lines: Vec<_> = u16s.lines().rev().map(to_string).collect();
+        assert_eq!(lines, text.lines().rev().collect::<Vec<_>>());
+
+        let words: Vec<_> = u16s.split_whitespace().map(to_string).collect();
+        assert_eq!(words, text.split_whitespace().collect::<Vec<_>>());
+        let words: Vec<_> = u16s.split_whitespace().rev().map(to_string).collect();
+        assert_eq!(words, text.split_whitespace().rev().collect::<Vec<_>>());
+        let words: Vec<_> = u16s.split_ascii_whitespace().map(to_string).collect();
+        assert_eq!(words, text.split_ascii_whitespace().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_char_boundaries() {
+        let s = Str::from_str(SAMPLE);
+        for i in 0..=SAMPLE.len() + 1 {
+            assert_eq!(s.is_char_boundary(i), SAMPLE.is_char_boundary(i), "{i}");
+            assert_eq!(s.get(..i).is_some(), SAMPLE.get(..i).is_some(), "{i}");
+            assert_eq!(s.get(i..).is_some(), SAMPLE.get(i..).is_some(), "{i}");
+            assert_eq!(
+                s.slice_checked(i..).map(|s| s.as_chars()),
+                SAMPLE.get(i..).map(str::as_bytes)
+            );
+            assert_eq!(s.split_at_checked(i).is_some(), SAMPLE.is_char_boundary(i));
+        }
+        assert_eq!(s.floor_char_boundary(5), 4);
+        assert_eq!(s.ceil_char_boundary(5), 6);
+        assert_eq!(s.floor_char_boundary(100), SAMPLE.len());
+        assert_eq!(s.slice_checked(6..=8).unwrap().as_str(), "\u{800}");
+        assert!(s
+            .slice_checked((Bound::Excluded(4), Bound::Included(3)))
+            .is_none());
+
+        let units: Vec<u16> = SAMPLE.encode_utf16().collect();
+        let u16s = U16Str::from_chars(&units).unwrap();
+        let boundaries: Vec<usize> = (0..=units.len() + 1)
+            .filter(|&i| u16s.is_char_boundary(i))
+            .collect();
+        assert_eq!(boundaries, [0, 1, 2, 3, 4, 5, 6, 8, 10]);
+        assert!(u16s.slice_checked(7..).is_none());
+        assert!(u16s.split_at_checked(9).is_none());
+        assert_eq!(u16s.floor_char_boundary(9), 8);
+        assert_eq!(u16s.ceil_char_boundary(7), 8);
+    }
+
+    #[test]
+    fn test_char_indices() {
+        let s = Str::from_str(SAMPLE);
+        let expected: Vec<_> = SAMPLE.char_indices().collect();
+        assert_eq!(s.char_indices().collect::<Vec<_>>(), expected);
+        let rev: Vec<_> = s.char_indices().rev().collect();
+        assert_eq!(rev, SAMPLE.char_indices().rev().collect::<Vec<_>>());
+
+        let mut iter = s.char_indices();
+        assert_eq!(iter.next(), Some((0, 'a')));
+        assert_eq!(iter.next_back(), Some((16, '\u{10ffff}')));
+        assert_eq!(iter.offset(), 1);
+        assert_eq!(iter.as_basic_str().as_str(), &SAMPLE[1..16]);
+
+        let units: Vec<u16> = SAMPLE.encode_utf16().collect();
+        let u16s = U16Str::from_chars(&units).unwrap();
+        let indices: Vec<_> = u16s.char_indices().map(|(i, _)| i).collect();
+        assert_eq!(indices, [0, 1, 2, 3, 4, 5, 6, 8]);
+        let rev: Vec<_> = u16s.char_indices().rev().collect();
+        let mut fwd: Vec<_> = u16s.char_indices().collect();
+        fwd.reverse();
+        assert_eq!(rev, fwd);
+    }
+
+    #[test]
+    fn test_unicode_iter() {
+        let s = Str::from_str(SAMPLE);
+        let rev: Vec<char> = s.unicode_iter().rev().collect();
+        assert_eq!(rev, SAMPLE.chars().rev().collect::<Vec<_>>());
+        assert_eq!(s.unicode_iter().count(), SAMPLE.chars().count());
+        for n in 0..=8 {
+            assert_eq!(s.unicode_iter().nth(n), SAMPLE.chars().nth(n));
+        }
+
+        let mut iter = s.unicode_iter();
+        assert_eq!(iter.size_hint(), (5, Some(20)));
+        assert_eq!(iter.nth(2), Some('\u{80}'));
+        assert_eq!(iter.next_back(), Some('\u{10ffff}'));
+        assert_eq!(iter.as_basic_str().as_str(), &SAMPLE[4..16]);
+        assert_eq!(iter.size_hint(), (3, Some(12)));
+        assert_eq!(iter.count(), 4);
+
+        let units: Vec<u16> = SAMPLE.encode_utf16().collect();
+        let u16s = U16Str::from_chars(&units).unwrap();
+        let rev: Vec<char> = u16s.unicode_iter().rev().collect();
+        assert_eq!(rev, SAMPLE.chars().rev().collect::<Vec<_>>());
+        assert_eq!(u16s.unicode_iter().count(), 8);
+        assert_eq!(u16s.unicode_iter().nth(7), Some('\u{10ffff}'));
+        assert_eq!(u16s.unicode_iter().nth(8), None);
+        let mut iter = u16s.unicode_iter();
+        iter.nth(6);
+        assert_eq!(iter.as_basic_str().as_chars(), &units[8..]);
+    }
+}
@@ -53,6 +53,12 @@ impl<CharT, Traits> BasicString<CharT, Traits, Global> {
     }
 }
 
+impl<CharT, Traits> Default for BasicString<CharT, Traits, Global> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<CharT, Traits, A: Allocator> BasicString<CharT, Traits, A> {
     #[cfg(feature = "allocator-api")]
     pub const fn new_in(alloc: A) -> Self {
@@ -71,6 +77,10 @@ impl<CharT, Traits, A: Allocator> BasicString<CharT, Traits, A> {
         }
     }
 
+    /// Converts a [`Vec`] of `CharT` into a [`BasicString`] without validating it
+    ///
+    /// # Safety
+    /// The `chars` shall be valid according to [`CharTraits::validate_range`]
     #[cfg(feature = "allocator-api")]
     pub const unsafe fn from_chars_unchecked(chars: Vec<CharT, A>) -> Self {
         Self {
@@ -80,6 +90,10 @@ impl<CharT, Traits, A: Allocator> BasicString<CharT, Traits, A> {
         }
     }
 
+    /// Converts a [`Vec`] of `CharT` into a [`BasicString`] without validating it
+    ///
+    /// # Safety
+    /// The `chars` shall be valid according to [`CharTraits::validate_range`]
     #[cfg(not(feature = "allocator-api"))]
     pub const unsafe fn from_chars_unchecked(chars: Vec<CharT>) -> Self {
         Self {
@@ -192,6 +206,20 @@ impl<Traits: CharTraits, A: Allocator> BasicString<Traits::Char, Traits, A> {
     }
 }
 
+impl<Traits: IntoChars> BasicString<Traits::Char, Traits> {
+    /// Converts a [`Vec`] of `CharT` into a [`BasicString`], replacing each invalid sequence with U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// Reuses the allocation of `chars` if it is valid.
+    pub fn from_chars_lossy(chars: Vec<Traits::Char>) -> Self {
+        match Traits::validate_range(&chars) {
+            // SAFETY:
+            // `chars` was just validated
+            Ok(()) => unsafe { Self::from_chars_unchecked(chars) },
+            Err(_) => BasicStr::from_chars_lossy(&chars).into_owned(),
+        }
+    }
+}
+
 impl<Traits: CharTraits + IntoChars, A: Allocator> BasicString<Traits::Char, Traits, A> {
     pub fn push(&mut self, c: char) {
         let base_len = self.len();
@@ -277,7 +305,7 @@ impl<CharT: Eq, Traits, A: Allocator> Eq for BasicString<CharT, Traits, A> {}
 
 impl<Traits: CharTraits, A: Allocator> PartialOrd for BasicString<Traits::Char, Traits, A> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        BasicStr::partial_cmp(&**self, &**other)
+        Some(self.cmp(other))
     }
 }
 
@@ -192,6 +192,18 @@ pub trait CharTraits {
     /// The "End of File" Sentinel.
     /// This is typically not a possible value of `Char`
     fn eof() -> Self::Int;
+
+    /// Whether `idx` is a character boundary of `buf`, that is either the index of the first `Self::Char` of a character, or `buf.len()`.
+    ///
+    /// Returns `false` if `idx` is greater than `buf.len()`.
+    /// If `buf` is not valid according to [`CharTraits::validate_range`], the result is unspecified.
+    /// `unsafe` code may rely on `buf[..idx]` and `buf[idx..]` being valid if this returns `true` for a valid `buf`.
+    ///
+    /// The default implementation validates `buf[..idx]`, which is only valid if it ends on a character boundary.
+    /// Implementations should override this if a boundary can be recognized from the units around `idx`.
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        idx <= buf.len() && Self::validate_range(&buf[..idx]).is_ok()
+    }
 }
 
 /// Methods for [`CharTraits`] implementations that can be encoded/decoded losslessly through the Rust [`char`] type.
@@ -217,6 +229,11 @@ pub unsafe trait IntoChars: CharTraits {
     ///  and yield `c` exactly, if `b` is a `[Self::Char]` that has a `len` at least this value
     fn max_encoding_len() -> usize;
 
+    /// Determines the minimum encoding length, such that no `char` is encoded by fewer `Self::Char`
+    fn min_encoding_len() -> usize {
+        1
+    }
+
     /// Encodes `c` into the beginning of `buf` and returns the slice of `buf` that entrely contains the encoded characters
     ///
     /// The returned buffer shall be valid according to [`CharTraits::validate_range`]
@@ -228,8 +245,123 @@ pub unsafe trait IntoChars: CharTraits {
 
     /// The exact length of the buf necessary to `encode` `c`
     fn encoding_len(c: char) -> usize;
+
+    /// The number of `Self::Char` that encode the first character of `buf`, or `0` if `buf` is empty.
+    ///
+    /// If `buf` is not valid according to [`CharTraits::validate_range`], the result is unspecified, but no greater than `buf.len()`.
+    ///
+    /// The default implementation decodes the first character.
+    fn first_char_len(buf: &[Self::Char]) -> usize {
+        match Self::decode_buf(buf) {
+            Some((_, rest)) => buf.len() - rest.len(),
+            None => buf.len(),
+        }
+    }
+
+    /// The number of `char`s encoded by `buf`.
+    ///
+    /// If `buf` is not valid according to [`CharTraits::validate_range`], the result is unspecified.
+    fn char_count(mut buf: &[Self::Char]) -> usize {
+        let mut count = 0;
+        while let Some((_, rest)) = Self::decode_buf(buf) {
+            count += 1;
+            buf = rest;
+        }
+        count
+    }
+
+    /// The number of UTF-8 code units needed to encode the characters of `buf`.
+    ///
+    /// If `buf` is not valid according to [`CharTraits::validate_range`], the result is unspecified.
+    fn utf8_len(mut buf: &[Self::Char]) -> usize {
+        let mut len = 0;
+        while let Some((c, rest)) = Self::decode_buf(buf) {
+            len += c.len_utf8();
+            buf = rest;
+        }
+        len
+    }
+
+    /// The number of UTF-16 code units needed to encode the characters of `buf`.
+    ///
+    /// If `buf` is not valid according to [`CharTraits::validate_range`], the result is unspecified.
+    fn utf16_len(mut buf: &[Self::Char]) -> usize {
+        let mut len = 0;
+        while let Some((c, rest)) = Self::decode_buf(buf) {
+            len += c.len_utf16();
+            buf = rest;
+        }
+        len
+    }
+
+    /// The exact length of the buffer of `Self::Char` necessary to `encode` every character of `buf`, which is encoded according to `From`.
+    ///
+    /// Implementations may override this to dispatch to a faster counting method of `From`, such as [`IntoChars::utf8_len`].
+    ///
+    /// If `buf` is not valid according to `From`, the result is unspecified.
+    fn transcoded_len<From: IntoChars>(mut buf: &[From::Char]) -> usize {
+        let mut len = 0;
+        while let Some((c, rest)) = From::decode_buf(buf) {
+            len += Self::encoding_len(c);
+            buf = rest;
+        }
+        len
+    }
+
+    /// Encodes the characters of `buf` as UTF-8 into the start of `out`, and returns the number of bytes written.
+    ///
+    /// # Panics
+    /// Panics if `out` is shorter than [`IntoChars::utf8_len`] of `buf`.
+    ///
+    /// If `buf` is not valid according to [`CharTraits::validate_range`], the result is unspecified.
+    fn encode_utf8(mut buf: &[Self::Char], out: &mut [u8]) -> usize {
+        let mut pos = 0;
+        while let Some((c, rest)) = Self::decode_buf(buf) {
+            pos += c.encode_utf8(&mut out[pos..]).len();
+            buf = rest;
+        }
+        pos
+    }
+
+    /// Encodes the characters of `buf` as UTF-16 into the start of `out`, and returns the number of code units written.
+    ///
+    /// # Panics
+    /// Panics if `out` is shorter than [`IntoChars::utf16_len`] of `buf`.
+    ///
+    /// If `buf` is not valid according to [`CharTraits::validate_range`], the result is unspecified.
+    fn encode_utf16(mut buf: &[Self::Char], out: &mut [u16]) -> usize {
+        let mut pos = 0;
+        while let Some((c, rest)) = Self::decode_buf(buf) {
+            pos += c.encode_utf16(&mut out[pos..]).len();
+            buf = rest;
+        }
+        pos
+    }
+
+    /// Encodes the characters of `buf`, which is encoded according to `From`, into the start of `out`,
+    ///  and returns the number of `Self::Char` written.
+    ///
+    /// Implementations may override this to dispatch to a faster encoding method of `From`, such as [`IntoChars::encode_utf8`].
+    ///
+    /// # Panics
+    /// Panics if `out` is shorter than [`IntoChars::transcoded_len`] of `buf`.
+    ///
+    /// If `buf` is not valid according to `From`, the result is unspecified.
+    fn transcode_from<From: IntoChars>(mut buf: &[From::Char], out: &mut [Self::Char]) -> usize {
+        let mut pos = 0;
+        while let Some((c, rest)) = From::decode_buf(buf) {
+            pos += Self::encode(c, &mut out[pos..]).len();
+            buf = rest;
+        }
+        pos
+    }
 }
 
+/// Methods for [`IntoChars`] implementations that can also decode from the back of a buffer.
+///
+/// # Safety
+/// The `decode_back` methods shall be consistent with [`IntoChars::decode_buf`] and [`IntoChars::decode_buf_unchecked`],
+/// such that decoding a valid buffer from the back yields the same sequence of `char`s in reverse order.
 pub unsafe trait DecodeRev: IntoChars {
     /// Decodes from the back of the given buffer into a char, and returns it and the remainder of the buffer.
     ///
@@ -0,0 +1,301 @@
+use core::marker::PhantomData;
+
+use crate::cstr::RawCharTraits;
+use crate::traits::{CharTraits, IntoChars, ValidationError};
+
+/// The maximum number of code units of a single character that a [`Transcoder`] can hold between calls
+const PENDING_LEN: usize = 4;
+
+/// The result of decoding a single character from the front of a buffer
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecodeResult {
+    /// A character was decoded from the given number of code units
+    Char(char, usize),
+    /// The buffer ends in the middle of a character
+    Incomplete,
+    /// The buffer begins with the given number of code units that do not form a valid character
+    Malformed(usize),
+}
+
+/// The result of encoding a single character into a buffer
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EncodeResult {
+    /// The character was encoded into the given number of code units
+    Encoded(usize),
+    /// The buffer is too small to hold the encoded character
+    OutputFull,
+    /// The character cannot be represented by the encoding
+    Unmappable,
+}
+
+/// [`CharTraits`] that can decode characters from a possibly invalid or truncated buffer.
+///
+/// This is implemented for every [`IntoChars`] implementation, as well as for [`RawCharTraits`],
+/// which decodes each byte as the code point of the same value (ISO-8859-1).
+pub trait Decode: CharTraits {
+    /// Decodes a single character from the front of `buf`.
+    ///
+    /// `buf` shall not be empty.
+    fn decode_next(buf: &[Self::Char]) -> DecodeResult;
+}
+
+/// [`CharTraits`] that can encode characters into a bounded buffer.
+///
+/// This is implemented for every [`IntoChars`] implementation, as well as for [`RawCharTraits`],
+/// which encodes each code point up to U+00FF as a byte of the same value (ISO-8859-1).
+pub trait Encode: CharTraits {
+    /// Encodes `c` into the front of `buf`.
+    ///
+    /// The encoded characters shall be valid according to [`CharTraits::validate_range`].
+    fn encode_next(c: char, buf: &mut [Self::Char]) -> EncodeResult;
+}
+
+impl<Traits: IntoChars> Decode for Traits {
+    fn decode_next(buf: &[Self::Char]) -> DecodeResult {
+        let window = &buf[..buf.len().min(Traits::max_encoding_len())];
+
+        let valid = match Traits::validate_range(window) {
+            Ok(()) => window,
+            Err(e) if e.first_error_pos() != 0 => &window[..e.first_error_pos()],
+            Err(e) => {
+                return match e.first_error_len() {
+                    Some(len) => DecodeResult::Malformed(len.max(1)),
+                    None if buf.len() < Traits::max_encoding_len() => DecodeResult::Incomplete,
+                    None => DecodeResult::Malformed(1),
+                };
+            }
+        };
+
+        // SAFETY:
+        // `valid` is non-empty and was validated above
+        let (c, rest) = unsafe { Traits::decode_buf_unchecked(valid) };
+        DecodeResult::Char(c, valid.len() - rest.len())
+    }
+}
+
+impl<Traits: IntoChars> Encode for Traits {
+    fn encode_next(c: char, buf: &mut [Self::Char]) -> EncodeResult {
+        if buf.len() < Traits::encoding_len(c) {
+            EncodeResult::OutputFull
+        } else {
+            EncodeResult::Encoded(Traits::encode(c, buf).len())
+        }
+    }
+}
+
+impl Decode for RawCharTraits {
+    fn decode_next(buf: &[u8]) -> DecodeResult {
+        DecodeResult::Char(buf[0] as char, 1)
+    }
+}
+
+impl Encode for RawCharTraits {
+    fn encode_next(c: char, buf: &mut [u8]) -> EncodeResult {
+        match (u8::try_from(c), buf.first_mut()) {
+            (Err(_), _) => EncodeResult::Unmappable,
+            (Ok(_), None) => EncodeResult::OutputFull,
+            (Ok(b), Some(out)) => {
+                *out = b;
+                EncodeResult::Encoded(1)
+            }
+        }
+    }
+}
+
+/// The reason a call to [`Transcoder::transcode`] returned
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TranscodeStatus {
+    /// All of the input was consumed.
+    InputEmpty,
+    /// The output buffer has no room for the next character.
+    OutputFull,
+    /// The given number of code units ending at the returned read position were malformed, and have been consumed.
+    Malformed(usize),
+    /// The character ending at the returned read position cannot be represented in the target encoding, and has been consumed.
+    Unmappable(char),
+}
+
+/// A resumable transcoder from `From` to `To` that reads from and writes to bounded buffers.
+///
+/// A character that is split across two calls to [`Transcoder::transcode`] is held by the transcoder until the rest of it is available.
+pub struct Transcoder<From: Decode, To: Encode> {
+    pending: [From::Char; PENDING_LEN],
+    pending_len: usize,
+    _to: PhantomData<To>,
+}
+
+impl<From: Decode, To: Encode> Default for Transcoder<From, To> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<From: Decode, To: Encode> Transcoder<From, To> {
+    /// Creates a transcoder that holds no partial character
+    pub fn new() -> Self {
+        Self {
+            pending: [From::zero_term(); PENDING_LEN],
+            pending_len: 0,
+            _to: PhantomData,
+        }
+    }
+
+    /// Discards any partial character held from a previous call
+    pub fn reset(&mut self) {
+        self.pending_len = 0;
+    }
+
+    /// Returns `true` if the transcoder is holding a partial character from a previous call
+    pub fn has_pending_input(&self) -> bool {
+        self.pending_len != 0
+    }
+
+    /// Transcodes as much of `src` into `dst` as possible.
+    ///
+    /// Returns the status, the number of code units read from `src`, and the number of code units written to `dst`.
+    /// Transcoding stops at the first malformed sequence or unmappable character, so that the caller can choose how to handle it,
+    ///  and may be resumed by calling this function again with the unread remainder of `src`.
+    ///
+    /// `last` shall be `true` if `src` is the end of the input. In that case, an incomplete character at the end of `src` is reported as malformed,
+    ///  otherwise it is held until the next call.
+    pub fn transcode(
+        &mut self,
+        src: &[From::Char],
+        dst: &mut [To::Char],
+        last: bool,
+    ) -> (TranscodeStatus, usize, usize) {
+        let mut read = 0;
+        let mut written = 0;
+
+        while self.pending_len != 0 {
+            match From::decode_next(&self.pending[..self.pending_len]) {
+                DecodeResult::Char(c, len) => {
+                    let status = match To::encode_next(c, &mut dst[written..]) {
+                        EncodeResult::Encoded(n) => {
+                            written += n;
+                            None
+                        }
+                        EncodeResult::OutputFull => {
+                            return (TranscodeStatus::OutputFull, read, written)
+                        }
+                        EncodeResult::Unmappable => Some(TranscodeStatus::Unmappable(c)),
+                    };
+                    self.consume_pending(len);
+                    if let Some(status) = status {
+                        return (status, read, written);
+                    }
+                }
+                DecodeResult::Incomplete if read < src.len() && self.pending_len < PENDING_LEN => {
+                    self.pending[self.pending_len] = src[read];
+                    self.pending_len += 1;
+                    read += 1;
+                }
+                DecodeResult::Incomplete if read < src.len() || last => {
+                    let len = self.pending_len;
+                    self.pending_len = 0;
+                    return (TranscodeStatus::Malformed(len), read, written);
+                }
+                DecodeResult::Incomplete => return (TranscodeStatus::InputEmpty, read, written),
+                DecodeResult::Malformed(len) => {
+                    let len = len.min(self.pending_len);
+                    self.consume_pending(len);
+                    return (TranscodeStatus::Malformed(len), read, written);
+                }
+            }
+        }
+
+        while read < src.len() {
+            match From::decode_next(&src[read..]) {
+                DecodeResult::Char(c, len) => match To::encode_next(c, &mut dst[written..]) {
+                    EncodeResult::Encoded(n) => {
+                        read += len;
+                        written += n;
+                    }
+                    EncodeResult::OutputFull => {
+                        return (TranscodeStatus::OutputFull, read, written)
+                    }
+                    EncodeResult::Unmappable => {
+                        read += len;
+                        return (TranscodeStatus::Unmappable(c), read, written);
+                    }
+                },
+                DecodeResult::Incomplete if !last && src.len() - read <= PENDING_LEN => {
+                    let rest = &src[read..];
+                    self.pending[..rest.len()].copy_from_slice(rest);
+                    self.pending_len = rest.len();
+                    read = src.len();
+                }
+                DecodeResult::Incomplete => {
+                    let len = (src.len() - read).min(PENDING_LEN);
+                    read += len;
+                    return (TranscodeStatus::Malformed(len), read, written);
+                }
+                DecodeResult::Malformed(len) => {
+                    let len = len.min(src.len() - read);
+                    read += len;
+                    return (TranscodeStatus::Malformed(len), read, written);
+                }
+            }
+        }
+
+        (TranscodeStatus::InputEmpty, read, written)
+    }
+
+    fn consume_pending(&mut self, len: usize) {
+        self.pending.copy_within(len..self.pending_len, 0);
+        self.pending_len -= len;
+    }
+}
+
+#[cfg(all(test, feature = "utf"))]
+mod test {
+    use super::{TranscodeStatus, Transcoder};
+    use crate::cstr::RawCharTraits;
+    use crate::utf::UtfCharTraits;
+
+    #[test]
+    fn test_split_input() {
+        let input = "a\u{e9}\u{1F600}".as_bytes();
+        let mut transcoder = Transcoder::<UtfCharTraits<u8>, UtfCharTraits<u16>>::new();
+        let mut out = [0u16; 8];
+        let mut written = 0;
+        for (i, chunk) in input.chunks(1).enumerate() {
+            let (status, read, n) =
+                transcoder.transcode(chunk, &mut out[written..], i == input.len() - 1);
+            assert_eq!(status, TranscodeStatus::InputEmpty);
+            assert_eq!(read, 1);
+            written += n;
+        }
+        let expected: Vec<u16> = "a\u{e9}\u{1F600}".encode_utf16().collect();
+        assert_eq!(&out[..written], &expected[..]);
+    }
+
+    #[test]
+    fn test_output_full() {
+        let mut transcoder = Transcoder::<UtfCharTraits<u16>, UtfCharTraits<u8>>::new();
+        let mut out = [0u8; 2];
+        let (status, read, written) = transcoder.transcode(&[0x61, 0xe9], &mut out, true);
+        assert_eq!((status, read, written), (TranscodeStatus::OutputFull, 1, 1));
+    }
+
+    #[test]
+    fn test_malformed_and_unmappable() {
+        let mut transcoder = Transcoder::<UtfCharTraits<u8>, RawCharTraits>::new();
+        let mut out = [0u8; 8];
+        let (status, read, written) = transcoder.transcode(b"a\xffb", &mut out, true);
+        assert_eq!(
+            (status, read, written),
+            (TranscodeStatus::Malformed(1), 2, 1)
+        );
+        let (status, read, written) =
+            transcoder.transcode("\u{e9}\u{1F600}".as_bytes(), &mut out, true);
+        assert_eq!(
+            (status, read, written),
+            (TranscodeStatus::Unmappable('\u{1F600}'), 6, 1)
+        );
+        assert_eq!(out[0], 0xe9);
+
+        let (status, read, _) = transcoder.transcode(b"\xe2\x82", &mut out, true);
+        assert_eq!((status, read), (TranscodeStatus::Malformed(2), 2));
+    }
+}
@@ -52,10 +52,10 @@ mod private {
             let v0 = iter.next()?;
             if (0xD800..=0xDBFF).contains(&v0) {
                 let v1 = iter.next().expect("Expected valid UTF-16");
-                if !(0xDC00..=0xDFFF).contains(&v0) {
+                if !(0xDC00..=0xDFFF).contains(&v1) {
                     panic!("Expected valid UTF-16")
                 }
-                let val = ((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32);
+                let val = (((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32)) + 0x10000;
                 Some(char::from_u32(val).expect("Expected valid UTF-8"))
             } else {
                 Some(char::from_u32(v0 as u32).expect("Expected valid UTF-8"))
@@ -136,7 +136,7 @@ unsafe impl IntoChars for UtfCharTraits<u8> {
     }
 
     fn decode_buf(buf: &[Self::Char]) -> Option<(char, &[Self::Char])> {
-        let c0 = *buf.get(0)?;
+        let c0 = *buf.first()?;
         if c0 & 0x80 == 0x00 {
             Some((c0 as char, buf.get(1..).unwrap_or(&[])))
         } else if c0 & 0xe0 == 0xc0 {
@@ -175,6 +175,67 @@ unsafe impl IntoChars for UtfCharTraits<u8> {
     fn encoding_len(c: char) -> usize {
         c.len_utf8()
     }
+
+    fn first_char_len(buf: &[Self::Char]) -> usize {
+        match buf.first() {
+            Some(0x00..=0x7f) => 1,
+            Some(b) => (b.leading_ones() as usize).min(buf.len()),
+            None => 0,
+        }
+    }
+
+    fn char_count(buf: &[Self::Char]) -> usize {
+        // Count every byte that isn't a continuation byte
+        buf.iter().filter(|&&b| b & 0xc0 != 0x80).count()
+    }
+
+    fn utf8_len(buf: &[Self::Char]) -> usize {
+        buf.len()
+    }
+
+    fn utf16_len(buf: &[Self::Char]) -> usize {
+        // Every lead byte starts one code unit, and 4-byte sequences need a surrogate pair
+        buf.iter()
+            .map(|&b| match b {
+                0x80..=0xBF => 0,
+                0xF0..=0xFF => 2,
+                _ => 1,
+            })
+            .sum()
+    }
+
+    fn transcoded_len<From: IntoChars>(buf: &[From::Char]) -> usize {
+        From::utf8_len(buf)
+    }
+
+    fn encode_utf8(buf: &[Self::Char], out: &mut [u8]) -> usize {
+        out[..buf.len()].copy_from_slice(buf);
+        buf.len()
+    }
+
+    fn encode_utf16(mut buf: &[Self::Char], out: &mut [u16]) -> usize {
+        let mut pos = 0;
+        loop {
+            // Widen a run of ASCII bytes without decoding them
+            let ascii = buf.iter().take_while(|b| b.is_ascii()).count();
+            for (unit, &b) in out[pos..pos + ascii].iter_mut().zip(&buf[..ascii]) {
+                *unit = b as u16;
+            }
+            pos += ascii;
+            buf = &buf[ascii..];
+            match Self::decode_buf(buf) {
+                Some((c, rest)) => {
+                    pos += c.encode_utf16(&mut out[pos..]).len();
+                    buf = rest;
+                }
+                None => return pos,
+            }
+        }
+    }
+
+    fn transcode_from<From: IntoChars>(buf: &[From::Char], out: &mut [Self::Char]) -> usize {
+        From::encode_utf8(buf, out)
+    }
 }
 
 unsafe impl DecodeRev for UtfCharTraits<u8> {
@@ -184,7 +245,9 @@ unsafe impl DecodeRev for UtfCharTraits<u8> {
             let (&b, rest) = buf.split_last().unwrap_unchecked();
             buf = rest;
             if b & 0xC0 != 0x80 {
-                val |= (b as u32 & ((0x100 >> i) - 1)) << (6 * i);
+                // The lead byte of an `i+1` byte sequence has `i+2` prefix bits, except for ASCII
+                let mask = if i == 0 { 0x7f } else { 0xff >> (i + 2) };
+                val |= (b as u32 & mask) << (6 * i);
                 break;
             } else {
                 val |= (b as u32 & 0x3f) << (6 * i);
@@ -203,10 +266,12 @@ unsafe impl DecodeRev for UtfCharTraits<u8> {
             let (&b, rest) = buf.split_last()?;
             buf = rest;
             if b & 0xC0 != 0x80 {
-                if (i == 0 && b.leading_ones() != 1) || (b.leading_ones() != (i + 1)) {
+                if (i == 0 && b.leading_ones() != 0) || (i != 0 && b.leading_ones() != (i + 1)) {
                     return None;
                 }
-                val |= (b as u32 & ((0x100 >> i) - 1)) << (6 * i);
+                // The lead byte of an `i+1` byte sequence has `i+2` prefix bits, except for ASCII
+                let mask = if i == 0 { 0x7f } else { 0xff >> (i + 2) };
+                val |= (b as u32 & mask) << (6 * i);
                 break;
             } else {
                 val |= (b as u32 & 0x3f) << (6 * i);
@@ -222,7 +287,7 @@ unsafe impl IntoChars for UtfCharTraits<u16> {
         let v0 = *buf.get_unchecked(0);
         if (0xD800..=0xDBFF).contains(&v0) {
             let v1 = *buf.get_unchecked(1);
-            let val = ((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32);
+            let val = (((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32)) + 0x10000;
             (char::from_u32_unchecked(val), buf.get(2..).unwrap_or(&[]))
         } else {
             (char::from_u32_unchecked(v0 as u32), buf.get_unchecked(1..))
@@ -230,16 +295,16 @@ unsafe impl IntoChars for UtfCharTraits<u16> {
     }
 
     fn decode_buf(buf: &[Self::Char]) -> Option<(char, &[Self::Char])> {
-        let v0 = *buf.get(0)?;
+        let v0 = *buf.first()?;
         if (0xD800..=0xDBFF).contains(&v0) {
             let v1 = *buf.get(1)?;
-            if (0xDC00..=0xDFFF).contains(&v1) {
+            if !(0xDC00..=0xDFFF).contains(&v1) {
                 return None;
             }
-            let val = ((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32);
+            let val = (((v0 - 0xD800) as u32) << 10 | ((v1 - 0xDC00) as u32)) + 0x10000;
             Some((char::from_u32(val)?, buf.get(2..).unwrap_or(&[])))
         } else {
-            Some((char::from_u32(v0 as u32)?, buf.get(2..).unwrap_or(&[])))
+            Some((char::from_u32(v0 as u32)?, buf.get(1..).unwrap_or(&[])))
         }
     }
 
@@ -252,12 +317,72 @@ unsafe impl IntoChars for UtfCharTraits<u16> {
     }
 
     fn encoding_len(c: char) -> usize {
-        if (c as u32) < 0xFFFF {
-            1
-        } else {
-            2
+        c.len_utf16()
+    }
+
+    fn first_char_len(buf: &[Self::Char]) -> usize {
+        match buf.first() {
+            Some(0xD800..=0xDBFF) => buf.len().min(2),
+            Some(_) => 1,
+            None => 0,
         }
     }
+
+    fn char_count(buf: &[Self::Char]) -> usize {
+        // Count every unit that isn't a low surrogate
+        buf.iter()
+            .filter(|&&c| !(0xDC00..=0xDFFF).contains(&c))
+            .count()
+    }
+
+    fn utf8_len(buf: &[Self::Char]) -> usize {
+        // A surrogate pair encodes as 4 bytes, so each half contributes 2
+        buf.iter()
+            .map(|&c| match c {
+                0x0000..=0x007F => 1,
+                0x0080..=0x07FF => 2,
+                0xD800..=0xDFFF => 2,
+                _ => 3,
+            })
+            .sum()
+    }
+
+    fn utf16_len(buf: &[Self::Char]) -> usize {
+        buf.len()
+    }
+
+    fn transcoded_len<From: IntoChars>(buf: &[From::Char]) -> usize {
+        From::utf16_len(buf)
+    }
+
+    fn encode_utf8(mut buf: &[Self::Char], out: &mut [u8]) -> usize {
+        let mut pos = 0;
+        loop {
+            // Narrow a run of ASCII code units without decoding them
+            let ascii = buf.iter().take_while(|&&c| c < 0x80).count();
+            for (byte, &c) in out[pos..pos + ascii].iter_mut().zip(&buf[..ascii]) {
+                *byte = c as u8;
+            }
+            pos += ascii;
+            buf = &buf[ascii..];
+            match Self::decode_buf(buf) {
+                Some((c, rest)) => {
+                    pos += c.encode_utf8(&mut out[pos..]).len();
+                    buf = rest;
+                }
+                None => return pos,
+            }
+        }
+    }
+
+    fn encode_utf16(buf: &[Self::Char], out: &mut [u16]) -> usize {
+        out[..buf.len()].copy_from_slice(buf);
+        buf.len()
+    }
+
+    fn transcode_from<From: IntoChars>(buf: &[From::Char], out: &mut [Self::Char]) -> usize {
+        From::encode_utf16(buf, out)
+    }
 }
 
 unsafe impl DecodeRev for UtfCharTraits<u16> {
@@ -266,7 +391,7 @@ unsafe impl DecodeRev for UtfCharTraits<u16> {
 
         if (0xDC00..=0xDFFF).contains(v1) {
             let (v0, rest) = rest.split_last().unwrap_unchecked();
-            let val = ((*v0 - 0xD800) as u32) << 10 | ((*v1 - 0xDC00) as u32);
+            let val = (((*v0 - 0xD800) as u32) << 10 | ((*v1 - 0xDC00) as u32)) + 0x10000;
             (char::from_u32_unchecked(val), rest)
         } else {
             (char::from_u32_unchecked(*v1 as u32), rest)
@@ -281,7 +406,7 @@ unsafe impl DecodeRev for UtfCharTraits<u16> {
             if !(0xD800..=0xDBFF).contains(v0) {
                 return None;
             }
-            let val = ((*v0 - 0xD800) as u32) << 10 | ((*v1 - 0xDC00) as u32);
+            let val = (((*v0 - 0xD800) as u32) << 10 | ((*v1 - 0xDC00) as u32)) + 0x10000;
             Some((unsafe { char::from_u32_unchecked(val) }, rest))
         } else {
             Some((unsafe { char::from_u32_unchecked(*v1 as u32) }, rest))
@@ -295,7 +420,7 @@ unsafe impl IntoChars for UtfCharTraits<char> {
     }
 
     fn decode_buf(buf: &[Self::Char]) -> Option<(char, &[Self::Char])> {
-        Some((*buf.get(0)?, buf.get(1..).unwrap_or(&[])))
+        Some((*buf.first()?, buf.get(1..).unwrap_or(&[])))
     }
 
     fn max_encoding_len() -> usize {
@@ -310,6 +435,26 @@ unsafe impl IntoChars for UtfCharTraits<char> {
     fn encoding_len(_: char) -> usize {
         1
     }
+
+    fn first_char_len(buf: &[Self::Char]) -> usize {
+        buf.len().min(1)
+    }
+
+    fn char_count(buf: &[Self::Char]) -> usize {
+        buf.len()
+    }
+
+    fn utf8_len(buf: &[Self::Char]) -> usize {
+        buf.iter().map(|c| c.len_utf8()).sum()
+    }
+
+    fn utf16_len(buf: &[Self::Char]) -> usize {
+        buf.iter().map(|c| c.len_utf16()).sum()
+    }
+
+    fn transcoded_len<From: IntoChars>(buf: &[From::Char]) -> usize {
+        From::char_count(buf)
+    }
 }
 
 unsafe impl DecodeRev for UtfCharTraits<char> {
@@ -376,3 +521,383 @@ macro_rules! u8str {
         $crate::str::Str::from_str($lit)
     };
 }
+
+/// Decodes the code point beginning at `pos` in `bytes`, which must be valid UTF-8, and returns it along with the position of the next code point.
+const fn decode_utf8_at(bytes: &[u8], pos: usize) -> (char, usize) {
+    let c0 = bytes[pos];
+    let (val, len) = if c0 & 0x80 == 0x00 {
+        (c0 as u32, 1)
+    } else if c0 & 0xe0 == 0xc0 {
+        (
+            ((c0 & 0x1f) as u32) << 6 | ((bytes[pos + 1] & 0x3f) as u32),
+            2,
+        )
+    } else if c0 & 0xf0 == 0xe0 {
+        (
+            ((c0 & 0xf) as u32) << 12
+                | ((bytes[pos + 1] & 0x3f) as u32) << 6
+                | ((bytes[pos + 2] & 0x3f) as u32),
+            3,
+        )
+    } else {
+        (
+            ((c0 & 0x7) as u32) << 18
+                | ((bytes[pos + 1] & 0x3f) as u32) << 12
+                | ((bytes[pos + 2] & 0x3f) as u32) << 6
+                | ((bytes[pos + 3] & 0x3f) as u32),
+            4,
+        )
+    };
+
+    match char::from_u32(val) {
+        Some(c) => (c, pos + len),
+        None => panic!("Expected valid UTF-8"),
+    }
+}
+
+/// Returns the number of UTF-16 code units required to encode `s`
+#[doc(hidden)]
+pub const fn utf16_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+    let mut len = 0;
+    while pos < bytes.len() {
+        let (c, next) = decode_utf8_at(bytes, pos);
+        len += c.len_utf16();
+        pos = next;
+    }
+    len
+}
+
+/// Returns the number of code points in `s`
+#[doc(hidden)]
+pub const fn utf32_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+    let mut len = 0;
+    while pos < bytes.len() {
+        // Count every byte that isn't a continuation byte
+        if bytes[pos] & 0xc0 != 0x80 {
+            len += 1;
+        }
+        pos += 1;
+    }
+    len
+}
+
+/// Transcodes `s` into an array of UTF-16 code units.
+///
+/// # Panics
+/// Panics if `N` is not equal to [`utf16_len`] of `s`.
+#[doc(hidden)]
+pub const fn encode_utf16_array<const N: usize>(s: &str) -> [u16; N] {
+    let bytes = s.as_bytes();
+    let mut out = [0u16; N];
+    let mut pos = 0;
+    let mut i = 0;
+    while pos < bytes.len() {
+        let (c, next) = decode_utf8_at(bytes, pos);
+        let val = c as u32;
+        if val < 0x10000 {
+            out[i] = val as u16;
+            i += 1;
+        } else {
+            let val = val - 0x10000;
+            out[i] = 0xD800 | (val >> 10) as u16;
+            out[i + 1] = 0xDC00 | (val & 0x3ff) as u16;
+            i += 2;
+        }
+        pos = next;
+    }
+    if i != N {
+        panic!("Length of array does not match the UTF-16 length of the string")
+    }
+    out
+}
+
+/// Transcodes `s` into an array of `char`s.
+///
+/// # Panics
+/// Panics if `N` is not equal to [`utf32_len`] of `s`.
+#[doc(hidden)]
+pub const fn encode_utf32_array<const N: usize>(s: &str) -> [char; N] {
+    let bytes = s.as_bytes();
+    let mut out = ['\0'; N];
+    let mut pos = 0;
+    let mut i = 0;
+    while pos < bytes.len() {
+        let (c, next) = decode_utf8_at(bytes, pos);
+        out[i] = c;
+        i += 1;
+        pos = next;
+    }
+    if i != N {
+        panic!("Length of array does not match the number of code points in the string")
+    }
+    out
+}
+
+/// Copies the bytes of `s` into an array with a trailing zero terminator.
+///
+/// # Panics
+/// Panics if `N` is not equal to `s.len() + 1`, or if `s` contains an interior nul.
+#[doc(hidden)]
+pub const fn encode_utf8_with_nul_array<const N: usize>(s: &str) -> [u8; N] {
+    let bytes = s.as_bytes();
+    if bytes.len() + 1 != N {
+        panic!("Length of array does not match the length of the string with a nul terminator")
+    }
+    let mut out = [0u8; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == 0 {
+            panic!("String contains an interior nul")
+        }
+        out[i] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
+/// Transcodes `s` into an array of UTF-16 code units with a trailing zero terminator.
+///
+/// # Panics
+/// Panics if `N` is not equal to [`utf16_len`] of `s` plus one, or if `s` contains an interior nul.
+#[doc(hidden)]
+pub const fn encode_utf16_with_nul_array<const N: usize>(s: &str) -> [u16; N] {
+    let bytes = s.as_bytes();
+    let mut out = [0u16; N];
+    let mut pos = 0;
+    let mut i = 0;
+    while pos < bytes.len() {
+        let (c, next) = decode_utf8_at(bytes, pos);
+        let val = c as u32;
+        if val == 0 {
+            panic!("String contains an interior nul")
+        } else if val < 0x10000 {
+            out[i] = val as u16;
+            i += 1;
+        } else {
+            let val = val - 0x10000;
+            out[i] = 0xD800 | (val >> 10) as u16;
+            out[i + 1] = 0xDC00 | (val & 0x3ff) as u16;
+            i += 2;
+        }
+        pos = next;
+    }
+    if i + 1 != N {
+        panic!(
+            "Length of array does not match the UTF-16 length of the string with a nul terminator"
+        )
+    }
+    out
+}
+
+/// Transcodes a string literal into a `&'static U16Str` at compile time
+#[macro_export]
+macro_rules! u16str {
+    ($lit:literal) => {{
+        const __STR: &::core::primitive::str = $lit;
+        const __LEN: usize = $crate::utf::utf16_len(__STR);
+        const __ARR: [u16; __LEN] = $crate::utf::encode_utf16_array::<__LEN>(__STR);
+        const __RET: &$crate::str::U16Str =
+            unsafe { $crate::str::U16Str::from_chars_unchecked(&__ARR) };
+
+        __RET
+    }};
+}
+
+/// Transcodes a string literal into a `&'static U32Str` at compile time
+#[macro_export]
+macro_rules! u32str {
+    ($lit:literal) => {{
+        const __STR: &::core::primitive::str = $lit;
+        const __LEN: usize = $crate::utf::utf32_len(__STR);
+        const __ARR: [char; __LEN] = $crate::utf::encode_utf32_array::<__LEN>(__STR);
+        const __RET: &$crate::str::U32Str = $crate::str::U32Str::new(&__ARR);
+
+        __RET
+    }};
+}
+
+/// Converts a string literal into a `&'static Utf8CStr` at compile time.
+///
+/// Fails to compile if the literal contains an interior nul.
+#[macro_export]
+macro_rules! u8cstr {
+    ($lit:literal) => {{
+        const __STR: &::core::primitive::str = $lit;
+        const __LEN: usize = __STR.len() + 1;
+        const __ARR: [u8; __LEN] = $crate::utf::encode_utf8_with_nul_array::<__LEN>(__STR);
+        const __RET: &$crate::cstr::Utf8CStr =
+            unsafe { $crate::cstr::Utf8CStr::from_chars_with_null_unchecked(&__ARR) };
+
+        __RET
+    }};
+}
+
+/// Transcodes a string literal into a `&'static Utf16CStr` at compile time.
+///
+/// Fails to compile if the literal contains an interior nul.
+///
+/// ```compile_fail
+/// let _ = rust_basic_string::u16cstr!("interior\0nul");
+/// ```
+#[macro_export]
+macro_rules! u16cstr {
+    ($lit:literal) => {{
+        const __STR: &::core::primitive::str = $lit;
+        const __LEN: usize = $crate::utf::utf16_len(__STR) + 1;
+        const __ARR: [u16; __LEN] = $crate::utf::encode_utf16_with_nul_array::<__LEN>(__STR);
+        const __RET: &$crate::cstr::Utf16CStr =
+            unsafe { $crate::cstr::Utf16CStr::from_chars_with_null_unchecked(&__ARR) };
+
+        __RET
+    }};
+}
+
+/// Transcodes a string literal into a [`U16ArrayString`](crate::array_str::U16ArrayString) at compile time
+#[macro_export]
+macro_rules! const_u16_array_str {
+    ($lit:literal) => {{
+        const __STR: &::core::primitive::str = $lit;
+        const __LEN: usize = $crate::utf::utf16_len(__STR);
+        const __RET: $crate::array_str::U16ArrayString<__LEN> = unsafe {
+            $crate::array_str::U16ArrayString::from_chars_unchecked(
+                $crate::utf::encode_utf16_array::<__LEN>(__STR),
+            )
+        };
+
+        __RET
+    }};
+}
+
+/// Transcodes a string literal into a [`U32ArrayString`](crate::array_str::U32ArrayString) at compile time
+#[macro_export]
+macro_rules! const_u32_array_str {
+    ($lit:literal) => {{
+        const __STR: &::core::primitive::str = $lit;
+        const __LEN: usize = $crate::utf::utf32_len(__STR);
+        const __RET: $crate::array_str::U32ArrayString<__LEN> = unsafe {
+            $crate::array_str::U32ArrayString::from_chars_unchecked(
+                $crate::utf::encode_utf32_array::<__LEN>(__STR),
+            )
+        };
+
+        __RET
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use super::UtfCharTraits;
+    use crate::array_str::{U16ArrayString, U32ArrayString};
+    use crate::cstr::{Utf16CStr, Utf8CStr};
+    use crate::str::{U16Str, U32Str};
+    use crate::traits::{DecodeRev, IntoChars};
+
+    type Utf8 = UtfCharTraits<u8>;
+    type Utf16 = UtfCharTraits<u16>;
+
+    #[test]
+    fn test_decode_surrogate_pair() {
+        let units = [0xD83D, 0xDE00, 0x41];
+        assert_eq!(Utf16::decode_buf(&units), Some(('\u{1F600}', &units[2..])));
+        assert_eq!(
+            unsafe { Utf16::decode_buf_unchecked(&units) },
+            ('\u{1F600}', &units[2..])
+        );
+        assert_eq!(
+            Utf16::decode_back(&units[..2]),
+            Some(('\u{1F600}', &[][..]))
+        );
+        assert_eq!(
+            unsafe { Utf16::decode_back_unchecked(&units[..2]) },
+            ('\u{1F600}', &[][..])
+        );
+        let s = U16Str::from_chars(&units).unwrap();
+        assert_eq!(format!("{}", s), "\u{1F600}A");
+    }
+
+    #[test]
+    fn test_decode_checks_low_surrogate() {
+        assert!(Utf16::decode_buf(&[0xD83D, 0xDE00]).is_some());
+        assert_eq!(Utf16::decode_buf(&[0xD83D, 0x41]), None);
+    }
+
+    #[test]
+    fn test_decode_bmp_rest() {
+        let units = [0x41, 0x42, 0x43];
+        assert_eq!(Utf16::decode_buf(&units), Some(('A', &units[1..])));
+    }
+
+    #[test]
+    fn test_decode_back_utf8() {
+        for c in ['a', '\u{e9}', '\u{20ac}', '\u{1F600}'] {
+            let mut buf = [0; 5];
+            buf[0] = b'x';
+            let len = 1 + c.encode_utf8(&mut buf[1..]).len();
+            assert_eq!(
+                Utf8::decode_back(&buf[..len]),
+                Some((c, &buf[..1])),
+                "{c:?}"
+            );
+            assert_eq!(
+                unsafe { Utf8::decode_back_unchecked(&buf[..len]) },
+                (c, &buf[..1]),
+                "{c:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_utf16_encoding_len() {
+        assert_eq!(Utf16::encoding_len('\u{FFFF}'), 1);
+        assert_eq!(Utf16::encoding_len('\u{10000}'), 2);
+    }
+
+    #[test]
+    fn test_u16str() {
+        const S: &U16Str = u16str!("a\u{e9}\u{1F600}");
+        let expected: Vec<u16> = "a\u{e9}\u{1F600}".encode_utf16().collect();
+        assert_eq!(S.as_chars(), &expected[..]);
+    }
+
+    #[test]
+    fn test_u32str() {
+        const S: &U32Str = u32str!("a\u{e9}\u{1F600}");
+        assert_eq!(S.as_chars(), &['a', '\u{e9}', '\u{1F600}']);
+    }
+
+    #[test]
+    fn test_cstr_macros() {
+        const S8: &Utf8CStr = u8cstr!("abc");
+        const S16: &Utf16CStr = u16cstr!("\u{1F600}");
+        assert_eq!(S8.as_chars(), b"abc\0");
+        assert_eq!(S16.as_chars(), &[0xD83D, 0xDE00, 0]);
+    }
+
+    #[test]
+    fn test_array_str_macros() {
+        const S16: U16ArrayString<2> = const_u16_array_str!("hi");
+        const S32: U32ArrayString<1> = const_u32_array_str!("\u{1F600}");
+        assert_eq!(S16.as_chars(), &[b'h' as u16, b'i' as u16]);
+        assert_eq!(S32.as_chars(), &['\u{1F600}']);
+    }
+
+    #[test]
+    fn test_u16_validation_error() {
+        use super::UtfCharTraits;
+        use crate::traits::{CharTraits, ValidationError};
+
+        let error = |buf: &[u16]| {
+            let err = UtfCharTraits::<u16>::validate_range(buf).unwrap_err();
+            (err.first_error_pos(), err.first_error_len())
+        };
+        // An unpaired high surrogate is invalid by itself, and the unit after it begins the next character
+        assert_eq!(error(&[0x61, 0xD800, 0x62]), (1, Some(1)));
+        assert_eq!(error(&[0xD800, 0xD800, 0xDC00]), (0, Some(1)));
+        assert_eq!(error(&[0x61, 0xDC00]), (1, Some(1)));
+        assert_eq!(error(&[0x61, 0xD800]), (1, None));
+    }
+}
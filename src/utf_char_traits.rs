@@ -18,20 +18,25 @@ impl CharTraits for UtfCharTraits<u8> {
         } else if buf.len() == 1 {
             Ok(())
         } else {
-            for (i, &c) in buf.iter().rev().enumerate() {
-                if c & 0xc0 == 0x80 {
-                    continue;
-                } else if ((c & 0x80 == 0x00) && i == 0)
-                    || ((c & 0xe0 == 0xc0) && i == 1)
-                    || (i == 2)
-                {
-                    return Ok(());
-                }
+            // The last character must be complete: find its lead byte, and check the length it encodes
+            let (i, &c) = buf
+                .iter()
+                .rev()
+                .enumerate()
+                .find(|&(_, &c)| c & 0xc0 != 0x80)
+                .unwrap();
+            let len = match c.leading_ones() {
+                0 => 1,
+                n => n as usize,
+            };
+            if len == i + 1 {
+                Ok(())
+            } else {
+                Err(UtfError {
+                    pos: buf.len() - 1 - i,
+                    len: None,
+                })
             }
-            Err(UtfError {
-                pos: buf.len(),
-                len: None,
-            })
         }
     }
 
@@ -50,6 +55,13 @@ impl CharTraits for UtfCharTraits<u8> {
     fn is_zero_term(c: Self::Char) -> bool {
         c == 0
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        match buf.get(idx) {
+            Some(&b) => b & 0xc0 != 0x80,
+            None => idx == buf.len(),
+        }
+    }
 }
 
 impl CharTraits for UtfCharTraits<u16> {
@@ -66,9 +78,10 @@ impl CharTraits for UtfCharTraits<u16> {
             if (0xD800..=0xDBFF).contains(&c) {
                 let (_, &c) = iter.next().ok_or(UtfError { pos: i, len: None })?;
                 if !(0xDC00..=0xDFFF).contains(&c) {
+                    // Only the unpaired high surrogate is invalid
                     return Err(UtfError {
                         pos: i,
-                        len: Some(2),
+                        len: Some(1),
                     });
                 }
             } else if (0xDC00..=0xDFFF).contains(&c) {
@@ -113,6 +126,13 @@ impl CharTraits for UtfCharTraits<u16> {
     fn is_zero_term(c: Self::Char) -> bool {
         c == 0
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        match buf.get(idx) {
+            Some(&c) => !(0xDC00..=0xDFFF).contains(&c),
+            None => idx == buf.len(),
+        }
+    }
 }
 
 impl CharTraits for UtfCharTraits<char> {
@@ -145,4 +165,8 @@ impl CharTraits for UtfCharTraits<char> {
     fn is_zero_term(c: Self::Char) -> bool {
         c == '\0'
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        idx <= buf.len()
+    }
 }
@@ -113,37 +113,21 @@ impl const CharTraits for UtfCharTraits<u8> {
         } else if buf.len() == 1 {
             Ok(())
         } else {
-            // for (i, &c) in buf.iter().rev().enumerate() {
-            //     if c & 0xc0 == 0x80 {
-            //         continue;
-            //     } else if ((c & 0x80 == 0x00) && i == 0)
-            //         || ((c & 0xe0 == 0xc0) && i == 1)
-            //         || (i == 2)
-            //     {
-            //         return Ok(());
-            //     }
-            // }
-            let mut i = 0;
+            // The last character must be complete: find its lead byte, and check the length it encodes
             let mut l = buf.len() - 1;
-            while i < 3 {
-                if buf[l] & 0xc0 == 0x80 {
-                    continue;
-                } else if ((buf[l] & 0x80 == 0x00) && i == 0)
-                    || ((buf[l] & 0xe0 == 0xc0) && i == 1)
-                    || (i == 2)
-                {
-                    return Ok(());
-                }
-                if l == 0 {
-                    break;
-                }
-                i += 1;
+            while buf[l] & 0xc0 == 0x80 {
                 l -= 1;
             }
-            Err(UtfError {
-                pos: buf.len(),
-                len: None,
-            })
+            let len = if buf[l] & 0x80 == 0x00 {
+                1
+            } else {
+                buf[l].leading_ones() as usize
+            };
+            if len == buf.len() - l {
+                Ok(())
+            } else {
+                Err(UtfError { pos: l, len: None })
+            }
         }
     }
 
@@ -180,6 +164,13 @@ impl const CharTraits for UtfCharTraits<u8> {
     fn is_zero_term(c: Self::Char) -> bool {
         c == 0
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        match buf.get(idx) {
+            Some(&b) => b & 0xc0 != 0x80,
+            None => idx == buf.len(),
+        }
+    }
 }
 
 impl const CharTraits for UtfCharTraits<u16> {
@@ -267,6 +258,13 @@ impl const CharTraits for UtfCharTraits<u16> {
     fn is_zero_term(c: Self::Char) -> bool {
         c == 0
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        match buf.get(idx) {
+            Some(&c) => !(0xDC00..=0xDFFF).contains(&c),
+            None => idx == buf.len(),
+        }
+    }
 }
 
 impl const CharTraits for UtfCharTraits<char> {
@@ -317,4 +315,8 @@ impl const CharTraits for UtfCharTraits<char> {
     fn is_zero_term(c: Self::Char) -> bool {
         c == '\0'
     }
+
+    fn is_char_boundary(buf: &[Self::Char], idx: usize) -> bool {
+        idx <= buf.len()
+    }
 }
@@ -36,6 +36,11 @@ impl<'a, CharT, Traits> BasicStringView<'a, CharT, Traits> {
         unsafe { Self::from_raw_parts(dangling, dangling) }
     }
 
+    /// Constructs a [`BasicStringView`] from a pair of pointers to the beginning and end of the range
+    ///
+    /// # Safety
+    /// `[begin, end)` shall be a range which is valid and not modified for the duration of `'a`,
+    /// and the characters in that range shall be valid according to [`CharTraits::validate_range`](crate::traits::CharTraits::validate_range)
     pub const unsafe fn from_raw_parts(begin: *const CharT, end: *const CharT) -> Self {
         Self {
             begin,
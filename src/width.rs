@@ -0,0 +1,253 @@
+//! East Asian Width (UAX #11) and display width of characters and strings
+//!
+//! The tables are generated by `tools/gen_width_tables.py`.
+
+use core::cmp::Ordering;
+
+use crate::segment::is_extended_pictographic;
+use crate::str::BasicStr;
+use crate::traits::IntoChars;
+
+#[rustfmt::skip]
+mod tables;
+
+/// The East_Asian_Width property of a character
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth {
+    /// Characters that are wide in East Asian contexts, and narrow otherwise
+    Ambiguous,
+    /// Fullwidth compatibility characters
+    Fullwidth,
+    /// Halfwidth compatibility characters
+    Halfwidth,
+    /// Characters that do not occur in East Asian text
+    Neutral,
+    /// Narrow characters with a fullwidth counterpart
+    Narrow,
+    /// Characters that are always wide
+    Wide,
+}
+
+impl EastAsianWidth {
+    /// Returns `true` for [`EastAsianWidth::Wide`] and [`EastAsianWidth::Fullwidth`], which occupy two columns
+    pub fn is_wide(self) -> bool {
+        matches!(self, Self::Wide | Self::Fullwidth)
+    }
+}
+
+fn range_cmp(lo: u32, hi: u32, c: u32) -> Ordering {
+    if hi < c {
+        Ordering::Less
+    } else if lo > c {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Returns the East_Asian_Width of `c`
+pub fn east_asian_width(c: char) -> EastAsianWidth {
+    tables::EAST_ASIAN_WIDTH
+        .binary_search_by(|&(lo, hi, _)| range_cmp(lo, hi, c as u32))
+        .map_or(EastAsianWidth::Neutral, |i| tables::EAST_ASIAN_WIDTH[i].2)
+}
+
+fn is_zero_width(c: char) -> bool {
+    tables::ZERO_WIDTH
+        .binary_search_by(|&(lo, hi)| range_cmp(lo, hi, c as u32))
+        .is_ok()
+}
+
+/// Returns the number of columns `c` occupies in a monospace terminal, on its own.
+///
+/// Wide and Fullwidth characters occupy two columns, and combining marks, format and control characters,
+///  and Hangul medial vowels and final consonants occupy none.
+/// Ambiguous characters are treated as narrow.
+///
+/// The width of a string may differ from the sum of the widths of its characters, see [`BasicStr::display_width`].
+pub fn display_width(c: char) -> usize {
+    if is_zero_width(c) {
+        0
+    } else if east_asian_width(c).is_wide() {
+        2
+    } else {
+        1
+    }
+}
+
+fn is_emoji_base(c: char) -> bool {
+    is_extended_pictographic(c) || matches!(c, '#' | '*' | '0'..='9')
+}
+
+/// The display width of a grapheme cluster, taking emoji presentation and zwj sequences into account
+fn grapheme_width<I: Iterator<Item = char>>(chars: I) -> usize {
+    let mut width = 0;
+    let mut prev = None;
+    let mut prev_width = 0;
+    let mut emoji = false;
+    for c in chars {
+        let w = match (prev, c) {
+            // An emoji presentation selector makes a narrow emoji wide
+            (Some(p), '\u{fe0f}') if is_emoji_base(p) && prev_width != 0 => 2 - prev_width,
+            // A text presentation selector makes a wide emoji narrow
+            (Some(p), '\u{fe0e}') if is_extended_pictographic(p) && prev_width == 2 => {
+                width -= 1;
+                0
+            }
+            // Emoji joined to or modifying a preceding emoji are drawn together with it
+            (Some('\u{200d}'), c) if emoji && is_extended_pictographic(c) => 0,
+            (_, '\u{1f3fb}'..='\u{1f3ff}') if emoji => 0,
+            (_, c) => display_width(c),
+        };
+        emoji |= is_extended_pictographic(c);
+        width += w;
+        prev = Some(c);
+        prev_width = w;
+    }
+    width
+}
+
+impl<Traits: IntoChars> BasicStr<Traits::Char, Traits> {
+    /// Returns the number of columns the string occupies in a monospace terminal.
+    ///
+    /// Each grapheme cluster has the width of its characters, as given by [`display_width`], except that
+    ///  emoji presentation (U+FE0F) and text presentation (U+FE0E) selectors widen or narrow the emoji they follow,
+    ///  and emoji joined by a ZERO WIDTH JOINER or followed by an emoji modifier are drawn as one emoji.
+    pub fn display_width(&self) -> usize {
+        self.graphemes()
+            .map(|g| grapheme_width(g.unicode_iter()))
+            .sum()
+    }
+
+    /// Returns the longest prefix of the string that fits in `width` columns, after which `ellipsis` may be appended.
+    ///
+    /// If the whole string fits in `width` columns, it is returned unchanged.
+    /// Otherwise the string is cut at a grapheme cluster boundary, such that the prefix followed by `ellipsis` fits in `width` columns,
+    ///  or the prefix is empty if `ellipsis` is wider than `width`.
+    pub fn truncate_to_width<Other: IntoChars>(
+        &self,
+        width: usize,
+        ellipsis: &BasicStr<Other::Char, Other>,
+    ) -> &Self {
+        let limit = width.saturating_sub(ellipsis.display_width());
+        let mut used = 0;
+        let mut end = None;
+        for (offset, g) in self.grapheme_indices() {
+            used += grapheme_width(g.unicode_iter());
+            if used > limit && end.is_none() {
+                end = Some(offset);
+            }
+            if used > width {
+                // SAFETY:
+                // `end` is the offset of a grapheme cluster, and therefore a character boundary
+                return unsafe {
+                    Self::from_chars_unchecked(&self.as_chars()[..end.unwrap_or(offset)])
+                };
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{display_width, east_asian_width, EastAsianWidth};
+
+    #[test]
+    fn test_east_asian_width() {
+        assert_eq!(east_asian_width('a'), EastAsianWidth::Narrow);
+        assert_eq!(east_asian_width('\u{e9}'), EastAsianWidth::Ambiguous);
+        assert_eq!(east_asian_width('\u{3042}'), EastAsianWidth::Wide);
+        assert_eq!(east_asian_width('\u{ff21}'), EastAsianWidth::Fullwidth);
+        assert_eq!(east_asian_width('\u{ff71}'), EastAsianWidth::Halfwidth);
+        assert_eq!(east_asian_width('\u{5d0}'), EastAsianWidth::Neutral);
+        // Unassigned code points in the CJK ideograph blocks default to Wide
+        assert_eq!(east_asian_width('\u{2fffd}'), EastAsianWidth::Wide);
+        assert!(east_asian_width('\u{4e00}').is_wide());
+    }
+
+    #[test]
+    fn test_char_display_width() {
+        assert_eq!(display_width('a'), 1);
+        assert_eq!(display_width('\u{e9}'), 1);
+        assert_eq!(display_width('\u{4e00}'), 2);
+        assert_eq!(display_width('\u{ff21}'), 2);
+        assert_eq!(display_width('\u{ff71}'), 1);
+        assert_eq!(display_width('\u{301}'), 0);
+        assert_eq!(display_width('\u{200d}'), 0);
+        assert_eq!(display_width('\u{1161}'), 0);
+        assert_eq!(display_width('\n'), 0);
+        assert_eq!(display_width('\u{ad}'), 1);
+        assert_eq!(display_width('\u{1f600}'), 2);
+        assert_eq!(display_width('\u{2764}'), 1);
+        // Emoji added in Unicode 15.0 are wide as well as pictographic
+        assert_eq!(display_width('\u{1fae8}'), 2);
+    }
+
+    #[cfg(feature = "utf")]
+    #[test]
+    fn test_str_display_width() {
+        use crate::str::{Str, U16Str};
+        let width = |s: &str| Str::from_str(s).display_width();
+
+        assert_eq!(width(""), 0);
+        assert_eq!(width("hello"), 5);
+        assert_eq!(width("\u{65e5}\u{672c}\u{8a9e} text"), 11);
+        assert_eq!(width("e\u{301}"), 1);
+        // Hangul conjoining jamo form one syllable
+        assert_eq!(width("\u{1100}\u{1161}\u{11a8}"), 2);
+        // Presentation selectors
+        assert_eq!(width("\u{2764}\u{fe0f}"), 2);
+        assert_eq!(width("\u{1f600}\u{fe0e}"), 1);
+        assert_eq!(width("1\u{fe0f}\u{20e3}"), 2);
+        assert_eq!(width("a\u{fe0f}"), 1);
+        // Modifiers, zwj sequences and flags
+        assert_eq!(width("\u{1f44d}\u{1f3fd}"), 2);
+        assert_eq!(width("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"), 2);
+        assert_eq!(width("\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}"), 2);
+        assert_eq!(width("\u{1f1ef}\u{1f1f5}"), 2);
+        // A zero width joiner between other characters joins nothing
+        assert_eq!(width("a\u{200d}\u{1f600}"), 3);
+
+        let utf16: Vec<u16> = "\u{1f468}\u{200d}\u{1f469} \u{4e00}"
+            .encode_utf16()
+            .collect();
+        assert_eq!(U16Str::from_chars(&utf16).unwrap().display_width(), 5);
+    }
+
+    #[cfg(feature = "utf")]
+    #[test]
+    fn test_truncate_to_width() {
+        use crate::str::{Str, U16Str};
+        fn truncate(s: &str, w: usize) -> &str {
+            Str::from_str(s)
+                .truncate_to_width(w, Str::from_str("\u{2026}"))
+                .as_str()
+        }
+
+        assert_eq!(truncate("hello", 5), "hello");
+        assert_eq!(truncate("hello world", 8), "hello w");
+        assert_eq!(truncate("hello world", 0), "");
+        assert_eq!(truncate("", 0), "");
+        // Never cuts within a grapheme cluster or a wide character
+        assert_eq!(truncate("cafe\u{301} au lait", 5), "cafe\u{301}");
+        assert_eq!(truncate("\u{65e5}\u{672c}\u{8a9e}", 5), "\u{65e5}\u{672c}");
+        assert_eq!(truncate("\u{65e5}\u{672c}\u{8a9e}", 4), "\u{65e5}");
+        assert_eq!(
+            truncate("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}abc", 4),
+            "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}a"
+        );
+        assert_eq!(
+            Str::from_str("abc")
+                .truncate_to_width(2, Str::from_str("..."))
+                .as_str(),
+            ""
+        );
+
+        let utf16: Vec<u16> = "\u{4e00}\u{4e01}\u{4e02}".encode_utf16().collect();
+        let s = U16Str::from_chars(&utf16).unwrap();
+        let ellipsis = Str::from_str("\u{2026}");
+        assert_eq!(s.truncate_to_width(5, ellipsis).as_chars(), &utf16[..2]);
+        assert_eq!(s.truncate_to_width(6, ellipsis).as_chars(), &utf16[..]);
+    }
+}
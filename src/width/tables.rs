@@ -0,0 +1,684 @@
+// Generated by tools/gen_width_tables.py. Do not edit.
+
+// Unicode Character Database version 15.0.0
+
+use super::EastAsianWidth;
+
+/// Ranges of characters with an East_Asian_Width other than Neutral, and their width
+pub(super) static EAST_ASIAN_WIDTH: [(u32, u32, EastAsianWidth); 315] = [
+    (0x0020, 0x007E, EastAsianWidth::Narrow),
+    (0x00A1, 0x00A1, EastAsianWidth::Ambiguous),
+    (0x00A2, 0x00A3, EastAsianWidth::Narrow),
+    (0x00A4, 0x00A4, EastAsianWidth::Ambiguous),
+    (0x00A5, 0x00A6, EastAsianWidth::Narrow),
+    (0x00A7, 0x00A8, EastAsianWidth::Ambiguous),
+    (0x00AA, 0x00AA, EastAsianWidth::Ambiguous),
+    (0x00AC, 0x00AC, EastAsianWidth::Narrow),
+    (0x00AD, 0x00AE, EastAsianWidth::Ambiguous),
+    (0x00AF, 0x00AF, EastAsianWidth::Narrow),
+    (0x00B0, 0x00B4, EastAsianWidth::Ambiguous),
+    (0x00B6, 0x00BA, EastAsianWidth::Ambiguous),
+    (0x00BC, 0x00BF, EastAsianWidth::Ambiguous),
+    (0x00C6, 0x00C6, EastAsianWidth::Ambiguous),
+    (0x00D0, 0x00D0, EastAsianWidth::Ambiguous),
+    (0x00D7, 0x00D8, EastAsianWidth::Ambiguous),
+    (0x00DE, 0x00E1, EastAsianWidth::Ambiguous),
+    (0x00E6, 0x00E6, EastAsianWidth::Ambiguous),
+    (0x00E8, 0x00EA, EastAsianWidth::Ambiguous),
+    (0x00EC, 0x00ED, EastAsianWidth::Ambiguous),
+    (0x00F0, 0x00F0, EastAsianWidth::Ambiguous),
+    (0x00F2, 0x00F3, EastAsianWidth::Ambiguous),
+    (0x00F7, 0x00FA, EastAsianWidth::Ambiguous),
+    (0x00FC, 0x00FC, EastAsianWidth::Ambiguous),
+    (0x00FE, 0x00FE, EastAsianWidth::Ambiguous),
+    (0x0101, 0x0101, EastAsianWidth::Ambiguous),
+    (0x0111, 0x0111, EastAsianWidth::Ambiguous),
+    (0x0113, 0x0113, EastAsianWidth::Ambiguous),
+    (0x011B, 0x011B, EastAsianWidth::Ambiguous),
+    (0x0126, 0x0127, EastAsianWidth::Ambiguous),
+    (0x012B, 0x012B, EastAsianWidth::Ambiguous),
+    (0x0131, 0x0133, EastAsianWidth::Ambiguous),
+    (0x0138, 0x0138, EastAsianWidth::Ambiguous),
+    (0x013F, 0x0142, EastAsianWidth::Ambiguous),
+    (0x0144, 0x0144, EastAsianWidth::Ambiguous),
+    (0x0148, 0x014B, EastAsianWidth::Ambiguous),
+    (0x014D, 0x014D, EastAsianWidth::Ambiguous),
+    (0x0152, 0x0153, EastAsianWidth::Ambiguous),
+    (0x0166, 0x0167, EastAsianWidth::Ambiguous),
+    (0x016B, 0x016B, EastAsianWidth::Ambiguous),
+    (0x01CE, 0x01CE, EastAsianWidth::Ambiguous),
+    (0x01D0, 0x01D0, EastAsianWidth::Ambiguous),
+    (0x01D2, 0x01D2, EastAsianWidth::Ambiguous),
+    (0x01D4, 0x01D4, EastAsianWidth::Ambiguous),
+    (0x01D6, 0x01D6, EastAsianWidth::Ambiguous),
+    (0x01D8, 0x01D8, EastAsianWidth::Ambiguous),
+    (0x01DA, 0x01DA, EastAsianWidth::Ambiguous),
+    (0x01DC, 0x01DC, EastAsianWidth::Ambiguous),
+    (0x0251, 0x0251, EastAsianWidth::Ambiguous),
+    (0x0261, 0x0261, EastAsianWidth::Ambiguous),
+    (0x02C4, 0x02C4, EastAsianWidth::Ambiguous),
+    (0x02C7, 0x02C7, EastAsianWidth::Ambiguous),
+    (0x02C9, 0x02CB, EastAsianWidth::Ambiguous),
+    (0x02CD, 0x02CD, EastAsianWidth::Ambiguous),
+    (0x02D0, 0x02D0, EastAsianWidth::Ambiguous),
+    (0x02D8, 0x02DB, EastAsianWidth::Ambiguous),
+    (0x02DD, 0x02DD, EastAsianWidth::Ambiguous),
+    (0x02DF, 0x02DF, EastAsianWidth::Ambiguous),
+    (0x0300, 0x036F, EastAsianWidth::Ambiguous),
+    (0x0391, 0x03A1, EastAsianWidth::Ambiguous),
+    (0x03A3, 0x03A9, EastAsianWidth::Ambiguous),
+    (0x03B1, 0x03C1, EastAsianWidth::Ambiguous),
+    (0x03C3, 0x03C9, EastAsianWidth::Ambiguous),
+    (0x0401, 0x0401, EastAsianWidth::Ambiguous),
+    (0x0410, 0x044F, EastAsianWidth::Ambiguous),
+    (0x0451, 0x0451, EastAsianWidth::Ambiguous),
+    (0x1100, 0x115F, EastAsianWidth::Wide),
+    (0x2010, 0x2010, EastAsianWidth::Ambiguous),
+    (0x2013, 0x2016, EastAsianWidth::Ambiguous),
+    (0x2018, 0x2019, EastAsianWidth::Ambiguous),
+    (0x201C, 0x201D, EastAsianWidth::Ambiguous),
+    (0x2020, 0x2022, EastAsianWidth::Ambiguous),
+    (0x2024, 0x2027, EastAsianWidth::Ambiguous),
+    (0x2030, 0x2030, EastAsianWidth::Ambiguous),
+    (0x2032, 0x2033, EastAsianWidth::Ambiguous),
+    (0x2035, 0x2035, EastAsianWidth::Ambiguous),
+    (0x203B, 0x203B, EastAsianWidth::Ambiguous),
+    (0x203E, 0x203E, EastAsianWidth::Ambiguous),
+    (0x2074, 0x2074, EastAsianWidth::Ambiguous),
+    (0x207F, 0x207F, EastAsianWidth::Ambiguous),
+    (0x2081, 0x2084, EastAsianWidth::Ambiguous),
+    (0x20A9, 0x20A9, EastAsianWidth::Halfwidth),
+    (0x20AC, 0x20AC, EastAsianWidth::Ambiguous),
+    (0x2103, 0x2103, EastAsianWidth::Ambiguous),
+    (0x2105, 0x2105, EastAsianWidth::Ambiguous),
+    (0x2109, 0x2109, EastAsianWidth::Ambiguous),
+    (0x2113, 0x2113, EastAsianWidth::Ambiguous),
+    (0x2116, 0x2116, EastAsianWidth::Ambiguous),
+    (0x2121, 0x2122, EastAsianWidth::Ambiguous),
+    (0x2126, 0x2126, EastAsianWidth::Ambiguous),
+    (0x212B, 0x212B, EastAsianWidth::Ambiguous),
+    (0x2153, 0x2154, EastAsianWidth::Ambiguous),
+    (0x215B, 0x215E, EastAsianWidth::Ambiguous),
+    (0x2160, 0x216B, EastAsianWidth::Ambiguous),
+    (0x2170, 0x2179, EastAsianWidth::Ambiguous),
+    (0x2189, 0x2189, EastAsianWidth::Ambiguous),
+    (0x2190, 0x2199, EastAsianWidth::Ambiguous),
+    (0x21B8, 0x21B9, EastAsianWidth::Ambiguous),
+    (0x21D2, 0x21D2, EastAsianWidth::Ambiguous),
+    (0x21D4, 0x21D4, EastAsianWidth::Ambiguous),
+    (0x21E7, 0x21E7, EastAsianWidth::Ambiguous),
+    (0x2200, 0x2200, EastAsianWidth::Ambiguous),
+    (0x2202, 0x2203, EastAsianWidth::Ambiguous),
+    (0x2207, 0x2208, EastAsianWidth::Ambiguous),
+    (0x220B, 0x220B, EastAsianWidth::Ambiguous),
+    (0x220F, 0x220F, EastAsianWidth::Ambiguous),
+    (0x2211, 0x2211, EastAsianWidth::Ambiguous),
+    (0x2215, 0x2215, EastAsianWidth::Ambiguous),
+    (0x221A, 0x221A, EastAsianWidth::Ambiguous),
+    (0x221D, 0x2220, EastAsianWidth::Ambiguous),
+    (0x2223, 0x2223, EastAsianWidth::Ambiguous),
+    (0x2225, 0x2225, EastAsianWidth::Ambiguous),
+    (0x2227, 0x222C, EastAsianWidth::Ambiguous),
+    (0x222E, 0x222E, EastAsianWidth::Ambiguous),
+    (0x2234, 0x2237, EastAsianWidth::Ambiguous),
+    (0x223C, 0x223D, EastAsianWidth::Ambiguous),
+    (0x2248, 0x2248, EastAsianWidth::Ambiguous),
+    (0x224C, 0x224C, EastAsianWidth::Ambiguous),
+    (0x2252, 0x2252, EastAsianWidth::Ambiguous),
+    (0x2260, 0x2261, EastAsianWidth::Ambiguous),
+    (0x2264, 0x2267, EastAsianWidth::Ambiguous),
+    (0x226A, 0x226B, EastAsianWidth::Ambiguous),
+    (0x226E, 0x226F, EastAsianWidth::Ambiguous),
+    (0x2282, 0x2283, EastAsianWidth::Ambiguous),
+    (0x2286, 0x2287, EastAsianWidth::Ambiguous),
+    (0x2295, 0x2295, EastAsianWidth::Ambiguous),
+    (0x2299, 0x2299, EastAsianWidth::Ambiguous),
+    (0x22A5, 0x22A5, EastAsianWidth::Ambiguous),
+    (0x22BF, 0x22BF, EastAsianWidth::Ambiguous),
+    (0x2312, 0x2312, EastAsianWidth::Ambiguous),
+    (0x231A, 0x231B, EastAsianWidth::Wide),
+    (0x2329, 0x232A, EastAsianWidth::Wide),
+    (0x23E9, 0x23EC, EastAsianWidth::Wide),
+    (0x23F0, 0x23F0, EastAsianWidth::Wide),
+    (0x23F3, 0x23F3, EastAsianWidth::Wide),
+    (0x2460, 0x24E9, EastAsianWidth::Ambiguous),
+    (0x24EB, 0x254B, EastAsianWidth::Ambiguous),
+    (0x2550, 0x2573, EastAsianWidth::Ambiguous),
+    (0x2580, 0x258F, EastAsianWidth::Ambiguous),
+    (0x2592, 0x2595, EastAsianWidth::Ambiguous),
+    (0x25A0, 0x25A1, EastAsianWidth::Ambiguous),
+    (0x25A3, 0x25A9, EastAsianWidth::Ambiguous),
+    (0x25B2, 0x25B3, EastAsianWidth::Ambiguous),
+    (0x25B6, 0x25B7, EastAsianWidth::Ambiguous),
+    (0x25BC, 0x25BD, EastAsianWidth::Ambiguous),
+    (0x25C0, 0x25C1, EastAsianWidth::Ambiguous),
+    (0x25C6, 0x25C8, EastAsianWidth::Ambiguous),
+    (0x25CB, 0x25CB, EastAsianWidth::Ambiguous),
+    (0x25CE, 0x25D1, EastAsianWidth::Ambiguous),
+    (0x25E2, 0x25E5, EastAsianWidth::Ambiguous),
+    (0x25EF, 0x25EF, EastAsianWidth::Ambiguous),
+    (0x25FD, 0x25FE, EastAsianWidth::Wide),
+    (0x2605, 0x2606, EastAsianWidth::Ambiguous),
+    (0x2609, 0x2609, EastAsianWidth::Ambiguous),
+    (0x260E, 0x260F, EastAsianWidth::Ambiguous),
+    (0x2614, 0x2615, EastAsianWidth::Wide),
+    (0x261C, 0x261C, EastAsianWidth::Ambiguous),
+    (0x261E, 0x261E, EastAsianWidth::Ambiguous),
+    (0x2640, 0x2640, EastAsianWidth::Ambiguous),
+    (0x2642, 0x2642, EastAsianWidth::Ambiguous),
+    (0x2648, 0x2653, EastAsianWidth::Wide),
+    (0x2660, 0x2661, EastAsianWidth::Ambiguous),
+    (0x2663, 0x2665, EastAsianWidth::Ambiguous),
+    (0x2667, 0x266A, EastAsianWidth::Ambiguous),
+    (0x266C, 0x266D, EastAsianWidth::Ambiguous),
+    (0x266F, 0x266F, EastAsianWidth::Ambiguous),
+    (0x267F, 0x267F, EastAsianWidth::Wide),
+    (0x2693, 0x2693, EastAsianWidth::Wide),
+    (0x269E, 0x269F, EastAsianWidth::Ambiguous),
+    (0x26A1, 0x26A1, EastAsianWidth::Wide),
+    (0x26AA, 0x26AB, EastAsianWidth::Wide),
+    (0x26BD, 0x26BE, EastAsianWidth::Wide),
+    (0x26BF, 0x26BF, EastAsianWidth::Ambiguous),
+    (0x26C4, 0x26C5, EastAsianWidth::Wide),
+    (0x26C6, 0x26CD, EastAsianWidth::Ambiguous),
+    (0x26CE, 0x26CE, EastAsianWidth::Wide),
+    (0x26CF, 0x26D3, EastAsianWidth::Ambiguous),
+    (0x26D4, 0x26D4, EastAsianWidth::Wide),
+    (0x26D5, 0x26E1, EastAsianWidth::Ambiguous),
+    (0x26E3, 0x26E3, EastAsianWidth::Ambiguous),
+    (0x26E8, 0x26E9, EastAsianWidth::Ambiguous),
+    (0x26EA, 0x26EA, EastAsianWidth::Wide),
+    (0x26EB, 0x26F1, EastAsianWidth::Ambiguous),
+    (0x26F2, 0x26F3, EastAsianWidth::Wide),
+    (0x26F4, 0x26F4, EastAsianWidth::Ambiguous),
+    (0x26F5, 0x26F5, EastAsianWidth::Wide),
+    (0x26F6, 0x26F9, EastAsianWidth::Ambiguous),
+    (0x26FA, 0x26FA, EastAsianWidth::Wide),
+    (0x26FB, 0x26FC, EastAsianWidth::Ambiguous),
+    (0x26FD, 0x26FD, EastAsianWidth::Wide),
+    (0x26FE, 0x26FF, EastAsianWidth::Ambiguous),
+    (0x2705, 0x2705, EastAsianWidth::Wide),
+    (0x270A, 0x270B, EastAsianWidth::Wide),
+    (0x2728, 0x2728, EastAsianWidth::Wide),
+    (0x273D, 0x273D, EastAsianWidth::Ambiguous),
+    (0x274C, 0x274C, EastAsianWidth::Wide),
+    (0x274E, 0x274E, EastAsianWidth::Wide),
+    (0x2753, 0x2755, EastAsianWidth::Wide),
+    (0x2757, 0x2757, EastAsianWidth::Wide),
+    (0x2776, 0x277F, EastAsianWidth::Ambiguous),
+    (0x2795, 0x2797, EastAsianWidth::Wide),
+    (0x27B0, 0x27B0, EastAsianWidth::Wide),
+    (0x27BF, 0x27BF, EastAsianWidth::Wide),
+    (0x27E6, 0x27ED, EastAsianWidth::Narrow),
+    (0x2985, 0x2986, EastAsianWidth::Narrow),
+    (0x2B1B, 0x2B1C, EastAsianWidth::Wide),
+    (0x2B50, 0x2B50, EastAsianWidth::Wide),
+    (0x2B55, 0x2B55, EastAsianWidth::Wide),
+    (0x2B56, 0x2B59, EastAsianWidth::Ambiguous),
+    (0x2E80, 0x2E99, EastAsianWidth::Wide),
+    (0x2E9B, 0x2EF3, EastAsianWidth::Wide),
+    (0x2F00, 0x2FD5, EastAsianWidth::Wide),
+    (0x2FF0, 0x2FFB, EastAsianWidth::Wide),
+    (0x3000, 0x3000, EastAsianWidth::Fullwidth),
+    (0x3001, 0x303E, EastAsianWidth::Wide),
+    (0x3041, 0x3096, EastAsianWidth::Wide),
+    (0x3099, 0x30FF, EastAsianWidth::Wide),
+    (0x3105, 0x312F, EastAsianWidth::Wide),
+    (0x3131, 0x318E, EastAsianWidth::Wide),
+    (0x3190, 0x31E3, EastAsianWidth::Wide),
+    (0x31F0, 0x321E, EastAsianWidth::Wide),
+    (0x3220, 0x3247, EastAsianWidth::Wide),
+    (0x3248, 0x324F, EastAsianWidth::Ambiguous),
+    (0x3250, 0x4DBF, EastAsianWidth::Wide),
+    (0x4E00, 0xA48C, EastAsianWidth::Wide),
+    (0xA490, 0xA4C6, EastAsianWidth::Wide),
+    (0xA960, 0xA97C, EastAsianWidth::Wide),
+    (0xAC00, 0xD7A3, EastAsianWidth::Wide),
+    (0xE000, 0xF8FF, EastAsianWidth::Ambiguous),
+    (0xF900, 0xFAFF, EastAsianWidth::Wide),
+    (0xFE00, 0xFE0F, EastAsianWidth::Ambiguous),
+    (0xFE10, 0xFE19, EastAsianWidth::Wide),
+    (0xFE30, 0xFE52, EastAsianWidth::Wide),
+    (0xFE54, 0xFE66, EastAsianWidth::Wide),
+    (0xFE68, 0xFE6B, EastAsianWidth::Wide),
+    (0xFF01, 0xFF60, EastAsianWidth::Fullwidth),
+    (0xFF61, 0xFFBE, EastAsianWidth::Halfwidth),
+    (0xFFC2, 0xFFC7, EastAsianWidth::Halfwidth),
+    (0xFFCA, 0xFFCF, EastAsianWidth::Halfwidth),
+    (0xFFD2, 0xFFD7, EastAsianWidth::Halfwidth),
+    (0xFFDA, 0xFFDC, EastAsianWidth::Halfwidth),
+    (0xFFE0, 0xFFE6, EastAsianWidth::Fullwidth),
+    (0xFFE8, 0xFFEE, EastAsianWidth::Halfwidth),
+    (0xFFFD, 0xFFFD, EastAsianWidth::Ambiguous),
+    (0x16FE0, 0x16FE4, EastAsianWidth::Wide),
+    (0x16FF0, 0x16FF1, EastAsianWidth::Wide),
+    (0x17000, 0x187F7, EastAsianWidth::Wide),
+    (0x18800, 0x18CD5, EastAsianWidth::Wide),
+    (0x18D00, 0x18D08, EastAsianWidth::Wide),
+    (0x1AFF0, 0x1AFF3, EastAsianWidth::Wide),
+    (0x1AFF5, 0x1AFFB, EastAsianWidth::Wide),
+    (0x1AFFD, 0x1AFFE, EastAsianWidth::Wide),
+    (0x1B000, 0x1B122, EastAsianWidth::Wide),
+    (0x1B132, 0x1B132, EastAsianWidth::Wide),
+    (0x1B150, 0x1B152, EastAsianWidth::Wide),
+    (0x1B155, 0x1B155, EastAsianWidth::Wide),
+    (0x1B164, 0x1B167, EastAsianWidth::Wide),
+    (0x1B170, 0x1B2FB, EastAsianWidth::Wide),
+    (0x1F004, 0x1F004, EastAsianWidth::Wide),
+    (0x1F0CF, 0x1F0CF, EastAsianWidth::Wide),
+    (0x1F100, 0x1F10A, EastAsianWidth::Ambiguous),
+    (0x1F110, 0x1F12D, EastAsianWidth::Ambiguous),
+    (0x1F130, 0x1F169, EastAsianWidth::Ambiguous),
+    (0x1F170, 0x1F18D, EastAsianWidth::Ambiguous),
+    (0x1F18E, 0x1F18E, EastAsianWidth::Wide),
+    (0x1F18F, 0x1F190, EastAsianWidth::Ambiguous),
+    (0x1F191, 0x1F19A, EastAsianWidth::Wide),
+    (0x1F19B, 0x1F1AC, EastAsianWidth::Ambiguous),
+    (0x1F200, 0x1F202, EastAsianWidth::Wide),
+    (0x1F210, 0x1F23B, EastAsianWidth::Wide),
+    (0x1F240, 0x1F248, EastAsianWidth::Wide),
+    (0x1F250, 0x1F251, EastAsianWidth::Wide),
+    (0x1F260, 0x1F265, EastAsianWidth::Wide),
+    (0x1F300, 0x1F320, EastAsianWidth::Wide),
+    (0x1F32D, 0x1F335, EastAsianWidth::Wide),
+    (0x1F337, 0x1F37C, EastAsianWidth::Wide),
+    (0x1F37E, 0x1F393, EastAsianWidth::Wide),
+    (0x1F3A0, 0x1F3CA, EastAsianWidth::Wide),
+    (0x1F3CF, 0x1F3D3, EastAsianWidth::Wide),
+    (0x1F3E0, 0x1F3F0, EastAsianWidth::Wide),
+    (0x1F3F4, 0x1F3F4, EastAsianWidth::Wide),
+    (0x1F3F8, 0x1F43E, EastAsianWidth::Wide),
+    (0x1F440, 0x1F440, EastAsianWidth::Wide),
+    (0x1F442, 0x1F4FC, EastAsianWidth::Wide),
+    (0x1F4FF, 0x1F53D, EastAsianWidth::Wide),
+    (0x1F54B, 0x1F54E, EastAsianWidth::Wide),
+    (0x1F550, 0x1F567, EastAsianWidth::Wide),
+    (0x1F57A, 0x1F57A, EastAsianWidth::Wide),
+    (0x1F595, 0x1F596, EastAsianWidth::Wide),
+    (0x1F5A4, 0x1F5A4, EastAsianWidth::Wide),
+    (0x1F5FB, 0x1F64F, EastAsianWidth::Wide),
+    (0x1F680, 0x1F6C5, EastAsianWidth::Wide),
+    (0x1F6CC, 0x1F6CC, EastAsianWidth::Wide),
+    (0x1F6D0, 0x1F6D2, EastAsianWidth::Wide),
+    (0x1F6D5, 0x1F6D7, EastAsianWidth::Wide),
+    (0x1F6DC, 0x1F6DF, EastAsianWidth::Wide),
+    (0x1F6EB, 0x1F6EC, EastAsianWidth::Wide),
+    (0x1F6F4, 0x1F6FC, EastAsianWidth::Wide),
+    (0x1F7E0, 0x1F7EB, EastAsianWidth::Wide),
+    (0x1F7F0, 0x1F7F0, EastAsianWidth::Wide),
+    (0x1F90C, 0x1F93A, EastAsianWidth::Wide),
+    (0x1F93C, 0x1F945, EastAsianWidth::Wide),
+    (0x1F947, 0x1F9FF, EastAsianWidth::Wide),
+    (0x1FA70, 0x1FA7C, EastAsianWidth::Wide),
+    (0x1FA80, 0x1FA88, EastAsianWidth::Wide),
+    (0x1FA90, 0x1FABD, EastAsianWidth::Wide),
+    (0x1FABF, 0x1FAC5, EastAsianWidth::Wide),
+    (0x1FACE, 0x1FADB, EastAsianWidth::Wide),
+    (0x1FAE0, 0x1FAE8, EastAsianWidth::Wide),
+    (0x1FAF0, 0x1FAF8, EastAsianWidth::Wide),
+    (0x20000, 0x2FFFD, EastAsianWidth::Wide),
+    (0x30000, 0x3FFFD, EastAsianWidth::Wide),
+    (0xE0100, 0xE01EF, EastAsianWidth::Ambiguous),
+    (0xF0000, 0xFFFFD, EastAsianWidth::Ambiguous),
+    (0x100000, 0x10FFFD, EastAsianWidth::Ambiguous),
+];
+
+/// Ranges of characters that do not occupy a column of their own
+pub(super) static ZERO_WIDTH: [(u32, u32); 356] = [
+    (0x0000, 0x001F),
+    (0x007F, 0x009F),
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x0610, 0x061A),
+    (0x061C, 0x061C),
+    (0x064B, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DC),
+    (0x06DF, 0x06E4),
+    (0x06E7, 0x06E8),
+    (0x06EA, 0x06ED),
+    (0x0711, 0x0711),
+    (0x0730, 0x074A),
+    (0x07A6, 0x07B0),
+    (0x07EB, 0x07F3),
+    (0x07FD, 0x07FD),
+    (0x0816, 0x0819),
+    (0x081B, 0x0823),
+    (0x0825, 0x0827),
+    (0x0829, 0x082D),
+    (0x0859, 0x085B),
+    (0x0898, 0x089F),
+    (0x08CA, 0x08E1),
+    (0x08E3, 0x0902),
+    (0x093A, 0x093A),
+    (0x093C, 0x093C),
+    (0x0941, 0x0948),
+    (0x094D, 0x094D),
+    (0x0951, 0x0957),
+    (0x0962, 0x0963),
+    (0x0981, 0x0981),
+    (0x09BC, 0x09BC),
+    (0x09C1, 0x09C4),
+    (0x09CD, 0x09CD),
+    (0x09E2, 0x09E3),
+    (0x09FE, 0x09FE),
+    (0x0A01, 0x0A02),
+    (0x0A3C, 0x0A3C),
+    (0x0A41, 0x0A42),
+    (0x0A47, 0x0A48),
+    (0x0A4B, 0x0A4D),
+    (0x0A51, 0x0A51),
+    (0x0A70, 0x0A71),
+    (0x0A75, 0x0A75),
+    (0x0A81, 0x0A82),
+    (0x0ABC, 0x0ABC),
+    (0x0AC1, 0x0AC5),
+    (0x0AC7, 0x0AC8),
+    (0x0ACD, 0x0ACD),
+    (0x0AE2, 0x0AE3),
+    (0x0AFA, 0x0AFF),
+    (0x0B01, 0x0B01),
+    (0x0B3C, 0x0B3C),
+    (0x0B3F, 0x0B3F),
+    (0x0B41, 0x0B44),
+    (0x0B4D, 0x0B4D),
+    (0x0B55, 0x0B56),
+    (0x0B62, 0x0B63),
+    (0x0B82, 0x0B82),
+    (0x0BC0, 0x0BC0),
+    (0x0BCD, 0x0BCD),
+    (0x0C00, 0x0C00),
+    (0x0C04, 0x0C04),
+    (0x0C3C, 0x0C3C),
+    (0x0C3E, 0x0C40),
+    (0x0C46, 0x0C48),
+    (0x0C4A, 0x0C4D),
+    (0x0C55, 0x0C56),
+    (0x0C62, 0x0C63),
+    (0x0C81, 0x0C81),
+    (0x0CBC, 0x0CBC),
+    (0x0CBF, 0x0CBF),
+    (0x0CC6, 0x0CC6),
+    (0x0CCC, 0x0CCD),
+    (0x0CE2, 0x0CE3),
+    (0x0D00, 0x0D01),
+    (0x0D3B, 0x0D3C),
+    (0x0D41, 0x0D44),
+    (0x0D4D, 0x0D4D),
+    (0x0D62, 0x0D63),
+    (0x0D81, 0x0D81),
+    (0x0DCA, 0x0DCA),
+    (0x0DD2, 0x0DD4),
+    (0x0DD6, 0x0DD6),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E),
+    (0x0EB1, 0x0EB1),
+    (0x0EB4, 0x0EBC),
+    (0x0EC8, 0x0ECE),
+    (0x0F18, 0x0F19),
+    (0x0F35, 0x0F35),
+    (0x0F37, 0x0F37),
+    (0x0F39, 0x0F39),
+    (0x0F71, 0x0F7E),
+    (0x0F80, 0x0F84),
+    (0x0F86, 0x0F87),
+    (0x0F8D, 0x0F97),
+    (0x0F99, 0x0FBC),
+    (0x0FC6, 0x0FC6),
+    (0x102D, 0x1030),
+    (0x1032, 0x1037),
+    (0x1039, 0x103A),
+    (0x103D, 0x103E),
+    (0x1058, 0x1059),
+    (0x105E, 0x1060),
+    (0x1071, 0x1074),
+    (0x1082, 0x1082),
+    (0x1085, 0x1086),
+    (0x108D, 0x108D),
+    (0x109D, 0x109D),
+    (0x1160, 0x11FF),
+    (0x135D, 0x135F),
+    (0x1712, 0x1714),
+    (0x1732, 0x1733),
+    (0x1752, 0x1753),
+    (0x1772, 0x1773),
+    (0x17B4, 0x17B5),
+    (0x17B7, 0x17BD),
+    (0x17C6, 0x17C6),
+    (0x17C9, 0x17D3),
+    (0x17DD, 0x17DD),
+    (0x180B, 0x180F),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1920, 0x1922),
+    (0x1927, 0x1928),
+    (0x1932, 0x1932),
+    (0x1939, 0x193B),
+    (0x1A17, 0x1A18),
+    (0x1A1B, 0x1A1B),
+    (0x1A56, 0x1A56),
+    (0x1A58, 0x1A5E),
+    (0x1A60, 0x1A60),
+    (0x1A62, 0x1A62),
+    (0x1A65, 0x1A6C),
+    (0x1A73, 0x1A7C),
+    (0x1A7F, 0x1A7F),
+    (0x1AB0, 0x1ACE),
+    (0x1B00, 0x1B03),
+    (0x1B34, 0x1B34),
+    (0x1B36, 0x1B3A),
+    (0x1B3C, 0x1B3C),
+    (0x1B42, 0x1B42),
+    (0x1B6B, 0x1B73),
+    (0x1B80, 0x1B81),
+    (0x1BA2, 0x1BA5),
+    (0x1BA8, 0x1BA9),
+    (0x1BAB, 0x1BAD),
+    (0x1BE6, 0x1BE6),
+    (0x1BE8, 0x1BE9),
+    (0x1BED, 0x1BED),
+    (0x1BEF, 0x1BF1),
+    (0x1C2C, 0x1C33),
+    (0x1C36, 0x1C37),
+    (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE0),
+    (0x1CE2, 0x1CE8),
+    (0x1CED, 0x1CED),
+    (0x1CF4, 0x1CF4),
+    (0x1CF8, 0x1CF9),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x202A, 0x202E),
+    (0x2060, 0x2064),
+    (0x2066, 0x206F),
+    (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1),
+    (0x2D7F, 0x2D7F),
+    (0x2DE0, 0x2DFF),
+    (0x302A, 0x302D),
+    (0x3099, 0x309A),
+    (0xA66F, 0xA672),
+    (0xA674, 0xA67D),
+    (0xA69E, 0xA69F),
+    (0xA6F0, 0xA6F1),
+    (0xA802, 0xA802),
+    (0xA806, 0xA806),
+    (0xA80B, 0xA80B),
+    (0xA825, 0xA826),
+    (0xA82C, 0xA82C),
+    (0xA8C4, 0xA8C5),
+    (0xA8E0, 0xA8F1),
+    (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D),
+    (0xA947, 0xA951),
+    (0xA980, 0xA982),
+    (0xA9B3, 0xA9B3),
+    (0xA9B6, 0xA9B9),
+    (0xA9BC, 0xA9BD),
+    (0xA9E5, 0xA9E5),
+    (0xAA29, 0xAA2E),
+    (0xAA31, 0xAA32),
+    (0xAA35, 0xAA36),
+    (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4C),
+    (0xAA7C, 0xAA7C),
+    (0xAAB0, 0xAAB0),
+    (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF),
+    (0xAAC1, 0xAAC1),
+    (0xAAEC, 0xAAED),
+    (0xAAF6, 0xAAF6),
+    (0xABE5, 0xABE5),
+    (0xABE8, 0xABE8),
+    (0xABED, 0xABED),
+    (0xD7B0, 0xD7FF),
+    (0xFB1E, 0xFB1E),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFEFF, 0xFEFF),
+    (0xFFF9, 0xFFFB),
+    (0x101FD, 0x101FD),
+    (0x102E0, 0x102E0),
+    (0x10376, 0x1037A),
+    (0x10A01, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F),
+    (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F),
+    (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27),
+    (0x10EAB, 0x10EAC),
+    (0x10EFD, 0x10EFF),
+    (0x10F46, 0x10F50),
+    (0x10F82, 0x10F85),
+    (0x11001, 0x11001),
+    (0x11038, 0x11046),
+    (0x11070, 0x11070),
+    (0x11073, 0x11074),
+    (0x1107F, 0x11081),
+    (0x110B3, 0x110B6),
+    (0x110B9, 0x110BA),
+    (0x110C2, 0x110C2),
+    (0x11100, 0x11102),
+    (0x11127, 0x1112B),
+    (0x1112D, 0x11134),
+    (0x11173, 0x11173),
+    (0x11180, 0x11181),
+    (0x111B6, 0x111BE),
+    (0x111C9, 0x111CC),
+    (0x111CF, 0x111CF),
+    (0x1122F, 0x11231),
+    (0x11234, 0x11234),
+    (0x11236, 0x11237),
+    (0x1123E, 0x1123E),
+    (0x11241, 0x11241),
+    (0x112DF, 0x112DF),
+    (0x112E3, 0x112EA),
+    (0x11300, 0x11301),
+    (0x1133B, 0x1133C),
+    (0x11340, 0x11340),
+    (0x11366, 0x1136C),
+    (0x11370, 0x11374),
+    (0x11438, 0x1143F),
+    (0x11442, 0x11444),
+    (0x11446, 0x11446),
+    (0x1145E, 0x1145E),
+    (0x114B3, 0x114B8),
+    (0x114BA, 0x114BA),
+    (0x114BF, 0x114C0),
+    (0x114C2, 0x114C3),
+    (0x115B2, 0x115B5),
+    (0x115BC, 0x115BD),
+    (0x115BF, 0x115C0),
+    (0x115DC, 0x115DD),
+    (0x11633, 0x1163A),
+    (0x1163D, 0x1163D),
+    (0x1163F, 0x11640),
+    (0x116AB, 0x116AB),
+    (0x116AD, 0x116AD),
+    (0x116B0, 0x116B5),
+    (0x116B7, 0x116B7),
+    (0x1171D, 0x1171F),
+    (0x11722, 0x11725),
+    (0x11727, 0x1172B),
+    (0x1182F, 0x11837),
+    (0x11839, 0x1183A),
+    (0x1193B, 0x1193C),
+    (0x1193E, 0x1193E),
+    (0x11943, 0x11943),
+    (0x119D4, 0x119D7),
+    (0x119DA, 0x119DB),
+    (0x119E0, 0x119E0),
+    (0x11A01, 0x11A0A),
+    (0x11A33, 0x11A38),
+    (0x11A3B, 0x11A3E),
+    (0x11A47, 0x11A47),
+    (0x11A51, 0x11A56),
+    (0x11A59, 0x11A5B),
+    (0x11A8A, 0x11A96),
+    (0x11A98, 0x11A99),
+    (0x11C30, 0x11C36),
+    (0x11C38, 0x11C3D),
+    (0x11C3F, 0x11C3F),
+    (0x11C92, 0x11CA7),
+    (0x11CAA, 0x11CB0),
+    (0x11CB2, 0x11CB3),
+    (0x11CB5, 0x11CB6),
+    (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D45),
+    (0x11D47, 0x11D47),
+    (0x11D90, 0x11D91),
+    (0x11D95, 0x11D95),
+    (0x11D97, 0x11D97),
+    (0x11EF3, 0x11EF4),
+    (0x11F00, 0x11F01),
+    (0x11F36, 0x11F3A),
+    (0x11F40, 0x11F40),
+    (0x11F42, 0x11F42),
+    (0x13430, 0x13440),
+    (0x13447, 0x13455),
+    (0x16AF0, 0x16AF4),
+    (0x16B30, 0x16B36),
+    (0x16F4F, 0x16F4F),
+    (0x16F8F, 0x16F92),
+    (0x16FE4, 0x16FE4),
+    (0x1BC9D, 0x1BC9E),
+    (0x1BCA0, 0x1BCA3),
+    (0x1CF00, 0x1CF2D),
+    (0x1CF30, 0x1CF46),
+    (0x1D167, 0x1D169),
+    (0x1D173, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C),
+    (0x1DA75, 0x1DA75),
+    (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E08F, 0x1E08F),
+    (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE),
+    (0x1E2EC, 0x1E2EF),
+    (0x1E4EC, 0x1E4EF),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E94A),
+    (0xE0001, 0xE0001),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
@@ -0,0 +1,133 @@
+#!/usr/bin/env python3
+"""Generates src/case/tables.rs from the Unicode database bundled with Python.
+
+Python does not expose the Cased and Case_Ignorable properties directly, so they are
+recovered from `str.isupper`/`str.islower` and from the Final_Sigma handling of `str.lower`,
+which are defined in terms of those properties.
+
+The full case folding is taken from `str.casefold`. The simple case folding is the same where that is a
+single character, and otherwise is the simple lowercase mapping, except for U+0130, which has none.
+
+The tables of this crate are all generated from Unicode 15.0.0, which is bundled with Python 3.12.
+"""
+
+import os
+import sys
+import unicodedata
+
+UCD_VERSION = "15.0.0"
+
+ALL = [chr(c) for c in range(0x110000) if not 0xD800 <= c <= 0xDFFF]
+
+
+def is_cased(c):
+    return c.isupper() or c.islower() or unicodedata.category(c) == "Lt"
+
+
+def is_case_ignorable(c):
+    # A final sigma is lowercased to U+03C2 only if it is not followed by a cased letter,
+    #  skipping case-ignorable characters
+    if is_cased(c):
+        return ("AΣ" + c + ".").lower()[1] == "ς"
+    else:
+        return ("AΣ" + c + "B").lower()[1] == "σ"
+
+
+def ranges(pred):
+    out = []
+    for c in ALL:
+        if pred(c):
+            cp = ord(c)
+            if out and out[-1][1] == cp - 1:
+                out[-1][1] = cp
+            else:
+                out.append([cp, cp])
+    return out
+
+
+def write_ranges(f, name, doc, rs):
+    f.write(f"/// {doc}\n")
+    f.write(f"pub(super) static {name}: [(u32, u32); {len(rs)}] = [\n")
+    for lo, hi in rs:
+        f.write(f"    (0x{lo:04X}, 0x{hi:04X}),\n")
+    f.write("];\n\n")
+
+
+def char_lit(c):
+    return f"'\\u{{{ord(c):04X}}}'"
+
+
+def write_mappings(f, name, doc, mapped):
+    f.write(f"/// {doc},\n")
+    f.write("///  padded with NUL\n")
+    f.write(f"pub(super) static {name}: [(char, [char; 3]); {len(mapped)}] = [\n")
+    for c, m in mapped:
+        m = list(m) + ["\0"] * (3 - len(m))
+        f.write(f"    ({char_lit(c)}, [{', '.join(char_lit(x) for x in m)}]),\n")
+    f.write("];\n")
+
+
+def simple_fold(c):
+    folded = c.casefold()
+    if len(folded) == 1:
+        return folded
+    lower = c.lower()
+    if len(lower) == 1 and c != "\u0130":
+        return lower
+    return c
+
+
+def main():
+    if unicodedata.unidata_version != UCD_VERSION:
+        sys.exit(f"Python's Unicode database is version {unicodedata.unidata_version}, not {UCD_VERSION}")
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "case", "tables.rs")
+    with open(path, "w") as f:
+        f.write("// Generated by tools/gen_case_tables.py. Do not edit.\n\n")
+        f.write(f"// Unicode Character Database version {unicodedata.unidata_version}\n\n")
+        write_ranges(f, "CASED", "Ranges of characters with the Cased property", ranges(is_cased))
+        write_ranges(
+            f,
+            "CASE_IGNORABLE",
+            "Ranges of characters with the Case_Ignorable property",
+            ranges(is_case_ignorable),
+        )
+        write_mappings(
+            f,
+            "UPPERCASE",
+            "Full uppercase mappings of the characters whose uppercase mapping is not themselves",
+            [(c, c.upper()) for c in ALL if c.upper() != c],
+        )
+        f.write("\n")
+        write_mappings(
+            f,
+            "LOWERCASE",
+            "Full lowercase mappings of the characters whose lowercase mapping is not themselves",
+            [(c, c.lower()) for c in ALL if c.lower() != c],
+        )
+        f.write("\n")
+        write_mappings(
+            f,
+            "TITLECASE",
+            "Full titlecase mappings of the characters whose titlecase mapping differs from their uppercase mapping",
+            [(c, c.title()) for c in ALL if c.title() != c.upper()],
+        )
+        f.write("\n")
+
+        simple = [(c, simple_fold(c)) for c in ALL if simple_fold(c) != c]
+        f.write("/// Simple case foldings\n")
+        f.write(f"pub(super) static SIMPLE_CASE_FOLDING: [(char, char); {len(simple)}] = [\n")
+        for c, folded in simple:
+            f.write(f"    ({char_lit(c)}, {char_lit(folded)}),\n")
+        f.write("];\n\n")
+
+        full = [c for c in ALL if len(c.casefold()) > 1]
+        f.write("/// Full case foldings of the characters that fold to more than one character, padded with NUL\n")
+        f.write(f"pub(super) static FULL_CASE_FOLDING: [(char, [char; 3]); {len(full)}] = [\n")
+        for c in full:
+            mapped = list(c.casefold()) + ["\0"] * (3 - len(c.casefold()))
+            f.write(f"    ({char_lit(c)}, [{', '.join(char_lit(m) for m in mapped)}]),\n")
+        f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,95 @@
+#!/usr/bin/env python3
+"""Generates src/encoding/tables.rs from the indexes of the WHATWG Encoding Standard.
+
+Usage: gen_encoding_tables.py <index directory>
+
+The index directory shall contain the index-*.txt files of https://encoding.spec.whatwg.org/,
+such as index-windows-1252.txt and index-jis0208.txt.
+Pointers that are not listed in an index are unmapped, and are written as 0.
+"""
+
+import os
+import re
+import sys
+
+LINE = re.compile(r"^\s*(\d+)\t0x([0-9A-F]+)")
+
+SINGLE_BYTE = [
+    ("IBM866", "ibm866"),
+    ("ISO_8859_2", "iso-8859-2"),
+    ("ISO_8859_3", "iso-8859-3"),
+    ("ISO_8859_4", "iso-8859-4"),
+    ("ISO_8859_5", "iso-8859-5"),
+    ("ISO_8859_6", "iso-8859-6"),
+    ("ISO_8859_7", "iso-8859-7"),
+    ("ISO_8859_8", "iso-8859-8"),
+    ("ISO_8859_10", "iso-8859-10"),
+    ("ISO_8859_13", "iso-8859-13"),
+    ("ISO_8859_14", "iso-8859-14"),
+    ("ISO_8859_15", "iso-8859-15"),
+    ("ISO_8859_16", "iso-8859-16"),
+    ("KOI8_R", "koi8-r"),
+    ("KOI8_U", "koi8-u"),
+    ("MACINTOSH", "macintosh"),
+    ("WINDOWS_874", "windows-874"),
+    ("WINDOWS_1250", "windows-1250"),
+    ("WINDOWS_1251", "windows-1251"),
+    ("WINDOWS_1252", "windows-1252"),
+    ("WINDOWS_1253", "windows-1253"),
+    ("WINDOWS_1254", "windows-1254"),
+    ("WINDOWS_1255", "windows-1255"),
+    ("WINDOWS_1256", "windows-1256"),
+    ("WINDOWS_1257", "windows-1257"),
+    ("WINDOWS_1258", "windows-1258"),
+    ("X_MAC_CYRILLIC", "x-mac-cyrillic"),
+]
+
+# The pointers of index-jis0208 reachable from Shift_JIS, which has 60 lead bytes of 188 trail bytes each
+JIS0208_LEN = 60 * 188
+
+
+def read_index(directory, name, length):
+    """Returns the code points of the pointers `0..length` of an index, with 0 for the pointers it does not list"""
+    table = [0] * length
+    with open(os.path.join(directory, f"index-{name}.txt"), encoding="utf-8") as f:
+        for line in f:
+            m = LINE.match(line)
+            if m and int(m.group(1)) < length:
+                table[int(m.group(1))] = int(m.group(2), 16)
+    return table
+
+
+def write_array(out, name, ty, values, per_line=12):
+    out.write("pub(super) static %s: [%s; %d] = [\n" % (name, ty, len(values)))
+    for i in range(0, len(values), per_line):
+        out.write("    " + " ".join("0x%04X," % v for v in values[i : i + per_line]) + "\n")
+    out.write("];\n\n")
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(f"usage: {sys.argv[0]} <index directory>")
+    indexes = sys.argv[1]
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "encoding", "tables.rs")
+    with open(path, "w") as out:
+        out.write("// Generated by tools/gen_encoding_tables.py. Do not edit.\n\n")
+        for name, index in SINGLE_BYTE:
+            write_array(out, name, "u16", read_index(indexes, index, 128))
+
+        jis = read_index(indexes, "jis0208", JIS0208_LEN)
+        write_array(out, "JIS0208", "u16", jis)
+
+        # Code point to pointer, for encoding, excluding the duplicate NEC-selected IBM extensions
+        seen = {}
+        for pointer, cp in enumerate(jis):
+            if cp and not (8272 <= pointer <= 8835) and cp not in seen:
+                seen[cp] = pointer
+        pairs = sorted(seen.items())
+        out.write("pub(super) static JIS0208_ENCODE: [(u16, u16); %d] = [\n" % len(pairs))
+        for i in range(0, len(pairs), 6):
+            out.write("    " + " ".join("(0x%04X, %d)," % p for p in pairs[i : i + 6]) + "\n")
+        out.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,121 @@
+#!/usr/bin/env python3
+"""Generates src/linebreak/tables.rs from the Unicode Character Database.
+
+Usage: gen_linebreak_tables.py <UCD directory>
+
+The UCD directory is the extracted UCD.zip of a Unicode release, and shall contain LineBreak.txt,
+extracted/DerivedGeneralCategory.txt and emoji/emoji-data.txt.
+It shall be Unicode 15.0.0, the version of the other generated tables, so that the properties agree.
+
+The classes are resolved as described by rule LB1 of UAX #14: AI, SG and XX are resolved to AL,
+SA to CM if it is a nonspacing or spacing mark and AL otherwise, and CJ to NS.
+Unassigned Extended_Pictographic characters are resolved to EB, as rule LB30b treats them the same.
+"""
+
+import os
+import re
+import sys
+
+LINE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*([^#]*?)\s*(?:#.*)?$")
+MISSING = re.compile(r"^#\s*@missing:\s*(.*)$")
+VERSION = re.compile(r"-(\d+\.\d+\.\d+)\.txt")
+UCD_VERSION = "15.0.0"
+
+
+def read_property(path, prop=None):
+    """Returns a map from code point to value of the property in a UCD file, including `@missing` defaults.
+
+    If `prop` is given, only the lines for that binary property are read.
+    """
+    missing = []
+    listed = []
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.strip()
+            m = MISSING.match(line)
+            target = missing if m else listed
+            m = LINE.match(m.group(1) if m else line)
+            if not m:
+                continue
+            lo = int(m.group(1), 16)
+            hi = int(m.group(2) or m.group(1), 16)
+            value = m.group(3).split(";")[0].strip()
+            if prop is not None:
+                if value != prop:
+                    continue
+                value = True
+            target.append((lo, hi, value))
+
+    values = {}
+    for lo, hi, value in missing + listed:
+        for cp in range(lo, hi + 1):
+            values[cp] = value
+    return values
+
+
+def read_version(path):
+    with open(path, encoding="utf-8") as f:
+        m = VERSION.search(f.readline())
+    return m.group(1)
+
+
+def ranges(values):
+    out = []
+    for cp in sorted(values):
+        v = values[cp]
+        if out and out[-1][1] == cp - 1 and out[-1][2] == v:
+            out[-1][1] = cp
+        else:
+            out.append([cp, cp, v])
+    return out
+
+
+def resolve(cls, gc, pict):
+    if cls in ("AI", "SG", "XX"):
+        return "AL"
+    if cls == "SA":
+        return "CM" if gc in ("Mn", "Mc") else "AL"
+    if cls == "CJ":
+        return "NS"
+    if cls == "ID" and pict and gc == "Cn":
+        return "EB"
+    return cls
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(f"usage: {sys.argv[0]} <UCD directory>")
+    ucd = sys.argv[1]
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "linebreak", "tables.rs")
+    source = os.path.join(ucd, "LineBreak.txt")
+    version = read_version(source)
+    if version != UCD_VERSION:
+        sys.exit(f"the UCD directory is version {version}, not {UCD_VERSION}")
+
+    lb = read_property(source)
+    gc = read_property(os.path.join(ucd, "extracted", "DerivedGeneralCategory.txt"))
+    pict = read_property(os.path.join(ucd, "emoji", "emoji-data.txt"), "Extended_Pictographic")
+
+    classes = {}
+    for cp in range(0x110000):
+        if 0xD800 <= cp <= 0xDFFF:
+            continue
+        cls = resolve(lb.get(cp, "XX"), gc.get(cp, "Cn"), cp in pict)
+        if cls != "AL":
+            classes[cp] = cls[0] + cls[1:].lower()
+    rs = ranges(classes)
+
+    with open(path, "w") as f:
+        f.write("// Generated by tools/gen_linebreak_tables.py. Do not edit.\n\n")
+        f.write(f"// Unicode Character Database version {version}\n\n")
+        f.write("use super::LineBreak;\n\n")
+
+        f.write("/// Ranges of characters with a resolved Line_Break other than AL, and their class\n")
+        f.write(f"pub(super) static LINE_BREAK: [(u32, u32, LineBreak); {len(rs)}] = [\n")
+        for lo, hi, v in rs:
+            f.write(f"    (0x{lo:04X}, 0x{hi:04X}, LineBreak::{v}),\n")
+        f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
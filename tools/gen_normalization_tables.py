@@ -0,0 +1,141 @@
+#!/usr/bin/env python3
+"""Generates src/normalize/tables.rs from the Unicode database bundled with Python.
+
+Hangul syllables are decomposed and composed algorithmically, and are not included in the tables.
+The decomposition tables hold full (recursive) decompositions, and the compatibility table
+only holds the characters whose compatibility decomposition differs from their canonical one.
+
+Requires Python 3.12, whose database is Unicode 15.0.0, the version that all other tables are generated from too.
+"""
+
+import os
+import sys
+import unicodedata
+from unicodedata import normalize
+
+UCD_VERSION = "15.0.0"
+
+ALL = [
+    chr(c)
+    for c in range(0x110000)
+    if not 0xD800 <= c <= 0xDFFF and not 0xAC00 <= c <= 0xD7A3
+]
+HANGUL_V = range(0x1161, 0x1176)
+HANGUL_T = range(0x11A8, 0x11C3)
+
+
+def char_lit(c):
+    return f"'\\u{{{ord(c):04X}}}'"
+
+
+def ranges(pred):
+    out = []
+    for c in ALL:
+        if pred(c):
+            cp = ord(c)
+            if out and out[-1][1] == cp - 1:
+                out[-1][1] = cp
+            else:
+                out.append([cp, cp])
+    return out
+
+
+def write_ranges(f, name, doc, rs):
+    f.write(f"/// {doc}\n")
+    f.write(f"pub(super) static {name}: [(u32, u32); {len(rs)}] = [\n")
+    for lo, hi in rs:
+        f.write(f"    (0x{lo:04X}, 0x{hi:04X}),\n")
+    f.write("];\n\n")
+
+
+def write_decompositions(f, name, doc, mapping):
+    chars = []
+    entries = []
+    for c, d in mapping:
+        entries.append((c, len(chars), len(d)))
+        chars.extend(d)
+    f.write(f"/// {doc}, as offsets and lengths into `{name}_CHARS`\n")
+    f.write(f"pub(super) static {name}: [(char, u16, u8); {len(entries)}] = [\n")
+    for c, off, n in entries:
+        f.write(f"    ({char_lit(c)}, {off}, {n}),\n")
+    f.write("];\n\n")
+    f.write(f"pub(super) static {name}_CHARS: [char; {len(chars)}] = [\n")
+    for i in range(0, len(chars), 8):
+        f.write("    " + ", ".join(char_lit(c) for c in chars[i : i + 8]) + ",\n")
+    f.write("];\n\n")
+
+
+def main():
+    if unicodedata.unidata_version != UCD_VERSION:
+        sys.exit(f"Python's Unicode database is version {unicodedata.unidata_version}, not {UCD_VERSION}")
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "normalize", "tables.rs")
+
+    ccc = []
+    for c in ALL:
+        cls = unicodedata.combining(c)
+        if cls:
+            cp = ord(c)
+            if ccc and ccc[-1][1] == cp - 1 and ccc[-1][2] == cls:
+                ccc[-1][1] = cp
+            else:
+                ccc.append([cp, cp, cls])
+
+    canonical = [(c, normalize("NFD", c)) for c in ALL if normalize("NFD", c) != c]
+    compatible = [
+        (c, normalize("NFKD", c)) for c in ALL if normalize("NFKD", c) != normalize("NFD", c)
+    ]
+
+    # The primary composites are the characters with a canonical decomposition of two characters
+    #  that are not excluded from composition
+    compositions = []
+    for c in ALL:
+        d = unicodedata.decomposition(c)
+        if d and not d.startswith("<") and normalize("NFC", c) == c:
+            pair = [chr(int(x, 16)) for x in d.split()]
+            if len(pair) == 2:
+                compositions.append((pair[0], pair[1], c))
+    compositions.sort()
+    seconds = {ord(b) for _, b, _ in compositions} | set(HANGUL_V) | set(HANGUL_T)
+
+    with open(path, "w") as f:
+        f.write("// Generated by tools/gen_normalization_tables.py. Do not edit.\n\n")
+        f.write(f"// Unicode Character Database version {unicodedata.unidata_version}\n\n")
+
+        f.write("/// Ranges of characters with a non-zero Canonical_Combining_Class, and that class\n")
+        f.write(f"pub(super) static COMBINING_CLASS: [(u32, u32, u8); {len(ccc)}] = [\n")
+        for lo, hi, cls in ccc:
+            f.write(f"    (0x{lo:04X}, 0x{hi:04X}, {cls}),\n")
+        f.write("];\n\n")
+
+        write_decompositions(f, "CANONICAL", "Full canonical decompositions", canonical)
+        write_decompositions(
+            f,
+            "COMPATIBLE",
+            "Full compatibility decompositions that differ from the canonical decomposition",
+            compatible,
+        )
+
+        f.write("/// Primary composites, sorted by the pair of characters they are composed from\n")
+        f.write(f"pub(super) static COMPOSITIONS: [(char, char, char); {len(compositions)}] = [\n")
+        for a, b, c in compositions:
+            f.write(f"    ({char_lit(a)}, {char_lit(b)}, {char_lit(c)}),\n")
+        f.write("];\n\n")
+
+        write_ranges(f, "NFD_QC_NO", "Ranges of characters with NFD_Quick_Check=No",
+                     ranges(lambda c: normalize("NFD", c) != c))
+        write_ranges(f, "NFKD_QC_NO", "Ranges of characters with NFKD_Quick_Check=No",
+                     ranges(lambda c: normalize("NFKD", c) != c))
+        write_ranges(f, "NFC_QC_NO", "Ranges of characters with NFC_Quick_Check=No",
+                     ranges(lambda c: normalize("NFC", c) != c))
+        write_ranges(f, "NFKC_QC_NO", "Ranges of characters with NFKC_Quick_Check=No",
+                     ranges(lambda c: normalize("NFKC", c) != c))
+        write_ranges(
+            f,
+            "NFC_QC_MAYBE",
+            "Ranges of characters with NFC_Quick_Check=Maybe, which is also NFKC_Quick_Check=Maybe unless NFKC_Quick_Check=No",
+            ranges(lambda c: ord(c) in seconds),
+        )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,138 @@
+#!/usr/bin/env python3
+"""Generates src/regex/tables.rs from the Unicode Character Database.
+
+Usage: gen_regex_tables.py <UCD directory>
+
+The UCD directory is the extracted UCD.zip of a Unicode release, and shall contain extracted/DerivedGeneralCategory.txt,
+DerivedCoreProperties.txt and PropList.txt.
+It shall be Unicode 15.0.0, so that `\p{..}` agrees with the case, segmentation and width tables of the crate.
+Code points that are not listed are unassigned (Cn), and are left out of the table, as are surrogates (Cs),
+which are not characters.
+"""
+
+import os
+import re
+import sys
+
+LINE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*([^#]*?)\s*(?:#.*)?$")
+VERSION = re.compile(r"-(\d+\.\d+\.\d+)\.txt")
+UCD_VERSION = "15.0.0"
+
+BINARY_PROPERTIES = [
+    ("DerivedCoreProperties.txt", "Alphabetic", "ALPHABETIC"),
+    ("DerivedCoreProperties.txt", "Lowercase", "LOWERCASE"),
+    ("DerivedCoreProperties.txt", "Uppercase", "UPPERCASE"),
+    ("PropList.txt", "White_Space", "WHITE_SPACE"),
+]
+
+NAMES = {
+    "Lu": "UppercaseLetter",
+    "Ll": "LowercaseLetter",
+    "Lt": "TitlecaseLetter",
+    "Lm": "ModifierLetter",
+    "Lo": "OtherLetter",
+    "Mn": "NonspacingMark",
+    "Mc": "SpacingMark",
+    "Me": "EnclosingMark",
+    "Nd": "DecimalNumber",
+    "Nl": "LetterNumber",
+    "No": "OtherNumber",
+    "Pc": "ConnectorPunctuation",
+    "Pd": "DashPunctuation",
+    "Ps": "OpenPunctuation",
+    "Pe": "ClosePunctuation",
+    "Pi": "InitialPunctuation",
+    "Pf": "FinalPunctuation",
+    "Po": "OtherPunctuation",
+    "Sm": "MathSymbol",
+    "Sc": "CurrencySymbol",
+    "Sk": "ModifierSymbol",
+    "So": "OtherSymbol",
+    "Zs": "SpaceSeparator",
+    "Zl": "LineSeparator",
+    "Zp": "ParagraphSeparator",
+    "Cc": "Control",
+    "Cf": "Format",
+    "Co": "PrivateUse",
+}
+
+
+def read_property(path):
+    """Returns a map from code point to value of the property in a UCD file"""
+    values = {}
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            m = LINE.match(line.strip())
+            if not m:
+                continue
+            lo = int(m.group(1), 16)
+            hi = int(m.group(2) or m.group(1), 16)
+            for cp in range(lo, hi + 1):
+                values[cp] = m.group(3)
+    return values
+
+
+def read_binary_property(path, prop):
+    """Returns the set of code points with a binary property in a UCD file, which may list several properties"""
+    cps = set()
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            m = LINE.match(line.strip())
+            if m and m.group(3) == prop:
+                cps.update(range(int(m.group(1), 16), int(m.group(2) or m.group(1), 16) + 1))
+    return cps
+
+
+def read_version(path):
+    with open(path, encoding="utf-8") as f:
+        m = VERSION.search(f.readline())
+    return m.group(1)
+
+
+def ranges(values):
+    out = []
+    for cp in sorted(values):
+        v = values[cp]
+        if out and out[-1][1] == cp - 1 and out[-1][2] == v:
+            out[-1][1] = cp
+        else:
+            out.append([cp, cp, v])
+    return out
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(f"usage: {sys.argv[0]} <UCD directory>")
+    ucd = sys.argv[1]
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "regex", "tables.rs")
+    source = os.path.join(ucd, "extracted", "DerivedGeneralCategory.txt")
+    version = read_version(source)
+    if version != UCD_VERSION:
+        sys.exit(f"the UCD directory is version {version}, not {UCD_VERSION}")
+
+    gc = {cp: NAMES[v] for cp, v in read_property(source).items() if v in NAMES}
+    rs = ranges(gc)
+
+    with open(path, "w") as f:
+        f.write("// Generated by tools/gen_regex_tables.py. Do not edit.\n\n")
+        f.write(f"// Unicode Character Database version {version}\n\n")
+        f.write("use super::GeneralCategory;\n\n")
+
+        f.write("/// Ranges of assigned characters, and their General_Category\n")
+        f.write(f"pub(super) static GENERAL_CATEGORY: [(u32, u32, GeneralCategory); {len(rs)}] = [\n")
+        for lo, hi, v in rs:
+            f.write(f"    (0x{lo:04X}, 0x{hi:04X}, GeneralCategory::{v}),\n")
+        f.write("];\n")
+
+        for file, prop, name in BINARY_PROPERTIES:
+            values = {cp: True for cp in read_binary_property(os.path.join(ucd, file), prop)}
+            ps = ranges(values)
+            f.write(f"\n/// Ranges of characters with the {prop} property\n")
+            f.write(f"pub(super) static {name}: [(u32, u32); {len(ps)}] = [\n")
+            for lo, hi, _ in ps:
+                f.write(f"    (0x{lo:04X}, 0x{hi:04X}),\n")
+            f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
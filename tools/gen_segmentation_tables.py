@@ -0,0 +1,157 @@
+#!/usr/bin/env python3
+"""Generates src/segment/tables.rs from the Unicode Character Database.
+
+Usage: gen_segmentation_tables.py <UCD directory>
+
+The UCD directory is the extracted UCD.zip of a Unicode release, and shall contain
+auxiliary/GraphemeBreakProperty.txt, auxiliary/WordBreakProperty.txt, auxiliary/SentenceBreakProperty.txt,
+emoji/emoji-data.txt, DerivedCoreProperties.txt and extracted/DerivedGeneralCategory.txt, from Unicode 15.0.0,
+the version that all tables of this crate are generated from.
+
+The grapheme table merges Grapheme_Cluster_Break with Extended_Pictographic,
+which never overlaps with a Grapheme_Cluster_Break other than Any.
+"""
+
+import os
+import re
+import sys
+
+LINE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*([^#]*?)\s*(?:#.*)?$")
+VERSION = re.compile(r"-(\d+\.\d+\.\d+)\.txt")
+UCD_VERSION = "15.0.0"
+
+
+def read_property(path, prop=None):
+    """Returns a map from code point to value of the property in a UCD file.
+
+    If `prop` is given, only the lines for that property are read,
+    and the value is the remaining field (or `True` for binary properties).
+    """
+    values = {}
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            m = LINE.match(line.strip())
+            if not m:
+                continue
+            lo = int(m.group(1), 16)
+            hi = int(m.group(2) or m.group(1), 16)
+            fields = [x.strip() for x in m.group(3).split(";")]
+            if prop is not None:
+                if fields[0] != prop:
+                    continue
+                value = fields[1] if len(fields) > 1 else True
+            else:
+                value = fields[0]
+            for cp in range(lo, hi + 1):
+                values[cp] = value
+    return values
+
+
+def read_version(path):
+    with open(path, encoding="utf-8") as f:
+        m = VERSION.search(f.readline())
+    return m.group(1)
+
+
+def ranges(values):
+    out = []
+    for cp in sorted(values):
+        v = values[cp]
+        if out and out[-1][1] == cp - 1 and out[-1][2] == v:
+            out[-1][1] = cp
+        else:
+            out.append([cp, cp, v])
+    return out
+
+
+def write_table(f, name, doc, ty, values):
+    rs = ranges(values)
+    f.write(f"/// {doc}\n")
+    f.write(f"pub(super) static {name}: [(u32, u32, {ty}); {len(rs)}] = [\n")
+    for lo, hi, v in rs:
+        f.write(f"    (0x{lo:04X}, 0x{hi:04X}, {ty}::{v}),\n")
+    f.write("];\n\n")
+
+
+def write_ranges(f, name, doc, cps):
+    rs = ranges(dict.fromkeys(cps, True))
+    f.write(f"/// {doc}\n")
+    f.write(f"pub(super) static {name}: [(u32, u32); {len(rs)}] = [\n")
+    for lo, hi, _ in rs:
+        f.write(f"    (0x{lo:04X}, 0x{hi:04X}),\n")
+    f.write("];\n\n")
+
+
+def camel(name):
+    if name.isupper():
+        return name[0] + name[1:].lower()
+    return "".join(part[0].upper() + part[1:] for part in name.split("_"))
+
+
+def grapheme_cats(ucd):
+    gcb = read_property(os.path.join(ucd, "auxiliary", "GraphemeBreakProperty.txt"))
+    pict = read_property(os.path.join(ucd, "emoji", "emoji-data.txt"), "Extended_Pictographic")
+
+    cats = {cp: camel(v) for cp, v in gcb.items()}
+    for cp in pict:
+        assert cp not in gcb, f"Extended_Pictographic U+{cp:04X} has a Grapheme_Cluster_Break"
+        cats[cp] = "ExtendedPictographic"
+    return cats
+
+
+def break_cats(ucd, name):
+    values = read_property(os.path.join(ucd, "auxiliary", f"{name}.txt"))
+    return {cp: camel(v) for cp, v in values.items()}
+
+
+def alphanumeric(ucd):
+    alphabetic = read_property(os.path.join(ucd, "DerivedCoreProperties.txt"), "Alphabetic")
+    gc = read_property(os.path.join(ucd, "extracted", "DerivedGeneralCategory.txt"))
+    return set(alphabetic) | {cp for cp, v in gc.items() if v in ("Nd", "Nl", "No")}
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(f"usage: {sys.argv[0]} <UCD directory>")
+    ucd = sys.argv[1]
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "segment", "tables.rs")
+    version = read_version(os.path.join(ucd, "auxiliary", "GraphemeBreakProperty.txt"))
+    if version != UCD_VERSION:
+        sys.exit(f"the UCD directory is version {version}, not {UCD_VERSION}")
+
+    with open(path, "w") as f:
+        f.write("// Generated by tools/gen_segmentation_tables.py. Do not edit.\n\n")
+        f.write(f"// Unicode Character Database version {version}\n\n")
+        f.write("use super::{GraphemeCat as G, SentenceCat as S, WordCat as W};\n\n")
+
+        write_table(
+            f,
+            "GRAPHEME_CAT",
+            "Ranges of characters with a Grapheme_Cluster_Break other than Any, or Extended_Pictographic, and their category",
+            "G",
+            grapheme_cats(ucd),
+        )
+        write_table(
+            f,
+            "WORD_CAT",
+            "Ranges of characters with a Word_Break other than Other, and their category",
+            "W",
+            break_cats(ucd, "WordBreakProperty"),
+        )
+        write_table(
+            f,
+            "SENTENCE_CAT",
+            "Ranges of characters with a Sentence_Break other than Other, and their category",
+            "S",
+            break_cats(ucd, "SentenceBreakProperty"),
+        )
+        write_ranges(
+            f,
+            "ALPHANUMERIC",
+            "Ranges of characters with the Alphabetic property or a General_Category of Nd, Nl or No",
+            alphanumeric(ucd),
+        )
+
+
+if __name__ == "__main__":
+    main()
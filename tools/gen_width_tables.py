@@ -0,0 +1,134 @@
+#!/usr/bin/env python3
+"""Generates src/width/tables.rs from the Unicode Character Database.
+
+Usage: gen_width_tables.py <UCD directory>
+
+The UCD directory is the extracted UCD.zip of a Unicode release, and shall contain EastAsianWidth.txt
+and extracted/DerivedGeneralCategory.txt.
+It shall be Unicode 15.0.0, the version of the other generated tables, so that the properties agree.
+The defaults given by `@missing` lines apply to the code points that are not listed.
+
+Characters are zero width if they are nonspacing or enclosing marks, format or control characters,
+or Hangul medial vowels and final consonants, which combine with the preceding initial consonant.
+The soft hyphen and the prepended concatenation marks are visible, and keep their width.
+"""
+
+import os
+import re
+import sys
+
+LINE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*([^#]*?)\s*(?:#.*)?$")
+MISSING = re.compile(r"^#\s*@missing:\s*(.*)$")
+VERSION = re.compile(r"-(\d+\.\d+\.\d+)\.txt")
+UCD_VERSION = "15.0.0"
+
+NAMES = {
+    "A": "Ambiguous",
+    "F": "Fullwidth",
+    "H": "Halfwidth",
+    "N": "Neutral",
+    "Na": "Narrow",
+    "W": "Wide",
+}
+
+ZERO_WIDTH_CATEGORIES = ("Mn", "Me", "Cf", "Cc")
+HANGUL_JAMO = [(0x1160, 0x11FF), (0xD7B0, 0xD7FF)]
+VISIBLE_FORMAT = [
+    (0x00AD, 0x00AD),
+    (0x0600, 0x0605),
+    (0x06DD, 0x06DD),
+    (0x070F, 0x070F),
+    (0x0890, 0x0891),
+    (0x08E2, 0x08E2),
+    (0x110BD, 0x110BD),
+    (0x110CD, 0x110CD),
+]
+
+
+def read_property(path):
+    """Returns a map from code point to value of the property in a UCD file, including `@missing` defaults"""
+    missing = []
+    listed = []
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.strip()
+            m = MISSING.match(line)
+            target = missing if m else listed
+            m = LINE.match(m.group(1) if m else line)
+            if not m:
+                continue
+            lo = int(m.group(1), 16)
+            hi = int(m.group(2) or m.group(1), 16)
+            target.append((lo, hi, m.group(3).split(";")[0].strip()))
+
+    values = {}
+    for lo, hi, value in missing + listed:
+        for cp in range(lo, hi + 1):
+            values[cp] = value
+    return values
+
+
+def read_version(path):
+    with open(path, encoding="utf-8") as f:
+        m = VERSION.search(f.readline())
+    return m.group(1)
+
+
+def ranges(values):
+    out = []
+    for cp in sorted(values):
+        v = values[cp]
+        if out and out[-1][1] == cp - 1 and out[-1][2] == v:
+            out[-1][1] = cp
+        else:
+            out.append([cp, cp, v])
+    return out
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(f"usage: {sys.argv[0]} <UCD directory>")
+    ucd = sys.argv[1]
+    path = os.path.join(os.path.dirname(__file__), "..", "src", "width", "tables.rs")
+    source = os.path.join(ucd, "EastAsianWidth.txt")
+    version = read_version(source)
+    if version != UCD_VERSION:
+        sys.exit(f"the UCD directory is version {version}, not {UCD_VERSION}")
+
+    eaw = {
+        cp: NAMES[v]
+        for cp, v in read_property(source).items()
+        if v != "N" and not 0xD800 <= cp <= 0xDFFF
+    }
+    rs = ranges(eaw)
+
+    gc = read_property(os.path.join(ucd, "extracted", "DerivedGeneralCategory.txt"))
+    zero = {cp: True for cp, v in gc.items() if v in ZERO_WIDTH_CATEGORIES}
+    for lo, hi in HANGUL_JAMO:
+        for cp in range(lo, hi + 1):
+            zero[cp] = True
+    for lo, hi in VISIBLE_FORMAT:
+        for cp in range(lo, hi + 1):
+            zero.pop(cp, None)
+    zs = ranges(zero)
+
+    with open(path, "w") as f:
+        f.write("// Generated by tools/gen_width_tables.py. Do not edit.\n\n")
+        f.write(f"// Unicode Character Database version {version}\n\n")
+        f.write("use super::EastAsianWidth;\n\n")
+
+        f.write("/// Ranges of characters with an East_Asian_Width other than Neutral, and their width\n")
+        f.write(f"pub(super) static EAST_ASIAN_WIDTH: [(u32, u32, EastAsianWidth); {len(rs)}] = [\n")
+        for lo, hi, v in rs:
+            f.write(f"    (0x{lo:04X}, 0x{hi:04X}, EastAsianWidth::{v}),\n")
+        f.write("];\n\n")
+
+        f.write("/// Ranges of characters that do not occupy a column of their own\n")
+        f.write(f"pub(super) static ZERO_WIDTH: [(u32, u32); {len(zs)}] = [\n")
+        for lo, hi, _ in zs:
+            f.write(f"    (0x{lo:04X}, 0x{hi:04X}),\n")
+        f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()